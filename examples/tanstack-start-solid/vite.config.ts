@@ -20,7 +20,7 @@ export default defineConfig({
     solidOxc({
       // TanStack Start ships JSX in node_modules, compile everything
       exclude: [],
-      hydratable: true,
+      solid: { hydratable: true },
     }),
   ],
 })
@@ -0,0 +1,161 @@
+/* auto-generated by NAPI-RS */
+/* eslint-disable */
+/**
+ * `jsxImportSource` for a dev server, which reads the configs of each
+ * directory once; call `invalidate` with the files its watcher reports
+ */
+export declare class JsxImportSources {
+  constructor()
+  /** The JSX import source of `filename`, as `jsxImportSource` finds it */
+  get(filename: string, source?: string | undefined | null): string | null
+  /**
+   * Forget what was read when `path`, a changed, added or removed file, is
+   * a tsconfig.json or a config one extends; `true` when it was
+   */
+  invalidate(path: string): boolean
+}
+
+/**
+ * Compiled modules by their contents, path and options, so a dev server does
+ * not compile an unchanged module again. With a directory, outputs are also
+ * kept there for the next process; `prune` keeps it to a size.
+ */
+export declare class TransformCache {
+  /**
+   * Keep the `capacity` (4096 by default) most recently used outputs in
+   * memory, and every output in `dir` when given
+   */
+  constructor(dir?: string | undefined | null, capacity?: number | undefined | null)
+  /**
+   * `transform`, with the output from the cache when the module, its
+   * `filename` and options are unchanged
+   */
+  transform(source: string, options?: JsTransformOptions | undefined | null): Promise<TransformResult>
+  /**
+   * Remove the least recently used outputs from the directory until the
+   * rest take at most `maxBytes`; returns how many were removed
+   */
+  prune(maxBytes: number): number
+}
+
+/**
+ * Compiles many files with the same options, as a dev server does. The
+ * options are converted from JavaScript once, and the memory that holds the
+ * syntax tree of a file is reset and reused for the next.
+ */
+export declare class TransformSession {
+  /** Throws when the options are invalid */
+  constructor(options?: JsTransformOptions | undefined | null)
+  /**
+   * Transform `source`, the contents of `filename`; without a filename,
+   * the one of the session's options
+   */
+  transform(source: string, filename?: string | undefined | null): TransformResult
+}
+
+/** JSX attribute aliases, mapping the JSX name to the DOM attribute name */
+export declare function getAliases(): Record<string, string>
+
+/** Attributes treated as booleans (present = true) */
+export declare function getBooleanAttributes(): Array<string>
+
+/** Events that are delegated to the document by default */
+export declare function getDelegatedEvents(): Array<string>
+
+/**
+ * Whether `importSource` is Solid's: solid-js, or the package of
+ * `moduleName` (solid-js/web by default) for custom renderers
+ */
+export declare function isSolidImportSource(importSource: string, moduleName?: string | undefined | null): boolean
+
+/**
+ * The JSX import source of a file, from a `@jsxImportSource` comment in
+ * `source` or the nearest tsconfig.json; null when neither says
+ */
+export declare function jsxImportSource(filename: string, source?: string | undefined | null): string | null
+
+/**
+ * Transform JSX source code on the libuv thread pool, resolving to the
+ * same result as `transformSync`
+ */
+export declare function transform(source: string, options?: JsTransformOptions | undefined | null): Promise<TransformResult>
+
+/** Transform options exposed to JavaScript */
+export interface JsTransformOptions {
+  /**
+   * The module to import runtime helpers from
+   * @default "solid-js/web"
+   */
+  moduleName?: string
+  /**
+   * Generate mode: "dom", "ssr", or "universal"
+   * Note: "universal" is currently treated as "dom" (not a separate universal renderer output).
+   * @default "dom"
+   */
+  generate?: string
+  /**
+   * Whether to enable hydration support
+   * @default false
+   */
+  hydratable?: boolean
+  /**
+   * Whether to delegate events
+   * @default true
+   */
+  delegateEvents?: boolean
+  /**
+   * Whether to wrap conditionals
+   * @default true
+   */
+  wrapConditionals?: boolean
+  /**
+   * Whether to pass context to custom elements
+   * @default true
+   */
+  contextToCustomElements?: boolean
+  /**
+   * Source filename
+   * @default "input.jsx"
+   */
+  filename?: string
+  /**
+   * Whether to generate source maps
+   * @default false
+   */
+  sourceMap?: boolean
+  /**
+   * Whether to register exported components with solid-refresh
+   * @default false
+   */
+  hmr?: boolean
+  /**
+   * HMR API for solid-refresh: "vite", "esm", "standard" or "webpack5"
+   * @default "vite"
+   */
+  hmrBundler?: string
+  /**
+   * Extensions parsed like a known one, e.g. `{ solid: "tsx" }`; files with
+   * other unknown extensions are parsed as TSX
+   */
+  customExtensions?: Record<string, string>
+}
+
+/** Transform JSX source code */
+export declare function transformJsx(source: string, options?: JsTransformOptions | undefined | null): TransformResult
+
+/** Transform JSX source code on the calling thread */
+export declare function transformSync(source: string, options?: JsTransformOptions | undefined | null): TransformResult
+
+/** Result of a transform operation */
+export interface TransformResult {
+  /** The transformed code */
+  code: string
+  /** Source map (if enabled) */
+  map?: string
+  /** Events the output passes to `delegateEvents` */
+  delegatedEvents: Array<string>
+  /** HTML of each hoisted template */
+  templates: Array<string>
+  /** Runtime helpers the output imports */
+  helpers: Array<string>
+}
@@ -0,0 +1,198 @@
+/**
+ * @solid-jsx-oxc/core - native addon for the OXC-based SolidJS JSX compiler
+ */
+
+export interface TransformOptions {
+  /**
+   * The module to import runtime helpers from
+   * @default "solid-js/web"
+   */
+  moduleName?: string;
+
+  /**
+   * Generate mode: "dom", "ssr", or "universal" (currently treated as "dom")
+   * @default "dom"
+   */
+  generate?: 'dom' | 'ssr' | 'universal';
+
+  /**
+   * Whether to enable hydration support
+   * @default false
+   */
+  hydratable?: boolean;
+
+  /**
+   * Whether to delegate events
+   * @default true
+   */
+  delegateEvents?: boolean;
+
+  /**
+   * Whether to wrap conditionals
+   * @default true
+   */
+  wrapConditionals?: boolean;
+
+  /**
+   * Whether to pass context to custom elements
+   * @default true
+   */
+  contextToCustomElements?: boolean;
+
+  /**
+   * Source filename, also used to pick the parser (jsx, tsx, ...)
+   * @default "input.jsx"
+   */
+  filename?: string;
+
+  /**
+   * Whether to generate a source map
+   * @default false
+   */
+  sourceMap?: boolean;
+
+  /**
+   * Register exported components with solid-refresh for hot reload
+   * (ignored for SSR output)
+   * @default false
+   */
+  hmr?: boolean;
+
+  /**
+   * HMR API the solid-refresh glue talks to: `import.meta.hot` for 'vite'
+   * and 'esm', `module.hot` for 'standard', `import.meta.webpackHot` for
+   * 'webpack5'
+   * @default 'vite'
+   */
+  hmrBundler?: 'vite' | 'esm' | 'standard' | 'webpack5';
+
+  /**
+   * Extensions parsed like a known one, e.g. `{ solid: 'tsx' }`; the source
+   * type otherwise follows the extension of `filename`, and unknown
+   * extensions are parsed as TSX
+   */
+  customExtensions?: Record<string, 'tsx' | 'jsx' | 'ts' | 'mts' | 'cts' | 'js' | 'mjs' | 'cjs'>;
+}
+
+export interface TransformResult {
+  /** The transformed code */
+  code: string;
+  /** Source map as a JSON string, when `sourceMap` is enabled */
+  map?: string;
+  /** Events the output passes to `delegateEvents` (empty for SSR) */
+  delegatedEvents: string[];
+  /** HTML of each hoisted template, in declaration order (empty for SSR) */
+  templates: string[];
+  /** Runtime helpers the output imports from `moduleName`, in order of first use */
+  helpers: string[];
+}
+
+/**
+ * Transform JSX source code on the calling thread. Throws when an option is
+ * invalid, such as an unknown `generate`, `hmrBundler` or `customExtensions`
+ * target.
+ */
+export function transformSync(source: string, options?: TransformOptions | null): TransformResult;
+
+/**
+ * Transform JSX source code on a worker thread, rejecting when an option is
+ * invalid
+ */
+export function transform(source: string, options?: TransformOptions | null): Promise<TransformResult>;
+
+/**
+ * Compiles files one after the other with the same options, as a dev server
+ * does. The options are read once, and the memory of each file is reused
+ * for the next.
+ */
+export class TransformSession {
+  /** Throws when an option is invalid */
+  constructor(options?: TransformOptions | null);
+  /**
+   * Transform `source`, the contents of `filename` (the `filename` option
+   * when omitted)
+   */
+  transform(source: string, filename?: string | null): TransformResult;
+}
+
+/**
+ * Outputs of compiled modules by their source, filename and options, so a
+ * dev server does not compile an unchanged module again. With a directory,
+ * every output is also written there for the next process.
+ */
+export class TransformCache {
+  /**
+   * Keep the `capacity` (default 4096) most recently used outputs in memory,
+   * and every output in `dir` when given. Throws when `dir` cannot be created.
+   */
+  constructor(dir?: string | null, capacity?: number | null);
+  /**
+   * `transform`, resolving to the cached output when the source, `filename`
+   * and options are unchanged
+   */
+  transform(source: string, options?: TransformOptions | null): Promise<TransformResult>;
+  /**
+   * Remove the least recently used outputs from the directory until the rest
+   * take at most `maxBytes`; returns how many were removed
+   */
+  prune(maxBytes: number): number;
+}
+
+/**
+ * Events that are delegated to the document by default.
+ */
+export function getDelegatedEvents(): string[];
+
+/**
+ * Attributes treated as booleans (present = true).
+ */
+export function getBooleanAttributes(): string[];
+
+/**
+ * JSX attribute aliases, mapping the JSX name to the DOM attribute name.
+ */
+export function getAliases(): Record<string, string>;
+
+/**
+ * The JSX import source of a file: from a `@jsxImportSource` comment in
+ * `source`, then from `jsxImportSource` (or a React `jsx` mode, as 'react')
+ * in the nearest tsconfig.json and the configs it extends. Null when neither
+ * says.
+ */
+export function jsxImportSource(filename: string, source?: string | null): string | null;
+
+/**
+ * Whether `importSource` is Solid's: 'solid-js', or the package of
+ * `moduleName` (default 'solid-js/web') for custom renderers.
+ */
+export function isSolidImportSource(importSource: string, moduleName?: string | null): boolean;
+
+/**
+ * `jsxImportSource` for dev servers: the configs of each directory are read
+ * once, until `invalidate` is called with a file the watcher reports.
+ */
+export class JsxImportSources {
+  constructor();
+  /** The JSX import source of `filename`, as `jsxImportSource` finds it */
+  get(filename: string, source?: string | null): string | null;
+  /**
+   * Forget what was read when `path`, a changed, added or removed file, is a
+   * tsconfig.json or a config one extends; true when it was
+   */
+  invalidate(path: string): boolean;
+}
+
+declare const _default: {
+  transformSync: typeof transformSync;
+  transform: typeof transform;
+  TransformSession: typeof TransformSession;
+  TransformCache: typeof TransformCache;
+  getDelegatedEvents: typeof getDelegatedEvents;
+  getBooleanAttributes: typeof getBooleanAttributes;
+  getAliases: typeof getAliases;
+  jsxImportSource: typeof jsxImportSource;
+  isSolidImportSource: typeof isSolidImportSource;
+  JsxImportSources: typeof JsxImportSources;
+};
+
+export default _default;
@@ -0,0 +1,96 @@
+/**
+ * @solid-jsx-oxc/core - native addon for the OXC-based SolidJS JSX compiler
+ *
+ * Loads the platform binary and re-exports its functions; build tools call
+ * the Rust transform directly instead of shelling out.
+ */
+
+import { createRequire } from 'node:module';
+import { platform, arch } from 'node:process';
+import { fileURLToPath } from 'node:url';
+import { dirname, join } from 'node:path';
+
+const require = createRequire(import.meta.url);
+const __dirname = dirname(fileURLToPath(import.meta.url));
+
+// Map Node.js platform/arch to binary file suffix
+const platformMap = {
+  'darwin-arm64': 'darwin-arm64',
+  'darwin-x64': 'darwin-x64',
+  'linux-x64': 'linux-x64-gnu',
+  'linux-arm64': 'linux-arm64-gnu',
+  'win32-x64': 'win32-x64-msvc',
+  'win32-arm64': 'win32-arm64-msvc',
+};
+
+const platformKey = `${platform}-${arch}`;
+const nativeTarget = platformMap[platformKey];
+
+if (!nativeTarget) {
+  throw new Error(`@solid-jsx-oxc/core: unsupported platform ${platformKey}`);
+}
+
+const binding = require(join(__dirname, `core.${nativeTarget}.node`));
+
+/**
+ * Transform JSX source code on the calling thread
+ * @param {string} source
+ * @param {import('./index.d.ts').TransformOptions} [options]
+ * @returns {import('./index.d.ts').TransformResult}
+ */
+export const transformSync = binding.transformSync;
+
+/**
+ * Transform JSX source code on a worker thread
+ * @param {string} source
+ * @param {import('./index.d.ts').TransformOptions} [options]
+ * @returns {Promise<import('./index.d.ts').TransformResult>}
+ */
+export const transform = binding.transform;
+
+/**
+ * Compiles files one after the other with the same options, reusing memory
+ * between them
+ * @type {typeof import('./index.d.ts').TransformSession}
+ */
+export const TransformSession = binding.TransformSession;
+
+/**
+ * Outputs of compiled modules, in memory and optionally in a directory, so
+ * unchanged modules are not compiled again
+ * @type {typeof import('./index.d.ts').TransformCache}
+ */
+export const TransformCache = binding.TransformCache;
+
+/**
+ * Compiler metadata, so tooling doesn't need to hardcode copies
+ */
+export const getDelegatedEvents = binding.getDelegatedEvents;
+export const getBooleanAttributes = binding.getBooleanAttributes;
+export const getAliases = binding.getAliases;
+
+/**
+ * JSX import source of a file, for leaving other frameworks' JSX alone
+ */
+export const jsxImportSource = binding.jsxImportSource;
+export const isSolidImportSource = binding.isSolidImportSource;
+
+/**
+ * `jsxImportSource` for dev servers, reading the configs of each directory
+ * once until the watcher reports a change to one
+ * @type {typeof import('./index.d.ts').JsxImportSources}
+ */
+export const JsxImportSources = binding.JsxImportSources;
+
+export default {
+  transformSync,
+  transform,
+  TransformSession,
+  TransformCache,
+  getDelegatedEvents,
+  getBooleanAttributes,
+  getAliases,
+  jsxImportSource,
+  isSolidImportSource,
+  JsxImportSources,
+};
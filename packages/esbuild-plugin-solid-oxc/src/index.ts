@@ -0,0 +1,137 @@
+/**
+ * esbuild plugin for SolidJS using OXC-based compiler
+ *
+ * JSX files are compiled by the native addon in an `onLoad` hook, on the
+ * libuv thread pool, and handed back to esbuild as TypeScript or JavaScript
+ * without JSX; esbuild then strips the types and bundles as usual. The
+ * compiler's source map is inlined into the contents, and esbuild chains it
+ * into its own, so the bundle's source map points into the original files.
+ */
+
+import { readFile } from 'node:fs/promises';
+import { extname } from 'node:path';
+import type { Loader, OnLoadResult, Plugin } from 'esbuild';
+import type { TransformOptions } from '@solid-jsx-oxc/core';
+
+export interface SolidOxcOptions {
+  /**
+   * Filter which files to transform; esbuild matches it in Go, so only
+   * syntax Go's regexp supports works
+   * @default /\.[mc]?[jt]sx$/
+   */
+  include?: RegExp;
+
+  /**
+   * Filter which files to exclude
+   * @default /node_modules/
+   */
+  exclude?: RegExp;
+
+  /**
+   * The module to import runtime helpers from
+   * @default 'solid-js/web'
+   */
+  moduleName?: string;
+
+  /**
+   * Generate mode; use 'ssr' for server bundles
+   * @default 'dom'
+   */
+  generate?: 'dom' | 'ssr' | 'universal';
+
+  /**
+   * Enable hydration support
+   * @default false
+   */
+  hydratable?: boolean;
+
+  /**
+   * Delegate events for better performance
+   * @default true
+   */
+  delegateEvents?: boolean;
+
+  /**
+   * Wrap conditionals in memos
+   * @default true
+   */
+  wrapConditionals?: boolean;
+
+  /**
+   * Pass context to custom elements
+   * @default true
+   */
+  contextToCustomElements?: boolean;
+
+  /**
+   * Chain the compiler's source map into esbuild's
+   * @default true when the build's `sourcemap` option is set
+   */
+  sourceMap?: boolean;
+}
+
+const TYPESCRIPT_EXTENSIONS = new Set(['.ts', '.tsx', '.mts', '.cts']);
+
+function inlineSourceMap(code: string, map: string): string {
+  const encoded = Buffer.from(map).toString('base64');
+  return `${code}\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,${encoded}\n`;
+}
+
+/**
+ * The esbuild loader for the compiled file: its JSX is gone, its types are not
+ */
+export function loaderFor(path: string): Loader {
+  return TYPESCRIPT_EXTENSIONS.has(extname(path)) ? 'ts' : 'js';
+}
+
+/**
+ * esbuild plugin for SolidJS using OXC-based compiler
+ */
+export default function solidOxc(options: SolidOxcOptions = {}): Plugin {
+  const include = options.include ?? /\.[mc]?[jt]sx$/;
+  const exclude = options.exclude ?? /node_modules/;
+
+  return {
+    name: 'esbuild-plugin-solid-oxc',
+
+    setup(build) {
+      const sourceMap = options.sourceMap ?? !!build.initialOptions.sourcemap;
+      let core: Promise<typeof import('@solid-jsx-oxc/core')> | null = null;
+
+      build.onLoad({ filter: include, namespace: 'file' }, async (args): Promise<OnLoadResult | undefined> => {
+        if (exclude.test(args.path)) {
+          return undefined;
+        }
+
+        const compileOptions: TransformOptions = {
+          filename: args.path,
+          moduleName: options.moduleName,
+          generate: options.generate,
+          hydratable: options.hydratable,
+          delegateEvents: options.delegateEvents,
+          wrapConditionals: options.wrapConditionals,
+          contextToCustomElements: options.contextToCustomElements,
+          sourceMap,
+        };
+
+        try {
+          core ??= import('@solid-jsx-oxc/core');
+          const [{ transform }, source] = await Promise.all([core, readFile(args.path, 'utf8')]);
+          const result = await transform(source, compileOptions);
+          return {
+            contents: result.map ? inlineSourceMap(result.code, result.map) : result.code,
+            loader: loaderFor(args.path),
+          };
+        } catch (e: unknown) {
+          const message = e instanceof Error ? e.message : String(e);
+          return {
+            errors: [{ text: `Failed to transform ${args.path}: ${message}` }],
+          };
+        }
+      });
+    },
+  };
+}
+
+// Named export for compatibility
+export { solidOxc };
@@ -0,0 +1,139 @@
+/**
+ * Jest transformer for SolidJS using OXC-based compiler
+ *
+ * JSX is compiled for the DOM by the native addon; esbuild then strips
+ * TypeScript and, unless Jest runs test files as ES modules, converts the
+ * module to CommonJS. The source maps of both steps are merged and inlined,
+ * so stack traces and coverage point into the original file.
+ */
+
+import { createHash } from 'node:crypto';
+import { extname } from 'node:path';
+import remapping from '@ampproject/remapping';
+import { transformSync } from '@solid-jsx-oxc/core';
+import { transformSync as esbuildTransformSync } from 'esbuild';
+
+export interface SolidJestOptions {
+  /**
+   * The module to import runtime helpers from
+   * @default 'solid-js/web'
+   */
+  moduleName?: string;
+
+  /**
+   * Enable hydration support
+   * @default false
+   */
+  hydratable?: boolean;
+
+  /**
+   * Delegate events for better performance
+   * @default true
+   */
+  delegateEvents?: boolean;
+
+  /**
+   * Wrap conditionals in memos
+   * @default true
+   */
+  wrapConditionals?: boolean;
+
+  /**
+   * Pass context to custom elements
+   * @default true
+   */
+  contextToCustomElements?: boolean;
+}
+
+/**
+ * The part of Jest's transform options this transformer uses
+ */
+export interface JestTransformOptions {
+  configString: string;
+  supportsStaticESM?: boolean;
+  transformerConfig?: SolidJestOptions;
+}
+
+export interface JestTransformResult {
+  code: string;
+  map: string | null;
+}
+
+// Part of every cache key; bump when the output changes for the same input
+const CACHE_VERSION = '1';
+
+const TYPESCRIPT_EXTENSIONS = new Set(['.ts', '.tsx', '.mts', '.cts']);
+
+function inlineSourceMap(code: string, map: string): string {
+  const encoded = Buffer.from(map).toString('base64');
+  return `${code}\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,${encoded}\n`;
+}
+
+/**
+ * Compile `sourceText` for Jest
+ */
+export function process(
+  sourceText: string,
+  sourcePath: string,
+  options: JestTransformOptions
+): JestTransformResult {
+  const config = options.transformerConfig ?? {};
+  const solid = transformSync(sourceText, {
+    filename: sourcePath,
+    generate: 'dom',
+    moduleName: config.moduleName,
+    hydratable: config.hydratable,
+    delegateEvents: config.delegateEvents,
+    wrapConditionals: config.wrapConditionals,
+    contextToCustomElements: config.contextToCustomElements,
+    sourceMap: true,
+  });
+
+  const output = esbuildTransformSync(solid.code, {
+    loader: TYPESCRIPT_EXTENSIONS.has(extname(sourcePath)) ? 'ts' : 'js',
+    format: options.supportsStaticESM ? 'esm' : 'cjs',
+    sourcefile: sourcePath,
+    sourcemap: 'external',
+    target: `node${globalThis.process.versions.node}`,
+  });
+
+  const maps = solid.map ? [output.map, solid.map] : [output.map];
+  const map = remapping(maps, () => null).toString();
+  return { code: inlineSourceMap(output.code, map), map };
+}
+
+/**
+ * Same as `process`; Jest calls this for ES module test runs
+ */
+export async function processAsync(
+  sourceText: string,
+  sourcePath: string,
+  options: JestTransformOptions
+): Promise<JestTransformResult> {
+  return process(sourceText, sourcePath, options);
+}
+
+/**
+ * Key of Jest's transform cache: changes with the file, the Jest config and
+ * the transformer options
+ */
+export function getCacheKey(
+  sourceText: string,
+  sourcePath: string,
+  options: JestTransformOptions
+): string {
+  return createHash('sha256')
+    .update(CACHE_VERSION)
+    .update('\0')
+    .update(sourceText)
+    .update('\0')
+    .update(sourcePath)
+    .update('\0')
+    .update(options.configString)
+    .update('\0')
+    .update(JSON.stringify(options.transformerConfig ?? {}))
+    .update(options.supportsStaticESM ? '\0esm' : '\0cjs')
+    .digest('hex');
+}
+
+export default { process, processAsync, getCacheKey };
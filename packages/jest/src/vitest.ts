@@ -0,0 +1,109 @@
+/**
+ * Vitest plugin for SolidJS using OXC-based compiler
+ *
+ * Compiles JSX for the DOM before Vite strips TypeScript, and sets up the
+ * test environment the way Solid tests expect: jsdom, Solid's browser and
+ * development builds, and solid-js inlined so test files and the library
+ * share one copy of the runtime.
+ */
+
+import type { Plugin, UserConfig } from 'vite';
+import type { SolidJestOptions } from './index.js';
+
+export interface SolidVitestOptions extends SolidJestOptions {
+  /**
+   * Filter which files to transform
+   * @default /\.[mc]?[jt]sx$/
+   */
+  include?: RegExp;
+
+  /**
+   * Filter which files to exclude
+   * @default /node_modules/
+   */
+  exclude?: RegExp;
+}
+
+interface TestConfig {
+  environment?: string;
+  server?: { deps?: { inline?: (string | RegExp)[] | true } };
+}
+
+/**
+ * Vitest plugin for SolidJS using OXC-based compiler
+ */
+export default function solidVitest(options: SolidVitestOptions = {}): Plugin {
+  const include = options.include ?? /\.[mc]?[jt]sx$/;
+  const exclude = options.exclude ?? /node_modules/;
+
+  // Lazy load the native module
+  let core: typeof import('@solid-jsx-oxc/core') | null = null;
+
+  return {
+    name: 'solid-jsx-oxc:vitest',
+    enforce: 'pre',
+
+    config(userConfig) {
+      const test = (userConfig as UserConfig & { test?: TestConfig }).test ?? {};
+      const inline = test.server?.deps?.inline;
+      return {
+        resolve: {
+          conditions: ['module', 'browser', 'development'],
+        },
+        test: {
+          environment: test.environment ?? 'jsdom',
+          server: {
+            deps: {
+              inline: inline === true ? true : [...(inline ?? []), /solid-js/],
+            },
+          },
+        },
+      } as UserConfig;
+    },
+
+    async buildStart() {
+      try {
+        core = await import('@solid-jsx-oxc/core');
+      } catch (e) {
+        this.error(
+          'Failed to load @solid-jsx-oxc/core. Make sure it is built for your platform.'
+        );
+      }
+    },
+
+    async transform(code, id) {
+      const fileId = id.split('?', 1)[0];
+      if (!include.test(fileId) || exclude.test(fileId)) {
+        return null;
+      }
+
+      if (!core) {
+        this.error('@solid-jsx-oxc/core module not loaded');
+        return null;
+      }
+
+      try {
+        const result = await core.transform(code, {
+          filename: fileId,
+          generate: 'dom',
+          moduleName: options.moduleName,
+          hydratable: options.hydratable,
+          delegateEvents: options.delegateEvents,
+          wrapConditionals: options.wrapConditionals,
+          contextToCustomElements: options.contextToCustomElements,
+          sourceMap: true,
+        });
+        return {
+          code: result.code,
+          map: result.map ? JSON.parse(result.map) : null,
+        };
+      } catch (e: unknown) {
+        const message = e instanceof Error ? e.message : String(e);
+        this.error(`Failed to transform ${id}: ${message}`);
+        return null;
+      }
+    },
+  };
+}
+
+export { solidVitest };
@@ -0,0 +1,229 @@
+// One element with 200 static and dynamic attributes
+export const Attributes = (props) => {
+  return (
+    <div
+      data-static-0="0"
+      data-dynamic-0={props.values[0]}
+      data-static-1="1"
+      data-dynamic-1={props.values[1]}
+      data-static-2="2"
+      data-dynamic-2={props.values[2]}
+      data-static-3="3"
+      data-dynamic-3={props.values[3]}
+      data-static-4="4"
+      data-dynamic-4={props.values[4]}
+      data-static-5="5"
+      data-dynamic-5={props.values[5]}
+      data-static-6="6"
+      data-dynamic-6={props.values[6]}
+      data-static-7="7"
+      data-dynamic-7={props.values[7]}
+      data-static-8="8"
+      data-dynamic-8={props.values[8]}
+      data-static-9="9"
+      data-dynamic-9={props.values[9]}
+      data-static-10="10"
+      data-dynamic-10={props.values[10]}
+      data-static-11="11"
+      data-dynamic-11={props.values[11]}
+      data-static-12="12"
+      data-dynamic-12={props.values[12]}
+      data-static-13="13"
+      data-dynamic-13={props.values[13]}
+      data-static-14="14"
+      data-dynamic-14={props.values[14]}
+      data-static-15="15"
+      data-dynamic-15={props.values[15]}
+      data-static-16="16"
+      data-dynamic-16={props.values[16]}
+      data-static-17="17"
+      data-dynamic-17={props.values[17]}
+      data-static-18="18"
+      data-dynamic-18={props.values[18]}
+      data-static-19="19"
+      data-dynamic-19={props.values[19]}
+      data-static-20="20"
+      data-dynamic-20={props.values[20]}
+      data-static-21="21"
+      data-dynamic-21={props.values[21]}
+      data-static-22="22"
+      data-dynamic-22={props.values[22]}
+      data-static-23="23"
+      data-dynamic-23={props.values[23]}
+      data-static-24="24"
+      data-dynamic-24={props.values[24]}
+      data-static-25="25"
+      data-dynamic-25={props.values[25]}
+      data-static-26="26"
+      data-dynamic-26={props.values[26]}
+      data-static-27="27"
+      data-dynamic-27={props.values[27]}
+      data-static-28="28"
+      data-dynamic-28={props.values[28]}
+      data-static-29="29"
+      data-dynamic-29={props.values[29]}
+      data-static-30="30"
+      data-dynamic-30={props.values[30]}
+      data-static-31="31"
+      data-dynamic-31={props.values[31]}
+      data-static-32="32"
+      data-dynamic-32={props.values[32]}
+      data-static-33="33"
+      data-dynamic-33={props.values[33]}
+      data-static-34="34"
+      data-dynamic-34={props.values[34]}
+      data-static-35="35"
+      data-dynamic-35={props.values[35]}
+      data-static-36="36"
+      data-dynamic-36={props.values[36]}
+      data-static-37="37"
+      data-dynamic-37={props.values[37]}
+      data-static-38="38"
+      data-dynamic-38={props.values[38]}
+      data-static-39="39"
+      data-dynamic-39={props.values[39]}
+      data-static-40="40"
+      data-dynamic-40={props.values[40]}
+      data-static-41="41"
+      data-dynamic-41={props.values[41]}
+      data-static-42="42"
+      data-dynamic-42={props.values[42]}
+      data-static-43="43"
+      data-dynamic-43={props.values[43]}
+      data-static-44="44"
+      data-dynamic-44={props.values[44]}
+      data-static-45="45"
+      data-dynamic-45={props.values[45]}
+      data-static-46="46"
+      data-dynamic-46={props.values[46]}
+      data-static-47="47"
+      data-dynamic-47={props.values[47]}
+      data-static-48="48"
+      data-dynamic-48={props.values[48]}
+      data-static-49="49"
+      data-dynamic-49={props.values[49]}
+      data-static-50="50"
+      data-dynamic-50={props.values[50]}
+      data-static-51="51"
+      data-dynamic-51={props.values[51]}
+      data-static-52="52"
+      data-dynamic-52={props.values[52]}
+      data-static-53="53"
+      data-dynamic-53={props.values[53]}
+      data-static-54="54"
+      data-dynamic-54={props.values[54]}
+      data-static-55="55"
+      data-dynamic-55={props.values[55]}
+      data-static-56="56"
+      data-dynamic-56={props.values[56]}
+      data-static-57="57"
+      data-dynamic-57={props.values[57]}
+      data-static-58="58"
+      data-dynamic-58={props.values[58]}
+      data-static-59="59"
+      data-dynamic-59={props.values[59]}
+      data-static-60="60"
+      data-dynamic-60={props.values[60]}
+      data-static-61="61"
+      data-dynamic-61={props.values[61]}
+      data-static-62="62"
+      data-dynamic-62={props.values[62]}
+      data-static-63="63"
+      data-dynamic-63={props.values[63]}
+      data-static-64="64"
+      data-dynamic-64={props.values[64]}
+      data-static-65="65"
+      data-dynamic-65={props.values[65]}
+      data-static-66="66"
+      data-dynamic-66={props.values[66]}
+      data-static-67="67"
+      data-dynamic-67={props.values[67]}
+      data-static-68="68"
+      data-dynamic-68={props.values[68]}
+      data-static-69="69"
+      data-dynamic-69={props.values[69]}
+      data-static-70="70"
+      data-dynamic-70={props.values[70]}
+      data-static-71="71"
+      data-dynamic-71={props.values[71]}
+      data-static-72="72"
+      data-dynamic-72={props.values[72]}
+      data-static-73="73"
+      data-dynamic-73={props.values[73]}
+      data-static-74="74"
+      data-dynamic-74={props.values[74]}
+      data-static-75="75"
+      data-dynamic-75={props.values[75]}
+      data-static-76="76"
+      data-dynamic-76={props.values[76]}
+      data-static-77="77"
+      data-dynamic-77={props.values[77]}
+      data-static-78="78"
+      data-dynamic-78={props.values[78]}
+      data-static-79="79"
+      data-dynamic-79={props.values[79]}
+      data-static-80="80"
+      data-dynamic-80={props.values[80]}
+      data-static-81="81"
+      data-dynamic-81={props.values[81]}
+      data-static-82="82"
+      data-dynamic-82={props.values[82]}
+      data-static-83="83"
+      data-dynamic-83={props.values[83]}
+      data-static-84="84"
+      data-dynamic-84={props.values[84]}
+      data-static-85="85"
+      data-dynamic-85={props.values[85]}
+      data-static-86="86"
+      data-dynamic-86={props.values[86]}
+      data-static-87="87"
+      data-dynamic-87={props.values[87]}
+      data-static-88="88"
+      data-dynamic-88={props.values[88]}
+      data-static-89="89"
+      data-dynamic-89={props.values[89]}
+      data-static-90="90"
+      data-dynamic-90={props.values[90]}
+      data-static-91="91"
+      data-dynamic-91={props.values[91]}
+      data-static-92="92"
+      data-dynamic-92={props.values[92]}
+      data-static-93="93"
+      data-dynamic-93={props.values[93]}
+      data-static-94="94"
+      data-dynamic-94={props.values[94]}
+      data-static-95="95"
+      data-dynamic-95={props.values[95]}
+      data-static-96="96"
+      data-dynamic-96={props.values[96]}
+      data-static-97="97"
+      data-dynamic-97={props.values[97]}
+      data-static-98="98"
+      data-dynamic-98={props.values[98]}
+      data-static-99="99"
+      data-dynamic-99={props.values[99]}
+      on:custom0={props.handlers[0]}
+      on:custom1={props.handlers[1]}
+      on:custom2={props.handlers[2]}
+      on:custom3={props.handlers[3]}
+      on:custom4={props.handlers[4]}
+      on:custom5={props.handlers[5]}
+      on:custom6={props.handlers[6]}
+      on:custom7={props.handlers[7]}
+      on:custom8={props.handlers[8]}
+      on:custom9={props.handlers[9]}
+      on:custom10={props.handlers[10]}
+      on:custom11={props.handlers[11]}
+      on:custom12={props.handlers[12]}
+      on:custom13={props.handlers[13]}
+      on:custom14={props.handlers[14]}
+      on:custom15={props.handlers[15]}
+      on:custom16={props.handlers[16]}
+      on:custom17={props.handlers[17]}
+      on:custom18={props.handlers[18]}
+      on:custom19={props.handlers[19]}
+      style={{ color: props.color, "font-size": props.size }}
+      classList={{ active: props.active, disabled: props.disabled }}
+    />
+  );
+};
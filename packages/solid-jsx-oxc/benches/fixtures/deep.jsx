@@ -0,0 +1,518 @@
+// 256 nested elements with a dynamic leaf
+export const Deep = (props) => {
+  return (
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <div class="level">
+    <span>{props.value}</span>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+    </div>
+  );
+};
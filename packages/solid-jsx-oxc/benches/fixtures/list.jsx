@@ -0,0 +1,509 @@
+// 500 hand-written rows, as generated tables and docs produce
+export const List = (props) => {
+  return (
+    <table>
+      <tbody>
+        <tr onClick={() => props.select(0)}><td>0</td><td>{props.rows[0].name}</td></tr>
+        <tr onClick={() => props.select(1)}><td>1</td><td>{props.rows[1].name}</td></tr>
+        <tr onClick={() => props.select(2)}><td>2</td><td>{props.rows[2].name}</td></tr>
+        <tr onClick={() => props.select(3)}><td>3</td><td>{props.rows[3].name}</td></tr>
+        <tr onClick={() => props.select(4)}><td>4</td><td>{props.rows[4].name}</td></tr>
+        <tr onClick={() => props.select(5)}><td>5</td><td>{props.rows[5].name}</td></tr>
+        <tr onClick={() => props.select(6)}><td>6</td><td>{props.rows[6].name}</td></tr>
+        <tr onClick={() => props.select(7)}><td>7</td><td>{props.rows[7].name}</td></tr>
+        <tr onClick={() => props.select(8)}><td>8</td><td>{props.rows[8].name}</td></tr>
+        <tr onClick={() => props.select(9)}><td>9</td><td>{props.rows[9].name}</td></tr>
+        <tr onClick={() => props.select(10)}><td>10</td><td>{props.rows[10].name}</td></tr>
+        <tr onClick={() => props.select(11)}><td>11</td><td>{props.rows[11].name}</td></tr>
+        <tr onClick={() => props.select(12)}><td>12</td><td>{props.rows[12].name}</td></tr>
+        <tr onClick={() => props.select(13)}><td>13</td><td>{props.rows[13].name}</td></tr>
+        <tr onClick={() => props.select(14)}><td>14</td><td>{props.rows[14].name}</td></tr>
+        <tr onClick={() => props.select(15)}><td>15</td><td>{props.rows[15].name}</td></tr>
+        <tr onClick={() => props.select(16)}><td>16</td><td>{props.rows[16].name}</td></tr>
+        <tr onClick={() => props.select(17)}><td>17</td><td>{props.rows[17].name}</td></tr>
+        <tr onClick={() => props.select(18)}><td>18</td><td>{props.rows[18].name}</td></tr>
+        <tr onClick={() => props.select(19)}><td>19</td><td>{props.rows[19].name}</td></tr>
+        <tr onClick={() => props.select(20)}><td>20</td><td>{props.rows[20].name}</td></tr>
+        <tr onClick={() => props.select(21)}><td>21</td><td>{props.rows[21].name}</td></tr>
+        <tr onClick={() => props.select(22)}><td>22</td><td>{props.rows[22].name}</td></tr>
+        <tr onClick={() => props.select(23)}><td>23</td><td>{props.rows[23].name}</td></tr>
+        <tr onClick={() => props.select(24)}><td>24</td><td>{props.rows[24].name}</td></tr>
+        <tr onClick={() => props.select(25)}><td>25</td><td>{props.rows[25].name}</td></tr>
+        <tr onClick={() => props.select(26)}><td>26</td><td>{props.rows[26].name}</td></tr>
+        <tr onClick={() => props.select(27)}><td>27</td><td>{props.rows[27].name}</td></tr>
+        <tr onClick={() => props.select(28)}><td>28</td><td>{props.rows[28].name}</td></tr>
+        <tr onClick={() => props.select(29)}><td>29</td><td>{props.rows[29].name}</td></tr>
+        <tr onClick={() => props.select(30)}><td>30</td><td>{props.rows[30].name}</td></tr>
+        <tr onClick={() => props.select(31)}><td>31</td><td>{props.rows[31].name}</td></tr>
+        <tr onClick={() => props.select(32)}><td>32</td><td>{props.rows[32].name}</td></tr>
+        <tr onClick={() => props.select(33)}><td>33</td><td>{props.rows[33].name}</td></tr>
+        <tr onClick={() => props.select(34)}><td>34</td><td>{props.rows[34].name}</td></tr>
+        <tr onClick={() => props.select(35)}><td>35</td><td>{props.rows[35].name}</td></tr>
+        <tr onClick={() => props.select(36)}><td>36</td><td>{props.rows[36].name}</td></tr>
+        <tr onClick={() => props.select(37)}><td>37</td><td>{props.rows[37].name}</td></tr>
+        <tr onClick={() => props.select(38)}><td>38</td><td>{props.rows[38].name}</td></tr>
+        <tr onClick={() => props.select(39)}><td>39</td><td>{props.rows[39].name}</td></tr>
+        <tr onClick={() => props.select(40)}><td>40</td><td>{props.rows[40].name}</td></tr>
+        <tr onClick={() => props.select(41)}><td>41</td><td>{props.rows[41].name}</td></tr>
+        <tr onClick={() => props.select(42)}><td>42</td><td>{props.rows[42].name}</td></tr>
+        <tr onClick={() => props.select(43)}><td>43</td><td>{props.rows[43].name}</td></tr>
+        <tr onClick={() => props.select(44)}><td>44</td><td>{props.rows[44].name}</td></tr>
+        <tr onClick={() => props.select(45)}><td>45</td><td>{props.rows[45].name}</td></tr>
+        <tr onClick={() => props.select(46)}><td>46</td><td>{props.rows[46].name}</td></tr>
+        <tr onClick={() => props.select(47)}><td>47</td><td>{props.rows[47].name}</td></tr>
+        <tr onClick={() => props.select(48)}><td>48</td><td>{props.rows[48].name}</td></tr>
+        <tr onClick={() => props.select(49)}><td>49</td><td>{props.rows[49].name}</td></tr>
+        <tr onClick={() => props.select(50)}><td>50</td><td>{props.rows[50].name}</td></tr>
+        <tr onClick={() => props.select(51)}><td>51</td><td>{props.rows[51].name}</td></tr>
+        <tr onClick={() => props.select(52)}><td>52</td><td>{props.rows[52].name}</td></tr>
+        <tr onClick={() => props.select(53)}><td>53</td><td>{props.rows[53].name}</td></tr>
+        <tr onClick={() => props.select(54)}><td>54</td><td>{props.rows[54].name}</td></tr>
+        <tr onClick={() => props.select(55)}><td>55</td><td>{props.rows[55].name}</td></tr>
+        <tr onClick={() => props.select(56)}><td>56</td><td>{props.rows[56].name}</td></tr>
+        <tr onClick={() => props.select(57)}><td>57</td><td>{props.rows[57].name}</td></tr>
+        <tr onClick={() => props.select(58)}><td>58</td><td>{props.rows[58].name}</td></tr>
+        <tr onClick={() => props.select(59)}><td>59</td><td>{props.rows[59].name}</td></tr>
+        <tr onClick={() => props.select(60)}><td>60</td><td>{props.rows[60].name}</td></tr>
+        <tr onClick={() => props.select(61)}><td>61</td><td>{props.rows[61].name}</td></tr>
+        <tr onClick={() => props.select(62)}><td>62</td><td>{props.rows[62].name}</td></tr>
+        <tr onClick={() => props.select(63)}><td>63</td><td>{props.rows[63].name}</td></tr>
+        <tr onClick={() => props.select(64)}><td>64</td><td>{props.rows[64].name}</td></tr>
+        <tr onClick={() => props.select(65)}><td>65</td><td>{props.rows[65].name}</td></tr>
+        <tr onClick={() => props.select(66)}><td>66</td><td>{props.rows[66].name}</td></tr>
+        <tr onClick={() => props.select(67)}><td>67</td><td>{props.rows[67].name}</td></tr>
+        <tr onClick={() => props.select(68)}><td>68</td><td>{props.rows[68].name}</td></tr>
+        <tr onClick={() => props.select(69)}><td>69</td><td>{props.rows[69].name}</td></tr>
+        <tr onClick={() => props.select(70)}><td>70</td><td>{props.rows[70].name}</td></tr>
+        <tr onClick={() => props.select(71)}><td>71</td><td>{props.rows[71].name}</td></tr>
+        <tr onClick={() => props.select(72)}><td>72</td><td>{props.rows[72].name}</td></tr>
+        <tr onClick={() => props.select(73)}><td>73</td><td>{props.rows[73].name}</td></tr>
+        <tr onClick={() => props.select(74)}><td>74</td><td>{props.rows[74].name}</td></tr>
+        <tr onClick={() => props.select(75)}><td>75</td><td>{props.rows[75].name}</td></tr>
+        <tr onClick={() => props.select(76)}><td>76</td><td>{props.rows[76].name}</td></tr>
+        <tr onClick={() => props.select(77)}><td>77</td><td>{props.rows[77].name}</td></tr>
+        <tr onClick={() => props.select(78)}><td>78</td><td>{props.rows[78].name}</td></tr>
+        <tr onClick={() => props.select(79)}><td>79</td><td>{props.rows[79].name}</td></tr>
+        <tr onClick={() => props.select(80)}><td>80</td><td>{props.rows[80].name}</td></tr>
+        <tr onClick={() => props.select(81)}><td>81</td><td>{props.rows[81].name}</td></tr>
+        <tr onClick={() => props.select(82)}><td>82</td><td>{props.rows[82].name}</td></tr>
+        <tr onClick={() => props.select(83)}><td>83</td><td>{props.rows[83].name}</td></tr>
+        <tr onClick={() => props.select(84)}><td>84</td><td>{props.rows[84].name}</td></tr>
+        <tr onClick={() => props.select(85)}><td>85</td><td>{props.rows[85].name}</td></tr>
+        <tr onClick={() => props.select(86)}><td>86</td><td>{props.rows[86].name}</td></tr>
+        <tr onClick={() => props.select(87)}><td>87</td><td>{props.rows[87].name}</td></tr>
+        <tr onClick={() => props.select(88)}><td>88</td><td>{props.rows[88].name}</td></tr>
+        <tr onClick={() => props.select(89)}><td>89</td><td>{props.rows[89].name}</td></tr>
+        <tr onClick={() => props.select(90)}><td>90</td><td>{props.rows[90].name}</td></tr>
+        <tr onClick={() => props.select(91)}><td>91</td><td>{props.rows[91].name}</td></tr>
+        <tr onClick={() => props.select(92)}><td>92</td><td>{props.rows[92].name}</td></tr>
+        <tr onClick={() => props.select(93)}><td>93</td><td>{props.rows[93].name}</td></tr>
+        <tr onClick={() => props.select(94)}><td>94</td><td>{props.rows[94].name}</td></tr>
+        <tr onClick={() => props.select(95)}><td>95</td><td>{props.rows[95].name}</td></tr>
+        <tr onClick={() => props.select(96)}><td>96</td><td>{props.rows[96].name}</td></tr>
+        <tr onClick={() => props.select(97)}><td>97</td><td>{props.rows[97].name}</td></tr>
+        <tr onClick={() => props.select(98)}><td>98</td><td>{props.rows[98].name}</td></tr>
+        <tr onClick={() => props.select(99)}><td>99</td><td>{props.rows[99].name}</td></tr>
+        <tr onClick={() => props.select(100)}><td>100</td><td>{props.rows[100].name}</td></tr>
+        <tr onClick={() => props.select(101)}><td>101</td><td>{props.rows[101].name}</td></tr>
+        <tr onClick={() => props.select(102)}><td>102</td><td>{props.rows[102].name}</td></tr>
+        <tr onClick={() => props.select(103)}><td>103</td><td>{props.rows[103].name}</td></tr>
+        <tr onClick={() => props.select(104)}><td>104</td><td>{props.rows[104].name}</td></tr>
+        <tr onClick={() => props.select(105)}><td>105</td><td>{props.rows[105].name}</td></tr>
+        <tr onClick={() => props.select(106)}><td>106</td><td>{props.rows[106].name}</td></tr>
+        <tr onClick={() => props.select(107)}><td>107</td><td>{props.rows[107].name}</td></tr>
+        <tr onClick={() => props.select(108)}><td>108</td><td>{props.rows[108].name}</td></tr>
+        <tr onClick={() => props.select(109)}><td>109</td><td>{props.rows[109].name}</td></tr>
+        <tr onClick={() => props.select(110)}><td>110</td><td>{props.rows[110].name}</td></tr>
+        <tr onClick={() => props.select(111)}><td>111</td><td>{props.rows[111].name}</td></tr>
+        <tr onClick={() => props.select(112)}><td>112</td><td>{props.rows[112].name}</td></tr>
+        <tr onClick={() => props.select(113)}><td>113</td><td>{props.rows[113].name}</td></tr>
+        <tr onClick={() => props.select(114)}><td>114</td><td>{props.rows[114].name}</td></tr>
+        <tr onClick={() => props.select(115)}><td>115</td><td>{props.rows[115].name}</td></tr>
+        <tr onClick={() => props.select(116)}><td>116</td><td>{props.rows[116].name}</td></tr>
+        <tr onClick={() => props.select(117)}><td>117</td><td>{props.rows[117].name}</td></tr>
+        <tr onClick={() => props.select(118)}><td>118</td><td>{props.rows[118].name}</td></tr>
+        <tr onClick={() => props.select(119)}><td>119</td><td>{props.rows[119].name}</td></tr>
+        <tr onClick={() => props.select(120)}><td>120</td><td>{props.rows[120].name}</td></tr>
+        <tr onClick={() => props.select(121)}><td>121</td><td>{props.rows[121].name}</td></tr>
+        <tr onClick={() => props.select(122)}><td>122</td><td>{props.rows[122].name}</td></tr>
+        <tr onClick={() => props.select(123)}><td>123</td><td>{props.rows[123].name}</td></tr>
+        <tr onClick={() => props.select(124)}><td>124</td><td>{props.rows[124].name}</td></tr>
+        <tr onClick={() => props.select(125)}><td>125</td><td>{props.rows[125].name}</td></tr>
+        <tr onClick={() => props.select(126)}><td>126</td><td>{props.rows[126].name}</td></tr>
+        <tr onClick={() => props.select(127)}><td>127</td><td>{props.rows[127].name}</td></tr>
+        <tr onClick={() => props.select(128)}><td>128</td><td>{props.rows[128].name}</td></tr>
+        <tr onClick={() => props.select(129)}><td>129</td><td>{props.rows[129].name}</td></tr>
+        <tr onClick={() => props.select(130)}><td>130</td><td>{props.rows[130].name}</td></tr>
+        <tr onClick={() => props.select(131)}><td>131</td><td>{props.rows[131].name}</td></tr>
+        <tr onClick={() => props.select(132)}><td>132</td><td>{props.rows[132].name}</td></tr>
+        <tr onClick={() => props.select(133)}><td>133</td><td>{props.rows[133].name}</td></tr>
+        <tr onClick={() => props.select(134)}><td>134</td><td>{props.rows[134].name}</td></tr>
+        <tr onClick={() => props.select(135)}><td>135</td><td>{props.rows[135].name}</td></tr>
+        <tr onClick={() => props.select(136)}><td>136</td><td>{props.rows[136].name}</td></tr>
+        <tr onClick={() => props.select(137)}><td>137</td><td>{props.rows[137].name}</td></tr>
+        <tr onClick={() => props.select(138)}><td>138</td><td>{props.rows[138].name}</td></tr>
+        <tr onClick={() => props.select(139)}><td>139</td><td>{props.rows[139].name}</td></tr>
+        <tr onClick={() => props.select(140)}><td>140</td><td>{props.rows[140].name}</td></tr>
+        <tr onClick={() => props.select(141)}><td>141</td><td>{props.rows[141].name}</td></tr>
+        <tr onClick={() => props.select(142)}><td>142</td><td>{props.rows[142].name}</td></tr>
+        <tr onClick={() => props.select(143)}><td>143</td><td>{props.rows[143].name}</td></tr>
+        <tr onClick={() => props.select(144)}><td>144</td><td>{props.rows[144].name}</td></tr>
+        <tr onClick={() => props.select(145)}><td>145</td><td>{props.rows[145].name}</td></tr>
+        <tr onClick={() => props.select(146)}><td>146</td><td>{props.rows[146].name}</td></tr>
+        <tr onClick={() => props.select(147)}><td>147</td><td>{props.rows[147].name}</td></tr>
+        <tr onClick={() => props.select(148)}><td>148</td><td>{props.rows[148].name}</td></tr>
+        <tr onClick={() => props.select(149)}><td>149</td><td>{props.rows[149].name}</td></tr>
+        <tr onClick={() => props.select(150)}><td>150</td><td>{props.rows[150].name}</td></tr>
+        <tr onClick={() => props.select(151)}><td>151</td><td>{props.rows[151].name}</td></tr>
+        <tr onClick={() => props.select(152)}><td>152</td><td>{props.rows[152].name}</td></tr>
+        <tr onClick={() => props.select(153)}><td>153</td><td>{props.rows[153].name}</td></tr>
+        <tr onClick={() => props.select(154)}><td>154</td><td>{props.rows[154].name}</td></tr>
+        <tr onClick={() => props.select(155)}><td>155</td><td>{props.rows[155].name}</td></tr>
+        <tr onClick={() => props.select(156)}><td>156</td><td>{props.rows[156].name}</td></tr>
+        <tr onClick={() => props.select(157)}><td>157</td><td>{props.rows[157].name}</td></tr>
+        <tr onClick={() => props.select(158)}><td>158</td><td>{props.rows[158].name}</td></tr>
+        <tr onClick={() => props.select(159)}><td>159</td><td>{props.rows[159].name}</td></tr>
+        <tr onClick={() => props.select(160)}><td>160</td><td>{props.rows[160].name}</td></tr>
+        <tr onClick={() => props.select(161)}><td>161</td><td>{props.rows[161].name}</td></tr>
+        <tr onClick={() => props.select(162)}><td>162</td><td>{props.rows[162].name}</td></tr>
+        <tr onClick={() => props.select(163)}><td>163</td><td>{props.rows[163].name}</td></tr>
+        <tr onClick={() => props.select(164)}><td>164</td><td>{props.rows[164].name}</td></tr>
+        <tr onClick={() => props.select(165)}><td>165</td><td>{props.rows[165].name}</td></tr>
+        <tr onClick={() => props.select(166)}><td>166</td><td>{props.rows[166].name}</td></tr>
+        <tr onClick={() => props.select(167)}><td>167</td><td>{props.rows[167].name}</td></tr>
+        <tr onClick={() => props.select(168)}><td>168</td><td>{props.rows[168].name}</td></tr>
+        <tr onClick={() => props.select(169)}><td>169</td><td>{props.rows[169].name}</td></tr>
+        <tr onClick={() => props.select(170)}><td>170</td><td>{props.rows[170].name}</td></tr>
+        <tr onClick={() => props.select(171)}><td>171</td><td>{props.rows[171].name}</td></tr>
+        <tr onClick={() => props.select(172)}><td>172</td><td>{props.rows[172].name}</td></tr>
+        <tr onClick={() => props.select(173)}><td>173</td><td>{props.rows[173].name}</td></tr>
+        <tr onClick={() => props.select(174)}><td>174</td><td>{props.rows[174].name}</td></tr>
+        <tr onClick={() => props.select(175)}><td>175</td><td>{props.rows[175].name}</td></tr>
+        <tr onClick={() => props.select(176)}><td>176</td><td>{props.rows[176].name}</td></tr>
+        <tr onClick={() => props.select(177)}><td>177</td><td>{props.rows[177].name}</td></tr>
+        <tr onClick={() => props.select(178)}><td>178</td><td>{props.rows[178].name}</td></tr>
+        <tr onClick={() => props.select(179)}><td>179</td><td>{props.rows[179].name}</td></tr>
+        <tr onClick={() => props.select(180)}><td>180</td><td>{props.rows[180].name}</td></tr>
+        <tr onClick={() => props.select(181)}><td>181</td><td>{props.rows[181].name}</td></tr>
+        <tr onClick={() => props.select(182)}><td>182</td><td>{props.rows[182].name}</td></tr>
+        <tr onClick={() => props.select(183)}><td>183</td><td>{props.rows[183].name}</td></tr>
+        <tr onClick={() => props.select(184)}><td>184</td><td>{props.rows[184].name}</td></tr>
+        <tr onClick={() => props.select(185)}><td>185</td><td>{props.rows[185].name}</td></tr>
+        <tr onClick={() => props.select(186)}><td>186</td><td>{props.rows[186].name}</td></tr>
+        <tr onClick={() => props.select(187)}><td>187</td><td>{props.rows[187].name}</td></tr>
+        <tr onClick={() => props.select(188)}><td>188</td><td>{props.rows[188].name}</td></tr>
+        <tr onClick={() => props.select(189)}><td>189</td><td>{props.rows[189].name}</td></tr>
+        <tr onClick={() => props.select(190)}><td>190</td><td>{props.rows[190].name}</td></tr>
+        <tr onClick={() => props.select(191)}><td>191</td><td>{props.rows[191].name}</td></tr>
+        <tr onClick={() => props.select(192)}><td>192</td><td>{props.rows[192].name}</td></tr>
+        <tr onClick={() => props.select(193)}><td>193</td><td>{props.rows[193].name}</td></tr>
+        <tr onClick={() => props.select(194)}><td>194</td><td>{props.rows[194].name}</td></tr>
+        <tr onClick={() => props.select(195)}><td>195</td><td>{props.rows[195].name}</td></tr>
+        <tr onClick={() => props.select(196)}><td>196</td><td>{props.rows[196].name}</td></tr>
+        <tr onClick={() => props.select(197)}><td>197</td><td>{props.rows[197].name}</td></tr>
+        <tr onClick={() => props.select(198)}><td>198</td><td>{props.rows[198].name}</td></tr>
+        <tr onClick={() => props.select(199)}><td>199</td><td>{props.rows[199].name}</td></tr>
+        <tr onClick={() => props.select(200)}><td>200</td><td>{props.rows[200].name}</td></tr>
+        <tr onClick={() => props.select(201)}><td>201</td><td>{props.rows[201].name}</td></tr>
+        <tr onClick={() => props.select(202)}><td>202</td><td>{props.rows[202].name}</td></tr>
+        <tr onClick={() => props.select(203)}><td>203</td><td>{props.rows[203].name}</td></tr>
+        <tr onClick={() => props.select(204)}><td>204</td><td>{props.rows[204].name}</td></tr>
+        <tr onClick={() => props.select(205)}><td>205</td><td>{props.rows[205].name}</td></tr>
+        <tr onClick={() => props.select(206)}><td>206</td><td>{props.rows[206].name}</td></tr>
+        <tr onClick={() => props.select(207)}><td>207</td><td>{props.rows[207].name}</td></tr>
+        <tr onClick={() => props.select(208)}><td>208</td><td>{props.rows[208].name}</td></tr>
+        <tr onClick={() => props.select(209)}><td>209</td><td>{props.rows[209].name}</td></tr>
+        <tr onClick={() => props.select(210)}><td>210</td><td>{props.rows[210].name}</td></tr>
+        <tr onClick={() => props.select(211)}><td>211</td><td>{props.rows[211].name}</td></tr>
+        <tr onClick={() => props.select(212)}><td>212</td><td>{props.rows[212].name}</td></tr>
+        <tr onClick={() => props.select(213)}><td>213</td><td>{props.rows[213].name}</td></tr>
+        <tr onClick={() => props.select(214)}><td>214</td><td>{props.rows[214].name}</td></tr>
+        <tr onClick={() => props.select(215)}><td>215</td><td>{props.rows[215].name}</td></tr>
+        <tr onClick={() => props.select(216)}><td>216</td><td>{props.rows[216].name}</td></tr>
+        <tr onClick={() => props.select(217)}><td>217</td><td>{props.rows[217].name}</td></tr>
+        <tr onClick={() => props.select(218)}><td>218</td><td>{props.rows[218].name}</td></tr>
+        <tr onClick={() => props.select(219)}><td>219</td><td>{props.rows[219].name}</td></tr>
+        <tr onClick={() => props.select(220)}><td>220</td><td>{props.rows[220].name}</td></tr>
+        <tr onClick={() => props.select(221)}><td>221</td><td>{props.rows[221].name}</td></tr>
+        <tr onClick={() => props.select(222)}><td>222</td><td>{props.rows[222].name}</td></tr>
+        <tr onClick={() => props.select(223)}><td>223</td><td>{props.rows[223].name}</td></tr>
+        <tr onClick={() => props.select(224)}><td>224</td><td>{props.rows[224].name}</td></tr>
+        <tr onClick={() => props.select(225)}><td>225</td><td>{props.rows[225].name}</td></tr>
+        <tr onClick={() => props.select(226)}><td>226</td><td>{props.rows[226].name}</td></tr>
+        <tr onClick={() => props.select(227)}><td>227</td><td>{props.rows[227].name}</td></tr>
+        <tr onClick={() => props.select(228)}><td>228</td><td>{props.rows[228].name}</td></tr>
+        <tr onClick={() => props.select(229)}><td>229</td><td>{props.rows[229].name}</td></tr>
+        <tr onClick={() => props.select(230)}><td>230</td><td>{props.rows[230].name}</td></tr>
+        <tr onClick={() => props.select(231)}><td>231</td><td>{props.rows[231].name}</td></tr>
+        <tr onClick={() => props.select(232)}><td>232</td><td>{props.rows[232].name}</td></tr>
+        <tr onClick={() => props.select(233)}><td>233</td><td>{props.rows[233].name}</td></tr>
+        <tr onClick={() => props.select(234)}><td>234</td><td>{props.rows[234].name}</td></tr>
+        <tr onClick={() => props.select(235)}><td>235</td><td>{props.rows[235].name}</td></tr>
+        <tr onClick={() => props.select(236)}><td>236</td><td>{props.rows[236].name}</td></tr>
+        <tr onClick={() => props.select(237)}><td>237</td><td>{props.rows[237].name}</td></tr>
+        <tr onClick={() => props.select(238)}><td>238</td><td>{props.rows[238].name}</td></tr>
+        <tr onClick={() => props.select(239)}><td>239</td><td>{props.rows[239].name}</td></tr>
+        <tr onClick={() => props.select(240)}><td>240</td><td>{props.rows[240].name}</td></tr>
+        <tr onClick={() => props.select(241)}><td>241</td><td>{props.rows[241].name}</td></tr>
+        <tr onClick={() => props.select(242)}><td>242</td><td>{props.rows[242].name}</td></tr>
+        <tr onClick={() => props.select(243)}><td>243</td><td>{props.rows[243].name}</td></tr>
+        <tr onClick={() => props.select(244)}><td>244</td><td>{props.rows[244].name}</td></tr>
+        <tr onClick={() => props.select(245)}><td>245</td><td>{props.rows[245].name}</td></tr>
+        <tr onClick={() => props.select(246)}><td>246</td><td>{props.rows[246].name}</td></tr>
+        <tr onClick={() => props.select(247)}><td>247</td><td>{props.rows[247].name}</td></tr>
+        <tr onClick={() => props.select(248)}><td>248</td><td>{props.rows[248].name}</td></tr>
+        <tr onClick={() => props.select(249)}><td>249</td><td>{props.rows[249].name}</td></tr>
+        <tr onClick={() => props.select(250)}><td>250</td><td>{props.rows[250].name}</td></tr>
+        <tr onClick={() => props.select(251)}><td>251</td><td>{props.rows[251].name}</td></tr>
+        <tr onClick={() => props.select(252)}><td>252</td><td>{props.rows[252].name}</td></tr>
+        <tr onClick={() => props.select(253)}><td>253</td><td>{props.rows[253].name}</td></tr>
+        <tr onClick={() => props.select(254)}><td>254</td><td>{props.rows[254].name}</td></tr>
+        <tr onClick={() => props.select(255)}><td>255</td><td>{props.rows[255].name}</td></tr>
+        <tr onClick={() => props.select(256)}><td>256</td><td>{props.rows[256].name}</td></tr>
+        <tr onClick={() => props.select(257)}><td>257</td><td>{props.rows[257].name}</td></tr>
+        <tr onClick={() => props.select(258)}><td>258</td><td>{props.rows[258].name}</td></tr>
+        <tr onClick={() => props.select(259)}><td>259</td><td>{props.rows[259].name}</td></tr>
+        <tr onClick={() => props.select(260)}><td>260</td><td>{props.rows[260].name}</td></tr>
+        <tr onClick={() => props.select(261)}><td>261</td><td>{props.rows[261].name}</td></tr>
+        <tr onClick={() => props.select(262)}><td>262</td><td>{props.rows[262].name}</td></tr>
+        <tr onClick={() => props.select(263)}><td>263</td><td>{props.rows[263].name}</td></tr>
+        <tr onClick={() => props.select(264)}><td>264</td><td>{props.rows[264].name}</td></tr>
+        <tr onClick={() => props.select(265)}><td>265</td><td>{props.rows[265].name}</td></tr>
+        <tr onClick={() => props.select(266)}><td>266</td><td>{props.rows[266].name}</td></tr>
+        <tr onClick={() => props.select(267)}><td>267</td><td>{props.rows[267].name}</td></tr>
+        <tr onClick={() => props.select(268)}><td>268</td><td>{props.rows[268].name}</td></tr>
+        <tr onClick={() => props.select(269)}><td>269</td><td>{props.rows[269].name}</td></tr>
+        <tr onClick={() => props.select(270)}><td>270</td><td>{props.rows[270].name}</td></tr>
+        <tr onClick={() => props.select(271)}><td>271</td><td>{props.rows[271].name}</td></tr>
+        <tr onClick={() => props.select(272)}><td>272</td><td>{props.rows[272].name}</td></tr>
+        <tr onClick={() => props.select(273)}><td>273</td><td>{props.rows[273].name}</td></tr>
+        <tr onClick={() => props.select(274)}><td>274</td><td>{props.rows[274].name}</td></tr>
+        <tr onClick={() => props.select(275)}><td>275</td><td>{props.rows[275].name}</td></tr>
+        <tr onClick={() => props.select(276)}><td>276</td><td>{props.rows[276].name}</td></tr>
+        <tr onClick={() => props.select(277)}><td>277</td><td>{props.rows[277].name}</td></tr>
+        <tr onClick={() => props.select(278)}><td>278</td><td>{props.rows[278].name}</td></tr>
+        <tr onClick={() => props.select(279)}><td>279</td><td>{props.rows[279].name}</td></tr>
+        <tr onClick={() => props.select(280)}><td>280</td><td>{props.rows[280].name}</td></tr>
+        <tr onClick={() => props.select(281)}><td>281</td><td>{props.rows[281].name}</td></tr>
+        <tr onClick={() => props.select(282)}><td>282</td><td>{props.rows[282].name}</td></tr>
+        <tr onClick={() => props.select(283)}><td>283</td><td>{props.rows[283].name}</td></tr>
+        <tr onClick={() => props.select(284)}><td>284</td><td>{props.rows[284].name}</td></tr>
+        <tr onClick={() => props.select(285)}><td>285</td><td>{props.rows[285].name}</td></tr>
+        <tr onClick={() => props.select(286)}><td>286</td><td>{props.rows[286].name}</td></tr>
+        <tr onClick={() => props.select(287)}><td>287</td><td>{props.rows[287].name}</td></tr>
+        <tr onClick={() => props.select(288)}><td>288</td><td>{props.rows[288].name}</td></tr>
+        <tr onClick={() => props.select(289)}><td>289</td><td>{props.rows[289].name}</td></tr>
+        <tr onClick={() => props.select(290)}><td>290</td><td>{props.rows[290].name}</td></tr>
+        <tr onClick={() => props.select(291)}><td>291</td><td>{props.rows[291].name}</td></tr>
+        <tr onClick={() => props.select(292)}><td>292</td><td>{props.rows[292].name}</td></tr>
+        <tr onClick={() => props.select(293)}><td>293</td><td>{props.rows[293].name}</td></tr>
+        <tr onClick={() => props.select(294)}><td>294</td><td>{props.rows[294].name}</td></tr>
+        <tr onClick={() => props.select(295)}><td>295</td><td>{props.rows[295].name}</td></tr>
+        <tr onClick={() => props.select(296)}><td>296</td><td>{props.rows[296].name}</td></tr>
+        <tr onClick={() => props.select(297)}><td>297</td><td>{props.rows[297].name}</td></tr>
+        <tr onClick={() => props.select(298)}><td>298</td><td>{props.rows[298].name}</td></tr>
+        <tr onClick={() => props.select(299)}><td>299</td><td>{props.rows[299].name}</td></tr>
+        <tr onClick={() => props.select(300)}><td>300</td><td>{props.rows[300].name}</td></tr>
+        <tr onClick={() => props.select(301)}><td>301</td><td>{props.rows[301].name}</td></tr>
+        <tr onClick={() => props.select(302)}><td>302</td><td>{props.rows[302].name}</td></tr>
+        <tr onClick={() => props.select(303)}><td>303</td><td>{props.rows[303].name}</td></tr>
+        <tr onClick={() => props.select(304)}><td>304</td><td>{props.rows[304].name}</td></tr>
+        <tr onClick={() => props.select(305)}><td>305</td><td>{props.rows[305].name}</td></tr>
+        <tr onClick={() => props.select(306)}><td>306</td><td>{props.rows[306].name}</td></tr>
+        <tr onClick={() => props.select(307)}><td>307</td><td>{props.rows[307].name}</td></tr>
+        <tr onClick={() => props.select(308)}><td>308</td><td>{props.rows[308].name}</td></tr>
+        <tr onClick={() => props.select(309)}><td>309</td><td>{props.rows[309].name}</td></tr>
+        <tr onClick={() => props.select(310)}><td>310</td><td>{props.rows[310].name}</td></tr>
+        <tr onClick={() => props.select(311)}><td>311</td><td>{props.rows[311].name}</td></tr>
+        <tr onClick={() => props.select(312)}><td>312</td><td>{props.rows[312].name}</td></tr>
+        <tr onClick={() => props.select(313)}><td>313</td><td>{props.rows[313].name}</td></tr>
+        <tr onClick={() => props.select(314)}><td>314</td><td>{props.rows[314].name}</td></tr>
+        <tr onClick={() => props.select(315)}><td>315</td><td>{props.rows[315].name}</td></tr>
+        <tr onClick={() => props.select(316)}><td>316</td><td>{props.rows[316].name}</td></tr>
+        <tr onClick={() => props.select(317)}><td>317</td><td>{props.rows[317].name}</td></tr>
+        <tr onClick={() => props.select(318)}><td>318</td><td>{props.rows[318].name}</td></tr>
+        <tr onClick={() => props.select(319)}><td>319</td><td>{props.rows[319].name}</td></tr>
+        <tr onClick={() => props.select(320)}><td>320</td><td>{props.rows[320].name}</td></tr>
+        <tr onClick={() => props.select(321)}><td>321</td><td>{props.rows[321].name}</td></tr>
+        <tr onClick={() => props.select(322)}><td>322</td><td>{props.rows[322].name}</td></tr>
+        <tr onClick={() => props.select(323)}><td>323</td><td>{props.rows[323].name}</td></tr>
+        <tr onClick={() => props.select(324)}><td>324</td><td>{props.rows[324].name}</td></tr>
+        <tr onClick={() => props.select(325)}><td>325</td><td>{props.rows[325].name}</td></tr>
+        <tr onClick={() => props.select(326)}><td>326</td><td>{props.rows[326].name}</td></tr>
+        <tr onClick={() => props.select(327)}><td>327</td><td>{props.rows[327].name}</td></tr>
+        <tr onClick={() => props.select(328)}><td>328</td><td>{props.rows[328].name}</td></tr>
+        <tr onClick={() => props.select(329)}><td>329</td><td>{props.rows[329].name}</td></tr>
+        <tr onClick={() => props.select(330)}><td>330</td><td>{props.rows[330].name}</td></tr>
+        <tr onClick={() => props.select(331)}><td>331</td><td>{props.rows[331].name}</td></tr>
+        <tr onClick={() => props.select(332)}><td>332</td><td>{props.rows[332].name}</td></tr>
+        <tr onClick={() => props.select(333)}><td>333</td><td>{props.rows[333].name}</td></tr>
+        <tr onClick={() => props.select(334)}><td>334</td><td>{props.rows[334].name}</td></tr>
+        <tr onClick={() => props.select(335)}><td>335</td><td>{props.rows[335].name}</td></tr>
+        <tr onClick={() => props.select(336)}><td>336</td><td>{props.rows[336].name}</td></tr>
+        <tr onClick={() => props.select(337)}><td>337</td><td>{props.rows[337].name}</td></tr>
+        <tr onClick={() => props.select(338)}><td>338</td><td>{props.rows[338].name}</td></tr>
+        <tr onClick={() => props.select(339)}><td>339</td><td>{props.rows[339].name}</td></tr>
+        <tr onClick={() => props.select(340)}><td>340</td><td>{props.rows[340].name}</td></tr>
+        <tr onClick={() => props.select(341)}><td>341</td><td>{props.rows[341].name}</td></tr>
+        <tr onClick={() => props.select(342)}><td>342</td><td>{props.rows[342].name}</td></tr>
+        <tr onClick={() => props.select(343)}><td>343</td><td>{props.rows[343].name}</td></tr>
+        <tr onClick={() => props.select(344)}><td>344</td><td>{props.rows[344].name}</td></tr>
+        <tr onClick={() => props.select(345)}><td>345</td><td>{props.rows[345].name}</td></tr>
+        <tr onClick={() => props.select(346)}><td>346</td><td>{props.rows[346].name}</td></tr>
+        <tr onClick={() => props.select(347)}><td>347</td><td>{props.rows[347].name}</td></tr>
+        <tr onClick={() => props.select(348)}><td>348</td><td>{props.rows[348].name}</td></tr>
+        <tr onClick={() => props.select(349)}><td>349</td><td>{props.rows[349].name}</td></tr>
+        <tr onClick={() => props.select(350)}><td>350</td><td>{props.rows[350].name}</td></tr>
+        <tr onClick={() => props.select(351)}><td>351</td><td>{props.rows[351].name}</td></tr>
+        <tr onClick={() => props.select(352)}><td>352</td><td>{props.rows[352].name}</td></tr>
+        <tr onClick={() => props.select(353)}><td>353</td><td>{props.rows[353].name}</td></tr>
+        <tr onClick={() => props.select(354)}><td>354</td><td>{props.rows[354].name}</td></tr>
+        <tr onClick={() => props.select(355)}><td>355</td><td>{props.rows[355].name}</td></tr>
+        <tr onClick={() => props.select(356)}><td>356</td><td>{props.rows[356].name}</td></tr>
+        <tr onClick={() => props.select(357)}><td>357</td><td>{props.rows[357].name}</td></tr>
+        <tr onClick={() => props.select(358)}><td>358</td><td>{props.rows[358].name}</td></tr>
+        <tr onClick={() => props.select(359)}><td>359</td><td>{props.rows[359].name}</td></tr>
+        <tr onClick={() => props.select(360)}><td>360</td><td>{props.rows[360].name}</td></tr>
+        <tr onClick={() => props.select(361)}><td>361</td><td>{props.rows[361].name}</td></tr>
+        <tr onClick={() => props.select(362)}><td>362</td><td>{props.rows[362].name}</td></tr>
+        <tr onClick={() => props.select(363)}><td>363</td><td>{props.rows[363].name}</td></tr>
+        <tr onClick={() => props.select(364)}><td>364</td><td>{props.rows[364].name}</td></tr>
+        <tr onClick={() => props.select(365)}><td>365</td><td>{props.rows[365].name}</td></tr>
+        <tr onClick={() => props.select(366)}><td>366</td><td>{props.rows[366].name}</td></tr>
+        <tr onClick={() => props.select(367)}><td>367</td><td>{props.rows[367].name}</td></tr>
+        <tr onClick={() => props.select(368)}><td>368</td><td>{props.rows[368].name}</td></tr>
+        <tr onClick={() => props.select(369)}><td>369</td><td>{props.rows[369].name}</td></tr>
+        <tr onClick={() => props.select(370)}><td>370</td><td>{props.rows[370].name}</td></tr>
+        <tr onClick={() => props.select(371)}><td>371</td><td>{props.rows[371].name}</td></tr>
+        <tr onClick={() => props.select(372)}><td>372</td><td>{props.rows[372].name}</td></tr>
+        <tr onClick={() => props.select(373)}><td>373</td><td>{props.rows[373].name}</td></tr>
+        <tr onClick={() => props.select(374)}><td>374</td><td>{props.rows[374].name}</td></tr>
+        <tr onClick={() => props.select(375)}><td>375</td><td>{props.rows[375].name}</td></tr>
+        <tr onClick={() => props.select(376)}><td>376</td><td>{props.rows[376].name}</td></tr>
+        <tr onClick={() => props.select(377)}><td>377</td><td>{props.rows[377].name}</td></tr>
+        <tr onClick={() => props.select(378)}><td>378</td><td>{props.rows[378].name}</td></tr>
+        <tr onClick={() => props.select(379)}><td>379</td><td>{props.rows[379].name}</td></tr>
+        <tr onClick={() => props.select(380)}><td>380</td><td>{props.rows[380].name}</td></tr>
+        <tr onClick={() => props.select(381)}><td>381</td><td>{props.rows[381].name}</td></tr>
+        <tr onClick={() => props.select(382)}><td>382</td><td>{props.rows[382].name}</td></tr>
+        <tr onClick={() => props.select(383)}><td>383</td><td>{props.rows[383].name}</td></tr>
+        <tr onClick={() => props.select(384)}><td>384</td><td>{props.rows[384].name}</td></tr>
+        <tr onClick={() => props.select(385)}><td>385</td><td>{props.rows[385].name}</td></tr>
+        <tr onClick={() => props.select(386)}><td>386</td><td>{props.rows[386].name}</td></tr>
+        <tr onClick={() => props.select(387)}><td>387</td><td>{props.rows[387].name}</td></tr>
+        <tr onClick={() => props.select(388)}><td>388</td><td>{props.rows[388].name}</td></tr>
+        <tr onClick={() => props.select(389)}><td>389</td><td>{props.rows[389].name}</td></tr>
+        <tr onClick={() => props.select(390)}><td>390</td><td>{props.rows[390].name}</td></tr>
+        <tr onClick={() => props.select(391)}><td>391</td><td>{props.rows[391].name}</td></tr>
+        <tr onClick={() => props.select(392)}><td>392</td><td>{props.rows[392].name}</td></tr>
+        <tr onClick={() => props.select(393)}><td>393</td><td>{props.rows[393].name}</td></tr>
+        <tr onClick={() => props.select(394)}><td>394</td><td>{props.rows[394].name}</td></tr>
+        <tr onClick={() => props.select(395)}><td>395</td><td>{props.rows[395].name}</td></tr>
+        <tr onClick={() => props.select(396)}><td>396</td><td>{props.rows[396].name}</td></tr>
+        <tr onClick={() => props.select(397)}><td>397</td><td>{props.rows[397].name}</td></tr>
+        <tr onClick={() => props.select(398)}><td>398</td><td>{props.rows[398].name}</td></tr>
+        <tr onClick={() => props.select(399)}><td>399</td><td>{props.rows[399].name}</td></tr>
+        <tr onClick={() => props.select(400)}><td>400</td><td>{props.rows[400].name}</td></tr>
+        <tr onClick={() => props.select(401)}><td>401</td><td>{props.rows[401].name}</td></tr>
+        <tr onClick={() => props.select(402)}><td>402</td><td>{props.rows[402].name}</td></tr>
+        <tr onClick={() => props.select(403)}><td>403</td><td>{props.rows[403].name}</td></tr>
+        <tr onClick={() => props.select(404)}><td>404</td><td>{props.rows[404].name}</td></tr>
+        <tr onClick={() => props.select(405)}><td>405</td><td>{props.rows[405].name}</td></tr>
+        <tr onClick={() => props.select(406)}><td>406</td><td>{props.rows[406].name}</td></tr>
+        <tr onClick={() => props.select(407)}><td>407</td><td>{props.rows[407].name}</td></tr>
+        <tr onClick={() => props.select(408)}><td>408</td><td>{props.rows[408].name}</td></tr>
+        <tr onClick={() => props.select(409)}><td>409</td><td>{props.rows[409].name}</td></tr>
+        <tr onClick={() => props.select(410)}><td>410</td><td>{props.rows[410].name}</td></tr>
+        <tr onClick={() => props.select(411)}><td>411</td><td>{props.rows[411].name}</td></tr>
+        <tr onClick={() => props.select(412)}><td>412</td><td>{props.rows[412].name}</td></tr>
+        <tr onClick={() => props.select(413)}><td>413</td><td>{props.rows[413].name}</td></tr>
+        <tr onClick={() => props.select(414)}><td>414</td><td>{props.rows[414].name}</td></tr>
+        <tr onClick={() => props.select(415)}><td>415</td><td>{props.rows[415].name}</td></tr>
+        <tr onClick={() => props.select(416)}><td>416</td><td>{props.rows[416].name}</td></tr>
+        <tr onClick={() => props.select(417)}><td>417</td><td>{props.rows[417].name}</td></tr>
+        <tr onClick={() => props.select(418)}><td>418</td><td>{props.rows[418].name}</td></tr>
+        <tr onClick={() => props.select(419)}><td>419</td><td>{props.rows[419].name}</td></tr>
+        <tr onClick={() => props.select(420)}><td>420</td><td>{props.rows[420].name}</td></tr>
+        <tr onClick={() => props.select(421)}><td>421</td><td>{props.rows[421].name}</td></tr>
+        <tr onClick={() => props.select(422)}><td>422</td><td>{props.rows[422].name}</td></tr>
+        <tr onClick={() => props.select(423)}><td>423</td><td>{props.rows[423].name}</td></tr>
+        <tr onClick={() => props.select(424)}><td>424</td><td>{props.rows[424].name}</td></tr>
+        <tr onClick={() => props.select(425)}><td>425</td><td>{props.rows[425].name}</td></tr>
+        <tr onClick={() => props.select(426)}><td>426</td><td>{props.rows[426].name}</td></tr>
+        <tr onClick={() => props.select(427)}><td>427</td><td>{props.rows[427].name}</td></tr>
+        <tr onClick={() => props.select(428)}><td>428</td><td>{props.rows[428].name}</td></tr>
+        <tr onClick={() => props.select(429)}><td>429</td><td>{props.rows[429].name}</td></tr>
+        <tr onClick={() => props.select(430)}><td>430</td><td>{props.rows[430].name}</td></tr>
+        <tr onClick={() => props.select(431)}><td>431</td><td>{props.rows[431].name}</td></tr>
+        <tr onClick={() => props.select(432)}><td>432</td><td>{props.rows[432].name}</td></tr>
+        <tr onClick={() => props.select(433)}><td>433</td><td>{props.rows[433].name}</td></tr>
+        <tr onClick={() => props.select(434)}><td>434</td><td>{props.rows[434].name}</td></tr>
+        <tr onClick={() => props.select(435)}><td>435</td><td>{props.rows[435].name}</td></tr>
+        <tr onClick={() => props.select(436)}><td>436</td><td>{props.rows[436].name}</td></tr>
+        <tr onClick={() => props.select(437)}><td>437</td><td>{props.rows[437].name}</td></tr>
+        <tr onClick={() => props.select(438)}><td>438</td><td>{props.rows[438].name}</td></tr>
+        <tr onClick={() => props.select(439)}><td>439</td><td>{props.rows[439].name}</td></tr>
+        <tr onClick={() => props.select(440)}><td>440</td><td>{props.rows[440].name}</td></tr>
+        <tr onClick={() => props.select(441)}><td>441</td><td>{props.rows[441].name}</td></tr>
+        <tr onClick={() => props.select(442)}><td>442</td><td>{props.rows[442].name}</td></tr>
+        <tr onClick={() => props.select(443)}><td>443</td><td>{props.rows[443].name}</td></tr>
+        <tr onClick={() => props.select(444)}><td>444</td><td>{props.rows[444].name}</td></tr>
+        <tr onClick={() => props.select(445)}><td>445</td><td>{props.rows[445].name}</td></tr>
+        <tr onClick={() => props.select(446)}><td>446</td><td>{props.rows[446].name}</td></tr>
+        <tr onClick={() => props.select(447)}><td>447</td><td>{props.rows[447].name}</td></tr>
+        <tr onClick={() => props.select(448)}><td>448</td><td>{props.rows[448].name}</td></tr>
+        <tr onClick={() => props.select(449)}><td>449</td><td>{props.rows[449].name}</td></tr>
+        <tr onClick={() => props.select(450)}><td>450</td><td>{props.rows[450].name}</td></tr>
+        <tr onClick={() => props.select(451)}><td>451</td><td>{props.rows[451].name}</td></tr>
+        <tr onClick={() => props.select(452)}><td>452</td><td>{props.rows[452].name}</td></tr>
+        <tr onClick={() => props.select(453)}><td>453</td><td>{props.rows[453].name}</td></tr>
+        <tr onClick={() => props.select(454)}><td>454</td><td>{props.rows[454].name}</td></tr>
+        <tr onClick={() => props.select(455)}><td>455</td><td>{props.rows[455].name}</td></tr>
+        <tr onClick={() => props.select(456)}><td>456</td><td>{props.rows[456].name}</td></tr>
+        <tr onClick={() => props.select(457)}><td>457</td><td>{props.rows[457].name}</td></tr>
+        <tr onClick={() => props.select(458)}><td>458</td><td>{props.rows[458].name}</td></tr>
+        <tr onClick={() => props.select(459)}><td>459</td><td>{props.rows[459].name}</td></tr>
+        <tr onClick={() => props.select(460)}><td>460</td><td>{props.rows[460].name}</td></tr>
+        <tr onClick={() => props.select(461)}><td>461</td><td>{props.rows[461].name}</td></tr>
+        <tr onClick={() => props.select(462)}><td>462</td><td>{props.rows[462].name}</td></tr>
+        <tr onClick={() => props.select(463)}><td>463</td><td>{props.rows[463].name}</td></tr>
+        <tr onClick={() => props.select(464)}><td>464</td><td>{props.rows[464].name}</td></tr>
+        <tr onClick={() => props.select(465)}><td>465</td><td>{props.rows[465].name}</td></tr>
+        <tr onClick={() => props.select(466)}><td>466</td><td>{props.rows[466].name}</td></tr>
+        <tr onClick={() => props.select(467)}><td>467</td><td>{props.rows[467].name}</td></tr>
+        <tr onClick={() => props.select(468)}><td>468</td><td>{props.rows[468].name}</td></tr>
+        <tr onClick={() => props.select(469)}><td>469</td><td>{props.rows[469].name}</td></tr>
+        <tr onClick={() => props.select(470)}><td>470</td><td>{props.rows[470].name}</td></tr>
+        <tr onClick={() => props.select(471)}><td>471</td><td>{props.rows[471].name}</td></tr>
+        <tr onClick={() => props.select(472)}><td>472</td><td>{props.rows[472].name}</td></tr>
+        <tr onClick={() => props.select(473)}><td>473</td><td>{props.rows[473].name}</td></tr>
+        <tr onClick={() => props.select(474)}><td>474</td><td>{props.rows[474].name}</td></tr>
+        <tr onClick={() => props.select(475)}><td>475</td><td>{props.rows[475].name}</td></tr>
+        <tr onClick={() => props.select(476)}><td>476</td><td>{props.rows[476].name}</td></tr>
+        <tr onClick={() => props.select(477)}><td>477</td><td>{props.rows[477].name}</td></tr>
+        <tr onClick={() => props.select(478)}><td>478</td><td>{props.rows[478].name}</td></tr>
+        <tr onClick={() => props.select(479)}><td>479</td><td>{props.rows[479].name}</td></tr>
+        <tr onClick={() => props.select(480)}><td>480</td><td>{props.rows[480].name}</td></tr>
+        <tr onClick={() => props.select(481)}><td>481</td><td>{props.rows[481].name}</td></tr>
+        <tr onClick={() => props.select(482)}><td>482</td><td>{props.rows[482].name}</td></tr>
+        <tr onClick={() => props.select(483)}><td>483</td><td>{props.rows[483].name}</td></tr>
+        <tr onClick={() => props.select(484)}><td>484</td><td>{props.rows[484].name}</td></tr>
+        <tr onClick={() => props.select(485)}><td>485</td><td>{props.rows[485].name}</td></tr>
+        <tr onClick={() => props.select(486)}><td>486</td><td>{props.rows[486].name}</td></tr>
+        <tr onClick={() => props.select(487)}><td>487</td><td>{props.rows[487].name}</td></tr>
+        <tr onClick={() => props.select(488)}><td>488</td><td>{props.rows[488].name}</td></tr>
+        <tr onClick={() => props.select(489)}><td>489</td><td>{props.rows[489].name}</td></tr>
+        <tr onClick={() => props.select(490)}><td>490</td><td>{props.rows[490].name}</td></tr>
+        <tr onClick={() => props.select(491)}><td>491</td><td>{props.rows[491].name}</td></tr>
+        <tr onClick={() => props.select(492)}><td>492</td><td>{props.rows[492].name}</td></tr>
+        <tr onClick={() => props.select(493)}><td>493</td><td>{props.rows[493].name}</td></tr>
+        <tr onClick={() => props.select(494)}><td>494</td><td>{props.rows[494].name}</td></tr>
+        <tr onClick={() => props.select(495)}><td>495</td><td>{props.rows[495].name}</td></tr>
+        <tr onClick={() => props.select(496)}><td>496</td><td>{props.rows[496].name}</td></tr>
+        <tr onClick={() => props.select(497)}><td>497</td><td>{props.rows[497].name}</td></tr>
+        <tr onClick={() => props.select(498)}><td>498</td><td>{props.rows[498].name}</td></tr>
+        <tr onClick={() => props.select(499)}><td>499</td><td>{props.rows[499].name}</td></tr>
+      </tbody>
+    </table>
+  );
+};
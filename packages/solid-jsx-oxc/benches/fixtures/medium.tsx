@@ -0,0 +1,119 @@
+import { createMemo, createSignal, For, Show } from "solid-js";
+import { createStore } from "solid-js/store";
+
+type Todo = { id: number; title: string; done: boolean };
+type Filter = "all" | "active" | "done";
+
+function TodoItem(props: { todo: Todo; onToggle: (id: number) => void; onRemove: (id: number) => void }) {
+  const [editing, setEditing] = createSignal(false);
+  let input!: HTMLInputElement;
+  return (
+    <li class="todo" classList={{ done: props.todo.done, editing: editing() }}>
+      <div class="view">
+        <input
+          class="toggle"
+          type="checkbox"
+          checked={props.todo.done}
+          onChange={() => props.onToggle(props.todo.id)}
+        />
+        <label onDblClick={() => setEditing(true)}>{props.todo.title}</label>
+        <button class="destroy" aria-label="Remove" onClick={() => props.onRemove(props.todo.id)} />
+      </div>
+      <Show when={editing()}>
+        <input
+          ref={input}
+          class="edit"
+          value={props.todo.title}
+          onBlur={() => setEditing(false)}
+          onKeyUp={(e) => e.key === "Escape" && setEditing(false)}
+        />
+      </Show>
+    </li>
+  );
+}
+
+function Footer(props: { remaining: number; filter: Filter; setFilter: (f: Filter) => void; onClear: () => void }) {
+  return (
+    <footer class="footer">
+      <span class="todo-count">
+        <strong>{props.remaining}</strong> {props.remaining === 1 ? "item" : "items"} left
+      </span>
+      <ul class="filters">
+        <For each={["all", "active", "done"] as Filter[]}>
+          {(filter) => (
+            <li>
+              <a
+                href={`#/${filter}`}
+                classList={{ selected: props.filter === filter }}
+                onClick={() => props.setFilter(filter)}
+              >
+                {filter}
+              </a>
+            </li>
+          )}
+        </For>
+      </ul>
+      <button class="clear-completed" onClick={props.onClear}>
+        Clear completed
+      </button>
+    </footer>
+  );
+}
+
+export default function TodoApp() {
+  const [state, setState] = createStore({ todos: [] as Todo[], filter: "all" as Filter });
+  const [title, setTitle] = createSignal("");
+  let nextId = 0;
+
+  const visible = createMemo(() =>
+    state.todos.filter((todo) =>
+      state.filter === "all" ? true : state.filter === "done" ? todo.done : !todo.done,
+    ),
+  );
+  const remaining = createMemo(() => state.todos.filter((todo) => !todo.done).length);
+
+  const add = (e: SubmitEvent) => {
+    e.preventDefault();
+    if (!title().trim()) return;
+    setState("todos", (todos) => [...todos, { id: nextId++, title: title().trim(), done: false }]);
+    setTitle("");
+  };
+
+  return (
+    <section class="todoapp">
+      <header class="header">
+        <h1>todos</h1>
+        <form onSubmit={add}>
+          <input
+            class="new-todo"
+            placeholder="What needs to be done?"
+            value={title()}
+            onInput={(e) => setTitle(e.currentTarget.value)}
+            autofocus
+          />
+        </form>
+      </header>
+      <Show when={state.todos.length > 0} fallback={<p class="empty">Nothing to do</p>}>
+        <section class="main" style={{ opacity: remaining() ? 1 : 0.6 }}>
+          <ul class="todo-list">
+            <For each={visible()}>
+              {(todo) => (
+                <TodoItem
+                  todo={todo}
+                  onToggle={(id) => setState("todos", (t) => t.id === id, "done", (done) => !done)}
+                  onRemove={(id) => setState("todos", (todos) => todos.filter((t) => t.id !== id))}
+                />
+              )}
+            </For>
+          </ul>
+        </section>
+        <Footer
+          remaining={remaining()}
+          filter={state.filter}
+          setFilter={(filter) => setState("filter", filter)}
+          onClear={() => setState("todos", (todos) => todos.filter((t) => !t.done))}
+        />
+      </Show>
+    </section>
+  );
+}
@@ -0,0 +1,10 @@
+import { createSignal } from "solid-js";
+
+export function Counter(props) {
+  const [count, setCount] = createSignal(props.initial ?? 0);
+  return (
+    <button class="counter" onClick={() => setCount(count() + 1)}>
+      {props.label}: {count()}
+    </button>
+  );
+}
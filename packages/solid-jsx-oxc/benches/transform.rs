@@ -0,0 +1,48 @@
+//! Transform throughput on a corpus of components
+//!
+//! `small` and `medium` are typical components; `deep`, `attributes` and
+//! `list` are pathological: 256 nested elements, one element with 200
+//! attributes, and 500 rows written out by hand. Run with `cargo bench`; the
+//! same files can be given to `benchmark/scripts/benchmark.ts` for the
+//! babel-preset-solid baseline.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use solid_jsx_oxc::{GenerateMode, TransformOptions, Transformer};
+
+const FIXTURES: [(&str, &str, &str); 5] = [
+    ("small", "small.jsx", include_str!("fixtures/small.jsx")),
+    ("medium", "medium.tsx", include_str!("fixtures/medium.tsx")),
+    ("deep", "deep.jsx", include_str!("fixtures/deep.jsx")),
+    (
+        "attributes",
+        "attributes.jsx",
+        include_str!("fixtures/attributes.jsx"),
+    ),
+    ("list", "list.jsx", include_str!("fixtures/list.jsx")),
+];
+
+fn bench_generate(c: &mut Criterion, group_name: &str, generate: GenerateMode) {
+    let mut group = c.benchmark_group(group_name);
+    let mut transformer = Transformer::new(TransformOptions {
+        generate,
+        ..TransformOptions::solid_defaults()
+    });
+    for (name, filename, source) in FIXTURES {
+        group.throughput(Throughput::Bytes(source.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), source, |b, source| {
+            b.iter(|| transformer.transform_source(filename, source))
+        });
+    }
+    group.finish();
+}
+
+fn dom(c: &mut Criterion) {
+    bench_generate(c, "dom", GenerateMode::Dom);
+}
+
+fn ssr(c: &mut Criterion) {
+    bench_generate(c, "ssr", GenerateMode::Ssr);
+}
+
+criterion_group!(benches, dom, ssr);
+criterion_main!(benches);
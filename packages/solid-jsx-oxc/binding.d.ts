@@ -1,5 +1,40 @@
 /* auto-generated by NAPI-RS */
 /* eslint-disable */
+/**
+ * Compiles many files with the same options, as a dev server does. The
+ * options are converted from JavaScript once, and the memory that holds the
+ * syntax tree of a file is reset and reused for the next.
+ */
+export declare class TransformSession {
+  constructor(options?: JsTransformOptions | undefined | null)
+  /**
+   * Transform `source`, the contents of `filename`; without a filename,
+   * the one of the session's options
+   */
+  transform(source: string, filename?: string | undefined | null): TransformResult
+}
+
+/** JSX attribute aliases, mapping the JSX name to the DOM attribute name */
+export declare function getAliases(): Record<string, string>
+
+/** Attributes treated as booleans (present = true) */
+export declare function getBooleanAttributes(): Array<string>
+
+/** Events that are delegated to the document by default */
+export declare function getDelegatedEvents(): Array<string>
+
+/**
+ * The JSX import source of a file, from a `@jsxImportSource` comment in
+ * `source` or the nearest tsconfig.json; null when neither says
+ */
+export declare function jsxImportSource(filename: string, source?: string | undefined | null): string | null
+
+/**
+ * Transform JSX source code on the libuv thread pool, resolving to the
+ * same result as `transformSync`
+ */
+export declare function transform(source: string, options?: JsTransformOptions | undefined | null): Promise<TransformResult>
+
 /** Transform options exposed to JavaScript */
 export interface JsTransformOptions {
   /**
@@ -28,6 +63,11 @@ export interface JsTransformOptions {
    * @default true
    */
   wrapConditionals?: boolean
+  /**
+   * Whether to leave off the closing tags that end a DOM template
+   * @default false
+   */
+  omitLastClosingTag?: boolean
   /**
    * Whether to pass context to custom elements
    * @default true
@@ -43,15 +83,39 @@ export interface JsTransformOptions {
    * @default false
    */
   sourceMap?: boolean
+  /**
+   * Whether to register exported components with solid-refresh
+   * @default false
+   */
+  hmr?: boolean
+  /**
+   * HMR API for solid-refresh: "vite", "esm", "standard" or "webpack5"
+   * @default "vite"
+   */
+  hmrBundler?: string
+  /**
+   * Extensions parsed like a known one, e.g. `{ solid: "tsx" }`; files with
+   * other unknown extensions are parsed as TSX
+   */
+  customExtensions?: Record<string, string>
 }
 
 /** Transform JSX source code */
 export declare function transformJsx(source: string, options?: JsTransformOptions | undefined | null): TransformResult
 
+/** Transform JSX source code on the calling thread */
+export declare function transformSync(source: string, options?: JsTransformOptions | undefined | null): TransformResult
+
 /** Result of a transform operation */
 export interface TransformResult {
   /** The transformed code */
   code: string
   /** Source map (if enabled) */
   map?: string
+  /** Events the output passes to `delegateEvents` */
+  delegatedEvents: Array<string>
+  /** HTML of each hoisted template */
+  templates: Array<string>
+  /** Runtime helpers the output imports */
+  helpers: Array<string>
 }
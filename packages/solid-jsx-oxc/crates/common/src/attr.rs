@@ -0,0 +1,429 @@
+//! Attribute lowering plan
+//!
+//! Classifies every attribute of a native element once, independent of the
+//! output mode. The dom and ssr generators both consume this plan and only
+//! differ in the code they emit for each kind (e.g. SSR drops client-only
+//! kinds like events and refs).
+
+use std::borrow::Cow;
+
+use oxc_ast::ast::{Expression, JSXAttributeItem, JSXAttributeValue, JSXElement};
+use oxc_span::Span;
+
+use crate::check::get_attr_name;
+use crate::constants::{
+    ALIASES, ATTRIBUTE_NAMESPACES, CHILD_PROPERTIES, DELEGATED_EVENTS, PROPERTIES,
+};
+use crate::expression::to_event_name;
+use crate::options::TransformOptions;
+
+/// How a single attribute should be lowered
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttrKind {
+    /// String literal or boolean attribute that is inlined into the template
+    Static { name: String },
+    /// Dynamic value assigned as a DOM property.
+    /// `forced` is set for the explicit `prop:` namespace.
+    Property { name: String, forced: bool },
+    /// Dynamic value written with `setAttribute`.
+    /// `forced` is set for the explicit `attr:` namespace.
+    SetAttribute { name: String, forced: bool },
+    /// `style={...}`
+    Style,
+    /// `style:prop={...}`
+    StyleProperty { name: String },
+    /// `class:name={...}`
+    ClassToggle { name: String },
+    /// `bool:name={...}`, present while the value is truthy
+    BoolAttribute { name: String },
+    /// Properties that replace the element's children (`innerHTML`, `textContent`, ...)
+    ChildProperty { name: String },
+    /// `children={...}`, inserted as the element's children when it has none
+    Children,
+    /// `onClick`, `on:click`, `onClickCapture`
+    Event {
+        name: String,
+        capture: bool,
+        delegated: bool,
+    },
+    /// `use:directive`
+    Directive { name: String },
+    /// `ref={...}`
+    Ref,
+    /// `{...props}` segment
+    Spread,
+}
+
+impl AttrKind {
+    /// Kinds that only make sense in the browser and are dropped by SSR
+    pub fn is_client_only(&self) -> bool {
+        matches!(
+            self,
+            AttrKind::Ref
+                | AttrKind::Event { .. }
+                | AttrKind::Directive { .. }
+                | AttrKind::Property { forced: true, .. }
+        )
+    }
+}
+
+/// A single classified attribute
+pub struct PlannedAttr<'b, 'a> {
+    /// The original attribute
+    pub item: &'b JSXAttributeItem<'a>,
+    /// The full source key (`on:click`, `class`, ...); empty for spreads
+    pub key: Cow<'a, str>,
+    pub kind: AttrKind,
+}
+
+impl<'b, 'a> PlannedAttr<'b, 'a> {
+    pub fn span(&self) -> Span {
+        match self.item {
+            JSXAttributeItem::Attribute(attr) => attr.span,
+            JSXAttributeItem::SpreadAttribute(spread) => spread.span,
+        }
+    }
+
+    /// The attribute's value expression, or the spread argument
+    pub fn expression(&self) -> Option<&'b Expression<'a>> {
+        match self.item {
+            JSXAttributeItem::Attribute(attr) => match &attr.value {
+                Some(JSXAttributeValue::ExpressionContainer(container)) => {
+                    container.expression.as_expression()
+                }
+                _ => None,
+            },
+            JSXAttributeItem::SpreadAttribute(spread) => Some(&spread.argument),
+        }
+    }
+
+    /// Whether a `bool:` attribute is present, when its value is a literal
+    /// (`"on"`, `{true}`, `{null}`, ...); `"0"` counts as absent, as in Babel
+    pub fn static_presence(&self) -> Option<bool> {
+        let JSXAttributeItem::Attribute(attr) = self.item else {
+            return None;
+        };
+        let expr = match &attr.value {
+            None => return Some(true),
+            Some(JSXAttributeValue::StringLiteral(lit)) => {
+                return Some(!lit.value.is_empty() && lit.value != "0")
+            }
+            _ => self.expression()?,
+        };
+        match expr {
+            Expression::StringLiteral(lit) => Some(!lit.value.is_empty() && lit.value != "0"),
+            Expression::BooleanLiteral(lit) => Some(lit.value),
+            Expression::NullLiteral(_) => Some(false),
+            Expression::Identifier(ident) if ident.name == "undefined" => Some(false),
+            _ => None,
+        }
+    }
+}
+
+/// The attribute plan for one element, in source order
+pub struct AttrPlan<'b, 'a> {
+    pub attrs: Vec<PlannedAttr<'b, 'a>>,
+}
+
+impl<'b, 'a> AttrPlan<'b, 'a> {
+    pub fn new(element: &'b JSXElement<'a>, is_svg: bool, options: &TransformOptions) -> Self {
+        let attrs = element
+            .opening_element
+            .attributes
+            .iter()
+            .map(|item| plan_attribute(item, is_svg, options))
+            .collect();
+        Self { attrs }
+    }
+
+    pub fn has_spread(&self) -> bool {
+        self.attrs.iter().any(|a| a.kind == AttrKind::Spread)
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, PlannedAttr<'b, 'a>> {
+        self.attrs.iter()
+    }
+}
+
+/// Classify a single attribute
+pub fn plan_attribute<'b, 'a>(
+    item: &'b JSXAttributeItem<'a>,
+    is_svg: bool,
+    options: &TransformOptions,
+) -> PlannedAttr<'b, 'a> {
+    let attr = match item {
+        JSXAttributeItem::Attribute(attr) => attr,
+        JSXAttributeItem::SpreadAttribute(_) => {
+            return PlannedAttr {
+                item,
+                key: Cow::Borrowed(""),
+                kind: AttrKind::Spread,
+            };
+        }
+    };
+
+    let key = get_attr_name(&attr.name);
+    let is_expression = matches!(&attr.value, Some(JSXAttributeValue::ExpressionContainer(_)));
+    let kind = classify_key(&key, is_expression, is_svg, options);
+    PlannedAttr { item, key, kind }
+}
+
+/// Split `ns:name` into its parts when `ns` is one of Solid's attribute namespaces
+pub fn split_namespace(key: &str) -> Option<(&str, &str)> {
+    let (namespace, name) = key.split_once(':')?;
+    ATTRIBUTE_NAMESPACES
+        .contains(&namespace)
+        .then_some((namespace, name))
+}
+
+fn classify_key(
+    key: &str,
+    is_expression: bool,
+    is_svg: bool,
+    options: &TransformOptions,
+) -> AttrKind {
+    if key == "ref" {
+        return AttrKind::Ref;
+    }
+
+    let namespace = split_namespace(key);
+
+    if let Some(("oncapture", name)) = namespace {
+        return AttrKind::Event {
+            name: name.to_string(),
+            capture: true,
+            delegated: false,
+        };
+    }
+
+    if key.starts_with("on") {
+        // onClickCapture -> click with capture=true
+        let (base_key, capture) = match key.strip_suffix("Capture") {
+            Some(base) => (base, true),
+            None => (key, false),
+        };
+        let name = to_event_name(base_key);
+        // on: forces direct addEventListener, and capture events can't be delegated
+        let delegated = !key.starts_with("on:")
+            && !capture
+            && options.delegate_events
+            && (DELEGATED_EVENTS.contains(name.as_str())
+                || options.delegated_events.contains(&name.as_str()));
+        return AttrKind::Event {
+            name,
+            capture,
+            delegated,
+        };
+    }
+
+    match namespace {
+        Some(("use", name)) => {
+            return AttrKind::Directive {
+                name: name.to_string(),
+            };
+        }
+        Some(("prop", name)) => {
+            return AttrKind::Property {
+                name: name.to_string(),
+                forced: true,
+            };
+        }
+        Some(("attr", name)) => {
+            return AttrKind::SetAttribute {
+                name: name.to_string(),
+                forced: true,
+            };
+        }
+        Some(("class", name)) => {
+            return AttrKind::ClassToggle {
+                name: name.to_string(),
+            };
+        }
+        Some(("bool", name)) => {
+            return AttrKind::BoolAttribute {
+                name: name.to_string(),
+            };
+        }
+        Some(("style", name)) => {
+            return AttrKind::StyleProperty {
+                name: name.to_string(),
+            };
+        }
+        _ => {}
+    }
+    if key == "style" {
+        return AttrKind::Style;
+    }
+    if key == "children" {
+        return AttrKind::Children;
+    }
+    if CHILD_PROPERTIES.contains(key) {
+        return AttrKind::ChildProperty {
+            name: key.to_string(),
+        };
+    }
+
+    let name = ALIASES.get(key).copied().unwrap_or(key);
+    if !is_expression {
+        return AttrKind::Static {
+            name: name.to_string(),
+        };
+    }
+
+    if name == "class" && !is_svg {
+        AttrKind::Property {
+            name: "className".to_string(),
+            forced: false,
+        }
+    } else if PROPERTIES.contains(key) && !is_svg {
+        AttrKind::Property {
+            name: key.to_string(),
+            forced: false,
+        }
+    } else {
+        AttrKind::SetAttribute {
+            name: name.to_string(),
+            forced: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kind(key: &str, is_expression: bool) -> AttrKind {
+        classify_key(
+            key,
+            is_expression,
+            false,
+            &TransformOptions::solid_defaults(),
+        )
+    }
+
+    #[test]
+    fn test_static_attributes_are_aliased() {
+        assert_eq!(
+            kind("className", false),
+            AttrKind::Static {
+                name: "class".to_string()
+            }
+        );
+        assert_eq!(
+            kind("htmlFor", false),
+            AttrKind::Static {
+                name: "for".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_dynamic_attributes() {
+        assert_eq!(
+            kind("class", true),
+            AttrKind::Property {
+                name: "className".to_string(),
+                forced: false
+            }
+        );
+        assert_eq!(
+            kind("checked", true),
+            AttrKind::Property {
+                name: "checked".to_string(),
+                forced: false
+            }
+        );
+        assert_eq!(
+            kind("title", true),
+            AttrKind::SetAttribute {
+                name: "title".to_string(),
+                forced: false
+            }
+        );
+        assert_eq!(
+            classify_key("class", true, true, &TransformOptions::solid_defaults()),
+            AttrKind::SetAttribute {
+                name: "class".to_string(),
+                forced: false
+            }
+        );
+    }
+
+    #[test]
+    fn test_events() {
+        assert_eq!(
+            kind("onClick", true),
+            AttrKind::Event {
+                name: "click".to_string(),
+                capture: false,
+                delegated: true
+            }
+        );
+        assert_eq!(
+            kind("on:click", true),
+            AttrKind::Event {
+                name: "click".to_string(),
+                capture: false,
+                delegated: false
+            }
+        );
+        assert_eq!(
+            kind("onClickCapture", true),
+            AttrKind::Event {
+                name: "click".to_string(),
+                capture: true,
+                delegated: false
+            }
+        );
+        assert_eq!(
+            kind("onScroll", true),
+            AttrKind::Event {
+                name: "scroll".to_string(),
+                capture: false,
+                delegated: false
+            }
+        );
+    }
+
+    #[test]
+    fn test_namespaces() {
+        assert_eq!(
+            kind("use:tooltip", true),
+            AttrKind::Directive {
+                name: "tooltip".to_string()
+            }
+        );
+        assert_eq!(
+            kind("prop:value", true),
+            AttrKind::Property {
+                name: "value".to_string(),
+                forced: true
+            }
+        );
+        assert_eq!(
+            kind("oncapture:click", true),
+            AttrKind::Event {
+                name: "click".to_string(),
+                capture: true,
+                delegated: false
+            }
+        );
+        assert_eq!(
+            kind("bool:hidden", true),
+            AttrKind::BoolAttribute {
+                name: "hidden".to_string()
+            }
+        );
+        assert_eq!(split_namespace("xlink:href"), None);
+        assert!(kind("prop:value", true).is_client_only());
+        assert!(!kind("attr:foo", true).is_client_only());
+        assert!(!kind("bool:hidden", true).is_client_only());
+        assert_eq!(
+            kind("innerHTML", true),
+            AttrKind::ChildProperty {
+                name: "innerHTML".to_string()
+            }
+        );
+        assert_eq!(kind("children", true), AttrKind::Children);
+    }
+}
@@ -1,13 +1,67 @@
 //! Check functions for JSX nodes
 //! Ported from dom-expressions/src/shared/utils.js
 
+use std::borrow::Cow;
+
+use oxc_allocator::CloneIn;
 use oxc_ast::ast::{
-    Expression, JSXAttribute, JSXAttributeItem, JSXAttributeName, JSXAttributeValue, JSXElement,
-    JSXElementName, JSXMemberExpression, JSXMemberExpressionObject,
+    Expression, JSXAttribute, JSXAttributeItem, JSXAttributeName, JSXAttributeValue, JSXChild,
+    JSXElement, JSXElementName, JSXExpression, JSXMemberExpression, JSXMemberExpressionObject,
 };
+use oxc_ast::AstBuilder;
+use oxc_span::GetSpan;
 
-use crate::constants::{BUILT_INS, SVG_ELEMENTS};
+use crate::constants::{BUILT_INS, MATHML_ELEMENTS, SVG_ELEMENTS, UNITLESS_STYLE_PROPERTIES};
 use crate::expression::expr_to_string;
+use crate::options::TransformOptions;
+
+/// What kind of thing a JSX tag name refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagKind {
+    /// Plain HTML element (`div`, `span`, ...)
+    Html,
+    /// SVG element (`svg`, `path`, ...)
+    Svg,
+    /// MathML element (`math`, `mi`, ...)
+    MathMl,
+    /// Custom element (hyphenated, or listed in `options.custom_elements`)
+    CustomElement,
+    /// User component (`Button`, `ui.Card`, ...)
+    Component,
+    /// Solid built-in component listed in `options.built_ins` (`For`, `Show`, ...)
+    BuiltIn,
+}
+
+impl TagKind {
+    /// Components and built-ins are compiled to `createComponent` calls
+    pub fn is_component(self) -> bool {
+        matches!(self, TagKind::Component | TagKind::BuiltIn)
+    }
+
+    /// Native elements end up in a template
+    pub fn is_native(self) -> bool {
+        !self.is_component()
+    }
+}
+
+/// Classify a tag name, consulting the built-in and custom element lists in `options`
+pub fn classify_tag(tag: &str, options: &TransformOptions) -> TagKind {
+    if is_component(tag) {
+        if options.built_ins.contains(&tag) {
+            TagKind::BuiltIn
+        } else {
+            TagKind::Component
+        }
+    } else if is_svg_element(tag) {
+        TagKind::Svg
+    } else if MATHML_ELEMENTS.contains(tag) {
+        TagKind::MathMl
+    } else if tag.contains('-') || options.custom_elements.contains(&tag) {
+        TagKind::CustomElement
+    } else {
+        TagKind::Html
+    }
+}
 
 /// Check if a tag name represents a component (starts with uppercase or contains dot)
 pub fn is_component(tag: &str) -> bool {
@@ -28,21 +82,27 @@ pub fn is_svg_element(tag: &str) -> bool {
     SVG_ELEMENTS.contains(tag)
 }
 
+/// Check if a numeric value for this (kebab-case) CSS property needs a `px` unit
+pub fn needs_px_suffix(prop: &str) -> bool {
+    !UNITLESS_STYLE_PROPERTIES.contains(prop)
+}
+
 /// Get the tag name from a JSX element
-pub fn get_tag_name(element: &JSXElement) -> String {
+pub fn get_tag_name<'a>(element: &JSXElement<'a>) -> Cow<'a, str> {
     get_jsx_element_name(&element.opening_element.name)
 }
 
-/// Get the name from a JSXElementName
-fn get_jsx_element_name(name: &JSXElementName) -> String {
+/// Get the name from a JSXElementName, borrowed from the source unless it has
+/// to be joined
+fn get_jsx_element_name<'a>(name: &JSXElementName<'a>) -> Cow<'a, str> {
     match name {
-        JSXElementName::Identifier(id) => id.name.to_string(),
-        JSXElementName::IdentifierReference(id) => id.name.to_string(),
+        JSXElementName::Identifier(id) => Cow::Borrowed(id.name.as_str()),
+        JSXElementName::IdentifierReference(id) => Cow::Borrowed(id.name.as_str()),
         JSXElementName::NamespacedName(ns) => {
-            format!("{}:{}", ns.namespace.name, ns.name.name)
+            Cow::Owned(format!("{}:{}", ns.namespace.name, ns.name.name))
         }
-        JSXElementName::MemberExpression(member) => get_member_expression_name(member),
-        JSXElementName::ThisExpression(_) => "this".to_string(),
+        JSXElementName::MemberExpression(member) => Cow::Owned(get_member_expression_name(member)),
+        JSXElementName::ThisExpression(_) => Cow::Borrowed("this"),
     }
 }
 
@@ -128,6 +188,38 @@ pub fn find_prop<'a>(element: &'a JSXElement<'a>, name: &str) -> Option<&'a JSXA
     None
 }
 
+/// The `children` attribute of a native element as its only child, if the
+/// element has no children of its own (`<div children={a} />` renders `a`)
+pub fn children_attr_child<'a>(
+    element: &JSXElement<'a>,
+    ast: AstBuilder<'a>,
+) -> Option<JSXChild<'a>> {
+    if !element.children.is_empty() {
+        return None;
+    }
+    let value = element
+        .opening_element
+        .attributes
+        .iter()
+        .find_map(|attr| match attr {
+            JSXAttributeItem::Attribute(attr) if get_attr_name(&attr.name) == "children" => {
+                attr.value.as_ref()
+            }
+            _ => None,
+        })?;
+    let expression = match value {
+        JSXAttributeValue::ExpressionContainer(container) => match &container.expression {
+            JSXExpression::EmptyExpression(_) => return None,
+            expression => expression.clone_in(ast.allocator),
+        },
+        JSXAttributeValue::StringLiteral(literal) => {
+            JSXExpression::StringLiteral(literal.clone_in(ast.allocator))
+        }
+        _ => return None,
+    };
+    Some(ast.jsx_child_expression_container(value.span(), expression))
+}
+
 /// Find a JSX attribute by name and return its value as a string.
 ///
 /// Handles expression containers, string literals, and boolean attributes (no value = true).
@@ -156,11 +248,11 @@ pub fn get_attr_value(attr: &JSXAttribute<'_>) -> Option<String> {
 ///
 /// - `id` -> "id"
 /// - `on:click` -> "on:click"
-pub fn get_attr_name(name: &JSXAttributeName) -> String {
+pub fn get_attr_name<'a>(name: &JSXAttributeName<'a>) -> Cow<'a, str> {
     match name {
-        JSXAttributeName::Identifier(id) => id.name.to_string(),
+        JSXAttributeName::Identifier(id) => Cow::Borrowed(id.name.as_str()),
         JSXAttributeName::NamespacedName(ns) => {
-            format!("{}:{}", ns.namespace.name, ns.name.name)
+            Cow::Owned(format!("{}:{}", ns.namespace.name, ns.name.name))
         }
     }
 }
@@ -169,3 +261,74 @@ pub fn get_attr_name(name: &JSXAttributeName) -> String {
 pub fn is_namespaced_attr(name: &JSXAttributeName) -> bool {
     matches!(name, JSXAttributeName::NamespacedName(_))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_tag() {
+        let options = TransformOptions::solid_defaults();
+        assert_eq!(classify_tag("div", &options), TagKind::Html);
+        assert_eq!(classify_tag("path", &options), TagKind::Svg);
+        assert_eq!(classify_tag("mfrac", &options), TagKind::MathMl);
+        assert_eq!(classify_tag("my-element", &options), TagKind::CustomElement);
+        assert_eq!(classify_tag("Button", &options), TagKind::Component);
+        assert_eq!(classify_tag("ui.Card", &options), TagKind::Component);
+        assert_eq!(classify_tag("For", &options), TagKind::BuiltIn);
+    }
+
+    #[test]
+    fn test_classify_tag_custom_lists() {
+        let options = TransformOptions {
+            built_ins: vec!["Show"],
+            custom_elements: vec!["widget"],
+            ..TransformOptions::solid_defaults()
+        };
+        assert_eq!(classify_tag("widget", &options), TagKind::CustomElement);
+        assert_eq!(classify_tag("Show", &options), TagKind::BuiltIn);
+        assert_eq!(classify_tag("For", &options), TagKind::Component);
+        assert!(classify_tag("For", &options).is_component());
+        assert!(classify_tag("widget", &options).is_native());
+    }
+
+    #[test]
+    fn test_needs_px_suffix() {
+        assert!(needs_px_suffix("width"));
+        assert!(needs_px_suffix("margin-top"));
+        assert!(!needs_px_suffix("z-index"));
+        assert!(!needs_px_suffix("stroke-width"));
+        assert!(!needs_px_suffix("opacity"));
+    }
+
+    #[test]
+    fn test_names_borrow_from_source() {
+        use oxc_allocator::Allocator;
+        use oxc_ast::ast::Statement;
+        use oxc_parser::Parser;
+        use oxc_span::SourceType;
+
+        let allocator = Allocator::default();
+        let source = r#"<ui.Card id="a" on:click={f} />"#;
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected JSX element");
+        };
+        assert_eq!(get_tag_name(element), "ui.Card");
+
+        let names: Vec<_> = element
+            .opening_element
+            .attributes
+            .iter()
+            .filter_map(|item| item.as_attribute())
+            .map(|attr| get_attr_name(&attr.name))
+            .collect();
+        assert!(matches!(names[0], Cow::Borrowed("id")));
+        assert!(matches!(&names[1], Cow::Owned(name) if name == "on:click"));
+    }
+}
@@ -39,12 +39,42 @@ pub static PROPERTIES: Set<&'static str> = phf_set! {
     "selected",
 };
 
-/// Child properties that affect children
+/// Boolean attributes (present = true, absent = false)
+pub static BOOLEANS: Set<&'static str> = phf_set! {
+    "allowfullscreen",
+    "async",
+    "autofocus",
+    "autoplay",
+    "checked",
+    "controls",
+    "default",
+    "defer",
+    "disabled",
+    "formnovalidate",
+    "hidden",
+    "indeterminate",
+    "inert",
+    "ismap",
+    "loop",
+    "multiple",
+    "muted",
+    "nomodule",
+    "novalidate",
+    "open",
+    "playsinline",
+    "readonly",
+    "required",
+    "reversed",
+    "seamless",
+    "selected",
+};
+
+/// Child properties that affect children. Not `children`: `Element.children`
+/// is a read-only HTMLCollection, so the attribute is inserted as JSX children
 pub static CHILD_PROPERTIES: Set<&'static str> = phf_set! {
     "innerHTML",
     "textContent",
     "innerText",
-    "children",
 };
 
 /// Attribute aliases (JSX name -> DOM name)
@@ -53,6 +83,64 @@ pub static ALIASES: phf::Map<&'static str, &'static str> = phf::phf_map! {
     "htmlFor" => "for",
 };
 
+/// Attribute namespaces with special meaning in Solid's JSX (`on:click`, `use:tooltip`, ...).
+/// Shared with the linter's `no-unknown-namespaces` rule.
+pub const ATTRIBUTE_NAMESPACES: &[&str] = &[
+    "on",
+    "oncapture",
+    "use",
+    "prop",
+    "attr",
+    "bool",
+    "style",
+    "class",
+];
+
+/// CSS properties whose numeric values are unitless (no `px` suffix)
+pub static UNITLESS_STYLE_PROPERTIES: Set<&'static str> = phf_set! {
+    "animation-iteration-count",
+    "border-image-outset",
+    "border-image-slice",
+    "border-image-width",
+    "box-flex",
+    "box-flex-group",
+    "box-ordinal-group",
+    "column-count",
+    "columns",
+    "flex",
+    "flex-grow",
+    "flex-positive",
+    "flex-shrink",
+    "flex-negative",
+    "flex-order",
+    "grid-row",
+    "grid-row-end",
+    "grid-row-span",
+    "grid-row-start",
+    "grid-column",
+    "grid-column-end",
+    "grid-column-span",
+    "grid-column-start",
+    "font-weight",
+    "line-clamp",
+    "line-height",
+    "opacity",
+    "order",
+    "orphans",
+    "tab-size",
+    "widows",
+    "z-index",
+    "zoom",
+    "fill-opacity",
+    "flood-opacity",
+    "stop-opacity",
+    "stroke-dasharray",
+    "stroke-dashoffset",
+    "stroke-miterlimit",
+    "stroke-opacity",
+    "stroke-width",
+};
+
 /// Events that can be delegated (bubbling events)
 pub static DELEGATED_EVENTS: Set<&'static str> = phf_set! {
     "beforeinput",
@@ -79,6 +167,135 @@ pub static DELEGATED_EVENTS: Set<&'static str> = phf_set! {
     "touchstart",
 };
 
+/// HTML elements, including obsolete ones browsers still parse
+pub static HTML_ELEMENTS: Set<&'static str> = phf_set! {
+    "a",
+    "abbr",
+    "acronym",
+    "address",
+    "area",
+    "article",
+    "aside",
+    "audio",
+    "b",
+    "base",
+    "bdi",
+    "bdo",
+    "big",
+    "blockquote",
+    "body",
+    "br",
+    "button",
+    "canvas",
+    "caption",
+    "center",
+    "cite",
+    "code",
+    "col",
+    "colgroup",
+    "data",
+    "datalist",
+    "dd",
+    "del",
+    "details",
+    "dfn",
+    "dialog",
+    "dir",
+    "div",
+    "dl",
+    "dt",
+    "em",
+    "embed",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "font",
+    "footer",
+    "form",
+    "frame",
+    "frameset",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "header",
+    "hgroup",
+    "hr",
+    "html",
+    "i",
+    "iframe",
+    "img",
+    "input",
+    "ins",
+    "kbd",
+    "label",
+    "legend",
+    "li",
+    "link",
+    "main",
+    "map",
+    "mark",
+    "marquee",
+    "menu",
+    "meta",
+    "meter",
+    "nav",
+    "nobr",
+    "noframes",
+    "noscript",
+    "object",
+    "ol",
+    "optgroup",
+    "option",
+    "output",
+    "p",
+    "param",
+    "picture",
+    "pre",
+    "progress",
+    "q",
+    "rp",
+    "rt",
+    "ruby",
+    "s",
+    "samp",
+    "script",
+    "search",
+    "section",
+    "select",
+    "slot",
+    "small",
+    "source",
+    "span",
+    "strike",
+    "strong",
+    "style",
+    "sub",
+    "summary",
+    "sup",
+    "table",
+    "tbody",
+    "td",
+    "template",
+    "textarea",
+    "tfoot",
+    "th",
+    "thead",
+    "time",
+    "title",
+    "tr",
+    "track",
+    "tt",
+    "u",
+    "ul",
+    "var",
+    "video",
+    "wbr",
+};
+
 /// SVG elements
 pub static SVG_ELEMENTS: Set<&'static str> = phf_set! {
     "svg",
@@ -143,6 +360,42 @@ pub static SVG_ELEMENTS: Set<&'static str> = phf_set! {
     "view",
 };
 
+/// MathML elements
+pub static MATHML_ELEMENTS: Set<&'static str> = phf_set! {
+    "math",
+    "annotation",
+    "annotation-xml",
+    "maction",
+    "menclose",
+    "merror",
+    "mfenced",
+    "mfrac",
+    "mi",
+    "mmultiscripts",
+    "mn",
+    "mo",
+    "mover",
+    "mpadded",
+    "mphantom",
+    "mprescripts",
+    "mroot",
+    "mrow",
+    "ms",
+    "mspace",
+    "msqrt",
+    "mstyle",
+    "msub",
+    "msubsup",
+    "msup",
+    "mtable",
+    "mtd",
+    "mtext",
+    "mtr",
+    "munder",
+    "munderover",
+    "semantics",
+};
+
 /// Void elements (self-closing)
 pub static VOID_ELEMENTS: Set<&'static str> = phf_set! {
     "area",
@@ -174,3 +427,24 @@ pub static BUILT_INS: Set<&'static str> = phf_set! {
     "Dynamic",
     "ErrorBoundary",
 };
+
+/// Events delegated to the document by default, sorted by name
+pub fn delegated_events() -> Vec<&'static str> {
+    sorted(DELEGATED_EVENTS.iter().copied())
+}
+
+/// Boolean attributes, sorted by name
+pub fn boolean_attributes() -> Vec<&'static str> {
+    sorted(BOOLEANS.iter().copied())
+}
+
+/// JSX attribute aliases as `(jsx name, dom name)` pairs, sorted by JSX name
+pub fn aliases() -> Vec<(&'static str, &'static str)> {
+    sorted(ALIASES.entries().map(|(k, v)| (*k, *v)))
+}
+
+fn sorted<T: Ord>(items: impl Iterator<Item = T>) -> Vec<T> {
+    let mut items: Vec<T> = items.collect();
+    items.sort();
+    items
+}
@@ -1,6 +1,6 @@
 //! Expression utilities for working with OXC AST
 
-use oxc_ast::ast::{Expression, JSXChild, JSXElement, Statement};
+use oxc_ast::ast::{BinaryOperator, Expression, JSXChild, JSXElement, Statement};
 use oxc_codegen::{Codegen, CodegenOptions};
 use oxc_span::Span;
 
@@ -19,7 +19,7 @@ pub fn stmt_to_string(stmt: &Statement<'_>) -> String {
         Statement::ExpressionStatement(expr_stmt) => expr_to_string(&expr_stmt.expression),
         _ => {
             // Fallback - this is less common
-            format!("/* unsupported statement */")
+            "/* unsupported statement */".to_string()
         }
     }
 }
@@ -70,56 +70,112 @@ pub fn escape_html(text: &str, quote_escape: bool) -> String {
 
 /// Trim whitespace from JSX text (preserving significant spaces)
 ///
-/// JSX whitespace rules:
-/// - Text with newlines: trim leading/trailing whitespace (indentation)
-/// - Inline text (no newlines): preserve trailing space (e.g., ". " between expressions)
+/// JSX whitespace rules, as dom-expressions applies them:
+/// - Lines after the first lose their indentation, and blank lines are dropped
+/// - The remaining lines are joined by a space, so the space before an
+///   expression on the last line (`Hello, {name}`) is kept
 /// - Multiple whitespace collapses to single space
 pub fn trim_whitespace(text: &str) -> String {
-    let has_newline = text.contains('\n');
+    let mut result = String::with_capacity(text.len());
+    if text.contains('\n') {
+        let lines = text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| if i == 0 { line } else { line.trim_start() })
+            .filter(|line| !line.trim().is_empty());
+        for (i, line) in lines.enumerate() {
+            if i > 0 {
+                result.push(' ');
+            }
+            result.push_str(line);
+        }
+    } else {
+        result.push_str(text);
+    }
 
     // Collapse multiple whitespace into single space
-    let mut result = String::new();
+    let mut collapsed = String::with_capacity(result.len());
     let mut prev_was_space = false;
-
-    for c in text.chars() {
+    for c in result.chars() {
         if c.is_whitespace() {
-            if has_newline {
-                // Ignore leading indentation/newlines; we'll trim later.
-                if !prev_was_space && !result.is_empty() {
-                    result.push(' ');
-                    prev_was_space = true;
-                }
-                continue;
-            }
-
-            // Inline text: preserve a single leading space (e.g., " Click" after an element)
             if !prev_was_space {
-                result.push(' ');
-                prev_was_space = true;
+                collapsed.push(' ');
             }
+            prev_was_space = true;
         } else {
-            result.push(c);
+            collapsed.push(c);
             prev_was_space = false;
         }
     }
+    collapsed
+}
 
-    // Only trim if text contained newlines (multi-line JSX text with indentation)
-    // Preserve trailing space for inline text like ". " between expressions
-    if has_newline {
-        result.trim().to_string()
-    } else {
-        result
+/// A value known at compile time
+enum Constant {
+    String(String),
+    Number(f64),
+}
+
+impl Constant {
+    fn into_string(self) -> String {
+        match self {
+            Constant::String(value) => value,
+            Constant::Number(value) if value.fract() == 0.0 && value.abs() < 1e21 => {
+                format!("{}", value as i64)
+            }
+            Constant::Number(value) => value.to_string(),
+        }
+    }
+}
+
+/// Statically evaluate an expression to the string it produces.
+///
+/// Handles string and number literals, template literals whose substitutions
+/// are themselves constant, and `+` concatenations of those. Returns `None`
+/// for anything that depends on runtime values.
+pub fn evaluate_string(expr: &Expression<'_>) -> Option<String> {
+    evaluate_constant(expr).map(Constant::into_string)
+}
+
+fn evaluate_constant(expr: &Expression<'_>) -> Option<Constant> {
+    match expr.get_inner_expression() {
+        Expression::StringLiteral(lit) => Some(Constant::String(lit.value.to_string())),
+        Expression::NumericLiteral(lit) => Some(Constant::Number(lit.value)),
+        Expression::TemplateLiteral(template) => {
+            let mut result = String::new();
+            for (i, quasi) in template.quasis.iter().enumerate() {
+                result.push_str(quasi.value.cooked.as_ref()?.as_str());
+                if let Some(expr) = template.expressions.get(i) {
+                    result.push_str(&evaluate_string(expr)?);
+                }
+            }
+            Some(Constant::String(result))
+        }
+        Expression::BinaryExpression(binary) if binary.operator == BinaryOperator::Addition => {
+            match (
+                evaluate_constant(&binary.left)?,
+                evaluate_constant(&binary.right)?,
+            ) {
+                (Constant::Number(left), Constant::Number(right)) => {
+                    Some(Constant::Number(left + right))
+                }
+                (left, right) => Some(Constant::String(
+                    left.into_string() + &right.into_string(),
+                )),
+            }
+        }
+        _ => None,
     }
 }
 
 /// Convert event name from JSX format (onClick or on:click) to DOM format (click)
 pub fn to_event_name(name: &str) -> String {
-    if name.starts_with("on:") {
+    if let Some(event) = name.strip_prefix("on:") {
         // Handle on:click -> click (namespaced form)
-        name[3..].to_string()
-    } else if name.starts_with("on") {
+        event.to_string()
+    } else if let Some(event) = name.strip_prefix("on") {
         // Handle onClick -> click, onMouseDown -> mousedown (lowercase entire name)
-        name[2..].to_lowercase()
+        event.to_lowercase()
     } else {
         name.to_string()
     }
@@ -147,3 +203,39 @@ pub fn get_children_callback(element: &JSXElement<'_>) -> String {
     }
     "() => undefined".to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn evaluate(source: &str) -> Option<String> {
+        let allocator = Allocator::default();
+        let expr = Parser::new(&allocator, source, SourceType::jsx())
+            .parse_expression()
+            .unwrap();
+        evaluate_string(&expr)
+    }
+
+    #[test]
+    fn test_evaluate_string() {
+        assert_eq!(evaluate(r#""a" + 'b'"#).as_deref(), Some("ab"));
+        assert_eq!(evaluate(r#"1 + 2 + "px""#).as_deref(), Some("3px"));
+        assert_eq!(evaluate(r#"`x${1.5}${"y"}`"#).as_deref(), Some("x1.5y"));
+        assert_eq!(evaluate(r#"("a" + "b")"#).as_deref(), Some("ab"));
+        assert_eq!(evaluate(r#""a" + b"#), None);
+        assert_eq!(evaluate(r#"`${value}`"#), None);
+    }
+
+    #[test]
+    fn test_trim_whitespace() {
+        assert_eq!(trim_whitespace("\n    hello\n    world\n  "), "hello world");
+        // The space before an expression on the last line is kept
+        assert_eq!(trim_whitespace("\n    Hello, "), "Hello, ");
+        assert_eq!(trim_whitespace("  a \t b  "), " a b ");
+        assert_eq!(trim_whitespace("a\r\n  b"), "a b");
+        assert_eq!(trim_whitespace("\n   \n"), "");
+    }
+}
@@ -1,15 +1,21 @@
+pub mod attr;
 pub mod check;
 pub mod constants;
 pub mod expression;
+pub mod metadata;
 pub mod options;
+pub mod visit;
 
 pub use check::{
-    find_prop, find_prop_value, get_attr_name, get_attr_value, get_tag_name, is_built_in,
-    is_component, is_dynamic, is_namespaced_attr, is_svg_element,
+    children_attr_child, classify_tag, find_prop, find_prop_value, get_attr_name, get_attr_value, get_tag_name,
+    is_built_in, is_component, is_dynamic, is_namespaced_attr, is_svg_element, needs_px_suffix,
+    TagKind,
 };
 pub use constants::*;
 pub use expression::{
-    escape_html, expr_to_string, get_children_callback, stmt_to_string, to_event_name,
+    escape_html, evaluate_string, expr_to_string, get_children_callback, stmt_to_string, to_event_name,
     trim_whitespace,
 };
+pub use metadata::{ChunkMetadata, SharedTemplate, TransformMetadata};
 pub use options::*;
+pub use visit::{walk_child, walk_children, walk_element, walk_fragment, JsxVisitor, VisitFlow};
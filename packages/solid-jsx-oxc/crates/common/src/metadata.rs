@@ -0,0 +1,142 @@
+//! What transforms report besides the generated code
+//!
+//! Each module's [`TransformMetadata`] lists the templates, delegated events
+//! and runtime helpers of its output. A bundler plugin merges the metadata of
+//! the modules in a chunk into a [`ChunkMetadata`] to hoist templates that
+//! several modules share, and to emit one `delegateEvents` call per chunk.
+
+use indexmap::{IndexMap, IndexSet};
+
+/// What a transform reports about the file besides the generated code
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TransformMetadata {
+    /// Events passed to `delegateEvents`, in order of first use
+    pub delegated_events: Vec<String>,
+    /// HTML of each hoisted `template()` call, in declaration order
+    pub templates: Vec<String>,
+    /// Runtime helpers the output uses from the module name, in order of
+    /// first use
+    pub helpers: Vec<String>,
+}
+
+/// A template and the modules whose output contains it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SharedTemplate<'m> {
+    pub html: &'m str,
+    /// Module ids, in the order the modules were added
+    pub modules: Vec<&'m str>,
+}
+
+/// Metadata of several modules, merged
+#[derive(Debug, Default, Clone)]
+pub struct ChunkMetadata {
+    delegated_events: IndexSet<String>,
+    helpers: IndexSet<String>,
+    /// Template HTML to the ids of the modules using it
+    templates: IndexMap<String, IndexSet<String>>,
+}
+
+impl ChunkMetadata {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Merge the metadata of the module `id`
+    pub fn add(&mut self, id: &str, metadata: &TransformMetadata) {
+        self.delegated_events
+            .extend(metadata.delegated_events.iter().cloned());
+        self.helpers.extend(metadata.helpers.iter().cloned());
+        for html in &metadata.templates {
+            self.templates
+                .entry(html.clone())
+                .or_default()
+                .insert(id.to_string());
+        }
+    }
+
+    /// Every module's delegated events, in order of first use; one
+    /// `delegateEvents` call with these covers the chunk
+    pub fn delegated_events(&self) -> impl Iterator<Item = &str> {
+        self.delegated_events.iter().map(String::as_str)
+    }
+
+    /// Every module's runtime helpers, in order of first use
+    pub fn helpers(&self) -> impl Iterator<Item = &str> {
+        self.helpers.iter().map(String::as_str)
+    }
+
+    /// Every distinct template, in order of first use
+    pub fn templates(&self) -> impl Iterator<Item = SharedTemplate<'_>> {
+        self.templates.iter().map(|(html, modules)| SharedTemplate {
+            html,
+            modules: modules.iter().map(String::as_str).collect(),
+        })
+    }
+
+    /// The templates used by more than one module, which a bundler can hoist
+    /// into a shared module
+    pub fn shared_templates(&self) -> impl Iterator<Item = SharedTemplate<'_>> {
+        self.templates()
+            .filter(|template| template.modules.len() > 1)
+    }
+}
+
+impl<'m> FromIterator<(&'m str, &'m TransformMetadata)> for ChunkMetadata {
+    fn from_iter<I: IntoIterator<Item = (&'m str, &'m TransformMetadata)>>(modules: I) -> Self {
+        let mut chunk = Self::new();
+        for (id, metadata) in modules {
+            chunk.add(id, metadata);
+        }
+        chunk
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata(
+        delegated_events: &[&str],
+        templates: &[&str],
+        helpers: &[&str],
+    ) -> TransformMetadata {
+        let strings = |items: &[&str]| items.iter().map(|s| s.to_string()).collect();
+        TransformMetadata {
+            delegated_events: strings(delegated_events),
+            templates: strings(templates),
+            helpers: strings(helpers),
+        }
+    }
+
+    #[test]
+    fn test_chunk_metadata() {
+        let a = metadata(
+            &["click"],
+            &["<div>a</div>", "<p></p>"],
+            &["template", "insert"],
+        );
+        let b = metadata(
+            &["input", "click"],
+            &["<p></p>"],
+            &["template", "delegateEvents"],
+        );
+        let chunk: ChunkMetadata = [("a.tsx", &a), ("b.tsx", &b)].into_iter().collect();
+
+        assert_eq!(
+            chunk.delegated_events().collect::<Vec<_>>(),
+            ["click", "input"]
+        );
+        assert_eq!(
+            chunk.helpers().collect::<Vec<_>>(),
+            ["template", "insert", "delegateEvents"]
+        );
+        assert_eq!(chunk.templates().count(), 2);
+        assert_eq!(
+            chunk.shared_templates().collect::<Vec<_>>(),
+            [SharedTemplate {
+                html: "<p></p>",
+                modules: vec!["a.tsx", "b.tsx"],
+            }]
+        );
+    }
+}
@@ -3,9 +3,10 @@
 use oxc_span::SourceType;
 use std::cell::RefCell;
 use std::collections::HashSet;
+use std::path::Path;
 
 /// Configuration options for the JSX transform
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct TransformOptions<'a> {
     /// The module to import runtime helpers from
     pub module_name: &'a str,
@@ -25,12 +26,20 @@ pub struct TransformOptions<'a> {
     /// Whether to wrap conditionals
     pub wrap_conditionals: bool,
 
+    /// Whether to leave off the closing tags that end a DOM template, which
+    /// the browser adds back when parsing it
+    pub omit_last_closing_tag: bool,
+
     /// Whether to pass context to custom elements
     pub context_to_custom_elements: bool,
 
     /// Built-in components (For, Show, etc.)
     pub built_ins: Vec<&'a str>,
 
+    /// Additional tag names to treat as custom elements, on top of
+    /// any hyphenated tag name
+    pub custom_elements: Vec<&'a str>,
+
     /// Effect wrapper function name
     pub effect_wrapper: &'a str,
 
@@ -40,15 +49,28 @@ pub struct TransformOptions<'a> {
     /// Source filename
     pub filename: &'a str,
 
-    /// Source type (tsx, jsx, etc.)
+    /// Source type of files whose extension is neither one OXC knows nor in
+    /// `custom_extensions`
     pub source_type: SourceType,
 
+    /// Extensions, without the dot, parsed as the given source type, such as
+    /// `("solid", SourceType::tsx())`; they take precedence over the
+    /// extensions OXC knows
+    pub custom_extensions: Vec<(&'a str, SourceType)>,
+
     /// Whether to generate source maps
     pub source_map: bool,
 
     /// Static marker comment
     pub static_marker: &'a str,
 
+    /// Whether to register exported components with solid-refresh for hot
+    /// reload (ignored for SSR output)
+    pub hmr: bool,
+
+    /// HMR API the solid-refresh glue talks to
+    pub hmr_bundler: HmrBundler,
+
     /// Collected templates
     pub templates: RefCell<Vec<(String, bool)>>,
 
@@ -67,6 +89,63 @@ pub enum GenerateMode {
     Universal,
 }
 
+impl GenerateMode {
+    /// Parse the `generate` option
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "dom" => Some(Self::Dom),
+            "ssr" => Some(Self::Ssr),
+            "universal" => Some(Self::Universal),
+            _ => None,
+        }
+    }
+}
+
+/// The HMR API of a bundler, as solid-refresh names it
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum HmrBundler {
+    /// `import.meta.hot`, accepted statically as Vite requires
+    #[default]
+    Vite,
+    /// `import.meta.hot`
+    Esm,
+    /// `module.hot`, as in webpack 4 and Parcel
+    Standard,
+    /// `import.meta.webpackHot`, as in webpack 5 and Rspack
+    Webpack5,
+}
+
+impl HmrBundler {
+    /// Parse solid-refresh's name of a bundler
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "vite" => Some(Self::Vite),
+            "esm" => Some(Self::Esm),
+            "standard" => Some(Self::Standard),
+            "webpack5" => Some(Self::Webpack5),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Vite => "vite",
+            Self::Esm => "esm",
+            Self::Standard => "standard",
+            Self::Webpack5 => "webpack5",
+        }
+    }
+
+    /// The expression holding the hot module API
+    pub fn hot_api(self) -> &'static str {
+        match self {
+            Self::Vite | Self::Esm => "import.meta.hot",
+            Self::Standard => "module.hot",
+            Self::Webpack5 => "import.meta.webpackHot",
+        }
+    }
+}
+
 impl<'a> TransformOptions<'a> {
     pub fn solid_defaults() -> Self {
         Self {
@@ -76,6 +155,7 @@ impl<'a> TransformOptions<'a> {
             delegate_events: true,
             delegated_events: vec![],
             wrap_conditionals: true,
+            omit_last_closing_tag: false,
             context_to_custom_elements: true,
             built_ins: vec![
                 "For",
@@ -89,18 +169,41 @@ impl<'a> TransformOptions<'a> {
                 "Dynamic",
                 "ErrorBoundary",
             ],
+            custom_elements: vec![],
             effect_wrapper: "effect",
             memo_wrapper: "memo",
             filename: "input.jsx",
             source_type: SourceType::tsx(),
+            custom_extensions: vec![],
             source_map: false,
             static_marker: "@once",
+            hmr: false,
+            hmr_bundler: HmrBundler::Vite,
             templates: RefCell::new(vec![]),
             helpers: RefCell::new(HashSet::new()),
             delegates: RefCell::new(HashSet::new()),
         }
     }
 
+    /// Source type of the file at `filename`: from `custom_extensions`, then
+    /// from the extension (.tsx, .jsx, .ts, .mts, .cts, .js, .mjs, .cjs), then
+    /// `source_type`
+    pub fn source_type_of(&self, filename: &str) -> SourceType {
+        let extension = Path::new(filename)
+            .extension()
+            .and_then(|extension| extension.to_str());
+        if let Some(extension) = extension {
+            if let Some((_, source_type)) = self
+                .custom_extensions
+                .iter()
+                .find(|(custom, _)| custom.trim_start_matches('.') == extension)
+            {
+                return *source_type;
+            }
+        }
+        SourceType::from_path(filename).unwrap_or(self.source_type)
+    }
+
     /// Register a helper import
     pub fn register_helper(&self, name: &str) {
         self.helpers.borrow_mut().insert(name.to_string());
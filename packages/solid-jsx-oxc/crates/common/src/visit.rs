@@ -0,0 +1,276 @@
+//! Reusable JSX tree walker
+//!
+//! Generators and lint rules frequently need to walk a JSX subtree (elements,
+//! their attributes, children and nested fragments). `JsxVisitor` provides
+//! enter/leave hooks for each of these so callers only implement the hooks
+//! they care about and the traversal order stays consistent everywhere.
+//!
+//! The walker only follows the JSX tree itself: JSX nested inside expression
+//! containers (e.g. `{cond && <div />}`) is reported via the child hooks but
+//! not descended into.
+
+use oxc_ast::ast::{JSXAttributeItem, JSXChild, JSXElement, JSXFragment};
+
+/// Controls how the walker proceeds after an `enter_*` hook
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VisitFlow {
+    /// Keep walking into this node
+    #[default]
+    Continue,
+    /// Don't descend into this node, but keep walking its siblings.
+    /// The matching `leave_*` hook is still called.
+    SkipChildren,
+    /// Abort the whole walk
+    Stop,
+}
+
+/// Visitor over a JSX tree with enter/leave hooks
+///
+/// All hooks default to no-ops that continue the walk.
+#[allow(unused_variables)]
+pub trait JsxVisitor<'a> {
+    fn enter_element(&mut self, element: &JSXElement<'a>) -> VisitFlow {
+        VisitFlow::Continue
+    }
+
+    fn leave_element(&mut self, element: &JSXElement<'a>) {}
+
+    fn enter_fragment(&mut self, fragment: &JSXFragment<'a>) -> VisitFlow {
+        VisitFlow::Continue
+    }
+
+    fn leave_fragment(&mut self, fragment: &JSXFragment<'a>) {}
+
+    /// Called for every attribute (including spreads) of an element,
+    /// before its children are visited
+    fn enter_attribute(&mut self, attr: &JSXAttributeItem<'a>) -> VisitFlow {
+        VisitFlow::Continue
+    }
+
+    fn leave_attribute(&mut self, attr: &JSXAttributeItem<'a>) {}
+
+    /// Called for every child, before the child's own element/fragment hooks
+    fn enter_child(&mut self, child: &JSXChild<'a>) -> VisitFlow {
+        VisitFlow::Continue
+    }
+
+    fn leave_child(&mut self, child: &JSXChild<'a>) {}
+}
+
+/// Walk a JSX element: attributes first, then children.
+/// Returns `false` if the walk was stopped.
+pub fn walk_element<'a, V: JsxVisitor<'a> + ?Sized>(
+    visitor: &mut V,
+    element: &JSXElement<'a>,
+) -> bool {
+    match visitor.enter_element(element) {
+        VisitFlow::Stop => return false,
+        VisitFlow::SkipChildren => {
+            visitor.leave_element(element);
+            return true;
+        }
+        VisitFlow::Continue => {}
+    }
+
+    for attr in &element.opening_element.attributes {
+        match visitor.enter_attribute(attr) {
+            VisitFlow::Stop => return false,
+            VisitFlow::SkipChildren | VisitFlow::Continue => visitor.leave_attribute(attr),
+        }
+    }
+
+    if !walk_children(visitor, &element.children) {
+        return false;
+    }
+
+    visitor.leave_element(element);
+    true
+}
+
+/// Walk a JSX fragment's children.
+/// Returns `false` if the walk was stopped.
+pub fn walk_fragment<'a, V: JsxVisitor<'a> + ?Sized>(
+    visitor: &mut V,
+    fragment: &JSXFragment<'a>,
+) -> bool {
+    match visitor.enter_fragment(fragment) {
+        VisitFlow::Stop => return false,
+        VisitFlow::SkipChildren => {
+            visitor.leave_fragment(fragment);
+            return true;
+        }
+        VisitFlow::Continue => {}
+    }
+
+    if !walk_children(visitor, &fragment.children) {
+        return false;
+    }
+
+    visitor.leave_fragment(fragment);
+    true
+}
+
+/// Walk a list of JSX children, descending into nested elements and fragments.
+/// Returns `false` if the walk was stopped.
+pub fn walk_children<'a, V: JsxVisitor<'a> + ?Sized>(
+    visitor: &mut V,
+    children: &[JSXChild<'a>],
+) -> bool {
+    for child in children {
+        if !walk_child(visitor, child) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Walk a single JSX child.
+/// Returns `false` if the walk was stopped.
+pub fn walk_child<'a, V: JsxVisitor<'a> + ?Sized>(visitor: &mut V, child: &JSXChild<'a>) -> bool {
+    match visitor.enter_child(child) {
+        VisitFlow::Stop => return false,
+        VisitFlow::SkipChildren => {
+            visitor.leave_child(child);
+            return true;
+        }
+        VisitFlow::Continue => {}
+    }
+
+    let keep_going = match child {
+        JSXChild::Element(element) => walk_element(visitor, element),
+        JSXChild::Fragment(fragment) => walk_fragment(visitor, fragment),
+        _ => true,
+    };
+    if !keep_going {
+        return false;
+    }
+
+    visitor.leave_child(child);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use crate::get_tag_name;
+
+    #[derive(Default)]
+    struct Recorder {
+        events: Vec<String>,
+        skip: Option<&'static str>,
+        stop: Option<&'static str>,
+    }
+
+    impl<'a> JsxVisitor<'a> for Recorder {
+        fn enter_element(&mut self, element: &JSXElement<'a>) -> VisitFlow {
+            let tag = get_tag_name(element);
+            self.events.push(format!("enter {}", tag));
+            if self.stop == Some(tag.as_ref()) {
+                VisitFlow::Stop
+            } else if self.skip == Some(tag.as_ref()) {
+                VisitFlow::SkipChildren
+            } else {
+                VisitFlow::Continue
+            }
+        }
+
+        fn leave_element(&mut self, element: &JSXElement<'a>) {
+            self.events.push(format!("leave {}", get_tag_name(element)));
+        }
+
+        fn enter_fragment(&mut self, _fragment: &JSXFragment<'a>) -> VisitFlow {
+            self.events.push("enter <>".to_string());
+            VisitFlow::Continue
+        }
+
+        fn leave_fragment(&mut self, _fragment: &JSXFragment<'a>) {
+            self.events.push("leave <>".to_string());
+        }
+
+        fn enter_attribute(&mut self, attr: &JSXAttributeItem<'a>) -> VisitFlow {
+            if let JSXAttributeItem::Attribute(attr) = attr {
+                self.events
+                    .push(format!("attr {}", crate::get_attr_name(&attr.name)));
+            }
+            VisitFlow::Continue
+        }
+    }
+
+    fn record(source: &str, mut recorder: Recorder) -> Vec<String> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        match &stmt.expression {
+            Expression::JSXElement(element) => {
+                walk_element(&mut recorder, element);
+            }
+            Expression::JSXFragment(fragment) => {
+                walk_fragment(&mut recorder, fragment);
+            }
+            _ => panic!("expected JSX"),
+        }
+        recorder.events
+    }
+
+    #[test]
+    fn test_walk_order() {
+        let events = record(
+            r#"<div id="a"><span class="b" /><>text<p /></></div>"#,
+            Recorder::default(),
+        );
+        assert_eq!(
+            events,
+            [
+                "enter div",
+                "attr id",
+                "enter span",
+                "attr class",
+                "leave span",
+                "enter <>",
+                "enter p",
+                "leave p",
+                "leave <>",
+                "leave div",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_skip_children() {
+        let recorder = Recorder {
+            skip: Some("ul"),
+            ..Recorder::default()
+        };
+        let events = record(r#"<div><ul><li /></ul><p /></div>"#, recorder);
+        assert_eq!(
+            events,
+            [
+                "enter div",
+                "enter ul",
+                "leave ul",
+                "enter p",
+                "leave p",
+                "leave div"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stop() {
+        let recorder = Recorder {
+            stop: Some("ul"),
+            ..Recorder::default()
+        };
+        let events = record(r#"<div><ul><li /></ul><p /></div>"#, recorder);
+        assert_eq!(events, ["enter div", "enter ul"]);
+    }
+}
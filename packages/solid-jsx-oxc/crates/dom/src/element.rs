@@ -1,30 +1,35 @@
 //! Native element transform
 //! Handles <div>, <span>, etc. -> template + effects
 
+use std::fmt::Write;
+
 use oxc_allocator::CloneIn;
 use oxc_ast::ast::{
     Argument, AssignmentTarget, Expression, FormalParameterKind, JSXAttribute, JSXAttributeItem,
-    JSXAttributeValue, JSXElement, Statement,
+    JSXAttributeValue, JSXChild, JSXElement, Statement,
 };
 use oxc_ast::AstBuilder;
 use oxc_ast::NONE;
-use oxc_span::{Span, SPAN};
+use oxc_span::{Atom, Span, SPAN};
 use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, UnaryOperator};
 use oxc_syntax::symbol::SymbolFlags;
 use oxc_traverse::TraverseCtx;
+use smallvec::SmallVec;
 
 use common::{
-    constants::{ALIASES, DELEGATED_EVENTS, VOID_ELEMENTS},
-    expression::{escape_html, to_event_name},
-    get_attr_name, is_component, is_dynamic, is_namespaced_attr, is_svg_element, TransformOptions,
+    attr::{plan_attribute, AttrKind, AttrPlan, PlannedAttr},
+    children_attr_child, classify_tag,
+    constants::VOID_ELEMENTS,
+    expression::escape_html,
+    is_dynamic, is_namespaced_attr, needs_px_suffix, walk_children, JsxVisitor,
+    TagKind, TransformOptions, VisitFlow,
 };
 
 use crate::ir::{BlockContext, ChildTransformer, Declaration, DynamicBinding, TransformResult};
-use crate::transform::TransformInfo;
+use crate::transform::{PathStep, TransformInfo};
 
-fn ident_expr<'a>(ast: AstBuilder<'a>, span: Span, name: &str) -> Expression<'a> {
-    let _ = span;
-    ast.expression_identifier(SPAN, ast.allocator.alloc_str(name))
+fn ident_expr<'a>(ast: AstBuilder<'a>, span: Span, name: impl Into<Atom<'a>>) -> Expression<'a> {
+    ast.expression_identifier(span, name.into())
 }
 
 fn static_member<'a>(
@@ -33,10 +38,9 @@ fn static_member<'a>(
     object: Expression<'a>,
     property: &str,
 ) -> Expression<'a> {
-    let _ = span;
-    let prop = ast.identifier_name(SPAN, ast.allocator.alloc_str(property));
+    let prop = ast.identifier_name(span, ast.allocator.alloc_str(property));
     Expression::StaticMemberExpression(
-        ast.alloc_static_member_expression(SPAN, object, prop, false),
+        ast.alloc_static_member_expression(span, object, prop, false),
     )
 }
 
@@ -46,13 +50,12 @@ fn call_expr<'a>(
     callee: Expression<'a>,
     args: impl IntoIterator<Item = Expression<'a>>,
 ) -> Expression<'a> {
-    let _ = span;
     let mut arguments = ast.vec();
     for arg in args {
         arguments.push(Argument::from(arg));
     }
     ast.expression_call(
-        SPAN,
+        span,
         callee,
         None::<oxc_ast::ast::TSTypeParameterInstantiation<'a>>,
         arguments,
@@ -61,35 +64,34 @@ fn call_expr<'a>(
 }
 
 fn bool_cast_expr<'a>(ast: AstBuilder<'a>, span: Span, expr: Expression<'a>) -> Expression<'a> {
-    let _ = span;
-    let not_expr = ast.expression_unary(SPAN, UnaryOperator::LogicalNot, expr);
-    ast.expression_unary(SPAN, UnaryOperator::LogicalNot, not_expr)
+    let not_expr = ast.expression_unary(span, UnaryOperator::LogicalNot, expr);
+    ast.expression_unary(span, UnaryOperator::LogicalNot, not_expr)
 }
 
 fn class_toggle_expr<'a>(
     ast: AstBuilder<'a>,
     span: Span,
-    elem_id: &str,
+    elem_id: Atom<'a>,
     class_name: &str,
     value: Expression<'a>,
 ) -> Expression<'a> {
     let elem = ident_expr(ast, span, elem_id);
     let class_list = static_member(ast, span, elem, "classList");
     let toggle = static_member(ast, span, class_list, "toggle");
-    let class_name_lit = ast.expression_string_literal(SPAN, ast.allocator.alloc_str(class_name), None);
+    let class_name_lit = ast.expression_string_literal(span, ast.allocator.alloc_str(class_name), None);
     call_expr(ast, span, toggle, [class_name_lit, value])
 }
 
 fn set_style_property_expr<'a>(
     ast: AstBuilder<'a>,
     span: Span,
-    elem_id: &str,
+    elem_id: Atom<'a>,
     prop_name: &str,
     value: Expression<'a>,
 ) -> Expression<'a> {
     let callee = ident_expr(ast, span, "setStyleProperty");
     let elem = ident_expr(ast, span, elem_id);
-    let prop_name_lit = ast.expression_string_literal(SPAN, ast.allocator.alloc_str(prop_name), None);
+    let prop_name_lit = ast.expression_string_literal(span, ast.allocator.alloc_str(prop_name), None);
     call_expr(ast, span, callee, [elem, prop_name_lit, value])
 }
 
@@ -98,19 +100,18 @@ fn arrow_zero_params_return_expr<'a>(
     span: Span,
     expr: Expression<'a>,
 ) -> Expression<'a> {
-    let _ = span;
     let params = ast.alloc_formal_parameters(
-        SPAN,
+        span,
         FormalParameterKind::ArrowFormalParameters,
         ast.vec(),
         NONE,
     );
     let mut statements = ast.vec_with_capacity(1);
     statements.push(Statement::ExpressionStatement(
-        ast.alloc_expression_statement(SPAN, expr),
+        ast.alloc_expression_statement(span, expr),
     ));
-    let body = ast.alloc_function_body(SPAN, ast.vec(), statements);
-    ast.expression_arrow_function(SPAN, true, false, NONE, params, NONE, body)
+    let body = ast.alloc_function_body(span, ast.vec(), statements);
+    ast.expression_arrow_function(span, true, false, NONE, params, NONE, body)
 }
 
 fn expression_to_assignment_target<'a>(expr: Expression<'a>) -> Option<AssignmentTarget<'a>> {
@@ -128,9 +129,7 @@ fn expression_to_assignment_target<'a>(expr: Expression<'a>) -> Option<Assignmen
         Expression::TSSatisfiesExpression(e) => {
             expression_to_assignment_target(e.unbox().expression)
         }
-        Expression::TSNonNullExpression(e) => {
-            expression_to_assignment_target(e.unbox().expression)
-        }
+        Expression::TSNonNullExpression(e) => expression_to_assignment_target(e.unbox().expression),
         Expression::TSTypeAssertion(e) => expression_to_assignment_target(e.unbox().expression),
         _ => None,
     }
@@ -140,59 +139,92 @@ fn expression_to_assignment_target<'a>(expr: Expression<'a>) -> Option<Assignmen
 pub fn transform_element<'a, 'b>(
     element: &JSXElement<'a>,
     tag_name: &str,
-    info: &TransformInfo,
+    info: &TransformInfo<'a>,
+    context: &BlockContext<'a>,
+    options: &TransformOptions<'a>,
+    transform_child: ChildTransformer<'a, 'b>,
+    ctx: &TraverseCtx<'a, ()>,
+) -> TransformResult<'a> {
+    // Sized for both tags and some markup per child
+    let template = String::with_capacity(2 * tag_name.len() + 5 + 16 * element.children.len());
+    transform_element_into(
+        element,
+        tag_name,
+        info,
+        context,
+        options,
+        transform_child,
+        ctx,
+        template,
+    )
+}
+
+/// [`transform_element`], appending the markup to `template` rather than a
+/// new string. Nested elements pass the template of their parent down and get
+/// it back in the result, so a whole tree is written into one buffer.
+#[allow(clippy::too_many_arguments)]
+fn transform_element_into<'a, 'b>(
+    element: &JSXElement<'a>,
+    tag_name: &str,
+    info: &TransformInfo<'a>,
     context: &BlockContext<'a>,
     options: &TransformOptions<'a>,
     transform_child: ChildTransformer<'a, 'b>,
     ctx: &TraverseCtx<'a, ()>,
+    template: String,
 ) -> TransformResult<'a> {
     let ast = context.ast();
-    let is_svg = is_svg_element(tag_name);
+    let kind = classify_tag(tag_name, options);
+    let is_svg = kind == TagKind::Svg;
     let is_void = VOID_ELEMENTS.contains(tag_name);
-    let is_custom_element = tag_name.contains('-');
+    let is_custom_element = kind == TagKind::CustomElement;
+    // The last element of a template may leave its closing tag off, and so
+    // may its last element down the tree until one is closed
+    let closed =
+        is_void || !options.omit_last_closing_tag || !info.last_element || info.parent_closed;
 
     let mut result = TransformResult {
         span: element.span,
-        tag_name: Some(tag_name.to_string()),
+        template,
+        tag_name: Some(context.atom(tag_name)),
         is_svg,
         has_custom_element: is_custom_element,
         ..Default::default()
     };
 
     // Check if this element needs runtime access (dynamic attributes, refs, events)
-    let needs_runtime_access = element_needs_runtime_access(element);
+    let needs_runtime_access = element_needs_runtime_access(element, options);
 
     // Generate element ID if needed
     if !info.skip_id && (info.top_level || needs_runtime_access) {
         let elem_id = context.generate_uid("el$");
-        result.id = Some(elem_id.clone());
+        result.id = Some(elem_id);
 
         // If we have a path, we need to walk to this element
         if !info.path.is_empty() {
             if let Some(root_id) = &info.root_id {
                 result.declarations.push(Declaration {
-                    name: elem_id.clone(),
+                    span: element.span,
+                    name: elem_id,
                     init: info
                         .path
                         .iter()
-                        .fold(ident_expr(ast, element.span, root_id), |acc, step| {
-                            static_member(ast, element.span, acc, step)
+                        .fold(ident_expr(ast, element.span, *root_id), |acc, step| {
+                            static_member(ast, element.span, acc, step.as_str())
                         }),
                 });
             }
         }
     }
 
-    // Start building template
-    result.template = format!("<{}", tag_name);
-    result.template_with_closing_tags = result.template.clone();
+    result.template.push('<');
+    result.template.push_str(tag_name);
 
     // Transform attributes
     transform_attributes(element, &mut result, context, options, ctx);
 
     // Close opening tag
     result.template.push('>');
-    result.template_with_closing_tags.push('>');
 
     // Transform children (if not void element)
     if !is_void {
@@ -200,13 +232,14 @@ pub fn transform_element<'a, 'b>(
         // If this element has an ID, it becomes the new root for children
         // and children's paths reset to be relative to this element
         let child_info = TransformInfo {
-            root_id: result.id.clone().or_else(|| info.root_id.clone()),
+            root_id: result.id.or(info.root_id),
             path: if result.id.is_some() {
-                vec![]
+                SmallVec::new()
             } else {
                 info.path.clone()
             },
             top_level: false,
+            parent_closed: info.parent_closed || closed,
             ..info.clone()
         };
         transform_children(
@@ -220,35 +253,49 @@ pub fn transform_element<'a, 'b>(
         );
 
         // Close tag
-        result.template.push_str(&format!("</{}>", tag_name));
-        result
+        if closed {
+            let _ = write!(result.template, "</{}>", tag_name);
+        }
+    }
+
+    if options.omit_last_closing_tag && (!closed || info.top_level) {
+        // A child left open is the last thing in the template, so its full
+        // template is ours so far
+        let mut full = result
             .template_with_closing_tags
-            .push_str(&format!("</{}>", tag_name));
+            .take()
+            .unwrap_or_else(|| result.template.clone());
+        if !closed {
+            let _ = write!(full, "</{}>", tag_name);
+        }
+        result.template_with_closing_tags = Some(full);
     }
 
     result
 }
 
 /// Check if an element needs runtime access
-fn element_needs_runtime_access(element: &JSXElement) -> bool {
+fn element_needs_runtime_access(element: &JSXElement, options: &TransformOptions) -> bool {
+    let is_svg = classify_tag(&common::get_tag_name(element), options) == TagKind::Svg;
     // Check attributes
-    for attr in &element.opening_element.attributes {
-        match attr {
+    for item in &element.opening_element.attributes {
+        match item {
             JSXAttributeItem::Attribute(attr) => {
                 // Namespaced attributes like on:click or use:directive always need access
                 if is_namespaced_attr(&attr.name) {
                     return true;
                 }
-                let key = get_attr_name(&attr.name);
 
-                // ref and inner content setters need access
-                if key == "ref" || key == "innerHTML" || key == "textContent" || key == "innerText"
-                {
-                    return true;
-                }
-
-                // Event handlers need access
-                if key.starts_with("on") && key.len() > 2 {
+                // Refs, event handlers and inner content setters need access,
+                // whatever their value
+                let planned = plan_attribute(item, is_svg, options);
+                if !matches!(
+                    planned.kind,
+                    AttrKind::Static { .. }
+                        | AttrKind::Property { forced: false, .. }
+                        | AttrKind::SetAttribute { forced: false, .. }
+                        | AttrKind::Style
+                ) {
                     return true;
                 }
 
@@ -267,34 +314,154 @@ fn element_needs_runtime_access(element: &JSXElement) -> bool {
 
     // Check children for components or dynamic expressions
     // If any child is a component, we need an ID for insert() calls
-    fn children_need_runtime_access<'a>(children: &[oxc_ast::ast::JSXChild<'a>]) -> bool {
-        for child in children {
-            match child {
-                oxc_ast::ast::JSXChild::Element(child_elem) => {
-                    let child_tag = common::get_tag_name(child_elem);
-                    if is_component(&child_tag) {
-                        return true;
-                    }
-                }
-                oxc_ast::ast::JSXChild::ExpressionContainer(_) => {
-                    return true;
-                }
-                oxc_ast::ast::JSXChild::Fragment(fragment) => {
-                    if children_need_runtime_access(&fragment.children) {
-                        return true;
-                    }
-                }
-                _ => {}
+    let mut finder = RuntimeChildFinder {
+        options,
+        found: false,
+    };
+    walk_children(&mut finder, &element.children);
+    finder.found
+}
+
+/// Whether `element` and everything in it is plain markup: native elements
+/// with literal attributes, and text. Such a subtree needs no ids, paths or
+/// effects, so it is written straight into the template.
+fn is_static_subtree(
+    element: &JSXElement,
+    context: &BlockContext,
+    options: &TransformOptions,
+) -> bool {
+    context.is_static_subtree(element.span, || {
+        let kind = classify_tag(&common::get_tag_name(element), options);
+        if !matches!(kind, TagKind::Html | TagKind::Svg | TagKind::MathMl) {
+            return false;
+        }
+        // The children are checked below, once each, rather than walked
+        // again by `element_needs_runtime_access` at every level
+        let attrs_static = element.opening_element.attributes.iter().all(|item| {
+            let planned = plan_attribute(item, kind == TagKind::Svg, options);
+            let JSXAttributeItem::Attribute(attr) = item else {
+                return false;
+            };
+            !is_namespaced_attr(&attr.name)
+                && matches!(planned.kind, AttrKind::Static { .. } | AttrKind::Style)
+                && matches!(attr.value, None | Some(JSXAttributeValue::StringLiteral(_)))
+        });
+        attrs_static
+            && element.children.iter().all(|child| match child {
+                JSXChild::Text(_) => true,
+                JSXChild::Element(child) => is_static_subtree(child, context, options),
+                _ => false,
+            })
+    })
+}
+
+/// The span of the last child element, when nothing follows it in the
+/// template
+fn last_element_span(children: &[JSXChild<'_>], options: &TransformOptions<'_>) -> Option<Span> {
+    for child in children.iter().rev() {
+        match child {
+            JSXChild::Text(text) if common::expression::trim_whitespace(&text.value).is_empty() => {
             }
+            JSXChild::ExpressionContainer(container)
+                if container.expression.as_expression().is_none() => {}
+            JSXChild::Fragment(fragment) => {
+                return last_element_span(&fragment.children, options);
+            }
+            JSXChild::Element(element)
+                if !classify_tag(&common::get_tag_name(element), options).is_component() =>
+            {
+                return Some(element.span);
+            }
+            _ => return None,
         }
-        false
     }
+    None
+}
 
-    if children_need_runtime_access(&element.children) {
-        return true;
+/// Append the HTML of a subtree that [`is_static_subtree`] accepted, as
+/// [`transform_element`] would build it. With `open_tags`, the closing tags
+/// that end the subtree go there instead.
+fn write_static_subtree(
+    element: &JSXElement,
+    options: &TransformOptions,
+    template: &mut String,
+    mut open_tags: Option<&mut String>,
+) {
+    let tag_name = common::get_tag_name(element);
+    let is_svg = classify_tag(&tag_name, options) == TagKind::Svg;
+    template.push('<');
+    template.push_str(&tag_name);
+    for item in &element.opening_element.attributes {
+        let JSXAttributeItem::Attribute(attr) = item else {
+            continue;
+        };
+        let planned = plan_attribute(item, is_svg, options);
+        match (&planned.kind, &attr.value) {
+            (AttrKind::Static { name }, Some(JSXAttributeValue::StringLiteral(lit))) => {
+                let _ = write!(template, " {}=\"{}\"", name, escape_html(&lit.value, true));
+            }
+            (AttrKind::Style, Some(JSXAttributeValue::StringLiteral(lit))) => {
+                let _ = write!(template, " style=\"{}\"", escape_html(&lit.value, true));
+            }
+            (AttrKind::Static { .. }, None) => {
+                template.push(' ');
+                template.push_str(&planned.key);
+            }
+            _ => {}
+        }
+    }
+    template.push('>');
+    if VOID_ELEMENTS.contains(&tag_name) {
+        return;
+    }
+    let last_element = open_tags
+        .is_some()
+        .then(|| last_element_span(&element.children, options))
+        .flatten();
+    for child in &element.children {
+        match child {
+            JSXChild::Text(text) => {
+                let content = common::expression::trim_whitespace(&text.value);
+                template.push_str(&escape_html(&content, false));
+            }
+            JSXChild::Element(child) => {
+                let child_open_tags = if last_element == Some(child.span) {
+                    open_tags.as_deref_mut()
+                } else {
+                    None
+                };
+                write_static_subtree(child, options, template, child_open_tags);
+            }
+            _ => {}
+        }
+    }
+    let _ = write!(open_tags.unwrap_or(template), "</{}>", tag_name);
+}
+
+/// Looks for children that need runtime insertion (components or expressions),
+/// looking through fragments but not into nested native elements
+struct RuntimeChildFinder<'o, 'a> {
+    options: &'o TransformOptions<'a>,
+    found: bool,
+}
+
+impl<'a> JsxVisitor<'a> for RuntimeChildFinder<'_, '_> {
+    fn enter_element(&mut self, element: &JSXElement<'a>) -> VisitFlow {
+        if classify_tag(&common::get_tag_name(element), self.options).is_component() {
+            self.found = true;
+            VisitFlow::Stop
+        } else {
+            VisitFlow::SkipChildren
+        }
     }
 
-    false
+    fn enter_child(&mut self, child: &JSXChild<'a>) -> VisitFlow {
+        if matches!(child, JSXChild::ExpressionContainer(_)) {
+            self.found = true;
+            return VisitFlow::Stop;
+        }
+        VisitFlow::Continue
+    }
 }
 
 /// Transform element attributes
@@ -306,18 +473,17 @@ fn transform_attributes<'a>(
     ctx: &TraverseCtx<'a, ()>,
 ) {
     let ast = context.ast();
-    let elem_id = result.id.clone();
+    let elem_id = result.id;
+    let plan = AttrPlan::new(element, result.is_svg, options);
 
-    for attr in &element.opening_element.attributes {
-        match attr {
+    for planned in plan.iter() {
+        match planned.item {
             JSXAttributeItem::Attribute(attr) => {
-                transform_attribute(attr, elem_id.as_deref(), result, context, options, ctx);
+                transform_attribute(attr, planned, elem_id, result, context, ctx);
             }
             JSXAttributeItem::SpreadAttribute(spread) => {
                 // Handle {...props} spread
-                let elem_id = elem_id
-                    .as_deref()
-                    .expect("Spread attributes require an element id");
+                let elem_id = elem_id.expect("Spread attributes require an element id");
                 context.register_helper("spread");
                 let callee = ident_expr(ast, spread.span, "spread");
                 let elem = ident_expr(ast, spread.span, elem_id);
@@ -333,86 +499,93 @@ fn transform_attributes<'a>(
     }
 }
 
-/// Transform a single attribute
+/// Transform a single attribute according to its planned kind
 fn transform_attribute<'a>(
     attr: &JSXAttribute<'a>,
-    elem_id: Option<&str>,
+    planned: &PlannedAttr<'_, 'a>,
+    elem_id: Option<Atom<'a>>,
     result: &mut TransformResult<'a>,
     context: &BlockContext<'a>,
-    options: &TransformOptions<'a>,
     ctx: &TraverseCtx<'a, ()>,
 ) {
-    let key = get_attr_name(&attr.name);
-
-    // Handle different attribute types
-    if key == "ref" {
-        let elem_id = elem_id.expect("ref requires an element id");
-        transform_ref(attr, elem_id, result, context, ctx);
-        return;
-    }
+    let key = planned.key.as_ref();
 
-    if key.starts_with("on") {
-        let elem_id = elem_id.expect("event handlers require an element id");
-        transform_event(attr, &key, elem_id, result, context, options);
-        return;
-    }
-
-    if key.starts_with("use:") {
-        let elem_id = elem_id.expect("directives require an element id");
-        transform_directive(attr, &key, elem_id, result, context);
-        return;
-    }
-
-    // Handle prop: prefix - direct DOM property assignment
-    if key.starts_with("prop:") {
-        let elem_id = elem_id.expect("prop: requires an element id");
-        transform_prop(attr, &key, elem_id, result, context);
-        return;
-    }
-
-    // Handle attr: prefix - force attribute mode
-    if key.starts_with("attr:") {
-        let elem_id = elem_id.expect("attr: requires an element id");
-        transform_attr(attr, &key, elem_id, result, context);
-        return;
-    }
-
-    // Handle class: prefix - classList.toggle() behavior
-    if key.starts_with("class:") {
-        let elem_id = elem_id.expect("class: requires an element id");
-        transform_class_namespace(attr, &key, elem_id, result, context);
-        return;
-    }
-
-    // Handle style: prefix - setStyleProperty() behavior
-    if key.starts_with("style:") {
-        let elem_id = elem_id.expect("style: requires an element id");
-        transform_style_namespace(attr, &key, elem_id, result, context);
-        return;
-    }
-
-    // Handle style attribute specially
-    if key == "style" {
-        transform_style(attr, elem_id, result, context);
-        return;
-    }
-
-    // Handle innerHTML/textContent
-    if key == "innerHTML" || key == "textContent" {
-        let elem_id = elem_id.expect("inner content requires an element id");
-        transform_inner_content(attr, &key, elem_id, result, context);
-        return;
+    match &planned.kind {
+        AttrKind::Ref => {
+            let elem_id = elem_id.expect("ref requires an element id");
+            transform_ref(attr, elem_id, result, context, ctx);
+            return;
+        }
+        AttrKind::Event {
+            name,
+            capture,
+            delegated,
+        } => {
+            let elem_id = elem_id.expect("event handlers require an element id");
+            transform_event(attr, name, *capture, *delegated, elem_id, result, context);
+            return;
+        }
+        AttrKind::Directive { name } => {
+            let elem_id = elem_id.expect("directives require an element id");
+            transform_directive(attr, name, elem_id, result, context);
+            return;
+        }
+        // prop: prefix - direct DOM property assignment
+        AttrKind::Property { name, forced: true } => {
+            let elem_id = elem_id.expect("prop: requires an element id");
+            transform_prop(attr, name, elem_id, result, context);
+            return;
+        }
+        // attr: prefix - force attribute mode
+        AttrKind::SetAttribute { name, forced: true } => {
+            let elem_id = elem_id.expect("attr: requires an element id");
+            transform_attr(attr, name, elem_id, result, context);
+            return;
+        }
+        // class: prefix - classList.toggle() behavior
+        AttrKind::ClassToggle { name } => {
+            let elem_id = elem_id.expect("class: requires an element id");
+            transform_class_namespace(attr, name, elem_id, result, context);
+            return;
+        }
+        // bool: prefix - setBoolAttribute() behavior
+        AttrKind::BoolAttribute { name } => {
+            transform_bool_attribute(attr, planned, name, elem_id, result, context);
+            return;
+        }
+        // style: prefix - setStyleProperty() behavior
+        AttrKind::StyleProperty { name } => {
+            let elem_id = elem_id.expect("style: requires an element id");
+            transform_style_namespace(attr, name, elem_id, result, context);
+            return;
+        }
+        AttrKind::Style => {
+            transform_style(attr, elem_id, result, context);
+            return;
+        }
+        AttrKind::ChildProperty { name } => {
+            let elem_id = elem_id.expect("inner content requires an element id");
+            transform_inner_content(attr, name, elem_id, result, context);
+            return;
+        }
+        // Inserted by transform_children
+        AttrKind::Children => return,
+        AttrKind::Static { .. }
+        | AttrKind::Property { .. }
+        | AttrKind::SetAttribute { .. }
+        | AttrKind::Spread => {}
     }
 
     // Regular attribute
     match &attr.value {
         Some(JSXAttributeValue::StringLiteral(lit)) => {
             // Static string attribute - inline in template
-            let attr_key = ALIASES.get(key.as_str()).copied().unwrap_or(key.as_str());
+            let attr_key = match &planned.kind {
+                AttrKind::Static { name } => name.as_str(),
+                _ => key,
+            };
             let escaped = escape_html(&lit.value, true);
-            result
-                .template
-                .push_str(&format!(" {}=\"{}\"", attr_key, escaped));
+            let _ = write!(result.template, " {}=\"{}\"", attr_key, escaped);
         }
         Some(JSXAttributeValue::ExpressionContainer(container)) => {
             // Dynamic attribute - needs effect
@@ -421,31 +594,34 @@ fn transform_attribute<'a>(
                     // Dynamic - wrap in effect
                     let elem_id = elem_id.expect("dynamic attributes require an element id");
                     result.dynamics.push(DynamicBinding {
-                        elem: elem_id.to_string(),
-                        key: key.clone(),
+                        span: attr.span,
+                        elem: elem_id,
+                        key: context.atom(key),
                         value: context.clone_expr(expr),
                         is_svg: result.is_svg,
                         is_ce: result.has_custom_element,
-                        tag_name: result.tag_name.clone().unwrap_or_default(),
+                        tag_name: result.tag_name.unwrap_or(Atom::from("")),
                     });
                 } else {
                     // Static expression - we need to evaluate it at build time
                     // For now, treat as dynamic to be safe
                     let elem_id = elem_id.expect("expression attributes require an element id");
                     result.dynamics.push(DynamicBinding {
-                        elem: elem_id.to_string(),
-                        key: key.clone(),
+                        span: attr.span,
+                        elem: elem_id,
+                        key: context.atom(key),
                         value: context.clone_expr(expr),
                         is_svg: result.is_svg,
                         is_ce: result.has_custom_element,
-                        tag_name: result.tag_name.clone().unwrap_or_default(),
+                        tag_name: result.tag_name.unwrap_or(Atom::from("")),
                     });
                 }
             }
         }
         None => {
             // Boolean attribute (e.g., disabled)
-            result.template.push_str(&format!(" {}", key));
+            result.template.push(' ');
+            result.template.push_str(key);
         }
         _ => {}
     }
@@ -454,7 +630,7 @@ fn transform_attribute<'a>(
 /// Transform ref attribute
 fn transform_ref<'a>(
     attr: &JSXAttribute<'a>,
-    elem_id: &str,
+    elem_id: Atom<'a>,
     result: &mut TransformResult<'a>,
     context: &BlockContext<'a>,
     ctx: &TraverseCtx<'a, ()>,
@@ -563,72 +739,85 @@ fn peel_identifier_reference<'a, 'b>(
 /// Transform event handler
 fn transform_event<'a>(
     attr: &JSXAttribute<'a>,
-    key: &str,
-    elem_id: &str,
+    event_name: &str,
+    is_capture: bool,
+    should_delegate: bool,
+    elem_id: Atom<'a>,
     result: &mut TransformResult<'a>,
     context: &BlockContext<'a>,
-    options: &TransformOptions<'a>,
 ) {
     let ast = context.ast();
-    // Check for capture mode (onClickCapture -> click with capture=true)
-    let is_capture = key.ends_with("Capture");
-    let base_key = if is_capture {
-        &key[..key.len() - 7] // Remove "Capture" suffix
-    } else {
-        key
-    };
-
-    let event_name = to_event_name(base_key);
 
+    let value = attr.value.as_ref().and_then(|v| match v {
+        JSXAttributeValue::ExpressionContainer(container) => container.expression.as_expression(),
+        _ => None,
+    });
     // Get the handler expression
-    let handler = attr
-        .value
-        .as_ref()
-        .and_then(|v| match v {
-            JSXAttributeValue::ExpressionContainer(container) => {
-                container.expression.as_expression()
-            }
-            _ => None,
-        })
-        .map(|e| context.clone_expr(e))
-        .unwrap_or_else(|| ast.expression_identifier(SPAN, "undefined"));
-
-    // on: prefix forces non-delegation (direct addEventListener)
-    let force_no_delegate = key.starts_with("on:");
-
-    // Capture events cannot be delegated
-    // Check if this event should be delegated
-    let should_delegate = !force_no_delegate
-        && !is_capture
-        && options.delegate_events
-        && (DELEGATED_EVENTS.contains(event_name.as_str())
-            || options.delegated_events.contains(&event_name.as_str()));
+    let handler_of = |value: Option<&Expression<'a>>| {
+        value
+            .map(|e| context.clone_expr(e))
+            .unwrap_or_else(|| ast.expression_identifier(SPAN, "undefined"))
+    };
 
     if should_delegate {
-        context.register_delegate(&event_name);
-        let elem = ident_expr(ast, attr.span, elem_id);
-        let prop = format!("$${}", event_name);
-        let member = static_member(ast, attr.span, elem, &prop);
-        let Some(target) = expression_to_assignment_target(member) else {
-            return;
+        context.register_delegate(event_name);
+        // The delegated handler calls `$$click` with `$$clickData` as its first
+        // argument, so `[handler, data]` is split between the two
+        let (handler, data) = match value {
+            Some(Expression::ArrayExpression(array))
+                if (1..=2).contains(&array.elements.len())
+                    && array.elements.iter().all(|e| e.as_expression().is_some()) =>
+            {
+                let mut parts = array.elements.iter().map(|e| e.as_expression());
+                (
+                    handler_of(parts.next().flatten()),
+                    parts.next().flatten().map(|e| context.clone_expr(e)),
+                )
+            }
+            _ => (handler_of(value), None),
         };
-        result.exprs.push(ast.expression_assignment(
-            SPAN,
-            AssignmentOperator::Assign,
-            target,
-            handler,
+        let prop = format!("$${}", event_name);
+        let data_prop = format!("$${}Data", event_name);
+        for (prop, value) in [(prop.as_str(), Some(handler)), (data_prop.as_str(), data)] {
+            let Some(value) = value else {
+                continue;
+            };
+            let elem = ident_expr(ast, attr.span, elem_id);
+            let member = static_member(ast, attr.span, elem, prop);
+            let Some(target) = expression_to_assignment_target(member) else {
+                return;
+            };
+            result.exprs.push(ast.expression_assignment(
+                attr.span,
+                AssignmentOperator::Assign,
+                target,
+                value,
+            ));
+        }
+    } else if is_capture {
+        // The fourth argument of the `addEventListener` helper means delegation,
+        // so capturing listeners are added directly
+        let elem = ident_expr(ast, attr.span, elem_id);
+        let callee = static_member(ast, attr.span, elem, "addEventListener");
+        let event = ast.expression_string_literal(SPAN, ast.allocator.alloc_str(event_name), None);
+        let capture = ast.expression_boolean_literal(SPAN, true);
+        result.exprs.push(call_expr(
+            ast,
+            attr.span,
+            callee,
+            [event, handler_of(value), capture],
         ));
     } else {
         context.register_helper("addEventListener");
         let callee = ident_expr(ast, attr.span, "addEventListener");
         let elem = ident_expr(ast, attr.span, elem_id);
-        let event = ast.expression_string_literal(SPAN, ast.allocator.alloc_str(&event_name), None);
-        let capture = ast.expression_boolean_literal(SPAN, is_capture);
+        let event = ast.expression_string_literal(SPAN, ast.allocator.alloc_str(event_name), None);
+        let delegate = ast.expression_boolean_literal(SPAN, false);
         result.exprs.push(call_expr(
             ast,
             attr.span,
             callee,
-            [elem, event, handler, capture],
+            [elem, event, handler_of(value), delegate],
         ));
     }
 }
@@ -636,14 +825,13 @@ fn transform_event<'a>(
 /// Transform use: directive
 fn transform_directive<'a>(
     attr: &JSXAttribute<'a>,
-    key: &str,
-    elem_id: &str,
+    directive_name: &str,
+    elem_id: Atom<'a>,
     result: &mut TransformResult<'a>,
     context: &BlockContext<'a>,
 ) {
     let ast = context.ast();
     context.register_helper("use");
-    let directive_name = &key[4..]; // Strip "use:"
 
     let value = attr
         .value
@@ -663,7 +851,7 @@ fn transform_directive<'a>(
         attr.span,
         callee,
         [
-            ident_expr(ast, attr.span, directive_name),
+            ident_expr(ast, attr.span, context.atom(directive_name)),
             ident_expr(ast, attr.span, elem_id),
             value,
         ],
@@ -673,13 +861,12 @@ fn transform_directive<'a>(
 /// Transform prop: prefix (direct DOM property assignment)
 fn transform_prop<'a>(
     attr: &JSXAttribute<'a>,
-    key: &str,
-    elem_id: &str,
+    prop_name: &str,
+    elem_id: Atom<'a>,
     result: &mut TransformResult<'a>,
     context: &BlockContext<'a>,
 ) {
     let ast = context.ast();
-    let prop_name = &key[5..]; // Strip "prop:"
 
     if let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value {
         if let Some(expr) = container.expression.as_expression() {
@@ -712,13 +899,12 @@ fn transform_prop<'a>(
 /// Transform attr: prefix (force attribute mode via setAttribute)
 fn transform_attr<'a>(
     attr: &JSXAttribute<'a>,
-    key: &str,
-    elem_id: &str,
+    attr_name: &str,
+    elem_id: Atom<'a>,
     result: &mut TransformResult<'a>,
     context: &BlockContext<'a>,
 ) {
     let ast = context.ast();
-    let attr_name = &key[5..]; // Strip "attr:"
 
     if let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value {
         if let Some(expr) = container.expression.as_expression() {
@@ -738,22 +924,63 @@ fn transform_attr<'a>(
     } else if let Some(JSXAttributeValue::StringLiteral(lit)) = &attr.value {
         // Static value - inline in template
         let escaped = escape_html(&lit.value, true);
-        result
-            .template
-            .push_str(&format!(" {}=\"{}\"", attr_name, escaped));
+        let _ = write!(result.template, " {}=\"{}\"", attr_name, escaped);
+    }
+}
+
+/// Transform bool: prefix (the attribute is present while the value is truthy)
+fn transform_bool_attribute<'a>(
+    attr: &JSXAttribute<'a>,
+    planned: &PlannedAttr<'_, 'a>,
+    attr_name: &str,
+    elem_id: Option<Atom<'a>>,
+    result: &mut TransformResult<'a>,
+    context: &BlockContext<'a>,
+) {
+    let ast = context.ast();
+
+    match planned.static_presence() {
+        // Literal value - inline in template when present
+        Some(present) => {
+            if present {
+                result.template.push(' ');
+                result.template.push_str(attr_name);
+            }
+        }
+        None => {
+            let Some(expr) = planned.expression() else {
+                return;
+            };
+            let elem_id = elem_id.expect("bool: requires an element id");
+            context.register_helper("setBoolAttribute");
+            let callee = ident_expr(ast, attr.span, "setBoolAttribute");
+            let elem = ident_expr(ast, attr.span, elem_id);
+            let name =
+                ast.expression_string_literal(SPAN, ast.allocator.alloc_str(attr_name), None);
+            let call = call_expr(ast, attr.span, callee, [elem, name, context.clone_expr(expr)]);
+            if is_dynamic(expr) {
+                context.register_helper("effect");
+                let effect = ident_expr(ast, attr.span, "effect");
+                let arrow = arrow_zero_params_return_expr(ast, attr.span, call);
+                result
+                    .exprs
+                    .push(call_expr(ast, attr.span, effect, [arrow]));
+            } else {
+                result.exprs.push(call);
+            }
+        }
     }
 }
 
 /// Transform class: prefix (maps to classList.toggle)
 fn transform_class_namespace<'a>(
     attr: &JSXAttribute<'a>,
-    key: &str,
-    elem_id: &str,
+    class_name: &str,
+    elem_id: Atom<'a>,
     result: &mut TransformResult<'a>,
     context: &BlockContext<'a>,
 ) {
     let ast = context.ast();
-    let class_name = &key[6..]; // Strip "class:"
 
     match &attr.value {
         Some(JSXAttributeValue::ExpressionContainer(container)) => {
@@ -797,13 +1024,12 @@ fn transform_class_namespace<'a>(
 /// Transform style: prefix (maps to setStyleProperty)
 fn transform_style_namespace<'a>(
     attr: &JSXAttribute<'a>,
-    key: &str,
-    elem_id: &str,
+    prop_name: &str,
+    elem_id: Atom<'a>,
     result: &mut TransformResult<'a>,
     context: &BlockContext<'a>,
 ) {
     let ast = context.ast();
-    let prop_name = &key[6..]; // Strip "style:"
     context.register_helper("setStyleProperty");
 
     match &attr.value {
@@ -843,7 +1069,7 @@ fn transform_style_namespace<'a>(
 /// Transform style attribute
 fn transform_style<'a>(
     attr: &JSXAttribute<'a>,
-    elem_id: Option<&str>,
+    elem_id: Option<Atom<'a>>,
     result: &mut TransformResult<'a>,
     context: &BlockContext<'a>,
 ) {
@@ -851,19 +1077,14 @@ fn transform_style<'a>(
     match &attr.value {
         Some(JSXAttributeValue::StringLiteral(lit)) => {
             // Static style string - inline in template
-            result
-                .template
-                .push_str(&format!(" style=\"{}\"", escape_html(&lit.value, true)));
+            let _ = write!(result.template, " style=\"{}\"", escape_html(&lit.value, true));
         }
         Some(JSXAttributeValue::ExpressionContainer(container)) => {
             if let Some(expr) = container.expression.as_expression() {
                 // Check if it's an object expression (static object)
                 if let oxc_ast::ast::Expression::ObjectExpression(obj) = expr {
-                    // Try to convert to static style string
-                    if let Some(style_str) = object_to_style_string(obj) {
-                        result
-                            .template
-                            .push_str(&format!(" style=\"{}\"", style_str));
+                    // Try to write it as a static style string
+                    if write_style_object(obj, &mut result.template) {
                         return;
                     }
                 }
@@ -891,11 +1112,21 @@ fn transform_style<'a>(
     }
 }
 
-/// Try to convert a static object expression to a style string
-fn object_to_style_string(obj: &oxc_ast::ast::ObjectExpression) -> Option<String> {
-    let mut styles = Vec::new();
+/// Try to write a static object expression as a `style` attribute; leaves
+/// `template` as it was and returns false if the object is not static
+fn write_style_object(obj: &oxc_ast::ast::ObjectExpression, template: &mut String) -> bool {
+    let start = template.len();
+    template.push_str(" style=\"");
+    if !write_style_declarations(obj, template) {
+        template.truncate(start);
+        return false;
+    }
+    template.push('"');
+    true
+}
 
-    for prop in &obj.properties {
+fn write_style_declarations(obj: &oxc_ast::ast::ObjectExpression, template: &mut String) -> bool {
+    for (i, prop) in obj.properties.iter().enumerate() {
         if let oxc_ast::ast::ObjectPropertyKind::ObjectProperty(prop) = prop {
             // Get key
             let key = match &prop.key {
@@ -904,31 +1135,32 @@ fn object_to_style_string(obj: &oxc_ast::ast::ObjectExpression) -> Option<String
                     camel_to_kebab(&id.name)
                 }
                 oxc_ast::ast::PropertyKey::StringLiteral(lit) => lit.value.to_string(),
-                _ => return None, // Dynamic key, can't inline
+                _ => return false, // Dynamic key, can't inline
             };
+            if i > 0 {
+                template.push_str("; ");
+            }
 
-            // Get value - must be a static literal
-            let value = match &prop.value {
-                oxc_ast::ast::Expression::StringLiteral(lit) => lit.value.to_string(),
+            // Write value - must be a static literal
+            match &prop.value {
+                oxc_ast::ast::Expression::StringLiteral(lit) => {
+                    let _ = write!(template, "{}: {}", key, lit.value);
+                }
                 oxc_ast::ast::Expression::NumericLiteral(num) => {
                     // Add px for numeric values (except certain properties)
-                    let num_str = num.value.to_string();
+                    let _ = write!(template, "{}: {}", key, num.value);
                     if needs_px_suffix(&key) && num.value != 0.0 {
-                        format!("{}px", num_str)
-                    } else {
-                        num_str
+                        template.push_str("px");
                     }
                 }
-                _ => return None, // Dynamic value, can't inline
-            };
-
-            styles.push(format!("{}: {}", key, value));
+                _ => return false, // Dynamic value, can't inline
+            }
         } else {
-            return None; // Spread or method, can't inline
+            return false; // Spread or method, can't inline
         }
     }
 
-    Some(styles.join("; "))
+    true
 }
 
 /// Convert camelCase to kebab-case
@@ -947,60 +1179,11 @@ fn camel_to_kebab(s: &str) -> String {
     result
 }
 
-/// Check if a CSS property needs px suffix for numeric values
-fn needs_px_suffix(prop: &str) -> bool {
-    // Properties that don't need px suffix
-    let unitless = [
-        "animation-iteration-count",
-        "border-image-outset",
-        "border-image-slice",
-        "border-image-width",
-        "box-flex",
-        "box-flex-group",
-        "box-ordinal-group",
-        "column-count",
-        "columns",
-        "flex",
-        "flex-grow",
-        "flex-positive",
-        "flex-shrink",
-        "flex-negative",
-        "flex-order",
-        "grid-row",
-        "grid-row-end",
-        "grid-row-span",
-        "grid-row-start",
-        "grid-column",
-        "grid-column-end",
-        "grid-column-span",
-        "grid-column-start",
-        "font-weight",
-        "line-clamp",
-        "line-height",
-        "opacity",
-        "order",
-        "orphans",
-        "tab-size",
-        "widows",
-        "z-index",
-        "zoom",
-        "fill-opacity",
-        "flood-opacity",
-        "stop-opacity",
-        "stroke-dasharray",
-        "stroke-dashoffset",
-        "stroke-miterlimit",
-        "stroke-opacity",
-        "stroke-width",
-    ];
-    !unitless.contains(&prop)
-}
-
 /// Transform innerHTML/textContent
 fn transform_inner_content<'a>(
     attr: &JSXAttribute<'a>,
     key: &str,
-    elem_id: &str,
+    elem_id: Atom<'a>,
     result: &mut TransformResult<'a>,
     context: &BlockContext<'a>,
 ) {
@@ -1060,25 +1243,23 @@ fn transform_inner_content<'a>(
 fn transform_children<'a, 'b>(
     element: &JSXElement<'a>,
     result: &mut TransformResult<'a>,
-    info: &TransformInfo,
+    info: &TransformInfo<'a>,
     context: &BlockContext<'a>,
     options: &TransformOptions<'a>,
     transform_child: ChildTransformer<'a, 'b>,
     ctx: &TraverseCtx<'a, ()>,
 ) {
-    fn child_path(base: &[String], node_index: usize) -> Vec<String> {
-        let mut path = base.to_vec();
-        path.push("firstChild".to_string());
-        for _ in 0..node_index {
-            path.push("nextSibling".to_string());
-        }
+    fn child_path(base: &[PathStep], node_index: usize) -> SmallVec<[PathStep; 8]> {
+        let mut path = SmallVec::from_slice(base);
+        path.push(PathStep::FirstChild);
+        path.extend(std::iter::repeat_n(PathStep::NextSibling, node_index));
         path
     }
 
     fn child_accessor<'a>(
         ast: AstBuilder<'a>,
         span: Span,
-        parent_id: &str,
+        parent_id: Atom<'a>,
         node_index: usize,
     ) -> Expression<'a> {
         let mut expr = static_member(ast, span, ident_expr(ast, span, parent_id), "firstChild");
@@ -1104,10 +1285,10 @@ fn transform_children<'a, 'b>(
                 oxc_ast::ast::JSXChild::Element(_) => {
                     other_content = true;
                 }
-                oxc_ast::ast::JSXChild::ExpressionContainer(container) => {
-                    if container.expression.as_expression().is_some() {
-                        expr_count += 1;
-                    }
+                oxc_ast::ast::JSXChild::ExpressionContainer(container)
+                    if container.expression.as_expression().is_some() =>
+                {
+                    expr_count += 1;
                 }
                 oxc_ast::ast::JSXChild::Fragment(fragment) => {
                     // Recurse into fragments
@@ -1124,10 +1305,11 @@ fn transform_children<'a, 'b>(
         expr_count == 1 && !other_content
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn transform_children_list<'a, 'b>(
         children: &[oxc_ast::ast::JSXChild<'a>],
         result: &mut TransformResult<'a>,
-        info: &TransformInfo,
+        info: &TransformInfo<'a>,
         context: &BlockContext<'a>,
         options: &TransformOptions<'a>,
         transform_child: ChildTransformer<'a, 'b>,
@@ -1135,6 +1317,7 @@ fn transform_children<'a, 'b>(
         node_index: &mut usize,
         last_was_text: &mut bool,
         single_dynamic: bool,
+        last_element: Option<Span>,
     ) {
         let ast = context.ast();
         for child in children {
@@ -1142,9 +1325,7 @@ fn transform_children<'a, 'b>(
                 oxc_ast::ast::JSXChild::Text(text) => {
                     let content = common::expression::trim_whitespace(&text.value);
                     if !content.is_empty() {
-                        let escaped = escape_html(&content, false);
-                        result.template.push_str(&escaped);
-                        result.template_with_closing_tags.push_str(&escaped);
+                        result.template.push_str(&escape_html(&content, false));
                         if !*last_was_text {
                             *node_index += 1;
                             *last_was_text = true;
@@ -1154,10 +1335,10 @@ fn transform_children<'a, 'b>(
                 oxc_ast::ast::JSXChild::Element(child_elem) => {
                     let child_tag = common::get_tag_name(child_elem);
 
-                    if is_component(&child_tag) {
+                    if classify_tag(&child_tag, options).is_component() {
                         *last_was_text = false;
                         if let (Some(parent_id), Some(child_result)) =
-                            (result.id.as_deref(), transform_child(child))
+                            (result.id, transform_child(child))
                         {
                             if child_result.exprs.is_empty() {
                                 continue;
@@ -1178,11 +1359,11 @@ fn transform_children<'a, 'b>(
                                 ));
                             } else {
                                 result.template.push_str("<!>");
-                                result.template_with_closing_tags.push_str("<!>");
 
                                 let marker_id = context.generate_uid("el$");
                                 result.declarations.push(Declaration {
-                                    name: marker_id.clone(),
+                                    span: child_elem.span,
+                                    name: marker_id,
                                     init: child_accessor(
                                         ast,
                                         child_elem.span,
@@ -1194,7 +1375,7 @@ fn transform_children<'a, 'b>(
                                 let callee = ident_expr(ast, child_elem.span, "insert");
                                 let parent = ident_expr(ast, child_elem.span, parent_id);
                                 let child_expr = child_result.exprs[0].clone_in(ast.allocator);
-                                let marker = ident_expr(ast, child_elem.span, &marker_id);
+                                let marker = ident_expr(ast, child_elem.span, marker_id);
                                 result.exprs.push(call_expr(
                                     ast,
                                     child_elem.span,
@@ -1209,14 +1390,36 @@ fn transform_children<'a, 'b>(
                     }
 
                     *last_was_text = false;
+                    if is_static_subtree(child_elem, context, options) {
+                        if options.omit_last_closing_tag
+                            && last_element == Some(child_elem.span)
+                            && !info.parent_closed
+                        {
+                            let mut open_tags = String::new();
+                            write_static_subtree(
+                                child_elem,
+                                options,
+                                &mut result.template,
+                                Some(&mut open_tags),
+                            );
+                            result.template_with_closing_tags =
+                                Some(format!("{}{}", result.template, open_tags));
+                        } else {
+                            write_static_subtree(child_elem, options, &mut result.template, None);
+                        }
+                        *node_index += 1;
+                        continue;
+                    }
+
                     let child_info = TransformInfo {
                         top_level: false,
                         path: child_path(&info.path, *node_index),
-                        root_id: info.root_id.clone(),
+                        root_id: info.root_id,
+                        last_element: last_element == Some(child_elem.span),
                         ..info.clone()
                     };
 
-                    let child_result = transform_element(
+                    let child_result = transform_element_into(
                         child_elem,
                         &child_tag,
                         &child_info,
@@ -1224,18 +1427,11 @@ fn transform_children<'a, 'b>(
                         options,
                         transform_child,
                         ctx,
+                        std::mem::take(&mut result.template),
                     );
 
-                    result.template.push_str(&child_result.template);
-                    if !child_result.template_with_closing_tags.is_empty() {
-                        result
-                            .template_with_closing_tags
-                            .push_str(&child_result.template_with_closing_tags);
-                    } else {
-                        result
-                            .template_with_closing_tags
-                            .push_str(&child_result.template);
-                    }
+                    result.template = child_result.template;
+                    result.template_with_closing_tags = child_result.template_with_closing_tags;
                     result.declarations.extend(child_result.declarations);
                     result.exprs.extend(child_result.exprs);
                     result.dynamics.extend(child_result.dynamics);
@@ -1245,7 +1441,7 @@ fn transform_children<'a, 'b>(
                 }
                 oxc_ast::ast::JSXChild::ExpressionContainer(container) => {
                     if let (Some(parent_id), Some(expr)) =
-                        (result.id.as_deref(), container.expression.as_expression())
+                        (result.id, container.expression.as_expression())
                     {
                         *last_was_text = false;
                         context.register_helper("insert");
@@ -1272,17 +1468,17 @@ fn transform_children<'a, 'b>(
                             ));
                         } else {
                             result.template.push_str("<!>");
-                            result.template_with_closing_tags.push_str("<!>");
 
                             let marker_id = context.generate_uid("el$");
                             result.declarations.push(Declaration {
-                                name: marker_id.clone(),
+                                span: container.span,
+                                name: marker_id,
                                 init: child_accessor(ast, container.span, parent_id, *node_index),
                             });
 
                             let callee = ident_expr(ast, container.span, "insert");
                             let parent = ident_expr(ast, container.span, parent_id);
-                            let marker = ident_expr(ast, container.span, &marker_id);
+                            let marker = ident_expr(ast, container.span, marker_id);
                             result.exprs.push(call_expr(
                                 ast,
                                 container.span,
@@ -1306,6 +1502,7 @@ fn transform_children<'a, 'b>(
                         node_index,
                         last_was_text,
                         single_dynamic,
+                        last_element,
                     );
                 }
                 _ => {}
@@ -1315,9 +1512,15 @@ fn transform_children<'a, 'b>(
 
     let mut node_index = 0usize;
     let mut last_was_text = false;
-    let single_dynamic = is_single_dynamic_child(&element.children);
+    let children_attr = children_attr_child(element, context.ast());
+    let children = match &children_attr {
+        Some(child) => std::slice::from_ref(child),
+        None => &element.children[..],
+    };
+    let single_dynamic = is_single_dynamic_child(children);
+    let last_element = last_element_span(children, options);
     transform_children_list(
-        &element.children,
+        children,
         result,
         info,
         context,
@@ -1327,5 +1530,6 @@ fn transform_children<'a, 'b>(
         &mut node_index,
         &mut last_was_text,
         single_dynamic,
+        last_element,
     );
 }
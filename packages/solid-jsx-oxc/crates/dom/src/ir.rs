@@ -6,7 +6,9 @@ use indexmap::IndexSet;
 use oxc_allocator::{Allocator, CloneIn};
 use oxc_ast::ast::{Expression, JSXChild};
 use oxc_ast::AstBuilder;
-use oxc_span::Span;
+use oxc_span::{Atom, Span};
+use rustc_hash::FxHashMap;
+use smallvec::SmallVec;
 use std::cell::RefCell;
 
 /// Function type for transforming child JSX elements
@@ -21,17 +23,18 @@ pub struct TransformResult<'a> {
     /// The HTML template string
     pub template: String,
 
-    /// Template with all closing tags (for SSR)
-    pub template_with_closing_tags: String,
+    /// Template with all closing tags, only built when `template` may omit
+    /// some (the `omit_last_closing_tag` option)
+    pub template_with_closing_tags: Option<String>,
 
     /// Variable declarations needed
-    pub declarations: Vec<Declaration<'a>>,
+    pub declarations: SmallVec<[Declaration<'a>; 2]>,
 
     /// Expressions to execute (effects, inserts, etc.)
-    pub exprs: Vec<Expression<'a>>,
+    pub exprs: SmallVec<[Expression<'a>; 2]>,
 
     /// Dynamic attribute bindings
-    pub dynamics: Vec<DynamicBinding<'a>>,
+    pub dynamics: SmallVec<[DynamicBinding<'a>; 2]>,
 
     /// Post-expressions (run after main effects)
     pub post_exprs: Vec<Expression<'a>>,
@@ -43,13 +46,13 @@ pub struct TransformResult<'a> {
     pub has_custom_element: bool,
 
     /// The tag name (for native elements)
-    pub tag_name: Option<String>,
+    pub tag_name: Option<Atom<'a>>,
 
     /// Whether to skip template generation
     pub skip_template: bool,
 
     /// The generated element ID
-    pub id: Option<String>,
+    pub id: Option<Atom<'a>>,
 
     /// Whether this result is just text
     pub text: bool,
@@ -61,20 +64,33 @@ pub struct TransformResult<'a> {
     pub child_results: Vec<TransformResult<'a>>,
 }
 
+impl TransformResult<'_> {
+    /// The template with all its closing tags
+    pub fn full_template(&self) -> &str {
+        self.template_with_closing_tags
+            .as_deref()
+            .unwrap_or(&self.template)
+    }
+}
+
 /// A variable declaration
 pub struct Declaration<'a> {
-    pub name: String,
+    /// Source span of the JSX node this declaration walks to
+    pub span: Span,
+    pub name: Atom<'a>,
     pub init: Expression<'a>,
 }
 
 /// A dynamic attribute binding that needs effect wrapping
 pub struct DynamicBinding<'a> {
-    pub elem: String,
-    pub key: String,
+    /// Source span of the originating attribute
+    pub span: Span,
+    pub elem: Atom<'a>,
+    pub key: Atom<'a>,
     pub value: Expression<'a>,
     pub is_svg: bool,
     pub is_ce: bool,
-    pub tag_name: String,
+    pub tag_name: Atom<'a>,
 }
 
 /// Context for the current block being transformed
@@ -83,7 +99,11 @@ pub struct BlockContext<'a> {
     pub template: RefCell<String>,
 
     /// Templates collected at the file level
-    pub templates: RefCell<Vec<TemplateInfo>>,
+    pub templates: RefCell<Vec<TemplateInfo<'a>>>,
+
+    /// Ids of the collected templates by HTML and SVG flag, so identical
+    /// subtrees share one template
+    template_ids: RefCell<FxHashMap<(&'a str, bool), TemplateId>>,
 
     /// Helper imports needed
     pub helpers: RefCell<IndexSet<String>>,
@@ -94,32 +114,57 @@ pub struct BlockContext<'a> {
     /// Variable counter for unique names
     pub var_counter: RefCell<usize>,
 
+    /// Whether the subtree of each element checked so far is fully static
+    static_subtrees: RefCell<FxHashMap<Span, bool>>,
+
     allocator: &'a Allocator,
 }
 
-pub struct TemplateInfo {
-    pub content: String,
+pub struct TemplateInfo<'a> {
+    pub content: &'a str,
+    /// `content` is an SVG element wrapped in `<svg>`
     pub is_svg: bool,
     pub span: Span,
 }
 
+/// Index of a template in [`BlockContext::templates`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TemplateId(pub usize);
+
+impl TemplateId {
+    /// The variable the template is declared as, `_tmpl$1` for the first
+    pub fn var_name(self) -> String {
+        format!("_tmpl${}", self.0 + 1)
+    }
+}
+
 impl<'a> BlockContext<'a> {
     pub fn new(allocator: &'a Allocator) -> Self {
         Self {
             template: RefCell::new(String::new()),
             templates: RefCell::new(Vec::new()),
+            template_ids: RefCell::new(FxHashMap::default()),
             helpers: RefCell::new(IndexSet::new()),
             delegates: RefCell::new(IndexSet::new()),
             var_counter: RefCell::new(0),
+            static_subtrees: RefCell::new(FxHashMap::default()),
             allocator,
         }
     }
 
     /// Generate a unique variable name
-    pub fn generate_uid(&self, prefix: &str) -> String {
+    pub fn generate_uid(&self, prefix: &str) -> Atom<'a> {
         let mut counter = self.var_counter.borrow_mut();
         *counter += 1;
-        format!("_{}{}", prefix, *counter)
+        let mut name = itoa::Buffer::new();
+        self.ast()
+            .atom_from_strs_array(["_", prefix, name.format(*counter)])
+    }
+
+    /// Copy `name` into the arena, for names that outlive the JSX they come
+    /// from
+    pub fn atom(&self, name: &str) -> Atom<'a> {
+        self.ast().atom(name)
     }
 
     /// Register a helper import
@@ -132,17 +177,35 @@ impl<'a> BlockContext<'a> {
         self.delegates.borrow_mut().insert(event.to_string());
     }
 
-    /// Push a template and return its index
-    pub fn push_template(&self, content: String, is_svg: bool, span: Span) -> usize {
+    /// Push a template and return its id, the id of the same template when
+    /// it was pushed before
+    pub fn push_template(&self, content: &str, is_svg: bool, span: Span) -> TemplateId {
         self.register_helper("template");
+        let mut template_ids = self.template_ids.borrow_mut();
+        if let Some(&id) = template_ids.get(&(content, is_svg)) {
+            return id;
+        }
+        let content = self.allocator.alloc_str(content);
         let mut templates = self.templates.borrow_mut();
-        let index = templates.len();
+        let id = TemplateId(templates.len());
         templates.push(TemplateInfo {
             content,
             is_svg,
             span,
         });
-        index
+        template_ids.insert((content, is_svg), id);
+        id
+    }
+
+    /// Whether the subtree at `span` is fully static, checked with `check`
+    /// the first time
+    pub fn is_static_subtree(&self, span: Span, check: impl FnOnce() -> bool) -> bool {
+        if let Some(&is_static) = self.static_subtrees.borrow().get(&span) {
+            return is_static;
+        }
+        let is_static = check();
+        self.static_subtrees.borrow_mut().insert(span, is_static);
+        is_static
     }
 
     pub fn ast(&self) -> AstBuilder<'a> {
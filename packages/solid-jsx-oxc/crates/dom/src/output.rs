@@ -4,12 +4,12 @@ use oxc_ast::ast::{
     VariableDeclarationKind,
 };
 use oxc_ast::{AstBuilder, NONE};
-use oxc_span::{Span, SPAN};
+use oxc_span::{Atom, Span, SPAN};
 
 use crate::ir::{BlockContext, TransformResult};
 
-fn ident_expr<'a>(ast: AstBuilder<'a>, span: Span, name: &str) -> Expression<'a> {
-    ast.expression_identifier(span, ast.allocator.alloc_str(name))
+fn ident_expr<'a>(ast: AstBuilder<'a>, span: Span, name: impl Into<Atom<'a>>) -> Expression<'a> {
+    ast.expression_identifier(span, name.into())
 }
 
 fn static_member<'a>(
@@ -114,12 +114,20 @@ pub fn build_dom_output_expr<'a>(
     if !result.template.is_empty() && !result.skip_template {
         // Push template and get variable name
         // The template string is generated code; don't attribute it to the source with spans.
-        let tmpl_idx = context.push_template(result.template.clone(), result.is_svg, gen_span);
-        let tmpl_var = format!("_tmpl${}", tmpl_idx + 1);
+        // SVG elements only parse as such inside an `<svg>`, so other roots are
+        // wrapped in one and `template` is told to unwrap them
+        let wrap_svg = result.is_svg && result.tag_name.is_some_and(|tag| tag != "svg");
+        let tmpl_id = if wrap_svg {
+            let content = format!("<svg>{}</svg>", result.template);
+            context.push_template(&content, true, gen_span)
+        } else {
+            context.push_template(&result.template, false, gen_span)
+        };
+        let tmpl_var = tmpl_id.var_name();
 
         // Use the generated element ID when available (matches expression wiring).
         // Fall back to a local _el$ when the element didn't require a stable ID.
-        let elem_var = result.id.clone().unwrap_or_else(|| "_el$".to_string());
+        let elem_var = result.id.unwrap_or(Atom::from("_el$"));
 
         let mut statements = ast.vec();
 
@@ -130,7 +138,7 @@ pub fn build_dom_output_expr<'a>(
             static_member(
                 ast,
                 gen_span,
-                ident_expr(ast, gen_span, &tmpl_var),
+                ident_expr(ast, gen_span, context.atom(&tmpl_var)),
                 "cloneNode",
             ),
             [ast.expression_boolean_literal(gen_span, true)],
@@ -141,7 +149,7 @@ pub fn build_dom_output_expr<'a>(
         for decl in &result.declarations {
             statements.push(const_decl_stmt(
                 ast,
-                gen_span,
+                decl.span,
                 &decl.name,
                 decl.init.clone_in(ast.allocator),
             ));
@@ -165,12 +173,14 @@ pub fn build_dom_output_expr<'a>(
                 context.register_helper("setAttribute");
             }
 
+            // Attribute the effect to the originating attribute so source maps
+            // and diagnostics point at the JSX rather than the generated IIFE.
             let setter = crate::template::generate_set_attr_expr(ast, gen_span, binding);
             let effect = ident_expr(ast, gen_span, "effect");
             let arrow = arrow_zero_params_body(ast, gen_span, setter);
-            let effect_call = call_expr(ast, gen_span, effect, [arrow]);
+            let effect_call = call_expr(ast, binding.span, effect, [arrow]);
             statements.push(Statement::ExpressionStatement(
-                ast.alloc_expression_statement(gen_span, effect_call),
+                ast.alloc_expression_statement(binding.span, effect_call),
             ));
         }
 
@@ -184,7 +194,7 @@ pub fn build_dom_output_expr<'a>(
         // return _el$;
         statements.push(Statement::ReturnStatement(ast.alloc_return_statement(
             gen_span,
-            Some(ident_expr(ast, gen_span, &elem_var)),
+            Some(ident_expr(ast, gen_span, elem_var)),
         )));
 
         // (() => { ... })()
@@ -197,7 +207,7 @@ pub fn build_dom_output_expr<'a>(
         let body = ast.alloc_function_body(gen_span, ast.vec(), statements);
         let arrow_fn =
             ast.expression_arrow_function(gen_span, false, false, NONE, params, NONE, body);
-        return call_expr(ast, gen_span, arrow_fn, []);
+        return call_expr(ast, result.span, arrow_fn, []);
     }
 
     // Expression-only result (like createComponent(...) or fragment expression)
@@ -1,13 +1,13 @@
 use oxc_allocator::CloneIn;
 use oxc_ast::ast::{AssignmentTarget, Expression};
 use oxc_ast::AstBuilder;
-use oxc_span::Span;
+use oxc_span::{Atom, Span};
 use oxc_syntax::operator::AssignmentOperator;
 
 use crate::ir::DynamicBinding;
 
-fn ident_expr<'a>(ast: AstBuilder<'a>, span: Span, name: &str) -> Expression<'a> {
-    ast.expression_identifier(span, ast.allocator.alloc_str(name))
+fn ident_expr<'a>(ast: AstBuilder<'a>, span: Span, name: impl Into<Atom<'a>>) -> Expression<'a> {
+    ast.expression_identifier(span, name.into())
 }
 
 fn static_member<'a>(
@@ -44,7 +44,7 @@ pub fn generate_set_attr_expr<'a>(
     binding: &DynamicBinding<'a>,
 ) -> Expression<'a> {
     let key = binding.key.as_str();
-    let elem = ident_expr(ast, span, &binding.elem);
+    let elem = ident_expr(ast, span, binding.elem);
     let value = binding.value.clone_in(ast.allocator);
 
     // Handle special cases
@@ -9,14 +9,15 @@ use oxc_ast::ast::{
 };
 use oxc_ast::NONE;
 use oxc_semantic::SemanticBuilder;
-use oxc_span::SPAN;
+use oxc_span::{Atom, SPAN};
 use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
+use smallvec::{smallvec, SmallVec};
 
-use common::{get_tag_name, is_component, TransformOptions};
+use common::{classify_tag, get_tag_name, TransformMetadata, TransformOptions};
 
 use crate::component::transform_component;
 use crate::element::transform_element;
-use crate::ir::{BlockContext, TransformResult};
+use crate::ir::{BlockContext, TemplateId, TransformResult};
 use crate::output::build_dom_output_expr;
 
 /// The main Solid JSX transformer
@@ -36,7 +37,7 @@ impl<'a> SolidTransform<'a> {
     }
 
     /// Run the transform on a program
-    pub fn transform(mut self, program: &mut Program<'a>) {
+    pub fn transform(mut self, program: &mut Program<'a>) -> TransformMetadata {
         // SAFETY: We convert the allocator reference to a raw pointer and back to a reference
         // to satisfy oxc_traverse's API which requires `&Allocator` while we hold `&mut self`.
         // This is safe because:
@@ -55,13 +56,25 @@ impl<'a> SolidTransform<'a> {
                 .into_scoping(),
             (),
         );
+
+        TransformMetadata {
+            delegated_events: self.context.delegates.take().into_iter().collect(),
+            templates: self
+                .context
+                .templates
+                .take()
+                .into_iter()
+                .map(|template| template.content.to_string())
+                .collect(),
+            helpers: self.context.helpers.take().into_iter().collect(),
+        }
     }
 
     /// Transform a JSX node and return the result
     fn transform_node(
         &self,
         node: &JSXChild<'a>,
-        info: &TransformInfo,
+        info: &TransformInfo<'a>,
         ctx: &TraverseCtx<'a, ()>,
     ) -> Option<TransformResult<'a>> {
         match node {
@@ -80,7 +93,7 @@ impl<'a> SolidTransform<'a> {
                 );
                 Some(TransformResult {
                     span: spread.span,
-                    exprs: vec![expr],
+                    exprs: smallvec![expr],
                     ..Default::default()
                 })
             }
@@ -91,7 +104,7 @@ impl<'a> SolidTransform<'a> {
     fn transform_jsx_element(
         &self,
         element: &JSXElement<'a>,
-        info: &TransformInfo,
+        info: &TransformInfo<'a>,
         ctx: &TraverseCtx<'a, ()>,
     ) -> TransformResult<'a> {
         let tag_name = get_tag_name(element);
@@ -101,7 +114,7 @@ impl<'a> SolidTransform<'a> {
             self.transform_node(child, info, ctx)
         };
 
-        if is_component(&tag_name) {
+        if classify_tag(&tag_name, self.options).is_component() {
             transform_component(
                 element,
                 &tag_name,
@@ -127,7 +140,7 @@ impl<'a> SolidTransform<'a> {
     fn transform_fragment(
         &self,
         fragment: &JSXFragment<'a>,
-        info: &TransformInfo,
+        info: &TransformInfo<'a>,
         ctx: &TraverseCtx<'a, ()>,
     ) -> TransformResult<'a> {
         let mut result = TransformResult {
@@ -204,7 +217,7 @@ impl<'a> SolidTransform<'a> {
     fn transform_expression_container(
         &self,
         container: &JSXExpressionContainer<'a>,
-        _info: &TransformInfo,
+        _info: &TransformInfo<'a>,
     ) -> Option<TransformResult<'a>> {
         // Use as_expression() to get the expression if it exists
         if let Some(expr) = container.expression.as_expression() {
@@ -227,14 +240,14 @@ impl<'a> SolidTransform<'a> {
                     ast.expression_arrow_function(span, true, false, NONE, params, NONE, body);
                 Some(TransformResult {
                     span: container.span,
-                    exprs: vec![arrow],
+                    exprs: smallvec![arrow],
                     ..Default::default()
                 })
             } else {
                 // Static expression
                 Some(TransformResult {
                     span: container.span,
-                    exprs: vec![self.context.clone_expr(expr)],
+                    exprs: smallvec![self.context.clone_expr(expr)],
                     ..Default::default()
                 })
             }
@@ -247,16 +260,36 @@ impl<'a> SolidTransform<'a> {
 
 /// Additional info passed during transform
 #[derive(Default, Clone)]
-pub struct TransformInfo {
+pub struct TransformInfo<'a> {
     pub top_level: bool,
     pub last_element: bool,
+    /// Whether an ancestor in the same template writes its closing tag, so
+    /// this element has to write its own
+    pub parent_closed: bool,
     pub skip_id: bool,
     pub component_child: bool,
     pub fragment_child: bool,
-    /// Path from root element to this element (e.g., ["firstChild", "nextSibling"])
-    pub path: Vec<String>,
+    /// Path from root element to this element (e.g., [FirstChild, NextSibling])
+    pub path: SmallVec<[PathStep; 8]>,
     /// The root element variable name (e.g., "_el$1")
-    pub root_id: Option<String>,
+    pub root_id: Option<Atom<'a>>,
+}
+
+/// A step of the walk from a root element to one of its descendants
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathStep {
+    FirstChild,
+    NextSibling,
+}
+
+impl PathStep {
+    /// The DOM property this step reads
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PathStep::FirstChild => "firstChild",
+            PathStep::NextSibling => "nextSibling",
+        }
+    }
 }
 
 impl<'a> Traverse<'a, ()> for SolidTransform<'a> {
@@ -442,10 +475,10 @@ impl<'a> Traverse<'a, ()> for SolidTransform<'a> {
         // const _tmpl$1 = template(`<div></div>`);
         for (i, tmpl) in templates.iter().enumerate() {
             let tmpl_span = tmpl.span;
-            let tmpl_var = format!("_tmpl${}", i + 1);
+            let tmpl_var = TemplateId(i).var_name();
 
             let mut quasis = ast.vec_with_capacity(1);
-            let part_str = ast.allocator.alloc_str(&tmpl.content);
+            let part_str = tmpl.content;
             let value = TemplateElementValue {
                 raw: ast.atom(part_str),
                 cooked: Some(ast.atom(part_str)),
@@ -454,12 +487,15 @@ impl<'a> Traverse<'a, ()> for SolidTransform<'a> {
             let template_lit = ast.template_literal(tmpl_span, quasis, ast.vec());
             let template_expr = Expression::TemplateLiteral(ast.alloc(template_lit));
 
-            let mut args = ast.vec_with_capacity(if tmpl.is_svg { 2 } else { 1 });
+            let mut args = ast.vec_with_capacity(if tmpl.is_svg { 4 } else { 1 });
             args.push(Argument::from(template_expr));
             if tmpl.is_svg {
-                args.push(Argument::from(
-                    ast.expression_boolean_literal(tmpl_span, true),
-                ));
+                // template(html, isImportNode, isSVG, isMathML)
+                for flag in [false, true, false] {
+                    args.push(Argument::from(
+                        ast.expression_boolean_literal(tmpl_span, flag),
+                    ));
+                }
             }
 
             let call = ast.expression_call(
@@ -498,3 +534,79 @@ impl<'a> Traverse<'a, ()> for SolidTransform<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    /// Collects the template of each root element, and its full template
+    struct Templates<'a> {
+        transform: SolidTransform<'a>,
+        templates: Vec<String>,
+    }
+
+    impl<'a> Traverse<'a, ()> for Templates<'a> {
+        fn exit_expression(&mut self, node: &mut Expression<'a>, ctx: &mut TraverseCtx<'a, ()>) {
+            if let Expression::JSXElement(element) = node {
+                let info = TransformInfo {
+                    top_level: true,
+                    last_element: true,
+                    ..Default::default()
+                };
+                let result = self.transform.transform_jsx_element(element, &info, ctx);
+                self.templates.push(format!(
+                    "{} | {:?}",
+                    result.template, result.template_with_closing_tags
+                ));
+            }
+        }
+    }
+
+    fn templates(source: &str, omit_last_closing_tag: bool) -> String {
+        let allocator = Allocator::default();
+        let options = TransformOptions {
+            omit_last_closing_tag,
+            ..TransformOptions::solid_defaults()
+        };
+        let mut program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let scoping = SemanticBuilder::new()
+            .build(&program)
+            .semantic
+            .into_scoping();
+        let mut templates = Templates {
+            transform: SolidTransform::new(&allocator, &options),
+            templates: vec![],
+        };
+        traverse_mut(&mut templates, &allocator, &mut program, scoping, ());
+        templates.templates.join("\n")
+    }
+
+    #[test]
+    fn test_omit_last_closing_tag() {
+        let source = r#"
+            <div><span>a</span><p>b <b>c</b></p></div>;
+            <ul><li>a</li><li>{x()}</li></ul>;
+            <div><p>a</p>{x()}</div>;
+            <div><p>a</p><Comp /></div>;
+            <section><input /></section>;
+        "#;
+        insta::assert_snapshot!(templates(source, false), @r"
+        <div><span>a</span><p>b <b>c</b></p></div> | None
+        <ul><li>a</li><li></li></ul> | None
+        <div><p>a</p><!></div> | None
+        <div><p>a</p><!></div> | None
+        <section><input></section> | None
+        ");
+        insta::assert_snapshot!(templates(source, true), @r#"
+        <div><span>a</span><p>b <b>c | Some("<div><span>a</span><p>b <b>c</b></p></div>")
+        <ul><li>a</li><li> | Some("<ul><li>a</li><li></li></ul>")
+        <div><p>a</p><!> | Some("<div><p>a</p><!></div>")
+        <div><p>a</p><!> | Some("<div><p>a</p><!></div>")
+        <section><input> | Some("<section><input></section>")
+        "#);
+    }
+}
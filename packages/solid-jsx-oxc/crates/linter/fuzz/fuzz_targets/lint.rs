@@ -0,0 +1,17 @@
+//! Arbitrary source through every rule, with the checks of
+//! `tests/support`: no panics, and every span on character boundaries
+
+#![no_main]
+
+#[path = "../../tests/support/mod.rs"]
+mod support;
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(source_text) = std::str::from_utf8(data) {
+        if let Err(error) = support::check(source_text) {
+            panic!("{error}");
+        }
+    }
+});
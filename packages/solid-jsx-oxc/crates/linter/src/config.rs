@@ -0,0 +1,926 @@
+//! Lint configuration files
+//!
+//! A `.solidlint.json` or `solid-lint.toml` maps rule names to a severity,
+//! optionally with options, in the same shape as ESLint's `rules`:
+//!
+//! ```json
+//! {
+//!   "rules": {
+//!     "solid/reactivity": "error",
+//!     "prefer-show": "off",
+//!     "style-prop": ["warn", { "allowString": true }]
+//!   }
+//! }
+//! ```
+//!
+//! Options are checked against each rule's `ConfigurableRule::Options`; invalid
+//! options are reported in `LintConfig::diagnostics` and the rule keeps its
+//! defaults. Rule names may omit the `solid/` prefix. Rules missing from the file keep
+//! their default: enabled unless the rule's `RuleMeta::DEFAULT_ENABLED` is
+//! false, which `solid-lint --explain` shows. Names in another namespace (`acme/rule`) belong to
+//! plugin rules and are not checked.
+//!
+//! `overrides` change rules for files matching glob patterns, relative to the
+//! config file's directory; patterns without a `/` match file names anywhere
+//! below it. Later overrides win:
+//!
+//! ```json
+//! {
+//!   "overrides": [
+//!     { "files": ["*.test.tsx", "*.stories.tsx"], "rules": { "no-innerhtml": "off" } }
+//!   ]
+//! }
+//! ```
+//!
+//! Config files nest: `ConfigResolver` merges every config file from a
+//! directory up to the file system root, or up to one with `"root": true`,
+//! with nearer files taking precedence: each file's rules and then its
+//! overrides apply over everything from the files above it, so a nearer
+//! file's rules beat an outer file's overrides.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::diagnostic::{Diagnostic, DiagnosticSeverity};
+use crate::plugin::is_plugin_rule_name;
+use crate::registry::RULES;
+use crate::rules::*;
+use crate::semantic_visitor::SemanticRulesConfig;
+use crate::visitor::RulesConfig;
+use crate::{ConfigurableRule, RuleMeta};
+
+/// Config file names, in lookup order within a directory
+pub const CONFIG_FILE_NAMES: &[&str] = &[".solidlint.json", "solid-lint.toml"];
+
+/// Severity of a configured rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleSeverity {
+    Off,
+    Warn,
+    Error,
+}
+
+/// Severity and raw options of a configured rule
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleSetting {
+    pub severity: RuleSeverity,
+    /// Options as written in the config file, `None` when only a severity is given
+    pub options: Option<serde_json::Value>,
+}
+
+/// `"warn"` or `["warn", { ...options }]`
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawRuleSetting {
+    Severity(RuleSeverity),
+    WithOptions(RuleSeverity, serde_json::Value),
+}
+
+/// `"*.test.tsx"` or `["*.test.tsx", "*.stories.tsx"]`
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawPatterns {
+    One(String),
+    Many(Vec<String>),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawOverride {
+    files: RawPatterns,
+    #[serde(default)]
+    rules: FxHashMap<String, RawRuleSetting>,
+}
+
+#[derive(Deserialize)]
+struct RawLintConfig {
+    #[serde(default)]
+    root: bool,
+    #[serde(default)]
+    rules: FxHashMap<String, RawRuleSetting>,
+    #[serde(default)]
+    overrides: Vec<RawOverride>,
+}
+
+/// Rules for the files matching some glob patterns
+#[derive(Debug, Clone)]
+pub struct ConfigOverride {
+    pub files: Vec<String>,
+    pub rules: FxHashMap<String, RuleSetting>,
+    /// Directory the patterns are relative to, that of the config file
+    pub base_dir: Option<PathBuf>,
+    /// Patterns containing a `/`, matched against the relative path
+    path_globs: GlobSet,
+    /// Patterns without a `/`, matched against the file name
+    name_globs: GlobSet,
+}
+
+impl ConfigOverride {
+    fn new(files: Vec<String>, rules: FxHashMap<String, RuleSetting>) -> Result<Self, ConfigError> {
+        let mut path_globs = GlobSetBuilder::new();
+        let mut name_globs = GlobSetBuilder::new();
+        for pattern in &files {
+            let glob = GlobBuilder::new(pattern.trim_start_matches("./"))
+                .literal_separator(true)
+                .build()
+                .map_err(ConfigError::Pattern)?;
+            if pattern.contains('/') {
+                path_globs.add(glob);
+            } else {
+                name_globs.add(glob);
+            }
+        }
+        Ok(Self {
+            files,
+            rules,
+            base_dir: None,
+            path_globs: path_globs.build().map_err(ConfigError::Pattern)?,
+            name_globs: name_globs.build().map_err(ConfigError::Pattern)?,
+        })
+    }
+
+    /// An override for every file, used to apply a nested config's rules
+    /// after the overrides of the configs above it
+    fn all(rules: FxHashMap<String, RuleSetting>) -> Self {
+        Self::new(vec!["*".to_string()], rules).expect("`*` is a valid pattern")
+    }
+
+    /// Whether the override applies to `path`, which must be below `base_dir`
+    pub fn matches(&self, path: &Path) -> bool {
+        let relative = match &self.base_dir {
+            Some(base_dir) => {
+                let path = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+                match path.strip_prefix(base_dir) {
+                    Ok(relative) => relative.to_path_buf(),
+                    Err(_) => return false,
+                }
+            }
+            None => path.to_path_buf(),
+        };
+        relative
+            .file_name()
+            .is_some_and(|name| self.name_globs.is_match(name))
+            || self.path_globs.is_match(&relative)
+    }
+}
+
+/// Error loading a config file
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(PathBuf, std::io::Error),
+    Json(serde_json::Error),
+    Toml(toml::de::Error),
+    UnknownRule(String),
+    Pattern(globset::Error),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(path, err) => write!(f, "failed to read {}: {}", path.display(), err),
+            Self::Json(err) => write!(f, "invalid JSON config: {}", err),
+            Self::Toml(err) => write!(f, "invalid TOML config: {}", err),
+            Self::UnknownRule(name) => write!(f, "unknown rule `{}`", name),
+            Self::Pattern(err) => write!(f, "invalid `files` pattern: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Rule settings loaded from a config file
+#[derive(Debug, Clone, Default)]
+pub struct LintConfig {
+    /// Rule name without the `solid/` prefix -> setting
+    pub rules: FxHashMap<String, RuleSetting>,
+    /// Per-file rules, applied by `for_file`
+    pub overrides: Vec<ConfigOverride>,
+    /// Nested config lookup stops at this file
+    pub root: bool,
+    /// Problems with rule options; those rules fall back to their default options
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl LintConfig {
+    pub fn from_json_str(text: &str) -> Result<Self, ConfigError> {
+        Self::from_raw(serde_json::from_str(text).map_err(ConfigError::Json)?)
+    }
+
+    pub fn from_toml_str(text: &str) -> Result<Self, ConfigError> {
+        Self::from_raw(toml::from_str(text).map_err(ConfigError::Toml)?)
+    }
+
+    /// Load a config file, parsed as TOML for `.toml` files and JSON otherwise;
+    /// override patterns are relative to its directory
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let text =
+            std::fs::read_to_string(path).map_err(|err| ConfigError::Io(path.into(), err))?;
+        let mut config = if path.extension().is_some_and(|ext| ext == "toml") {
+            Self::from_toml_str(&text)?
+        } else {
+            Self::from_json_str(&text)?
+        };
+        let path = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+        for config_override in &mut config.overrides {
+            config_override.base_dir = path.parent().map(Path::to_path_buf);
+        }
+        Ok(config)
+    }
+
+    /// Find the nearest config file in `dir` or its ancestors
+    pub fn find(dir: &Path) -> Option<PathBuf> {
+        dir.ancestors()
+            .flat_map(|dir| CONFIG_FILE_NAMES.iter().map(move |name| dir.join(name)))
+            .find(|path| path.is_file())
+    }
+
+    /// Load the nearest config file, if any, along with its path
+    pub fn discover(dir: &Path) -> Result<Option<(PathBuf, Self)>, ConfigError> {
+        Self::find(dir)
+            .map(|path| Self::load(&path).map(|config| (path, config)))
+            .transpose()
+    }
+
+    fn from_raw(raw: RawLintConfig) -> Result<Self, ConfigError> {
+        let mut config = Self {
+            root: raw.root,
+            ..Self::default()
+        };
+        config.rules = parse_rules(raw.rules, &mut config.diagnostics)?;
+        for raw_override in raw.overrides {
+            let files = match raw_override.files {
+                RawPatterns::One(pattern) => vec![pattern],
+                RawPatterns::Many(patterns) => patterns,
+            };
+            let rules = parse_rules(raw_override.rules, &mut config.diagnostics)?;
+            config.overrides.push(ConfigOverride::new(files, rules)?);
+        }
+        Ok(config)
+    }
+
+    /// The config for one file: the rules with every matching override applied
+    pub fn for_file(&self, path: &Path) -> Self {
+        let mut rules = self.rules.clone();
+        for config_override in &self.overrides {
+            if config_override.matches(path) {
+                merge_rules(&mut rules, &config_override.rules);
+            }
+        }
+        Self {
+            rules,
+            overrides: Vec::new(),
+            root: self.root,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Layer a nested config over this one; its rules and overrides win,
+    /// including over the overrides of this one
+    pub fn extend(&mut self, nested: &Self) {
+        merge_rules(&mut self.rules, &nested.rules);
+        if !self.overrides.is_empty() && !nested.rules.is_empty() {
+            self.overrides.push(ConfigOverride::all(nested.rules.clone()));
+        }
+        self.overrides.extend(nested.overrides.iter().cloned());
+    }
+
+    /// The configured severity of a rule, `None` when it is not in the config
+    pub fn severity(&self, rule: &str) -> Option<RuleSeverity> {
+        let rule = rule.strip_prefix("solid/").unwrap_or(rule);
+        self.rules.get(rule).map(|setting| setting.severity)
+    }
+
+    /// Whether a plugin rule should be registered: anything but `"off"`
+    pub fn plugin_enabled(&self, rule: &str) -> bool {
+        self.severity(rule) != Some(RuleSeverity::Off)
+    }
+
+    /// AST rules: the defaults, with configured rules turned on or off
+    pub fn rules_config(&self) -> RulesConfig {
+        let mut rules = RulesConfig::default();
+        let mut semantic = SemanticRulesConfig::none();
+        for (name, setting) in &self.rules {
+            // Options were validated when loading
+            let _ = set_rule(
+                &mut rules,
+                &mut semantic,
+                name,
+                setting.severity != RuleSeverity::Off,
+                setting.options.as_ref(),
+            );
+        }
+        rules
+    }
+
+    /// Semantic rules: the defaults, with configured rules turned on or off
+    pub fn semantic_rules_config(&self) -> SemanticRulesConfig {
+        let mut rules = RulesConfig::none();
+        let mut semantic = SemanticRulesConfig::all();
+        for (name, setting) in &self.rules {
+            // Options were validated when loading
+            let _ = set_rule(
+                &mut rules,
+                &mut semantic,
+                name,
+                setting.severity != RuleSeverity::Off,
+                setting.options.as_ref(),
+            );
+        }
+        semantic
+    }
+
+    /// Give diagnostics of configured rules the configured severity
+    pub fn apply_severity(&self, diagnostics: &mut [Diagnostic]) {
+        for diagnostic in diagnostics {
+            match self.severity(&diagnostic.rule) {
+                Some(RuleSeverity::Error) => diagnostic.severity = DiagnosticSeverity::Error,
+                Some(RuleSeverity::Warn) => diagnostic.severity = DiagnosticSeverity::Warning,
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Merges nested config files, loading each file once
+#[derive(Debug, Default)]
+pub struct ConfigResolver {
+    /// Config file path -> its config
+    loaded: FxHashMap<PathBuf, LintConfig>,
+}
+
+impl ConfigResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The config for files in `dir`: every config file from the outermost
+    /// (the file system root or the nearest `"root": true`) to the nearest
+    pub fn resolve(&mut self, dir: &Path) -> Result<LintConfig, ConfigError> {
+        let dir = std::path::absolute(dir).unwrap_or_else(|_| dir.to_path_buf());
+        let mut paths = Vec::new();
+        for ancestor in dir.ancestors() {
+            let Some(path) = CONFIG_FILE_NAMES
+                .iter()
+                .map(|name| ancestor.join(name))
+                .find(|path| path.is_file())
+            else {
+                continue;
+            };
+            if !self.loaded.contains_key(&path) {
+                let config = LintConfig::load(&path)?;
+                self.loaded.insert(path.clone(), config);
+            }
+            let root = self.loaded[&path].root;
+            paths.push(path);
+            if root {
+                break;
+            }
+        }
+
+        let mut config = LintConfig::default();
+        for path in paths.iter().rev() {
+            config.extend(&self.loaded[path]);
+        }
+        Ok(config)
+    }
+
+    /// Config file problems, with the file they were found in
+    pub fn diagnostics(&self) -> impl Iterator<Item = (&Path, &Diagnostic)> {
+        self.loaded.iter().flat_map(|(path, config)| {
+            config
+                .diagnostics
+                .iter()
+                .map(move |diagnostic| (path.as_path(), diagnostic))
+        })
+    }
+}
+
+/// Validate rule settings, reporting invalid options to `diagnostics`
+fn parse_rules(
+    raw: FxHashMap<String, RawRuleSetting>,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<FxHashMap<String, RuleSetting>, ConfigError> {
+    let mut rules = FxHashMap::default();
+    for (name, setting) in raw {
+        let name = name.strip_prefix("solid/").unwrap_or(&name).to_string();
+        let (severity, mut options) = match setting {
+            RawRuleSetting::Severity(severity) => (severity, None),
+            RawRuleSetting::WithOptions(severity, options) => (severity, Some(options)),
+        };
+        // Plugin rules are not known here; their options are kept as written
+        if is_plugin_rule_name(&name) {
+            rules.insert(name, RuleSetting { severity, options });
+            continue;
+        }
+        match set_rule(
+            &mut RulesConfig::none(),
+            &mut SemanticRulesConfig::none(),
+            &name,
+            true,
+            options.as_ref(),
+        ) {
+            Ok(_) => {}
+            Err(RuleError::Unknown) => return Err(ConfigError::UnknownRule(name)),
+            Err(RuleError::InvalidOptions(message)) => {
+                diagnostics.push(
+                    Diagnostic::error(
+                        name.clone(),
+                        Span::default(),
+                        format!("Invalid options for `{}`: {}", name, message),
+                    )
+                    .with_help("The rule runs with its default options."),
+                );
+                options = None;
+            }
+        }
+        rules.insert(name, RuleSetting { severity, options });
+    }
+    Ok(rules)
+}
+
+/// Settings in `layer` replace those in `rules`, but a bare severity keeps the
+/// options set before, like ESLint
+fn merge_rules(rules: &mut FxHashMap<String, RuleSetting>, layer: &FxHashMap<String, RuleSetting>) {
+    for (name, setting) in layer {
+        let options = match (&setting.options, rules.get(name)) {
+            (None, Some(previous)) => previous.options.clone(),
+            (options, _) => options.clone(),
+        };
+        rules.insert(
+            name.clone(),
+            RuleSetting {
+                severity: setting.severity,
+                options,
+            },
+        );
+    }
+}
+
+pub(crate) enum RuleError {
+    Unknown,
+    InvalidOptions(String),
+}
+
+fn parse_options<R: ConfigurableRule>(
+    options: Option<&serde_json::Value>,
+) -> Result<R::Options, RuleError> {
+    match options {
+        Some(options) => R::Options::deserialize(options)
+            .map_err(|err| RuleError::InvalidOptions(err.to_string())),
+        None => Ok(R::Options::default()),
+    }
+}
+
+fn no_options(options: Option<&serde_json::Value>) -> Result<(), RuleError> {
+    match options {
+        Some(_) => Err(RuleError::InvalidOptions(
+            "the rule takes no options".to_string(),
+        )),
+        None => Ok(()),
+    }
+}
+
+fn flag(
+    rule: &mut bool,
+    enabled: bool,
+    options: Option<&serde_json::Value>,
+) -> Result<(), RuleError> {
+    no_options(options)?;
+    *rule = enabled;
+    Ok(())
+}
+
+/// Turn a configurable rule on or off; enabling it without options keeps its
+/// current configuration
+fn toggle<R: ConfigurableRule>(
+    rule: &mut Option<R>,
+    enabled: bool,
+    options: Option<&serde_json::Value>,
+) -> Result<(), RuleError> {
+    let parsed = parse_options::<R>(options)?;
+    if !enabled {
+        *rule = None;
+    } else if options.is_some() || rule.is_none() {
+        *rule = Some(R::from_options(parsed));
+    }
+    Ok(())
+}
+
+/// Both runners' configs with the rules that are on by default, per
+/// `RuleMeta::DEFAULT_ENABLED`
+pub(crate) fn default_rules() -> (RulesConfig, SemanticRulesConfig) {
+    let mut rules = RulesConfig::none();
+    let mut semantic = SemanticRulesConfig::none();
+    for rule in RULES {
+        // Default options are always valid
+        let _ = set_rule(
+            &mut rules,
+            &mut semantic,
+            rule.name,
+            rule.default_enabled,
+            None,
+        );
+    }
+    (rules, semantic)
+}
+
+/// Turn a rule on or off in whichever runner runs it, with its options
+pub(crate) fn set_rule(
+    rules: &mut RulesConfig,
+    semantic: &mut SemanticRulesConfig,
+    name: &str,
+    enabled: bool,
+    options: Option<&serde_json::Value>,
+) -> Result<(), RuleError> {
+    match name {
+        AltText::NAME => flag(&mut rules.alt_text, enabled, options)?,
+        AnchorIsValid::NAME => flag(&mut rules.anchor_is_valid, enabled, options)?,
+        AriaProps::NAME => flag(&mut rules.aria_props, enabled, options)?,
+        AriaRole::NAME => flag(&mut rules.aria_role, enabled, options)?,
+        ClickEventsHaveKeyEvents::NAME => {
+            flag(&mut rules.click_events_have_key_events, enabled, options)?
+        }
+        NoAutofocus::NAME => toggle(&mut rules.no_autofocus, enabled, options)?,
+        EventHandlers::NAME => toggle(&mut rules.event_handlers, enabled, options)?,
+        EventNameCasing::NAME => toggle(&mut rules.event_name_casing, enabled, options)?,
+        Imports::NAME => flag(&mut rules.imports, enabled, options)?,
+        JsxBooleanValue::NAME => toggle(&mut rules.jsx_boolean_value, enabled, options)?,
+        JsxNoDuplicateProps::NAME => toggle(&mut rules.jsx_no_duplicate_props, enabled, options)?,
+        JsxNoScriptUrl::NAME => {
+            no_options(options)?;
+            rules.jsx_no_script_url = enabled.then(JsxNoScriptUrl::new);
+        }
+        JsxUsesVars::NAME => {
+            flag(&mut rules.jsx_uses_vars, enabled, options)?;
+            semantic.jsx_uses_vars = enabled;
+        }
+        NoArrayHandlers::NAME => flag(&mut rules.no_array_handlers, enabled, options)?,
+        NoAsyncEffect::NAME => flag(&mut rules.no_async_effect, enabled, options)?,
+        NoCreateSignalInJsx::NAME => flag(&mut rules.no_create_signal_in_jsx, enabled, options)?,
+        NoDupeStyleProperties::NAME => flag(&mut rules.no_dupe_style_properties, enabled, options)?,
+        NoInnerhtml::NAME => toggle(&mut rules.no_innerhtml, enabled, options)?,
+        NoMemoSideEffects::NAME => flag(&mut rules.no_memo_side_effects, enabled, options)?,
+        NoReactDeps::NAME => flag(&mut rules.no_react_deps, enabled, options)?,
+        NoReactSpecificProps::NAME => flag(&mut rules.no_react_specific_props, enabled, options)?,
+        NoUnknownNamespaces::NAME => toggle(&mut rules.no_unknown_namespaces, enabled, options)?,
+        NoUnstableJsxInSignal::NAME => {
+            flag(&mut rules.no_unstable_jsx_in_signal, enabled, options)?
+        }
+        PreferClasslist::NAME => toggle(&mut rules.prefer_classlist, enabled, options)?,
+        PreferFor::NAME => flag(&mut rules.prefer_for, enabled, options)?,
+        PreferIndex::NAME => flag(&mut rules.prefer_index, enabled, options)?,
+        PreferShow::NAME => toggle(&mut rules.prefer_show, enabled, options)?,
+        RequireCleanup::NAME => flag(&mut rules.require_cleanup, enabled, options)?,
+        SelfClosingComp::NAME => toggle(&mut rules.self_closing_comp, enabled, options)?,
+        StyleProp::NAME => toggle(&mut rules.style_prop, enabled, options)?,
+        ValidateJsxNesting::NAME => flag(&mut rules.validate_jsx_nesting, enabled, options)?,
+
+        JsxNoUndef::NAME => {
+            if options.is_some() {
+                semantic.jsx_no_undef_options = parse_options::<JsxNoUndef>(options)?;
+            }
+            semantic.jsx_no_undef = enabled;
+        }
+        ComponentNameCasing::NAME => flag(&mut semantic.component_name_casing, enabled, options)?,
+        ComponentsReturnOnce::NAME => flag(&mut semantic.components_return_once, enabled, options)?,
+        Reactivity::NAME => flag(&mut semantic.reactivity, enabled, options)?,
+        NoDestructure::NAME => flag(&mut semantic.no_destructure, enabled, options)?,
+        NoDirectStoreMutation::NAME => {
+            flag(&mut semantic.no_direct_store_mutation, enabled, options)?
+        }
+        NoForwardRef::NAME => flag(&mut semantic.no_forward_ref, enabled, options)?,
+        NoPropsMutation::NAME => flag(&mut semantic.no_props_mutation, enabled, options)?,
+        NoProxyApis::NAME => flag(&mut semantic.no_proxy_apis, enabled, options)?,
+        NoReactiveCallsAtModuleScope::NAME => flag(
+            &mut semantic.no_reactive_calls_at_module_scope,
+            enabled,
+            options,
+        )?,
+        NoRenderInComponent::NAME => flag(&mut semantic.no_render_in_component, enabled, options)?,
+        NoThisInComponents::NAME => flag(&mut semantic.no_this_in_components, enabled, options)?,
+        PreferMergeProps::NAME => flag(&mut semantic.prefer_merge_props, enabled, options)?,
+        PreferOptionalShow::NAME => flag(&mut semantic.prefer_optional_show, enabled, options)?,
+        NoUnusedSignals::NAME => flag(&mut semantic.no_unused_signals, enabled, options)?,
+        NoUnusedVars::NAME => flag(&mut semantic.no_unused_vars, enabled, options)?,
+        _ => return Err(RuleError::Unknown),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::style_prop::StyleStringOption;
+    use oxc_span::Span;
+
+    #[test]
+    fn test_json_config() {
+        let config = LintConfig::from_json_str(
+            r#"{
+                "rules": {
+                    "solid/reactivity": "error",
+                    "prefer-show": "off",
+                    "prefer-index": "warn",
+                    "style-prop": ["warn", { "allowString": true }]
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(config.severity("reactivity"), Some(RuleSeverity::Error));
+        assert_eq!(
+            config.severity("solid/prefer-show"),
+            Some(RuleSeverity::Off)
+        );
+        assert_eq!(config.severity("no-innerhtml"), None);
+        assert_eq!(
+            config.rules["style-prop"].options,
+            Some(serde_json::json!({ "allowString": true }))
+        );
+
+        let rules = config.rules_config();
+        assert!(rules.prefer_show.is_none());
+        assert!(rules.prefer_index);
+        assert!(rules.no_innerhtml.is_some());
+        assert!(config.semantic_rules_config().reactivity);
+    }
+
+    #[test]
+    fn test_rule_options() {
+        let config = LintConfig::from_json_str(
+            r#"{
+                "rules": {
+                    "style-prop": ["warn", { "allowString": true, "styleProps": ["style", "css"] }],
+                    "no-autofocus": ["error", { "ignoreNonDOM": true }],
+                    "jsx-no-undef": ["error", { "autoImport": false }],
+                    "prefer-show": ["warn", { "minBranch": 2 }],
+                    "prefer-for": ["warn", {}]
+                }
+            }"#,
+        )
+        .unwrap();
+        let rules = config.rules_config();
+        let style_prop = rules.style_prop.unwrap();
+        assert_eq!(style_prop.style_string, StyleStringOption::Allow);
+        assert_eq!(style_prop.style_props, ["style", "css"]);
+        assert!(rules.no_autofocus.unwrap().ignore_non_dom);
+        assert!(
+            !config
+                .semantic_rules_config()
+                .jsx_no_undef_options
+                .auto_import
+        );
+
+        // Invalid options are reported and the rule keeps its defaults
+        assert_eq!(rules.prefer_show.unwrap().min_branch_elements, 1);
+        assert!(rules.prefer_for);
+        let mut messages: Vec<_> = config.diagnostics.iter().map(|d| &d.message).collect();
+        messages.sort();
+        assert_eq!(
+            messages,
+            [
+                "Invalid options for `prefer-for`: the rule takes no options",
+                "Invalid options for `prefer-show`: unknown field `minBranch`, expected `minBranchElements` or `fix`",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_toml_config() {
+        let config = LintConfig::from_toml_str(
+            r#"
+            [rules]
+            "solid/no-destructure" = "off"
+            "no-unused-vars" = "warn"
+            "self-closing-comp" = ["error", { html = "void" }]
+            "#,
+        )
+        .unwrap();
+        let semantic = config.semantic_rules_config();
+        assert!(!semantic.no_destructure);
+        assert!(semantic.no_unused_vars);
+        assert!(semantic.reactivity);
+        assert_eq!(
+            config.rules["self-closing-comp"].options,
+            Some(serde_json::json!({ "html": "void" }))
+        );
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        assert!(matches!(
+            LintConfig::from_json_str(r#"{ "rules": { "solid/no-such-rule": "warn" } }"#),
+            Err(ConfigError::UnknownRule(name)) if name == "no-such-rule"
+        ));
+        assert!(matches!(
+            LintConfig::from_json_str(r#"{ "rules": { "reactivity": "loud" } }"#),
+            Err(ConfigError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn test_apply_severity() {
+        let config =
+            LintConfig::from_json_str(r#"{ "rules": { "prefer-for": "error" } }"#).unwrap();
+        let mut diagnostics = vec![
+            Diagnostic::warning(PreferFor::NAME, Span::new(0, 1), "a"),
+            Diagnostic::warning(PreferShow::NAME, Span::new(0, 1), "b"),
+        ];
+        config.apply_severity(&mut diagnostics);
+        assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Error);
+        assert_eq!(diagnostics[1].severity, DiagnosticSeverity::Warning);
+    }
+
+    #[test]
+    fn test_discover() {
+        let root = std::env::temp_dir().join(format!("solid-lint-config-{}", std::process::id()));
+        let nested = root.join("src/components");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(
+            root.join("solid-lint.toml"),
+            "[rules]\nprefer-for = \"off\"\n",
+        )
+        .unwrap();
+
+        let (path, config) = LintConfig::discover(&nested).unwrap().unwrap();
+        assert_eq!(path, root.join("solid-lint.toml"));
+        assert!(!config.rules_config().prefer_for);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_overrides() {
+        let config = LintConfig::from_json_str(
+            r#"{
+                "rules": { "no-innerhtml": "error", "style-prop": ["warn", { "allowString": true }] },
+                "overrides": [
+                    { "files": ["*.test.tsx", "*.stories.tsx"], "rules": { "no-innerhtml": "off" } },
+                    { "files": "src/legacy/**", "rules": { "style-prop": "error" } }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(config.overrides.len(), 2);
+
+        let test_file = config.for_file(Path::new("src/components/Button.test.tsx"));
+        assert_eq!(test_file.severity("no-innerhtml"), Some(RuleSeverity::Off));
+        assert!(test_file.rules_config().no_innerhtml.is_none());
+        assert_eq!(
+            config
+                .for_file(Path::new("src/Button.tsx"))
+                .severity("no-innerhtml"),
+            Some(RuleSeverity::Error)
+        );
+
+        // Path patterns are anchored, and a bare severity keeps the options
+        let legacy = config.for_file(Path::new("src/legacy/Old.tsx"));
+        assert_eq!(legacy.severity("style-prop"), Some(RuleSeverity::Error));
+        assert_eq!(
+            legacy.rules["style-prop"].options,
+            Some(serde_json::json!({ "allowString": true }))
+        );
+        assert_eq!(
+            config
+                .for_file(Path::new("app/src/legacy/Old.tsx"))
+                .severity("style-prop"),
+            Some(RuleSeverity::Warn)
+        );
+
+        let config = LintConfig::from_toml_str(
+            r#"
+            [[overrides]]
+            files = ["*.stories.tsx"]
+            rules = { "solid/prefer-for" = "off" }
+            "#,
+        )
+        .unwrap();
+        assert!(
+            !config
+                .for_file(Path::new("Button.stories.tsx"))
+                .rules_config()
+                .prefer_for
+        );
+    }
+
+    #[test]
+    fn test_invalid_override() {
+        assert!(matches!(
+            LintConfig::from_json_str(r#"{ "overrides": [{ "files": "a[", "rules": {} }] }"#),
+            Err(ConfigError::Pattern(_))
+        ));
+        assert!(matches!(
+            LintConfig::from_json_str(
+                r#"{ "overrides": [{ "files": "*.tsx", "rules": { "nope": "off" } }] }"#
+            ),
+            Err(ConfigError::UnknownRule(name)) if name == "nope"
+        ));
+    }
+
+    #[test]
+    fn test_resolver() {
+        let root = std::env::temp_dir().join(format!("solid-lint-nested-{}", std::process::id()));
+        let app = root.join("app");
+        let stories = app.join("src/stories");
+        std::fs::create_dir_all(&stories).unwrap();
+        std::fs::write(
+            root.join(".solidlint.json"),
+            r#"{ "rules": { "prefer-show": "off", "prefer-for": "off" } }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            app.join("solid-lint.toml"),
+            r#"
+            [rules]
+            prefer-for = "error"
+
+            [[overrides]]
+            files = "src/stories/*.tsx"
+            rules = { no-innerhtml = "off" }
+            "#,
+        )
+        .unwrap();
+
+        let mut resolver = ConfigResolver::new();
+        let config = resolver.resolve(&stories).unwrap();
+        assert_eq!(config.severity("prefer-show"), Some(RuleSeverity::Off));
+        assert_eq!(config.severity("prefer-for"), Some(RuleSeverity::Error));
+        let file = config.for_file(&stories.join("Button.tsx"));
+        assert_eq!(file.severity("no-innerhtml"), Some(RuleSeverity::Off));
+        assert_eq!(
+            config
+                .for_file(&app.join("Button.tsx"))
+                .severity("no-innerhtml"),
+            None
+        );
+
+        // `root` stops the lookup
+        std::fs::write(
+            app.join("solid-lint.toml"),
+            "root = true\n\n[rules]\nprefer-for = \"error\"\n",
+        )
+        .unwrap();
+        let config = ConfigResolver::new().resolve(&stories).unwrap();
+        assert_eq!(config.severity("prefer-show"), None);
+        assert_eq!(resolver.diagnostics().count(), 0);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_resolver_cascade() {
+        let root = std::env::temp_dir().join(format!("solid-lint-cascade-{}", std::process::id()));
+        let pkg = root.join("pkg");
+        std::fs::create_dir_all(pkg.join("legacy")).unwrap();
+        std::fs::write(
+            root.join(".solidlint.json"),
+            r#"{ "root": true, "overrides": [{ "files": "*.tsx", "rules": { "no-innerhtml": "off" } }] }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            pkg.join(".solidlint.json"),
+            r#"{
+                "rules": { "no-innerhtml": "error" },
+                "overrides": [{ "files": "legacy/*.tsx", "rules": { "no-innerhtml": "warn" } }]
+            }"#,
+        )
+        .unwrap();
+
+        // The outer override applies before the nearer file's rules
+        let config = ConfigResolver::new().resolve(&pkg).unwrap();
+        assert_eq!(
+            config.for_file(&pkg.join("a.tsx")).severity("no-innerhtml"),
+            Some(RuleSeverity::Error)
+        );
+        assert_eq!(
+            config
+                .for_file(&pkg.join("legacy/a.tsx"))
+                .severity("no-innerhtml"),
+            Some(RuleSeverity::Warn)
+        );
+        assert_eq!(
+            ConfigResolver::new()
+                .resolve(&root)
+                .unwrap()
+                .for_file(&root.join("a.tsx"))
+                .severity("no-innerhtml"),
+            Some(RuleSeverity::Off)
+        );
+
+        let file = config.for_file(&pkg.join("a.tsx"));
+        let diagnostics = crate::lint_source(
+            "<div innerHTML={x} />;",
+            oxc_span::SourceType::tsx(),
+            &file,
+            &[],
+        );
+        assert!(diagnostics.iter().any(|d| d.rule == NoInnerhtml::NAME));
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -4,13 +4,40 @@
 //! - Source text and type information
 //! - Semantic analysis (scopes, symbols, etc.)
 //! - Symbol tracking (used variables, component detection)
+//! - TypeScript normalization: `x as T`, `x satisfies T`, `x!`, `<T>x` and
+//!   type-only imports, so rules see TSX the way they see JSX
 
+use oxc_ast::ast::{Argument, Expression, JSXExpressionContainer};
 use oxc_semantic::{ScopeId, Scoping, Semantic, SymbolId};
 use oxc_span::SourceType;
 use rustc_hash::FxHashSet;
 
 use crate::Diagnostic;
 
+/// The expression under any TypeScript wrappers and parentheses:
+/// `(cond && <div />) as JSX.Element` is `cond && <div />`
+pub fn strip_ts_wrappers<'b, 'a>(expr: &'b Expression<'a>) -> &'b Expression<'a> {
+    expr.get_inner_expression()
+}
+
+/// The expression of `{...}` in JSX, without TypeScript wrappers; `None` for `{}`
+pub fn jsx_container_expression<'b, 'a>(
+    container: &'b JSXExpressionContainer<'a>,
+) -> Option<&'b Expression<'a>> {
+    container.expression.as_expression().map(strip_ts_wrappers)
+}
+
+/// A call argument without TypeScript wrappers; `None` for spreads
+pub fn argument_expression<'b, 'a>(argument: &'b Argument<'a>) -> Option<&'b Expression<'a>> {
+    argument.as_expression().map(strip_ts_wrappers)
+}
+
+/// Whether a symbol comes from `import type` or `import { type X }`, and so has
+/// no runtime value
+pub fn is_type_only_import(scoping: &Scoping, symbol_id: SymbolId) -> bool {
+    scoping.symbol_flags(symbol_id).is_type_import()
+}
+
 /// Context passed to rules during linting
 pub struct LintContext<'a> {
     /// Source code being linted
@@ -82,9 +109,10 @@ impl<'a> LintContext<'a> {
         self.diagnostics.push(diagnostic);
     }
 
-    /// Get a slice of source text for a span
+    /// Get a slice of source text for a span, empty if the span is not
+    /// inside the source or splits a character
     pub fn span_text(&self, span: oxc_span::Span) -> &'a str {
-        &self.source_text[span.start as usize..span.end as usize]
+        crate::utils::span_text(self.source_text, span)
     }
 
     /// Consume the context and return all diagnostics
@@ -158,4 +186,23 @@ impl<'a> LintContext<'a> {
     pub fn solid_imports(&self) -> &FxHashSet<String> {
         &self.solid_imports
     }
+
+    // ==================== TypeScript ====================
+
+    /// The expression under any TypeScript wrappers, see `strip_ts_wrappers`
+    pub fn strip_ts<'b>(&self, expr: &'b Expression<'a>) -> &'b Expression<'a> {
+        strip_ts_wrappers(expr)
+    }
+
+    /// Whether a symbol is only imported as a type; false without semantic
+    pub fn is_type_only_import(&self, symbol_id: SymbolId) -> bool {
+        self.scoping()
+            .is_some_and(|scoping| is_type_only_import(scoping, symbol_id))
+    }
+
+    /// Resolve `name` to a binding with a runtime value, skipping type-only imports
+    pub fn resolve_value(&self, scope_id: ScopeId, name: &str) -> Option<SymbolId> {
+        self.resolve_binding(scope_id, name)
+            .filter(|&symbol_id| !self.is_type_only_import(symbol_id))
+    }
 }
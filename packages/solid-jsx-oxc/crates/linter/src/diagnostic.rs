@@ -2,6 +2,8 @@
 
 use oxc_span::Span;
 
+use crate::registry::{find_rule, RuleInfo};
+
 /// Severity level for diagnostics
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DiagnosticSeverity {
@@ -44,6 +46,31 @@ impl Fix {
     }
 }
 
+/// An edit offered for a diagnostic that needs a human to confirm it, e.g.
+/// because it may change behavior. Unlike fixes, suggestions are never applied
+/// by `--fix`, and a diagnostic can offer several alternatives.
+#[derive(Debug, Clone)]
+pub struct Suggestion {
+    /// Description of what the suggestion does
+    pub message: String,
+    /// The edits making up the suggestion, applied together
+    pub fixes: Vec<Fix>,
+}
+
+impl Suggestion {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            fixes: Vec::new(),
+        }
+    }
+
+    pub fn with_fix(mut self, fix: Fix) -> Self {
+        self.fixes.push(fix);
+        self
+    }
+}
+
 /// A lint diagnostic
 #[derive(Debug, Clone)]
 pub struct Diagnostic {
@@ -63,12 +90,18 @@ pub struct Diagnostic {
     pub labels: Vec<(u32, u32, String)>,
     /// Suggested fixes
     pub fixes: Vec<Fix>,
+    /// Alternative edits that are not applied automatically
+    pub suggestions: Vec<Suggestion>,
+    /// Documentation of the rule, when it is a built-in rule
+    pub url: Option<String>,
 }
 
 impl Diagnostic {
     pub fn new(rule: impl Into<String>, span: Span, message: impl Into<String>) -> Self {
+        let rule = rule.into();
         Self {
-            rule: rule.into(),
+            url: find_rule(&rule).map(RuleInfo::docs_url),
+            rule,
             start: span.start,
             end: span.end,
             message: message.into(),
@@ -76,6 +109,7 @@ impl Diagnostic {
             severity: DiagnosticSeverity::Warning,
             labels: Vec::new(),
             fixes: Vec::new(),
+            suggestions: Vec::new(),
         }
     }
 
@@ -103,6 +137,11 @@ impl Diagnostic {
         self
     }
 
+    pub fn with_suggestion(mut self, suggestion: Suggestion) -> Self {
+        self.suggestions.push(suggestion);
+        self
+    }
+
     pub fn error(rule: impl Into<String>, span: Span, message: impl Into<String>) -> Self {
         Self::new(rule, span, message).with_severity(DiagnosticSeverity::Error)
     }
@@ -0,0 +1,198 @@
+//! Applying fixes to source text
+//!
+//! All fixes of a diagnostic are parts of one edit (renaming a component
+//! touches every reference), so they are applied together or not at all.
+//! When edits of two diagnostics overlap, the one starting first wins and the
+//! other is left for the next pass, after the source has been linted again.
+
+use crate::diagnostic::{Diagnostic, Fix};
+
+/// Upper bound on lint/fix passes, in case two rules keep undoing each other
+pub const MAX_FIX_PASSES: usize = 10;
+
+/// Source text after applying fixes
+#[derive(Debug, Clone)]
+pub struct FixResult {
+    /// The fixed source text
+    pub output: String,
+    /// Diagnostics whose fixes were applied
+    pub fixed: Vec<Diagnostic>,
+    /// Diagnostics without a fix, or whose fix conflicted with an applied one
+    pub remaining: Vec<Diagnostic>,
+}
+
+impl FixResult {
+    pub fn is_changed(&self) -> bool {
+        !self.fixed.is_empty()
+    }
+}
+
+/// Apply the fixes of `diagnostics` to `source_text` in a single pass
+pub fn apply_fixes(source_text: &str, diagnostics: Vec<Diagnostic>) -> FixResult {
+    let mut diagnostics = diagnostics;
+    diagnostics.sort_by_key(|d| {
+        (
+            d.fixes.iter().map(|f| f.start).min().unwrap_or(u32::MAX),
+            d.start,
+        )
+    });
+
+    let mut edits: Vec<&Fix> = Vec::new();
+    let mut accepted = Vec::with_capacity(diagnostics.len());
+    for diagnostic in &diagnostics {
+        let fixes = &diagnostic.fixes;
+        let applies = !fixes.is_empty()
+            && fixes.iter().all(|fix| is_valid(source_text, fix))
+            && fixes.iter().enumerate().all(|(i, fix)| {
+                !fixes[..i].iter().any(|other| conflicts(fix, other))
+                    && !edits.iter().any(|other| conflicts(fix, other))
+            });
+        if applies {
+            edits.extend(fixes);
+        }
+        accepted.push(applies);
+    }
+
+    edits.sort_by_key(|fix| (fix.start, fix.end));
+    let mut output = String::with_capacity(source_text.len());
+    let mut last = 0;
+    for fix in edits {
+        output.push_str(&source_text[last..fix.start as usize]);
+        output.push_str(&fix.replacement);
+        last = fix.end as usize;
+    }
+    output.push_str(&source_text[last..]);
+
+    let (fixed, remaining) = diagnostics
+        .into_iter()
+        .zip(accepted)
+        .partition::<Vec<_>, _>(|(_, applies)| *applies);
+    FixResult {
+        output,
+        fixed: fixed.into_iter().map(|(d, _)| d).collect(),
+        remaining: remaining.into_iter().map(|(d, _)| d).collect(),
+    }
+}
+
+/// Lint and fix repeatedly until no fix applies, as `--fix` does
+///
+/// `lint` is called with the current source text and returns its diagnostics,
+/// typically by parsing it and running both lint runners. Diagnostics in
+/// `fixed` point into the source of the pass that fixed them; `remaining` are
+/// from linting `output`.
+pub fn fix_until_stable(
+    source_text: &str,
+    mut lint: impl FnMut(&str) -> Vec<Diagnostic>,
+) -> FixResult {
+    let mut output = source_text.to_string();
+    let mut fixed = Vec::new();
+    for _ in 0..MAX_FIX_PASSES {
+        let pass = apply_fixes(&output, lint(&output));
+        if !pass.is_changed() {
+            return FixResult {
+                output,
+                fixed,
+                remaining: pass.remaining,
+            };
+        }
+        fixed.extend(pass.fixed);
+        output = pass.output;
+    }
+    let remaining = lint(&output);
+    FixResult {
+        output,
+        fixed,
+        remaining,
+    }
+}
+
+fn is_valid(source_text: &str, fix: &Fix) -> bool {
+    fix.start <= fix.end
+        && source_text.is_char_boundary(fix.start as usize)
+        && source_text.is_char_boundary(fix.end as usize)
+}
+
+/// Overlapping ranges, or two edits at the same position whose order would be
+/// ambiguous
+fn conflicts(a: &Fix, b: &Fix) -> bool {
+    a.start == b.start || (a.start < b.end && b.start < a.end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostic::Suggestion;
+    use crate::visitor::{lint_with_config, RulesConfig};
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::{SourceType, Span};
+
+    fn diagnostic(fixes: &[(u32, u32, &str)]) -> Diagnostic {
+        fixes.iter().fold(
+            Diagnostic::warning("test", Span::new(0, 0), "test"),
+            |diagnostic, (start, end, replacement)| {
+                diagnostic.with_fix(Fix::new(Span::new(*start, *end), *replacement))
+            },
+        )
+    }
+
+    #[test]
+    fn test_apply_fixes() {
+        let result = apply_fixes(
+            "let a = b;",
+            vec![
+                diagnostic(&[(8, 9, "c")]),
+                diagnostic(&[(4, 5, "x"), (8, 9, "x")]),
+            ],
+        );
+        assert_eq!(result.output, "let x = x;");
+        assert_eq!(result.fixed.len(), 1);
+        assert_eq!(result.remaining.len(), 1);
+
+        let result = apply_fixes(
+            "a b",
+            vec![
+                diagnostic(&[(2, 2, "!")]),
+                diagnostic(&[]),
+                diagnostic(&[(0, 1, "é")]),
+                diagnostic(&[(1, 1, "?")]),
+            ],
+        );
+        assert_eq!(result.output, "é? !b");
+        assert_eq!(result.remaining.len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_fixes() {
+        let result = apply_fixes(
+            "é",
+            vec![diagnostic(&[(1, 2, "e")]), diagnostic(&[(2, 1, "")])],
+        );
+        assert_eq!(result.output, "é");
+        assert!(!result.is_changed());
+    }
+
+    #[test]
+    fn test_suggestions_not_applied() {
+        let suggested = Diagnostic::warning("test", Span::new(0, 1), "test")
+            .with_suggestion(Suggestion::new("Rename").with_fix(Fix::new(Span::new(0, 1), "b")));
+        let result = apply_fixes("a", vec![suggested]);
+        assert_eq!(result.output, "a");
+        assert_eq!(result.remaining.len(), 1);
+    }
+
+    #[test]
+    fn test_fix_until_stable() {
+        let config = RulesConfig::none()
+            .with_no_react_specific_props(true)
+            .with_self_closing_comp(crate::rules::SelfClosingComp::new());
+        let result = fix_until_stable(r#"<div className="a"></div>"#, |source| {
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, source, SourceType::jsx()).parse();
+            lint_with_config(source, SourceType::jsx(), &ret.program, config.clone()).diagnostics
+        });
+        assert_eq!(result.output, r#"<div class="a" />"#);
+        assert_eq!(result.fixed.len(), 2);
+        assert!(result.remaining.is_empty());
+    }
+}
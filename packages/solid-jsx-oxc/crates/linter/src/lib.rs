@@ -6,20 +6,40 @@
 //! 2. Integrated with oxlint as a plugin (future)
 //! 3. With type-aware analysis via tsgolint integration (future)
 
+pub mod config;
+pub mod plugin;
+pub mod registry;
 pub mod rules;
 pub mod semantic_visitor;
+pub mod source;
+pub mod summary;
+#[cfg(any(test, feature = "tester"))]
+pub mod tester;
 pub mod utils;
 pub mod visitor;
 mod context;
 mod diagnostic;
+mod fix;
 
-pub use context::LintContext;
-pub use diagnostic::{Diagnostic, DiagnosticSeverity, Fix};
+pub use config::{
+    ConfigError, ConfigOverride, ConfigResolver, LintConfig, RuleSetting, RuleSeverity,
+    CONFIG_FILE_NAMES,
+};
+pub use context::{
+    argument_expression, is_type_only_import, jsx_container_expression, strip_ts_wrappers,
+    LintContext,
+};
+pub use diagnostic::{Diagnostic, DiagnosticSeverity, Fix, Suggestion};
+pub use fix::{apply_fixes, fix_until_stable, FixResult, MAX_FIX_PASSES};
+pub use plugin::Rule;
+pub use registry::{find_rule, RuleInfo, RULES};
 pub use rules::*;
 pub use semantic_visitor::{
     lint_with_semantic, lint_with_semantic_config, SemanticLintResult, SemanticLintRunner,
     SemanticRulesConfig,
 };
+pub use source::{lint_source, PARSE_ERROR_RULE};
+pub use summary::{ExitStatus, LintSummary};
 pub use visitor::{lint, lint_with_config, LintResult, LintRunner, RulesConfig, VisitorLintContext};
 
 /// Rule category for Solid rules
@@ -39,11 +59,65 @@ pub enum RuleCategory {
 pub trait RuleMeta {
     const NAME: &'static str;
     const CATEGORY: RuleCategory;
+    /// What the rule reports and why, shown by `solid-lint --explain`
+    const DESCRIPTION: &'static str;
+    /// Whether some diagnostics carry a fix that `apply_fixes` applies
+    const FIXABLE: bool = false;
+    /// Severity of the rule's diagnostics unless the config overrides it
+    const DEFAULT_SEVERITY: DiagnosticSeverity = DiagnosticSeverity::Warning;
+    /// Whether the rule runs when the config does not mention it; opt-in rules
+    /// are those that are noisy or duplicate checks most setups already have
+    const DEFAULT_ENABLED: bool = true;
     /// URL to documentation
     fn docs_url() -> String {
+        docs_url(Self::NAME)
+    }
+}
+
+/// Rules ported from eslint-plugin-solid, which documents them; the others
+/// are documented in `crates/linter/docs/rules`
+const ESLINT_PLUGIN_SOLID_RULES: &[&str] = &[
+    "components-return-once",
+    "event-handlers",
+    "imports",
+    "jsx-no-duplicate-props",
+    "jsx-no-script-url",
+    "jsx-no-undef",
+    "jsx-uses-vars",
+    "no-array-handlers",
+    "no-destructure",
+    "no-innerhtml",
+    "no-proxy-apis",
+    "no-react-deps",
+    "no-react-specific-props",
+    "no-unknown-namespaces",
+    "prefer-classlist",
+    "prefer-for",
+    "prefer-show",
+    "reactivity",
+    "self-closing-comp",
+    "style-prop",
+];
+
+/// URL to the documentation of the rule called `name`
+pub fn docs_url(name: &str) -> String {
+    if ESLINT_PLUGIN_SOLID_RULES.contains(&name) {
         format!(
             "https://github.com/solidjs-community/eslint-plugin-solid/blob/main/docs/{}.md",
-            Self::NAME
+            name
+        )
+    } else {
+        format!(
+            "https://github.com/ryansolid/solid-jsx-oxc/blob/main/packages/solid-jsx-oxc/crates/linter/docs/rules/{}.md",
+            name
         )
     }
 }
+
+/// A rule that takes options from the config file
+pub trait ConfigurableRule: RuleMeta + Sized {
+    /// Options as written in the config file; omitted fields keep their defaults
+    type Options: serde::de::DeserializeOwned + Default;
+
+    fn from_options(options: Self::Options) -> Self;
+}
@@ -0,0 +1,196 @@
+//! Rules defined outside this crate
+//!
+//! A plugin rule implements `Rule` and is registered with
+//! `SemanticRulesConfig::with_plugin`. Its hooks run during the semantic
+//! runner's walk, next to the built-in rules, and report through a shared
+//! `LintContext` that knows the semantic model, the Solid imports and the
+//! components of the file.
+//!
+//! Plugin rule names carry their own namespace (`acme/no-legacy-button`).
+//! Config files accept them like built-in rules; see `LintConfig::plugin_enabled`.
+
+use std::fmt;
+
+use oxc_ast::ast::{CallExpression, JSXElement, Program};
+
+use crate::context::LintContext;
+
+/// A lint rule supplied by a downstream project
+pub trait Rule: Send + Sync {
+    /// Name used in diagnostics and config files, with a namespace such as
+    /// `acme/`
+    fn name(&self) -> &str;
+
+    /// Called once per file, after the whole program has been walked
+    fn run_on_program<'a>(&self, _program: &Program<'a>, _ctx: &mut LintContext<'a>) {}
+
+    /// Called for every JSX element, outermost first
+    fn run_on_jsx_element<'a>(&self, _element: &JSXElement<'a>, _ctx: &mut LintContext<'a>) {}
+
+    /// Called for every call expression
+    fn run_on_call_expression<'a>(&self, _call: &CallExpression<'a>, _ctx: &mut LintContext<'a>) {}
+}
+
+impl fmt::Debug for dyn Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Rule").field(&self.name()).finish()
+    }
+}
+
+/// Whether `name` belongs to a plugin: namespaced, but not `solid/`
+pub fn is_plugin_rule_name(name: &str) -> bool {
+    name.split_once('/').is_some_and(|(namespace, rule)| {
+        namespace != "solid" && !namespace.is_empty() && !rule.is_empty()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::config::{LintConfig, RuleSeverity};
+    use crate::semantic_visitor::{SemanticLintRunner, SemanticRulesConfig};
+    use crate::Diagnostic;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, JSXElementName};
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{GetSpan, SourceType};
+
+    /// `<LegacyButton>` is replaced by `<Button>`
+    struct NoLegacyButton;
+
+    impl Rule for NoLegacyButton {
+        fn name(&self) -> &str {
+            "acme/no-legacy-button"
+        }
+
+        fn run_on_jsx_element<'a>(&self, element: &JSXElement<'a>, ctx: &mut LintContext<'a>) {
+            let name = &element.opening_element.name;
+            if matches!(name, JSXElementName::IdentifierReference(ident) if ident.name == "LegacyButton")
+            {
+                ctx.report(Diagnostic::warning(
+                    self.name(),
+                    name.span(),
+                    "Use <Button />",
+                ));
+            }
+        }
+    }
+
+    /// Effects must come from solid-js, and there may be at most one per file
+    struct OneEffect;
+
+    impl Rule for OneEffect {
+        fn name(&self) -> &str {
+            "acme/one-effect"
+        }
+
+        fn run_on_call_expression<'a>(&self, call: &CallExpression<'a>, ctx: &mut LintContext<'a>) {
+            if let Expression::Identifier(ident) = &call.callee {
+                if ident.name == "createEffect" && ctx.is_solid_import("createEffect") {
+                    ctx.report(Diagnostic::warning(self.name(), call.span, "effect"));
+                }
+            }
+        }
+
+        fn run_on_program<'a>(&self, program: &Program<'a>, ctx: &mut LintContext<'a>) {
+            let effects = ctx
+                .diagnostics()
+                .iter()
+                .filter(|d| d.rule == self.name())
+                .count();
+            if effects > 1 {
+                ctx.report(Diagnostic::error(
+                    self.name(),
+                    program.span,
+                    "Too many effects",
+                ));
+            }
+        }
+    }
+
+    crate::rule_test!(test_plugin_rule_test, plugin NoLegacyButton, {
+        valid: ["<Button />", "<div><LegacyButtons /></div>"],
+        invalid: [
+            {
+                code: "<div><LegacyButton /></div>",
+                errors: [{ message: "Use <Button />", text: "LegacyButton" }],
+            },
+        ],
+    });
+
+    fn lint(source: &str, config: SemanticRulesConfig) -> Vec<Diagnostic> {
+        let allocator = Allocator::default();
+        let source_type = SourceType::tsx();
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        SemanticLintRunner::new(&semantic, source, source_type, config)
+            .run(&ret.program)
+            .diagnostics
+    }
+
+    #[test]
+    fn test_plugin_rules() {
+        let config = SemanticRulesConfig::none()
+            .with_plugin(Arc::new(NoLegacyButton))
+            .with_plugin(Arc::new(OneEffect));
+        let diagnostics = lint(
+            r#"
+            import { createEffect } from "solid-js";
+            const App = () => {
+                createEffect(() => {});
+                createEffect(() => {});
+                return <div><LegacyButton /></div>;
+            };
+            "#,
+            config,
+        );
+        let rules: Vec<_> = diagnostics.iter().map(|d| d.rule.as_str()).collect();
+        assert_eq!(
+            rules,
+            [
+                "acme/one-effect",
+                "acme/one-effect",
+                "acme/no-legacy-button",
+                "acme/one-effect"
+            ]
+        );
+        assert!(diagnostics.iter().all(|d| d.url.is_none()));
+    }
+
+    #[test]
+    fn test_plugin_shares_solid_imports() {
+        let config = SemanticRulesConfig::none().with_plugin(Arc::new(OneEffect));
+        // Not imported from solid-js
+        assert!(lint("createEffect(() => {});", config.clone()).is_empty());
+        // Imported as a type only
+        assert!(lint(
+            "import { type createEffect } from \"solid-js\";\ncreateEffect(() => {});",
+            config
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_plugin_config() {
+        assert!(is_plugin_rule_name("acme/no-legacy-button"));
+        assert!(!is_plugin_rule_name("solid/reactivity"));
+        assert!(!is_plugin_rule_name("reactivity"));
+        assert!(!is_plugin_rule_name("/x"));
+
+        let config = LintConfig::from_json_str(
+            r#"{ "rules": { "acme/no-legacy-button": "off", "acme/one-effect": "error" } }"#,
+        )
+        .unwrap();
+        assert!(!config.plugin_enabled("acme/no-legacy-button"));
+        assert!(config.plugin_enabled("acme/one-effect"));
+        assert!(config.plugin_enabled("acme/unconfigured"));
+        assert_eq!(
+            config.severity("acme/one-effect"),
+            Some(RuleSeverity::Error)
+        );
+        assert!(LintConfig::from_json_str(r#"{ "rules": { "solid/nope": "off" } }"#).is_err());
+    }
+}
@@ -0,0 +1,172 @@
+//! Metadata of every built-in rule, looked up by name
+//!
+//! Tooling uses this to explain rules (`solid-lint --explain`), link
+//! diagnostics to their documentation and list what `--fix` can fix.
+
+use crate::diagnostic::DiagnosticSeverity;
+use crate::rules::*;
+use crate::{RuleCategory, RuleMeta};
+
+/// A rule's `RuleMeta`, as a value
+#[derive(Debug, Clone, Copy)]
+pub struct RuleInfo {
+    pub name: &'static str,
+    pub category: RuleCategory,
+    pub description: &'static str,
+    pub fixable: bool,
+    pub default_severity: DiagnosticSeverity,
+    pub default_enabled: bool,
+    docs_url: fn() -> String,
+}
+
+impl RuleInfo {
+    pub const fn of<R: RuleMeta>() -> Self {
+        Self {
+            name: R::NAME,
+            category: R::CATEGORY,
+            description: R::DESCRIPTION,
+            fixable: R::FIXABLE,
+            default_severity: R::DEFAULT_SEVERITY,
+            default_enabled: R::DEFAULT_ENABLED,
+            docs_url: R::docs_url,
+        }
+    }
+
+    pub fn docs_url(&self) -> String {
+        (self.docs_url)()
+    }
+}
+
+/// All built-in rules, sorted by name
+pub static RULES: &[RuleInfo] = &[
+    RuleInfo::of::<AltText>(),
+    RuleInfo::of::<AnchorIsValid>(),
+    RuleInfo::of::<AriaProps>(),
+    RuleInfo::of::<AriaRole>(),
+    RuleInfo::of::<ClickEventsHaveKeyEvents>(),
+    RuleInfo::of::<ComponentNameCasing>(),
+    RuleInfo::of::<ComponentsReturnOnce>(),
+    RuleInfo::of::<EventHandlers>(),
+    RuleInfo::of::<EventNameCasing>(),
+    RuleInfo::of::<Imports>(),
+    RuleInfo::of::<JsxBooleanValue>(),
+    RuleInfo::of::<JsxNoDuplicateProps>(),
+    RuleInfo::of::<JsxNoScriptUrl>(),
+    RuleInfo::of::<JsxNoUndef>(),
+    RuleInfo::of::<JsxUsesVars>(),
+    RuleInfo::of::<NoArrayHandlers>(),
+    RuleInfo::of::<NoAsyncEffect>(),
+    RuleInfo::of::<NoAutofocus>(),
+    RuleInfo::of::<NoCreateSignalInJsx>(),
+    RuleInfo::of::<NoDestructure>(),
+    RuleInfo::of::<NoDirectStoreMutation>(),
+    RuleInfo::of::<NoDupeStyleProperties>(),
+    RuleInfo::of::<NoForwardRef>(),
+    RuleInfo::of::<NoInnerhtml>(),
+    RuleInfo::of::<NoMemoSideEffects>(),
+    RuleInfo::of::<NoPropsMutation>(),
+    RuleInfo::of::<NoProxyApis>(),
+    RuleInfo::of::<NoReactDeps>(),
+    RuleInfo::of::<NoReactSpecificProps>(),
+    RuleInfo::of::<NoReactiveCallsAtModuleScope>(),
+    RuleInfo::of::<NoRenderInComponent>(),
+    RuleInfo::of::<NoThisInComponents>(),
+    RuleInfo::of::<NoUnknownNamespaces>(),
+    RuleInfo::of::<NoUnstableJsxInSignal>(),
+    RuleInfo::of::<NoUnusedSignals>(),
+    RuleInfo::of::<NoUnusedVars>(),
+    RuleInfo::of::<PreferClasslist>(),
+    RuleInfo::of::<PreferFor>(),
+    RuleInfo::of::<PreferIndex>(),
+    RuleInfo::of::<PreferMergeProps>(),
+    RuleInfo::of::<PreferOptionalShow>(),
+    RuleInfo::of::<PreferShow>(),
+    RuleInfo::of::<Reactivity>(),
+    RuleInfo::of::<RequireCleanup>(),
+    RuleInfo::of::<SelfClosingComp>(),
+    RuleInfo::of::<StyleProp>(),
+    RuleInfo::of::<ValidateJsxNesting>(),
+];
+
+/// The rule called `name`, with or without the `solid/` prefix
+pub fn find_rule(name: &str) -> Option<&'static RuleInfo> {
+    let name = name.strip_prefix("solid/").unwrap_or(name);
+    RULES.iter().find(|rule| rule.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LintConfig;
+
+    #[test]
+    fn test_registry() {
+        assert!(RULES.windows(2).all(|pair| pair[0].name < pair[1].name));
+        for rule in RULES {
+            assert!(!rule.description.is_empty(), "{}", rule.name);
+            // Every registered rule can be configured
+            let config = LintConfig::from_json_str(&format!(
+                r#"{{ "rules": {{ "{}": "off" }} }}"#,
+                rule.name
+            ));
+            assert!(config.is_ok(), "{}", rule.name);
+        }
+    }
+
+    #[test]
+    fn test_find_rule() {
+        let reactivity = find_rule("solid/reactivity").unwrap();
+        assert_eq!(reactivity.name, "reactivity");
+        assert_eq!(reactivity.category, RuleCategory::Correctness);
+        assert!(!reactivity.fixable);
+        assert_eq!(
+            reactivity.docs_url(),
+            "https://github.com/solidjs-community/eslint-plugin-solid/blob/main/docs/reactivity.md"
+        );
+
+        let undef = find_rule("jsx-no-undef").unwrap();
+        assert!(undef.fixable);
+        assert_eq!(undef.default_severity, DiagnosticSeverity::Error);
+        assert!(undef.default_enabled);
+        assert!(find_rule("no-such-rule").is_none());
+    }
+
+    #[test]
+    fn test_default_enabled() {
+        for name in [
+            "require-cleanup",
+            "prefer-index",
+            "no-proxy-apis",
+            "jsx-boolean-value",
+            "alt-text",
+        ] {
+            assert!(!find_rule(name).unwrap().default_enabled, "{}", name);
+        }
+
+        // The default config runs exactly the rules enabled by default
+        let config = LintConfig::default();
+        let (rules, semantic) = (config.rules_config(), config.semantic_rules_config());
+        assert!(!rules.require_cleanup && !rules.prefer_index && !rules.alt_text);
+        assert!(rules.jsx_boolean_value.is_none());
+        assert!(rules.event_handlers.is_some());
+        assert!(!semantic.no_proxy_apis && !semantic.no_unused_vars);
+        assert!(rules.style_prop.is_some() && rules.prefer_for && rules.jsx_uses_vars);
+        assert!(semantic.reactivity && semantic.jsx_no_undef && semantic.jsx_uses_vars);
+    }
+
+    #[test]
+    fn test_docs() {
+        let docs = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("docs/rules");
+        for rule in RULES {
+            let url = rule.docs_url();
+            if url.contains("/solid-jsx-oxc/") {
+                let path = docs.join(format!("{}.md", rule.name));
+                assert!(path.is_file(), "{} has no {}", rule.name, path.display());
+            }
+        }
+        assert!(find_rule("no-unused-signals")
+            .unwrap()
+            .docs_url()
+            .ends_with("/crates/linter/docs/rules/no-unused-signals.md"));
+    }
+}
@@ -0,0 +1,156 @@
+//! solid/alt-text
+//!
+//! Require a text alternative on `<img>`, `<area>`, `<input type="image">`
+//! and `<object>`. `alt=""` marks an image as decorative; without `alt`
+//! screen readers fall back to announcing the file name.
+//!
+//! `alt={undefined}` counts as missing: Solid removes attributes set to
+//! `undefined`, `null` or `false`.
+
+use oxc_ast::ast::{JSXChild, JSXElement, JSXOpeningElement};
+
+use super::aria::is_input_type;
+use super::{dom_element_name, find_attribute, has_spread, is_unset};
+use crate::diagnostic::Diagnostic;
+use crate::{RuleCategory, RuleMeta};
+
+/// alt-text rule
+#[derive(Debug, Clone, Default)]
+pub struct AltText;
+
+impl RuleMeta for AltText {
+    const NAME: &'static str = "alt-text";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Require a text alternative on `<img>`, `<area>`, `<input type=\"image\">` and \
+        `<object>`. `alt=\"\"` marks an image as decorative; without `alt` screen \
+        readers fall back to announcing the file name.";
+    const DEFAULT_ENABLED: bool = false;
+}
+
+impl AltText {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn check<'a>(&self, element: &JSXElement<'a>) -> Option<Diagnostic> {
+        let opening = &element.opening_element;
+        let tag = dom_element_name(opening)?;
+        if has_spread(opening) {
+            return None;
+        }
+
+        let label = match tag {
+            "img" | "area" => format!("<{}>", tag),
+            "input" if is_input_type(opening, "image") => "<input type=\"image\">".to_string(),
+            "object" => return self.check_object(element),
+            _ => return None,
+        };
+        if has_label(opening) {
+            return None;
+        }
+
+        let message = match find_attribute(opening, "alt") {
+            None => format!("`{}` needs an `alt` attribute.", label),
+            Some(alt) if is_unset(alt) => {
+                format!("The `alt` value of `{}` removes the attribute.", label)
+            }
+            Some(_) => return None,
+        };
+        Some(
+            Diagnostic::warning(Self::NAME, opening.span, message).with_help(
+                "Describe the image in `alt`, or use `alt=\"\"` if it is purely decorative.",
+            ),
+        )
+    }
+
+    fn check_object(&self, element: &JSXElement) -> Option<Diagnostic> {
+        let opening = &element.opening_element;
+        let has_fallback = element.children.iter().any(|child| match child {
+            JSXChild::Text(text) => !text.value.trim().is_empty(),
+            _ => true,
+        });
+        if has_label(opening) || find_attribute(opening, "title").is_some() || has_fallback {
+            return None;
+        }
+        Some(
+            Diagnostic::warning(
+                Self::NAME,
+                opening.span,
+                "`<object>` needs a text alternative.",
+            )
+            .with_help("Add `title` or `aria-label`, or fallback content as children."),
+        )
+    }
+}
+
+fn has_label(opening: &JSXOpeningElement) -> bool {
+    ["aria-label", "aria-labelledby"]
+        .iter()
+        .any(|name| find_attribute(opening, name).is_some_and(|attr| !is_unset(attr)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn alt_message(source: &str) -> Option<String> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected JSX element");
+        };
+        AltText::new().check(element).map(|d| d.message)
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(AltText::NAME, "alt-text");
+    }
+
+    #[test]
+    fn test_missing_alt() {
+        assert_eq!(
+            alt_message(r#"<img src={src} />"#).as_deref(),
+            Some("`<img>` needs an `alt` attribute.")
+        );
+        assert_eq!(
+            alt_message(r#"<input type="image" src={src} />"#).as_deref(),
+            Some("`<input type=\"image\">` needs an `alt` attribute.")
+        );
+        assert_eq!(
+            alt_message(r#"<img alt={undefined} />"#).as_deref(),
+            Some("The `alt` value of `<img>` removes the attribute.")
+        );
+        assert_eq!(
+            alt_message(r#"<object data="a.pdf" />"#).as_deref(),
+            Some("`<object>` needs a text alternative.")
+        );
+    }
+
+    #[test]
+    fn test_valid() {
+        for source in [
+            r#"<img alt="" src={src} />"#,
+            r#"<img alt={props.label} />"#,
+            r#"<img attr:alt="Logo" />"#,
+            r#"<img aria-label="Logo" />"#,
+            r#"<img {...props} />"#,
+            r#"<area alt="Home" />"#,
+            r#"<input type="text" />"#,
+            r#"<object data="a.pdf">Annual report</object>"#,
+            r#"<Image src={src} />"#,
+        ] {
+            assert_eq!(alt_message(source), None, "{}", source);
+        }
+    }
+}
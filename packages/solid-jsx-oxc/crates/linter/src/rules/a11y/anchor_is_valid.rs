@@ -0,0 +1,151 @@
+//! solid/anchor-is-valid
+//!
+//! Require `<a>` elements to be real links: an `href` that navigates
+//! somewhere, rather than no `href`, `href="#"` or a `javascript:` URL. Anchors
+//! that only run a click handler should be buttons, which are focusable and
+//! activated by the keyboard without extra work.
+
+use oxc_ast::ast::JSXOpeningElement;
+
+use super::{
+    dom_element_name, find_attribute, has_event_handler, has_spread, is_unset, static_value,
+};
+use crate::diagnostic::Diagnostic;
+use crate::{RuleCategory, RuleMeta};
+
+/// anchor-is-valid rule
+#[derive(Debug, Clone, Default)]
+pub struct AnchorIsValid;
+
+impl RuleMeta for AnchorIsValid {
+    const NAME: &'static str = "anchor-is-valid";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Require `<a>` elements to be real links: an `href` that navigates somewhere, \
+        rather than no `href`, `href=\"#\"` or a `javascript:` URL. Anchors that only \
+        run a click handler should be buttons, which are focusable and activated by the \
+        keyboard without extra work.";
+    const DEFAULT_ENABLED: bool = false;
+}
+
+impl AnchorIsValid {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn check<'a>(&self, opening: &JSXOpeningElement<'a>) -> Option<Diagnostic> {
+        if dom_element_name(opening)? != "a" {
+            return None;
+        }
+
+        let valid = match find_attribute(opening, "href") {
+            None if has_spread(opening) => return None,
+            None => false,
+            Some(href) if is_unset(href) => false,
+            // Dynamic hrefs can't be checked
+            Some(href) => static_value(href).is_none_or(|value| is_valid_href(&value)),
+        };
+        if valid {
+            return None;
+        }
+
+        let diagnostic = if has_event_handler(opening, "click") {
+            Diagnostic::warning(
+                Self::NAME,
+                opening.span,
+                "Anchors used only for their click handler should be buttons.",
+            )
+            .with_help("Use a `<button>`, and style it like a link if needed.")
+        } else if find_attribute(opening, "href").is_none() {
+            Diagnostic::warning(
+                Self::NAME,
+                opening.span,
+                "`<a>` needs an `href` to be a focusable link.",
+            )
+        } else {
+            Diagnostic::warning(
+                Self::NAME,
+                opening.span,
+                "The `href` of `<a>` does not lead anywhere.",
+            )
+            .with_help("Link to a page or a fragment such as `#section`.")
+        };
+        Some(diagnostic)
+    }
+}
+
+fn is_valid_href(href: &str) -> bool {
+    let href = href.trim();
+    !(href.is_empty()
+        || href == "#"
+        || href
+            .get(..11)
+            .is_some_and(|scheme| scheme.eq_ignore_ascii_case("javascript:")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn anchor_message(source: &str) -> Option<String> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected JSX element");
+        };
+        AnchorIsValid::new()
+            .check(&element.opening_element)
+            .map(|d| d.message)
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(AnchorIsValid::NAME, "anchor-is-valid");
+    }
+
+    #[test]
+    fn test_invalid_anchors() {
+        assert_eq!(
+            anchor_message(r#"<a>Home</a>"#).as_deref(),
+            Some("`<a>` needs an `href` to be a focusable link.")
+        );
+        assert_eq!(
+            anchor_message(r#"<a href="JavaScript:void(0)">Home</a>"#).as_deref(),
+            Some("The `href` of `<a>` does not lead anywhere.")
+        );
+        for source in [
+            r##"<a href="#" onClick={open}>Open</a>"##,
+            r#"<a on:click={open}>Open</a>"#,
+            r#"<a href={undefined} onclick={open}>Open</a>"#,
+        ] {
+            assert_eq!(
+                anchor_message(source).as_deref(),
+                Some("Anchors used only for their click handler should be buttons."),
+                "{}",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn test_valid_anchors() {
+        for source in [
+            r#"<a href="/home">Home</a>"#,
+            r##"<a href="#top">Top</a>"##,
+            r#"<a href={url()}>Docs</a>"#,
+            r#"<a {...props}>Docs</a>"#,
+            r#"<A href="/home">Home</A>"#,
+        ] {
+            assert_eq!(anchor_message(source), None, "{}", source);
+        }
+    }
+}
@@ -0,0 +1,250 @@
+//! ARIA metadata shared by the accessibility rules
+//!
+//! Property and role names follow WAI-ARIA 1.2 and DPUB-ARIA 1.0.
+
+use oxc_ast::ast::JSXOpeningElement;
+
+use super::{find_attribute, is_unset, static_value};
+
+/// Every `aria-*` state and property
+pub const ARIA_PROPERTIES: &[&str] = &[
+    "aria-activedescendant",
+    "aria-atomic",
+    "aria-autocomplete",
+    "aria-braillelabel",
+    "aria-brailleroledescription",
+    "aria-busy",
+    "aria-checked",
+    "aria-colcount",
+    "aria-colindex",
+    "aria-colindextext",
+    "aria-colspan",
+    "aria-controls",
+    "aria-current",
+    "aria-describedby",
+    "aria-description",
+    "aria-details",
+    "aria-disabled",
+    "aria-dropeffect",
+    "aria-errormessage",
+    "aria-expanded",
+    "aria-flowto",
+    "aria-grabbed",
+    "aria-haspopup",
+    "aria-hidden",
+    "aria-invalid",
+    "aria-keyshortcuts",
+    "aria-label",
+    "aria-labelledby",
+    "aria-level",
+    "aria-live",
+    "aria-modal",
+    "aria-multiline",
+    "aria-multiselectable",
+    "aria-orientation",
+    "aria-owns",
+    "aria-placeholder",
+    "aria-posinset",
+    "aria-pressed",
+    "aria-readonly",
+    "aria-relevant",
+    "aria-required",
+    "aria-roledescription",
+    "aria-rowcount",
+    "aria-rowindex",
+    "aria-rowindextext",
+    "aria-rowspan",
+    "aria-selected",
+    "aria-setsize",
+    "aria-sort",
+    "aria-valuemax",
+    "aria-valuemin",
+    "aria-valuenow",
+    "aria-valuetext",
+];
+
+/// Concrete roles that may be used in a `role` attribute
+pub const ARIA_ROLES: &[&str] = &[
+    "alert",
+    "alertdialog",
+    "application",
+    "article",
+    "banner",
+    "blockquote",
+    "button",
+    "caption",
+    "cell",
+    "checkbox",
+    "code",
+    "columnheader",
+    "combobox",
+    "complementary",
+    "contentinfo",
+    "definition",
+    "deletion",
+    "dialog",
+    "directory",
+    "document",
+    "emphasis",
+    "feed",
+    "figure",
+    "form",
+    "generic",
+    "graphics-document",
+    "graphics-object",
+    "graphics-symbol",
+    "grid",
+    "gridcell",
+    "group",
+    "heading",
+    "img",
+    "insertion",
+    "link",
+    "list",
+    "listbox",
+    "listitem",
+    "log",
+    "main",
+    "mark",
+    "marquee",
+    "math",
+    "menu",
+    "menubar",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "meter",
+    "navigation",
+    "none",
+    "note",
+    "option",
+    "paragraph",
+    "presentation",
+    "progressbar",
+    "radio",
+    "radiogroup",
+    "region",
+    "row",
+    "rowgroup",
+    "rowheader",
+    "scrollbar",
+    "search",
+    "searchbox",
+    "separator",
+    "slider",
+    "spinbutton",
+    "status",
+    "strong",
+    "subscript",
+    "superscript",
+    "switch",
+    "tab",
+    "table",
+    "tablist",
+    "tabpanel",
+    "term",
+    "textbox",
+    "time",
+    "timer",
+    "toolbar",
+    "tooltip",
+    "tree",
+    "treegrid",
+    "treeitem",
+    "doc-abstract",
+    "doc-acknowledgments",
+    "doc-afterword",
+    "doc-appendix",
+    "doc-backlink",
+    "doc-biblioentry",
+    "doc-bibliography",
+    "doc-biblioref",
+    "doc-chapter",
+    "doc-colophon",
+    "doc-conclusion",
+    "doc-cover",
+    "doc-credit",
+    "doc-credits",
+    "doc-dedication",
+    "doc-endnote",
+    "doc-endnotes",
+    "doc-epigraph",
+    "doc-epilogue",
+    "doc-errata",
+    "doc-example",
+    "doc-footnote",
+    "doc-foreword",
+    "doc-glossary",
+    "doc-glossref",
+    "doc-index",
+    "doc-introduction",
+    "doc-noteref",
+    "doc-notice",
+    "doc-pagebreak",
+    "doc-pagelist",
+    "doc-part",
+    "doc-preface",
+    "doc-prologue",
+    "doc-pullquote",
+    "doc-qna",
+    "doc-subtitle",
+    "doc-tip",
+    "doc-toc",
+];
+
+/// Roles that only exist to structure the taxonomy and must not be used
+pub const ABSTRACT_ROLES: &[&str] = &[
+    "command",
+    "composite",
+    "input",
+    "landmark",
+    "range",
+    "roletype",
+    "section",
+    "sectionhead",
+    "select",
+    "structure",
+    "widget",
+    "window",
+];
+
+/// Native elements that are focusable and operable with the keyboard
+const INTERACTIVE_ELEMENTS: &[&str] = &[
+    "a", "button", "details", "input", "option", "select", "summary", "textarea",
+];
+
+/// Whether the element is natively interactive (`<a>` only with an `href`)
+pub fn is_interactive_element(tag: &str, opening: &JSXOpeningElement) -> bool {
+    match tag {
+        "a" => find_attribute(opening, "href").is_some(),
+        "input" => !is_input_type(opening, "hidden"),
+        _ => INTERACTIVE_ELEMENTS.contains(&tag),
+    }
+}
+
+/// Whether assistive technology ignores the element: `aria-hidden`,
+/// `<input type="hidden">` or a `presentation`/`none` role
+pub fn is_hidden_from_screen_reader(tag: &str, opening: &JSXOpeningElement) -> bool {
+    if tag == "input" && is_input_type(opening, "hidden") {
+        return true;
+    }
+    if find_attribute(opening, "aria-hidden")
+        .is_some_and(|attr| !is_unset(attr) && static_value(attr).as_deref() != Some("false"))
+    {
+        return true;
+    }
+    find_attribute(opening, "role")
+        .and_then(static_value)
+        .is_some_and(|role| {
+            role.split_whitespace()
+                .next()
+                .is_some_and(|role| matches!(role, "presentation" | "none"))
+        })
+}
+
+/// Whether an `<input>` has the given static `type`
+pub fn is_input_type(opening: &JSXOpeningElement, ty: &str) -> bool {
+    find_attribute(opening, "type")
+        .and_then(static_value)
+        .is_some_and(|value| value.eq_ignore_ascii_case(ty))
+}
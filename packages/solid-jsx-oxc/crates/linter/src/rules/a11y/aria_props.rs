@@ -0,0 +1,143 @@
+//! solid/aria-props
+//!
+//! Report `aria-*` attributes that are not defined by WAI-ARIA, such as the
+//! common `aria-labeledby` misspelling. Browsers silently ignore them.
+
+use oxc_ast::ast::{JSXAttributeItem, JSXAttributeName, JSXOpeningElement};
+
+use super::aria::ARIA_PROPERTIES;
+use crate::diagnostic::Diagnostic;
+use crate::{RuleCategory, RuleMeta};
+
+/// aria-props rule
+#[derive(Debug, Clone, Default)]
+pub struct AriaProps;
+
+impl RuleMeta for AriaProps {
+    const NAME: &'static str = "aria-props";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Report `aria-*` attributes that are not defined by WAI-ARIA, such as the common \
+        `aria-labeledby` misspelling. Browsers silently ignore them.";
+    const DEFAULT_ENABLED: bool = false;
+}
+
+impl AriaProps {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn check<'a>(&self, opening: &JSXOpeningElement<'a>) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for item in &opening.attributes {
+            let JSXAttributeItem::Attribute(attr) = item else {
+                continue;
+            };
+            let (name, span) = match &attr.name {
+                JSXAttributeName::Identifier(ident) => (ident.name.as_str(), ident.span),
+                JSXAttributeName::NamespacedName(ns)
+                    if matches!(ns.namespace.name.as_str(), "attr" | "prop") =>
+                {
+                    (ns.name.name.as_str(), ns.name.span)
+                }
+                JSXAttributeName::NamespacedName(_) => continue,
+            };
+            if !name.starts_with("aria-") || ARIA_PROPERTIES.contains(&name) {
+                continue;
+            }
+
+            let mut diagnostic = Diagnostic::warning(
+                Self::NAME,
+                span,
+                format!("`{}` is not a valid ARIA attribute.", name),
+            );
+            if let Some(suggestion) = closest_property(name) {
+                diagnostic = diagnostic.with_help(format!("Did you mean `{}`?", suggestion));
+            }
+            diagnostics.push(diagnostic);
+        }
+
+        diagnostics
+    }
+}
+
+/// The known property within two edits of `name`, ignoring case
+fn closest_property(name: &str) -> Option<&'static str> {
+    let name = name.to_ascii_lowercase();
+    ARIA_PROPERTIES
+        .iter()
+        .map(|property| (edit_distance(&name, property), *property))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, property)| property)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn aria_diagnostics(source: &str) -> Vec<(String, Option<String>)> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected JSX element");
+        };
+        AriaProps::new()
+            .check(&element.opening_element)
+            .into_iter()
+            .map(|d| (d.message, d.help))
+            .collect()
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(AriaProps::NAME, "aria-props");
+    }
+
+    #[test]
+    fn test_unknown_properties() {
+        assert_eq!(
+            aria_diagnostics(
+                r#"<div aria-label="Close" aria-labeledby="t" attr:aria-hiden="true" aria-foo={x} />"#
+            ),
+            [
+                (
+                    "`aria-labeledby` is not a valid ARIA attribute.".to_string(),
+                    Some("Did you mean `aria-labelledby`?".to_string())
+                ),
+                (
+                    "`aria-hiden` is not a valid ARIA attribute.".to_string(),
+                    Some("Did you mean `aria-hidden`?".to_string())
+                ),
+                (
+                    "`aria-foo` is not a valid ARIA attribute.".to_string(),
+                    None
+                ),
+            ]
+        );
+    }
+}
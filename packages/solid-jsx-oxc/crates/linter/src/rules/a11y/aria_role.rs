@@ -0,0 +1,124 @@
+//! solid/aria-role
+//!
+//! Require `role` on native elements to list valid, non-abstract ARIA roles.
+//! Components are skipped since their `role` prop may mean something else.
+//! Dynamic values can't be checked and are ignored.
+
+use oxc_ast::ast::JSXOpeningElement;
+
+use super::aria::{ABSTRACT_ROLES, ARIA_ROLES};
+use super::{dom_element_name, find_attribute, static_value};
+use crate::diagnostic::Diagnostic;
+use crate::{RuleCategory, RuleMeta};
+
+/// aria-role rule
+#[derive(Debug, Clone, Default)]
+pub struct AriaRole;
+
+impl RuleMeta for AriaRole {
+    const NAME: &'static str = "aria-role";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Require `role` on native elements to list valid, non-abstract ARIA roles. \
+        Components are skipped since their `role` prop may mean something else. Dynamic \
+        values can't be checked and are ignored.";
+    const DEFAULT_ENABLED: bool = false;
+}
+
+impl AriaRole {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn check<'a>(&self, opening: &JSXOpeningElement<'a>) -> Vec<Diagnostic> {
+        let Some(attr) = dom_element_name(opening).and_then(|_| find_attribute(opening, "role"))
+        else {
+            return Vec::new();
+        };
+        let Some(value) = static_value(attr) else {
+            return Vec::new();
+        };
+
+        if value.trim().is_empty() {
+            return vec![Diagnostic::warning(
+                Self::NAME,
+                attr.span,
+                "`role` must not be empty.",
+            )];
+        }
+
+        // A space-separated list is a fallback chain; every entry must be valid
+        value
+            .split_whitespace()
+            .filter_map(|role| {
+                let message = if ABSTRACT_ROLES.contains(&role) {
+                    format!("`{}` is an abstract ARIA role and must not be used.", role)
+                } else if !ARIA_ROLES.contains(&role) {
+                    format!("`{}` is not a valid ARIA role.", role)
+                } else {
+                    return None;
+                };
+                Some(Diagnostic::warning(Self::NAME, attr.span, message))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn role_messages(source: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected JSX element");
+        };
+        AriaRole::new()
+            .check(&element.opening_element)
+            .into_iter()
+            .map(|d| d.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(AriaRole::NAME, "aria-role");
+    }
+
+    #[test]
+    fn test_invalid_roles() {
+        assert_eq!(
+            role_messages(r#"<div role="switch buton widget" />"#),
+            [
+                "`buton` is not a valid ARIA role.",
+                "`widget` is an abstract ARIA role and must not be used.",
+            ]
+        );
+        assert_eq!(
+            role_messages(r#"<div role="" />"#),
+            ["`role` must not be empty."]
+        );
+    }
+
+    #[test]
+    fn test_valid_roles() {
+        for source in [
+            r#"<div role="button" />"#,
+            r#"<section role={`doc-${"chapter"}`} />"#,
+            r#"<div role={props.role} />"#,
+            r#"<Tabs role="anything" />"#,
+        ] {
+            assert!(role_messages(source).is_empty(), "{}", source);
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! solid/click-events-have-key-events
+//!
+//! Require a keyboard handler next to click handlers on non-interactive
+//! elements. A `<div onClick>` can't be reached or activated without a mouse;
+//! natively interactive elements like `<button>` handle the keyboard already.
+
+use oxc_ast::ast::JSXOpeningElement;
+
+use super::aria::{is_hidden_from_screen_reader, is_interactive_element};
+use super::{dom_element_name, has_event_handler, has_spread};
+use crate::diagnostic::Diagnostic;
+use crate::{RuleCategory, RuleMeta};
+
+/// click-events-have-key-events rule
+#[derive(Debug, Clone, Default)]
+pub struct ClickEventsHaveKeyEvents;
+
+impl RuleMeta for ClickEventsHaveKeyEvents {
+    const NAME: &'static str = "click-events-have-key-events";
+    const CATEGORY: RuleCategory = RuleCategory::Pedantic;
+    const DESCRIPTION: &'static str = "\
+        Require a keyboard handler next to click handlers on non-interactive elements. A \
+        `<div onClick>` can't be reached or activated without a mouse; natively \
+        interactive elements like `<button>` handle the keyboard already.";
+    const DEFAULT_ENABLED: bool = false;
+}
+
+impl ClickEventsHaveKeyEvents {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn check<'a>(&self, opening: &JSXOpeningElement<'a>) -> Option<Diagnostic> {
+        let tag = dom_element_name(opening)?;
+        // Custom elements may handle the keyboard internally
+        if tag.contains('-')
+            || !has_event_handler(opening, "click")
+            || has_spread(opening)
+            || is_interactive_element(tag, opening)
+            || is_hidden_from_screen_reader(tag, opening)
+            || ["keydown", "keyup", "keypress"]
+                .iter()
+                .any(|event| has_event_handler(opening, event))
+        {
+            return None;
+        }
+
+        Some(
+            Diagnostic::warning(
+                Self::NAME,
+                opening.span,
+                format!(
+                    "`<{}>` has a click handler but no keyboard handler.",
+                    tag
+                ),
+            )
+            .with_help("Handle `onKeyDown` as well, or use a `<button>`, which responds to Enter and Space."),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn click_message(source: &str) -> Option<String> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected JSX element");
+        };
+        ClickEventsHaveKeyEvents::new()
+            .check(&element.opening_element)
+            .map(|d| d.message)
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(
+            ClickEventsHaveKeyEvents::NAME,
+            "click-events-have-key-events"
+        );
+    }
+
+    #[test]
+    fn test_missing_key_handler() {
+        for source in [
+            r#"<div onClick={select} />"#,
+            r#"<span on:click={select} />"#,
+            r#"<li onclick={select} aria-hidden="false" />"#,
+        ] {
+            assert!(click_message(source).is_some(), "{}", source);
+        }
+        assert_eq!(
+            click_message(r#"<div onClick={select} />"#).as_deref(),
+            Some("`<div>` has a click handler but no keyboard handler.")
+        );
+    }
+
+    #[test]
+    fn test_valid() {
+        for source in [
+            r#"<div onClick={select} onKeyDown={select} />"#,
+            r#"<div onClick={select} on:keyup={select} />"#,
+            r#"<button onClick={select} />"#,
+            r#"<a href="/x" onClick={select} />"#,
+            r#"<div onClick={select} aria-hidden="true" />"#,
+            r#"<div onClick={select} role="presentation" />"#,
+            r#"<div onClick={select} {...props} />"#,
+            r#"<my-card onClick={select} />"#,
+            r#"<Card onClick={select} />"#,
+        ] {
+            assert_eq!(click_message(source), None, "{}", source);
+        }
+    }
+}
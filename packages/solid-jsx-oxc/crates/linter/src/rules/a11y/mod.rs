@@ -0,0 +1,114 @@
+//! Accessibility rules
+//!
+//! A subset of eslint-plugin-jsx-a11y adapted to Solid JSX. Attributes may be
+//! written with the `attr:`/`prop:` namespaces and handlers as `onClick`,
+//! `onclick` or `on:click`, so lookups go through the helpers below. ARIA
+//! metadata shared by the rules lives in [`aria`].
+
+use oxc_ast::ast::{
+    Expression, JSXAttribute, JSXAttributeItem, JSXAttributeName, JSXAttributeValue,
+    JSXElementName, JSXOpeningElement,
+};
+
+use common::evaluate_string;
+
+use crate::utils::is_dom_element;
+
+pub mod alt_text;
+pub mod anchor_is_valid;
+pub mod aria;
+pub mod aria_props;
+pub mod aria_role;
+pub mod click_events_have_key_events;
+pub mod no_autofocus;
+
+pub use alt_text::AltText;
+pub use anchor_is_valid::AnchorIsValid;
+pub use aria_props::AriaProps;
+pub use aria_role::AriaRole;
+pub use click_events_have_key_events::ClickEventsHaveKeyEvents;
+pub use no_autofocus::NoAutofocus;
+
+/// The tag name of a native element, `None` for components
+pub(crate) fn dom_element_name<'a>(opening: &JSXOpeningElement<'a>) -> Option<&'a str> {
+    let name = match &opening.name {
+        JSXElementName::Identifier(ident) => ident.name.as_str(),
+        _ => return None,
+    };
+    is_dom_element(name).then_some(name)
+}
+
+/// Find an attribute by name, also matching its `attr:`/`prop:` forms
+pub(crate) fn find_attribute<'b, 'a>(
+    opening: &'b JSXOpeningElement<'a>,
+    name: &str,
+) -> Option<&'b JSXAttribute<'a>> {
+    opening.attributes.iter().find_map(|item| {
+        let JSXAttributeItem::Attribute(attr) = item else {
+            return None;
+        };
+        let matches = match &attr.name {
+            JSXAttributeName::Identifier(ident) => ident.name == name,
+            JSXAttributeName::NamespacedName(ns) => {
+                matches!(ns.namespace.name.as_str(), "attr" | "prop") && ns.name.name == name
+            }
+        };
+        matches.then_some(&**attr)
+    })
+}
+
+pub(crate) fn has_spread(opening: &JSXOpeningElement) -> bool {
+    opening
+        .attributes
+        .iter()
+        .any(|item| matches!(item, JSXAttributeItem::SpreadAttribute(_)))
+}
+
+/// The statically known value of an attribute. A bare attribute is `""`.
+pub(crate) fn static_value(attr: &JSXAttribute) -> Option<String> {
+    match &attr.value {
+        None => Some(String::new()),
+        Some(JSXAttributeValue::StringLiteral(lit)) => Some(lit.value.to_string()),
+        Some(JSXAttributeValue::ExpressionContainer(container)) => {
+            evaluate_string(container.expression.as_expression()?)
+        }
+        _ => None,
+    }
+}
+
+/// Whether an attribute is set to `false`, `null` or `undefined`, which removes it
+pub(crate) fn is_unset(attr: &JSXAttribute) -> bool {
+    let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value else {
+        return false;
+    };
+    match container
+        .expression
+        .as_expression()
+        .map(Expression::get_inner_expression)
+    {
+        Some(Expression::BooleanLiteral(lit)) => !lit.value,
+        Some(Expression::NullLiteral(_)) => true,
+        Some(Expression::Identifier(ident)) => ident.name == "undefined",
+        _ => false,
+    }
+}
+
+/// Whether the element listens for `event` in any of Solid's handler forms
+pub(crate) fn has_event_handler(opening: &JSXOpeningElement, event: &str) -> bool {
+    opening.attributes.iter().any(|item| {
+        let JSXAttributeItem::Attribute(attr) = item else {
+            return false;
+        };
+        match &attr.name {
+            JSXAttributeName::Identifier(ident) => ident
+                .name
+                .strip_prefix("on")
+                .map(|rest| rest.strip_suffix("Capture").unwrap_or(rest))
+                .is_some_and(|rest| rest.eq_ignore_ascii_case(event)),
+            JSXAttributeName::NamespacedName(ns) => {
+                matches!(ns.namespace.name.as_str(), "on" | "oncapture")
+                    && ns.name.name.eq_ignore_ascii_case(event)
+            }
+        }
+    })
+}
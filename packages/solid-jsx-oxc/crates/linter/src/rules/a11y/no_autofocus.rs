@@ -0,0 +1,134 @@
+//! solid/no-autofocus
+//!
+//! Disallow `autofocus`, which moves focus on page load and can disorient
+//! screen reader and keyboard users. Both the HTML spelling and React's
+//! `autoFocus` are checked.
+
+use oxc_ast::ast::{JSXAttributeItem, JSXOpeningElement};
+use oxc_span::{GetSpan, Span};
+
+use super::{dom_element_name, find_attribute, is_unset};
+use serde::{Deserialize, Serialize};
+
+use crate::diagnostic::{Diagnostic, Fix};
+use crate::{ConfigurableRule, RuleCategory, RuleMeta};
+
+/// no-autofocus rule
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoAutofocus {
+    /// Only check native elements, leaving `autofocus` props of components alone
+    #[serde(rename = "ignoreNonDOM")]
+    pub ignore_non_dom: bool,
+}
+
+impl RuleMeta for NoAutofocus {
+    const NAME: &'static str = "no-autofocus";
+    const CATEGORY: RuleCategory = RuleCategory::Pedantic;
+    const DESCRIPTION: &'static str = "\
+        Disallow `autofocus`, which moves focus on page load and can disorient screen \
+        reader and keyboard users. Both the HTML spelling and React's `autoFocus` are \
+        checked.";
+    const FIXABLE: bool = true;
+    const DEFAULT_ENABLED: bool = false;
+}
+
+impl ConfigurableRule for NoAutofocus {
+    type Options = Self;
+
+    fn from_options(options: Self) -> Self {
+        options
+    }
+}
+
+impl NoAutofocus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_ignore_non_dom(mut self, ignore_non_dom: bool) -> Self {
+        self.ignore_non_dom = ignore_non_dom;
+        self
+    }
+
+    pub fn check<'a>(&self, opening: &JSXOpeningElement<'a>) -> Option<Diagnostic> {
+        if self.ignore_non_dom && dom_element_name(opening).is_none() {
+            return None;
+        }
+        let attr = find_attribute(opening, "autofocus")
+            .or_else(|| find_attribute(opening, "autoFocus"))
+            .filter(|attr| !is_unset(attr))?;
+
+        // Remove the attribute along with the whitespace before it
+        let index = opening.attributes.iter().position(
+            |item| matches!(item, JSXAttributeItem::Attribute(a) if a.span == attr.span),
+        )?;
+        let start = match index {
+            0 => opening.name.span().end,
+            _ => opening.attributes[index - 1].span().end,
+        };
+
+        Some(
+            Diagnostic::warning(
+                Self::NAME,
+                attr.span,
+                "Avoid `autofocus`; moving focus on load disorients keyboard and screen reader users.",
+            )
+            .with_fix(Fix::new(Span::new(start, attr.span.end), "").with_message("Remove `autofocus`")),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fix::apply_fixes;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn fixed(source: &str, rule: &NoAutofocus) -> String {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected JSX element");
+        };
+        let diagnostics = rule.check(&element.opening_element).into_iter().collect();
+        apply_fixes(source, diagnostics).output
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(NoAutofocus::NAME, "no-autofocus");
+    }
+
+    #[test]
+    fn test_removes_autofocus() {
+        let rule = NoAutofocus::new();
+        assert_eq!(fixed(r#"<input autofocus />"#, &rule), r#"<input />"#);
+        assert_eq!(
+            fixed(r#"<input type="text" autoFocus={true} name="q" />"#, &rule),
+            r#"<input type="text" name="q" />"#
+        );
+        assert_eq!(
+            fixed(r#"<input autofocus={false} />"#, &rule),
+            r#"<input autofocus={false} />"#
+        );
+    }
+
+    #[test]
+    fn test_ignore_non_dom() {
+        let source = r#"<TextField autofocus />"#;
+        assert_eq!(fixed(source, &NoAutofocus::new()), r#"<TextField />"#);
+        assert_eq!(
+            fixed(source, &NoAutofocus::new().with_ignore_non_dom(true)),
+            source
+        );
+    }
+}
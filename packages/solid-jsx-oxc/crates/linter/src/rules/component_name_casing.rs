@@ -0,0 +1,303 @@
+//! solid/component-name-casing
+//!
+//! Components must be PascalCase. A lowercase tag like `<myComp />` always
+//! compiles to an HTML element named `mycomp`, even when a `myComp` component
+//! is in scope, so nothing renders and nothing warns.
+//!
+//! Every such tag is reported. When the binding is a function returning JSX
+//! declared in this file, its declaration is reported too, with a fix that
+//! renames the function, its references and the tags. The fix is left out when
+//! the name is seen outside the file or as an object key (`export { card }`,
+//! `{ card }`), where renaming the binding would rename those too.
+
+use oxc_ast::ast::{Expression, JSXElement, JSXElementName, Program};
+use oxc_ast::AstKind;
+use oxc_ast_visit::{walk, Visit};
+use oxc_semantic::{Semantic, SymbolId};
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
+
+use common::constants::{HTML_ELEMENTS, MATHML_ELEMENTS, SVG_ELEMENTS};
+
+use crate::diagnostic::{Diagnostic, Fix};
+use crate::rules::NoDestructure;
+use crate::{RuleCategory, RuleMeta};
+
+/// component-name-casing rule
+#[derive(Debug, Clone, Default)]
+pub struct ComponentNameCasing;
+
+impl RuleMeta for ComponentNameCasing {
+    const NAME: &'static str = "component-name-casing";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Components must be PascalCase. A lowercase tag like `<myComp />` always compiles \
+        to an HTML element named `mycomp`, even when a `myComp` component is in scope, \
+        so nothing renders and nothing warns.";
+    const FIXABLE: bool = true;
+}
+
+impl ComponentNameCasing {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn check<'a>(&self, program: &Program<'a>, semantic: &Semantic<'a>) -> Vec<Diagnostic> {
+        let mut tags = LowercaseTags::default();
+        tags.visit_program(program);
+        if tags.spans.is_empty() {
+            return Vec::new();
+        }
+
+        let scoping = semantic.scoping();
+        let mut symbols: FxHashMap<&str, Vec<SymbolId>> = FxHashMap::default();
+        for symbol_id in scoping.symbol_ids() {
+            let name = scoping.symbol_name(symbol_id);
+            if tags.spans.contains_key(name) {
+                symbols.entry(name).or_default().push(symbol_id);
+            }
+        }
+
+        let mut diagnostics = Vec::new();
+        for (name, spans) in &tags.spans {
+            let Some(symbol_ids) = symbols.get(name.as_str()) else {
+                continue;
+            };
+            let pascal = to_pascal_case(name);
+
+            for (span, is_opening) in spans {
+                if *is_opening {
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            Self::NAME,
+                            *span,
+                            format!(
+                                "`<{}>` is compiled as an HTML element, not the `{}` in scope.",
+                                name, name
+                            ),
+                        )
+                        .with_help(format!(
+                            "Components must start with an uppercase letter: rename it to `{}`, or use `<Dynamic component={{{}}} />`.",
+                            pascal, name
+                        )),
+                    );
+                }
+            }
+
+            // Renaming is only safe when the name is unambiguous
+            let [symbol_id] = symbol_ids.as_slice() else {
+                continue;
+            };
+            if !is_jsx_function(semantic, *symbol_id) {
+                continue;
+            }
+            let mut diagnostic = Diagnostic::warning(
+                Self::NAME,
+                scoping.symbol_span(*symbol_id),
+                format!("Component `{}` should be PascalCase: `{}`.", name, pascal),
+            );
+            if !is_local_name(semantic, *symbol_id) {
+                diagnostics.push(diagnostic);
+                continue;
+            }
+
+            let mut rename_spans = vec![scoping.symbol_span(*symbol_id)];
+            rename_spans.extend(
+                scoping
+                    .get_resolved_reference_ids(*symbol_id)
+                    .iter()
+                    .map(|id| {
+                        semantic
+                            .nodes()
+                            .kind(scoping.get_reference(*id).node_id())
+                            .span()
+                    }),
+            );
+            rename_spans.extend(spans.iter().map(|(span, _)| *span));
+
+            for (i, span) in rename_spans.into_iter().enumerate() {
+                let message = if i == 0 {
+                    format!("Rename to `{}`", pascal)
+                } else {
+                    String::new()
+                };
+                diagnostic =
+                    diagnostic.with_fix(Fix::new(span, pascal.clone()).with_message(message));
+            }
+            diagnostics.push(diagnostic);
+        }
+
+        diagnostics.sort_by_key(|d| d.start);
+        diagnostics
+    }
+}
+
+/// Lowercase tags that are not HTML, SVG, MathML or custom elements
+#[derive(Default)]
+struct LowercaseTags {
+    /// Tag name -> spans of the names in opening (`true`) and closing tags
+    spans: FxHashMap<String, Vec<(Span, bool)>>,
+}
+
+impl<'a> Visit<'a> for LowercaseTags {
+    fn visit_jsx_element(&mut self, element: &JSXElement<'a>) {
+        if let JSXElementName::Identifier(ident) = &element.opening_element.name {
+            let name = ident.name.as_str();
+            if name.starts_with(|c: char| c.is_ascii_lowercase())
+                && !name.contains('-')
+                && !HTML_ELEMENTS.contains(name)
+                && !SVG_ELEMENTS.contains(name)
+                && !MATHML_ELEMENTS.contains(name)
+            {
+                let spans = self.spans.entry(name.to_string()).or_default();
+                spans.push((ident.span, true));
+                if let Some(JSXElementName::Identifier(closing)) =
+                    element.closing_element.as_ref().map(|c| &c.name)
+                {
+                    spans.push((closing.span, false));
+                }
+            }
+        }
+        walk::walk_jsx_element(self, element);
+    }
+}
+
+/// Whether a symbol is a function declaration or a variable initialized with a
+/// function, and that function returns JSX
+fn is_jsx_function(semantic: &Semantic, symbol_id: SymbolId) -> bool {
+    let declaration = semantic.scoping().symbol_declaration(symbol_id);
+    match semantic.nodes().kind(declaration) {
+        AstKind::Function(func) => func
+            .body
+            .as_ref()
+            .is_some_and(|body| NoDestructure::body_has_jsx(body)),
+        AstKind::VariableDeclarator(declarator) => {
+            match declarator
+                .init
+                .as_ref()
+                .map(Expression::get_inner_expression)
+            {
+                Some(Expression::ArrowFunctionExpression(arrow)) => {
+                    NoDestructure::body_has_jsx(&arrow.body)
+                }
+                Some(Expression::FunctionExpression(func)) => func
+                    .body
+                    .as_ref()
+                    .is_some_and(|body| NoDestructure::body_has_jsx(body)),
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Whether the name of a symbol is only used as a binding in this file: it is
+/// not exported, and no reference is also an object key
+fn is_local_name(semantic: &Semantic, symbol_id: SymbolId) -> bool {
+    let scoping = semantic.scoping();
+    let nodes = semantic.nodes();
+    for kind in nodes.ancestor_kinds(scoping.symbol_declaration(symbol_id)) {
+        match kind {
+            AstKind::ExportNamedDeclaration(_) | AstKind::ExportDefaultDeclaration(_) => {
+                return false;
+            }
+            AstKind::Program(_) | AstKind::BlockStatement(_) | AstKind::FunctionBody(_) => break,
+            _ => {}
+        }
+    }
+    scoping
+        .get_resolved_reference_ids(symbol_id)
+        .iter()
+        .all(
+            |id| match nodes.parent_kind(scoping.get_reference(*id).node_id()) {
+                AstKind::ExportSpecifier(_) => false,
+                AstKind::ObjectProperty(property) => !property.shorthand,
+                _ => true,
+            },
+        )
+}
+
+fn to_pascal_case(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fix::apply_fixes;
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    fn lint(source: &str) -> (Vec<String>, String) {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).parse();
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        let diagnostics = ComponentNameCasing::new().check(&ret.program, &semantic);
+        let output = apply_fixes(source, diagnostics.clone()).output;
+        (diagnostics.into_iter().map(|d| d.message).collect(), output)
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(ComponentNameCasing::NAME, "component-name-casing");
+    }
+
+    #[test]
+    fn test_lowercase_component() {
+        let (messages, output) = lint(
+            r#"const card = (props) => <div>{props.children}</div>;
+export const cards = [card];
+const App = () => <card><p>hi</p></card>;"#,
+        );
+        assert_eq!(
+            messages,
+            [
+                "Component `card` should be PascalCase: `Card`.",
+                "`<card>` is compiled as an HTML element, not the `card` in scope.",
+            ]
+        );
+        assert_eq!(
+            output,
+            r#"const Card = (props) => <div>{props.children}</div>;
+export const cards = [Card];
+const App = () => <Card><p>hi</p></Card>;"#
+        );
+    }
+
+    #[test]
+    fn test_exported_names_are_not_renamed() {
+        for source in [
+            "export const card = () => <div />;\nconst a = <card />;",
+            "export function card() { return <div />; }\nconst a = <card />;",
+            "const card = () => <div />;\nexport { card };\nconst a = <card />;",
+            "const card = () => <div />;\nconst parts = { card };\nconst a = <card />;",
+        ] {
+            let (messages, output) = lint(source);
+            assert_eq!(messages.len(), 2, "{}", source);
+            assert_eq!(output, source);
+        }
+    }
+
+    #[test]
+    fn test_other_bindings() {
+        let (messages, output) = lint(
+            r#"import { icon } from "./icons";
+const tag = "section";
+const a = <><icon /><tag /><label /><my-el /><Button /><widget /></>;"#,
+        );
+        assert_eq!(
+            messages,
+            [
+                "`<icon>` is compiled as an HTML element, not the `icon` in scope.",
+                "`<tag>` is compiled as an HTML element, not the `tag` in scope.",
+            ]
+        );
+        assert!(!output.contains("Icon"));
+    }
+}
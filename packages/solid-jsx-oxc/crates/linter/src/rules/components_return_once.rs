@@ -2,12 +2,22 @@
 //!
 //! Disallow early returns in components. Solid components only run once,
 //! and so conditionals should be inside JSX.
+//!
+//! Every `return` reachable in the component's own body (inside `if`, `switch`,
+//! loops, `try`, ...) other than the final one is reported. A final ternary or
+//! `&&` return gets a fix that rewrites it to `<Show>`, importing `Show` from
+//! `solid-js` when nothing at the top level binds it.
 
 use oxc_ast::ast::{
-    ArrowFunctionExpression, Expression, Function, FunctionBody, Statement,
+    ArrowFunctionExpression, Expression, Function, FunctionBody, LogicalExpression, Program,
+    ReturnStatement, Statement,
 };
+use oxc_span::GetSpan;
+use oxc_syntax::operator::LogicalOperator;
 
-use crate::diagnostic::Diagnostic;
+use crate::context::strip_ts_wrappers;
+use crate::diagnostic::{Diagnostic, Fix};
+use crate::utils::{import_fix, span_text};
 use crate::{RuleCategory, RuleMeta};
 
 /// components-return-once rule
@@ -17,6 +27,10 @@ pub struct ComponentsReturnOnce;
 impl RuleMeta for ComponentsReturnOnce {
     const NAME: &'static str = "components-return-once";
     const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Disallow early returns in components. Solid components only run once, and so \
+        conditionals should be inside JSX.";
+    const FIXABLE: bool = true;
 }
 
 impl ComponentsReturnOnce {
@@ -30,6 +44,8 @@ impl ComponentsReturnOnce {
         func: &Function<'a>,
         is_component: bool,
         is_render_prop: bool,
+        program: &Program<'a>,
+        source_text: &str,
     ) -> Vec<Diagnostic> {
         if !is_component || is_render_prop {
             return Vec::new();
@@ -43,7 +59,7 @@ impl ComponentsReturnOnce {
         }
 
         if let Some(body) = &func.body {
-            self.check_body(body)
+            self.check_body(body, program, source_text)
         } else {
             Vec::new()
         }
@@ -55,24 +71,35 @@ impl ComponentsReturnOnce {
         arrow: &ArrowFunctionExpression<'a>,
         is_component: bool,
         is_render_prop: bool,
+        program: &Program<'a>,
+        source_text: &str,
     ) -> Vec<Diagnostic> {
         if !is_component || is_render_prop {
             return Vec::new();
         }
 
-        self.check_body(&arrow.body)
+        // `() => cond ? <A /> : <B />` returns its only expression
+        if arrow.expression {
+            let mut diagnostics = Vec::new();
+            if let Some(Statement::ExpressionStatement(stmt)) = arrow.body.statements.first() {
+                self.check_conditional(&stmt.expression, program, source_text, &mut diagnostics);
+            }
+            return diagnostics;
+        }
+        self.check_body(&arrow.body, program, source_text)
     }
 
-    fn check_body(&self, body: &FunctionBody) -> Vec<Diagnostic> {
+    fn check_body(
+        &self,
+        body: &FunctionBody,
+        program: &Program,
+        source_text: &str,
+    ) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
         let statements = &body.statements;
 
-        if statements.is_empty() {
-            return diagnostics;
-        }
-
         // Find the last non-declaration statement (the "main" return)
-        let last_return_idx = statements.iter().rposition(|stmt| {
+        let last_idx = statements.iter().rposition(|stmt| {
             !matches!(
                 stmt,
                 Statement::FunctionDeclaration(_)
@@ -80,59 +107,163 @@ impl ComponentsReturnOnce {
                     | Statement::VariableDeclaration(_)
             )
         });
+        let final_return = last_idx.and_then(|idx| match &statements[idx] {
+            Statement::ReturnStatement(ret) => Some(ret),
+            _ => None,
+        });
 
-        // Check for early returns (any return that isn't the last statement)
-        for (idx, stmt) in statements.iter().enumerate() {
-            if Some(idx) != last_return_idx {
-                if let Statement::ReturnStatement(ret) = stmt {
-                    diagnostics.push(
-                        Diagnostic::warning(
-                            Self::NAME,
-                            ret.span,
-                            "Solid components run once, so an early return breaks reactivity. Move the condition inside a JSX element, such as a fragment or <Show />.",
-                        ),
-                    );
-                }
+        // Every other return in the component's own control flow is an early return
+        let mut returns = Vec::new();
+        for stmt in statements {
+            collect_returns(stmt, &mut returns);
+        }
+        for ret in returns {
+            if final_return.is_some_and(|last| last.span == ret.span) {
+                continue;
             }
+            diagnostics.push(
+                Diagnostic::warning(
+                    Self::NAME,
+                    ret.span,
+                    "Solid components run once, so an early return breaks reactivity. Move the condition inside a JSX element, such as a fragment or <Show />.",
+                ),
+            );
         }
 
         // Check if the last statement is a conditional return
-        if let Some(idx) = last_return_idx {
-            if let Statement::ReturnStatement(ret) = &statements[idx] {
-                if let Some(arg) = &ret.argument {
-                    match arg {
-                        Expression::ConditionalExpression(cond) => {
-                            diagnostics.push(
-                                Diagnostic::warning(
-                                    Self::NAME,
-                                    cond.span,
-                                    "Solid components run once, so a conditional return breaks reactivity. Move the condition inside a JSX element, such as a fragment or <Show />.",
-                                )
-                                .with_help("Use <Show when={condition}> or <Switch><Match when={condition}> instead."),
-                            );
-                        }
-                        Expression::LogicalExpression(logical) => {
-                            if matches!(
-                                logical.operator,
-                                oxc_syntax::operator::LogicalOperator::And
-                            ) {
-                                diagnostics.push(
-                                    Diagnostic::warning(
-                                        Self::NAME,
-                                        logical.span,
-                                        "Solid components run once, so a conditional return breaks reactivity. Move the condition inside a JSX element, such as a fragment or <Show />.",
-                                    )
-                                    .with_help("Use <Show when={condition}> instead."),
-                                );
-                            }
-                        }
-                        _ => {}
-                    }
+        if let Some(arg) = final_return.and_then(|ret| ret.argument.as_ref()) {
+            self.check_conditional(arg, program, source_text, &mut diagnostics);
+        }
+
+        diagnostics
+    }
+
+    /// Report a returned ternary or `&&`, with a fix converting it to `<Show />`
+    fn check_conditional(
+        &self,
+        arg: &Expression,
+        program: &Program,
+        source_text: &str,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        let import = import_fix(program, "solid-js", "Show");
+        let diagnostic = match strip_ts_wrappers(arg) {
+            Expression::ConditionalExpression(cond) => {
+                let test = span_text(source_text, cond.test.span());
+                let mut replacement = format!("<Show when={{{}}}", test);
+                if !is_nullish(&cond.alternate) {
+                    replacement.push_str(&format!(
+                        " fallback={{{}}}",
+                        span_text(source_text, cond.alternate.span())
+                    ));
+                }
+                replacement.push_str(&format!(
+                    ">{}</Show>",
+                    as_jsx_child(&cond.consequent, source_text)
+                ));
+
+                Diagnostic::warning(
+                    Self::NAME,
+                    cond.span,
+                    "Solid components run once, so a conditional return breaks reactivity. Move the condition inside a JSX element, such as a fragment or <Show />.",
+                )
+                .with_help("Use <Show when={condition}> or <Switch><Match when={condition}> instead.")
+                .with_fix(Fix::new(cond.span, replacement).with_message("Replace with <Show />"))
+            }
+            Expression::LogicalExpression(logical) if logical.operator == LogicalOperator::And => {
+                Diagnostic::warning(
+                    Self::NAME,
+                    logical.span,
+                    "Solid components run once, so a conditional return breaks reactivity. Move the condition inside a JSX element, such as a fragment or <Show />.",
+                )
+                .with_help("Use <Show when={condition}> instead.")
+                .with_fix(
+                    Fix::new(logical.span, and_to_show(logical, source_text))
+                        .with_message("Replace with <Show />"),
+                )
+            }
+            _ => return,
+        };
+        diagnostics.push(match import {
+            Some(fix) => diagnostic.with_fix(fix),
+            None => diagnostic,
+        });
+    }
+}
+
+/// Collect the return statements of a component's own control flow,
+/// without descending into nested functions or classes
+fn collect_returns<'s, 'a>(stmt: &'s Statement<'a>, returns: &mut Vec<&'s ReturnStatement<'a>>) {
+    match stmt {
+        Statement::ReturnStatement(ret) => returns.push(ret),
+        Statement::BlockStatement(block) => {
+            for stmt in &block.body {
+                collect_returns(stmt, returns);
+            }
+        }
+        Statement::IfStatement(if_stmt) => {
+            collect_returns(&if_stmt.consequent, returns);
+            if let Some(alternate) = &if_stmt.alternate {
+                collect_returns(alternate, returns);
+            }
+        }
+        Statement::SwitchStatement(switch) => {
+            for case in &switch.cases {
+                for stmt in &case.consequent {
+                    collect_returns(stmt, returns);
+                }
+            }
+        }
+        Statement::TryStatement(try_stmt) => {
+            for stmt in &try_stmt.block.body {
+                collect_returns(stmt, returns);
+            }
+            if let Some(handler) = &try_stmt.handler {
+                for stmt in &handler.body.body {
+                    collect_returns(stmt, returns);
+                }
+            }
+            if let Some(finalizer) = &try_stmt.finalizer {
+                for stmt in &finalizer.body {
+                    collect_returns(stmt, returns);
                 }
             }
         }
+        Statement::ForStatement(for_stmt) => collect_returns(&for_stmt.body, returns),
+        Statement::ForInStatement(for_in) => collect_returns(&for_in.body, returns),
+        Statement::ForOfStatement(for_of) => collect_returns(&for_of.body, returns),
+        Statement::WhileStatement(while_stmt) => collect_returns(&while_stmt.body, returns),
+        Statement::DoWhileStatement(do_while) => collect_returns(&do_while.body, returns),
+        Statement::LabeledStatement(labeled) => collect_returns(&labeled.body, returns),
+        _ => {}
+    }
+}
 
-        diagnostics
+/// `cond && <A />` -> `<Show when={cond}><A /></Show>`
+fn and_to_show(logical: &LogicalExpression, source_text: &str) -> String {
+    format!(
+        "<Show when={{{}}}>{}</Show>",
+        span_text(source_text, logical.left.span()),
+        as_jsx_child(&logical.right, source_text)
+    )
+}
+
+/// JSX elements can be used as children directly; anything else needs braces
+fn as_jsx_child(expr: &Expression, source_text: &str) -> String {
+    let expr = strip_ts_wrappers(expr);
+    let text = span_text(source_text, expr.span());
+    if matches!(expr, Expression::JSXElement(_) | Expression::JSXFragment(_)) {
+        text.to_string()
+    } else {
+        format!("{{{}}}", text)
+    }
+}
+
+fn is_nullish(expr: &Expression) -> bool {
+    match strip_ts_wrappers(expr) {
+        Expression::NullLiteral(_) => true,
+        Expression::Identifier(ident) => ident.name == "undefined",
+        _ => false,
     }
 }
 
@@ -140,8 +271,97 @@ impl ComponentsReturnOnce {
 mod tests {
     use super::*;
 
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn check(source: &str) -> Vec<Diagnostic> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).parse();
+        let Some(func) = ret.program.body.iter().find_map(|stmt| match stmt {
+            Statement::FunctionDeclaration(func) => Some(func),
+            _ => None,
+        }) else {
+            panic!("expected a function declaration");
+        };
+        ComponentsReturnOnce::new().check_function(func, true, false, &ret.program, source)
+    }
+
     #[test]
     fn test_rule_name() {
         assert_eq!(ComponentsReturnOnce::NAME, "components-return-once");
     }
+
+    #[test]
+    fn test_nested_early_returns() {
+        let diagnostics = check(
+            r#"function App(props) {
+                if (!props.user) return null;
+                switch (props.kind) { case "a": { return <A />; } }
+                try { if (props.x) { return <X />; } } catch {}
+                const render = () => { return <Nested />; };
+                return <div />;
+            }"#,
+        );
+        assert_eq!(diagnostics.len(), 3);
+        assert!(diagnostics.iter().all(|d| d.message.contains("early return")));
+    }
+
+    #[test]
+    fn test_conditional_return_fix() {
+        let source = "function App(props) { return props.ok ? <A /> : <B />; }";
+        let diagnostics = check(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].fixes[0].replacement,
+            "<Show when={props.ok} fallback={<B />}><A /></Show>"
+        );
+        assert_eq!(
+            diagnostics[0].fixes[1].replacement,
+            "import { Show } from \"solid-js\";\n"
+        );
+
+        let diagnostics = check("function App(props) { return props.ok && (<A />); }");
+        assert_eq!(diagnostics[0].fixes[0].replacement, "<Show when={props.ok}><A /></Show>");
+
+        let diagnostics = check("function App(props) { return props.ok ? props.label : null; }");
+        assert_eq!(
+            diagnostics[0].fixes[0].replacement,
+            "<Show when={props.ok}>{props.label}</Show>"
+        );
+    }
+
+    #[test]
+    fn test_show_import() {
+        let diagnostics = check(
+            r#"import { For } from "solid-js";
+            function App(props) { return props.ok && <A />; }"#,
+        );
+        assert_eq!(diagnostics[0].fixes[1].replacement, ", Show");
+
+        let diagnostics = check(
+            r#"import { Show } from "solid-js";
+            function App(props) { return props.ok && <A />; }"#,
+        );
+        assert_eq!(diagnostics[0].fixes.len(), 1);
+    }
+
+    #[test]
+    fn test_expression_arrow() {
+        let source = "const App = (props) => props.ok ? props.label : null;";
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).parse();
+        let Some(Statement::VariableDeclaration(decl)) = ret.program.body.first() else {
+            panic!("expected a declaration");
+        };
+        let Some(Expression::ArrowFunctionExpression(arrow)) = &decl.declarations[0].init else {
+            panic!("expected an arrow function");
+        };
+        let diagnostics =
+            ComponentsReturnOnce::new().check_arrow(arrow, true, false, &ret.program, source);
+        assert_eq!(
+            diagnostics[0].fixes[0].replacement,
+            "<Show when={props.ok}>{props.label}</Show>"
+        );
+    }
 }
@@ -2,19 +2,24 @@
 //!
 //! Enforce naming DOM element event handlers consistently and prevent Solid's analysis
 //! from misunderstanding whether a prop should be an event handler.
+//!
+//! Also catches handler values that can't be functions, such as `onClick={save()}`,
+//! including on `on:` and `oncapture:` listeners.
 
 use oxc_ast::ast::{
     Expression, JSXAttributeItem, JSXAttributeName, JSXAttributeValue, JSXElementName,
     JSXOpeningElement,
 };
+use oxc_span::GetSpan;
 use serde::{Deserialize, Serialize};
 
+use crate::context::jsx_container_expression;
 use crate::diagnostic::{Diagnostic, Fix};
 use crate::utils::is_dom_element;
-use crate::{RuleCategory, RuleMeta};
+use crate::{ConfigurableRule, RuleCategory, RuleMeta};
 
 /// Common DOM events with correct casing
-const COMMON_EVENTS: &[&str] = &[
+pub(crate) const COMMON_EVENTS: &[&str] = &[
     "onAnimationEnd",
     "onAnimationIteration",
     "onAnimationStart",
@@ -77,7 +82,7 @@ const COMMON_EVENTS: &[&str] = &[
 
 /// Configuration for event-handlers rule
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct EventHandlersConfig {
     /// If true, don't warn on ambiguously named event handlers
     #[serde(default)]
@@ -96,6 +101,18 @@ pub struct EventHandlers {
 impl RuleMeta for EventHandlers {
     const NAME: &'static str = "event-handlers";
     const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Enforce naming DOM element event handlers consistently and prevent Solid's \
+        analysis from misunderstanding whether a prop should be an event handler.";
+    const FIXABLE: bool = true;
+}
+
+impl ConfigurableRule for EventHandlers {
+    type Options = EventHandlersConfig;
+
+    fn from_options(options: EventHandlersConfig) -> Self {
+        Self::with_config(options)
+    }
 }
 
 impl EventHandlers {
@@ -127,12 +144,25 @@ impl EventHandlers {
                 continue;
             };
 
-            // Skip namespaced attributes
+            // Namespaced listeners are named by the event itself; only their
+            // value is checked
             let (name, name_span) = match &jsx_attr.name {
                 JSXAttributeName::Identifier(ident) => {
                     (ident.name.as_str(), ident.span)
                 }
-                JSXAttributeName::NamespacedName(_) => continue,
+                JSXAttributeName::NamespacedName(ns) => {
+                    if matches!(ns.namespace.name.as_str(), "on" | "oncapture") {
+                        let name = format!("{}:{}", ns.namespace.name, ns.name.name);
+                        if let Some(diagnostic) = jsx_attr
+                            .value
+                            .as_ref()
+                            .and_then(|value| self.check_handler_value(&name, value))
+                        {
+                            diagnostics.push(diagnostic);
+                        }
+                    }
+                    continue;
+                }
             };
 
             // Check if it looks like an event handler
@@ -160,6 +190,9 @@ impl EventHandlers {
                     );
                     continue;
                 }
+                if let Some(diagnostic) = self.check_handler_value(name, value) {
+                    diagnostics.push(diagnostic);
+                }
             }
 
             if self.config.ignore_case {
@@ -223,7 +256,7 @@ impl EventHandlers {
         match value {
             JSXAttributeValue::StringLiteral(_) => true,
             JSXAttributeValue::ExpressionContainer(container) => {
-                if let Some(expr) = container.expression.as_expression() {
+                if let Some(expr) = jsx_container_expression(container) {
                     matches!(expr, Expression::StringLiteral(_) | Expression::NumericLiteral(_))
                 } else {
                     false
@@ -233,6 +266,45 @@ impl EventHandlers {
         }
     }
 
+    /// Report handler values that can't be a function: calls evaluated during
+    /// render and non-function literals
+    fn check_handler_value(&self, name: &str, value: &JSXAttributeValue) -> Option<Diagnostic> {
+        let JSXAttributeValue::ExpressionContainer(container) = value else {
+            return None;
+        };
+        let expr = jsx_container_expression(container)?;
+
+        // Even handler factories like `debounce(fn)` are better created once
+        // outside the JSX than on every render
+        if let Expression::CallExpression(_) = expr {
+            return Some(
+                Diagnostic::warning(
+                    Self::NAME,
+                    expr.span(),
+                    format!(
+                        "The {} handler is called immediately while rendering, and its return value is used as the handler.",
+                        name
+                    ),
+                )
+                .with_help("Pass a function instead, e.g. `() => handler()`, or create the handler outside the JSX."),
+            );
+        }
+
+        let kind = match expr {
+            Expression::BooleanLiteral(_) => "a boolean",
+            Expression::ObjectExpression(_) => "an object",
+            Expression::TemplateLiteral(_) => "a string",
+            Expression::RegExpLiteral(_) => "a regular expression",
+            Expression::BigIntLiteral(_) => "a number",
+            _ => return None,
+        };
+        Some(Diagnostic::warning(
+            Self::NAME,
+            expr.span(),
+            format!("The {} prop expects a function, but got {}.", name, kind),
+        ))
+    }
+
     fn get_correct_event_name(&self, lowercase: &str) -> Option<&'static str> {
         COMMON_EVENTS
             .iter()
@@ -244,6 +316,10 @@ impl EventHandlers {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Statement;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
 
     #[test]
     fn test_rule_name() {
@@ -257,4 +333,87 @@ mod tests {
         assert_eq!(rule.get_correct_event_name("onmousedown"), Some("onMouseDown"));
         assert_eq!(rule.get_correct_event_name("onfoobar"), None);
     }
+
+    fn check(source: &str) -> Vec<Diagnostic> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected JSX element");
+        };
+        EventHandlers::new().check(&element.opening_element)
+    }
+
+    #[test]
+    fn test_casing_fix() {
+        let diagnostics = check(r#"<div onclick={handle} />"#);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].fixes[0].replacement, "onClick");
+    }
+
+    #[test]
+    fn test_immediately_invoked_handlers() {
+        assert_eq!(check(r#"<button onClick={handle()} />"#).len(), 1);
+        assert_eq!(check(r#"<button onClick={setCount(count() + 1)} />"#).len(), 1);
+        assert_eq!(check(r#"<button onClick={props.handler(1)} />"#).len(), 1);
+        assert_eq!(
+            check(r#"<button onClick={debounce(save, 100)} />"#).len(),
+            1
+        );
+        assert!(check(r#"<button onClick={() => handle()} />"#).is_empty());
+        assert!(check(r#"<button onClick={[select, id]} />"#).is_empty());
+    }
+
+    #[test]
+    fn test_namespaced_handlers() {
+        let diagnostics = check(r#"<div on:click={f()} oncapture:focus={true} />"#);
+        assert_eq!(
+            diagnostics.iter().map(|d| d.message.as_str()).collect::<Vec<_>>(),
+            [
+                "The on:click handler is called immediately while rendering, and its return value is used as the handler.",
+                "The oncapture:focus prop expects a function, but got a boolean.",
+            ]
+        );
+        // The event name is the element's, so its casing is left alone
+        assert!(
+            check(r#"<div on:click={f} on:valueChange={g} on:my-event={() => f()} />"#).is_empty()
+        );
+        assert!(check(r#"<div use:tooltip={f()} attr:onclick="x" />"#).is_empty());
+    }
+
+    #[test]
+    fn test_enabled_by_default() {
+        let config = crate::LintConfig::default();
+        assert!(config.rules_config().event_handlers.is_some());
+        let diagnostics = crate::lint_source(
+            "<button onClick={props.handler(1)} on:click={f()} />;",
+            SourceType::tsx(),
+            &config,
+            &[],
+        );
+        let handlers = diagnostics
+            .iter()
+            .filter(|d| d.rule == EventHandlers::NAME)
+            .collect::<Vec<_>>();
+        assert_eq!(handlers.len(), 2);
+        assert!(handlers
+            .iter()
+            .all(|d| d.severity == crate::DiagnosticSeverity::Warning));
+    }
+
+    #[test]
+    fn test_non_function_literals() {
+        let diagnostics = check(r#"<button onClick={true} onInput={{}} />"#);
+        assert_eq!(
+            diagnostics.iter().map(|d| d.message.as_str()).collect::<Vec<_>>(),
+            [
+                "The onClick prop expects a function, but got a boolean.",
+                "The onInput prop expects a function, but got an object.",
+            ]
+        );
+    }
 }
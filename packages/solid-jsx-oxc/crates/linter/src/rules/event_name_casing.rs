@@ -0,0 +1,231 @@
+//! solid/event-name-casing
+//!
+//! Enforce one casing convention for custom events listened to with `on:`
+//! (`on:value-change` or `on:valueChange`), and catch `onValueChange` on
+//! custom elements. The transform lowercases the event name of `onX` handlers,
+//! so `onValueChange` listens for `valuechange` and never sees a `valueChange`
+//! or `value-change` event dispatched by the element.
+//!
+//! The names are not fixed automatically: the right spelling is whatever the
+//! element dispatches, which the linter can't see.
+
+use oxc_ast::ast::{JSXAttributeItem, JSXAttributeName, JSXElementName, JSXOpeningElement};
+use serde::{Deserialize, Serialize};
+
+use crate::diagnostic::Diagnostic;
+use crate::rules::event_handlers::COMMON_EVENTS;
+use crate::rules::style_prop::{to_camel_case, to_kebab_case};
+use crate::{ConfigurableRule, RuleCategory, RuleMeta};
+
+/// Casing of custom event names in `on:` attributes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventCasing {
+    /// `on:value-change`
+    #[default]
+    Kebab,
+    /// `on:valueChange`
+    Camel,
+}
+
+impl EventCasing {
+    fn apply(self, name: &str) -> String {
+        match self {
+            EventCasing::Kebab => to_kebab_case(name),
+            EventCasing::Camel => to_camel_case(name),
+        }
+    }
+}
+
+/// event-name-casing rule
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct EventNameCasing {
+    pub casing: EventCasing,
+}
+
+impl RuleMeta for EventNameCasing {
+    const NAME: &'static str = "event-name-casing";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Enforce one casing convention for custom events listened to with `on:` \
+        (`on:value-change` or `on:valueChange`), and catch `onValueChange` on custom \
+        elements. The transform lowercases the event name of `onX` handlers, so \
+        `onValueChange` listens for `valuechange` and never sees a `valueChange` or \
+        `value-change` event dispatched by the element.";
+}
+
+impl ConfigurableRule for EventNameCasing {
+    type Options = Self;
+
+    fn from_options(options: Self) -> Self {
+        options
+    }
+}
+
+impl EventNameCasing {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_casing(mut self, casing: EventCasing) -> Self {
+        self.casing = casing;
+        self
+    }
+
+    /// Check the event attributes of a JSX opening element
+    pub fn check<'a>(&self, opening: &JSXOpeningElement<'a>) -> Vec<Diagnostic> {
+        let is_custom_element = match &opening.name {
+            JSXElementName::Identifier(ident) => ident.name.contains('-'),
+            _ => false,
+        };
+        let mut diagnostics = Vec::new();
+
+        for attr in &opening.attributes {
+            let JSXAttributeItem::Attribute(jsx_attr) = attr else {
+                continue;
+            };
+
+            match &jsx_attr.name {
+                JSXAttributeName::NamespacedName(ns) if ns.namespace.name == "on" => {
+                    let name = ns.name.name.as_str();
+                    // Native `DOMContentLoaded`-style events have a fixed spelling
+                    if name.starts_with("DOM") {
+                        continue;
+                    }
+                    let expected = self.casing.apply(name);
+                    if expected != name {
+                        diagnostics.push(
+                            Diagnostic::warning(
+                                Self::NAME,
+                                ns.span,
+                                format!(
+                                    "Custom event `on:{}` should be {}: `on:{}`.",
+                                    name,
+                                    self.casing_name(),
+                                    expected
+                                ),
+                            )
+                            .with_help("Rename the event where it is dispatched as well."),
+                        );
+                    }
+                }
+                JSXAttributeName::Identifier(ident) if is_custom_element => {
+                    let name = ident.name.as_str();
+                    let Some(event) = camel_cased_custom_event(name) else {
+                        continue;
+                    };
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            Self::NAME,
+                            ident.span,
+                            format!(
+                                "`{}` listens for `{}`, so a `{}` event from this element never fires it.",
+                                name,
+                                event.to_lowercase(),
+                                event
+                            ),
+                        )
+                        .with_help(format!(
+                            "Use `on:{}` to listen for the event with its exact name.",
+                            self.casing.apply(&event)
+                        )),
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        diagnostics
+    }
+
+    fn casing_name(&self) -> &'static str {
+        match self.casing {
+            EventCasing::Kebab => "kebab-case",
+            EventCasing::Camel => "camelCase",
+        }
+    }
+}
+
+/// `onValueChange` -> `valueChange`, for handler names that lose meaningful
+/// casing when lowercased. Known DOM events (`onMouseDown`) are skipped.
+fn camel_cased_custom_event(name: &str) -> Option<String> {
+    let rest = name.strip_prefix("on")?;
+    let rest = rest.strip_suffix("Capture").unwrap_or(rest);
+    let mut chars = rest.chars();
+    let first = chars.next().filter(|c| c.is_ascii_uppercase())?;
+    if !chars.as_str().chars().any(|c| c.is_ascii_uppercase()) {
+        return None;
+    }
+    let handler = format!("on{}", rest);
+    if COMMON_EVENTS
+        .iter()
+        .any(|e| e.eq_ignore_ascii_case(&handler))
+    {
+        return None;
+    }
+    Some(format!("{}{}", first.to_ascii_lowercase(), chars.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn casing_messages(source: &str, rule: &EventNameCasing) -> Vec<String> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected JSX element");
+        };
+        rule.check(&element.opening_element)
+            .into_iter()
+            .map(|d| d.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(EventNameCasing::NAME, "event-name-casing");
+    }
+
+    #[test]
+    fn test_namespaced_casing() {
+        let source =
+            r#"<div on:valueChange={a} on:item-select={b} on:click={c} on:DOMContentLoaded={d} />"#;
+        assert_eq!(
+            casing_messages(source, &EventNameCasing::new()),
+            ["Custom event `on:valueChange` should be kebab-case: `on:value-change`."]
+        );
+        assert_eq!(
+            casing_messages(
+                source,
+                &EventNameCasing::new().with_casing(EventCasing::Camel)
+            ),
+            ["Custom event `on:item-select` should be camelCase: `on:itemSelect`."]
+        );
+    }
+
+    #[test]
+    fn test_camel_cased_handler_on_custom_element() {
+        let messages = casing_messages(
+            r#"<my-picker onValueChange={a} onMouseDown={b} onClick={c} onOpen={d} />"#,
+            &EventNameCasing::new(),
+        );
+        assert_eq!(
+            messages,
+            ["`onValueChange` listens for `valuechange`, so a `valueChange` event from this element never fires it."]
+        );
+        assert!(
+            casing_messages(r#"<Picker onValueChange={a} />"#, &EventNameCasing::new()).is_empty()
+        );
+    }
+}
@@ -1,10 +1,14 @@
 //! solid/imports
 //!
 //! Enforce consistent imports from "solid-js", "solid-js/web", and "solid-js/store".
+//!
+//! `check_program` also fixes the imports: misplaced specifiers are moved into
+//! an existing import of the right entry point, or into a new one.
 
-use oxc_ast::ast::ImportDeclaration;
+use oxc_ast::ast::{ImportDeclaration, ImportDeclarationSpecifier, Program, Statement};
 
-use crate::diagnostic::Diagnostic;
+use crate::diagnostic::{Diagnostic, Fix};
+use crate::utils::{line_span, span_text};
 use crate::{RuleCategory, RuleMeta};
 
 /// imports rule
@@ -14,6 +18,10 @@ pub struct Imports;
 impl RuleMeta for Imports {
     const NAME: &'static str = "imports";
     const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Enforce consistent imports from \"solid-js\", \"solid-js/web\", and \
+        \"solid-js/store\".";
+    const FIXABLE: bool = true;
 }
 
 /// Valid sources for Solid imports
@@ -212,9 +220,236 @@ impl Imports {
     }
 }
 
+/// A named specifier that is being moved to another entry point
+struct MovedSpecifier {
+    /// Specifier text without a leading `type` keyword (`Portal`, `Store as S`)
+    text: String,
+    is_type: bool,
+    target: &'static str,
+}
+
+/// Final state of one Solid import declaration once the fix is applied
+struct PlannedImport<'b, 'a> {
+    import: &'b ImportDeclaration<'a>,
+    /// Named specifiers as they will be written
+    named: Vec<String>,
+    /// Declarations for entry points that had no import yet, written after this one
+    appended: Vec<String>,
+    changed: bool,
+    /// Whether any specifier was moved out of this declaration
+    lost_specifiers: bool,
+}
+
+impl Imports {
+    /// Check all Solid imports in a program, fixing misplaced specifiers by
+    /// moving them between import declarations.
+    ///
+    /// Every diagnostic is reported, but the fix (which rewrites all affected
+    /// declarations at once) is attached to the first one only.
+    pub fn check_program<'a>(&self, program: &Program<'a>, source_text: &str) -> Vec<Diagnostic> {
+        let imports: Vec<&ImportDeclaration<'a>> = program
+            .body
+            .iter()
+            .filter_map(|stmt| match stmt {
+                Statement::ImportDeclaration(import) => Some(&**import),
+                _ => None,
+            })
+            .filter(|import| is_solid_source(import.source.value.as_str()))
+            .collect();
+
+        let mut diagnostics = Vec::new();
+        let mut plans = Vec::new();
+        let mut moved = Vec::new();
+        let mut fixable = true;
+
+        for import in &imports {
+            let found = self.check(import);
+            if found.is_empty() {
+                plans.push(PlannedImport::unchanged(import, source_text));
+                continue;
+            }
+            if import.with_clause.is_some() || import.phase.is_some() {
+                fixable = false;
+            }
+
+            let declaration_is_type = import.import_kind.is_type();
+            let mut named = Vec::new();
+            for specifier in import.specifiers.iter().flatten() {
+                let ImportDeclarationSpecifier::ImportSpecifier(spec) = specifier else {
+                    continue;
+                };
+                let spec_text = span_text(source_text, spec.span);
+                if found.iter().any(|d| d.span() == spec.span) {
+                    let is_type = declaration_is_type || spec.import_kind.is_type();
+                    let name = spec.imported.name();
+                    let target = if is_type {
+                        get_type_source(name.as_str())
+                    } else {
+                        get_primitive_source(name.as_str())
+                    };
+                    moved.push(MovedSpecifier {
+                        text: strip_type_keyword(spec_text).to_string(),
+                        is_type,
+                        target: target.unwrap_or("solid-js"),
+                    });
+                } else {
+                    named.push(spec_text.to_string());
+                }
+            }
+
+            plans.push(PlannedImport {
+                import,
+                named,
+                appended: Vec::new(),
+                changed: true,
+                lost_specifiers: true,
+            });
+            diagnostics.extend(found);
+        }
+
+        if diagnostics.is_empty() || !fixable {
+            return diagnostics;
+        }
+
+        // Place every moved specifier into an import of its target entry point
+        let mut new_imports: Vec<(&'static str, bool, Vec<String>)> = Vec::new();
+        for spec in moved {
+            let existing = plans.iter_mut().find(|plan| {
+                plan.import.source.value == spec.target
+                    && plan.accepts_named()
+                    && (spec.is_type || !plan.import.import_kind.is_type())
+            });
+            match existing {
+                Some(plan) => {
+                    let text = if spec.is_type && !plan.import.import_kind.is_type() {
+                        format!("type {}", spec.text)
+                    } else {
+                        spec.text
+                    };
+                    plan.named.push(text);
+                    plan.changed = true;
+                }
+                None => match new_imports
+                    .iter_mut()
+                    .find(|(target, is_type, _)| *target == spec.target && *is_type == spec.is_type)
+                {
+                    Some((_, _, named)) => named.push(spec.text),
+                    None => new_imports.push((spec.target, spec.is_type, vec![spec.text])),
+                },
+            }
+        }
+
+        // New declarations go after the last declaration that lost a specifier
+        if let Some(anchor) = plans.iter_mut().rev().find(|plan| plan.lost_specifiers) {
+            for (target, is_type, named) in new_imports {
+                anchor.appended.push(format!(
+                    "import {}{{ {} }} from \"{}\";",
+                    if is_type { "type " } else { "" },
+                    named.join(", "),
+                    target
+                ));
+            }
+        }
+
+        let mut fixes = Vec::new();
+        for plan in plans.iter().filter(|plan| plan.changed) {
+            let mut statements = Vec::new();
+            if let Some(declaration) = plan.render(source_text) {
+                statements.push(declaration);
+            }
+            statements.extend(plan.appended.iter().cloned());
+
+            let fix = if statements.is_empty() {
+                Fix::new(line_span(source_text, plan.import.span), "")
+            } else {
+                Fix::new(plan.import.span, statements.join("\n"))
+            };
+            fixes.push(fix.with_message(""));
+        }
+
+        if let (Some(first), Some(diagnostic)) = (fixes.first_mut(), diagnostics.first_mut()) {
+            first.message = Some("Move imports to their correct entry points".to_string());
+            diagnostic.fixes.append(&mut fixes);
+        }
+
+        diagnostics
+    }
+}
+
+impl<'b, 'a> PlannedImport<'b, 'a> {
+    fn unchanged(import: &'b ImportDeclaration<'a>, source_text: &str) -> Self {
+        let named = import
+            .specifiers
+            .iter()
+            .flatten()
+            .filter_map(|specifier| match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(spec) => {
+                    Some(span_text(source_text, spec.span).to_string())
+                }
+                _ => None,
+            })
+            .collect();
+        Self {
+            import,
+            named,
+            appended: Vec::new(),
+            changed: false,
+            lost_specifiers: false,
+        }
+    }
+
+    /// Named specifiers can't be added next to a namespace import or to a
+    /// side-effect-only import
+    fn accepts_named(&self) -> bool {
+        self.import.with_clause.is_none()
+            && self.import.phase.is_none()
+            && self.import.specifiers.as_ref().is_some_and(|specifiers| {
+                !specifiers.iter().any(|specifier| {
+                    matches!(
+                        specifier,
+                        ImportDeclarationSpecifier::ImportNamespaceSpecifier(_)
+                    )
+                })
+            })
+    }
+
+    /// The declaration as it will be written, or `None` if nothing is left
+    fn render(&self, source_text: &str) -> Option<String> {
+        let mut clauses = Vec::new();
+        for specifier in self.import.specifiers.iter().flatten() {
+            match specifier {
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(default) => {
+                    clauses.push(span_text(source_text, default.span).to_string());
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(namespace) => {
+                    clauses.push(span_text(source_text, namespace.span).to_string());
+                }
+                ImportDeclarationSpecifier::ImportSpecifier(_) => {}
+            }
+        }
+        if !self.named.is_empty() {
+            clauses.push(format!("{{ {} }}", self.named.join(", ")));
+        }
+        if clauses.is_empty() {
+            return None;
+        }
+        Some(format!(
+            "import {}{} from {};",
+            if self.import.import_kind.is_type() { "type " } else { "" },
+            clauses.join(", "),
+            span_text(source_text, self.import.source.span)
+        ))
+    }
+}
+
+fn strip_type_keyword(text: &str) -> &str {
+    text.strip_prefix("type ").map_or(text, str::trim_start)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::apply_fixes;
     use oxc_allocator::Allocator;
     use oxc_parser::Parser;
     use oxc_span::SourceType;
@@ -248,6 +483,49 @@ mod tests {
         assert_eq!(Imports::NAME, "imports");
     }
 
+    fn fix_program(source: &str) -> (usize, String) {
+        let allocator = Allocator::default();
+        let program = parse_and_get_import(&allocator, source).expect("should parse");
+        let diagnostics = Imports::new().check_program(&program, source);
+        let output = apply_fixes(source, diagnostics.clone()).output;
+        (diagnostics.len(), output)
+    }
+
+    #[test]
+    fn test_fix_moves_into_existing_import() {
+        let source = "import { createSignal, Portal } from \"solid-js\";\nimport { render } from \"solid-js/web\";\n";
+        assert_eq!(
+            fix_program(source),
+            (
+                1,
+                "import { createSignal } from \"solid-js\";\nimport { render, Portal } from \"solid-js/web\";\n"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_fix_creates_missing_imports() {
+        let source = "import { render, createSignal } from \"solid-js/web\";\nimport { createStore } from \"solid-js\";\nrender();\n";
+        assert_eq!(
+            fix_program(source),
+            (
+                2,
+                "import { render } from \"solid-js/web\";\nimport { createSignal } from \"solid-js\";\nimport { createStore } from \"solid-js/store\";\nrender();\n"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_fix_type_imports() {
+        let source = "import type { Component, Store } from \"solid-js\";\nimport { createStore } from \"solid-js/store\";\n";
+        assert_eq!(
+            fix_program(source).1,
+            "import type { Component } from \"solid-js\";\nimport { createStore, type Store } from \"solid-js/store\";\n"
+        );
+    }
+
     #[test]
     fn test_correct_solid_js_import() {
         let allocator = Allocator::default();
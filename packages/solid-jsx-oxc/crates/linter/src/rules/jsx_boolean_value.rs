@@ -0,0 +1,224 @@
+//! solid/jsx-boolean-value
+//!
+//! Enforce one spelling for `true` props: `<Comp flag />` or
+//! `<Comp flag={true} />`. Both pass `true` to a component. On an element a
+//! bare attribute is set to `""` and `={true}` to `"true"`, which boolean
+//! attributes like `disabled` treat the same. `aria-*` and `data-*` values
+//! are read as strings, though (`aria-hidden=""` does not hide anything), so
+//! those are skipped on elements, as are namespaced attributes like
+//! `use:tooltip`.
+
+use oxc_ast::ast::{
+    Expression, JSXAttributeItem, JSXAttributeName, JSXAttributeValue, JSXOpeningElement,
+};
+use oxc_span::Span;
+use serde::{Deserialize, Serialize};
+
+use crate::context::jsx_container_expression;
+use crate::diagnostic::{Diagnostic, Fix};
+use crate::utils::is_component;
+use crate::{ConfigurableRule, RuleCategory, RuleMeta};
+
+/// How `true` props should be written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BooleanValueStyle {
+    /// `<Comp flag={true} />`
+    Always,
+    /// `<Comp flag />`
+    #[default]
+    Never,
+}
+
+/// jsx-boolean-value rule
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct JsxBooleanValue {
+    pub style: BooleanValueStyle,
+    /// Props that use the opposite style
+    pub exceptions: Vec<String>,
+}
+
+impl RuleMeta for JsxBooleanValue {
+    const NAME: &'static str = "jsx-boolean-value";
+    const CATEGORY: RuleCategory = RuleCategory::Style;
+    const DESCRIPTION: &'static str = "\
+        Enforce one spelling for `true` props: `<Comp flag />` or `<Comp flag={true} \
+        />`. Both pass `true` to a component. On an element a bare attribute is set \
+        to `\"\"` and `={true}` to `\"true\"`, which boolean attributes like \
+        `disabled` treat the same. `aria-*` and `data-*` values are read as strings, \
+        though, so those are skipped on elements, as are namespaced attributes like \
+        `use:tooltip`.";
+    const FIXABLE: bool = true;
+    const DEFAULT_ENABLED: bool = false;
+}
+
+impl ConfigurableRule for JsxBooleanValue {
+    type Options = Self;
+
+    fn from_options(options: Self) -> Self {
+        options
+    }
+}
+
+impl JsxBooleanValue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_style(mut self, style: BooleanValueStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_exceptions(mut self, exceptions: Vec<String>) -> Self {
+        self.exceptions = exceptions;
+        self
+    }
+
+    /// Check the attributes of a JSX opening element
+    pub fn check<'a>(&self, opening: &JSXOpeningElement<'a>) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let component = is_component(opening);
+
+        for attr in &opening.attributes {
+            let JSXAttributeItem::Attribute(jsx_attr) = attr else {
+                continue;
+            };
+            let JSXAttributeName::Identifier(name) = &jsx_attr.name else {
+                continue;
+            };
+            // `""` and `"true"` are different values for these
+            if !component && (name.name.starts_with("aria-") || name.name.starts_with("data-")) {
+                continue;
+            }
+
+            let style = match (
+                self.style,
+                self.exceptions.iter().any(|e| e == name.name.as_str()),
+            ) {
+                (BooleanValueStyle::Always, false) | (BooleanValueStyle::Never, true) => {
+                    BooleanValueStyle::Always
+                }
+                _ => BooleanValueStyle::Never,
+            };
+
+            match (style, &jsx_attr.value) {
+                (BooleanValueStyle::Always, None) => diagnostics.push(
+                    Diagnostic::warning(
+                        Self::NAME,
+                        jsx_attr.span,
+                        format!("Value must be set for boolean prop `{}`.", name.name),
+                    )
+                    .with_fix(
+                        Fix::new(Span::new(name.span.end, name.span.end), "={true}")
+                            .with_message("Add `={true}`"),
+                    ),
+                ),
+                (
+                    BooleanValueStyle::Never,
+                    Some(JSXAttributeValue::ExpressionContainer(container)),
+                ) if matches!(
+                    jsx_container_expression(container),
+                    Some(Expression::BooleanLiteral(lit)) if lit.value
+                ) =>
+                {
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            Self::NAME,
+                            jsx_attr.span,
+                            format!("Value must be omitted for boolean prop `{}`.", name.name),
+                        )
+                        .with_fix(
+                            Fix::new(Span::new(name.span.end, jsx_attr.span.end), "")
+                                .with_message("Remove `={true}`"),
+                        ),
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        diagnostics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fix::apply_fixes;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Statement;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn fixed(source: &str, rule: &JsxBooleanValue) -> String {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected JSX element");
+        };
+        let diagnostics = rule.check(&element.opening_element);
+        apply_fixes(source, diagnostics).output
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(JsxBooleanValue::NAME, "jsx-boolean-value");
+    }
+
+    #[test]
+    fn test_never() {
+        let rule = JsxBooleanValue::new();
+        assert_eq!(
+            fixed(
+                r#"<Comp flag={true} open={false} use:tip disabled />"#,
+                &rule
+            ),
+            r#"<Comp flag open={false} use:tip disabled />"#
+        );
+    }
+
+    #[test]
+    fn test_aria_and_data_on_elements() {
+        let rule = JsxBooleanValue::new();
+        assert_eq!(
+            fixed(
+                r#"<div hidden={true} aria-hidden={true} data-open={true} />"#,
+                &rule
+            ),
+            r#"<div hidden aria-hidden={true} data-open={true} />"#
+        );
+        assert_eq!(
+            fixed(r#"<Comp aria-hidden={true} />"#, &rule),
+            r#"<Comp aria-hidden />"#
+        );
+        let rule = JsxBooleanValue::new().with_style(BooleanValueStyle::Always);
+        assert_eq!(
+            fixed(r#"<div hidden aria-hidden data-open />"#, &rule),
+            r#"<div hidden={true} aria-hidden data-open />"#
+        );
+    }
+
+    #[test]
+    fn test_always_with_exceptions() {
+        let rule = JsxBooleanValue::new()
+            .with_style(BooleanValueStyle::Always)
+            .with_exceptions(vec!["disabled".to_string()]);
+        assert_eq!(
+            fixed(r#"<Comp flag disabled={true} use:tip />"#, &rule),
+            r#"<Comp flag={true} disabled use:tip />"#
+        );
+    }
+
+    #[test]
+    fn test_style_from_json() {
+        let style: BooleanValueStyle = serde_json::from_str(r#""always""#).unwrap();
+        assert_eq!(style, BooleanValueStyle::Always);
+    }
+}
@@ -5,19 +5,33 @@
 //! In Solid, this also covers:
 //! - Duplicate `class` props (use `classList` instead)
 //! - Conflicting children sources (innerHTML, textContent, children prop, JSX children)
+//! - Aliases and namespaced spellings of the same prop (`className`/`class`,
+//!   `attr:title`/`title`, `onClick`/`onclick`)
+//! - `class:name` toggles that repeat a key of a static `classList` object
+//!
+//! `on:click`, `oncapture:click` and `onClickCapture` each add their own
+//! listener next to `onClick`, so they are distinct props.
+//!
+//! The transform keeps the last occurrence, so the fix removes the earlier one.
+//! Event handlers are never removed: a listener may be the one that is meant.
 
-use oxc_ast::ast::{JSXAttributeItem, JSXAttributeName, JSXChild, JSXOpeningElement};
-use oxc_span::Span;
+use common::constants::ALIASES;
+use oxc_ast::ast::{
+    Expression, JSXAttributeItem, JSXAttributeName, JSXAttributeValue, JSXChild,
+    JSXOpeningElement, ObjectPropertyKind,
+};
+use oxc_span::{GetSpan, Span};
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 
-use crate::diagnostic::Diagnostic;
+use crate::context::jsx_container_expression;
+use crate::diagnostic::{Diagnostic, Fix};
 use crate::utils::{has_children, is_event_handler};
-use crate::{RuleCategory, RuleMeta};
+use crate::{ConfigurableRule, RuleCategory, RuleMeta};
 
 /// Configuration for jsx-no-duplicate-props
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct JsxNoDuplicatePropsConfig {
     /// Consider two prop names differing only by case to be the same
     #[serde(default)]
@@ -33,6 +47,16 @@ pub struct JsxNoDuplicateProps {
 impl RuleMeta for JsxNoDuplicateProps {
     const NAME: &'static str = "jsx-no-duplicate-props";
     const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "Disallow passing the same prop twice in JSX.";
+    const FIXABLE: bool = true;
+}
+
+impl ConfigurableRule for JsxNoDuplicateProps {
+    type Options = JsxNoDuplicatePropsConfig;
+
+    fn from_options(options: JsxNoDuplicatePropsConfig) -> Self {
+        Self::with_config(options)
+    }
 }
 
 impl JsxNoDuplicateProps {
@@ -51,9 +75,13 @@ impl JsxNoDuplicateProps {
         children: &[JSXChild<'a>],
     ) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
-        let mut props: FxHashMap<String, Span> = FxHashMap::default();
+        // Normalized name -> span of the latest occurrence and of its whole attribute
+        let mut props: FxHashMap<String, (Span, Span)> = FxHashMap::default();
+        let mut previous_end = opening.name.span().end;
 
         for attr in &opening.attributes {
+            let attr_start = previous_end;
+            previous_end = attr.span().end;
             let JSXAttributeItem::Attribute(jsx_attr) = attr else {
                 continue;
             };
@@ -65,42 +93,51 @@ impl JsxNoDuplicateProps {
                     ns.span,
                 ),
             };
+            // Include the whitespace before the attribute so removing it leaves no gap
+            let removal = Span::new(attr_start, jsx_attr.span.end);
 
-            // Normalize name for comparison
-            let normalized = if self.config.ignore_case || is_event_handler(&name) {
-                name.to_lowercase()
-                    .replace("oncapture:", "on")
-                    .replace("on:", "on")
-                    .replace("attr:", "")
-                    .replace("prop:", "")
-            } else {
-                name.clone()
-            };
+            let mut keys = vec![self.normalize(&name)];
+            if name == "classList" {
+                keys.extend(class_list_keys(&jsx_attr.value));
+            }
+
+            for normalized in keys {
+                if let Some((previous_span, previous_removal)) =
+                    props.insert(normalized.clone(), (span, removal))
+                {
+                    let (message, help) = if normalized == "class" {
+                        (
+                            "Duplicate `class` props are not allowed.".to_string(),
+                            "While it might seem to work, it can break unexpectedly. Use `classList` instead.".to_string(),
+                        )
+                    } else if let Some(class) = normalized.strip_prefix("class:") {
+                        (
+                            format!("The class \"{}\" is toggled by both `class:{}` and `classList`.", class, class),
+                            "Toggle each class in only one place.".to_string(),
+                        )
+                    } else {
+                        (
+                            format!(
+                                "No duplicate props allowed. The prop \"{}\" is duplicated.",
+                                name
+                            ),
+                            "Remove one of the props, or rename them so each prop is distinct."
+                                .to_string(),
+                        )
+                    };
 
-            if let Some(first_span) = props.get(&normalized) {
-                let (message, help) = if normalized == "class" {
-                    (
-                        "Duplicate `class` props are not allowed.".to_string(),
-                        "While it might seem to work, it can break unexpectedly. Use `classList` instead.".to_string(),
-                    )
-                } else {
-                    (
-                        format!(
-                            "No duplicate props allowed. The prop \"{}\" is duplicated.",
-                            name
-                        ),
-                        "Remove one of the props, or rename them so each prop is distinct."
-                            .to_string(),
-                    )
-                };
-
-                diagnostics.push(
-                    Diagnostic::warning(Self::NAME, span, message)
+                    let mut diagnostic = Diagnostic::warning(Self::NAME, span, message)
                         .with_help(help)
-                        .with_label(*first_span, "first occurrence"),
-                );
-            } else {
-                props.insert(normalized, span);
+                        .with_label(previous_span, "previous occurrence");
+                    // A class toggle lives inside `classList`, so only whole props are removed
+                    if !normalized.starts_with("class:") && !normalized.starts_with("on") {
+                        diagnostic = diagnostic.with_fix(
+                            Fix::new(previous_removal, "")
+                                .with_message("Remove the earlier prop, which is ignored"),
+                        );
+                    }
+                    diagnostics.push(diagnostic);
+                }
             }
         }
 
@@ -141,9 +178,57 @@ impl JsxNoDuplicateProps {
     }
 }
 
+impl JsxNoDuplicateProps {
+    /// The name two spellings of the same prop have in common
+    fn normalize(&self, name: &str) -> String {
+        let name = name
+            .strip_prefix("attr:")
+            .or_else(|| name.strip_prefix("prop:"))
+            .unwrap_or(name);
+        let name = ALIASES.get(name).copied().unwrap_or(name);
+
+        // onClick, onclick -> onclick; the namespaced forms are kept as written
+        if is_event_handler(name) && !name.contains(':') {
+            return name.to_lowercase();
+        }
+
+        if self.config.ignore_case {
+            name.to_lowercase()
+        } else {
+            name.to_string()
+        }
+    }
+}
+
+/// `class:<key>` for each static key of a `classList={{ ... }}` object
+fn class_list_keys(value: &Option<JSXAttributeValue>) -> Vec<String> {
+    let Some(JSXAttributeValue::ExpressionContainer(container)) = value else {
+        return Vec::new();
+    };
+    let Some(Expression::ObjectExpression(object)) = jsx_container_expression(container) else {
+        return Vec::new();
+    };
+    object
+        .properties
+        .iter()
+        .filter_map(|property| match property {
+            ObjectPropertyKind::ObjectProperty(property) if !property.computed => {
+                property.key.static_name()
+            }
+            _ => None,
+        })
+        .map(|key| format!("class:{}", key))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::apply_fixes;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Statement;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
 
     #[test]
     fn test_config_defaults() {
@@ -157,4 +242,89 @@ mod tests {
         let config: JsxNoDuplicatePropsConfig = serde_json::from_str(json).unwrap();
         assert!(config.ignore_case);
     }
+
+    fn check(source: &str, rule: &JsxNoDuplicateProps) -> (Vec<String>, String) {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected JSX element");
+        };
+        let diagnostics = rule.check(&element.opening_element, &element.children);
+        let output = apply_fixes(source, diagnostics.clone()).output;
+        (diagnostics.into_iter().map(|d| d.message).collect(), output)
+    }
+
+    #[test]
+    fn test_equivalent_spellings() {
+        let rule = JsxNoDuplicateProps::new();
+        let (messages, fixed) = check(r#"<div className="a" class="b" />"#, &rule);
+        assert_eq!(messages, ["Duplicate `class` props are not allowed."]);
+        assert_eq!(fixed, r#"<div class="b" />"#);
+
+        let source = r#"<div on:click={a} onClick={b} oncapture:focus={c} onFocusCapture={d} />"#;
+        assert!(check(source, &rule).0.is_empty());
+
+        // Reported, but no handler is removed
+        let source = r#"<div onClick={a} onclick={b} on:click={c} on:click={d} />"#;
+        let (messages, fixed) = check(source, &rule);
+        assert_eq!(messages.len(), 2);
+        assert_eq!(fixed, source);
+
+        let (messages, _) = check(r#"<div attr:title="a" title="b" />"#, &rule);
+        assert_eq!(messages.len(), 1);
+    }
+
+    crate::rule_test!(test_jsx_no_duplicate_props, JsxNoDuplicateProps::NAME, {
+        valid: [
+            r#"<div class="a" id="b" />"#,
+            "<div tabIndex={1} tabindex={2} />",
+        ],
+        invalid: [
+            {
+                code: r#"<div id="a" id="b" />"#,
+                errors: [{
+                    message: "No duplicate props allowed. The prop \"id\" is duplicated.",
+                    text: "id",
+                }],
+                output: r#"<div id="b" />"#,
+            },
+            {
+                code: "<div tabIndex={1} tabindex={2} />",
+                errors: [{ span: 18..26 }],
+                options: serde_json::json!({ "ignoreCase": true }),
+                output: "<div tabindex={2} />",
+            },
+            {
+                code: "<div children={a} innerHTML={b} />",
+                errors: ["Using `props.children`, `props.innerHTML` at the same time is not allowed."],
+            },
+        ],
+    });
+
+    #[test]
+    fn test_ignore_case() {
+        let source = r#"<div tabIndex={1} tabindex={2} />"#;
+        assert!(check(source, &JsxNoDuplicateProps::new()).0.is_empty());
+
+        let rule = JsxNoDuplicateProps::with_config(JsxNoDuplicatePropsConfig { ignore_case: true });
+        assert_eq!(check(source, &rule).1, r#"<div tabindex={2} />"#);
+    }
+
+    #[test]
+    fn test_class_list_conflicts() {
+        let (messages, fixed) = check(
+            r#"<div class:active={a()} classList={{ active: b(), open: c() }} />"#,
+            &JsxNoDuplicateProps::new(),
+        );
+        assert_eq!(
+            messages,
+            ["The class \"active\" is toggled by both `class:active` and `classList`."]
+        );
+        assert_eq!(fixed, r#"<div class:active={a()} classList={{ active: b(), open: c() }} />"#);
+    }
 }
@@ -1,14 +1,20 @@
 //! solid/jsx-no-script-url
 //!
 //! Disallow `javascript:` URLs in JSX attributes.
+//!
+//! Values are resolved with the shared constant evaluator, so template
+//! literals and concatenations like `"java" + "script:void(0)"` are caught too.
 
-use oxc_ast::ast::{
-    Expression, JSXAttributeItem, JSXAttributeName, JSXAttributeValue, JSXOpeningElement,
-};
+use common::{evaluate_string, get_attr_name};
+use oxc_ast::ast::{JSXAttributeItem, JSXAttributeValue, JSXOpeningElement};
+use oxc_span::Span;
 
-use crate::diagnostic::Diagnostic;
+use crate::diagnostic::{Diagnostic, DiagnosticSeverity};
 use crate::{RuleCategory, RuleMeta};
 
+/// Attributes that navigate to or load a URL
+const URL_ATTRIBUTES: &[&str] = &["href", "src", "xlink:href", "action", "formAction"];
+
 /// jsx-no-script-url rule
 #[derive(Debug, Clone, Default)]
 pub struct JsxNoScriptUrl;
@@ -16,6 +22,8 @@ pub struct JsxNoScriptUrl;
 impl RuleMeta for JsxNoScriptUrl {
     const NAME: &'static str = "jsx-no-script-url";
     const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "Disallow `javascript:` URLs in JSX attributes.";
+    const DEFAULT_SEVERITY: DiagnosticSeverity = DiagnosticSeverity::Error;
 }
 
 impl JsxNoScriptUrl {
@@ -32,18 +40,13 @@ impl JsxNoScriptUrl {
                 continue;
             };
 
-            // Only check href and src attributes
-            let attr_name = match &jsx_attr.name {
-                JSXAttributeName::Identifier(ident) => ident.name.as_str(),
-                _ => continue,
-            };
-
-            if !matches!(attr_name, "href" | "src" | "action" | "formAction") {
+            let attr_name = get_attr_name(&jsx_attr.name);
+            if !URL_ATTRIBUTES.contains(&attr_name.as_ref()) {
                 continue;
             }
 
             if let Some(value) = &jsx_attr.value {
-                if let Some(diagnostic) = self.check_value(value, jsx_attr.span, attr_name) {
+                if let Some(diagnostic) = self.check_value(value, jsx_attr.span, &attr_name) {
                     diagnostics.push(diagnostic);
                 }
             }
@@ -55,59 +58,86 @@ impl JsxNoScriptUrl {
     fn check_value(
         &self,
         value: &JSXAttributeValue,
-        span: oxc_span::Span,
+        span: Span,
         attr_name: &str,
     ) -> Option<Diagnostic> {
-        match value {
-            JSXAttributeValue::StringLiteral(lit) => {
-                let value_str = lit.value.as_str().trim();
-                if value_str.to_lowercase().starts_with("javascript:") {
-                    return Some(
-                        Diagnostic::error(
-                            Self::NAME,
-                            span,
-                            format!(
-                                "`javascript:` URLs in the `{}` attribute are a security risk.",
-                                attr_name
-                            ),
-                        )
-                        .with_help("Use an event handler like `onClick` instead."),
-                    );
-                }
-            }
+        let url = match value {
+            JSXAttributeValue::StringLiteral(lit) => lit.value.to_string(),
             JSXAttributeValue::ExpressionContainer(container) => {
-                // Check string expressions
-                if let Some(expr) = container.expression.as_expression() {
-                    if let Expression::StringLiteral(lit) = expr {
-                        let value_str = lit.value.as_str().trim();
-                        if value_str.to_lowercase().starts_with("javascript:") {
-                            return Some(
-                                Diagnostic::error(
-                                    Self::NAME,
-                                    span,
-                                    format!(
-                                        "`javascript:` URLs in the `{}` attribute are a security risk.",
-                                        attr_name
-                                    ),
-                                )
-                                .with_help("Use an event handler like `onClick` instead."),
-                            );
-                        }
-                    }
-                }
+                evaluate_string(container.expression.as_expression()?)?
             }
-            _ => {}
-        }
-        None
+            _ => return None,
+        };
+
+        is_script_url(&url).then(|| {
+            Diagnostic::error(
+                Self::NAME,
+                span,
+                format!(
+                    "`javascript:` URLs in the `{}` attribute are a security risk.",
+                    attr_name
+                ),
+            )
+            .with_help("Use an event handler like `onClick` instead.")
+        })
     }
 }
 
+/// Whether a browser would treat `url` as a `javascript:` URL.
+///
+/// URL parsing drops leading control characters and spaces, and tabs or
+/// newlines anywhere, so `" java\tscript:"` still runs script.
+fn is_script_url(url: &str) -> bool {
+    let scheme: String = url
+        .trim_start_matches(|c: char| c <= ' ')
+        .chars()
+        .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+        .take("javascript:".len())
+        .collect();
+    scheme.eq_ignore_ascii_case("javascript:")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn count(source: &str) -> usize {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected JSX element");
+        };
+        JsxNoScriptUrl::new().check(&element.opening_element).len()
+    }
 
     #[test]
     fn test_rule_name() {
         assert_eq!(JsxNoScriptUrl::NAME, "jsx-no-script-url");
     }
+
+    #[test]
+    fn test_script_urls() {
+        assert_eq!(count(r#"<a href="javascript:void(0)" />"#), 1);
+        assert_eq!(count(r#"<a href=" JavaScript:alert(1)" />"#), 1);
+        assert_eq!(count(r#"<a href={`javascript:${"go"}()`} />"#), 1);
+        assert_eq!(count(r#"<a href={"java" + "script:go()"} />"#), 1);
+        assert_eq!(count(r#"<img src={"java\tscript:go()"} />"#), 1);
+        assert_eq!(count(r#"<use xlink:href="javascript:go()" />"#), 1);
+    }
+
+    #[test]
+    fn test_safe_urls() {
+        assert_eq!(count(r#"<a href="/javascript:go" />"#), 0);
+        assert_eq!(count(r#"<a href={url} />"#), 0);
+        assert_eq!(count(r#"<a title="javascript:go()" />"#), 0);
+    }
 }
@@ -2,6 +2,11 @@
 //!
 //! Disallow references to undefined variables in JSX.
 //! Handles custom directives with use:X namespace.
+//!
+//! Solid built-ins (`Show`, `For`, `Portal`, ...) get an import fix that extends an
+//! existing import from the right module or adds a new one after the last import.
+
+use std::collections::BTreeMap;
 
 use oxc_ast::ast::{
     JSXAttributeItem, JSXAttributeName, JSXElementName, JSXMemberExpressionObject,
@@ -10,16 +15,40 @@ use oxc_ast::ast::{
 use oxc_semantic::{ScopeId, Scoping};
 use oxc_span::Span;
 
-use crate::diagnostic::{Diagnostic, Fix};
-use crate::utils::is_dom_element;
-use crate::{RuleCategory, RuleMeta};
-
-/// Solid control flow components that can be auto-imported from "solid-js"
-const AUTO_COMPONENTS: &[&str] = &["Show", "For", "Index", "Switch", "Match"];
+use serde::{Deserialize, Serialize};
+
+use crate::context::is_type_only_import;
+use crate::diagnostic::{Diagnostic, DiagnosticSeverity, Fix};
+use crate::utils::{add_import_fix, is_dom_element};
+use crate::{ConfigurableRule, RuleCategory, RuleMeta};
+
+/// Solid built-in components that can be auto-imported, with their module
+const AUTO_IMPORTS: &[(&str, &str)] = &[
+    ("ErrorBoundary", "solid-js"),
+    ("For", "solid-js"),
+    ("Index", "solid-js"),
+    ("Match", "solid-js"),
+    ("Show", "solid-js"),
+    ("Suspense", "solid-js"),
+    ("SuspenseList", "solid-js"),
+    ("Switch", "solid-js"),
+    ("Dynamic", "solid-js/web"),
+    ("NoHydration", "solid-js/web"),
+    ("Portal", "solid-js/web"),
+];
 const SOURCE_MODULE: &str = "solid-js";
 
+/// The module a Solid built-in component is exported from
+pub fn auto_import_source(name: &str) -> Option<&'static str> {
+    AUTO_IMPORTS
+        .iter()
+        .find(|(component, _)| *component == name)
+        .map(|(_, source)| *source)
+}
+
 /// Options for the jsx-no-undef rule
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
 pub struct JsxNoUndefOptions {
     /// When true, consider global scope when checking for defined components
     pub allow_globals: bool,
@@ -48,6 +77,19 @@ pub struct JsxNoUndef {
 impl RuleMeta for JsxNoUndef {
     const NAME: &'static str = "jsx-no-undef";
     const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Disallow references to undefined variables in JSX. Handles custom directives \
+        with use:X namespace.";
+    const FIXABLE: bool = true;
+    const DEFAULT_SEVERITY: DiagnosticSeverity = DiagnosticSeverity::Error;
+}
+
+impl ConfigurableRule for JsxNoUndef {
+    type Options = JsxNoUndefOptions;
+
+    fn from_options(options: JsxNoUndefOptions) -> Self {
+        Self::with_options(options)
+    }
 }
 
 /// Information about an undefined identifier
@@ -57,6 +99,8 @@ pub struct UndefinedIdent {
     span: Span,
     is_component: bool,
     is_custom_directive: bool,
+    /// Bound only by `import type`, so there is no value at runtime
+    is_type_only: bool,
 }
 
 impl JsxNoUndef {
@@ -81,28 +125,28 @@ impl JsxNoUndef {
         match &opening.name {
             JSXElementName::Identifier(ident) => {
                 // Only check components (capitalized names), not DOM elements
-                if !is_dom_element(&ident.name) && ident.name != "this" {
-                    if !self.is_defined(scoping, scope_id, &ident.name) {
+                if !is_dom_element(&ident.name) && ident.name != "this"
+                    && !self.is_defined(scoping, scope_id, &ident.name) {
                         undefined.push(UndefinedIdent {
                             name: ident.name.to_string(),
                             span: ident.span,
                             is_component: true,
                             is_custom_directive: false,
+                            is_type_only: false,
                         });
                     }
-                }
             }
             JSXElementName::IdentifierReference(ident) => {
-                if !is_dom_element(&ident.name) && ident.name != "this" {
-                    if !self.is_defined(scoping, scope_id, &ident.name) {
+                if !is_dom_element(&ident.name) && ident.name != "this"
+                    && !self.is_defined(scoping, scope_id, &ident.name) {
                         undefined.push(UndefinedIdent {
                             name: ident.name.to_string(),
                             span: ident.span,
                             is_component: true,
                             is_custom_directive: false,
+                            is_type_only: false,
                         });
                     }
-                }
             }
             JSXElementName::MemberExpression(member) => {
                 // For <Foo.Bar>, check if Foo is defined
@@ -113,6 +157,7 @@ impl JsxNoUndef {
                             span,
                             is_component: false,
                             is_custom_directive: false,
+                            is_type_only: false,
                         });
                     }
                 }
@@ -134,6 +179,7 @@ impl JsxNoUndef {
                                 span: ns_name.name.span,
                                 is_component: false,
                                 is_custom_directive: true,
+                                is_type_only: false,
                             });
                         }
                     }
@@ -141,45 +187,75 @@ impl JsxNoUndef {
             }
         }
 
+        for ident in &mut undefined {
+            ident.is_type_only = scoping
+                .find_binding(scope_id, ident.name.as_str().into())
+                .is_some_and(|symbol_id| is_type_only_import(scoping, symbol_id));
+        }
         undefined
     }
 
-    /// Check if an identifier is defined in scope
+    /// Check if an identifier is defined in scope as a value
     fn is_defined(&self, scoping: &Scoping, scope_id: ScopeId, name: &str) -> bool {
-        // Check local/module scopes
-        if scoping.find_binding(scope_id, name.into()).is_some() {
-            return true;
+        // Check local/module scopes; type-only imports shadow any global
+        if let Some(symbol_id) = scoping.find_binding(scope_id, name.into()) {
+            return !is_type_only_import(scoping, symbol_id);
         }
 
         // Check global scope if allowed
-        if self.options.allow_globals {
-            if scoping.get_root_binding(name.into()).is_some() {
+        if self.options.allow_globals
+            && scoping.get_root_binding(name.into()).is_some() {
                 return true;
             }
-        }
 
         false
     }
 
-    /// Generate diagnostics from undefined identifiers
-    pub fn generate_diagnostics(&self, undefined: Vec<UndefinedIdent>) -> Vec<Diagnostic> {
+    /// Generate diagnostics from undefined identifiers.
+    ///
+    /// With the program available, import fixes extend existing imports and are
+    /// inserted after the last import statement.
+    pub fn generate_diagnostics(
+        &self,
+        undefined: Vec<UndefinedIdent>,
+        program: Option<&Program>,
+    ) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
-        let mut missing_auto_imports: Vec<String> = Vec::new();
+        // module -> (first occurrence, missing names)
+        let mut missing_auto_imports: BTreeMap<&'static str, (Span, Vec<String>)> =
+            BTreeMap::new();
 
         for ident in undefined {
-            if ident.is_custom_directive {
+            let auto_import = if ident.is_component && self.options.auto_import {
+                auto_import_source(&ident.name)
+            } else {
+                None
+            };
+
+            if ident.is_type_only {
+                if !self.options.typescript_enabled {
+                    diagnostics.push(
+                        Diagnostic::error(
+                            Self::NAME,
+                            ident.span,
+                            format!("'{}' is imported as a type only.", ident.name),
+                        )
+                        .with_help("Use a value import, without `type`, to render it."),
+                    );
+                }
+            } else if ident.is_custom_directive {
                 diagnostics.push(Diagnostic::error(
                     Self::NAME,
                     ident.span,
                     format!("Custom directive '{}' is not defined.", ident.name),
                 ));
-            } else if ident.is_component
-                && self.options.auto_import
-                && AUTO_COMPONENTS.contains(&ident.name.as_str())
-            {
+            } else if let Some(source) = auto_import {
                 // Track for auto-import suggestion
-                if !missing_auto_imports.contains(&ident.name) {
-                    missing_auto_imports.push(ident.name);
+                let (_, names) = missing_auto_imports
+                    .entry(source)
+                    .or_insert_with(|| (ident.span, Vec::new()));
+                if !names.contains(&ident.name) {
+                    names.push(ident.name);
                 }
             } else if !self.options.typescript_enabled {
                 diagnostics.push(Diagnostic::error(
@@ -190,27 +266,30 @@ impl JsxNoUndef {
             }
         }
 
-        // Generate auto-import diagnostic if there are missing Solid components
-        if !missing_auto_imports.is_empty() {
-            missing_auto_imports.sort();
-            let imports_str = format_list(&missing_auto_imports);
+        // One auto-import diagnostic per module, at the first missing component
+        for (source, (span, mut names)) in missing_auto_imports {
+            names.sort();
+            let imports_str = format_list(&names);
             let import_statement =
-                format!("import {{ {} }} from \"{}\";", missing_auto_imports.join(", "), SOURCE_MODULE);
-
-            let mut diagnostic = Diagnostic::error(
-                Self::NAME,
-                Span::new(0, 0),
-                format!("{} should be imported from '{}'.", imports_str, SOURCE_MODULE),
-            )
-            .with_help(format!("Add: {}", import_statement));
-
-            // Add fix to insert import at top of file
-            diagnostic = diagnostic.with_fix(
-                Fix::new(Span::new(0, 0), format!("{}\n", import_statement))
-                    .with_message(format!("Import {} from {}", imports_str, SOURCE_MODULE)),
-            );
+                format!("import {{ {} }} from \"{}\";", names.join(", "), source);
+
+            let fix = match program {
+                Some(program) => {
+                    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+                    add_import_fix(program, source, &names)
+                }
+                None => Fix::new(Span::new(0, 0), format!("{}\n", import_statement)),
+            };
 
-            diagnostics.push(diagnostic);
+            diagnostics.push(
+                Diagnostic::error(
+                    Self::NAME,
+                    span,
+                    format!("{} should be imported from '{}'.", imports_str, source),
+                )
+                .with_help(format!("Add: {}", import_statement))
+                .with_fix(fix.with_message(format!("Import {} from {}", imports_str, source))),
+            );
         }
 
         diagnostics
@@ -224,7 +303,7 @@ impl JsxNoUndef {
         scope_id: ScopeId,
     ) -> Vec<Diagnostic> {
         let undefined = self.check(opening, scoping, scope_id);
-        self.generate_diagnostics(undefined)
+        self.generate_diagnostics(undefined, None)
     }
 
     /// Check if an existing solid-js import exists and return its span for appending
@@ -291,6 +370,13 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_auto_import_source() {
+        assert_eq!(auto_import_source("Show"), Some("solid-js"));
+        assert_eq!(auto_import_source("Portal"), Some("solid-js/web"));
+        assert_eq!(auto_import_source("Button"), None);
+    }
+
     #[test]
     fn test_default_options() {
         let options = JsxNoUndefOptions::default();
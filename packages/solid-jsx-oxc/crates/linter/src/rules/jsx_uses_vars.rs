@@ -22,6 +22,9 @@ pub struct JsxUsesVars;
 impl RuleMeta for JsxUsesVars {
     const NAME: &'static str = "jsx-uses-vars";
     const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Marks variables used in JSX elements as \"used\" to prevent false positives from \
+        no-unused-vars rules.";
 }
 
 impl JsxUsesVars {
@@ -101,10 +104,8 @@ mod tests {
         for stmt in &program.body {
             if let Statement::VariableDeclaration(decl) = stmt {
                 for declarator in &decl.declarations {
-                    if let Some(init) = &declarator.init {
-                        if let Expression::JSXElement(elem) = init {
-                            return Some(elem);
-                        }
+                    if let Some(Expression::JSXElement(elem)) = &declarator.init {
+                        return Some(elem);
                     }
                 }
             }
@@ -2,47 +2,91 @@
 //!
 //! Rules ported from eslint-plugin-solid
 
+pub mod a11y;
+pub mod component_name_casing;
 pub mod components_return_once;
 pub mod event_handlers;
+pub mod event_name_casing;
 pub mod imports;
+pub mod jsx_boolean_value;
 pub mod jsx_no_duplicate_props;
 pub mod jsx_no_script_url;
 pub mod jsx_no_undef;
 pub mod jsx_uses_vars;
 pub mod no_array_handlers;
+pub mod no_async_effect;
+pub mod no_create_signal_in_jsx;
 pub mod no_destructure;
+pub mod no_direct_store_mutation;
+pub mod no_dupe_style_properties;
+pub mod no_forward_ref;
 pub mod no_innerhtml;
+pub mod no_memo_side_effects;
+pub mod no_props_mutation;
 pub mod no_proxy_apis;
 pub mod no_react_deps;
 pub mod no_react_specific_props;
+pub mod no_reactive_calls_at_module_scope;
+pub mod no_render_in_component;
+pub mod no_this_in_components;
 pub mod no_unknown_namespaces;
+pub mod no_unstable_jsx_in_signal;
+pub mod no_unused_signals;
+pub mod no_unused_vars;
 pub mod prefer_classlist;
 pub mod prefer_for;
+pub mod prefer_index;
+pub mod prefer_merge_props;
+pub mod prefer_optional_show;
 pub mod prefer_show;
 pub mod reactivity;
+pub mod require_cleanup;
 pub mod self_closing_comp;
 pub mod style_prop;
 pub mod validate_jsx_nesting;
 
 // Re-export rule structs
+pub use a11y::{
+    AltText, AnchorIsValid, AriaProps, AriaRole, ClickEventsHaveKeyEvents, NoAutofocus,
+};
+pub use component_name_casing::ComponentNameCasing;
 pub use components_return_once::ComponentsReturnOnce;
 pub use event_handlers::EventHandlers;
+pub use event_name_casing::{EventCasing, EventNameCasing};
 pub use imports::Imports;
+pub use jsx_boolean_value::JsxBooleanValue;
 pub use jsx_no_duplicate_props::JsxNoDuplicateProps;
 pub use jsx_no_script_url::JsxNoScriptUrl;
 pub use jsx_no_undef::JsxNoUndef;
 pub use jsx_uses_vars::JsxUsesVars;
 pub use no_array_handlers::NoArrayHandlers;
+pub use no_async_effect::NoAsyncEffect;
+pub use no_create_signal_in_jsx::NoCreateSignalInJsx;
 pub use no_destructure::NoDestructure;
+pub use no_direct_store_mutation::NoDirectStoreMutation;
+pub use no_dupe_style_properties::NoDupeStyleProperties;
+pub use no_forward_ref::NoForwardRef;
 pub use no_innerhtml::NoInnerhtml;
+pub use no_memo_side_effects::NoMemoSideEffects;
+pub use no_props_mutation::NoPropsMutation;
 pub use no_proxy_apis::NoProxyApis;
 pub use no_react_deps::NoReactDeps;
 pub use no_react_specific_props::NoReactSpecificProps;
+pub use no_reactive_calls_at_module_scope::NoReactiveCallsAtModuleScope;
+pub use no_render_in_component::NoRenderInComponent;
+pub use no_this_in_components::NoThisInComponents;
 pub use no_unknown_namespaces::NoUnknownNamespaces;
+pub use no_unstable_jsx_in_signal::NoUnstableJsxInSignal;
+pub use no_unused_signals::NoUnusedSignals;
+pub use no_unused_vars::NoUnusedVars;
 pub use prefer_classlist::PreferClasslist;
 pub use prefer_for::PreferFor;
+pub use prefer_index::PreferIndex;
+pub use prefer_merge_props::PreferMergeProps;
+pub use prefer_optional_show::PreferOptionalShow;
 pub use prefer_show::PreferShow;
 pub use reactivity::Reactivity;
+pub use require_cleanup::RequireCleanup;
 pub use self_closing_comp::SelfClosingComp;
-pub use style_prop::StyleProp;
+pub use style_prop::{StyleProp, StylePropOptions};
 pub use validate_jsx_nesting::ValidateJsxNesting;
@@ -1,14 +1,20 @@
 //! solid/no-array-handlers
 //!
 //! Disallow usage of type-unsafe event handlers (passing arrays).
+//!
+//! `onClick={[handler, data]}` calls `handler(data, event)`. The form is easy to
+//! misread and isn't understood by `on:` handlers, so some teams forbid it. The
+//! rule is off by default.
 
 use oxc_ast::ast::{
-    Expression, JSXAttributeItem, JSXAttributeName, JSXAttributeValue, JSXElementName,
-    JSXOpeningElement,
+    ArrayExpression, ArrayExpressionElement, Expression, JSXAttributeItem, JSXAttributeName,
+    JSXAttributeValue, JSXElementName, JSXOpeningElement,
 };
+use oxc_span::GetSpan;
 
-use crate::diagnostic::Diagnostic;
-use crate::utils::is_dom_element;
+use crate::context::{jsx_container_expression, strip_ts_wrappers};
+use crate::diagnostic::{Diagnostic, Fix};
+use crate::utils::{is_dom_element, span_text};
 use crate::{RuleCategory, RuleMeta};
 
 /// no-array-handlers rule
@@ -18,6 +24,10 @@ pub struct NoArrayHandlers;
 impl RuleMeta for NoArrayHandlers {
     const NAME: &'static str = "no-array-handlers";
     const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Disallow usage of type-unsafe event handlers (passing arrays).";
+    const FIXABLE: bool = true;
+    const DEFAULT_ENABLED: bool = false;
 }
 
 impl NoArrayHandlers {
@@ -26,7 +36,7 @@ impl NoArrayHandlers {
     }
 
     /// Check a JSX opening element for array event handlers
-    pub fn check<'a>(&self, opening: &JSXOpeningElement<'a>) -> Vec<Diagnostic> {
+    pub fn check<'a>(&self, opening: &JSXOpeningElement<'a>, source_text: &str) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
 
         // Only check DOM elements (lowercase tag names)
@@ -64,29 +74,107 @@ impl NoArrayHandlers {
             }
 
             // Check if value is an array expression
-            if let Some(JSXAttributeValue::ExpressionContainer(container)) = &jsx_attr.value {
-                if let Some(expr) = container.expression.as_expression() {
-                    if matches!(expr, Expression::ArrayExpression(_)) {
-                        diagnostics.push(Diagnostic::warning(
-                            Self::NAME,
-                            jsx_attr.span,
-                            "Passing an array as an event handler is potentially type-unsafe.",
-                        ));
-                    }
-                }
+            let Some(JSXAttributeValue::ExpressionContainer(container)) = &jsx_attr.value else {
+                continue;
+            };
+            let Some(Expression::ArrayExpression(array)) = jsx_container_expression(container) else {
+                continue;
+            };
+
+            let mut diagnostic = Diagnostic::warning(
+                Self::NAME,
+                jsx_attr.span,
+                "Passing an array as an event handler is potentially type-unsafe.",
+            )
+            .with_help("Use a closure that passes the data to the handler instead.");
+            if let Some(closure) = closure_for(array, source_text) {
+                diagnostic = diagnostic.with_fix(
+                    Fix::new(array.span, closure).with_message("Replace with a closure"),
+                );
             }
+            diagnostics.push(diagnostic);
         }
 
         diagnostics
     }
 }
 
+/// `[handler, data]` -> `(e) => handler(data, e)`
+fn closure_for(array: &ArrayExpression, source_text: &str) -> Option<String> {
+    let [handler, data] = array.elements.as_slice() else {
+        return None;
+    };
+    let (handler, data) = (element_expression(handler)?, element_expression(data)?);
+    let handler_text = span_text(source_text, handler.span());
+    let data_text = span_text(source_text, data.span());
+
+    // Pick an event parameter name that doesn't shadow anything used
+    let event = ["e", "event", "ev"].into_iter().find(|name| {
+        ![handler_text, data_text]
+            .iter()
+            .any(|text| contains_word(text, name))
+    })?;
+
+    let callee = match strip_ts_wrappers(handler) {
+        Expression::Identifier(_) | Expression::StaticMemberExpression(_) => handler_text.to_string(),
+        _ => format!("({})", handler_text),
+    };
+    Some(format!("({event}) => {callee}({data_text}, {event})"))
+}
+
+fn element_expression<'b, 'a>(element: &'b ArrayExpressionElement<'a>) -> Option<&'b Expression<'a>> {
+    match element {
+        ArrayExpressionElement::SpreadElement(_) | ArrayExpressionElement::Elision(_) => None,
+        element => element.as_expression(),
+    }
+}
+
+fn contains_word(text: &str, word: &str) -> bool {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .any(|part| part == word)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Statement;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
 
     #[test]
     fn test_rule_name() {
         assert_eq!(NoArrayHandlers::NAME, "no-array-handlers");
     }
+
+    fn check(source: &str) -> Vec<Diagnostic> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected JSX element");
+        };
+        NoArrayHandlers::new().check(&element.opening_element, source)
+    }
+
+    #[test]
+    fn test_closure_fix() {
+        let diagnostics = check(r#"<button onClick={[select, item.id]} />"#);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].fixes[0].replacement, "(e) => select(item.id, e)");
+
+        let diagnostics = check(r#"<button on:click={[handlers[0], e]} />"#);
+        assert_eq!(
+            diagnostics[0].fixes[0].replacement,
+            "(event) => (handlers[0])(e, event)"
+        );
+
+        let diagnostics = check(r#"<button onClick={[a, b, c]} />"#);
+        assert!(diagnostics[0].fixes.is_empty());
+        assert!(check(r#"<Button onClick={[select, id]} />"#).is_empty());
+    }
 }
@@ -0,0 +1,193 @@
+//! solid/no-async-effect
+//!
+//! Disallow async callbacks in `createEffect`, `createComputed` and
+//! `createRenderEffect`. Solid only tracks reads made synchronously, so every
+//! signal read after the first `await` is silently untracked.
+
+use oxc_ast::ast::{
+    ArrowFunctionExpression, CallExpression, Expression, Function, ReturnStatement, Statement,
+};
+use oxc_ast_visit::Visit;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::scope::ScopeFlags;
+
+use crate::diagnostic::Diagnostic;
+use crate::{RuleCategory, RuleMeta};
+
+/// Primitives whose callback runs in a tracking scope
+const EFFECT_FUNCTIONS: &[&str] = &["createEffect", "createComputed", "createRenderEffect"];
+
+/// no-async-effect rule
+#[derive(Debug, Clone, Default)]
+pub struct NoAsyncEffect;
+
+impl RuleMeta for NoAsyncEffect {
+    const NAME: &'static str = "no-async-effect";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Disallow async callbacks in `createEffect`, `createComputed` and \
+        `createRenderEffect`. Solid only tracks reads made synchronously, so every \
+        signal read after the first `await` is silently untracked.";
+}
+
+impl NoAsyncEffect {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check a `createEffect(...)`-like call for an async callback
+    pub fn check(&self, call: &CallExpression) -> Option<Diagnostic> {
+        let Expression::Identifier(callee) = &call.callee else {
+            return None;
+        };
+        let effect = callee.name.as_str();
+        if !EFFECT_FUNCTIONS.contains(&effect) {
+            return None;
+        }
+        let callback = call
+            .arguments
+            .first()?
+            .as_expression()?
+            .get_inner_expression();
+
+        let message = match callback {
+            Expression::ArrowFunctionExpression(arrow) if arrow.r#async => {
+                format!("The `{}` callback should not be async.", effect)
+            }
+            Expression::FunctionExpression(func) if func.r#async => {
+                format!("The `{}` callback should not be async.", effect)
+            }
+            Expression::ArrowFunctionExpression(arrow)
+                if returns_promise(&arrow.body.statements, arrow.expression) =>
+            {
+                format!("The `{}` callback should not return a promise.", effect)
+            }
+            Expression::FunctionExpression(func)
+                if func
+                    .body
+                    .as_ref()
+                    .is_some_and(|body| returns_promise(&body.statements, false)) =>
+            {
+                format!("The `{}` callback should not return a promise.", effect)
+            }
+            _ => return None,
+        };
+
+        Some(
+            Diagnostic::warning(Self::NAME, callback.span(), message).with_help(
+                "Reads after an `await` are not tracked. Use `createResource` for async data, or read the signals first and start the async work from there.",
+            ),
+        )
+    }
+}
+
+/// Whether a function body returns a promise from one of its `return` statements.
+/// For expression-bodied arrows the single statement is the returned expression.
+fn returns_promise(statements: &[Statement], expression_body: bool) -> bool {
+    if expression_body {
+        return statements.iter().any(|statement| match statement {
+            Statement::ExpressionStatement(stmt) => is_promise(&stmt.expression),
+            _ => false,
+        });
+    }
+    let mut finder = PromiseReturnFinder::default();
+    for statement in statements {
+        finder.visit_statement(statement);
+    }
+    finder.span.is_some()
+}
+
+/// Expressions that evidently produce a promise
+fn is_promise(expr: &Expression) -> bool {
+    match expr.get_inner_expression() {
+        Expression::CallExpression(call) => match &call.callee {
+            Expression::Identifier(ident) => ident.name == "fetch",
+            callee => callee.as_member_expression().is_some_and(|member| {
+                matches!(member.static_property_name(), Some("then" | "catch" | "finally"))
+                    || matches!(member.object(), Expression::Identifier(object) if object.name == "Promise")
+            }),
+        },
+        Expression::NewExpression(new_expr) => {
+            matches!(&new_expr.callee, Expression::Identifier(ident) if ident.name == "Promise")
+        }
+        _ => false,
+    }
+}
+
+/// Finds a `return <promise>` in one function, skipping nested functions
+#[derive(Default)]
+struct PromiseReturnFinder {
+    span: Option<Span>,
+}
+
+impl<'a> Visit<'a> for PromiseReturnFinder {
+    fn visit_return_statement(&mut self, stmt: &ReturnStatement<'a>) {
+        if stmt.argument.as_ref().is_some_and(is_promise) {
+            self.span.get_or_insert(stmt.span);
+        }
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn message(source: &str) -> Option<String> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::CallExpression(call) = &stmt.expression else {
+            panic!("expected call");
+        };
+        NoAsyncEffect::new().check(call).map(|d| d.message)
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(NoAsyncEffect::NAME, "no-async-effect");
+    }
+
+    #[test]
+    fn test_async_callbacks() {
+        assert_eq!(
+            message("createEffect(async () => { await load(id()); })").as_deref(),
+            Some("The `createEffect` callback should not be async.")
+        );
+        assert_eq!(
+            message("createRenderEffect(async function () {})").as_deref(),
+            Some("The `createRenderEffect` callback should not be async.")
+        );
+        assert_eq!(
+            message("createComputed(() => fetch(url()).then(r => r.json()))").as_deref(),
+            Some("The `createComputed` callback should not return a promise.")
+        );
+        assert_eq!(
+            message("createEffect(() => { if (id()) return new Promise(r => r()); })").as_deref(),
+            Some("The `createEffect` callback should not return a promise.")
+        );
+    }
+
+    #[test]
+    fn test_sync_callbacks() {
+        assert_eq!(
+            message("createEffect(() => { load(id()).then(setData); })"),
+            None
+        );
+        assert_eq!(
+            message("createEffect(() => { const run = async () => {}; run(); })"),
+            None
+        );
+        assert_eq!(message("createResource(async () => fetch(url))"), None);
+    }
+}
@@ -0,0 +1,154 @@
+//! solid/no-createSignal-in-jsx
+//!
+//! Disallow creating signals, stores and memos inside JSX expressions. The
+//! expression re-runs whenever what it reads changes, and each run throws the
+//! old state away and starts a new one.
+//!
+//! Functions inside JSX (render callbacks like `<For>` children, event
+//! handlers) are skipped: creating per-row state there is intended.
+
+use oxc_ast::ast::{
+    ArrowFunctionExpression, CallExpression, Expression, Function, JSXElement,
+    JSXExpressionContainer, JSXFragment,
+};
+use oxc_ast_visit::{walk, Visit};
+use oxc_syntax::scope::ScopeFlags;
+
+use crate::diagnostic::Diagnostic;
+use crate::{RuleCategory, RuleMeta};
+
+/// Primitives that own state
+const STATE_PRIMITIVES: &[&str] = &["createSignal", "createStore", "createMemo"];
+
+/// no-createSignal-in-jsx rule
+#[derive(Debug, Clone, Default)]
+pub struct NoCreateSignalInJsx;
+
+impl RuleMeta for NoCreateSignalInJsx {
+    const NAME: &'static str = "no-createSignal-in-jsx";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Disallow creating signals, stores and memos inside JSX expressions. The \
+        expression re-runs whenever what it reads changes, and each run throws the old \
+        state away and starts a new one.";
+}
+
+impl NoCreateSignalInJsx {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check a JSX expression container (child or attribute value)
+    pub fn check_expression_container<'a>(
+        &self,
+        container: &JSXExpressionContainer<'a>,
+    ) -> Vec<Diagnostic> {
+        let Some(expr) = container.expression.as_expression() else {
+            return Vec::new();
+        };
+        let mut finder = StateCalls::default();
+        finder.visit_expression(expr);
+        finder.diagnostics
+    }
+}
+
+/// Finds state primitive calls evaluated as part of the expression itself
+#[derive(Default)]
+struct StateCalls {
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Visit<'a> for StateCalls {
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if let Expression::Identifier(callee) = &call.callee {
+            if STATE_PRIMITIVES.contains(&callee.name.as_str()) {
+                self.diagnostics.push(
+                    Diagnostic::warning(
+                        NoCreateSignalInJsx::NAME,
+                        call.span,
+                        format!(
+                            "`{}` inside JSX creates new state every time the expression re-runs.",
+                            callee.name
+                        ),
+                    )
+                    .with_help("Create it in the component body and read it here."),
+                );
+            }
+        }
+        walk::walk_call_expression(self, call);
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+
+    // Nested JSX has its own expression containers, which are checked separately
+    fn visit_jsx_element(&mut self, _element: &JSXElement<'a>) {}
+
+    fn visit_jsx_fragment(&mut self, _fragment: &JSXFragment<'a>) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Statement;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    /// Messages for every expression container in the source
+    fn messages(source: &str) -> Vec<String> {
+        struct Containers(Vec<String>);
+        impl<'a> Visit<'a> for Containers {
+            fn visit_jsx_expression_container(&mut self, container: &JSXExpressionContainer<'a>) {
+                let rule = NoCreateSignalInJsx::new();
+                self.0.extend(
+                    rule.check_expression_container(container)
+                        .into_iter()
+                        .map(|d| d.message),
+                );
+                walk::walk_jsx_expression_container(self, container);
+            }
+        }
+
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let mut containers = Containers(Vec::new());
+        containers.visit_expression(&stmt.expression);
+        containers.0
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(NoCreateSignalInJsx::NAME, "no-createSignal-in-jsx");
+    }
+
+    #[test]
+    fn test_state_in_jsx() {
+        assert_eq!(
+            messages(r#"<div>{createSignal(0)[0]()}<Child store={createStore({})} /></div>"#),
+            [
+                "`createSignal` inside JSX creates new state every time the expression re-runs.",
+                "`createStore` inside JSX creates new state every time the expression re-runs.",
+            ]
+        );
+        assert_eq!(
+            messages(r#"<Show when={ok()}>{<p>{createMemo(() => a() * 2)()}</p>}</Show>"#).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_state_in_callbacks() {
+        assert!(messages(
+            r#"<For each={rows()}>{(row) => { const [open, setOpen] = createSignal(false); return <Row open={open()} />; }}</For>"#
+        )
+        .is_empty());
+        assert!(messages(r#"<button onClick={() => createSignal(1)} />"#).is_empty());
+    }
+}
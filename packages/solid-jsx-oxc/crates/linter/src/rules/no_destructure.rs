@@ -2,13 +2,23 @@
 //!
 //! Disallow destructuring props. In Solid, props must be used with property accesses
 //! (`props.foo`) to preserve reactivity.
+//!
+//! Both parameter destructuring (`function App({ a })`) and destructuring at the
+//! top of the body (`const { a } = props`) are reported. When every binding is a
+//! plain `key` or `key: local`, the fix rewrites each use to `props.key`,
+//! unless `props` would then resolve to another binding at one of them.
 
 use oxc_ast::ast::{
-    ArrowFunctionExpression, Expression, Function, FunctionBody, Statement,
+    ArrowFunctionExpression, BindingPattern, Expression, FormalParameters, Function,
+    FunctionBody, ObjectPattern, Statement,
 };
-use oxc_span::GetSpan;
+use oxc_ast::AstKind;
+use oxc_semantic::{ScopeId, Semantic, SymbolId};
+use oxc_span::{GetSpan, Ident, Span};
+use oxc_syntax::identifier::is_identifier_name;
 
-use crate::diagnostic::Diagnostic;
+use crate::diagnostic::{Diagnostic, Fix};
+use crate::utils::line_span;
 use crate::{RuleCategory, RuleMeta};
 
 /// no-destructure rule
@@ -18,6 +28,10 @@ pub struct NoDestructure;
 impl RuleMeta for NoDestructure {
     const NAME: &'static str = "no-destructure";
     const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Disallow destructuring props. In Solid, props must be used with property \
+        accesses (`props.foo`) to preserve reactivity.";
+    const FIXABLE: bool = true;
 }
 
 impl NoDestructure {
@@ -90,6 +104,188 @@ impl NoDestructure {
         diagnostics
     }
 
+    /// Check a component's parameter and the top of its body for destructured
+    /// props, with an autofix that rewrites every use to a property access.
+    /// `scope_id` is the scope of the component function.
+    pub fn check_component<'a>(
+        &self,
+        params: &FormalParameters<'a>,
+        body: Option<&FunctionBody<'a>>,
+        scope_id: ScopeId,
+        semantic: &Semantic<'a>,
+        source_text: &str,
+    ) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        if params.items.len() != 1 {
+            return diagnostics;
+        }
+        let param = &params.items[0];
+
+        match &param.pattern {
+            BindingPattern::ObjectPattern(pattern) => {
+                let mut diagnostic = Self::destructure_diagnostic(param.span, pattern);
+                // Don't introduce a `props` that would clash with an existing binding
+                if Self::is_free(scope_id, "props", semantic) {
+                    if let Some(edits) = Self::rewrite_uses(pattern, "props", None, semantic) {
+                        diagnostic = diagnostic.with_fix(
+                            Fix::new(pattern.span, "props")
+                                .with_message("Access props through `props` instead"),
+                        );
+                        diagnostic.fixes.extend(edits);
+                    }
+                }
+                diagnostics.push(diagnostic);
+            }
+            BindingPattern::BindingIdentifier(props) => {
+                let (Some(body), Some(props_symbol)) = (body, props.symbol_id.get()) else {
+                    return diagnostics;
+                };
+                for stmt in &body.statements {
+                    let Statement::VariableDeclaration(decl) = stmt else {
+                        continue;
+                    };
+                    for declarator in &decl.declarations {
+                        let BindingPattern::ObjectPattern(pattern) = &declarator.id else {
+                            continue;
+                        };
+                        if !Self::is_reference_to(declarator.init.as_ref(), props_symbol, semantic) {
+                            continue;
+                        }
+
+                        let mut diagnostic = Self::destructure_diagnostic(declarator.span, pattern);
+                        if decl.declarations.len() == 1 {
+                            if let Some(edits) = Self::rewrite_uses(
+                                pattern,
+                                &props.name,
+                                Some(props_symbol),
+                                semantic,
+                            ) {
+                                diagnostic = diagnostic.with_fix(
+                                    Fix::new(line_span(source_text, decl.span), "")
+                                        .with_message(format!(
+                                            "Access props through `{}` instead",
+                                            props.name
+                                        )),
+                                );
+                                diagnostic.fixes.extend(edits);
+                            }
+                        }
+                        diagnostics.push(diagnostic);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        diagnostics
+    }
+
+    fn destructure_diagnostic(span: Span, pattern: &ObjectPattern) -> Diagnostic {
+        let has_defaults = pattern
+            .properties
+            .iter()
+            .any(|prop| matches!(prop.value, BindingPattern::AssignmentPattern(_)));
+        let help = if pattern.rest.is_some() {
+            "Use `splitProps(props, [...])` to separate props without losing reactivity."
+        } else if has_defaults {
+            "Use `mergeProps({ ...defaults }, props)` to provide default values."
+        } else {
+            "Use `props.propertyName` instead of destructuring."
+        };
+        Diagnostic::warning(
+            Self::NAME,
+            span,
+            "Destructuring component props breaks Solid's reactivity; use property access instead.",
+        )
+        .with_help(help)
+    }
+
+    fn is_reference_to(init: Option<&Expression>, symbol: SymbolId, semantic: &Semantic) -> bool {
+        let Some(Expression::Identifier(ident)) = init else {
+            return false;
+        };
+        ident.reference_id.get().is_some_and(|reference_id| {
+            semantic.scoping().get_reference(reference_id).symbol_id() == Some(symbol)
+        })
+    }
+
+    /// Whether nothing named `name` is bound in `scope_id` or around it, and
+    /// no undeclared global of that name is used inside it
+    fn is_free(scope_id: ScopeId, name: &str, semantic: &Semantic) -> bool {
+        let scoping = semantic.scoping();
+        if scoping.find_binding(scope_id, name.into()).is_some() {
+            return false;
+        }
+        let nodes = semantic.nodes();
+        let globals = scoping.root_unresolved_references().get(&Ident::from(name));
+        !globals.into_iter().flatten().any(|reference_id| {
+            let node_id = scoping.get_reference(*reference_id).node_id();
+            scoping
+                .scope_ancestors(nodes.get_node(node_id).scope_id())
+                .any(|scope| scope == scope_id)
+        })
+    }
+
+    /// Edits replacing every use of the pattern's bindings with `<props>.key`.
+    /// Returns `None` when the pattern can't be rewritten safely (defaults,
+    /// rest elements, computed keys, nested patterns, writes, JSX tag names),
+    /// or when `props_name` doesn't resolve to `props_symbol` at a use.
+    fn rewrite_uses(
+        pattern: &ObjectPattern,
+        props_name: &str,
+        props_symbol: Option<SymbolId>,
+        semantic: &Semantic,
+    ) -> Option<Vec<Fix>> {
+        if pattern.rest.is_some() {
+            return None;
+        }
+
+        let scoping = semantic.scoping();
+        let nodes = semantic.nodes();
+        let mut edits = Vec::new();
+        for prop in &pattern.properties {
+            if prop.computed {
+                return None;
+            }
+            let key = prop.key.static_name()?;
+            let BindingPattern::BindingIdentifier(local) = &prop.value else {
+                return None;
+            };
+            let access = if is_identifier_name(&key) {
+                format!("{}.{}", props_name, key)
+            } else {
+                format!("{}[{:?}]", props_name, key)
+            };
+
+            for reference_id in scoping.get_resolved_reference_ids(local.symbol_id.get()?) {
+                let reference = scoping.get_reference(*reference_id);
+                if reference.is_write() {
+                    return None;
+                }
+                let node_id = reference.node_id();
+                // An inner binding would shadow `props` here
+                let scope_id = nodes.get_node(node_id).scope_id();
+                if scoping.find_binding(scope_id, props_name.into()) != props_symbol {
+                    return None;
+                }
+                match nodes.parent_kind(node_id) {
+                    // The closing tag isn't a reference, so it can't be kept in sync
+                    AstKind::JSXOpeningElement(_) | AstKind::JSXMemberExpression(_) => {
+                        return None;
+                    }
+                    AstKind::ObjectProperty(object_prop) if object_prop.shorthand => {
+                        edits.push(Fix::new(
+                            object_prop.span,
+                            format!("{}: {}", local.name, access),
+                        ));
+                    }
+                    _ => edits.push(Fix::new(nodes.kind(node_id).span(), access.clone())),
+                }
+            }
+        }
+        Some(edits)
+    }
+
     /// Helper to check if a function body contains JSX
     pub fn body_has_jsx(body: &FunctionBody) -> bool {
         for stmt in &body.statements {
@@ -144,9 +340,130 @@ impl NoDestructure {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::apply_fixes;
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    /// Run the rule on the first function declaration and apply its fixes
+    fn fix(source: &str) -> (Vec<Diagnostic>, String) {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).parse();
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        let func = ret
+            .program
+            .body
+            .iter()
+            .find_map(|stmt| match stmt {
+                Statement::FunctionDeclaration(func) => Some(func),
+                _ => None,
+            })
+            .expect("expected a function");
+        let diagnostics = NoDestructure::new().check_component(
+            &func.params,
+            func.body.as_deref(),
+            func.scope_id(),
+            &semantic,
+            source,
+        );
+
+        let output = apply_fixes(source, diagnostics.clone()).output;
+        (diagnostics, output)
+    }
 
     #[test]
     fn test_rule_name() {
         assert_eq!(NoDestructure::NAME, "no-destructure");
     }
+
+    #[test]
+    fn test_fix_parameter_destructuring() {
+        let (diagnostics, output) = fix(
+            "function App({ title, count: n }) { const o = { title }; return <div>{title}{n}</div>; }",
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            output,
+            "function App(props) { const o = { title: props.title }; return <div>{props.title}{props.count}</div>; }"
+        );
+    }
+
+    crate::rule_test!(test_no_destructure, NoDestructure::NAME, {
+        valid: [
+            "function App(props) { return <div>{props.title}</div>; }",
+            "function helper({ a }) { return a; }",
+        ],
+        invalid: [
+            {
+                code: "const App = ({ title }) => <h1>{title}</h1>;",
+                errors: [{
+                    message: "Destructuring component props breaks Solid's reactivity; use property access instead.",
+                    text: "{ title }",
+                }],
+                output: "const App = (props) => <h1>{props.title}</h1>;",
+            },
+            {
+                code: "function App({ a = 1 }) { return <div>{a}</div>; }",
+                errors: [{ text: "{ a = 1 }" }],
+            },
+        ],
+    });
+
+    #[test]
+    fn test_fix_body_destructuring() {
+        let (diagnostics, output) = fix(
+            "function App(p) {\n  const { a } = p;\n  return <div>{a}</div>;\n}",
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(output, "function App(p) {\n  return <div>{p.a}</div>;\n}");
+    }
+
+    #[test]
+    fn test_no_fix_for_defaults_or_rest() {
+        let (diagnostics, output) = fix("function App({ a = 1 }) { return <div>{a}</div>; }");
+        assert!(diagnostics[0].fixes.is_empty());
+        assert!(diagnostics[0].help.as_deref().unwrap().contains("mergeProps"));
+        assert!(output.contains("{ a = 1 }"));
+
+        let (diagnostics, _) = fix("function App({ a, ...rest }) { return <div {...rest}>{a}</div>; }");
+        assert!(diagnostics[0].fixes.is_empty());
+        assert!(diagnostics[0].help.as_deref().unwrap().contains("splitProps"));
+    }
+
+    #[test]
+    fn test_no_fix_for_jsx_tag_or_taken_name() {
+        let (diagnostics, _) = fix("function App({ Icon }) { return <Icon></Icon>; }");
+        assert!(diagnostics[0].fixes.is_empty());
+
+        let (diagnostics, _) =
+            fix("const props = 1; function App({ a }) { return <div>{a}</div>; }");
+        assert!(diagnostics[0].fixes.is_empty());
+
+        // Shadowed or undeclared `props` where a use would be rewritten
+        let (diagnostics, _) = fix(
+            "function App({ a }) { return <For each={a}>{(props) => <p>{a}{props}</p>}</For>; }",
+        );
+        assert!(diagnostics[0].fixes.is_empty());
+        let (diagnostics, _) = fix("function App({ a }) { return <div>{props}{a}</div>; }");
+        assert!(diagnostics[0].fixes.is_empty());
+        let (diagnostics, _) = fix(
+            "function App(p) {\n  const { a } = p;\n  return <div>{[1].map((p) => a)}</div>;\n}",
+        );
+        assert!(diagnostics[0].fixes.is_empty());
+    }
+
+    #[test]
+    fn test_fix_with_props_elsewhere() {
+        // A `props` of another component doesn't block the fix
+        let (diagnostics, output) = fix(
+            "function App({ a }) { return <div>{a}</div>; }\nfunction B(props) { return props.b; }",
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            output,
+            "function App(props) { return <div>{props.a}</div>; }\nfunction B(props) { return props.b; }"
+        );
+    }
 }
@@ -0,0 +1,403 @@
+//! solid/no-direct-store-mutation
+//!
+//! Disallow mutating a store from `createStore` directly. Stores are read-only
+//! proxies: assignments and array mutators like `push` do not trigger updates
+//! (and warn in development). Changes go through the setter, optionally with
+//! `produce`.
+
+use oxc_ast::ast::{
+    AssignmentExpression, AssignmentOperator, BindingPattern, CallExpression, Expression,
+    IdentifierReference, MemberExpression, Program, SimpleAssignmentTarget, UnaryExpression,
+    UnaryOperator, UpdateExpression,
+};
+use oxc_ast::AstKind;
+use oxc_ast_visit::{walk, Visit};
+use oxc_semantic::{Semantic, SymbolId};
+use oxc_span::{GetSpan, Span};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::diagnostic::{Diagnostic, Fix};
+use crate::{RuleCategory, RuleMeta};
+
+/// Array methods that mutate in place
+const ARRAY_MUTATORS: &[&str] = &[
+    "push",
+    "pop",
+    "shift",
+    "unshift",
+    "splice",
+    "sort",
+    "reverse",
+    "fill",
+    "copyWithin",
+];
+
+/// no-direct-store-mutation rule
+#[derive(Debug, Clone, Default)]
+pub struct NoDirectStoreMutation;
+
+impl RuleMeta for NoDirectStoreMutation {
+    const NAME: &'static str = "no-direct-store-mutation";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Disallow mutating a store from `createStore` directly. Stores are read-only \
+        proxies: assignments and array mutators like `push` do not trigger updates (and \
+        warn in development). Changes go through the setter, optionally with `produce`.";
+    const FIXABLE: bool = true;
+}
+
+impl NoDirectStoreMutation {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check the whole program for writes to stores declared with
+    /// `const [store, setStore] = createStore(...)`
+    pub fn check<'a>(
+        &self,
+        program: &Program<'a>,
+        semantic: &Semantic<'a>,
+        source_text: &str,
+    ) -> Vec<Diagnostic> {
+        let mut stores = FxHashMap::default();
+        let mut setters = FxHashSet::default();
+        for node in semantic.nodes().iter() {
+            let AstKind::VariableDeclarator(declarator) = node.kind() else {
+                continue;
+            };
+            let Some(Expression::CallExpression(call)) = declarator
+                .init
+                .as_ref()
+                .map(|init| init.get_inner_expression())
+            else {
+                continue;
+            };
+            let BindingPattern::ArrayPattern(array) = &declarator.id else {
+                continue;
+            };
+            if !matches!(&call.callee, Expression::Identifier(callee) if callee.name == "createStore")
+            {
+                continue;
+            }
+            let mut bindings = array
+                .elements
+                .iter()
+                .map(|element| element.as_ref().and_then(|e| e.get_binding_identifier()));
+            let (Some(Some(store)), setter) = (bindings.next(), bindings.next().flatten()) else {
+                continue;
+            };
+            let Some(store_symbol) = store.symbol_id.get() else {
+                continue;
+            };
+            stores.insert(store_symbol, setter.map(|s| s.name.to_string()));
+            if let Some(setter_symbol) = setter.and_then(|s| s.symbol_id.get()) {
+                setters.insert(setter_symbol);
+            }
+        }
+        if stores.is_empty() {
+            return Vec::new();
+        }
+
+        let mut finder = StoreMutationFinder {
+            semantic,
+            source_text,
+            stores,
+            setters,
+            setter_depth: 0,
+            diagnostics: Vec::new(),
+        };
+        finder.visit_program(program);
+        finder.diagnostics
+    }
+}
+
+struct StoreMutationFinder<'s, 'a> {
+    semantic: &'s Semantic<'a>,
+    source_text: &'s str,
+    /// Store symbol -> name of its setter
+    stores: FxHashMap<SymbolId, Option<String>>,
+    setters: FxHashSet<SymbolId>,
+    /// Inside the arguments of the setter or `produce`, where mutation is the point
+    setter_depth: usize,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl StoreMutationFinder<'_, '_> {
+    fn symbol_of(&self, ident: &IdentifierReference) -> Option<SymbolId> {
+        let reference = self
+            .semantic
+            .scoping()
+            .get_reference(ident.reference_id.get()?);
+        reference.symbol_id()
+    }
+
+    /// Whether `value` is a function, written inline or bound to a function
+    /// declaration or a variable initialized with one
+    fn is_function_value(&self, value: &Expression) -> bool {
+        let is_function = |expr: &Expression| {
+            matches!(
+                expr.get_inner_expression(),
+                Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_)
+            )
+        };
+        match value.get_inner_expression() {
+            Expression::Identifier(ident) => {
+                let Some(symbol_id) = self.symbol_of(ident) else {
+                    return false;
+                };
+                let declaration = self.semantic.scoping().symbol_declaration(symbol_id);
+                match self.semantic.nodes().kind(declaration) {
+                    AstKind::Function(_) => true,
+                    AstKind::VariableDeclarator(decl) => {
+                        decl.init.as_ref().is_some_and(is_function)
+                    }
+                    _ => false,
+                }
+            }
+            value => is_function(value),
+        }
+    }
+
+    /// For `store.a[i].b`, the store, its setter and the path `["\"a\"", "i", "\"b\""]`
+    fn store_path(
+        &self,
+        member: &MemberExpression,
+    ) -> Option<(String, Option<String>, Vec<String>)> {
+        let mut path = Vec::new();
+        let mut current = member;
+        loop {
+            match current {
+                MemberExpression::StaticMemberExpression(member) => {
+                    path.push(format!("\"{}\"", member.property.name));
+                }
+                MemberExpression::ComputedMemberExpression(member) => {
+                    path.push(
+                        member
+                            .expression
+                            .span()
+                            .source_text(self.source_text)
+                            .to_string(),
+                    );
+                }
+                MemberExpression::PrivateFieldExpression(_) => return None,
+            }
+            match current.object().get_inner_expression() {
+                Expression::Identifier(ident) => {
+                    let setter = self.stores.get(&self.symbol_of(ident)?)?;
+                    path.reverse();
+                    return Some((ident.name.to_string(), setter.clone(), path));
+                }
+                object => current = object.as_member_expression()?,
+            }
+        }
+    }
+
+    fn report(&mut self, span: Span, store: &str, setter: Option<&str>, fix: Option<Fix>) {
+        if self.setter_depth > 0 {
+            return;
+        }
+        let mut diagnostic = Diagnostic::warning(
+            NoDirectStoreMutation::NAME,
+            span,
+            format!(
+                "Do not mutate the store `{}` directly; it will not trigger updates.",
+                store
+            ),
+        )
+        .with_help(match setter {
+            Some(setter) => format!(
+                "Update it with `{}`, using `produce` for in-place changes.",
+                setter
+            ),
+            None => "Update it with the store's setter, using `produce` for in-place changes."
+                .to_string(),
+        });
+        if let Some(fix) = fix {
+            diagnostic = diagnostic.with_fix(fix);
+        }
+        self.diagnostics.push(diagnostic);
+    }
+
+    fn check_target(
+        &mut self,
+        target: &SimpleAssignmentTarget,
+        span: Span,
+        value: Option<&Expression>,
+    ) {
+        let Some(member) = target.as_member_expression() else {
+            return;
+        };
+        let Some((store, setter, path)) = self.store_path(member) else {
+            return;
+        };
+        let fix = match (&setter, value) {
+            (Some(setter), Some(value)) => {
+                let mut value_text = value.span().source_text(self.source_text).to_string();
+                // The setter calls a function value as an updater, so it is
+                // wrapped to be stored as is
+                if self.is_function_value(value) {
+                    value_text = format!("() => {}", value_text);
+                }
+                Some(
+                    Fix::new(
+                        span,
+                        format!("{}({}, {})", setter, path.join(", "), value_text),
+                    )
+                    .with_message(format!("Use `{}`", setter)),
+                )
+            }
+            _ => None,
+        };
+        self.report(span, &store, setter.as_deref(), fix);
+    }
+}
+
+impl<'a> Visit<'a> for StoreMutationFinder<'_, 'a> {
+    fn visit_assignment_expression(&mut self, expr: &AssignmentExpression<'a>) {
+        if let Some(target) = expr.left.as_simple_assignment_target() {
+            let value = (expr.operator == AssignmentOperator::Assign).then_some(&expr.right);
+            self.check_target(target, expr.span, value);
+        }
+        walk::walk_assignment_expression(self, expr);
+    }
+
+    fn visit_update_expression(&mut self, expr: &UpdateExpression<'a>) {
+        self.check_target(&expr.argument, expr.span, None);
+        walk::walk_update_expression(self, expr);
+    }
+
+    fn visit_unary_expression(&mut self, expr: &UnaryExpression<'a>) {
+        if expr.operator == UnaryOperator::Delete {
+            if let Some(member) = expr.argument.get_inner_expression().as_member_expression() {
+                if let Some((store, setter, _)) = self.store_path(member) {
+                    self.report(expr.span, &store, setter.as_deref(), None);
+                }
+            }
+        }
+        walk::walk_unary_expression(self, expr);
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        let allows_mutation = match &call.callee {
+            Expression::Identifier(ident) => {
+                ident.name == "produce"
+                    || self
+                        .symbol_of(ident)
+                        .is_some_and(|s| self.setters.contains(&s))
+            }
+            _ => false,
+        };
+        if allows_mutation {
+            self.setter_depth += 1;
+            walk::walk_call_expression(self, call);
+            self.setter_depth -= 1;
+            return;
+        }
+
+        if let Some(callee) = call.callee.as_member_expression() {
+            if callee
+                .static_property_name()
+                .is_some_and(|name| ARRAY_MUTATORS.contains(&name))
+            {
+                let object = callee.object().get_inner_expression();
+                let store = match object {
+                    Expression::Identifier(ident) => self
+                        .symbol_of(ident)
+                        .and_then(|s| self.stores.get(&s))
+                        .map(|setter| (ident.name.to_string(), setter.clone())),
+                    object => object
+                        .as_member_expression()
+                        .and_then(|member| self.store_path(member))
+                        .map(|(store, setter, _)| (store, setter)),
+                };
+                if let Some((store, setter)) = store {
+                    self.report(call.span, &store, setter.as_deref(), None);
+                }
+            }
+        }
+        walk::walk_call_expression(self, call);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fix::apply_fixes;
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    fn check(source: &str) -> (usize, String) {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).parse();
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        let diagnostics = NoDirectStoreMutation::new().check(&ret.program, &semantic, source);
+        let output = apply_fixes(source, diagnostics.clone()).output;
+        (diagnostics.len(), output)
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(NoDirectStoreMutation::NAME, "no-direct-store-mutation");
+    }
+
+    #[test]
+    fn test_direct_mutations() {
+        let (count, output) = check(
+            r#"const [state, setState] = createStore({ user: {}, todos: [] });
+state.user.name = "Ada";
+state.todos[i].done = true;
+state.count++;
+state.todos.push(todo);
+delete state.user;"#,
+        );
+        assert_eq!(count, 5);
+        assert_eq!(
+            output,
+            r#"const [state, setState] = createStore({ user: {}, todos: [] });
+setState("user", "name", "Ada");
+setState("todos", i, "done", true);
+state.count++;
+state.todos.push(todo);
+delete state.user;"#
+        );
+    }
+
+    #[test]
+    fn test_function_values_are_wrapped() {
+        let (_, output) = check(
+            r#"const [state, setState] = createStore({});
+function onSave() {}
+const onLoad = () => {};
+state.onClick = () => save();
+state.onSave = onSave;
+state.onLoad = onLoad;
+state.label = label;"#,
+        );
+        assert_eq!(
+            output,
+            r#"const [state, setState] = createStore({});
+function onSave() {}
+const onLoad = () => {};
+setState("onClick", () => () => save());
+setState("onSave", () => onSave);
+setState("onLoad", () => onLoad);
+setState("label", label);"#
+        );
+    }
+
+    #[test]
+    fn test_allowed_mutations() {
+        let (count, _) = check(
+            r#"const [state, setState] = createStore({ todos: [] });
+setState(produce((s) => { s.todos.push(todo); }));
+setState("todos", (todos) => [...todos, todo]);
+const copy = [...state.todos];
+copy.push(todo);
+const [local] = createSignal({});
+local().value = 1;"#,
+        );
+        assert_eq!(count, 0);
+    }
+}
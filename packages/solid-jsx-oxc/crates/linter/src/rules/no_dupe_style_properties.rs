@@ -0,0 +1,207 @@
+//! solid/no-dupe-style-properties
+//!
+//! Report CSS properties set twice on one element, either within a
+//! `style={{ ... }}` object or between it and `style:prop` attributes.
+//! Spellings are compared in kebab-case, so `backgroundColor` and
+//! `"background-color"` collide. The transform keeps the last value, so the
+//! fix removes the earlier object property.
+
+use oxc_ast::ast::{
+    Expression, JSXAttributeItem, JSXAttributeName, JSXAttributeValue, JSXOpeningElement,
+    ObjectPropertyKind,
+};
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
+
+use crate::context::jsx_container_expression;
+use crate::diagnostic::{Diagnostic, Fix};
+use crate::rules::style_prop::to_kebab_case;
+use crate::{RuleCategory, RuleMeta};
+
+/// no-dupe-style-properties rule
+#[derive(Debug, Clone, Default)]
+pub struct NoDupeStyleProperties;
+
+impl RuleMeta for NoDupeStyleProperties {
+    const NAME: &'static str = "no-dupe-style-properties";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Report CSS properties set twice on one element, either within a `style={{ ... \
+        }}` object or between it and `style:prop` attributes. Spellings are compared in \
+        kebab-case, so `backgroundColor` and `\"background-color\"` collide. The \
+        transform keeps the last value, so the fix removes the earlier object property.";
+    const FIXABLE: bool = true;
+}
+
+/// One place a property is set
+struct Occurrence {
+    span: Span,
+    /// What to delete to drop this occurrence, if it can be dropped on its own
+    removal: Option<Span>,
+}
+
+impl NoDupeStyleProperties {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check a JSX opening element's `style` object and `style:` attributes
+    pub fn check<'a>(&self, opening: &JSXOpeningElement<'a>) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let mut seen: FxHashMap<String, Occurrence> = FxHashMap::default();
+
+        for attr in &opening.attributes {
+            let JSXAttributeItem::Attribute(jsx_attr) = attr else {
+                continue;
+            };
+            match &jsx_attr.name {
+                JSXAttributeName::NamespacedName(ns) if ns.namespace.name == "style" => {
+                    let occurrence = Occurrence {
+                        span: jsx_attr.span,
+                        removal: None,
+                    };
+                    self.record(
+                        &mut seen,
+                        ns.name.name.as_str(),
+                        occurrence,
+                        &mut diagnostics,
+                    );
+                }
+                JSXAttributeName::Identifier(ident) if ident.name == "style" => {
+                    let Some(JSXAttributeValue::ExpressionContainer(container)) = &jsx_attr.value
+                    else {
+                        continue;
+                    };
+                    let Some(Expression::ObjectExpression(object)) =
+                        jsx_container_expression(container)
+                    else {
+                        continue;
+                    };
+                    for (i, property) in object.properties.iter().enumerate() {
+                        let ObjectPropertyKind::ObjectProperty(prop) = property else {
+                            continue;
+                        };
+                        if prop.computed {
+                            continue;
+                        }
+                        let Some(name) = prop.key.static_name() else {
+                            continue;
+                        };
+                        // Up to the next property, taking the separating comma along
+                        let removal = object
+                            .properties
+                            .get(i + 1)
+                            .map(|next| Span::new(prop.span.start, next.span().start));
+                        let occurrence = Occurrence {
+                            span: prop.span,
+                            removal,
+                        };
+                        self.record(&mut seen, &name, occurrence, &mut diagnostics);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        diagnostics
+    }
+
+    fn record(
+        &self,
+        seen: &mut FxHashMap<String, Occurrence>,
+        name: &str,
+        occurrence: Occurrence,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        let span = occurrence.span;
+        let Some(previous) = seen.insert(normalize(name), occurrence) else {
+            return;
+        };
+        let mut diagnostic = Diagnostic::warning(
+            Self::NAME,
+            span,
+            format!(
+                "Duplicate style property `{}`; only the last value is used.",
+                name
+            ),
+        )
+        .with_label(previous.span, "previous occurrence");
+        if let Some(removal) = previous.removal {
+            diagnostic = diagnostic.with_fix(
+                Fix::new(removal, "").with_message("Remove the earlier property, which is ignored"),
+            );
+        }
+        diagnostics.push(diagnostic);
+    }
+}
+
+/// `backgroundColor`, `"background-color"` and `WebkitBox`/`"-webkit-box"` compare equal;
+/// custom properties are case-sensitive and compared as written
+fn normalize(name: &str) -> String {
+    if name.starts_with("--") {
+        return name.to_string();
+    }
+    to_kebab_case(name)
+        .trim_start_matches('-')
+        .to_ascii_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fix::apply_fixes;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Statement;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn check(source: &str) -> (Vec<String>, String) {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected JSX element");
+        };
+        let diagnostics = NoDupeStyleProperties::new().check(&element.opening_element);
+        let output = apply_fixes(source, diagnostics.clone()).output;
+        (diagnostics.into_iter().map(|d| d.message).collect(), output)
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(NoDupeStyleProperties::NAME, "no-dupe-style-properties");
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let (messages, output) = check(
+            r#"<div style={{ backgroundColor: "red", color: c(), "background-color": "blue", WebkitBox: 1, "-webkit-box": 2 }} />"#,
+        );
+        assert_eq!(
+            messages,
+            [
+                "Duplicate style property `background-color`; only the last value is used.",
+                "Duplicate style property `-webkit-box`; only the last value is used.",
+            ]
+        );
+        assert_eq!(
+            output,
+            r#"<div style={{ color: c(), "background-color": "blue", "-webkit-box": 2 }} />"#
+        );
+
+        let (messages, _) = check(r#"<div style={{ color: "red" }} style:color="blue" />"#);
+        assert_eq!(messages.len(), 1);
+    }
+
+    #[test]
+    fn test_distinct_properties() {
+        let (messages, _) = check(
+            r#"<div style={{ "--Accent": "red", "--accent": "blue", margin: 0, "margin-top": 1, [key]: 2, [key]: 3 }} />"#,
+        );
+        assert!(messages.is_empty());
+    }
+}
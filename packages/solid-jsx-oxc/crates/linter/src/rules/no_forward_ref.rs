@@ -0,0 +1,229 @@
+//! solid/no-forward-ref
+//!
+//! Disallow React's ref forwarding patterns: `forwardRef`,
+//! `useImperativeHandle` and components declared with a second `ref`
+//! parameter. Solid passes `ref` to components like any other prop, so a
+//! component forwards it with `<input ref={props.ref} />` and exposes an API by
+//! calling `props.ref` with it.
+
+use oxc_ast::ast::{
+    ArrowFunctionExpression, BindingPattern, CallExpression, Expression, FormalParameters,
+    Function, Program, VariableDeclarator,
+};
+use oxc_ast_visit::{walk, Visit};
+use oxc_span::GetSpan;
+use oxc_syntax::scope::ScopeFlags;
+
+use crate::diagnostic::Diagnostic;
+use crate::rules::NoDestructure;
+use crate::utils::is_component_name;
+use crate::{RuleCategory, RuleMeta};
+
+/// no-forward-ref rule
+#[derive(Debug, Clone, Default)]
+pub struct NoForwardRef;
+
+impl RuleMeta for NoForwardRef {
+    const NAME: &'static str = "no-forward-ref";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Disallow React's ref forwarding patterns: `forwardRef`, `useImperativeHandle` \
+        and components declared with a second `ref` parameter. Solid passes `ref` to \
+        components like any other prop, so a component forwards it with `<input \
+        ref={props.ref} />` and exposes an API by calling `props.ref` with it.";
+}
+
+impl NoForwardRef {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn check_program<'a>(&self, program: &Program<'a>) -> Vec<Diagnostic> {
+        let mut finder = ForwardRefFinder::default();
+        finder.visit_program(program);
+        finder.diagnostics
+    }
+}
+
+#[derive(Default)]
+struct ForwardRefFinder {
+    declarator_name: Option<String>,
+    /// Set while visiting the arguments of `forwardRef`, whose callback is
+    /// already covered by the `forwardRef` diagnostic
+    forward_ref_callback: bool,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl ForwardRefFinder {
+    fn check_params(&mut self, name: Option<String>, params: &FormalParameters, returns_jsx: bool) {
+        if std::mem::take(&mut self.forward_ref_callback) {
+            return;
+        }
+        let is_component = returns_jsx && name.is_some_and(|name| is_component_name(&name));
+        let Some(second) = params.items.get(1).filter(|_| is_component) else {
+            return;
+        };
+        let label = match &second.pattern {
+            BindingPattern::BindingIdentifier(ident) => format!("`{}`", ident.name),
+            _ => "second".to_string(),
+        };
+        self.diagnostics.push(
+            Diagnostic::warning(
+                NoForwardRef::NAME,
+                second.span,
+                format!(
+                    "Components receive only `props`; the {} parameter is always `undefined`.",
+                    label
+                ),
+            )
+            .with_help("Read the ref from `props.ref` instead."),
+        );
+    }
+}
+
+/// The called function's name for `name(...)` and `React.name(...)`
+fn callee_name<'b>(call: &'b CallExpression) -> Option<&'b str> {
+    match &call.callee {
+        Expression::Identifier(ident) => Some(ident.name.as_str()),
+        callee => callee
+            .as_member_expression()
+            .filter(|member| {
+                matches!(member.object(), Expression::Identifier(object) if object.name == "React")
+            })
+            .and_then(|member| member.static_property_name()),
+    }
+}
+
+impl<'a> Visit<'a> for ForwardRefFinder {
+    fn visit_variable_declarator(&mut self, declarator: &VariableDeclarator<'a>) {
+        if let BindingPattern::BindingIdentifier(ident) = &declarator.id {
+            self.declarator_name = Some(ident.name.to_string());
+        }
+        walk::walk_variable_declarator(self, declarator);
+        self.declarator_name = None;
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        let name = func
+            .id
+            .as_ref()
+            .map(|id| id.name.to_string())
+            .or_else(|| self.declarator_name.take());
+        let returns_jsx = func
+            .body
+            .as_ref()
+            .is_some_and(|body| NoDestructure::body_has_jsx(body));
+        self.check_params(name, &func.params, returns_jsx);
+        walk::walk_function(self, func, flags);
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        let name = self.declarator_name.take();
+        self.check_params(
+            name,
+            &arrow.params,
+            NoDestructure::body_has_jsx(&arrow.body),
+        );
+        walk::walk_arrow_function_expression(self, arrow);
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        let is_forward_ref = match callee_name(call) {
+            Some("forwardRef") => {
+                self.diagnostics.push(
+                    Diagnostic::warning(
+                        NoForwardRef::NAME,
+                        call.callee.span(),
+                        "`forwardRef` is not needed in Solid; components receive `ref` as `props.ref`.",
+                    )
+                    .with_help("Declare the component as a plain function and pass `props.ref` to the element."),
+                );
+                true
+            }
+            Some("useImperativeHandle") => {
+                self.diagnostics.push(
+                    Diagnostic::warning(
+                        NoForwardRef::NAME,
+                        call.callee.span(),
+                        "`useImperativeHandle` does not exist in Solid.",
+                    )
+                    .with_help(
+                        "Expose an API by calling the ref with it, e.g. `props.ref?.({ focus })`.",
+                    ),
+                );
+                false
+            }
+            _ => false,
+        };
+
+        self.visit_expression(&call.callee);
+        for argument in &call.arguments {
+            self.forward_ref_callback = is_forward_ref;
+            self.visit_argument(argument);
+            self.forward_ref_callback = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn ref_messages(source: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        NoForwardRef::new()
+            .check_program(&program)
+            .into_iter()
+            .map(|d| d.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(NoForwardRef::NAME, "no-forward-ref");
+    }
+
+    #[test]
+    fn test_react_patterns() {
+        let messages = ref_messages(
+            r#"
+            const Input = React.forwardRef((props, ref) => <input ref={ref} />);
+            function Dialog(props, ref) {
+                useImperativeHandle(ref, () => ({ open }));
+                return <dialog />;
+            }
+            const Field = ({ label }, { current }) => <label>{label}</label>;
+            "#,
+        );
+        assert_eq!(
+            messages,
+            [
+                "`forwardRef` is not needed in Solid; components receive `ref` as `props.ref`.",
+                "Components receive only `props`; the `ref` parameter is always `undefined`.",
+                "`useImperativeHandle` does not exist in Solid.",
+                "Components receive only `props`; the second parameter is always `undefined`.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_solid_patterns() {
+        let messages = ref_messages(
+            r#"
+            const Input = (props) => <input ref={props.ref} />;
+            const renderRow = (item, index) => <li>{index}</li>;
+            function Chart(props) {
+                const draw = (ctx, size) => ctx.fill(size);
+                return <canvas ref={(el) => props.ref?.({ draw })} />;
+            }
+            "#,
+        );
+        assert!(messages.is_empty(), "{:?}", messages);
+    }
+}
@@ -1,19 +1,31 @@
 //! solid/no-innerhtml
 //!
 //! Disallow usage of the innerHTML attribute, which can often lead to security vulnerabilities.
+//!
+//! Static values on an element without children can be moved into the element
+//! as JSX children: plain text always, HTML only when it parses as the same
+//! JSX. Other HTML is only offered as a suggestion.
 
+use common::evaluate_string;
+use oxc_allocator::Allocator;
 use oxc_ast::ast::{
-    Expression, JSXAttributeName, JSXAttributeValue, JSXElement, ObjectPropertyKind,
-    PropertyKey,
+    Expression, JSXAttribute, JSXAttributeItem, JSXAttributeName, JSXAttributeValue,
+    JSXElement, JSXElementName, ObjectPropertyKind, PropertyKey, Statement,
 };
-use oxc_span::{GetSpan, Span};
+use oxc_ast_visit::{walk, Visit};
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+
+use serde::{Deserialize, Serialize};
 
-use crate::diagnostic::{Diagnostic, Fix};
+use crate::context::jsx_container_expression;
+use crate::diagnostic::{Diagnostic, Fix, Suggestion};
 use crate::utils::has_children;
-use crate::{RuleCategory, RuleMeta};
+use crate::{ConfigurableRule, RuleCategory, RuleMeta};
 
 /// no-innerhtml rule
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
 pub struct NoInnerhtml {
     /// If the innerHTML value is guaranteed to be a static HTML string, allow it
     pub allow_static: bool,
@@ -28,6 +40,18 @@ impl Default for NoInnerhtml {
 impl RuleMeta for NoInnerhtml {
     const NAME: &'static str = "no-innerhtml";
     const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Disallow usage of the innerHTML attribute, which can often lead to security \
+        vulnerabilities.";
+    const FIXABLE: bool = true;
+}
+
+impl ConfigurableRule for NoInnerhtml {
+    type Options = Self;
+
+    fn from_options(options: Self) -> Self {
+        options
+    }
 }
 
 impl NoInnerhtml {
@@ -87,17 +111,20 @@ impl NoInnerhtml {
                                 JSXAttributeName::Identifier(ident) => ident.span,
                                 _ => jsx_attr.span,
                             };
-                            diagnostics.push(
-                                Diagnostic::warning(
-                                    Self::NAME,
-                                    jsx_attr.span,
-                                    "The string passed to innerHTML does not appear to be valid HTML.",
-                                )
-                                .with_fix(
+                            let diagnostic = Diagnostic::warning(
+                                Self::NAME,
+                                jsx_attr.span,
+                                "The string passed to innerHTML does not appear to be valid HTML.",
+                            );
+                            let children = text_as_children(&static_value);
+                            let diagnostic = match children_fix(element, jsx_attr, &children) {
+                                Some(fixes) => with_fixes(diagnostic, fixes),
+                                None => diagnostic.with_fix(
                                     Fix::new(attr_name_span, "innerText")
                                         .with_message("Use innerText for text content"),
                                 ),
-                            );
+                            };
+                            diagnostics.push(diagnostic);
                         }
                     } else {
                         // Dynamic value - warn about security
@@ -111,13 +138,31 @@ impl NoInnerhtml {
                     }
                 } else {
                     // allowStatic is false, always warn
-                    diagnostics.push(
-                        Diagnostic::warning(
-                            Self::NAME,
-                            jsx_attr.span,
-                            "The innerHTML attribute is dangerous; passing unsanitized input can lead to security vulnerabilities.",
-                        ),
+                    let mut diagnostic = Diagnostic::warning(
+                        Self::NAME,
+                        jsx_attr.span,
+                        "The innerHTML attribute is dangerous; passing unsanitized input can lead to security vulnerabilities.",
                     );
+                    if let Some(value) = get_static_string_value(&jsx_attr.value) {
+                        if !looks_like_html(&value) {
+                            let children = text_as_children(&value);
+                            if let Some(fixes) = children_fix(element, jsx_attr, &children) {
+                                diagnostic = with_fixes(diagnostic, fixes);
+                            }
+                        } else if let Some(fixes) = children_fix(element, jsx_attr, &value) {
+                            // HTML that JSX reads differently needs a human to check it
+                            if html_is_valid_jsx(&value) {
+                                diagnostic = with_fixes(diagnostic, fixes);
+                            } else {
+                                let suggestion = Suggestion {
+                                    message: "Move the HTML into the element as JSX".to_string(),
+                                    fixes,
+                                };
+                                diagnostic = diagnostic.with_suggestion(suggestion);
+                            }
+                        }
+                    }
+                    diagnostics.push(diagnostic);
                 }
             }
         }
@@ -132,7 +177,7 @@ impl NoInnerhtml {
     ) -> Diagnostic {
         // Check if it's the pattern: dangerouslySetInnerHTML={{ __html: value }}
         if let Some(JSXAttributeValue::ExpressionContainer(container)) = value {
-            if let Some(Expression::ObjectExpression(obj)) = container.expression.as_expression() {
+            if let Some(Expression::ObjectExpression(obj)) = jsx_container_expression(container) {
                 if obj.properties.len() == 1 {
                     if let Some(ObjectPropertyKind::ObjectProperty(prop)) =
                         obj.properties.first()
@@ -179,23 +224,116 @@ fn get_static_string_value(value: &Option<JSXAttributeValue<'_>>) -> Option<Stri
     match value {
         Some(JSXAttributeValue::StringLiteral(lit)) => Some(lit.value.to_string()),
         Some(JSXAttributeValue::ExpressionContainer(container)) => {
-            if let Some(expr) = container.expression.as_expression() {
-                match expr {
-                    Expression::StringLiteral(lit) => Some(lit.value.to_string()),
-                    Expression::TemplateLiteral(tpl) if tpl.expressions.is_empty() => {
-                        // Get text from template literal quasis
-                        tpl.quasis.first().map(|q| q.value.raw.to_string())
-                    }
-                    _ => None,
-                }
-            } else {
-                None
-            }
+            evaluate_string(container.expression.as_expression()?)
         }
         _ => None,
     }
 }
 
+/// Edits that drop the attribute and render `children` inside the element instead.
+/// Only possible for a plain tag name without existing children.
+fn children_fix(element: &JSXElement, attr: &JSXAttribute, children: &str) -> Option<Vec<Fix>> {
+    if has_children(&element.children) {
+        return None;
+    }
+    let opening = &element.opening_element;
+    let JSXElementName::Identifier(tag) = &opening.name else {
+        return None;
+    };
+
+    // Remove the attribute together with the whitespace before it
+    let index = opening.attributes.iter().position(|item| {
+        matches!(item, JSXAttributeItem::Attribute(a) if a.span == attr.span)
+    })?;
+    let start = match index {
+        0 => opening.name.span().end,
+        _ => opening.attributes[index - 1].span().end,
+    };
+    let message = "Move the static content into the element";
+
+    let Some(closing) = &element.closing_element else {
+        // Self-closing: rewrite everything after the remaining attributes
+        let last = opening.attributes.len() - 1;
+        let close = format!(">{}</{}>", children, tag.name);
+        if index == last {
+            return Some(vec![
+                Fix::new(Span::new(start, opening.span.end), close).with_message(message)
+            ]);
+        }
+        let last_end = opening.attributes[last].span().end;
+        return Some(vec![
+            Fix::new(Span::new(start, attr.span.end), "").with_message(message),
+            Fix::new(Span::new(last_end, opening.span.end), close),
+        ]);
+    };
+    let fixes = vec![
+        Fix::new(Span::new(start, attr.span.end), "").with_message(message),
+        Fix::new(Span::new(closing.span.start, closing.span.start), children),
+    ];
+    Some(fixes)
+}
+
+fn with_fixes(mut diagnostic: Diagnostic, fixes: Vec<Fix>) -> Diagnostic {
+    diagnostic.fixes.extend(fixes);
+    diagnostic
+}
+
+/// Text as JSX children, falling back to a string expression when it contains
+/// characters JSX text can't hold
+fn text_as_children(text: &str) -> String {
+    if text.contains(['{', '}', '<', '>']) || text.trim() != text {
+        format!("{{{}}}", serde_json::to_string(text).unwrap_or_default())
+    } else {
+        text.to_string()
+    }
+}
+
+/// Whether an HTML string means the same as JSX children: it parses as the
+/// children of a fragment, has no braces or comments, whose meaning differs,
+/// and no `on*` attributes, which JSX binds as event handlers
+fn html_is_valid_jsx(html: &str) -> bool {
+    if html.contains(['{', '}']) || html.contains("<!--") {
+        return false;
+    }
+    let source = format!("<>{}</>", html);
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &source, SourceType::jsx()).parse();
+    if !ret.errors.is_empty() || ret.panicked {
+        return false;
+    }
+    // The fragment must end where the HTML does, not at a `</>` inside it
+    let [Statement::ExpressionStatement(statement)] = ret.program.body.as_slice() else {
+        return false;
+    };
+    let Expression::JSXFragment(fragment) = &statement.expression else {
+        return false;
+    };
+    if fragment.span.end as usize != source.len() {
+        return false;
+    }
+
+    let mut attributes = HtmlAttributes::default();
+    attributes.visit_program(&ret.program);
+    !attributes.has_handler
+}
+
+/// Finds `on*` attributes in parsed HTML
+#[derive(Default)]
+struct HtmlAttributes {
+    has_handler: bool,
+}
+
+impl<'a> Visit<'a> for HtmlAttributes {
+    fn visit_jsx_attribute(&mut self, attribute: &JSXAttribute<'a>) {
+        if let JSXAttributeName::Identifier(name) = &attribute.name {
+            if name.name.len() > 2 && name.name[..2].eq_ignore_ascii_case("on") {
+                self.has_handler = true;
+            }
+        }
+        walk::walk_jsx_attribute(self, attribute);
+    }
+}
+
 /// Simple check if a string looks like HTML
 fn looks_like_html(s: &str) -> bool {
     let trimmed = s.trim();
@@ -223,4 +361,87 @@ mod tests {
         assert!(!looks_like_html("plain text"));
         assert!(!looks_like_html(""));
     }
+
+    #[test]
+    fn test_html_is_valid_jsx() {
+        assert!(html_is_valid_jsx("<b>bold</b><br />"));
+        assert!(html_is_valid_jsx(r#"<p class="a" hidden>x</p>"#));
+        assert!(!html_is_valid_jsx("<p>line<br>break</p>"));
+        assert!(!html_is_valid_jsx("<p>{x}</p>"));
+        assert!(!html_is_valid_jsx("<!-- note --><p></p>"));
+        assert!(!html_is_valid_jsx("<p class=a>x</p>"));
+        assert!(!html_is_valid_jsx("<p>a > b</p>"));
+        assert!(!html_is_valid_jsx("<p>one</p><p>two"));
+        assert!(!html_is_valid_jsx("<b>a</b></><b>"));
+        assert!(!html_is_valid_jsx("<button onclick='go()'>"));
+        assert!(!html_is_valid_jsx("<button onClick='go()'></button>"));
+    }
+
+    const DANGEROUS: &str = "The innerHTML attribute is dangerous; passing unsanitized input can lead to security vulnerabilities.";
+
+    crate::rule_test!(test_static_html_fix, NoInnerhtml::NAME, {
+        valid: [r#"<div innerHTML="<b>bold</b>" />"#],
+        invalid: [
+            {
+                code: r#"<div innerHTML='<p class="a">x</p>' />"#,
+                errors: [DANGEROUS],
+                options: serde_json::json!({ "allowStatic": false }),
+                output: r#"<div><p class="a">x</p></div>"#,
+            },
+            // JSX reads these differently, so they are only suggested
+            {
+                code: r#"<div innerHTML="<p class=a>x</p>" />"#,
+                errors: [DANGEROUS],
+                options: serde_json::json!({ "allowStatic": false }),
+            },
+            {
+                code: r#"<div innerHTML="<p>a > b</p>" />"#,
+                errors: [DANGEROUS],
+                options: serde_json::json!({ "allowStatic": false }),
+            },
+            {
+                code: r#"<div innerHTML="<p>one</p><p>two" />"#,
+                errors: [DANGEROUS],
+                options: serde_json::json!({ "allowStatic": false }),
+            },
+            {
+                code: r#"<div innerHTML="<button onclick='go()'>" />"#,
+                errors: [DANGEROUS],
+                options: serde_json::json!({ "allowStatic": false }),
+            },
+        ],
+    });
+
+    #[test]
+    fn test_suggests_other_html() {
+        let allocator = Allocator::default();
+        let source = r#"<div innerHTML="<p>a > b</p>" />"#;
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).parse();
+        let Some(Statement::ExpressionStatement(statement)) = ret.program.body.first() else {
+            unreachable!()
+        };
+        let Expression::JSXElement(element) = &statement.expression else {
+            unreachable!()
+        };
+        let diagnostics = NoInnerhtml::new().with_allow_static(false).check(element);
+        assert!(diagnostics[0].fixes.is_empty());
+        let suggestion = &diagnostics[0].suggestions[0];
+        assert_eq!(
+            crate::apply_fixes(
+                source,
+                vec![Diagnostic {
+                    fixes: suggestion.fixes.clone(),
+                    ..diagnostics[0].clone()
+                }]
+            )
+            .output,
+            "<div><p>a > b</p></div>"
+        );
+    }
+
+    #[test]
+    fn test_text_as_children() {
+        assert_eq!(text_as_children("hello"), "hello");
+        assert_eq!(text_as_children("a > b"), "{\"a > b\"}");
+    }
 }
@@ -0,0 +1,271 @@
+//! solid/no-memo-side-effects
+//!
+//! Disallow side effects in `createMemo` callbacks: assignments to outside
+//! variables, DOM mutations and signal setter calls. Memos may rerun, or not
+//! run at all when nothing reads them, so they should only derive a value.
+//!
+//! Variables declared inside the callback are its own and may be mutated
+//! freely. Setters are recognized by Solid's `setX` naming convention.
+
+use oxc_ast::ast::{
+    AssignmentExpression, BindingIdentifier, CallExpression, Expression, SimpleAssignmentTarget,
+    UpdateExpression,
+};
+use oxc_ast_visit::{walk, Visit};
+use oxc_span::Span;
+use rustc_hash::FxHashSet;
+
+use crate::diagnostic::Diagnostic;
+use crate::utils::is_setter_name;
+use crate::{RuleCategory, RuleMeta};
+
+/// Methods that change the document when called on a node
+const DOM_MUTATION_METHODS: &[&str] = &[
+    "after",
+    "append",
+    "appendChild",
+    "before",
+    "insertAdjacentElement",
+    "insertAdjacentHTML",
+    "insertAdjacentText",
+    "insertBefore",
+    "prepend",
+    "removeAttribute",
+    "removeChild",
+    "replaceChild",
+    "replaceChildren",
+    "replaceWith",
+    "setAttribute",
+    "toggleAttribute",
+];
+
+/// no-memo-side-effects rule
+#[derive(Debug, Clone, Default)]
+pub struct NoMemoSideEffects;
+
+impl RuleMeta for NoMemoSideEffects {
+    const NAME: &'static str = "no-memo-side-effects";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Disallow side effects in `createMemo` callbacks: assignments to outside \
+        variables, DOM mutations and signal setter calls. Memos may rerun, or not run at \
+        all when nothing reads them, so they should only derive a value.";
+}
+
+impl NoMemoSideEffects {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check the callback of a `createMemo(...)` call
+    pub fn check(&self, call: &CallExpression) -> Vec<Diagnostic> {
+        let is_memo =
+            matches!(&call.callee, Expression::Identifier(ident) if ident.name == "createMemo");
+        let Some(callback) = call
+            .arguments
+            .first()
+            .and_then(|arg| arg.as_expression())
+            .map(Expression::get_inner_expression)
+            .filter(|_| is_memo)
+        else {
+            return Vec::new();
+        };
+        if !matches!(
+            callback,
+            Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_)
+        ) {
+            return Vec::new();
+        }
+
+        let mut locals = LocalBindings::default();
+        locals.visit_expression(callback);
+        let mut finder = SideEffectFinder {
+            locals: locals.names,
+            effects: Vec::new(),
+        };
+        finder.visit_expression(callback);
+
+        finder
+            .effects
+            .into_iter()
+            .map(|(span, message)| {
+                Diagnostic::warning(Self::NAME, span, message).with_help(
+                    "Memos should only compute a value. Move side effects into `createEffect`.",
+                )
+            })
+            .collect()
+    }
+}
+
+/// Every name bound inside the callback, including its parameters
+#[derive(Default)]
+struct LocalBindings {
+    names: FxHashSet<String>,
+}
+
+impl<'a> Visit<'a> for LocalBindings {
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        self.names.insert(ident.name.to_string());
+    }
+}
+
+struct SideEffectFinder {
+    locals: FxHashSet<String>,
+    effects: Vec<(Span, String)>,
+}
+
+impl SideEffectFinder {
+    fn is_outside(&self, expr: &Expression) -> Option<String> {
+        let name = root_name(expr)?;
+        (!self.locals.contains(&name)).then_some(name)
+    }
+
+    fn check_target(&mut self, target: &SimpleAssignmentTarget, span: Span) {
+        let name = match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                Some(ident.name.to_string()).filter(|name| !self.locals.contains(name))
+            }
+            _ => target
+                .as_member_expression()
+                .and_then(|member| self.is_outside(member.object())),
+        };
+        if let Some(name) = name {
+            self.effects
+                .push((span, format!("`createMemo` should not modify `{}`.", name)));
+        }
+    }
+}
+
+impl<'a> Visit<'a> for SideEffectFinder {
+    fn visit_assignment_expression(&mut self, expr: &AssignmentExpression<'a>) {
+        if let Some(target) = expr.left.as_simple_assignment_target() {
+            self.check_target(target, expr.span);
+        }
+        walk::walk_assignment_expression(self, expr);
+    }
+
+    fn visit_update_expression(&mut self, expr: &UpdateExpression<'a>) {
+        self.check_target(&expr.argument, expr.span);
+        walk::walk_update_expression(self, expr);
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        match &call.callee {
+            // Nested memos are checked on their own
+            Expression::Identifier(ident) if ident.name == "createMemo" => return,
+            Expression::Identifier(ident)
+                if is_setter_name(&ident.name) && !self.locals.contains(ident.name.as_str()) =>
+            {
+                self.effects.push((
+                    call.span,
+                    format!("`createMemo` should not call the setter `{}`.", ident.name),
+                ));
+            }
+            callee => {
+                if let Some(member) = callee.as_member_expression() {
+                    let method = member.static_property_name();
+                    let is_mutation = method.is_some_and(|m| DOM_MUTATION_METHODS.contains(&m))
+                        || (matches!(method, Some("add" | "remove" | "toggle" | "replace"))
+                            && member.object().as_member_expression().is_some_and(|obj| {
+                                obj.static_property_name() == Some("classList")
+                            }));
+                    if is_mutation && self.is_outside(member.object()).is_some() {
+                        self.effects.push((
+                            call.span,
+                            format!(
+                                "`createMemo` should not modify the DOM with `{}`.",
+                                method.unwrap_or_default()
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+        walk::walk_call_expression(self, call);
+    }
+}
+
+/// The identifier a member/call chain starts from: `document` for
+/// `document.querySelector("p").textContent`
+fn root_name(expr: &Expression) -> Option<String> {
+    match expr.get_inner_expression() {
+        Expression::Identifier(ident) => Some(ident.name.to_string()),
+        Expression::CallExpression(call) => root_name(&call.callee),
+        expr => root_name(expr.as_member_expression()?.object()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Statement;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn memo_messages(source: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::CallExpression(call) = &stmt.expression else {
+            panic!("expected call");
+        };
+        NoMemoSideEffects::new()
+            .check(call)
+            .into_iter()
+            .map(|d| d.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(NoMemoSideEffects::NAME, "no-memo-side-effects");
+    }
+
+    #[test]
+    fn test_side_effects() {
+        let messages = memo_messages(
+            r#"createMemo(() => {
+                total = a() + b();
+                cache.last = total;
+                renders++;
+                setCount(a());
+                document.querySelector("p").setAttribute("data-x", a());
+                el.classList.toggle("on", a());
+                return total;
+            })"#,
+        );
+        assert_eq!(
+            messages,
+            [
+                "`createMemo` should not modify `total`.",
+                "`createMemo` should not modify `cache`.",
+                "`createMemo` should not modify `renders`.",
+                "`createMemo` should not call the setter `setCount`.",
+                "`createMemo` should not modify the DOM with `setAttribute`.",
+                "`createMemo` should not modify the DOM with `toggle`.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pure_memos() {
+        let messages = memo_messages(
+            r#"createMemo((prev) => {
+                let sum = 0;
+                const seen = { count: 0 };
+                for (const item of items()) { sum += item.price; seen.count++; }
+                const params = new URLSearchParams();
+                params.append("q", query());
+                items().forEach((item) => { sum += item.tax; });
+                return sum + (prev ?? 0);
+            })"#,
+        );
+        assert!(messages.is_empty(), "{:?}", messages);
+        assert!(memo_messages("createEffect(() => { total = a(); })").is_empty());
+    }
+}
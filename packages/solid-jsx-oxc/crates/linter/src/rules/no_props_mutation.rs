@@ -0,0 +1,313 @@
+//! solid/no-props-mutation
+//!
+//! Disallow writing to props inside a component. Solid's props object is
+//! read-only: assignments and `delete`s either throw or are silently lost, and
+//! reassigning a destructured prop never reaches the parent.
+
+use oxc_ast::ast::{
+    AssignmentExpression, BindingPattern, Expression, FormalParameters, FunctionBody,
+    IdentifierReference, SimpleAssignmentTarget, Statement, UnaryExpression, UnaryOperator,
+    UpdateExpression,
+};
+use oxc_ast_visit::{walk, Visit};
+use oxc_semantic::{Semantic, SymbolId};
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashSet;
+
+use crate::diagnostic::Diagnostic;
+use crate::{RuleCategory, RuleMeta};
+
+/// no-props-mutation rule
+#[derive(Debug, Clone, Default)]
+pub struct NoPropsMutation;
+
+impl RuleMeta for NoPropsMutation {
+    const NAME: &'static str = "no-props-mutation";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Disallow writing to props inside a component. Solid's props object is read-only: \
+        assignments and `delete`s either throw or are silently lost, and reassigning a \
+        destructured prop never reaches the parent.";
+}
+
+impl NoPropsMutation {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check a component for writes to its props object or to destructured props
+    pub fn check_component<'a>(
+        &self,
+        params: &FormalParameters<'a>,
+        body: Option<&FunctionBody<'a>>,
+        semantic: &Semantic<'a>,
+        source_text: &str,
+    ) -> Vec<Diagnostic> {
+        let (Some(param), Some(body)) = (params.items.first(), body) else {
+            return Vec::new();
+        };
+
+        let mut finder = MutationFinder {
+            semantic,
+            source_text,
+            props: FxHashSet::default(),
+            aliases: FxHashSet::default(),
+            diagnostics: Vec::new(),
+        };
+        finder.add_binding(&param.pattern);
+
+        // `const { a } = props` and `const local = props` at the top of the body
+        for stmt in &body.statements {
+            let Statement::VariableDeclaration(decl) = stmt else {
+                continue;
+            };
+            for declarator in &decl.declarations {
+                let Some(Expression::Identifier(init)) = declarator
+                    .init
+                    .as_ref()
+                    .map(|init| init.get_inner_expression())
+                else {
+                    continue;
+                };
+                if finder.resolves_to(init, true) {
+                    finder.add_binding(&declarator.id);
+                }
+            }
+        }
+
+        if finder.props.is_empty() && finder.aliases.is_empty() {
+            return Vec::new();
+        }
+        finder.visit_function_body(body);
+        finder.diagnostics
+    }
+}
+
+struct MutationFinder<'s, 'a> {
+    semantic: &'s Semantic<'a>,
+    source_text: &'s str,
+    /// Bindings that hold the props object itself
+    props: FxHashSet<SymbolId>,
+    /// Bindings destructured out of props
+    aliases: FxHashSet<SymbolId>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl MutationFinder<'_, '_> {
+    fn add_binding(&mut self, pattern: &BindingPattern) {
+        if let Some(ident) = pattern.get_binding_identifier() {
+            self.props.extend(ident.symbol_id.get());
+            return;
+        }
+        for ident in pattern.get_binding_identifiers() {
+            self.aliases.extend(ident.symbol_id.get());
+        }
+    }
+
+    /// Whether `ident` refers to the props object (`props`) or to a prop alias
+    fn resolves_to(&self, ident: &IdentifierReference, props: bool) -> bool {
+        let Some(reference_id) = ident.reference_id.get() else {
+            return false;
+        };
+        let Some(symbol) = self
+            .semantic
+            .scoping()
+            .get_reference(reference_id)
+            .symbol_id()
+        else {
+            return false;
+        };
+        if props {
+            self.props.contains(&symbol)
+        } else {
+            self.aliases.contains(&symbol)
+        }
+    }
+
+    /// For a member chain like `props.a.b`, whether its root is the props object
+    fn is_props_member(&self, mut expr: &Expression) -> bool {
+        loop {
+            match expr.get_inner_expression() {
+                Expression::Identifier(ident) => return self.resolves_to(ident, true),
+                inner => match inner.as_member_expression() {
+                    Some(member) => expr = member.object(),
+                    None => return false,
+                },
+            }
+        }
+    }
+
+    fn check_target(&mut self, target: &SimpleAssignmentTarget, span: Span) {
+        if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = target {
+            if self.resolves_to(ident, false) {
+                self.report_alias(ident);
+            } else if self.resolves_to(ident, true) {
+                self.report(
+                    span,
+                    format!("Do not reassign `{}`; props are read-only.", ident.name),
+                );
+            }
+            return;
+        }
+        let Some(member) = target.as_member_expression() else {
+            return;
+        };
+        if self.is_props_member(member.object()) {
+            let target = member.span().source_text(self.source_text);
+            self.report(
+                span,
+                format!("Props are read-only; do not assign to `{}`.", target),
+            );
+        }
+    }
+
+    fn report(&mut self, span: Span, message: String) {
+        self.diagnostics.push(
+            Diagnostic::warning(NoPropsMutation::NAME, span, message).with_help(
+                "Let the parent change the value, e.g. through a setter prop, or copy it into a signal for local state.",
+            ),
+        );
+    }
+
+    fn report_alias(&mut self, ident: &IdentifierReference) {
+        self.diagnostics.push(
+            Diagnostic::warning(
+                NoPropsMutation::NAME,
+                ident.span,
+                format!(
+                    "`{}` comes from props; reassigning it does not update the prop.",
+                    ident.name
+                ),
+            )
+            .with_help(format!(
+                "Use a signal for local state, e.g. `createSignal(props.{})`.",
+                ident.name
+            )),
+        );
+    }
+}
+
+impl<'a> Visit<'a> for MutationFinder<'_, 'a> {
+    fn visit_assignment_expression(&mut self, expr: &AssignmentExpression<'a>) {
+        if let Some(target) = expr.left.as_simple_assignment_target() {
+            self.check_target(target, expr.span);
+        }
+        walk::walk_assignment_expression(self, expr);
+    }
+
+    fn visit_update_expression(&mut self, expr: &UpdateExpression<'a>) {
+        self.check_target(&expr.argument, expr.span);
+        walk::walk_update_expression(self, expr);
+    }
+
+    fn visit_unary_expression(&mut self, expr: &UnaryExpression<'a>) {
+        if expr.operator == UnaryOperator::Delete {
+            if let Some(member) = expr.argument.get_inner_expression().as_member_expression() {
+                if self.is_props_member(member.object()) {
+                    let target = member.span().source_text(self.source_text);
+                    self.report(
+                        expr.span,
+                        format!("Props are read-only; do not delete `{}`.", target),
+                    );
+                }
+            }
+        }
+        walk::walk_unary_expression(self, expr);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    fn mutations(source: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).parse();
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        let Some(Statement::FunctionDeclaration(func)) = ret.program.body.first() else {
+            panic!("expected function");
+        };
+        NoPropsMutation::new()
+            .check_component(&func.params, func.body.as_deref(), &semantic, source)
+            .into_iter()
+            .map(|d| d.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(NoPropsMutation::NAME, "no-props-mutation");
+    }
+
+    #[test]
+    fn test_props_object_writes() {
+        let messages = mutations(
+            r#"function Counter(props) {
+                props.count = 1;
+                props.user.name += "!";
+                props["items"][0]++;
+                delete props.extra;
+                const onClick = () => { props.count = 2; };
+                return <button onClick={onClick}>{props.count}</button>;
+            }"#,
+        );
+        assert_eq!(
+            messages,
+            [
+                "Props are read-only; do not assign to `props.count`.",
+                "Props are read-only; do not assign to `props.user.name`.",
+                "Props are read-only; do not assign to `props[\"items\"][0]`.",
+                "Props are read-only; do not delete `props.extra`.",
+                "Props are read-only; do not assign to `props.count`.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_alias_writes() {
+        let messages = mutations(
+            r#"function Field({ value, label: text }) {
+                value = value.trim();
+                text = "";
+                return <input value={value} />;
+            }"#,
+        );
+        assert_eq!(
+            messages,
+            [
+                "`value` comes from props; reassigning it does not update the prop.",
+                "`text` comes from props; reassigning it does not update the prop.",
+            ]
+        );
+
+        let messages = mutations(
+            r#"function Field(props) {
+                const { value } = props;
+                const p = props;
+                value++;
+                p.value = 1;
+                return <input value={value} />;
+            }"#,
+        );
+        assert_eq!(messages.len(), 2);
+    }
+
+    #[test]
+    fn test_local_writes() {
+        let messages = mutations(
+            r#"function List(props) {
+                let count = 0;
+                count++;
+                const copy = { ...props };
+                copy.value = 1;
+                const [items, setItems] = createSignal(props.items);
+                return <ul />;
+            }"#,
+        );
+        assert!(messages.is_empty(), "{:?}", messages);
+    }
+}
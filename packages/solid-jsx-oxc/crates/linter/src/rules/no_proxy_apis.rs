@@ -1,11 +1,19 @@
 //! solid/no-proxy-apis
 //!
 //! Disallow usage of APIs that use ES6 Proxies, for environments that don't support them.
+//!
+//! Mirrors the upstream rule: store imports, `new Proxy`/`Proxy.revocable`,
+//! `mergeProps` with function sources and JSX spreads Solid has to wrap in a
+//! Proxy. `check_program` runs all of them and, given semantic information,
+//! also follows identifiers passed to `mergeProps` to their function definitions.
 
 use oxc_ast::ast::{
-    Argument, CallExpression, Expression, ImportDeclaration, JSXSpreadAttribute,
-    NewExpression,
+    Argument, CallExpression, Expression, ImportDeclaration, ImportExpression,
+    JSXSpreadAttribute, NewExpression, Program,
 };
+use oxc_ast::AstKind;
+use oxc_ast_visit::{walk, Visit};
+use oxc_semantic::Semantic;
 use oxc_span::GetSpan;
 
 use crate::diagnostic::Diagnostic;
@@ -18,6 +26,10 @@ pub struct NoProxyApis;
 impl RuleMeta for NoProxyApis {
     const NAME: &'static str = "no-proxy-apis";
     const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Disallow usage of APIs that use ES6 Proxies, for environments that don't support \
+        them.";
+    const DEFAULT_ENABLED: bool = false;
 }
 
 impl NoProxyApis {
@@ -29,17 +41,28 @@ impl NoProxyApis {
     pub fn check_import<'a>(&self, import: &ImportDeclaration<'a>) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
 
-        if import.source.value == "solid-js/store" {
-            diagnostics.push(Diagnostic::warning(
-                Self::NAME,
-                import.span,
-                "Solid Store APIs use Proxies, which are incompatible with your target environment.",
-            ));
+        if is_store_source(&import.source.value) {
+            diagnostics.push(store_diagnostic(import.span));
         }
 
         diagnostics
     }
 
+    /// Check every Proxy-requiring API and pattern in a program
+    pub fn check_program<'a>(
+        &self,
+        program: &Program<'a>,
+        semantic: Option<&Semantic<'a>>,
+    ) -> Vec<Diagnostic> {
+        let mut visitor = ProxyUsageVisitor {
+            rule: self,
+            semantic,
+            diagnostics: Vec::new(),
+        };
+        visitor.visit_program(program);
+        visitor.diagnostics
+    }
+
     /// Check a JSX spread attribute for proxy-creating patterns
     pub fn check_spread<'a>(&self, spread: &JSXSpreadAttribute<'a>) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
@@ -81,8 +104,27 @@ impl NoProxyApis {
 
     /// Check a call expression for Proxy.revocable() and mergeProps with functions
     pub fn check_call<'a>(&self, call: &CallExpression<'a>) -> Vec<Diagnostic> {
+        self.check_call_with(call, |_| false)
+    }
+
+    /// `is_function_reference` decides whether an identifier passed to
+    /// `mergeProps` refers to a function
+    fn check_call_with<'a>(
+        &self,
+        call: &CallExpression<'a>,
+        is_function_reference: impl Fn(&Expression<'a>) -> bool,
+    ) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
 
+        // `require("solid-js/store")`
+        if let (Expression::Identifier(callee), Some(Argument::StringLiteral(source))) =
+            (&call.callee, call.arguments.first())
+        {
+            if callee.name == "require" && is_store_source(&source.value) {
+                diagnostics.push(store_diagnostic(call.span));
+            }
+        }
+
         // Check for Proxy.revocable()
         if let Expression::StaticMemberExpression(member) = &call.callee {
             if let Expression::Identifier(obj) = &member.object {
@@ -101,19 +143,15 @@ impl NoProxyApis {
             if callee.name == "mergeProps" {
                 for arg in &call.arguments {
                     let is_problematic = match arg {
+                        // The spread sources may be functions
                         Argument::SpreadElement(_) => true,
-                        arg => {
-                            if let Some(expr) = arg.as_expression() {
-                                matches!(
-                                    expr,
-                                    Expression::Identifier(_)
-                                        | Expression::ArrowFunctionExpression(_)
-                                        | Expression::FunctionExpression(_)
-                                )
-                            } else {
-                                false
-                            }
-                        }
+                        arg => arg.as_expression().is_some_and(|expr| {
+                            matches!(
+                                expr.get_inner_expression(),
+                                Expression::ArrowFunctionExpression(_)
+                                    | Expression::FunctionExpression(_)
+                            ) || is_function_reference(expr.get_inner_expression())
+                        }),
                     };
 
                     if is_problematic {
@@ -131,12 +169,137 @@ impl NoProxyApis {
     }
 }
 
+fn is_store_source(source: &str) -> bool {
+    source == "solid-js/store" || source.starts_with("solid-js/store/")
+}
+
+fn store_diagnostic(span: oxc_span::Span) -> Diagnostic {
+    Diagnostic::warning(
+        NoProxyApis::NAME,
+        span,
+        "Solid Store APIs use Proxies, which are incompatible with your target environment.",
+    )
+}
+
+struct ProxyUsageVisitor<'r, 's, 'a> {
+    rule: &'r NoProxyApis,
+    semantic: Option<&'s Semantic<'a>>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> ProxyUsageVisitor<'_, '_, 'a> {
+    /// Whether an identifier is bound to a function declaration or a
+    /// variable initialized with a function
+    fn is_function_reference(&self, expr: &Expression<'a>) -> bool {
+        let (Some(semantic), Expression::Identifier(ident)) = (self.semantic, expr) else {
+            return false;
+        };
+        let scoping = semantic.scoping();
+        let Some(symbol_id) = ident
+            .reference_id
+            .get()
+            .and_then(|id| scoping.get_reference(id).symbol_id())
+        else {
+            return false;
+        };
+        match semantic.nodes().kind(scoping.symbol_declaration(symbol_id)) {
+            AstKind::Function(_) => true,
+            AstKind::VariableDeclarator(decl) => decl.init.as_ref().is_some_and(|init| {
+                matches!(
+                    init.get_inner_expression(),
+                    Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_)
+                )
+            }),
+            _ => false,
+        }
+    }
+}
+
+impl<'a> Visit<'a> for ProxyUsageVisitor<'_, '_, 'a> {
+    fn visit_import_declaration(&mut self, import: &ImportDeclaration<'a>) {
+        self.diagnostics.extend(self.rule.check_import(import));
+    }
+
+    fn visit_import_expression(&mut self, import: &ImportExpression<'a>) {
+        if let Expression::StringLiteral(source) = &import.source {
+            if is_store_source(&source.value) {
+                self.diagnostics.push(store_diagnostic(import.span));
+            }
+        }
+        walk::walk_import_expression(self, import);
+    }
+
+    fn visit_jsx_spread_attribute(&mut self, spread: &JSXSpreadAttribute<'a>) {
+        self.diagnostics.extend(self.rule.check_spread(spread));
+        walk::walk_jsx_spread_attribute(self, spread);
+    }
+
+    fn visit_new_expression(&mut self, new_expr: &NewExpression<'a>) {
+        self.diagnostics
+            .extend(self.rule.check_new_expression(new_expr));
+        walk::walk_new_expression(self, new_expr);
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        let diagnostics = self
+            .rule
+            .check_call_with(call, |expr| self.is_function_reference(expr));
+        self.diagnostics.extend(diagnostics);
+        walk::walk_call_expression(self, call);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
 
     #[test]
     fn test_rule_name() {
         assert_eq!(NoProxyApis::NAME, "no-proxy-apis");
     }
+
+    fn check(source: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let semantic = SemanticBuilder::new().build(&program).semantic;
+        NoProxyApis::new()
+            .check_program(&program, Some(&semantic))
+            .into_iter()
+            .map(|d| source[d.start as usize..d.end as usize].to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_program() {
+        let reported = check(
+            r#"
+            import { createStore } from "solid-js/store";
+            const lazyStore = import("solid-js/store");
+            const getDefaults = () => ({ size: 1 });
+            const defaults = { size: 1 };
+            const merged = mergeProps(defaults, getDefaults, ...sources, () => ({}));
+            const p = new Proxy({}, {});
+            const el = <Comp {...props.nested} {...getProps()} {...local} />;
+            "#,
+        );
+        assert_eq!(
+            reported,
+            [
+                r#"import { createStore } from "solid-js/store";"#,
+                r#"import("solid-js/store")"#,
+                "getDefaults",
+                "...sources",
+                "() => ({})",
+                "new Proxy({}, {})",
+                "{...props.nested}",
+                "{...getProps()}",
+            ]
+        );
+    }
 }
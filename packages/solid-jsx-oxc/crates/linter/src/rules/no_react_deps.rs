@@ -1,11 +1,21 @@
 //! solid/no-react-deps
 //!
 //! Disallow usage of dependency arrays in createEffect and createMemo.
+//!
+//! When every listed dependency is already read inside the function, the array
+//! is just noise and the fix removes it. Otherwise the array names sources the
+//! function would not track by itself, so the fix rewrites the call to
+//! `on(deps, fn)` to keep those dependencies explicit, importing `on` from
+//! `solid-js` when nothing at the top level binds it.
 
-use oxc_ast::ast::{Argument, CallExpression, Expression};
-use oxc_span::GetSpan;
+use oxc_ast::ast::{ArrayExpressionElement, CallExpression, Expression, Program};
+use oxc_ast_visit::{walk, Visit};
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashSet;
 
+use crate::context::{argument_expression, strip_ts_wrappers};
 use crate::diagnostic::{Diagnostic, Fix};
+use crate::utils::{import_fix, span_text};
 use crate::{RuleCategory, RuleMeta};
 
 #[derive(Debug, Clone, Default)]
@@ -14,6 +24,9 @@ pub struct NoReactDeps;
 impl RuleMeta for NoReactDeps {
     const NAME: &'static str = "no-react-deps";
     const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Disallow usage of dependency arrays in createEffect and createMemo.";
+    const FIXABLE: bool = true;
 }
 
 impl NoReactDeps {
@@ -21,7 +34,29 @@ impl NoReactDeps {
         Self
     }
 
-    pub fn check<'a>(&self, call: &CallExpression<'a>) -> Vec<Diagnostic> {
+    /// Check every call in `program`, with fixes that import `on`
+    pub fn check_program<'a>(&self, program: &Program<'a>, source_text: &str) -> Vec<Diagnostic> {
+        let mut calls = Calls {
+            rule: self,
+            program,
+            source_text,
+            diagnostics: Vec::new(),
+        };
+        calls.visit_program(program);
+        calls.diagnostics
+    }
+
+    /// Check a single call, leaving the `on` import to the user
+    pub fn check<'a>(&self, call: &CallExpression<'a>, source_text: &str) -> Vec<Diagnostic> {
+        self.check_call(call, None, source_text)
+    }
+
+    fn check_call<'a>(
+        &self,
+        call: &CallExpression<'a>,
+        program: Option<&Program<'a>>,
+        source_text: &str,
+    ) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
 
         let callee_name = match &call.callee {
@@ -37,25 +72,15 @@ impl NoReactDeps {
             return diagnostics;
         }
 
-        if call.arguments.iter().any(|arg| matches!(arg, Argument::SpreadElement(_))) {
-            return diagnostics;
-        }
-
-        let Some(first_arg) = call.arguments.first() else {
-            return diagnostics;
-        };
-        let Some(second_arg) = call.arguments.get(1) else {
+        let (Some(first_arg), Some(second_arg)) = (call.arguments.first(), call.arguments.get(1))
+        else {
             return diagnostics;
         };
 
-        let first_expr = match first_arg {
-            Argument::SpreadElement(_) => return diagnostics,
-            arg => arg.to_expression(),
-        };
-
-        let second_expr = match second_arg {
-            Argument::SpreadElement(_) => return diagnostics,
-            arg => arg.to_expression(),
+        let (Some(first_expr), Some(second_expr)) =
+            (argument_expression(first_arg), argument_expression(second_arg))
+        else {
+            return diagnostics;
         };
 
         let is_zero_param_function = match first_expr {
@@ -68,11 +93,9 @@ impl NoReactDeps {
             return diagnostics;
         }
 
-        let is_array_expression = matches!(second_expr, Expression::ArrayExpression(_));
-
-        if !is_array_expression {
+        let Expression::ArrayExpression(array) = second_expr else {
             return diagnostics;
-        }
+        };
 
         let second_span = second_arg.span();
 
@@ -85,24 +108,227 @@ impl NoReactDeps {
             ),
         );
 
-        let fix_start = call.arguments.first().unwrap().span().end;
-        let fix_end = second_span.end;
-        let fix_span = oxc_span::Span::new(fix_start, fix_end);
-        diagnostic = diagnostic.with_fix(
-            Fix::new(fix_span, String::new()).with_message("Remove dependency array"),
-        );
+        let mut read_in_body = ExpressionTexts {
+            source_text,
+            texts: FxHashSet::default(),
+        };
+        read_in_body.visit_expression(first_expr);
+
+        let mut accessors = Vec::new();
+        let mut all_read = true;
+        for element in &array.elements {
+            let Some(dep) = element_expression(element) else {
+                // Holes and spreads can't be turned into `on` sources
+                accessors.clear();
+                break;
+            };
+            let dep_text = span_text(source_text, dep.span());
+            let accessor = dep_accessor(dep, source_text);
+            let is_read = read_in_body.texts.contains(dep_text)
+                || read_in_body
+                    .texts
+                    .contains(format!("{}()", accessor).as_str());
+            all_read &= is_read;
+            accessors.push(accessor);
+        }
+
+        if all_read || accessors.is_empty() {
+            let fix_span = Span::new(first_arg.span().end, second_span.end);
+            diagnostic = diagnostic.with_fix(
+                Fix::new(fix_span, String::new()).with_message("Remove dependency array"),
+            );
+        } else {
+            let deps = if accessors.len() == 1 {
+                accessors.remove(0)
+            } else {
+                format!("[{}]", accessors.join(", "))
+            };
+            let replacement = format!("on({}, {})", deps, span_text(source_text, first_arg.span()));
+            diagnostic = diagnostic.with_fix(
+                Fix::new(
+                    Span::new(first_arg.span().start, second_span.end),
+                    replacement,
+                )
+                .with_message("Track the listed dependencies with `on`"),
+            );
+            match program {
+                Some(program) => {
+                    if let Some(fix) = import_fix(program, "solid-js", "on") {
+                        diagnostic = diagnostic.with_fix(fix);
+                    }
+                }
+                None => {
+                    diagnostic =
+                        diagnostic.with_help("`on` needs to be imported from \"solid-js\".");
+                }
+            }
+        }
 
         diagnostics.push(diagnostic);
         diagnostics
     }
 }
 
+struct Calls<'r, 'p, 'a> {
+    rule: &'r NoReactDeps,
+    program: &'p Program<'a>,
+    source_text: &'r str,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Visit<'a> for Calls<'_, '_, 'a> {
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        self.diagnostics.extend(
+            self.rule
+                .check_call(call, Some(self.program), self.source_text),
+        );
+        walk::walk_call_expression(self, call);
+    }
+}
+
+fn element_expression<'b, 'a>(
+    element: &'b ArrayExpressionElement<'a>,
+) -> Option<&'b Expression<'a>> {
+    match element {
+        ArrayExpressionElement::SpreadElement(_) | ArrayExpressionElement::Elision(_) => None,
+        element => element.as_expression(),
+    }
+}
+
+/// The source `on` should track for a dependency: `count()` becomes `count`,
+/// a bare identifier is assumed to already be an accessor, and anything else
+/// is wrapped in an arrow function.
+fn dep_accessor(dep: &Expression, source_text: &str) -> String {
+    match strip_ts_wrappers(dep) {
+        Expression::CallExpression(call) if call.arguments.is_empty() => {
+            span_text(source_text, call.callee.span()).to_string()
+        }
+        Expression::Identifier(ident) => ident.name.to_string(),
+        _ => format!("() => {}", span_text(source_text, dep.span())),
+    }
+}
+
+/// Source text of every expression inside the effect function
+struct ExpressionTexts<'s> {
+    source_text: &'s str,
+    texts: FxHashSet<&'s str>,
+}
+
+impl<'a> Visit<'a> for ExpressionTexts<'_> {
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        self.texts.insert(span_text(self.source_text, expr.span()));
+        walk::walk_expression(self, expr);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::apply_fixes;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Statement;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn fixed(source: &str) -> Option<String> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::tsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::CallExpression(call) = &stmt.expression else {
+            panic!("expected call");
+        };
+        let fixed = apply_fixes(source, NoReactDeps::new().check(call, source));
+        fixed.is_changed().then_some(fixed.output)
+    }
 
     #[test]
     fn test_rule_name() {
         assert_eq!(NoReactDeps::NAME, "no-react-deps");
     }
+
+    #[test]
+    fn test_removes_redundant_deps() {
+        assert_eq!(
+            fixed("createEffect(() => log(count()), [count()]);").as_deref(),
+            Some("createEffect(() => log(count()));")
+        );
+        assert_eq!(
+            fixed("createEffect(() => log(count()), [count]);").as_deref(),
+            Some("createEffect(() => log(count()));")
+        );
+        assert_eq!(
+            fixed("createMemo(() => 1, []);").as_deref(),
+            Some("createMemo(() => 1);")
+        );
+    }
+
+    #[test]
+    fn test_converts_explicit_deps_to_on() {
+        assert_eq!(
+            fixed("createEffect(() => save(), [count()]);").as_deref(),
+            Some("createEffect(on(count, () => save()));")
+        );
+        assert_eq!(
+            fixed("createEffect(() => log(a()), [a(), props.id]);").as_deref(),
+            Some("createEffect(on([a, () => props.id], () => log(a())));")
+        );
+    }
+
+    #[test]
+    fn test_program_imports_on() {
+        let source = r#"import { createEffect } from "solid-js";
+createEffect(() => save(), [count()]);"#;
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::tsx())
+            .parse()
+            .program;
+        let diagnostics = NoReactDeps::new().check_program(&program, source);
+        let output = apply_fixes(source, diagnostics).output;
+        assert_eq!(
+            output,
+            r#"import { createEffect, on } from "solid-js";
+createEffect(on(count, () => save()));"#
+        );
+    }
+
+    #[test]
+    fn test_fixed_output_lints_clean() {
+        for source in [
+            "createEffect(() => save(), [count()]);",
+            "createEffect(() => log(a()), [a(), props.id]);",
+            "createEffect((() => save()) satisfies () => void, [count()!]);",
+        ] {
+            let output = fixed(source).unwrap();
+            let diagnostics = crate::lint_source(
+                &output,
+                SourceType::tsx(),
+                &crate::LintConfig::default(),
+                &[],
+            );
+            assert!(
+                diagnostics
+                    .iter()
+                    .all(|d| d.rule != NoReactDeps::NAME && d.rule != crate::Reactivity::NAME),
+                "{}: {:?}",
+                output,
+                diagnostics
+            );
+        }
+    }
+
+    #[test]
+    fn test_typescript_wrappers() {
+        assert_eq!(
+            fixed("createEffect(() => log(count()), [count] as const);").as_deref(),
+            Some("createEffect(() => log(count()));")
+        );
+        assert_eq!(
+            fixed("createEffect((() => save()) satisfies () => void, [count()!]);").as_deref(),
+            Some("createEffect(on(count, (() => save()) satisfies () => void));")
+        );
+    }
 }
@@ -1,9 +1,14 @@
 //! solid/no-react-specific-props
 //!
 //! Disallow usage of React-specific `className`/`htmlFor` props.
-//! Also detects useless `key` props on DOM elements.
+//! Also detects useless `key` props on DOM elements and React-only DOM props
+//! like `defaultValue` or `suppressHydrationWarning`.
+//!
+//! The `className` -> `class` style renames come from the compiler's alias
+//! table, so the lint fix and the transform always agree.
 
-use oxc_ast::ast::{JSXAttributeName, JSXOpeningElement};
+use common::constants::ALIASES;
+use oxc_ast::ast::{JSXAttributeItem, JSXAttributeName, JSXOpeningElement};
 
 use crate::diagnostic::{Diagnostic, Fix};
 use crate::utils::{get_attribute, get_element_name, has_attribute, is_dom_element};
@@ -16,10 +21,20 @@ pub struct NoReactSpecificProps;
 impl RuleMeta for NoReactSpecificProps {
     const NAME: &'static str = "no-react-specific-props";
     const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Disallow usage of React-specific `className`/`htmlFor` props. Also detects \
+        useless `key` props on DOM elements and React-only DOM props like `defaultValue` \
+        or `suppressHydrationWarning`.";
+    const FIXABLE: bool = true;
 }
 
-/// React-specific props and their Solid equivalents
-const REACT_PROP_REPLACEMENTS: &[(&str, &str)] = &[("className", "class"), ("htmlFor", "for")];
+/// React-only DOM props, with the Solid prop to use instead (if any)
+const REACT_ONLY_DOM_PROPS: &[(&str, Option<&str>)] = &[
+    ("defaultChecked", Some("checked")),
+    ("defaultValue", Some("value")),
+    ("suppressContentEditableWarning", None),
+    ("suppressHydrationWarning", None),
+];
 
 impl NoReactSpecificProps {
     pub fn new() -> Self {
@@ -30,30 +45,34 @@ impl NoReactSpecificProps {
     pub fn check<'a>(&self, opening: &JSXOpeningElement<'a>) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
 
-        for (from, to) in REACT_PROP_REPLACEMENTS {
-            if let Some(attr) = get_attribute(opening, from) {
-                let attr_span = match &attr.name {
-                    JSXAttributeName::Identifier(ident) => ident.span,
-                    _ => attr.span,
-                };
-
-                let mut diagnostic = Diagnostic::warning(
-                    Self::NAME,
-                    attr.span,
-                    format!(
-                        "Prefer the `{}` prop over the deprecated `{}` prop.",
-                        to, from
-                    ),
-                );
-
-                // Only auto-fix if target prop doesn't already exist
-                if !has_attribute(opening, to) {
-                    diagnostic = diagnostic.with_fix(Fix::new(attr_span, to.to_string())
-                        .with_message(format!("Replace `{}` with `{}`", from, to)));
-                }
+        for item in &opening.attributes {
+            let JSXAttributeItem::Attribute(attr) = item else {
+                continue;
+            };
+            let JSXAttributeName::Identifier(ident) = &attr.name else {
+                continue;
+            };
+            let from = ident.name.as_str();
+            let Some(to) = ALIASES.get(from) else {
+                continue;
+            };
+
+            let mut diagnostic = Diagnostic::warning(
+                Self::NAME,
+                attr.span,
+                format!(
+                    "Prefer the `{}` prop over the deprecated `{}` prop.",
+                    to, from
+                ),
+            );
 
-                diagnostics.push(diagnostic);
+            // Only auto-fix if target prop doesn't already exist
+            if !has_attribute(opening, to) {
+                diagnostic = diagnostic.with_fix(Fix::new(ident.span, to.to_string())
+                    .with_message(format!("Replace `{}` with `{}`", from, to)));
             }
+
+            diagnostics.push(diagnostic);
         }
 
         // Check for useless `key` prop on DOM elements
@@ -71,6 +90,40 @@ impl NoReactSpecificProps {
                             .with_message("Remove `key` prop")),
                     );
                 }
+
+                for (from, to) in REACT_ONLY_DOM_PROPS {
+                    let Some(attr) = get_attribute(opening, from) else {
+                        continue;
+                    };
+                    let diagnostic = Diagnostic::warning(
+                        Self::NAME,
+                        attr.span,
+                        format!("`{}` is a React-only prop and has no effect in Solid.", from),
+                    );
+                    let diagnostic = match to {
+                        Some(to) => {
+                            let diagnostic = diagnostic
+                                .with_help(format!("Solid sets `{}` directly; use it instead.", to));
+                            if has_attribute(opening, to) {
+                                diagnostic
+                            } else {
+                                let name_span = match &attr.name {
+                                    JSXAttributeName::Identifier(ident) => ident.span,
+                                    _ => attr.span,
+                                };
+                                diagnostic.with_fix(
+                                    Fix::new(name_span, to.to_string())
+                                        .with_message(format!("Replace `{}` with `{}`", from, to)),
+                                )
+                            }
+                        }
+                        None => diagnostic.with_fix(
+                            Fix::new(attr.span, String::new())
+                                .with_message(format!("Remove `{}` prop", from)),
+                        ),
+                    };
+                    diagnostics.push(diagnostic);
+                }
             }
         }
 
@@ -82,8 +135,46 @@ impl NoReactSpecificProps {
 mod tests {
     use super::*;
 
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn check(source: &str) -> Vec<Diagnostic> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).parse();
+        let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.first() else {
+            panic!("expected an expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected a JSX element");
+        };
+        NoReactSpecificProps::new().check(&element.opening_element)
+    }
+
     #[test]
     fn test_rule_name() {
         assert_eq!(NoReactSpecificProps::NAME, "no-react-specific-props");
     }
+
+    #[test]
+    fn test_aliases_follow_attribute_order() {
+        let diagnostics = check(r#"<label htmlFor="a" className="b" />"#);
+        let replacements: Vec<_> = diagnostics
+            .iter()
+            .map(|d| d.fixes[0].replacement.as_str())
+            .collect();
+        assert_eq!(replacements, ["for", "class"]);
+    }
+
+    #[test]
+    fn test_react_only_dom_props() {
+        let diagnostics = check(r#"<input defaultValue="a" suppressHydrationWarning />"#);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].fixes[0].replacement, "value");
+        assert_eq!(diagnostics[1].fixes[0].replacement, "");
+
+        // Components may define their own `defaultValue` prop
+        assert!(check(r#"<Select defaultValue="a" />"#).is_empty());
+    }
 }
@@ -0,0 +1,185 @@
+//! solid/no-reactive-calls-at-module-scope
+//!
+//! Disallow creating computations (`createEffect`, `createMemo`, `onMount`,
+//! ...) while a module is evaluated. There is no owner at that point, so Solid
+//! warns at runtime and the computation is never disposed. Module-level
+//! signals and stores are fine; only the computations that track them leak.
+//!
+//! Anything inside a function runs later, under whatever owner calls it, and
+//! is not reported. That includes `createRoot` and `render` callbacks.
+
+use oxc_ast::ast::{
+    ArrowFunctionExpression, CallExpression, Class, Expression, Function,
+    ImportDeclarationSpecifier, Program, Statement,
+};
+use oxc_ast_visit::{walk, Visit};
+use oxc_syntax::scope::ScopeFlags;
+use rustc_hash::FxHashMap;
+
+use crate::diagnostic::Diagnostic;
+use crate::{RuleCategory, RuleMeta};
+
+/// Primitives that create a computation or register with the current owner
+const OWNED_PRIMITIVES: &[&str] = &[
+    "createComputed",
+    "createDeferred",
+    "createEffect",
+    "createMemo",
+    "createReaction",
+    "createRenderEffect",
+    "createSelector",
+    "onCleanup",
+    "onMount",
+];
+
+/// no-reactive-calls-at-module-scope rule
+#[derive(Debug, Clone, Default)]
+pub struct NoReactiveCallsAtModuleScope;
+
+impl RuleMeta for NoReactiveCallsAtModuleScope {
+    const NAME: &'static str = "no-reactive-calls-at-module-scope";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Disallow creating computations (`createEffect`, `createMemo`, `onMount`, ...) \
+        while a module is evaluated. There is no owner at that point, so Solid warns at \
+        runtime and the computation is never disposed. Module-level signals and stores \
+        are fine; only the computations that track them leak.";
+}
+
+impl NoReactiveCallsAtModuleScope {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn check_program<'a>(&self, program: &Program<'a>) -> Vec<Diagnostic> {
+        // Local name -> imported name, for the primitives imported from solid-js
+        let mut primitives = FxHashMap::default();
+        for stmt in &program.body {
+            let Statement::ImportDeclaration(import) = stmt else {
+                continue;
+            };
+            if import.source.value != "solid-js" {
+                continue;
+            }
+            for specifier in import.specifiers.iter().flatten() {
+                if let ImportDeclarationSpecifier::ImportSpecifier(named) = specifier {
+                    let imported = named.imported.name();
+                    if OWNED_PRIMITIVES.contains(&imported.as_str()) {
+                        primitives.insert(named.local.name.to_string(), imported.to_string());
+                    }
+                }
+            }
+        }
+        if primitives.is_empty() {
+            return Vec::new();
+        }
+
+        let mut finder = ModuleScopeCalls {
+            primitives,
+            diagnostics: Vec::new(),
+        };
+        finder.visit_program(program);
+        finder.diagnostics
+    }
+}
+
+struct ModuleScopeCalls {
+    primitives: FxHashMap<String, String>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Visit<'a> for ModuleScopeCalls {
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if let Expression::Identifier(callee) = &call.callee {
+            if let Some(primitive) = self.primitives.get(callee.name.as_str()) {
+                self.diagnostics.push(
+                    Diagnostic::warning(
+                        NoReactiveCallsAtModuleScope::NAME,
+                        call.span,
+                        format!(
+                            "`{}` at module scope has no owner and is never disposed.",
+                            primitive
+                        ),
+                    )
+                    .with_help(
+                        "Move it into a component, or wrap it in `createRoot` and keep the returned dispose function.",
+                    ),
+                );
+            }
+        }
+        walk::walk_call_expression(self, call);
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _class: &Class<'a>) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn module_messages(source: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        NoReactiveCallsAtModuleScope::new()
+            .check_program(&program)
+            .into_iter()
+            .map(|d| d.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(
+            NoReactiveCallsAtModuleScope::NAME,
+            "no-reactive-calls-at-module-scope"
+        );
+    }
+
+    #[test]
+    fn test_module_scope_computations() {
+        let messages = module_messages(
+            r#"
+            import { createSignal, createEffect as effect, createMemo, onMount } from "solid-js";
+            const [theme, setTheme] = createSignal("light");
+            effect(() => document.body.dataset.theme = theme());
+            export const dark = createMemo(() => theme() === "dark");
+            if (import.meta.hot) onMount(() => {});
+            "#,
+        );
+        assert_eq!(
+            messages,
+            [
+                "`createEffect` at module scope has no owner and is never disposed.",
+                "`createMemo` at module scope has no owner and is never disposed.",
+                "`onMount` at module scope has no owner and is never disposed.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_owned_computations() {
+        let messages = module_messages(
+            r#"
+            import { createRoot, createEffect, createSignal } from "solid-js";
+            const [count, setCount] = createSignal(0);
+            export const dispose = createRoot((dispose) => {
+                createEffect(() => console.log(count()));
+                return dispose;
+            });
+            function useCounter() { createEffect(() => count()); }
+            class Store { sync() { createEffect(() => count()); } }
+            "#,
+        );
+        assert!(messages.is_empty(), "{:?}", messages);
+        assert!(module_messages("createEffect(() => {});").is_empty());
+    }
+}
@@ -0,0 +1,237 @@
+//! solid/no-render-in-component
+//!
+//! Disallow calling `render`/`hydrate` from `solid-js/web` inside components
+//! and effects. Each call creates a new root that nothing disposes, so the
+//! nested tree leaks and effects calling it mount a fresh copy on every run.
+
+use oxc_ast::ast::{
+    ArrowFunctionExpression, BindingPattern, CallExpression, Expression, Function,
+    ImportDeclarationSpecifier, Program, Statement, VariableDeclarator,
+};
+use oxc_ast_visit::{walk, Visit};
+use oxc_semantic::{Semantic, SymbolId};
+use oxc_syntax::scope::ScopeFlags;
+use rustc_hash::FxHashMap;
+
+use crate::diagnostic::Diagnostic;
+use crate::rules::NoDestructure;
+use crate::utils::is_component_name;
+use crate::{RuleCategory, RuleMeta};
+
+/// Functions that run their callback as an effect
+const EFFECT_FUNCTIONS: &[&str] = &[
+    "onMount",
+    "createEffect",
+    "createRenderEffect",
+    "createComputed",
+];
+
+/// no-render-in-component rule
+#[derive(Debug, Clone, Default)]
+pub struct NoRenderInComponent;
+
+impl RuleMeta for NoRenderInComponent {
+    const NAME: &'static str = "no-render-in-component";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Disallow calling `render`/`hydrate` from `solid-js/web` inside components and \
+        effects. Each call creates a new root that nothing disposes, so the nested tree \
+        leaks and effects calling it mount a fresh copy on every run.";
+}
+
+impl NoRenderInComponent {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn check<'a>(&self, program: &Program<'a>, semantic: &Semantic<'a>) -> Vec<Diagnostic> {
+        // Import binding -> imported name, for `render`/`hydrate` from solid-js/web
+        let mut renderers = FxHashMap::default();
+        for stmt in &program.body {
+            let Statement::ImportDeclaration(import) = stmt else {
+                continue;
+            };
+            if import.source.value != "solid-js/web" {
+                continue;
+            }
+            for specifier in import.specifiers.iter().flatten() {
+                if let ImportDeclarationSpecifier::ImportSpecifier(named) = specifier {
+                    let imported = named.imported.name();
+                    if let ("render" | "hydrate", Some(symbol)) =
+                        (imported.as_str(), named.local.symbol_id.get())
+                    {
+                        renderers.insert(symbol, imported.to_string());
+                    }
+                }
+            }
+        }
+        if renderers.is_empty() {
+            return Vec::new();
+        }
+
+        let mut finder = RenderCalls {
+            semantic,
+            renderers,
+            owners: Vec::new(),
+            effect_callback: None,
+            declarator_name: None,
+            diagnostics: Vec::new(),
+        };
+        finder.visit_program(program);
+        finder.diagnostics
+    }
+}
+
+struct RenderCalls<'s, 'a> {
+    semantic: &'s Semantic<'a>,
+    renderers: FxHashMap<SymbolId, String>,
+    /// Enclosing components and effects, innermost last; `None` for other functions
+    owners: Vec<Option<String>>,
+    /// Set while visiting the arguments of an effect call
+    effect_callback: Option<String>,
+    declarator_name: Option<String>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl RenderCalls<'_, '_> {
+    fn enter(&mut self, name: Option<String>, has_jsx: bool) {
+        let owner = match self.effect_callback.take() {
+            Some(effect) => Some(format!("`{}`", effect)),
+            None if has_jsx || name.is_some_and(|n| is_component_name(&n)) => {
+                Some("a component".to_string())
+            }
+            None => None,
+        };
+        self.owners.push(owner);
+    }
+}
+
+impl<'a> Visit<'a> for RenderCalls<'_, 'a> {
+    fn visit_variable_declarator(&mut self, declarator: &VariableDeclarator<'a>) {
+        if let BindingPattern::BindingIdentifier(ident) = &declarator.id {
+            self.declarator_name = Some(ident.name.to_string());
+        }
+        walk::walk_variable_declarator(self, declarator);
+        self.declarator_name = None;
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        let name = func
+            .id
+            .as_ref()
+            .map(|id| id.name.to_string())
+            .or_else(|| self.declarator_name.take());
+        let has_jsx = func
+            .body
+            .as_ref()
+            .is_some_and(|body| NoDestructure::body_has_jsx(body));
+        self.enter(name, has_jsx);
+        walk::walk_function(self, func, flags);
+        self.owners.pop();
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        let name = self.declarator_name.take();
+        self.enter(name, NoDestructure::body_has_jsx(&arrow.body));
+        walk::walk_arrow_function_expression(self, arrow);
+        self.owners.pop();
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        let Expression::Identifier(callee) = &call.callee else {
+            walk::walk_call_expression(self, call);
+            return;
+        };
+
+        let imported = callee
+            .reference_id
+            .get()
+            .and_then(|id| self.semantic.scoping().get_reference(id).symbol_id())
+            .and_then(|symbol| self.renderers.get(&symbol));
+        if let Some(imported) = imported {
+            if let Some(owner) = self.owners.iter().rev().flatten().next() {
+                self.diagnostics.push(
+                    Diagnostic::warning(
+                        NoRenderInComponent::NAME,
+                        call.span,
+                        format!(
+                            "`{}` inside {} creates a nested root that is never disposed.",
+                            imported, owner
+                        ),
+                    )
+                    .with_help(
+                        "Use `<Portal>` to render into another DOM node, or call it once from the entry point.",
+                    ),
+                );
+            }
+        }
+
+        let effect = EFFECT_FUNCTIONS
+            .contains(&callee.name.as_str())
+            .then(|| callee.name.to_string());
+        for argument in &call.arguments {
+            self.effect_callback = effect.clone();
+            self.visit_argument(argument);
+            self.effect_callback = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    fn messages(source: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).parse();
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        NoRenderInComponent::new()
+            .check(&ret.program, &semantic)
+            .into_iter()
+            .map(|d| d.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(NoRenderInComponent::NAME, "no-render-in-component");
+    }
+
+    #[test]
+    fn test_nested_roots() {
+        let messages = messages(
+            r#"
+            import { render, hydrate as hydrateApp } from "solid-js/web";
+            function Modal(props) {
+                render(() => <div>{props.children}</div>, document.body);
+                return null;
+            }
+            createEffect(() => { if (open()) hydrateApp(() => <Panel />, el); });
+            "#,
+        );
+        assert_eq!(
+            messages,
+            [
+                "`render` inside a component creates a nested root that is never disposed.",
+                "`hydrate` inside `createEffect` creates a nested root that is never disposed.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_entry_point() {
+        let messages = messages(
+            r#"
+            import { render } from "solid-js/web";
+            const mount = (el) => render(() => <App />, el);
+            render(() => <App />, document.getElementById("root"));
+            function Chart() { const render = draw; render(); return <canvas />; }
+            "#,
+        );
+        assert!(messages.is_empty(), "{:?}", messages);
+    }
+}
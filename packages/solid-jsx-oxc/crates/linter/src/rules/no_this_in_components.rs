@@ -0,0 +1,255 @@
+//! solid/no-this-in-components
+//!
+//! Disallow `this` in function components, a leftover from class components,
+//! and in arrow callbacks of tracking scopes (`createEffect(() => this.x)`)
+//! declared outside any function, where `this` is `undefined`.
+//!
+//! Arrow functions inherit `this`, so uses inside a component's handlers and
+//! effects are reported too. Functions and classes nested in a component have
+//! their own `this` and are left alone.
+
+use oxc_ast::ast::{
+    ArrowFunctionExpression, BindingPattern, CallExpression, Class, Expression, Function, Program,
+    ThisExpression, VariableDeclarator,
+};
+use oxc_ast_visit::{walk, Visit};
+use oxc_syntax::scope::ScopeFlags;
+
+use crate::diagnostic::Diagnostic;
+use crate::rules::NoDestructure;
+use crate::utils::is_component_name;
+use crate::{RuleCategory, RuleMeta};
+
+/// Primitives whose callback is a tracking scope
+const TRACKING_FUNCTIONS: &[&str] = &[
+    "createEffect",
+    "createMemo",
+    "createComputed",
+    "createRenderEffect",
+    "createReaction",
+    "on",
+];
+
+/// no-this-in-components rule
+#[derive(Debug, Clone, Default)]
+pub struct NoThisInComponents;
+
+impl RuleMeta for NoThisInComponents {
+    const NAME: &'static str = "no-this-in-components";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Disallow `this` in function components, a leftover from class components, and in \
+        arrow callbacks of tracking scopes (`createEffect(() => this.x)`) declared \
+        outside any function, where `this` is `undefined`.";
+}
+
+impl NoThisInComponents {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn check_program<'a>(&self, program: &Program<'a>) -> Vec<Diagnostic> {
+        let mut finder = ThisFinder::default();
+        finder.visit_program(program);
+        finder.diagnostics
+    }
+}
+
+/// What `this` means at some point of the walk
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ThisBinding {
+    /// A function component's `this`
+    Component,
+    /// An ordinary function or class, with a `this` of its own
+    Own,
+    /// The callback of a tracking primitive, which inherits `this`
+    Tracking,
+}
+
+#[derive(Default)]
+struct ThisFinder {
+    stack: Vec<ThisBinding>,
+    /// Set while visiting the callback argument of a tracking primitive
+    tracking_callback: bool,
+    /// Name of the variable the next function expression is assigned to
+    declarator_name: Option<String>,
+    class_depth: usize,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Visit<'a> for ThisFinder {
+    fn visit_class(&mut self, class: &Class<'a>) {
+        self.class_depth += 1;
+        self.stack.push(ThisBinding::Own);
+        walk::walk_class(self, class);
+        self.stack.pop();
+        self.class_depth -= 1;
+    }
+
+    fn visit_variable_declarator(&mut self, declarator: &VariableDeclarator<'a>) {
+        if let BindingPattern::BindingIdentifier(ident) = &declarator.id {
+            self.declarator_name = Some(ident.name.to_string());
+        }
+        walk::walk_variable_declarator(self, declarator);
+        self.declarator_name = None;
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        let name = func
+            .id
+            .as_ref()
+            .map(|id| id.name.to_string())
+            .or_else(|| self.declarator_name.take());
+        let is_pascal_case = name.is_some_and(|name| is_component_name(&name));
+        let returns_jsx = func
+            .body
+            .as_ref()
+            .is_some_and(|body| NoDestructure::body_has_jsx(body));
+        let binding = if self.class_depth == 0 && (is_pascal_case || returns_jsx) {
+            ThisBinding::Component
+        } else {
+            ThisBinding::Own
+        };
+
+        self.tracking_callback = false;
+        self.stack.push(binding);
+        walk::walk_function(self, func, flags);
+        self.stack.pop();
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        let is_tracking = std::mem::take(&mut self.tracking_callback);
+        let is_component = self.class_depth == 0
+            && self.stack.iter().all(|b| *b == ThisBinding::Tracking)
+            && (self
+                .declarator_name
+                .take()
+                .is_some_and(|name| is_component_name(&name))
+                || NoDestructure::body_has_jsx(&arrow.body));
+
+        let pushed = if is_component {
+            Some(ThisBinding::Component)
+        } else if is_tracking {
+            Some(ThisBinding::Tracking)
+        } else {
+            None
+        };
+        if let Some(binding) = pushed {
+            self.stack.push(binding);
+        }
+        walk::walk_arrow_function_expression(self, arrow);
+        if pushed.is_some() {
+            self.stack.pop();
+        }
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        let is_tracking = matches!(
+            &call.callee,
+            Expression::Identifier(callee) if TRACKING_FUNCTIONS.contains(&callee.name.as_str())
+        );
+        self.visit_expression(&call.callee);
+        for argument in &call.arguments {
+            self.tracking_callback = is_tracking;
+            self.visit_argument(argument);
+            self.tracking_callback = false;
+        }
+    }
+
+    fn visit_this_expression(&mut self, this: &ThisExpression) {
+        let mut in_tracking = false;
+        for binding in self.stack.iter().rev() {
+            match binding {
+                ThisBinding::Own => return,
+                ThisBinding::Component => {
+                    self.diagnostics.push(
+                        Diagnostic::warning(
+                            NoThisInComponents::NAME,
+                            this.span,
+                            "`this` has no meaning in a function component.",
+                        )
+                        .with_help("Solid components are plain functions; read props, signals or local variables instead."),
+                    );
+                    return;
+                }
+                ThisBinding::Tracking => in_tracking = true,
+            }
+        }
+        if in_tracking {
+            self.diagnostics.push(
+                Diagnostic::warning(
+                    NoThisInComponents::NAME,
+                    this.span,
+                    "`this` is `undefined` in this tracking scope.",
+                )
+                .with_help("Arrow functions take `this` from where they are declared, which here is the module."),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn messages(source: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        NoThisInComponents::new()
+            .check_program(&program)
+            .into_iter()
+            .map(|d| d.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(NoThisInComponents::NAME, "no-this-in-components");
+    }
+
+    #[test]
+    fn test_this_in_components() {
+        let messages = messages(
+            r#"
+            function Counter() {
+                const onClick = () => this.increment();
+                return <button onClick={onClick}>{this.count}</button>;
+            }
+            const Label = (props) => <span>{this.props.text}</span>;
+            createEffect(() => console.log(this.value));
+            "#,
+        );
+        assert_eq!(
+            messages,
+            [
+                "`this` has no meaning in a function component.",
+                "`this` has no meaning in a function component.",
+                "`this` has no meaning in a function component.",
+                "`this` is `undefined` in this tracking scope.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_own_this() {
+        let messages = messages(
+            r#"
+            function App() {
+                const handler = { onEvent() { return this.name; } };
+                el.addEventListener("click", function () { this.focus(); });
+                return <div />;
+            }
+            class Store {
+                render() { createEffect(() => this.sync()); return <div />; }
+            }
+            function helper() { return this; }
+            "#,
+        );
+        assert!(messages.is_empty(), "{:?}", messages);
+    }
+}
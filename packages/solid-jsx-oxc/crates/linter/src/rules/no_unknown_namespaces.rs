@@ -1,24 +1,24 @@
 //! solid/no-unknown-namespaces
 //!
 //! Enforce using only Solid-specific namespaced attribute names.
+//!
+//! The accepted prefixes come from the transform's own namespace table, and
+//! near-misses such as `uses:` get a "did you mean" fix.
 
 use oxc_ast::ast::{JSXAttributeItem, JSXAttributeName, JSXElementName, JSXOpeningElement};
 
-use crate::diagnostic::{Diagnostic, Fix};
-use crate::utils::is_dom_element;
-use crate::{RuleCategory, RuleMeta};
-
-/// Known Solid namespace prefixes
-const KNOWN_NAMESPACES: &[&str] = &["on", "oncapture", "use", "prop", "attr", "bool"];
+use serde::{Deserialize, Serialize};
 
-/// Style-related namespaces (confusing, prefer the prop instead)
-const STYLE_NAMESPACES: &[&str] = &["style", "class"];
+use crate::diagnostic::{Diagnostic, Fix};
+use crate::utils::{is_dom_element, SOLID_NAMESPACES};
+use crate::{ConfigurableRule, RuleCategory, RuleMeta};
 
 /// Other valid XML namespaces
 const OTHER_NAMESPACES: &[&str] = &["xmlns", "xlink"];
 
 /// no-unknown-namespaces rule
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
 pub struct NoUnknownNamespaces {
     /// Additional namespace names to allow
     pub allowed_namespaces: Vec<String>,
@@ -27,6 +27,17 @@ pub struct NoUnknownNamespaces {
 impl RuleMeta for NoUnknownNamespaces {
     const NAME: &'static str = "no-unknown-namespaces";
     const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Enforce using only Solid-specific namespaced attribute names.";
+    const FIXABLE: bool = true;
+}
+
+impl ConfigurableRule for NoUnknownNamespaces {
+    type Options = Self;
+
+    fn from_options(options: Self) -> Self {
+        options
+    }
 }
 
 impl NoUnknownNamespaces {
@@ -39,6 +50,25 @@ impl NoUnknownNamespaces {
         self
     }
 
+    fn is_allowed(&self, namespace: &str) -> bool {
+        SOLID_NAMESPACES.contains(&namespace)
+            || OTHER_NAMESPACES.contains(&namespace)
+            || self.allowed_namespaces.iter().any(|n| n == namespace)
+    }
+
+    /// The closest allowed namespace, if `namespace` looks like a typo of one
+    fn suggestion(&self, namespace: &str) -> Option<String> {
+        let lower = namespace.to_ascii_lowercase();
+        SOLID_NAMESPACES
+            .iter()
+            .copied()
+            .chain(self.allowed_namespaces.iter().map(String::as_str))
+            .map(|candidate| (edit_distance(&lower, candidate), candidate))
+            .filter(|(distance, candidate)| *distance <= max_typo_distance(candidate))
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, candidate)| candidate.to_string())
+    }
+
     /// Check a JSX opening element for unknown namespaces
     pub fn check<'a>(&self, opening: &JSXOpeningElement<'a>) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
@@ -74,41 +104,32 @@ impl NoUnknownNamespaces {
                         continue;
                     }
 
-                    // Check if namespace is allowed
-                    let is_known = KNOWN_NAMESPACES.contains(&namespace)
-                        || OTHER_NAMESPACES.contains(&namespace)
-                        || self.allowed_namespaces.iter().any(|n| n == namespace);
-
-                    if !is_known {
-                        if STYLE_NAMESPACES.contains(&namespace) {
-                            diagnostics.push(
-                                Diagnostic::warning(
-                                    Self::NAME,
-                                    ns.span,
-                                    format!(
-                                        "Using the '{}:' special prefix is potentially confusing, prefer the '{}' prop instead.",
-                                        namespace, namespace
-                                    ),
-                                ),
-                            );
-                        } else {
-                            let known_list = KNOWN_NAMESPACES
-                                .iter()
-                                .map(|n| format!("'{}:'", n))
-                                .collect::<Vec<_>>()
-                                .join(", ");
-                            diagnostics.push(
-                                Diagnostic::warning(
-                                    Self::NAME,
-                                    ns.span,
-                                    format!(
-                                        "'{}:' is not one of Solid's special prefixes for JSX attributes ({}).",
-                                        namespace, known_list
-                                    ),
-                                ),
+                    if self.is_allowed(namespace) {
+                        continue;
+                    }
+
+                    let known_list = SOLID_NAMESPACES
+                        .iter()
+                        .map(|n| format!("'{}:'", n))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    let mut diagnostic = Diagnostic::warning(
+                        Self::NAME,
+                        ns.span,
+                        format!(
+                            "'{}:' is not one of Solid's special prefixes for JSX attributes ({}).",
+                            namespace, known_list
+                        ),
+                    );
+                    if let Some(suggestion) = self.suggestion(namespace) {
+                        diagnostic = diagnostic
+                            .with_help(format!("Did you mean '{}:'?", suggestion))
+                            .with_fix(
+                                Fix::new(ns.namespace.span, suggestion.clone())
+                                    .with_message(format!("Replace '{}:' with '{}:'", namespace, suggestion)),
                             );
-                        }
                     }
+                    diagnostics.push(diagnostic);
                 }
             }
         }
@@ -117,12 +138,91 @@ impl NoUnknownNamespaces {
     }
 }
 
+/// Short namespaces like `on` or `bool` only tolerate a single typo
+fn max_typo_distance(candidate: &str) -> usize {
+    if candidate.len() <= 4 {
+        1
+    } else {
+        2
+    }
+}
+
+/// Levenshtein distance, counting an adjacent transposition as one edit
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = distance;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
 
     #[test]
     fn test_rule_name() {
         assert_eq!(NoUnknownNamespaces::NAME, "no-unknown-namespaces");
     }
+
+    fn check(source: &str, rule: &NoUnknownNamespaces) -> Vec<Diagnostic> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected JSX element");
+        };
+        rule.check(&element.opening_element)
+    }
+
+    #[test]
+    fn test_solid_namespaces_are_allowed() {
+        let source = r#"<div style:color="red" class:active={a()} bool:hidden={b()} oncapture:click={f} />"#;
+        assert!(check(source, &NoUnknownNamespaces::new()).is_empty());
+    }
+
+    #[test]
+    fn test_typo_suggestion() {
+        let diagnostics = check(r#"<div uses:tooltip />"#, &NoUnknownNamespaces::new());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].help.as_deref(), Some("Did you mean 'use:'?"));
+        assert_eq!(diagnostics[0].fixes[0].replacement, "use");
+
+        let diagnostics = check(r#"<div porp:value={v} />"#, &NoUnknownNamespaces::new());
+        assert_eq!(diagnostics[0].fixes[0].replacement, "prop");
+
+        let diagnostics = check(r#"<div foo:bar={v} />"#, &NoUnknownNamespaces::new());
+        assert!(diagnostics[0].fixes.is_empty());
+    }
+
+    #[test]
+    fn test_allowed_namespaces() {
+        let rule = NoUnknownNamespaces::new().with_allowed_namespaces(vec!["foo".to_string()]);
+        assert!(check(r#"<div foo:bar={v} />"#, &rule).is_empty());
+    }
 }
@@ -0,0 +1,169 @@
+//! solid/no-unstable-jsx-in-signal
+//!
+//! Disallow putting JSX into signals and stores, as in
+//! `setItems([...items(), <li />])` or `createSignal(<Spinner />)`. In Solid
+//! JSX evaluates to real DOM nodes, so the state holds nodes created once
+//! outside any template: they can only be attached in one place, are not
+//! recreated when rendered again, and are never cleaned up with their owner.
+//!
+//! Setters are recognized by Solid's `setX` naming convention. For updater
+//! functions (`setItems((prev) => [...prev, <li />])`) the returned JSX counts
+//! too; JSX inside other nested functions is created later and left alone.
+
+use oxc_ast::ast::{
+    Argument, ArrowFunctionExpression, CallExpression, Expression, Function, JSXElement,
+    JSXFragment,
+};
+use oxc_ast_visit::Visit;
+use oxc_span::Span;
+use oxc_syntax::scope::ScopeFlags;
+
+use crate::diagnostic::Diagnostic;
+use crate::utils::is_setter_name;
+use crate::{RuleCategory, RuleMeta};
+
+/// Primitives whose first argument becomes stored state
+const STATE_CREATORS: &[&str] = &["createSignal", "createStore", "createMutable"];
+
+/// no-unstable-jsx-in-signal rule
+#[derive(Debug, Clone, Default)]
+pub struct NoUnstableJsxInSignal;
+
+impl RuleMeta for NoUnstableJsxInSignal {
+    const NAME: &'static str = "no-unstable-jsx-in-signal";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Disallow putting JSX into signals and stores, as in `setItems([...items(), <li \
+        />])` or `createSignal(<Spinner />)`. In Solid JSX evaluates to real DOM nodes, \
+        so the state holds nodes created once outside any template: they can only be \
+        attached in one place, are not recreated when rendered again, and are never \
+        cleaned up with their owner.";
+}
+
+impl NoUnstableJsxInSignal {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check a signal/store creation or setter call for JSX in the stored value
+    pub fn check(&self, call: &CallExpression) -> Vec<Diagnostic> {
+        let Expression::Identifier(callee) = &call.callee else {
+            return Vec::new();
+        };
+        let name = callee.name.as_str();
+        let stored: &[Argument] = if STATE_CREATORS.contains(&name) {
+            call.arguments.get(..1).unwrap_or_default()
+        } else if is_setter_name(name) {
+            &call.arguments
+        } else {
+            return Vec::new();
+        };
+
+        let mut finder = JsxFinder::default();
+        for argument in stored {
+            let Some(expr) = argument.as_expression() else {
+                continue;
+            };
+            match expr.get_inner_expression() {
+                Expression::ArrowFunctionExpression(arrow) => {
+                    finder.visit_function_body(&arrow.body);
+                }
+                Expression::FunctionExpression(func) => {
+                    if let Some(body) = &func.body {
+                        finder.visit_function_body(body);
+                    }
+                }
+                expr => finder.visit_expression(expr),
+            }
+        }
+
+        finder
+            .spans
+            .into_iter()
+            .map(|span| {
+                Diagnostic::warning(
+                    Self::NAME,
+                    span,
+                    format!("`{}` stores JSX elements, which are DOM nodes created only once.", name),
+                )
+                .with_help("Keep plain data in state and turn it into JSX where it is rendered, e.g. with `<For>`.")
+            })
+            .collect()
+    }
+}
+
+/// Outermost JSX in an expression, outside nested functions
+#[derive(Default)]
+struct JsxFinder {
+    spans: Vec<Span>,
+}
+
+impl<'a> Visit<'a> for JsxFinder {
+    fn visit_jsx_element(&mut self, element: &JSXElement<'a>) {
+        self.spans.push(element.span);
+    }
+
+    fn visit_jsx_fragment(&mut self, fragment: &JSXFragment<'a>) {
+        self.spans.push(fragment.span);
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Statement;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn stored_jsx(source: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::CallExpression(call) = &stmt.expression else {
+            panic!("expected call");
+        };
+        NoUnstableJsxInSignal::new()
+            .check(call)
+            .into_iter()
+            .map(|d| d.start.to_string() + ":" + &d.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(NoUnstableJsxInSignal::NAME, "no-unstable-jsx-in-signal");
+    }
+
+    #[test]
+    fn test_jsx_in_state() {
+        assert_eq!(
+            stored_jsx("setItems([...items(), <li>{text()}</li>])"),
+            ["22:`setItems` stores JSX elements, which are DOM nodes created only once."]
+        );
+        assert_eq!(
+            stored_jsx("setToasts((prev) => [...prev, <><Toast /></>])"),
+            ["30:`setToasts` stores JSX elements, which are DOM nodes created only once."]
+        );
+        assert_eq!(
+            stored_jsx("createSignal(cond ? <Spinner /> : null)"),
+            ["20:`createSignal` stores JSX elements, which are DOM nodes created only once."]
+        );
+    }
+
+    #[test]
+    fn test_deferred_jsx() {
+        assert!(stored_jsx("setItems([...items(), { id, render: () => <li /> }])").is_empty());
+        assert!(stored_jsx("setRenderer(() => () => <li />)").is_empty());
+        assert!(stored_jsx("setTimeout(() => <li />)").is_empty());
+        assert!(stored_jsx("createSignal([], { equals: () => <b /> })").is_empty());
+    }
+}
@@ -0,0 +1,201 @@
+//! solid/no-unused-signals
+//!
+//! Report `createSignal` getters that are never read and setters that are
+//! never used. A signal nobody reads is dead reactive state, and one nobody
+//! writes is just a constant.
+
+use oxc_ast::ast::{BindingIdentifier, BindingPattern, Expression, VariableDeclarator};
+use oxc_ast::AstKind;
+use oxc_semantic::{NodeId, Semantic, SymbolId};
+
+use crate::diagnostic::Diagnostic;
+use crate::{RuleCategory, RuleMeta};
+
+/// no-unused-signals rule
+#[derive(Debug, Clone, Default)]
+pub struct NoUnusedSignals;
+
+impl RuleMeta for NoUnusedSignals {
+    const NAME: &'static str = "no-unused-signals";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Report `createSignal` getters that are never read and setters that are never \
+        used. A signal nobody reads is dead reactive state, and one nobody writes is \
+        just a constant.";
+}
+
+impl NoUnusedSignals {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check every `const [getter, setter] = createSignal(...)` in the program
+    pub fn check(&self, semantic: &Semantic) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for node in semantic.nodes().iter() {
+            let AstKind::VariableDeclarator(declarator) = node.kind() else {
+                continue;
+            };
+            if !is_create_signal(declarator) || is_exported(semantic, node.id()) {
+                continue;
+            }
+            let BindingPattern::ArrayPattern(array) = &declarator.id else {
+                continue;
+            };
+
+            let getter = array
+                .elements
+                .first()
+                .and_then(|e| e.as_ref())
+                .and_then(binding);
+            let setter = array
+                .elements
+                .get(1)
+                .and_then(|e| e.as_ref())
+                .and_then(binding);
+            let getter_unused = getter.filter(|(_, symbol)| !is_read(semantic, *symbol));
+            let setter_unused = setter.filter(|(_, symbol)| !is_read(semantic, *symbol));
+
+            match (getter_unused, setter_unused) {
+                (Some((getter, _)), Some((setter, _))) => diagnostics.push(
+                    Diagnostic::warning(
+                        Self::NAME,
+                        array.span,
+                        format!(
+                            "The signal `{}` and its setter `{}` are never used.",
+                            getter.name, setter.name
+                        ),
+                    )
+                    .with_help("Remove the signal."),
+                ),
+                (Some((getter, _)), None) => diagnostics.push(
+                    Diagnostic::warning(
+                        Self::NAME,
+                        getter.span,
+                        format!("The signal `{}` is never read.", getter.name),
+                    )
+                    .with_help(
+                        "Writes to a signal nobody reads have no effect; remove the signal and its updates.",
+                    ),
+                ),
+                (None, Some((setter, _))) => diagnostics.push(
+                    Diagnostic::warning(
+                        Self::NAME,
+                        setter.span,
+                        format!("The signal setter `{}` is never used.", setter.name),
+                    )
+                    .with_help(
+                        "A signal that is never updated is a constant; use a plain value instead.",
+                    ),
+                ),
+                (None, None) => {}
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// `createSignal(...)`, possibly wrapped in parentheses or a type assertion
+fn is_create_signal(declarator: &VariableDeclarator) -> bool {
+    let Some(Expression::CallExpression(call)) = declarator
+        .init
+        .as_ref()
+        .map(|init| init.get_inner_expression())
+    else {
+        return false;
+    };
+    matches!(&call.callee, Expression::Identifier(callee) if callee.name == "createSignal")
+}
+
+/// The identifier and symbol of a plain, non-underscored binding
+fn binding<'b, 'a>(
+    pattern: &'b BindingPattern<'a>,
+) -> Option<(&'b BindingIdentifier<'a>, SymbolId)> {
+    let BindingPattern::BindingIdentifier(ident) = pattern else {
+        return None;
+    };
+    if ident.name.starts_with('_') {
+        return None;
+    }
+    Some((ident.as_ref(), ident.symbol_id.get()?))
+}
+
+fn is_read(semantic: &Semantic, symbol: SymbolId) -> bool {
+    semantic
+        .scoping()
+        .get_resolved_references(symbol)
+        .any(|reference| reference.is_read())
+}
+
+fn is_exported(semantic: &Semantic, node: NodeId) -> bool {
+    semantic
+        .nodes()
+        .ancestor_kinds(node)
+        .any(|kind| matches!(kind, AstKind::ExportNamedDeclaration(_)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    fn messages(source: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).parse();
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        NoUnusedSignals::new()
+            .check(&semantic)
+            .into_iter()
+            .map(|d| d.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(NoUnusedSignals::NAME, "no-unused-signals");
+    }
+
+    #[test]
+    fn test_unused_parts() {
+        let messages = messages(
+            r#"
+            function App() {
+                const [count, setCount] = createSignal(0);
+                const [name, setName] = createSignal("");
+                const [open, setOpen] = createSignal(false);
+                setName("a");
+                return <div>{open()}</div>;
+            }
+            "#,
+        );
+        assert_eq!(
+            messages,
+            [
+                "The signal `count` and its setter `setCount` are never used.",
+                "The signal `name` is never read.",
+                "The signal setter `setOpen` is never used.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_used_signals() {
+        let messages = messages(
+            r#"
+            export const [theme, setTheme] = createSignal("dark");
+            function App() {
+                const [count, setCount] = createSignal(0);
+                const [value] = createSignal(1);
+                const [_a, _b] = createSignal(2);
+                return <Counter count={count()} onInc={setCount} value={value()} />;
+            }
+            "#,
+        );
+        assert!(messages.is_empty(), "{:?}", messages);
+    }
+}
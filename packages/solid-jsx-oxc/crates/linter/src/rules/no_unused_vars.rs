@@ -0,0 +1,158 @@
+//! solid/no-unused-vars
+//!
+//! Report variables, functions and imports that are never used, counting uses
+//! that only exist in JSX (`<Foo />` tags and `use:directive` attributes) as
+//! collected by `jsx-uses-vars`.
+//!
+//! `use:tooltip` is a plain JSX name rather than an identifier reference, so a
+//! generic unused-variable check would flag the `tooltip` import. This rule
+//! exists for setups that don't run another linter's `no-unused-vars`.
+
+use oxc_ast::AstKind;
+use oxc_semantic::{Semantic, SymbolFlags, SymbolId};
+use rustc_hash::FxHashSet;
+
+use crate::diagnostic::Diagnostic;
+use crate::{RuleCategory, RuleMeta};
+
+/// no-unused-vars rule
+#[derive(Debug, Clone, Default)]
+pub struct NoUnusedVars;
+
+impl RuleMeta for NoUnusedVars {
+    const NAME: &'static str = "no-unused-vars";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Report variables, functions and imports that are never used, counting uses that \
+        only exist in JSX (`<Foo />` tags and `use:directive` attributes) as collected \
+        by `jsx-uses-vars`.";
+    const DEFAULT_ENABLED: bool = false;
+}
+
+impl NoUnusedVars {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check every declared symbol, treating `jsx_used` symbols as used
+    pub fn check(&self, semantic: &Semantic, jsx_used: &FxHashSet<SymbolId>) -> Vec<Diagnostic> {
+        let scoping = semantic.scoping();
+        let mut diagnostics = Vec::new();
+
+        for symbol_id in scoping.symbol_ids() {
+            let flags = scoping.symbol_flags(symbol_id);
+            if !flags.intersects(
+                SymbolFlags::Variable
+                    | SymbolFlags::Function
+                    | SymbolFlags::Class
+                    | SymbolFlags::Import,
+            ) {
+                continue;
+            }
+
+            let name = scoping.symbol_name(symbol_id);
+            if name.starts_with('_')
+                || jsx_used.contains(&symbol_id)
+                || !scoping.get_resolved_reference_ids(symbol_id).is_empty()
+                || !is_checked_declaration(semantic, symbol_id)
+            {
+                continue;
+            }
+
+            let kind = if flags.intersects(SymbolFlags::Import) {
+                "imported"
+            } else {
+                "declared"
+            };
+            diagnostics.push(Diagnostic::warning(
+                Self::NAME,
+                scoping.symbol_span(symbol_id),
+                format!("'{}' is {} but never used.", name, kind),
+            ));
+        }
+
+        diagnostics.sort_by_key(|d| d.start);
+        diagnostics
+    }
+}
+
+/// Skip parameters, names of function/class expressions and exported declarations
+fn is_checked_declaration(semantic: &Semantic, symbol_id: SymbolId) -> bool {
+    let nodes = semantic.nodes();
+    let declaration = semantic.scoping().symbol_declaration(symbol_id);
+
+    match nodes.kind(declaration) {
+        AstKind::FormalParameter(_) | AstKind::FormalParameters(_) | AstKind::CatchParameter(_) => {
+            return false;
+        }
+        AstKind::Function(func) if !func.is_declaration() => return false,
+        AstKind::Class(class) if !class.is_declaration() => return false,
+        _ => {}
+    }
+
+    for kind in nodes.ancestor_kinds(declaration) {
+        match kind {
+            AstKind::ExportNamedDeclaration(_) | AstKind::ExportDefaultDeclaration(_) => {
+                return false;
+            }
+            AstKind::FormalParameter(_) | AstKind::CatchParameter(_) => return false,
+            AstKind::Function(_)
+            | AstKind::ArrowFunctionExpression(_)
+            | AstKind::Class(_)
+            | AstKind::Program(_) => break,
+            _ => {}
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    fn unused(source: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).parse();
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        NoUnusedVars::new()
+            .check(&semantic, &FxHashSet::default())
+            .into_iter()
+            .map(|d| d.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(NoUnusedVars::NAME, "no-unused-vars");
+    }
+
+    #[test]
+    fn test_reports_unused_bindings() {
+        let messages = unused(
+            r#"
+            import { createSignal, Show } from "solid-js";
+            const unused = 1;
+            function helper() {}
+            const Button = () => <button />;
+            export function App(props, _ignored) {
+                try {} catch (e) {}
+                const f = function named() {};
+                return <Button onClick={f} />;
+            }
+            "#,
+        );
+        assert_eq!(
+            messages,
+            [
+                "'createSignal' is imported but never used.",
+                "'Show' is imported but never used.",
+                "'unused' is declared but never used.",
+                "'helper' is declared but never used.",
+            ]
+        );
+    }
+}
@@ -2,21 +2,28 @@
 //!
 //! Enforce using the classlist prop over importing a classnames helper.
 //! The classlist prop accepts an object `{ [class: string]: boolean }` just like classnames.
+//!
+//! Deprecated in eslint-plugin-solid and disabled in the default `RulesConfig`;
+//! enable it with `RulesConfig::with_prefer_classlist`.
 
 use oxc_ast::ast::{
     Argument, Expression, JSXAttributeName, JSXAttributeValue, JSXOpeningElement,
 };
 use oxc_span::Span;
 
+use serde::{Deserialize, Serialize};
+
+use crate::context::jsx_container_expression;
 use crate::diagnostic::{Diagnostic, Fix};
 use crate::utils::has_attribute;
-use crate::{RuleCategory, RuleMeta};
+use crate::{ConfigurableRule, RuleCategory, RuleMeta};
 
 /// Default classnames helper function names
 const DEFAULT_CLASSNAMES: &[&str] = &["cn", "clsx", "classnames"];
 
 /// prefer-classlist rule
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
 pub struct PreferClasslist {
     /// Names to treat as classnames functions
     pub classnames: Vec<String>,
@@ -33,6 +40,20 @@ impl Default for PreferClasslist {
 impl RuleMeta for PreferClasslist {
     const NAME: &'static str = "prefer-classlist";
     const CATEGORY: RuleCategory = RuleCategory::Style;
+    const DESCRIPTION: &'static str = "\
+        Enforce using the classlist prop over importing a classnames helper. The \
+        classlist prop accepts an object `{ [class: string]: boolean }` just like \
+        classnames.";
+    const FIXABLE: bool = true;
+    const DEFAULT_ENABLED: bool = false;
+}
+
+impl ConfigurableRule for PreferClasslist {
+    type Options = Self;
+
+    fn from_options(options: Self) -> Self {
+        options
+    }
 }
 
 impl PreferClasslist {
@@ -68,7 +89,7 @@ impl PreferClasslist {
 
                 // Check for expression container with classnames call
                 if let Some(JSXAttributeValue::ExpressionContainer(container)) = &jsx_attr.value {
-                    if let Some(expr) = container.expression.as_expression() {
+                    if let Some(expr) = jsx_container_expression(container) {
                         if let Some((callee_name, object_span)) =
                             self.get_classnames_call_info(expr)
                         {
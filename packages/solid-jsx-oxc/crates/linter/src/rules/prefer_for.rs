@@ -1,13 +1,19 @@
 //! solid/prefer-for
 //!
 //! Enforce using Solid's `<For />` component for mapping an array to JSX elements.
+//!
+//! `<For />` hands the callback its index as a signal, so the fix also rewrites
+//! reads of the index parameter (`i` -> `i()`).
 
 use oxc_ast::ast::{
-    Argument, CallExpression, ChainElement, Expression, JSXChild, JSXElement,
-    JSXExpressionContainer, JSXFragment, MemberExpression,
+    BindingIdentifier, BindingPattern, CallExpression, ChainElement, Expression, FormalParameter,
+    FormalParameters, FunctionBody, IdentifierReference, JSXChild, JSXElement,
+    JSXExpressionContainer, JSXFragment, MemberExpression, ObjectProperty, SimpleAssignmentTarget,
 };
+use oxc_ast_visit::{walk, Visit};
 use oxc_span::{GetSpan, Span};
 
+use crate::context::jsx_container_expression;
 use crate::diagnostic::{Diagnostic, Fix};
 use crate::{RuleCategory, RuleMeta};
 
@@ -18,6 +24,9 @@ pub struct PreferFor;
 impl RuleMeta for PreferFor {
     const NAME: &'static str = "prefer-for";
     const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Enforce using Solid's `<For />` component for mapping an array to JSX elements.";
+    const FIXABLE: bool = true;
 }
 
 impl PreferFor {
@@ -38,8 +47,8 @@ impl PreferFor {
 
         let mut diagnostics = Vec::new();
 
-        // Get the expression (handle ChainExpression)
-        let expr = match container.expression.as_expression() {
+        // Get the expression (handle ChainExpression and TypeScript wrappers)
+        let expr = match jsx_container_expression(container) {
             Some(e) => e,
             None => return diagnostics,
         };
@@ -56,50 +65,74 @@ impl PreferFor {
             _ => return diagnostics,
         };
 
-        // Check if it's a .map() call
-        if let Some((array_span, map_fn_span, param_count)) = self.analyze_map_call(call_expr) {
-            if param_count == 1 {
-                // Only one param (no index), can safely use <For />
-                diagnostics.push(
-                    Diagnostic::warning(
-                        Self::NAME,
-                        call_expr.span,
-                        "Use Solid's `<For />` component for efficiently rendering lists. Array#map causes DOM elements to be recreated.",
-                    )
-                    .with_fix(
-                        Fix::new(
-                            Span::new(container_span.start, array_span.start),
-                            "<For each={",
-                        )
-                        .with_message("Convert to <For /> component"),
+        let Some(map_call) = self.analyze_map_call(call_expr) else {
+            return diagnostics;
+        };
+
+        let Some(params) = map_call.params else {
+            // `items.map(renderItem)`: the callback's arity is unknown, so an
+            // index parameter can't be ruled out
+            diagnostics.push(Diagnostic::warning(
+                Self::NAME,
+                call_expr.span,
+                "Use Solid's `<For />` component for efficiently rendering lists. Array#map causes DOM elements to be recreated.",
+            ));
+            return diagnostics;
+        };
+
+        if params.items.len() > 2 {
+            // The array parameter has no `<For />` equivalent
+            diagnostics.push(
+                Diagnostic::warning(
+                    Self::NAME,
+                    call_expr.span,
+                    "Use Solid's `<For />` component or `<Index />` component for rendering lists. Array#map causes DOM elements to be recreated.",
+                ),
+            );
+            return diagnostics;
+        }
+
+        let mut diagnostic = Diagnostic::warning(
+            Self::NAME,
+            call_expr.span,
+            "Use Solid's `<For />` component for efficiently rendering lists. Array#map causes DOM elements to be recreated.",
+        );
+
+        // `<For />` passes the index as an accessor, so its uses become calls
+        let index_fixes = match params.items.get(1) {
+            Some(index) => accessor_fixes(index, map_call.body),
+            None => Some(Vec::new()),
+        };
+
+        if let Some(index_fixes) = index_fixes {
+            diagnostic = diagnostic
+                .with_fix(
+                    Fix::new(
+                        Span::new(container_span.start, map_call.array_span.start),
+                        "<For each={",
                     )
-                    .with_fix(
-                        Fix::new(
-                            Span::new(array_span.end, map_fn_span.start),
-                            "}>{",
-                        )
-                        .with_message(""),
+                    .with_message("Convert to <For /> component"),
+                )
+                .with_fix(
+                    Fix::new(
+                        Span::new(map_call.array_span.end, map_call.callback_span.start),
+                        "}>{",
                     )
-                    .with_fix(
-                        Fix::new(
-                            Span::new(map_fn_span.end, container_span.end),
-                            "}</For>",
-                        )
-                        .with_message(""),
-                    ),
-                );
-            } else if param_count >= 2 {
-                // Has index param, could be <For /> or <Index />
-                diagnostics.push(
-                    Diagnostic::warning(
-                        Self::NAME,
-                        call_expr.span,
-                        "Use Solid's `<For />` component or `<Index />` component for rendering lists. Array#map causes DOM elements to be recreated.",
-                    ),
+                    .with_message(""),
                 );
+            for fix in index_fixes {
+                diagnostic = diagnostic.with_fix(fix);
             }
+            diagnostic = diagnostic.with_fix(
+                Fix::new(
+                    Span::new(map_call.callback_span.end, container_span.end),
+                    "}</For>",
+                )
+                .with_message(""),
+            );
         }
 
+        diagnostics.push(diagnostic);
         diagnostics
     }
 
@@ -138,26 +171,27 @@ impl PreferFor {
     }
 
     /// Analyze a call expression to see if it's arr.map(fn)
-    /// Returns (array_span, map_fn_span, param_count) if it is
-    fn analyze_map_call<'a>(
-        &self,
-        call: &'a CallExpression<'a>,
-    ) -> Option<(Span, Span, usize)> {
+    pub(crate) fn analyze_map_call<'b, 'a>(&self, call: &'b CallExpression<'a>) -> Option<MapCall<'b, 'a>> {
+        // `items.map?.(fn)` may not be an array at all
+        if call.optional {
+            return None;
+        }
+
         // Check it's a member expression call like arr.map(...)
-        let member = call.callee.as_member_expression()?;
+        let member = call.callee.get_inner_expression().as_member_expression()?;
 
         // Check the property is "map"
         let prop_name = match member {
             MemberExpression::StaticMemberExpression(static_member) => {
                 static_member.property.name.as_str()
             }
-            MemberExpression::ComputedMemberExpression(computed) => {
-                if let Expression::StringLiteral(lit) = &computed.expression {
-                    lit.value.as_str()
-                } else {
-                    return None;
+            MemberExpression::ComputedMemberExpression(computed) => match &computed.expression {
+                Expression::StringLiteral(lit) => lit.value.as_str(),
+                Expression::TemplateLiteral(lit) if lit.expressions.is_empty() => {
+                    lit.quasis.first()?.value.cooked.as_ref()?.as_str()
                 }
-            }
+                _ => return None,
+            },
             _ => return None,
         };
 
@@ -165,46 +199,201 @@ impl PreferFor {
             return None;
         }
 
+        // `super.map(...)` is a class calling its own method, not a list
+        if matches!(member.object(), Expression::Super(_)) {
+            return None;
+        }
+
         // Check there's exactly one argument (no thisArg)
         if call.arguments.len() != 1 {
             return None;
         }
 
-        let map_fn = match &call.arguments[0] {
-            Argument::SpreadElement(_) => return None,
-            arg => arg.to_expression(),
-        };
+        let map_fn = call.arguments[0].as_expression()?;
 
-        // Check the argument is a function and get param count
-        let param_count = match map_fn {
-            Expression::ArrowFunctionExpression(arrow) => {
-                if arrow.params.rest.is_some() {
-                    return None; // Rest params, can't determine count
-                }
-                arrow.params.items.len()
-            }
+        // Check the argument is a function and get its params
+        let (params, body) = match map_fn.get_inner_expression() {
+            Expression::ArrowFunctionExpression(arrow) => (Some(&*arrow.params), Some(&*arrow.body)),
             Expression::FunctionExpression(func) => {
-                if func.params.rest.is_some() {
+                if func.generator {
                     return None;
                 }
-                func.params.items.len()
+                (Some(&*func.params), func.body.as_deref())
             }
+            Expression::Identifier(_) | Expression::StaticMemberExpression(_) => (None, None),
             _ => return None,
         };
 
-        let array_span = member.object().span();
-        let map_fn_span = map_fn.span();
+        if params.is_some_and(|params| params.rest.is_some()) {
+            return None; // Rest params, can't determine count
+        }
 
-        Some((array_span, map_fn_span, param_count))
+        Some(MapCall {
+            array_span: member.object().span(),
+            callback_span: map_fn.span(),
+            params,
+            body,
+        })
+    }
+}
+
+/// An `array.map(callback)` call found in JSX
+pub(crate) struct MapCall<'b, 'a> {
+    pub array_span: Span,
+    pub callback_span: Span,
+    /// `None` when the callback is a reference rather than an inline function
+    pub params: Option<&'b FormalParameters<'a>>,
+    pub body: Option<&'b FunctionBody<'a>>,
+}
+
+/// Fixes turning every read of a callback parameter into a call (`index` -> `index()`).
+/// Returns `None` when the parameter is destructured, defaulted, reassigned or shadowed.
+pub(crate) fn accessor_fixes(index: &FormalParameter, body: Option<&FunctionBody>) -> Option<Vec<Fix>> {
+    if index.initializer.is_some() {
+        return None;
+    }
+    let BindingPattern::BindingIdentifier(ident) = &index.pattern else {
+        return None;
+    };
+    let mut uses = IndexUses {
+        name: ident.name.as_str(),
+        fixes: Vec::new(),
+        blocked: false,
+    };
+    if let Some(body) = body {
+        uses.visit_function_body(body);
+    }
+    (!uses.blocked).then_some(uses.fixes)
+}
+
+struct IndexUses<'n> {
+    name: &'n str,
+    fixes: Vec<Fix>,
+    blocked: bool,
+}
+
+impl<'a> Visit<'a> for IndexUses<'_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if ident.name == self.name {
+            self.fixes
+                .push(Fix::new(Span::new(ident.span.end, ident.span.end), "()"));
+        }
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        if ident.name == self.name {
+            self.blocked = true;
+        }
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &SimpleAssignmentTarget<'a>) {
+        if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = target {
+            if ident.name == self.name {
+                self.blocked = true;
+                return;
+            }
+        }
+        walk::walk_simple_assignment_target(self, target);
+    }
+
+    fn visit_object_property(&mut self, prop: &ObjectProperty<'a>) {
+        if prop.shorthand {
+            if let Expression::Identifier(ident) = &prop.value {
+                if ident.name == self.name {
+                    self.fixes.push(Fix::new(
+                        Span::new(ident.span.end, ident.span.end),
+                        format!(": {}()", ident.name),
+                    ));
+                    return;
+                }
+            }
+        }
+        walk::walk_object_property(self, prop);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::apply_fixes;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Statement;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
 
     #[test]
     fn test_rule_name() {
         assert_eq!(PreferFor::NAME, "prefer-for");
     }
+
+    fn fixed(source: &str) -> (usize, String) {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::tsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected JSX element");
+        };
+        let diagnostics = PreferFor::new().check_element_children(element);
+        let output = apply_fixes(source, diagnostics.clone()).output;
+        (diagnostics.len(), output)
+    }
+
+    #[test]
+    fn test_single_param_fix() {
+        assert_eq!(
+            fixed("<ul>{props.items.map(item => <li>{item}</li>)}</ul>"),
+            (1, "<ul><For each={props.items}>{item => <li>{item}</li>}</For></ul>".to_string())
+        );
+        assert_eq!(
+            fixed("<ul>{items()?.map(item => <li />)}</ul>"),
+            (1, "<ul><For each={items()}>{item => <li />}</For></ul>".to_string())
+        );
+    }
+
+    #[test]
+    fn test_typescript_wrappers() {
+        assert_eq!(
+            fixed("<ul>{props.items.map((item: Item) => <li>{item.name}</li>)!}</ul>"),
+            (
+                1,
+                "<ul><For each={props.items}>{(item: Item) => <li>{item.name}</li>}</For></ul>"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            fixed("<ul>{(items() as Item[]).map(item => <li />) as JSX.Element[]}</ul>"),
+            (1, "<ul><For each={(items() as Item[])}>{item => <li />}</For></ul>".to_string())
+        );
+    }
+
+    #[test]
+    fn test_index_param_becomes_accessor() {
+        assert_eq!(
+            fixed("<ul>{items.map((item, i) => <li data-i={i} onClick={() => pick({ i })}>{item}</li>)}</ul>"),
+            (
+                1,
+                "<ul><For each={items}>{(item, i) => <li data-i={i()} onClick={() => pick({ i: i() })}>{item}</li>}</For></ul>"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_unfixable_maps() {
+        let source = "<ul>{items.map((item, i) => { i += 1; return <li />; })}</ul>";
+        assert_eq!(fixed(source), (1, source.to_string()));
+
+        let source = "<ul>{items.map(renderItem)}</ul>";
+        assert_eq!(fixed(source), (1, source.to_string()));
+
+        let source = "<ul>{items.map((item, i, all) => <li />)}</ul>";
+        assert_eq!(fixed(source), (1, source.to_string()));
+
+        assert_eq!(fixed("<ul>{items.map?.(item => <li />)}</ul>").0, 0);
+        assert_eq!(fixed("<ul>{items.filter(item => item)}</ul>").0, 0);
+    }
 }
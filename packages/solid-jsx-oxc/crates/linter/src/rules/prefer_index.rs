@@ -0,0 +1,412 @@
+//! solid/prefer-index
+//!
+//! Suggest `<Index />` when a list renders primitive values. `<For />` keys
+//! rows by value, so a list of strings or numbers recreates a row every time
+//! a value changes; `<Index />` keys rows by position and updates them in place.
+//!
+//! A callback counts as rendering primitives when its item is used like one
+//! (`{item}`, `` `${item}` ``, `item + 1`, `item.toUpperCase()`) and never has
+//! other properties read or is destructured. The `<For />` fix renames the
+//! tags, turns the item into an accessor and the index accessor into a number.
+
+use oxc_ast::ast::{
+    ArrayExpressionElement, BinaryExpression, BindingIdentifier, BindingPattern, CallExpression,
+    ChainElement, Expression, FormalParameters, FunctionBody, IdentifierReference,
+    JSXAttributeValue, JSXChild, JSXElement, JSXElementName, JSXExpressionContainer, JSXFragment,
+    JSXOpeningElement, MemberExpression, TemplateLiteral,
+};
+use oxc_ast_visit::{walk, Visit};
+use oxc_span::{GetSpan, Span};
+
+use crate::context::jsx_container_expression;
+use crate::diagnostic::{Diagnostic, Fix};
+use crate::rules::prefer_for::accessor_fixes;
+use crate::rules::PreferFor;
+use crate::utils::{get_attribute, is_component};
+use crate::{RuleCategory, RuleMeta};
+
+/// Properties and methods of strings and numbers
+const PRIMITIVE_MEMBERS: &[&str] = &[
+    "length",
+    "toString",
+    "toUpperCase",
+    "toLowerCase",
+    "trim",
+    "padStart",
+    "padEnd",
+    "slice",
+    "split",
+    "includes",
+    "startsWith",
+    "endsWith",
+    "replace",
+    "charAt",
+    "toFixed",
+    "toLocaleString",
+];
+
+/// prefer-index rule
+#[derive(Debug, Clone, Default)]
+pub struct PreferIndex;
+
+impl RuleMeta for PreferIndex {
+    const NAME: &'static str = "prefer-index";
+    const CATEGORY: RuleCategory = RuleCategory::Style;
+    const DESCRIPTION: &'static str = "\
+        Suggest `<Index />` when a list renders primitive values. `<For />` keys rows by \
+        value, so a list of strings or numbers recreates a row every time a value \
+        changes; `<Index />` keys rows by position and updates them in place.";
+    const FIXABLE: bool = true;
+    const DEFAULT_ENABLED: bool = false;
+}
+
+impl PreferIndex {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check a `<For />` element, and `.map` calls among an element's children
+    pub fn check_element<'a>(&self, element: &JSXElement<'a>) -> Vec<Diagnostic> {
+        let mut diagnostics: Vec<_> = self.check_for(element).into_iter().collect();
+        diagnostics.extend(self.check_map_children(&element.children));
+        diagnostics
+    }
+
+    /// Check `.map` calls among a fragment's children
+    pub fn check_fragment_children<'a>(&self, fragment: &JSXFragment<'a>) -> Vec<Diagnostic> {
+        self.check_map_children(&fragment.children)
+    }
+
+    fn check_for<'a>(&self, element: &JSXElement<'a>) -> Option<Diagnostic> {
+        let opening = &element.opening_element;
+        let JSXElementName::IdentifierReference(tag) = &opening.name else {
+            return None;
+        };
+        if tag.name != "For" {
+            return None;
+        }
+
+        let callback = element.children.iter().find_map(|child| match child {
+            JSXChild::ExpressionContainer(container) => jsx_container_expression(container),
+            _ => None,
+        })?;
+        let (params, body) = match callback {
+            Expression::ArrowFunctionExpression(arrow) => (&*arrow.params, &*arrow.body),
+            Expression::FunctionExpression(func) => (&*func.params, func.body.as_deref()?),
+            _ => return None,
+        };
+
+        if !each_is_primitive_literal(opening) && !renders_primitives(params, body) {
+            return None;
+        }
+
+        let mut diagnostic = Diagnostic::warning(
+            Self::NAME,
+            opening.span,
+            "This `<For />` renders primitive values; use `<Index />` so rows are keyed by position.",
+        )
+        .with_help("With `<Index />` the item is an accessor (`item()`) and the index is a number.");
+
+        if let Some(fixes) = index_fixes(element, params, body) {
+            diagnostic.fixes = fixes;
+        }
+        Some(diagnostic)
+    }
+
+    fn check_map_children<'a>(&self, children: &[JSXChild<'a>]) -> Vec<Diagnostic> {
+        children
+            .iter()
+            .filter_map(|child| {
+                let JSXChild::ExpressionContainer(container) = child else {
+                    return None;
+                };
+                self.check_map(container)
+            })
+            .collect()
+    }
+
+    fn check_map<'a>(&self, container: &JSXExpressionContainer<'a>) -> Option<Diagnostic> {
+        let call = match jsx_container_expression(container)? {
+            Expression::CallExpression(call) => call.as_ref(),
+            Expression::ChainExpression(chain) => match &chain.expression {
+                ChainElement::CallExpression(call) => call.as_ref(),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let map_call = PreferFor::new().analyze_map_call(call)?;
+        let (Some(params), Some(body)) = (map_call.params, map_call.body) else {
+            return None;
+        };
+        if params.items.len() > 2 || !renders_primitives(params, body) {
+            return None;
+        }
+
+        Some(
+            Diagnostic::warning(
+                Self::NAME,
+                call.span,
+                "This list renders primitive values; use Solid's `<Index />` component.",
+            )
+            .with_help(
+                "With `<Index />` the item is an accessor (`item()`) and the index is a number.",
+            ),
+        )
+    }
+}
+
+/// `each={["a", "b"]}` or `each={[1, 2, 3]}`
+fn each_is_primitive_literal(opening: &JSXOpeningElement) -> bool {
+    let Some(each) = get_attribute(opening, "each") else {
+        return false;
+    };
+    let Some(JSXAttributeValue::ExpressionContainer(container)) = &each.value else {
+        return false;
+    };
+    let Some(Expression::ArrayExpression(array)) = jsx_container_expression(container) else {
+        return false;
+    };
+    !array.elements.is_empty()
+        && array.elements.iter().all(|element| {
+            matches!(
+                element,
+                ArrayExpressionElement::StringLiteral(_)
+                    | ArrayExpressionElement::NumericLiteral(_)
+                    | ArrayExpressionElement::TemplateLiteral(_)
+            )
+        })
+}
+
+/// Whether the callback uses its item like a string or number
+fn renders_primitives(params: &FormalParameters, body: &FunctionBody) -> bool {
+    let Some(item) = params.items.first() else {
+        return false;
+    };
+    let (BindingPattern::BindingIdentifier(ident), None) = (&item.pattern, &item.initializer)
+    else {
+        return false;
+    };
+    let mut uses = ItemUses {
+        name: ident.name.as_str(),
+        primitive: false,
+        object: false,
+        in_component_attributes: false,
+    };
+    uses.visit_function_body(body);
+    uses.primitive && !uses.object
+}
+
+/// Rename `<For>` to `<Index>`, call the item and stop calling the index
+fn index_fixes(
+    element: &JSXElement,
+    params: &FormalParameters,
+    body: &FunctionBody,
+) -> Option<Vec<Fix>> {
+    let item = params.items.first()?;
+    let mut fixes =
+        vec![Fix::new(element.opening_element.name.span(), "Index")
+            .with_message("Convert to <Index />")];
+    if let Some(closing) = &element.closing_element {
+        fixes.push(Fix::new(closing.name.span(), "Index").with_message(""));
+    }
+    fixes.extend(accessor_fixes(item, Some(body))?);
+
+    if let Some(index) = params.items.get(1) {
+        let BindingPattern::BindingIdentifier(ident) = &index.pattern else {
+            return None;
+        };
+        let mut calls = IndexCalls {
+            name: ident.name.as_str(),
+            fixes: Vec::new(),
+            blocked: false,
+        };
+        calls.visit_function_body(body);
+        if calls.blocked {
+            return None;
+        }
+        fixes.extend(calls.fixes);
+    }
+
+    for fix in fixes.iter_mut().skip(1) {
+        fix.message.get_or_insert_with(String::new);
+    }
+    Some(fixes)
+}
+
+struct ItemUses<'n> {
+    name: &'n str,
+    /// Used like a string or number somewhere
+    primitive: bool,
+    /// Used like an object, or shadowed so uses can't be attributed
+    object: bool,
+    in_component_attributes: bool,
+}
+
+impl ItemUses<'_> {
+    fn is_item(&self, expr: &Expression) -> bool {
+        matches!(expr.get_inner_expression(), Expression::Identifier(ident) if ident.name == self.name)
+    }
+}
+
+impl<'a> Visit<'a> for ItemUses<'_> {
+    fn visit_member_expression(&mut self, member: &MemberExpression<'a>) {
+        if self.is_item(member.object()) {
+            match member.static_property_name() {
+                Some(name) if PRIMITIVE_MEMBERS.contains(&name) => self.primitive = true,
+                _ => self.object = true,
+            }
+        }
+        walk::walk_member_expression(self, member);
+    }
+
+    fn visit_jsx_opening_element(&mut self, opening: &JSXOpeningElement<'a>) {
+        let outer = self.in_component_attributes;
+        self.in_component_attributes = is_component(opening);
+        walk::walk_jsx_opening_element(self, opening);
+        self.in_component_attributes = outer;
+    }
+
+    fn visit_jsx_expression_container(&mut self, container: &JSXExpressionContainer<'a>) {
+        // A component prop may well expect an object
+        if !self.in_component_attributes
+            && container
+                .expression
+                .as_expression()
+                .is_some_and(|e| self.is_item(e))
+        {
+            self.primitive = true;
+        }
+        walk::walk_jsx_expression_container(self, container);
+    }
+
+    fn visit_template_literal(&mut self, literal: &TemplateLiteral<'a>) {
+        if literal.expressions.iter().any(|e| self.is_item(e)) {
+            self.primitive = true;
+        }
+        walk::walk_template_literal(self, literal);
+    }
+
+    fn visit_binary_expression(&mut self, expr: &BinaryExpression<'a>) {
+        if self.is_item(&expr.left) || self.is_item(&expr.right) {
+            self.primitive = true;
+        }
+        walk::walk_binary_expression(self, expr);
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        if ident.name == self.name {
+            self.object = true;
+        }
+    }
+}
+
+/// Fixes turning `index()` into `index`; blocked by any other use
+struct IndexCalls<'n> {
+    name: &'n str,
+    fixes: Vec<Fix>,
+    blocked: bool,
+}
+
+impl<'a> Visit<'a> for IndexCalls<'_> {
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if let Expression::Identifier(callee) = &call.callee {
+            if callee.name == self.name && call.arguments.is_empty() && !call.optional {
+                self.fixes
+                    .push(Fix::new(Span::new(callee.span.end, call.span.end), ""));
+                return;
+            }
+        }
+        walk::walk_call_expression(self, call);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if ident.name == self.name {
+            self.blocked = true;
+        }
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        if ident.name == self.name {
+            self.blocked = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fix::apply_fixes;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Statement;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn check(source: &str) -> (Vec<String>, String) {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected JSX element");
+        };
+        let diagnostics = PreferIndex::new().check_element(element);
+        let output = apply_fixes(source, diagnostics.clone()).output;
+        (diagnostics.into_iter().map(|d| d.message).collect(), output)
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(PreferIndex::NAME, "prefer-index");
+    }
+
+    #[test]
+    fn test_for_with_primitives() {
+        let (messages, output) = check(
+            r#"<For each={names()}>{(name, i) => <li title={name}>{i() + 1}. {name.toUpperCase()}</li>}</For>"#,
+        );
+        assert_eq!(messages.len(), 1);
+        assert_eq!(
+            output,
+            r#"<Index each={names()}>{(name, i) => <li title={name()}>{i + 1}. {name().toUpperCase()}</li>}</Index>"#
+        );
+
+        let (messages, _) = check(r#"<For each={[1, 2, 3]}>{(n) => <Cell value={n} />}</For>"#);
+        assert_eq!(messages.len(), 1);
+    }
+
+    #[test]
+    fn test_for_with_objects() {
+        assert!(
+            check(r#"<For each={users()}>{(user) => <li>{user.name}</li>}</For>"#)
+                .0
+                .is_empty()
+        );
+        assert!(
+            check(r#"<For each={users()}>{(user) => <Row user={user} />}</For>"#)
+                .0
+                .is_empty()
+        );
+        assert!(
+            check(r#"<For each={users()}>{({ name }) => <li>{name}</li>}</For>"#)
+                .0
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_map_with_primitives() {
+        let (messages, _) = check(r#"<ul>{tags.map((tag) => <li>#{tag}</li>)}</ul>"#);
+        assert_eq!(
+            messages,
+            ["This list renders primitive values; use Solid's `<Index />` component."]
+        );
+        assert!(
+            check(r#"<ul>{todos.map((todo) => <li>{todo.text}</li>)}</ul>"#)
+                .0
+                .is_empty()
+        );
+    }
+}
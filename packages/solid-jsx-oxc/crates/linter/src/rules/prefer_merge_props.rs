@@ -0,0 +1,295 @@
+//! solid/prefer-merge-props
+//!
+//! Prefer `mergeProps` over copying props with `{ ...props }` or
+//! `Object.assign({}, props)` in a component body. Copying reads every prop
+//! once, so the copy never updates; `mergeProps` keeps the getters.
+//!
+//! Copies made inside nested functions (handlers, effects) run later and are
+//! usually deliberate snapshots, so only the component body itself is checked.
+//!
+//! The fix also imports `mergeProps` from `solid-js` when nothing at the top
+//! level binds it.
+
+use oxc_ast::ast::{
+    Argument, ArrowFunctionExpression, CallExpression, Expression, FormalParameters, Function,
+    FunctionBody, ObjectExpression, ObjectPropertyKind,
+};
+use oxc_ast_visit::{walk, Visit};
+use oxc_semantic::{Semantic, SymbolId};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::scope::ScopeFlags;
+
+use crate::diagnostic::{Diagnostic, Fix};
+use crate::utils::import_fix;
+use crate::{RuleCategory, RuleMeta};
+
+/// prefer-merge-props rule
+#[derive(Debug, Clone, Default)]
+pub struct PreferMergeProps;
+
+impl RuleMeta for PreferMergeProps {
+    const NAME: &'static str = "prefer-merge-props";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Prefer `mergeProps` over copying props with `{ ...props }` or `Object.assign({}, \
+        props)` in a component body. Copying reads every prop once, so the copy never \
+        updates; `mergeProps` keeps the getters.";
+    const FIXABLE: bool = true;
+}
+
+impl PreferMergeProps {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check a component body for copies of its props object
+    pub fn check_component<'a>(
+        &self,
+        params: &FormalParameters<'a>,
+        body: Option<&FunctionBody<'a>>,
+        semantic: &Semantic<'a>,
+        source_text: &str,
+    ) -> Vec<Diagnostic> {
+        let (Some(param), Some(body)) = (params.items.first(), body) else {
+            return Vec::new();
+        };
+        let Some(props) = param
+            .pattern
+            .get_binding_identifier()
+            .and_then(|ident| ident.symbol_id.get())
+        else {
+            return Vec::new();
+        };
+
+        let mut finder = CopyFinder {
+            semantic,
+            source_text,
+            props,
+            diagnostics: Vec::new(),
+        };
+        finder.visit_function_body(body);
+        finder.diagnostics
+    }
+}
+
+struct CopyFinder<'s, 'a> {
+    semantic: &'s Semantic<'a>,
+    source_text: &'s str,
+    props: SymbolId,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl CopyFinder<'_, '_> {
+    fn is_props(&self, expr: &Expression) -> bool {
+        let Expression::Identifier(ident) = expr.get_inner_expression() else {
+            return false;
+        };
+        ident.reference_id.get().is_some_and(|reference_id| {
+            self.semantic
+                .scoping()
+                .get_reference(reference_id)
+                .symbol_id()
+                == Some(self.props)
+        })
+    }
+
+    fn report(&mut self, span: Span, message: &str, sources: Vec<String>) {
+        let mut diagnostic = Diagnostic::warning(PreferMergeProps::NAME, span, message)
+            .with_help("`mergeProps` keeps props reactive.")
+            .with_fix(
+                Fix::new(span, format!("mergeProps({})", sources.join(", ")))
+                    .with_message("Use `mergeProps`"),
+            );
+        if let Some(fix) = import_fix(self.semantic.nodes().program(), "solid-js", "mergeProps") {
+            diagnostic = diagnostic.with_fix(fix);
+        }
+        self.diagnostics.push(diagnostic);
+    }
+
+    /// `mergeProps` sources for an object literal: spreads become sources and
+    /// runs of plain properties become object literals
+    fn object_sources(&self, object: &ObjectExpression) -> Vec<String> {
+        let mut sources = Vec::new();
+        let mut run: Vec<&str> = Vec::new();
+        for property in &object.properties {
+            match property {
+                ObjectPropertyKind::SpreadProperty(spread) => {
+                    if !run.is_empty() {
+                        sources.push(format!("{{ {} }}", run.join(", ")));
+                        run.clear();
+                    }
+                    sources.push(
+                        spread
+                            .argument
+                            .span()
+                            .source_text(self.source_text)
+                            .to_string(),
+                    );
+                }
+                ObjectPropertyKind::ObjectProperty(prop) => {
+                    run.push(prop.span.source_text(self.source_text));
+                }
+            }
+        }
+        if !run.is_empty() {
+            sources.push(format!("{{ {} }}", run.join(", ")));
+        }
+        sources
+    }
+}
+
+impl<'a> Visit<'a> for CopyFinder<'_, 'a> {
+    fn visit_object_expression(&mut self, object: &ObjectExpression<'a>) {
+        let spreads_props = object.properties.iter().any(|property| {
+            matches!(property, ObjectPropertyKind::SpreadProperty(spread) if self.is_props(&spread.argument))
+        });
+        if spreads_props {
+            let sources = self.object_sources(object);
+            self.report(
+                object.span,
+                "Spreading props into an object reads every prop once and loses reactivity.",
+                sources,
+            );
+        }
+        walk::walk_object_expression(self, object);
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        let is_object_assign = call.callee.as_member_expression().is_some_and(|member| {
+            matches!(member.object(), Expression::Identifier(object) if object.name == "Object")
+                && member.static_property_name() == Some("assign")
+        });
+        // Only a fresh `{}` target; assigning into an existing object is a different operation
+        let copies_props = is_object_assign
+            && matches!(
+                call.arguments.first(),
+                Some(Argument::ObjectExpression(target)) if target.properties.is_empty()
+            )
+            && call
+                .arguments
+                .iter()
+                .skip(1)
+                .any(|arg| arg.as_expression().is_some_and(|expr| self.is_props(expr)));
+        if copies_props {
+            let sources = call
+                .arguments
+                .iter()
+                .skip(1)
+                .map(|arg| arg.span().source_text(self.source_text).to_string())
+                .collect();
+            self.report(
+                call.span,
+                "`Object.assign` reads every prop once and loses reactivity.",
+                sources,
+            );
+        }
+        walk::walk_call_expression(self, call);
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fix::apply_fixes;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Statement;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    fn fixed(source: &str) -> String {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).parse();
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        let func = ret
+            .program
+            .body
+            .iter()
+            .find_map(|stmt| match stmt {
+                Statement::FunctionDeclaration(func) => Some(func),
+                _ => None,
+            })
+            .expect("expected function");
+        let diagnostics = PreferMergeProps::new().check_component(
+            &func.params,
+            func.body.as_deref(),
+            &semantic,
+            source,
+        );
+        apply_fixes(source, diagnostics).output
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(PreferMergeProps::NAME, "prefer-merge-props");
+    }
+
+    #[test]
+    fn test_object_spread() {
+        assert_eq!(
+            fixed(
+                r#"function Button(props) {
+                const all = { type: "button", size: 2, ...props, role: "tab" };
+                return <button {...all} />;
+            }"#
+            ),
+            r#"import { mergeProps } from "solid-js";
+function Button(props) {
+                const all = mergeProps({ type: "button", size: 2 }, props, { role: "tab" });
+                return <button {...all} />;
+            }"#
+        );
+    }
+
+    #[test]
+    fn test_object_assign() {
+        assert_eq!(
+            fixed(
+                r#"import { createSignal } from "solid-js";
+            function Button(props) {
+                const all = Object.assign({}, defaults, props);
+                Object.assign(target, props);
+                return <button {...all} />;
+            }"#
+            ),
+            r#"import { createSignal, mergeProps } from "solid-js";
+            function Button(props) {
+                const all = mergeProps(defaults, props);
+                Object.assign(target, props);
+                return <button {...all} />;
+            }"#
+        );
+    }
+
+    #[test]
+    fn test_imported_merge_props() {
+        assert_eq!(
+            fixed(
+                r#"import { mergeProps } from "solid-js";
+            function Button(props) {
+                const all = { ...props };
+                return <button {...all} />;
+            }"#
+            ),
+            r#"import { mergeProps } from "solid-js";
+            function Button(props) {
+                const all = mergeProps(props);
+                return <button {...all} />;
+            }"#
+        );
+    }
+
+    #[test]
+    fn test_deferred_copies() {
+        let source = r#"function Form(props) {
+            const onSubmit = () => send({ ...props });
+            const other = { ...defaults };
+            return <form onSubmit={onSubmit} />;
+        }"#;
+        assert_eq!(fixed(source), source);
+    }
+}
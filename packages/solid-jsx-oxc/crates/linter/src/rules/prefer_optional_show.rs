@@ -0,0 +1,307 @@
+//! solid/prefer-optional-show
+//!
+//! Report property reads on a resource value in JSX, such as `{user().name}`,
+//! that aren't guarded against the resource still loading. Until it resolves
+//! the value is `undefined` and the read throws.
+//!
+//! Reads count as guarded inside `<Show>`/`<Match>` whose `when` mentions the
+//! resource, on the right of `user() && ...`, in the consequent of
+//! `user() ? ... : ...`, and when optional chaining is used. Resources
+//! created with an `initialValue` are never `undefined` and are skipped.
+
+use oxc_ast::ast::{
+    Argument, BindingPattern, CallExpression, ConditionalExpression, Expression,
+    IdentifierReference, JSXAttributeValue, JSXElement, JSXElementName, LogicalExpression,
+    LogicalOperator, MemberExpression, ObjectPropertyKind, Program,
+};
+use oxc_ast::AstKind;
+use oxc_ast_visit::{walk, Visit};
+use oxc_semantic::{Semantic, SymbolId};
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashSet;
+
+use crate::context::jsx_container_expression;
+use crate::diagnostic::{Diagnostic, Fix};
+use crate::utils::get_attribute;
+use crate::{RuleCategory, RuleMeta};
+
+/// prefer-optional-show rule
+#[derive(Debug, Clone, Default)]
+pub struct PreferOptionalShow;
+
+impl RuleMeta for PreferOptionalShow {
+    const NAME: &'static str = "prefer-optional-show";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Report property reads on a resource value in JSX, such as `{user().name}`, that \
+        aren't guarded against the resource still loading. Until it resolves the value \
+        is `undefined` and the read throws.";
+    const FIXABLE: bool = true;
+}
+
+impl PreferOptionalShow {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check every JSX expression in the program for unguarded resource reads
+    pub fn check<'a>(&self, program: &Program<'a>, semantic: &Semantic<'a>) -> Vec<Diagnostic> {
+        let mut resources = FxHashSet::default();
+        for node in semantic.nodes().iter() {
+            let AstKind::VariableDeclarator(declarator) = node.kind() else {
+                continue;
+            };
+            let Some(Expression::CallExpression(call)) = declarator
+                .init
+                .as_ref()
+                .map(|init| init.get_inner_expression())
+            else {
+                continue;
+            };
+            let (Expression::Identifier(callee), BindingPattern::ArrayPattern(array)) =
+                (&call.callee, &declarator.id)
+            else {
+                continue;
+            };
+            if callee.name != "createResource" || has_initial_value(call) {
+                continue;
+            }
+            if let Some(Some(BindingPattern::BindingIdentifier(value))) = array.elements.first() {
+                resources.extend(value.symbol_id.get());
+            }
+        }
+        if resources.is_empty() {
+            return Vec::new();
+        }
+
+        let mut finder = UnguardedReads {
+            semantic,
+            resources,
+            guards: Vec::new(),
+            jsx_depth: 0,
+            diagnostics: Vec::new(),
+        };
+        finder.visit_program(program);
+        finder.diagnostics
+    }
+}
+
+/// Whether the options of a `createResource` call set `initialValue`, or
+/// spread something that might
+fn has_initial_value(call: &CallExpression) -> bool {
+    let Some(Argument::ObjectExpression(options)) = call.arguments.last() else {
+        return false;
+    };
+    options.properties.iter().any(|property| match property {
+        ObjectPropertyKind::ObjectProperty(property) => {
+            property.key.static_name().as_deref() == Some("initialValue")
+        }
+        ObjectPropertyKind::SpreadProperty(_) => true,
+    })
+}
+
+struct UnguardedReads<'s, 'a> {
+    semantic: &'s Semantic<'a>,
+    resources: FxHashSet<SymbolId>,
+    /// Resources known to be loaded at the current point
+    guards: Vec<SymbolId>,
+    jsx_depth: usize,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl UnguardedReads<'_, '_> {
+    fn resource_of(&self, ident: &IdentifierReference) -> Option<SymbolId> {
+        let reference = self
+            .semantic
+            .scoping()
+            .get_reference(ident.reference_id.get()?);
+        reference
+            .symbol_id()
+            .filter(|symbol| self.resources.contains(symbol))
+    }
+
+    /// Resources mentioned anywhere in a condition
+    fn mentioned(&self, expr: &Expression) -> Vec<SymbolId> {
+        struct Mentions<'r, 's, 'a> {
+            reads: &'r UnguardedReads<'s, 'a>,
+            found: Vec<SymbolId>,
+        }
+        impl<'a> Visit<'a> for Mentions<'_, '_, '_> {
+            fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+                self.found.extend(self.reads.resource_of(ident));
+            }
+        }
+        let mut mentions = Mentions {
+            reads: self,
+            found: Vec::new(),
+        };
+        mentions.visit_expression(expr);
+        mentions.found
+    }
+
+    /// Visit `expr` with the resources mentioned in `condition` treated as loaded
+    fn visit_guarded<'a>(&mut self, condition: &Expression<'a>, visit: impl FnOnce(&mut Self)) {
+        let guards = self.mentioned(condition);
+        let count = guards.len();
+        self.guards.extend(guards);
+        visit(self);
+        self.guards.truncate(self.guards.len() - count);
+    }
+}
+
+impl<'a> Visit<'a> for UnguardedReads<'_, 'a> {
+    fn visit_jsx_element(&mut self, element: &JSXElement<'a>) {
+        self.jsx_depth += 1;
+        let opening = &element.opening_element;
+        let when = match &opening.name {
+            JSXElementName::IdentifierReference(tag)
+                if matches!(tag.name.as_str(), "Show" | "Match") =>
+            {
+                get_attribute(opening, "when").and_then(|attr| match &attr.value {
+                    Some(JSXAttributeValue::ExpressionContainer(container)) => {
+                        jsx_container_expression(container)
+                    }
+                    _ => None,
+                })
+            }
+            _ => None,
+        };
+
+        match when {
+            Some(when) => {
+                self.visit_jsx_opening_element(opening);
+                self.visit_guarded(when, |this| {
+                    for child in &element.children {
+                        this.visit_jsx_child(child);
+                    }
+                });
+            }
+            None => walk::walk_jsx_element(self, element),
+        }
+        self.jsx_depth -= 1;
+    }
+
+    fn visit_logical_expression(&mut self, expr: &LogicalExpression<'a>) {
+        if expr.operator != LogicalOperator::And {
+            walk::walk_logical_expression(self, expr);
+            return;
+        }
+        self.visit_expression(&expr.left);
+        self.visit_guarded(&expr.left, |this| this.visit_expression(&expr.right));
+    }
+
+    fn visit_conditional_expression(&mut self, expr: &ConditionalExpression<'a>) {
+        self.visit_expression(&expr.test);
+        self.visit_guarded(&expr.test, |this| this.visit_expression(&expr.consequent));
+        self.visit_expression(&expr.alternate);
+    }
+
+    fn visit_member_expression(&mut self, member: &MemberExpression<'a>) {
+        if self.jsx_depth > 0 && !member.optional() {
+            if let Expression::CallExpression(call) = member.object() {
+                if let Expression::Identifier(callee) = &call.callee {
+                    let resource = self.resource_of(callee);
+                    if call.arguments.is_empty()
+                        && resource.is_some_and(|symbol| !self.guards.contains(&symbol))
+                    {
+                        let insert =
+                            if matches!(member, MemberExpression::ComputedMemberExpression(_)) {
+                                "?."
+                            } else {
+                                "?"
+                            };
+                        self.diagnostics.push(
+                            Diagnostic::warning(
+                                PreferOptionalShow::NAME,
+                                member.span(),
+                                format!(
+                                    "`{}()` is undefined until the resource loads.",
+                                    callee.name
+                                ),
+                            )
+                            .with_help(format!(
+                                "Wrap this in `<Show when={{{}()}}>`, or use optional chaining.",
+                                callee.name
+                            ))
+                            .with_fix(
+                                Fix::new(Span::new(call.span.end, call.span.end), insert)
+                                    .with_message("Use optional chaining"),
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+        walk::walk_member_expression(self, member);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fix::apply_fixes;
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    fn check(source: &str) -> (usize, String) {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).parse();
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        let diagnostics = PreferOptionalShow::new().check(&ret.program, &semantic);
+        let output = apply_fixes(source, diagnostics.clone()).output;
+        (diagnostics.len(), output)
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(PreferOptionalShow::NAME, "prefer-optional-show");
+    }
+
+    #[test]
+    fn test_unguarded_reads() {
+        let (count, output) = check(
+            r#"const [user] = createResource(fetchUser);
+const view = <p>{user().name} {user()["tags"][0]}</p>;"#,
+        );
+        assert_eq!(count, 2);
+        assert_eq!(
+            output,
+            r#"const [user] = createResource(fetchUser);
+const view = <p>{user()?.name} {user()?.["tags"][0]}</p>;"#
+        );
+    }
+
+    #[test]
+    fn test_guarded_reads() {
+        let (count, _) = check(
+            r#"const [user] = createResource(fetchUser);
+const [count] = createSignal({ n: 1 });
+const name = user().name;
+const view = (
+  <div>
+    <Show when={user()} fallback={<p>Loading</p>}>
+      <p>{user().name}</p>
+    </Show>
+    {user() && user().email}
+    {user.state === "ready" ? user().age : null}
+    {user()?.bio}
+    {count().n}
+  </div>
+);"#,
+        );
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn test_initial_value() {
+        let (count, _) = check(
+            r#"const [user] = createResource(fetchUser, { initialValue: { name: "" } });
+const [post] = createResource(id, fetchPost, { initialValue: {} });
+const [tags] = createResource(fetchTags, { deferStream: true });
+const view = <p>{user().name} {post().title} {tags().length}</p>;"#,
+        );
+        assert_eq!(count, 1);
+    }
+}
@@ -1,28 +1,77 @@
 //! solid/prefer-show
 //!
 //! Enforce using Solid's `<Show />` component for conditionally showing content.
+//!
+//! Only conditions that can change (calls and property reads such as
+//! `count()` or `props.open`) are reported: a ternary on a plain local is
+//! evaluated once either way.
+//!
+//! The rewrite is offered as a suggestion rather than a fix: `<Show>` renders
+//! nothing for falsy conditions, while `count() && <A />` renders a `0`.
 
+use common::{walk_element, walk_fragment, JsxVisitor, VisitFlow};
 use oxc_ast::ast::{
-    ConditionalExpression, Expression, JSXChild, JSXElement, JSXExpressionContainer,
-    JSXFragment, LogicalExpression,
+    ArrowFunctionExpression, CallExpression, ConditionalExpression, Expression, Function,
+    JSXChild, JSXElement, JSXExpressionContainer, JSXFragment, LogicalExpression,
+    MemberExpression,
 };
+use oxc_ast_visit::Visit;
 use oxc_span::{GetSpan, Span};
+use oxc_syntax::scope::ScopeFlags;
 
-use crate::diagnostic::{Diagnostic, Fix};
-use crate::{RuleCategory, RuleMeta};
+use serde::{Deserialize, Serialize};
+
+use crate::context::{jsx_container_expression, strip_ts_wrappers};
+use crate::diagnostic::{Diagnostic, Fix, Suggestion};
+use crate::{ConfigurableRule, RuleCategory, RuleMeta};
 
 /// prefer-show rule
-#[derive(Debug, Clone, Default)]
-pub struct PreferShow;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct PreferShow {
+    /// Minimum number of JSX elements a branch must contain to be reported
+    pub min_branch_elements: usize,
+    /// Whether to offer the `<Show />` rewrite as a suggestion
+    pub fix: bool,
+}
+
+impl Default for PreferShow {
+    fn default() -> Self {
+        Self {
+            min_branch_elements: 1,
+            fix: true,
+        }
+    }
+}
 
 impl RuleMeta for PreferShow {
     const NAME: &'static str = "prefer-show";
     const CATEGORY: RuleCategory = RuleCategory::Style;
+    const DESCRIPTION: &'static str = "\
+        Enforce using Solid's `<Show />` component for conditionally showing content.";
+}
+
+impl ConfigurableRule for PreferShow {
+    type Options = Self;
+
+    fn from_options(options: Self) -> Self {
+        options
+    }
 }
 
 impl PreferShow {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    pub fn with_min_branch_elements(mut self, min_branch_elements: usize) -> Self {
+        self.min_branch_elements = min_branch_elements;
+        self
+    }
+
+    pub fn with_fix(mut self, fix: bool) -> Self {
+        self.fix = fix;
+        self
     }
 
     /// Check a JSX expression container for conditional expressions
@@ -38,7 +87,7 @@ impl PreferShow {
 
         let mut diagnostics = Vec::new();
 
-        let expr = match container.expression.as_expression() {
+        let expr = match jsx_container_expression(container) {
             Some(e) => e,
             None => return diagnostics,
         };
@@ -51,14 +100,14 @@ impl PreferShow {
                     source,
                 ));
             }
-            Expression::ArrowFunctionExpression(arrow) => {
+            Expression::ArrowFunctionExpression(arrow)
                 // For arrow functions, check if the body is an expression (not block body)
-                if arrow.expression {
+                if arrow.expression => {
                     // Get the expression from the function body
                     if let Some(oxc_ast::ast::Statement::ExpressionStatement(expr_stmt)) =
                         arrow.body.statements.first()
                     {
-                        match &expr_stmt.expression {
+                        match strip_ts_wrappers(&expr_stmt.expression) {
                             Expression::LogicalExpression(logical) => {
                                 diagnostics.extend(self.check_logical_expression(
                                     logical,
@@ -77,7 +126,6 @@ impl PreferShow {
                         }
                     }
                 }
-            }
             Expression::ConditionalExpression(cond) => {
                 diagnostics.extend(self.check_conditional_expression(
                     cond,
@@ -138,26 +186,26 @@ impl PreferShow {
             return diagnostics;
         }
 
-        // Check if right side is "expensive"
-        if self.is_expensive_type(&logical.right) {
+        if !self.is_complex_branch(&logical.right) || !is_reactive(&logical.left) {
+            return diagnostics;
+        }
+
+        let mut diagnostic = Diagnostic::warning(
+            Self::NAME,
+            logical.span,
+            "Use Solid's `<Show />` component for conditionally showing content.",
+        );
+        if self.fix {
             let when_text = self.get_source_text(source, logical.left.span());
             let children_text = self.put_into_jsx(source, &logical.right);
-
-            diagnostics.push(
-                Diagnostic::warning(
-                    Self::NAME,
-                    logical.span,
-                    "Use Solid's `<Show />` component for conditionally showing content.",
-                )
-                .with_fix(
-                    Fix::new(
-                        replace_span,
-                        format!("<Show when={{{when_text}}}>{children_text}</Show>"),
-                    )
-                    .with_message("Convert to <Show /> component"),
-                ),
+            diagnostic = diagnostic.with_suggestion(
+                Suggestion::new("Convert to <Show /> component").with_fix(Fix::new(
+                    replace_span,
+                    format!("<Show when={{{when_text}}}>{children_text}</Show>"),
+                )),
             );
         }
+        diagnostics.push(diagnostic);
 
         diagnostics
     }
@@ -170,45 +218,57 @@ impl PreferShow {
     ) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
 
-        // Check if consequent or alternate is "expensive"
-        if self.is_expensive_type(&cond.consequent) || self.is_expensive_type(&cond.alternate) {
+        let has_complex_branch =
+            self.is_complex_branch(&cond.consequent) || self.is_complex_branch(&cond.alternate);
+        if !has_complex_branch || !is_reactive(&cond.test) {
+            return diagnostics;
+        }
+
+        let mut diagnostic = Diagnostic::warning(
+            Self::NAME,
+            cond.span,
+            "Use Solid's `<Show />` component for conditionally showing content with a fallback.",
+        );
+        if self.fix {
             let when_text = self.get_source_text(source, cond.test.span());
             let children_text = self.put_into_jsx(source, &cond.consequent);
-            let fallback_text = self.get_source_text(source, cond.alternate.span());
-
-            diagnostics.push(
-                Diagnostic::warning(
-                    Self::NAME,
-                    cond.span,
-                    "Use Solid's `<Show />` component for conditionally showing content with a fallback.",
+            let replacement = if is_empty_fallback(&cond.alternate) {
+                format!("<Show when={{{when_text}}}>{children_text}</Show>")
+            } else {
+                let fallback_text = self.get_source_text(source, cond.alternate.span());
+                format!(
+                    "<Show when={{{when_text}}} fallback={{{fallback_text}}}>{children_text}</Show>"
                 )
-                .with_fix(
-                    Fix::new(
-                        replace_span,
-                        format!(
-                            "<Show when={{{when_text}}} fallback={{{fallback_text}}}>{children_text}</Show>"
-                        ),
-                    )
-                    .with_message("Convert to <Show /> component with fallback"),
-                ),
+            };
+            diagnostic = diagnostic.with_suggestion(
+                Suggestion::new("Convert to <Show /> component with fallback")
+                    .with_fix(Fix::new(replace_span, replacement)),
             );
         }
+        diagnostics.push(diagnostic);
 
         diagnostics
     }
 
-    /// Check if expression is an "expensive" type
-    fn is_expensive_type(&self, expr: &Expression<'_>) -> bool {
-        matches!(
-            expr,
-            Expression::JSXElement(_)
-                | Expression::JSXFragment(_)
-                | Expression::Identifier(_)
-        )
+    /// A JSX branch with at least `min_branch_elements` elements
+    fn is_complex_branch(&self, expr: &Expression<'_>) -> bool {
+        let mut counter = ElementCounter(0);
+        match strip_ts_wrappers(expr) {
+            Expression::JSXElement(element) => {
+                walk_element(&mut counter, element);
+            }
+            Expression::JSXFragment(fragment) => {
+                walk_fragment(&mut counter, fragment);
+            }
+            _ => return false,
+        }
+        counter.0 >= self.min_branch_elements.max(1)
     }
 
     /// Convert expression to JSX-safe format
     fn put_into_jsx(&self, source: &str, expr: &Expression<'_>) -> String {
+        // `<div /> as JSX.Element` needs no cast as a child
+        let expr = strip_ts_wrappers(expr);
         let text = self.get_source_text(source, expr.span());
         if matches!(expr, Expression::JSXElement(_) | Expression::JSXFragment(_)) {
             text
@@ -225,12 +285,125 @@ impl PreferShow {
     }
 }
 
+/// `null`, `undefined` and `false` render nothing, so they need no fallback
+fn is_empty_fallback(expr: &Expression<'_>) -> bool {
+    match strip_ts_wrappers(expr) {
+        Expression::NullLiteral(_) => true,
+        Expression::BooleanLiteral(lit) => !lit.value,
+        Expression::Identifier(ident) => ident.name == "undefined",
+        _ => false,
+    }
+}
+
+/// Whether a condition reads something that can change: a call or a property access
+fn is_reactive(expr: &Expression<'_>) -> bool {
+    let mut finder = ReactiveReadFinder(false);
+    finder.visit_expression(expr);
+    finder.0
+}
+
+struct ReactiveReadFinder(bool);
+
+impl<'a> Visit<'a> for ReactiveReadFinder {
+    fn visit_call_expression(&mut self, _call: &CallExpression<'a>) {
+        self.0 = true;
+    }
+
+    fn visit_member_expression(&mut self, _member: &MemberExpression<'a>) {
+        self.0 = true;
+    }
+
+    // Functions in the condition aren't called by it
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+}
+
+struct ElementCounter(usize);
+
+impl<'a> JsxVisitor<'a> for ElementCounter {
+    fn enter_element(&mut self, _element: &JSXElement<'a>) -> VisitFlow {
+        self.0 += 1;
+        VisitFlow::Continue
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Statement;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
 
     #[test]
     fn test_rule_name() {
         assert_eq!(PreferShow::NAME, "prefer-show");
     }
+
+    fn check(source: &str, rule: &PreferShow) -> Vec<Diagnostic> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::tsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected JSX element");
+        };
+        rule.check_element_children(element, source)
+    }
+
+    #[test]
+    fn test_reactive_conditions() {
+        let rule = PreferShow::new();
+        let diagnostics = check("<div>{open() && <Modal />}</div>", &rule);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].suggestions[0].fixes[0].replacement,
+            "<Show when={open()}><Modal /></Show>"
+        );
+
+        let diagnostics = check("<div>{props.user ? <Profile /> : null}</div>", &rule);
+        assert_eq!(
+            diagnostics[0].suggestions[0].fixes[0].replacement,
+            "<Show when={props.user}><Profile /></Show>"
+        );
+
+        assert!(check("<div>{isAdmin && <Panel />}</div>", &rule).is_empty());
+        assert!(check("<div>{open() && label}</div>", &rule).is_empty());
+    }
+
+    #[test]
+    fn test_typescript_wrappers() {
+        let rule = PreferShow::new();
+        let diagnostics = check(
+            "<div>{open() && (<Modal /> as JSX.Element)}</div>",
+            &rule,
+        );
+        assert_eq!(
+            diagnostics[0].suggestions[0].fixes[0].replacement,
+            "<Show when={open()}><Modal /></Show>"
+        );
+
+        let diagnostics = check(
+            "<div>{(props.user! ? <Profile /> : undefined) satisfies JSX.Element}</div>",
+            &rule,
+        );
+        assert_eq!(
+            diagnostics[0].suggestions[0].fixes[0].replacement,
+            "<Show when={props.user!}><Profile /></Show>"
+        );
+    }
+
+    #[test]
+    fn test_options() {
+        let rule = PreferShow::new().with_min_branch_elements(2).with_fix(false);
+        assert!(check("<div>{open() && <Modal />}</div>", &rule).is_empty());
+
+        let diagnostics = check("<div>{open() ? <Modal><p /></Modal> : <Empty />}</div>", &rule);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].suggestions.is_empty());
+    }
 }
@@ -1,21 +1,24 @@
 //! solid/reactivity
 //!
 //! Enforce that reactive expressions (signals, memos, stores) are accessed properly.
-//! This is a complex rule that tracks signal/store reads and ensures they happen
-//! in reactive contexts.
-//!
-//! Note: This is a simplified implementation. The full ESLint version is 1200+ lines
-//! and tracks control flow, function scopes, and more.
+//! Signal, prop and store reads must happen in reactive contexts; the scope
+//! analysis behind this lives in [`tracking`].
 
 use oxc_ast::ast::{
     Argument, CallExpression, Expression, JSXAttributeItem, JSXAttributeName,
-    JSXAttributeValue, JSXExpressionContainer, JSXOpeningElement, VariableDeclarator,
+    JSXAttributeValue, JSXExpressionContainer, JSXOpeningElement, Program, VariableDeclarator,
 };
+use oxc_semantic::Semantic;
 use oxc_span::GetSpan;
 
+use crate::context::{argument_expression, jsx_container_expression};
 use crate::diagnostic::Diagnostic;
 use crate::{RuleCategory, RuleMeta};
 
+pub mod tracking;
+
+pub use tracking::TrackingAnalysis;
+
 /// reactivity rule
 #[derive(Debug, Clone, Default)]
 pub struct Reactivity;
@@ -23,6 +26,9 @@ pub struct Reactivity;
 impl RuleMeta for Reactivity {
     const NAME: &'static str = "reactivity";
     const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Enforce that reactive expressions (signals, memos, stores) are accessed \
+        properly. Signal, prop and store reads must happen in reactive contexts.";
 }
 
 /// Solid primitives that create signals
@@ -34,7 +40,7 @@ const SIGNAL_CREATORS: &[&str] = &[
 ];
 
 /// Solid primitives that expect reactive expressions as arguments
-const REACTIVE_PRIMITIVES: &[&str] = &[
+pub(crate) const REACTIVE_PRIMITIVES: &[&str] = &[
     "createEffect",
     "createMemo",
     "createComputed",
@@ -51,6 +57,16 @@ impl Reactivity {
         Self
     }
 
+    /// Run the tracking-scope analysis over a whole program
+    pub fn check_program<'a>(
+        &self,
+        semantic: &'a Semantic<'a>,
+        source_text: &'a str,
+        program: &Program<'a>,
+    ) -> Vec<Diagnostic> {
+        TrackingAnalysis::new(semantic, source_text).run(program)
+    }
+
     /// Check a variable declarator for signal/store destructuring issues
     pub fn check_variable<'a>(&self, declarator: &VariableDeclarator<'a>) -> Vec<Diagnostic> {
         let diagnostics = Vec::new();
@@ -98,14 +114,9 @@ impl Reactivity {
                 match first_arg {
                     Argument::SpreadElement(_) => {}
                     arg => {
-                        if let Some(expr) = arg.as_expression() {
+                        if let Some(expr) = argument_expression(arg) {
                             // Check if it's not a function
-                            if !matches!(
-                                expr,
-                                Expression::ArrowFunctionExpression(_)
-                                    | Expression::FunctionExpression(_)
-                                    | Expression::Identifier(_)
-                            ) {
+                            if !may_be_function(callee_name, expr) {
                                 diagnostics.push(
                                     Diagnostic::warning(
                                         Self::NAME,
@@ -169,7 +180,7 @@ impl Reactivity {
                             &jsx_attr.value
                         {
                             if let Some(Expression::CallExpression(_)) =
-                                container.expression.as_expression()
+                                jsx_container_expression(container)
                             {
                                 diagnostics.push(
                                     Diagnostic::warning(
@@ -188,23 +199,23 @@ impl Reactivity {
             // Check for event handlers that don't use functions
             if attr_name.starts_with("on") && attr_name.len() > 2 {
                 if let Some(JSXAttributeValue::ExpressionContainer(container)) = &jsx_attr.value {
-                    if let Some(expr) = container.expression.as_expression() {
-                        // Event handlers should be functions, not calls
-                        if let Expression::CallExpression(call) = expr {
-                            // Check if it's not creating a bound function
-                            if let Expression::Identifier(callee) = &call.callee {
-                                if callee.name != "bind" {
-                                    diagnostics.push(
-                                        Diagnostic::warning(
-                                            Self::NAME,
-                                            call.span,
-                                            format!(
-                                                "Event handler `{}` is calling a function. This will execute immediately. Wrap in an arrow function: `() => {}(...)`",
-                                                attr_name, callee.name
-                                            ),
+                    // Event handlers should be functions, not calls
+                    if let Some(Expression::CallExpression(call)) =
+                        jsx_container_expression(container)
+                    {
+                        // Check if it's not creating a bound function
+                        if let Expression::Identifier(callee) = &call.callee {
+                            if callee.name != "bind" {
+                                diagnostics.push(
+                                    Diagnostic::warning(
+                                        Self::NAME,
+                                        call.span,
+                                        format!(
+                                            "Event handler `{}` is calling a function. This will execute immediately. Wrap in an arrow function: `() => {}(...)`",
+                                            attr_name, callee.name
                                         ),
-                                    );
-                                }
+                                    ),
+                                );
                             }
                         }
                     }
@@ -216,6 +227,20 @@ impl Reactivity {
     }
 }
 
+/// Whether `expr`, the first argument of the reactive primitive `callee`, may
+/// be a function: a function, a reference, or a call, which may return one
+/// like `on(...)` does. `on` also takes an array of dependencies.
+pub(crate) fn may_be_function(callee: &str, expr: &Expression) -> bool {
+    match expr.get_inner_expression() {
+        Expression::ArrowFunctionExpression(_)
+        | Expression::FunctionExpression(_)
+        | Expression::Identifier(_)
+        | Expression::CallExpression(_) => true,
+        Expression::ArrayExpression(_) => callee == "on",
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
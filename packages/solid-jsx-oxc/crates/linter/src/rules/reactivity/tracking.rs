@@ -0,0 +1,776 @@
+//! Tracking-scope analysis for `solid/reactivity`
+//!
+//! Solid only re-runs code that reads a signal inside a *tracked scope*: JSX
+//! expressions and the callbacks of `createEffect`, `createMemo` and friends.
+//! A component body runs exactly once, so a signal or prop read there is a
+//! snapshot and later changes are silently lost.
+//!
+//! The analysis walks the program once with a stack of scopes. Reactive symbols
+//! (signal getters, props, stores) are identified from their declarations via
+//! `Semantic`, and the analysis reports:
+//! - reactive reads directly in a component body, or in a JSX attribute that is
+//!   only evaluated once (event handlers, refs)
+//! - calls in those same places to local functions that read reactive values
+//! - signal getters used as plain values (`count + 1` instead of `count() + 1`)
+//!
+//! Handler and ref props (`props.onClick`, `props.ref`) hold functions and may
+//! be read once, and everything passed to `on(...)` is tracked by its effect.
+
+use oxc_ast::ast::{
+    ArrowFunctionExpression, BinaryExpression, BindingIdentifier, BindingPattern, CallExpression,
+    ComputedMemberExpression, Expression, FormalParameters, Function, FunctionBody, JSXAttribute,
+    JSXAttributeName, JSXAttributeValue, JSXExpressionContainer, JSXSpreadAttribute, Program,
+    SimpleAssignmentTarget, StaticMemberExpression, TemplateLiteral, UnaryExpression,
+    UnaryOperator, VariableDeclarator,
+};
+use oxc_ast_visit::{walk, Visit};
+use oxc_semantic::{Semantic, SymbolId};
+use oxc_span::Span;
+use oxc_syntax::scope::ScopeFlags;
+use rustc_hash::FxHashMap;
+
+use super::Reactivity;
+use crate::diagnostic::Diagnostic;
+use crate::rules::NoDestructure;
+use crate::utils::is_component_name;
+use crate::RuleMeta;
+
+/// How a reactive symbol is read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReactiveKind {
+    /// Getter function, read by calling it (`count()`)
+    Signal,
+    /// Reactive object, read through property access (`props.title`, `state.items`)
+    Object,
+}
+
+/// What kind of code a scope holds, as far as tracking is concerned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScopeKind {
+    /// Module top level
+    Module,
+    /// A component body: runs once, untracked
+    Component,
+    /// Re-runs when its dependencies change (JSX expressions, effect callbacks, ...)
+    Tracked,
+    /// A JSX attribute that is evaluated once when the element is created
+    /// (event handlers, refs)
+    Once,
+    /// Any other function: runs whenever it is called
+    Function,
+    /// Reads are deliberately untracked (`untrack`, the callback of `on`)
+    Untrack,
+}
+
+struct Scope {
+    kind: ScopeKind,
+    /// The variable a function scope is bound to, if any
+    binding: Option<SymbolId>,
+    /// Whether this function reads reactive values (directly or in nested functions)
+    captures: bool,
+    /// Local functions called from this function
+    calls: Vec<SymbolId>,
+}
+
+/// Information about the function that is about to be visited, gathered
+/// by its parent node
+#[derive(Default)]
+struct PendingFunction {
+    kind: Option<ScopeKind>,
+    binding: Option<SymbolId>,
+    pascal_case: bool,
+}
+
+/// Single-pass tracking-scope analysis over a program
+pub struct TrackingAnalysis<'a> {
+    semantic: &'a Semantic<'a>,
+    source_text: &'a str,
+    reactive: FxHashMap<SymbolId, ReactiveKind>,
+    scopes: Vec<Scope>,
+    component_depth: usize,
+    pending_function: Option<PendingFunction>,
+    /// Bound functions that read reactive values, with their spans
+    reactive_functions: FxHashMap<SymbolId, Span>,
+    function_spans: FxHashMap<SymbolId, Span>,
+    function_calls: FxHashMap<SymbolId, Vec<SymbolId>>,
+    /// Calls to local functions made where reads are not tracked
+    untracked_calls: Vec<(SymbolId, Span)>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> TrackingAnalysis<'a> {
+    pub fn new(semantic: &'a Semantic<'a>, source_text: &'a str) -> Self {
+        Self {
+            semantic,
+            source_text,
+            reactive: FxHashMap::default(),
+            scopes: vec![Scope {
+                kind: ScopeKind::Module,
+                binding: None,
+                captures: false,
+                calls: Vec::new(),
+            }],
+            component_depth: 0,
+            pending_function: None,
+            reactive_functions: FxHashMap::default(),
+            function_spans: FxHashMap::default(),
+            function_calls: FxHashMap::default(),
+            untracked_calls: Vec::new(),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    pub fn run(mut self, program: &Program<'a>) -> Vec<Diagnostic> {
+        self.visit_program(program);
+        self.report_untracked_calls();
+        self.diagnostics.sort_by_key(|d| d.start);
+        self.diagnostics
+    }
+
+    fn current_kind(&self) -> ScopeKind {
+        self.scopes.last().map_or(ScopeKind::Module, |s| s.kind)
+    }
+
+    /// Whether a read at the current position happens once and is never re-run
+    fn is_untracked(&self) -> bool {
+        match self.current_kind() {
+            ScopeKind::Component => true,
+            ScopeKind::Once => self.component_depth > 0,
+            _ => false,
+        }
+    }
+
+    fn symbol_of(&self, expr: &Expression<'a>) -> Option<SymbolId> {
+        let Expression::Identifier(ident) = expr else {
+            return None;
+        };
+        let reference_id = ident.reference_id.get()?;
+        self.semantic
+            .scoping()
+            .get_reference(reference_id)
+            .symbol_id()
+    }
+
+    fn source_text_of(&self, span: Span) -> &'a str {
+        self.source_text
+            .get(span.start as usize..span.end as usize)
+            .unwrap_or_default()
+    }
+
+    fn reactive_kind(&self, expr: &Expression<'a>) -> Option<ReactiveKind> {
+        self.symbol_of(expr)
+            .and_then(|symbol| self.reactive.get(&symbol).copied())
+    }
+
+    fn push_scope(&mut self, kind: ScopeKind, binding: Option<SymbolId>) {
+        if kind == ScopeKind::Component {
+            self.component_depth += 1;
+        }
+        self.scopes.push(Scope {
+            kind,
+            binding,
+            captures: false,
+            calls: Vec::new(),
+        });
+    }
+
+    fn pop_scope(&mut self, span: Span) {
+        let Some(scope) = self.scopes.pop() else {
+            return;
+        };
+        if scope.kind == ScopeKind::Component {
+            self.component_depth -= 1;
+        }
+        if scope.kind != ScopeKind::Function {
+            return;
+        }
+        if let Some(binding) = scope.binding {
+            self.function_spans.insert(binding, span);
+            if scope.captures {
+                self.reactive_functions.insert(binding, span);
+            }
+            self.function_calls
+                .entry(binding)
+                .or_default()
+                .extend(scope.calls);
+        }
+    }
+
+    /// Mark every enclosing plain function, up to the nearest scope that
+    /// decides tracking, as capturing reactivity
+    fn mark_captures(&mut self) {
+        for scope in self.scopes.iter_mut().rev() {
+            if scope.kind != ScopeKind::Function {
+                break;
+            }
+            scope.captures = true;
+        }
+    }
+
+    fn record_call(&mut self, callee: SymbolId, span: Span) {
+        if self.is_untracked() {
+            self.untracked_calls.push((callee, span));
+        }
+        for scope in self.scopes.iter_mut().rev() {
+            if scope.kind != ScopeKind::Function {
+                break;
+            }
+            scope.calls.push(callee);
+        }
+    }
+
+    fn read(&mut self, name: &str, span: Span) {
+        self.mark_captures();
+        if !self.is_untracked() {
+            return;
+        }
+        self.diagnostics.push(
+            Diagnostic::warning(
+                Reactivity::NAME,
+                span,
+                format!(
+                    "The reactive variable '{}' should be used within JSX, a tracked scope (like createEffect), or inside an event handler function, or else changes will be ignored.",
+                    name
+                ),
+            )
+            .with_help("Read it inside JSX or a function, e.g. `() => ...`, so Solid can track it."),
+        );
+    }
+
+    /// Report a signal getter used where its value was meant
+    fn check_uncalled_signal(&mut self, expr: &Expression<'a>, place: &str) {
+        if self.reactive_kind(expr) != Some(ReactiveKind::Signal) {
+            return;
+        }
+        let Expression::Identifier(ident) = expr else {
+            return;
+        };
+        self.diagnostics.push(
+            Diagnostic::warning(
+                Reactivity::NAME,
+                ident.span,
+                format!(
+                    "The reactive variable '{}' should be called as a function when used in {}.",
+                    ident.name, place
+                ),
+            )
+            .with_help(format!("Use `{}()` to read the current value.", ident.name)),
+        );
+    }
+
+    /// Record the reactive bindings introduced by a declarator such as
+    /// `const [count, setCount] = createSignal(0)`
+    fn register_declarator(&mut self, declarator: &VariableDeclarator<'a>) {
+        let Some(Expression::CallExpression(call)) = declarator
+            .init
+            .as_ref()
+            .map(|init| init.get_inner_expression())
+        else {
+            return;
+        };
+        let Expression::Identifier(callee) = &call.callee else {
+            return;
+        };
+
+        match (callee.name.as_str(), &declarator.id) {
+            ("createSignal" | "createResource", BindingPattern::ArrayPattern(array)) => {
+                if let Some(Some(getter)) = array.elements.first() {
+                    self.register_binding(getter, ReactiveKind::Signal);
+                }
+            }
+            ("createMemo" | "createSelector" | "createDeferred" | "children", pattern) => {
+                self.register_binding(pattern, ReactiveKind::Signal);
+            }
+            ("createStore", BindingPattern::ArrayPattern(array)) => {
+                if let Some(Some(store)) = array.elements.first() {
+                    self.register_binding(store, ReactiveKind::Object);
+                }
+            }
+            ("createMutable" | "mergeProps", pattern) => {
+                self.register_binding(pattern, ReactiveKind::Object);
+            }
+            ("splitProps", BindingPattern::ArrayPattern(array)) => {
+                for element in array.elements.iter().flatten() {
+                    self.register_binding(element, ReactiveKind::Object);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn register_binding(&mut self, pattern: &BindingPattern<'a>, kind: ReactiveKind) {
+        if let BindingPattern::BindingIdentifier(ident) = pattern {
+            if let Some(symbol) = ident.symbol_id.get() {
+                self.reactive.insert(symbol, kind);
+            }
+        }
+    }
+
+    fn enter_function(
+        &mut self,
+        id: Option<&BindingIdentifier<'a>>,
+        params: &FormalParameters<'a>,
+        body: Option<&FunctionBody<'a>>,
+    ) {
+        let pending = self.pending_function.take().unwrap_or_default();
+        let binding = pending
+            .binding
+            .or_else(|| id.and_then(|id| id.symbol_id.get()));
+        let pascal_case = pending.pascal_case || id.is_some_and(|id| is_component_name(&id.name));
+
+        let kind = pending.kind.unwrap_or_else(|| {
+            let returns_jsx = body.is_some_and(NoDestructure::body_has_jsx);
+            if returns_jsx && (pascal_case || self.current_kind() == ScopeKind::Module) {
+                ScopeKind::Component
+            } else {
+                ScopeKind::Function
+            }
+        });
+
+        if kind == ScopeKind::Component {
+            if let Some(param) = params.items.first() {
+                self.register_binding(&param.pattern, ReactiveKind::Object);
+            }
+        }
+        self.push_scope(kind, binding);
+    }
+
+    /// Visit a JSX expression container as a scope of the given kind
+    fn visit_container_as(&mut self, container: &JSXExpressionContainer<'a>, kind: ScopeKind) {
+        self.push_scope(kind, None);
+        if let Some(expr) = container.expression.as_expression() {
+            if is_function(expr) {
+                // Handlers run later; every other function in JSX is a
+                // render prop or accessor that Solid calls in a tracked scope
+                let kind = if kind == ScopeKind::Once {
+                    ScopeKind::Function
+                } else {
+                    ScopeKind::Tracked
+                };
+                self.pending_function = Some(PendingFunction {
+                    kind: Some(kind),
+                    ..PendingFunction::default()
+                });
+            }
+        }
+        walk::walk_jsx_expression_container(self, container);
+        self.pop_scope(container.span);
+    }
+
+    fn report_untracked_calls(&mut self) {
+        // A function also captures reactivity if it calls one that does
+        loop {
+            let newly_reactive: Vec<SymbolId> = self
+                .function_calls
+                .iter()
+                .filter(|(function, callees)| {
+                    !self.reactive_functions.contains_key(function)
+                        && callees
+                            .iter()
+                            .any(|callee| self.reactive_functions.contains_key(callee))
+                })
+                .map(|(function, _)| *function)
+                .collect();
+            if newly_reactive.is_empty() {
+                break;
+            }
+            for function in newly_reactive {
+                let span = self.function_spans[&function];
+                self.reactive_functions.insert(function, span);
+            }
+        }
+
+        let scoping = self.semantic.scoping();
+        for (callee, span) in std::mem::take(&mut self.untracked_calls) {
+            let Some(function_span) = self.reactive_functions.get(&callee) else {
+                continue;
+            };
+            self.diagnostics.push(
+                Diagnostic::warning(
+                    Reactivity::NAME,
+                    span,
+                    format!(
+                        "The function '{}' reads reactive values, but is called where changes won't be tracked.",
+                        scoping.symbol_name(callee)
+                    ),
+                )
+                .with_help("Call it inside JSX or a tracked scope like createEffect, or pass the function itself instead of its result.")
+                .with_label(*function_span, "reactive values are read in this function"),
+            );
+        }
+    }
+}
+
+impl<'a> Visit<'a> for TrackingAnalysis<'a> {
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        self.enter_function(func.id.as_ref(), &func.params, func.body.as_deref());
+        walk::walk_function(self, func, flags);
+        self.pop_scope(func.span);
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        self.enter_function(None, &arrow.params, Some(&arrow.body));
+        walk::walk_arrow_function_expression(self, arrow);
+        self.pop_scope(arrow.span);
+    }
+
+    fn visit_variable_declarator(&mut self, declarator: &VariableDeclarator<'a>) {
+        self.register_declarator(declarator);
+
+        if let (BindingPattern::BindingIdentifier(ident), Some(init)) =
+            (&declarator.id, &declarator.init)
+        {
+            if is_function(init) {
+                self.pending_function = Some(PendingFunction {
+                    kind: None,
+                    binding: ident.symbol_id.get(),
+                    pascal_case: is_component_name(&ident.name),
+                });
+            }
+        }
+        walk::walk_variable_declarator(self, declarator);
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if let Some(symbol) = self.symbol_of(&call.callee) {
+            if self.reactive.get(&symbol) == Some(&ReactiveKind::Signal) {
+                let Expression::Identifier(ident) = &call.callee else {
+                    unreachable!("symbols are only resolved for identifiers");
+                };
+                self.read(&ident.name, call.span);
+            } else {
+                self.record_call(symbol, call.span);
+            }
+        } else {
+            self.visit_expression(&call.callee);
+        }
+
+        let callee_name = match &call.callee {
+            Expression::Identifier(ident) => Some(ident.name.as_str()),
+            _ => None,
+        };
+        // Everything `on` is given is read by the effect it is passed to,
+        // including dependency arrays
+        let is_on = callee_name == Some("on");
+        if is_on {
+            self.push_scope(ScopeKind::Tracked, None);
+        }
+        let argc = call.arguments.len();
+        for (index, arg) in call.arguments.iter().enumerate() {
+            if arg.as_expression().is_some_and(is_function) {
+                // Callbacks handed to unknown functions are never components
+                let kind = callee_name.map_or(ScopeKind::Function, |name| {
+                    argument_scope(name, index, argc)
+                });
+                self.pending_function = Some(PendingFunction {
+                    kind: Some(kind),
+                    ..PendingFunction::default()
+                });
+            }
+            self.visit_argument(arg);
+        }
+        if is_on {
+            self.pop_scope(call.span);
+        }
+    }
+
+    fn visit_static_member_expression(&mut self, member: &StaticMemberExpression<'a>) {
+        if self.reactive_kind(&member.object) == Some(ReactiveKind::Object) {
+            let property = member.property.name.as_str();
+            if !is_initial_value(property) && !is_handler_or_ref(property) {
+                self.read(self.source_text_of(member.span), member.span);
+            }
+            return;
+        }
+        walk::walk_static_member_expression(self, member);
+    }
+
+    fn visit_computed_member_expression(&mut self, member: &ComputedMemberExpression<'a>) {
+        if self.reactive_kind(&member.object) == Some(ReactiveKind::Object) {
+            self.read(self.source_text_of(member.span), member.span);
+            self.visit_expression(&member.expression);
+            return;
+        }
+        walk::walk_computed_member_expression(self, member);
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &SimpleAssignmentTarget<'a>) {
+        // Writing to `props.x` / `state.x` is not a read
+        match target {
+            SimpleAssignmentTarget::StaticMemberExpression(member)
+                if self.reactive_kind(&member.object).is_some() => {}
+            SimpleAssignmentTarget::ComputedMemberExpression(member)
+                if self.reactive_kind(&member.object).is_some() =>
+            {
+                self.visit_expression(&member.expression);
+            }
+            _ => walk::walk_simple_assignment_target(self, target),
+        }
+    }
+
+    fn visit_binary_expression(&mut self, expr: &BinaryExpression<'a>) {
+        self.check_uncalled_signal(&expr.left, "an expression");
+        self.check_uncalled_signal(&expr.right, "an expression");
+        walk::walk_binary_expression(self, expr);
+    }
+
+    fn visit_unary_expression(&mut self, expr: &UnaryExpression<'a>) {
+        if matches!(
+            expr.operator,
+            UnaryOperator::LogicalNot | UnaryOperator::UnaryNegation | UnaryOperator::UnaryPlus
+        ) {
+            self.check_uncalled_signal(&expr.argument, "an expression");
+        }
+        walk::walk_unary_expression(self, expr);
+    }
+
+    fn visit_template_literal(&mut self, literal: &TemplateLiteral<'a>) {
+        for expr in &literal.expressions {
+            self.check_uncalled_signal(expr, "a template literal");
+        }
+        walk::walk_template_literal(self, literal);
+    }
+
+    fn visit_jsx_attribute(&mut self, attr: &JSXAttribute<'a>) {
+        match &attr.value {
+            Some(JSXAttributeValue::ExpressionContainer(container)) => {
+                let kind = if is_evaluated_once(&attr.name) {
+                    ScopeKind::Once
+                } else {
+                    ScopeKind::Tracked
+                };
+                self.visit_container_as(container, kind);
+            }
+            Some(value) => self.visit_jsx_attribute_value(value),
+            None => {}
+        }
+    }
+
+    fn visit_jsx_spread_attribute(&mut self, attr: &JSXSpreadAttribute<'a>) {
+        self.push_scope(ScopeKind::Tracked, None);
+        walk::walk_jsx_spread_attribute(self, attr);
+        self.pop_scope(attr.span);
+    }
+
+    fn visit_jsx_expression_container(&mut self, container: &JSXExpressionContainer<'a>) {
+        self.visit_container_as(container, ScopeKind::Tracked);
+    }
+}
+
+/// How a function passed as argument `index` of `callee` is run
+fn argument_scope(callee: &str, index: usize, argc: usize) -> ScopeKind {
+    match (callee, index) {
+        (
+            "createEffect" | "createRenderEffect" | "createComputed" | "createMemo"
+            | "createSelector" | "createDeferred" | "children" | "mapArray" | "indexArray",
+            0,
+        ) => ScopeKind::Tracked,
+        // createResource(source, fetcher): only the source is tracked
+        ("createResource", 0) if argc > 1 => ScopeKind::Tracked,
+        ("on", 0) => ScopeKind::Tracked,
+        ("on", _) | ("untrack", 0) => ScopeKind::Untrack,
+        _ => ScopeKind::Function,
+    }
+}
+
+/// Attributes whose expression Solid evaluates once instead of wrapping in an effect
+fn is_evaluated_once(name: &JSXAttributeName) -> bool {
+    match name {
+        JSXAttributeName::Identifier(ident) => {
+            let name = ident.name.as_str();
+            name == "ref"
+                || (name.len() > 2
+                    && name.starts_with("on")
+                    && name[2..].starts_with(|c: char| c.is_ascii_uppercase()))
+        }
+        JSXAttributeName::NamespacedName(ns) => {
+            matches!(ns.namespace.name.as_str(), "on" | "oncapture")
+        }
+    }
+}
+
+fn is_function(expr: &Expression) -> bool {
+    matches!(
+        expr.get_inner_expression(),
+        Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_)
+    )
+}
+
+/// Event handler and ref props hold functions that are called, not values
+/// to track, so reading them once is fine
+fn is_handler_or_ref(property: &str) -> bool {
+    property == "ref"
+        || property
+            .strip_prefix("on")
+            .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase()))
+}
+
+/// Props like `initialValue` or `defaultOpen` are meant to be read once
+fn is_initial_value(property: &str) -> bool {
+    ["initial", "default"].iter().any(|prefix| {
+        property.strip_prefix(prefix).is_some_and(|rest| {
+            rest.is_empty() || rest.starts_with(|c: char| c.is_ascii_uppercase())
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    fn analyze(source: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).parse();
+        let semantic_ret = SemanticBuilder::new().build(&ret.program);
+        TrackingAnalysis::new(&semantic_ret.semantic, source)
+            .run(&ret.program)
+            .into_iter()
+            .map(|d| d.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_reads_in_tracked_scopes() {
+        let messages = analyze(
+            r#"
+            function Counter(props) {
+                const [count, setCount] = createSignal(props.initialCount);
+                const double = createMemo(() => count() * 2);
+                createEffect(() => console.log(props.label, count()));
+                return (
+                    <button title={props.label} onClick={() => setCount(count() + 1)}>
+                        {double()} {count()}
+                    </button>
+                );
+            }
+            "#,
+        );
+        assert!(messages.is_empty(), "{:?}", messages);
+    }
+
+    #[test]
+    fn test_reads_in_component_body() {
+        let messages = analyze(
+            r#"
+            function Greeting(props) {
+                const [count] = createSignal(0);
+                const name = props.name;
+                const snapshot = count();
+                return <div>{name} {snapshot}</div>;
+            }
+            "#,
+        );
+        assert_eq!(messages.len(), 2, "{:?}", messages);
+        assert!(messages[0].contains("'props.name'"));
+        assert!(messages[1].contains("'count'"));
+    }
+
+    #[test]
+    fn test_event_handler_expression_is_untracked() {
+        let messages = analyze(
+            r#"
+            const App = () => {
+                const [count] = createSignal(0);
+                return <button onClick={count()} />;
+            };
+            "#,
+        );
+        assert_eq!(messages.len(), 1, "{:?}", messages);
+    }
+
+    #[test]
+    fn test_reactive_function_called_untracked() {
+        let messages = analyze(
+            r#"
+            function App() {
+                const [count] = createSignal(0);
+                const double = () => count() * 2;
+                const quadruple = () => double() * 2;
+                const value = quadruple();
+                createEffect(() => console.log(quadruple()));
+                return <div>{value}{double()}</div>;
+            }
+            "#,
+        );
+        assert_eq!(messages.len(), 1, "{:?}", messages);
+        assert!(messages[0].contains("'quadruple'"));
+    }
+
+    #[test]
+    fn test_untrack_and_module_scope() {
+        let messages = analyze(
+            r#"
+            const [count] = createSignal(0);
+            console.log(count());
+            function App() {
+                const initial = untrack(() => count());
+                return <div>{initial}</div>;
+            }
+            "#,
+        );
+        assert!(messages.is_empty(), "{:?}", messages);
+    }
+
+    #[test]
+    fn test_stores_and_assignments() {
+        let messages = analyze(
+            r#"
+            function App(props) {
+                const [state, setState] = createStore({ items: [] });
+                const [local, others] = splitProps(props, ["class"]);
+                props.ignored = 1;
+                const first = state.items[0];
+                return <div class={local.class} {...others}>{first}</div>;
+            }
+            "#,
+        );
+        assert_eq!(messages.len(), 1, "{:?}", messages);
+        assert!(messages[0].contains("'state.items'"));
+    }
+
+    #[test]
+    fn test_handler_and_ref_props() {
+        let messages = analyze(
+            r#"
+            function Button(props) {
+                const onKeyDown = props.onKeyDown;
+                return <button onClick={props.onClick} ref={props.ref} onKeyDown={onKeyDown} />;
+            }
+            "#,
+        );
+        assert!(messages.is_empty(), "{:?}", messages);
+    }
+
+    #[test]
+    fn test_reads_in_on() {
+        let messages = analyze(
+            r#"
+            function App(props) {
+                const [count] = createSignal(0);
+                createEffect(on([count, () => props.id], ([c, id]) => console.log(c, id, props.label)));
+                const double = createMemo(on(count, (value) => value * 2));
+                return <button onClick={on(count, () => console.log(props.label))}>{double()}</button>;
+            }
+            "#,
+        );
+        assert!(messages.is_empty(), "{:?}", messages);
+    }
+
+    #[test]
+    fn test_uncalled_signal() {
+        let messages = analyze(
+            r#"
+            function App() {
+                const [count] = createSignal(0);
+                return <div>{count + 1} {`${count}`}</div>;
+            }
+            "#,
+        );
+        assert_eq!(messages.len(), 2, "{:?}", messages);
+        assert!(messages.iter().all(|m| m.contains("called as a function")));
+    }
+}
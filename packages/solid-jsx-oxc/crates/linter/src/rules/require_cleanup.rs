@@ -0,0 +1,413 @@
+//! solid/require-cleanup
+//!
+//! Require an `onCleanup` next to timers, listeners, observers and
+//! subscriptions created inside `onMount` or an effect. Without one they
+//! outlive the component, and effects that re-run stack up duplicates.
+//!
+//! A resource counts as released when an `onCleanup` in the same function
+//! calls its release method (`clearInterval`, `disconnect`, ...) or mentions
+//! the variable it was stored in. `onCleanup(fn)` with a function defined
+//! elsewhere can't be inspected, so it releases everything. Listeners only
+//! leak when added to `window`, `document` or another global, and not when
+//! they pass `once` or an abort `signal`. The rule is off by default.
+
+use oxc_ast::ast::{
+    Argument, ArrowFunctionExpression, CallExpression, Expression, Function, FunctionBody,
+    IdentifierReference, NewExpression, ObjectPropertyKind, VariableDeclarator,
+};
+use oxc_ast_visit::{walk, Visit};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::scope::ScopeFlags;
+use rustc_hash::FxHashSet;
+
+use crate::diagnostic::Diagnostic;
+use crate::{RuleCategory, RuleMeta};
+
+/// Functions whose callback owns the resources it creates
+const OWNER_FUNCTIONS: &[&str] = &[
+    "onMount",
+    "createEffect",
+    "createRenderEffect",
+    "createComputed",
+];
+
+/// A resource, the calls that release it, and the release shown in the help
+type Acquisition = (&'static str, &'static [&'static str], &'static str);
+
+/// Calls that acquire a resource
+const ACQUIRING_CALLS: &[Acquisition] = &[
+    ("setInterval", &["clearInterval"], "clearInterval(id)"),
+    (
+        "addEventListener",
+        &["removeEventListener", "abort"],
+        "removeEventListener(...)",
+    ),
+    ("subscribe", &["unsubscribe"], "unsubscribe()"),
+];
+
+/// Constructors that acquire a resource
+const ACQUIRING_CONSTRUCTORS: &[Acquisition] = &[
+    ("ResizeObserver", &["disconnect"], "observer.disconnect()"),
+    ("MutationObserver", &["disconnect"], "observer.disconnect()"),
+    (
+        "IntersectionObserver",
+        &["disconnect"],
+        "observer.disconnect()",
+    ),
+    (
+        "PerformanceObserver",
+        &["disconnect"],
+        "observer.disconnect()",
+    ),
+    ("WebSocket", &["close"], "socket.close()"),
+    ("EventSource", &["close"], "source.close()"),
+];
+
+/// Listener targets that outlive every component
+const GLOBAL_TARGETS: &[&str] = &["window", "document", "globalThis", "self"];
+
+/// require-cleanup rule
+#[derive(Debug, Clone, Default)]
+pub struct RequireCleanup;
+
+impl RuleMeta for RequireCleanup {
+    const NAME: &'static str = "require-cleanup";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Require an `onCleanup` next to timers, listeners, observers and subscriptions \
+        created inside `onMount` or an effect. Without one they outlive the component, \
+        and effects that re-run stack up duplicates.";
+    const DEFAULT_ENABLED: bool = false;
+}
+
+impl RequireCleanup {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check an `onMount`/`createEffect`/... call whose callback acquires resources
+    pub fn check(&self, call: &CallExpression) -> Vec<Diagnostic> {
+        let Some(owner) = call
+            .callee_name()
+            .filter(|name| OWNER_FUNCTIONS.contains(name))
+        else {
+            return Vec::new();
+        };
+        let Some(body) = call.arguments.first().and_then(callback_body) else {
+            return Vec::new();
+        };
+
+        let mut finder = ResourceFinder::default();
+        walk::walk_function_body(&mut finder, body);
+        if finder.opaque_cleanup {
+            return Vec::new();
+        }
+
+        let cleanup = &finder.cleanup;
+        finder
+            .resources
+            .into_iter()
+            .filter(|resource| {
+                !resource
+                    .releases
+                    .iter()
+                    .any(|release| cleanup.calls.contains(*release))
+                    && !resource
+                        .binding
+                        .as_ref()
+                        .is_some_and(|binding| cleanup.names.contains(binding))
+            })
+            .map(|resource| {
+                Diagnostic::warning(
+                    Self::NAME,
+                    resource.span,
+                    format!(
+                        "`{}` inside `{}` is never cleaned up.",
+                        resource.name, owner
+                    ),
+                )
+                .with_help(format!(
+                    "Call `onCleanup(() => {})` in the same function so it is released when the owner is disposed.",
+                    resource.help
+                ))
+            })
+            .collect()
+    }
+}
+
+/// The callback of an owner call, looking through `on(deps, fn)`
+fn callback_body<'b, 'a>(argument: &'b Argument<'a>) -> Option<&'b FunctionBody<'a>> {
+    match argument.as_expression()?.get_inner_expression() {
+        Expression::ArrowFunctionExpression(arrow) => Some(&arrow.body),
+        Expression::FunctionExpression(func) => func.body.as_deref(),
+        Expression::CallExpression(call) if call.callee_name() == Some("on") => {
+            call.arguments.get(1).and_then(callback_body)
+        }
+        _ => None,
+    }
+}
+
+/// Whether an `addEventListener` call can outlive the component
+fn is_leaking_listener(call: &CallExpression) -> bool {
+    let is_global = match &call.callee {
+        Expression::Identifier(_) => true,
+        callee => callee.as_member_expression().is_some_and(|member| {
+            matches!(
+                member.object().get_inner_expression(),
+                Expression::Identifier(ident) if GLOBAL_TARGETS.contains(&ident.name.as_str())
+            )
+        }),
+    };
+    let releases_itself = call
+        .arguments
+        .get(2)
+        .and_then(Argument::as_expression)
+        .is_some_and(|options| match options.get_inner_expression() {
+            Expression::ObjectExpression(object) => {
+                object.properties.iter().any(|property| match property {
+                    ObjectPropertyKind::ObjectProperty(property) => {
+                        property.key.is_specific_static_name("signal")
+                            || (property.key.is_specific_static_name("once")
+                                && matches!(
+                                    property.value,
+                                    Expression::BooleanLiteral(ref literal) if literal.value
+                                ))
+                    }
+                    ObjectPropertyKind::SpreadProperty(_) => true,
+                })
+            }
+            _ => false,
+        });
+    is_global && !releases_itself
+}
+
+struct Resource {
+    span: Span,
+    name: &'static str,
+    releases: &'static [&'static str],
+    help: &'static str,
+    /// The variable the resource is stored in
+    binding: Option<String>,
+}
+
+impl Resource {
+    fn new(span: Span, (name, releases, help): Acquisition) -> Self {
+        Self {
+            span,
+            name,
+            releases,
+            help,
+            binding: None,
+        }
+    }
+}
+
+/// Collects resource-acquiring calls and `onCleanup` registrations in one
+/// function body, not descending into nested functions, which run at some
+/// other time and own their own cleanup
+#[derive(Default)]
+struct ResourceFinder {
+    resources: Vec<Resource>,
+    cleanup: CleanupReads,
+    /// An `onCleanup` whose callback is defined elsewhere
+    opaque_cleanup: bool,
+}
+
+impl<'a> Visit<'a> for ResourceFinder {
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        match call.callee_name() {
+            Some("onCleanup") => {
+                match call
+                    .arguments
+                    .first()
+                    .and_then(Argument::as_expression)
+                    .map(Expression::get_inner_expression)
+                {
+                    Some(
+                        callback @ (Expression::ArrowFunctionExpression(_)
+                        | Expression::FunctionExpression(_)),
+                    ) => self.cleanup.visit_expression(callback),
+                    _ => self.opaque_cleanup = true,
+                }
+                return;
+            }
+            Some(name) => {
+                if let Some(&acquisition) = ACQUIRING_CALLS.iter().find(|(n, ..)| *n == name) {
+                    if name != "addEventListener" || is_leaking_listener(call) {
+                        self.resources.push(Resource::new(call.span, acquisition));
+                    }
+                }
+            }
+            None => {}
+        }
+        walk::walk_call_expression(self, call);
+    }
+
+    fn visit_new_expression(&mut self, new_expr: &NewExpression<'a>) {
+        if let Expression::Identifier(ident) = &new_expr.callee {
+            if let Some(&acquisition) = ACQUIRING_CONSTRUCTORS
+                .iter()
+                .find(|(n, ..)| *n == ident.name.as_str())
+            {
+                self.resources
+                    .push(Resource::new(new_expr.span, acquisition));
+            }
+        }
+        walk::walk_new_expression(self, new_expr);
+    }
+
+    fn visit_variable_declarator(&mut self, declarator: &VariableDeclarator<'a>) {
+        walk::walk_variable_declarator(self, declarator);
+        let (Some(init), Some(ident)) = (&declarator.init, declarator.id.get_binding_identifier())
+        else {
+            return;
+        };
+        let init_span = init.get_inner_expression().span();
+        if let Some(resource) = self
+            .resources
+            .iter_mut()
+            .rev()
+            .find(|resource| resource.span == init_span)
+        {
+            resource.binding = Some(ident.name.to_string());
+        }
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+}
+
+/// What the `onCleanup` callbacks of a body call and read
+#[derive(Default)]
+struct CleanupReads {
+    calls: FxHashSet<String>,
+    names: FxHashSet<String>,
+}
+
+impl<'a> Visit<'a> for CleanupReads {
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if let Some(name) = call.callee_name() {
+            self.calls.insert(name.to_string());
+        }
+        walk::walk_call_expression(self, call);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.names.insert(ident.name.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Statement;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn cleanup_messages(source: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::CallExpression(call) = &stmt.expression else {
+            panic!("expected call");
+        };
+        RequireCleanup::new()
+            .check(call)
+            .into_iter()
+            .map(|d| d.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(RequireCleanup::NAME, "require-cleanup");
+    }
+
+    #[test]
+    fn test_missing_cleanup() {
+        let messages = cleanup_messages(
+            r#"onMount(() => {
+                const id = setInterval(tick, 1000);
+                window.addEventListener("resize", onResize);
+                const observer = new ResizeObserver(measure);
+            })"#,
+        );
+        assert_eq!(
+            messages,
+            [
+                "`setInterval` inside `onMount` is never cleaned up.",
+                "`addEventListener` inside `onMount` is never cleaned up.",
+                "`ResizeObserver` inside `onMount` is never cleaned up.",
+            ]
+        );
+        assert_eq!(
+            cleanup_messages(r#"createEffect(function () { store.subscribe(update); })"#),
+            ["`subscribe` inside `createEffect` is never cleaned up."]
+        );
+    }
+
+    #[test]
+    fn test_cleaned_up() {
+        assert!(cleanup_messages(
+            r#"onMount(() => {
+                const id = setInterval(tick, 1000);
+                onCleanup(() => clearInterval(id));
+            })"#
+        )
+        .is_empty());
+        // Listeners added later, from an event callback, are not owned by the effect
+        assert!(cleanup_messages(
+            r#"onMount(() => { button.onclick = () => el.addEventListener("x", f); })"#
+        )
+        .is_empty());
+        assert!(cleanup_messages(r#"createMemo(() => setInterval(tick, 10))"#).is_empty());
+        assert!(cleanup_messages(
+            r#"onMount(() => {
+                const observer = new ResizeObserver(measure);
+                const socket = new WebSocket(url);
+                onCleanup(() => { observer.disconnect(); socket.close(); });
+            })"#
+        )
+        .is_empty());
+        assert!(cleanup_messages(
+            r#"onMount(() => {
+                const unsubscribe = store.subscribe(update);
+                onCleanup(unsubscribe);
+            })"#
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_each_resource_needs_its_release() {
+        assert_eq!(
+            cleanup_messages(
+                r#"createEffect(on(source, () => {
+                    const id = setInterval(tick, 1000);
+                    const observer = new MutationObserver(update);
+                    onCleanup(() => clearInterval(id));
+                }))"#
+            ),
+            ["`MutationObserver` inside `createEffect` is never cleaned up."]
+        );
+    }
+
+    #[test]
+    fn test_listeners_that_do_not_leak() {
+        assert!(cleanup_messages(
+            r#"onMount(() => {
+                ref.addEventListener("click", onClick);
+                window.addEventListener("resize", onResize, { once: true });
+                document.addEventListener("keydown", onKey, { signal: controller.signal });
+                requestAnimationFrame(measure);
+            })"#
+        )
+        .is_empty());
+    }
+}
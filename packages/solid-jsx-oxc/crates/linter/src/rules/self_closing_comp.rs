@@ -1,9 +1,12 @@
 //! solid/self-closing-comp
 //!
 //! Disallow extra closing tags for components without children.
+//!
+//! Components and native elements are configured separately. Void elements
+//! (`<br />`, `<img />`) may always self-close, whatever the `html` option.
 
 use oxc_ast::ast::{JSXChild, JSXOpeningElement};
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 use serde::{Deserialize, Serialize};
 
 use crate::diagnostic::{Diagnostic, Fix};
@@ -11,7 +14,7 @@ use crate::utils::{
     children_is_empty_or_multiline_whitespace, get_element_name, is_component, is_dom_element,
     is_void_element,
 };
-use crate::{RuleCategory, RuleMeta};
+use crate::{ConfigurableRule, RuleCategory, RuleMeta};
 
 /// Which elements should be self-closing
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
@@ -39,7 +42,7 @@ pub enum HtmlSelfClosingOption {
 
 /// Configuration for self-closing-comp
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct SelfClosingCompConfig {
     /// Which Solid components should be self-closing when possible
     #[serde(default)]
@@ -58,6 +61,17 @@ pub struct SelfClosingComp {
 impl RuleMeta for SelfClosingComp {
     const NAME: &'static str = "self-closing-comp";
     const CATEGORY: RuleCategory = RuleCategory::Style;
+    const DESCRIPTION: &'static str = "\
+        Disallow extra closing tags for components without children.";
+    const FIXABLE: bool = true;
+}
+
+impl ConfigurableRule for SelfClosingComp {
+    type Options = SelfClosingCompConfig;
+
+    fn from_options(options: SelfClosingCompConfig) -> Self {
+        Self::with_config(options)
+    }
 }
 
 impl SelfClosingComp {
@@ -110,16 +124,17 @@ impl SelfClosingComp {
 
             if should_self_close && !is_self_closing {
                 // Should be self-closing but isn't
-                let mut diagnostic = Diagnostic::warning(
-                    Self::NAME,
-                    opening.span,
-                    "Empty components are self-closing.",
-                );
+                let message = if is_component(opening) {
+                    "Empty components are self-closing."
+                } else {
+                    "Empty elements are self-closing."
+                };
+                let mut diagnostic = Diagnostic::warning(Self::NAME, opening.span, message);
 
                 // Add fix: replace `>...</tagName>` with ` />`
                 if let Some(closing) = closing_span {
-                    // Calculate the range from '>' to end of closing tag
-                    let fix_start = opening.span.end - 1; // The '>' character
+                    // Everything after the tag name and attributes, through the closing tag
+                    let fix_start = opening_content_end(opening);
                     let fix_end = closing.end;
                     diagnostic = diagnostic.with_fix(
                         Fix::new(Span::new(fix_start, fix_end), " />")
@@ -129,8 +144,9 @@ impl SelfClosingComp {
 
                 diagnostics.push(diagnostic);
             } else if !should_self_close && is_self_closing {
-                // Should NOT be self-closing but is
-                if let Some(name) = get_element_name(opening) {
+                // Should NOT be self-closing but is. `<br></br>` would only
+                // be noise, so void elements are left alone.
+                if let Some(name) = get_element_name(opening).filter(|name| !is_void_element(name)) {
                     let diagnostic = Diagnostic::warning(
                         Self::NAME,
                         opening.span,
@@ -139,7 +155,7 @@ impl SelfClosingComp {
                     .with_fix(
                         // Replace ` />` or `/>` with `></${tagName}>`
                         Fix::new(
-                            Span::new(opening.span.end - 2, opening.span.end),
+                            Span::new(opening_content_end(opening), opening.span.end),
                             format!("></{}>", name),
                         )
                         .with_message("Add closing tag"),
@@ -154,9 +170,105 @@ impl SelfClosingComp {
     }
 }
 
+/// End of the last attribute (or of the tag name) in an opening element
+fn opening_content_end(opening: &JSXOpeningElement) -> u32 {
+    let name_end = opening
+        .type_arguments
+        .as_ref()
+        .map_or(opening.name.span().end, |args| args.span.end);
+    opening
+        .attributes
+        .last()
+        .map_or(name_end, |attr| attr.span().end)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::apply_fixes;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn fixed(source: &str, config: SelfClosingCompConfig) -> Option<String> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected JSX element");
+        };
+        let closing_span = element.closing_element.as_ref().map(|c| c.span);
+        let diagnostics = SelfClosingComp::with_config(config).check(
+            &element.opening_element,
+            &element.children,
+            closing_span,
+        );
+        let fixed = apply_fixes(source, diagnostics);
+        fixed.is_changed().then_some(fixed.output)
+    }
+
+    #[test]
+    fn test_fixes() {
+        let config = SelfClosingCompConfig::default();
+        assert_eq!(
+            fixed("<Foo.Bar a={1}>\n  </Foo.Bar>", config.clone()).as_deref(),
+            Some("<Foo.Bar a={1} />")
+        );
+        assert_eq!(fixed("<Foo>text</Foo>", config).as_deref(), None);
+
+        let config = SelfClosingCompConfig {
+            component: SelfClosingOption::None,
+            html: HtmlSelfClosingOption::None,
+        };
+        assert_eq!(fixed("<Foo />", config.clone()).as_deref(), Some("<Foo></Foo>"));
+        assert_eq!(fixed("<div/>", config.clone()).as_deref(), Some("<div></div>"));
+        assert_eq!(fixed("<br />", config).as_deref(), None);
+    }
+
+    #[test]
+    fn test_void_html_option() {
+        let config = SelfClosingCompConfig {
+            component: SelfClosingOption::All,
+            html: HtmlSelfClosingOption::Void,
+        };
+        assert_eq!(fixed("<br></br>", config.clone()).as_deref(), Some("<br />"));
+        assert_eq!(fixed("<div></div>", config.clone()).as_deref(), None);
+        assert_eq!(fixed("<div />", config).as_deref(), Some("<div></div>"));
+    }
+
+    crate::rule_test!(test_self_closing_comp, SelfClosingComp::NAME, {
+        valid: [
+            "<Foo />",
+            "<div>text</div>",
+            { code: "<div></div>", options: serde_json::json!({ "html": "void" }) },
+        ],
+        invalid: [
+            {
+                code: "const a = <Foo a={1}>\n  </Foo>;",
+                errors: [{ message: "Empty components are self-closing.", text: "<Foo a={1}>" }],
+                output: "const a = <Foo a={1} />;",
+            },
+            {
+                code: "<section><div></div><br></br></section>",
+                errors: [
+                    { text: "<div>" },
+                    { text: "<br>" },
+                ],
+                output: "<section><div /><br /></section>",
+            },
+            {
+                code: "<Foo />",
+                errors: ["This element should not be self-closing."],
+                options: serde_json::json!({ "component": "none" }),
+                output: "<Foo></Foo>",
+            },
+        ],
+    });
 
     #[test]
     fn test_config_defaults() {
@@ -1,52 +1,179 @@
 //! solid/style-prop
 //!
 //! Require CSS properties in the `style` prop to be valid and kebab-cased.
+//!
+//! Numeric values are only reported for properties that take a length, where
+//! a bare number is dropped by the browser and `px` is what was meant. Other
+//! properties (`aspect-ratio`, `scale`, `animation-delay`) take numbers or
+//! other units and are left alone.
 
 use oxc_ast::ast::{
     Expression, JSXAttributeName, JSXAttributeValue, JSXOpeningElement, ObjectPropertyKind,
     PropertyKey,
 };
 use oxc_span::{GetSpan, Span};
+use serde::{Deserialize, Serialize};
+
+use crate::context::jsx_container_expression;
+use crate::diagnostic::{Diagnostic, Fix, Suggestion};
+use crate::{ConfigurableRule, RuleCategory, RuleMeta};
+
+/// Known CSS property names (kebab-case)
+const CSS_PROPERTIES: &[&str] = &[
+    "accent-color", "align-content", "align-items", "align-self",
+    "all", "animation", "animation-delay", "animation-direction",
+    "animation-duration", "animation-fill-mode", "animation-iteration-count", "animation-name",
+    "animation-play-state", "animation-timing-function", "appearance", "aspect-ratio",
+    "backdrop-filter", "backface-visibility", "background", "background-attachment",
+    "background-blend-mode", "background-clip", "background-color", "background-image",
+    "background-origin", "background-position", "background-position-x", "background-position-y",
+    "background-repeat", "background-size", "block-size", "border",
+    "border-block", "border-block-color", "border-block-end", "border-block-start",
+    "border-block-style", "border-block-width", "border-bottom", "border-bottom-color",
+    "border-bottom-left-radius", "border-bottom-right-radius", "border-bottom-style", "border-bottom-width",
+    "border-collapse", "border-color", "border-image", "border-image-outset",
+    "border-image-repeat", "border-image-slice", "border-image-source", "border-image-width",
+    "border-inline", "border-inline-color", "border-inline-end", "border-inline-start",
+    "border-inline-style", "border-inline-width", "border-left", "border-left-color",
+    "border-left-style", "border-left-width", "border-radius", "border-right",
+    "border-right-color", "border-right-style", "border-right-width", "border-spacing",
+    "border-style", "border-top", "border-top-color", "border-top-left-radius",
+    "border-top-right-radius", "border-top-style", "border-top-width", "border-width",
+    "bottom", "box-decoration-break", "box-shadow", "box-sizing",
+    "break-after", "break-before", "break-inside", "caption-side",
+    "caret-color", "clear", "clip", "clip-path",
+    "clip-rule", "color", "color-scheme", "column-count",
+    "column-fill", "column-gap", "column-rule", "column-rule-color",
+    "column-rule-style", "column-rule-width", "column-span", "column-width",
+    "columns", "contain", "container", "container-name",
+    "container-type", "content", "content-visibility", "counter-increment",
+    "counter-reset", "counter-set", "cursor", "direction",
+    "display", "dominant-baseline", "empty-cells", "fill",
+    "fill-opacity", "fill-rule", "filter", "flex",
+    "flex-basis", "flex-direction", "flex-flow", "flex-grow",
+    "flex-shrink", "flex-wrap", "float", "flood-color",
+    "flood-opacity", "font", "font-family", "font-feature-settings",
+    "font-kerning", "font-optical-sizing", "font-size", "font-size-adjust",
+    "font-stretch", "font-style", "font-variant", "font-variant-caps",
+    "font-variant-ligatures", "font-variant-numeric", "font-variation-settings", "font-weight",
+    "gap", "grid", "grid-area", "grid-auto-columns",
+    "grid-auto-flow", "grid-auto-rows", "grid-column", "grid-column-end",
+    "grid-column-gap", "grid-column-start", "grid-gap", "grid-row",
+    "grid-row-end", "grid-row-gap", "grid-row-start", "grid-template",
+    "grid-template-areas", "grid-template-columns", "grid-template-rows", "height",
+    "hyphens", "image-rendering", "inline-size", "inset",
+    "inset-block", "inset-block-end", "inset-block-start", "inset-inline",
+    "inset-inline-end", "inset-inline-start", "isolation", "justify-content",
+    "justify-items", "justify-self", "left", "letter-spacing",
+    "lighting-color", "line-break", "line-clamp", "line-height",
+    "list-style", "list-style-image", "list-style-position", "list-style-type",
+    "margin", "margin-block", "margin-block-end", "margin-block-start",
+    "margin-bottom", "margin-inline", "margin-inline-end", "margin-inline-start",
+    "margin-left", "margin-right", "margin-top", "marker",
+    "marker-end", "marker-mid", "marker-start", "mask",
+    "mask-clip", "mask-composite", "mask-image", "mask-mode",
+    "mask-origin", "mask-position", "mask-repeat", "mask-size",
+    "mask-type", "max-block-size", "max-height", "max-inline-size",
+    "max-width", "min-block-size", "min-height", "min-inline-size",
+    "min-width", "mix-blend-mode", "object-fit", "object-position",
+    "offset", "offset-distance", "offset-path", "offset-rotate",
+    "opacity", "order", "orphans", "outline",
+    "outline-color", "outline-offset", "outline-style", "outline-width",
+    "overflow", "overflow-anchor", "overflow-wrap", "overflow-x",
+    "overflow-y", "overscroll-behavior", "overscroll-behavior-x", "overscroll-behavior-y",
+    "padding", "padding-block", "padding-block-end", "padding-block-start",
+    "padding-bottom", "padding-inline", "padding-inline-end", "padding-inline-start",
+    "padding-left", "padding-right", "padding-top", "page-break-after",
+    "page-break-before", "page-break-inside", "paint-order", "perspective",
+    "perspective-origin", "place-content", "place-items", "place-self",
+    "pointer-events", "position", "quotes", "resize",
+    "right", "rotate", "row-gap", "scale",
+    "scroll-behavior", "scroll-margin", "scroll-margin-bottom", "scroll-margin-left",
+    "scroll-margin-right", "scroll-margin-top", "scroll-padding", "scroll-padding-bottom",
+    "scroll-padding-left", "scroll-padding-right", "scroll-padding-top", "scroll-snap-align",
+    "scroll-snap-stop", "scroll-snap-type", "scrollbar-color", "scrollbar-gutter",
+    "scrollbar-width", "shape-outside", "shape-rendering", "stop-color",
+    "stop-opacity", "stroke", "stroke-dasharray", "stroke-dashoffset",
+    "stroke-linecap", "stroke-linejoin", "stroke-miterlimit", "stroke-opacity",
+    "stroke-width", "tab-size", "table-layout", "text-align",
+    "text-align-last", "text-anchor", "text-decoration", "text-decoration-color",
+    "text-decoration-line", "text-decoration-style", "text-decoration-thickness", "text-emphasis",
+    "text-indent", "text-justify", "text-orientation", "text-overflow",
+    "text-rendering", "text-shadow", "text-transform", "text-underline-offset",
+    "text-underline-position", "text-wrap", "top", "touch-action",
+    "transform", "transform-box", "transform-origin", "transform-style",
+    "transition", "transition-delay", "transition-duration", "transition-property",
+    "transition-timing-function", "translate", "unicode-bidi", "user-select",
+    "vector-effect", "vertical-align", "visibility", "white-space",
+    "widows", "width", "will-change", "word-break",
+    "word-spacing", "word-wrap", "writing-mode", "z-index",
+    "zoom",
+];
 
-use crate::diagnostic::{Diagnostic, Fix};
-use crate::{RuleCategory, RuleMeta};
-
-/// Common CSS length/percentage properties that shouldn't have numeric values
-const LENGTH_PERCENTAGE_PROPS: &[&str] = &[
-    "width",
-    "height",
-    "margin",
-    "padding",
-    "border-width",
-    "font-size",
-    "min-width",
-    "max-width",
-    "min-height",
-    "max-height",
-    "margin-top",
-    "margin-right",
-    "margin-bottom",
-    "margin-left",
-    "padding-top",
-    "padding-right",
-    "padding-bottom",
-    "padding-left",
+/// Properties whose numeric value is a length, so `2` means `"2px"`
+const LENGTH_PROPERTIES: &[&str] = &[
+    "block-size", "border-bottom-left-radius", "border-bottom-right-radius", "border-bottom-width",
+    "border-left-width", "border-radius", "border-right-width", "border-spacing",
+    "border-top-left-radius", "border-top-right-radius", "border-top-width", "border-width",
+    "bottom", "column-gap", "column-rule-width", "column-width",
+    "flex-basis", "font-size", "gap", "grid-column-gap",
+    "grid-gap", "grid-row-gap", "height", "inline-size",
+    "inset", "inset-block", "inset-block-end", "inset-block-start",
+    "inset-inline", "inset-inline-end", "inset-inline-start", "left",
+    "letter-spacing", "margin", "margin-block", "margin-block-end",
+    "margin-block-start", "margin-bottom", "margin-inline", "margin-inline-end",
+    "margin-inline-start", "margin-left", "margin-right", "margin-top",
+    "max-block-size", "max-height", "max-inline-size", "max-width",
+    "min-block-size", "min-height", "min-inline-size", "min-width",
+    "outline-offset", "outline-width", "padding", "padding-block",
+    "padding-block-end", "padding-block-start", "padding-bottom", "padding-inline",
+    "padding-inline-end", "padding-inline-start", "padding-left", "padding-right",
+    "padding-top", "perspective", "right", "row-gap",
+    "scroll-margin", "scroll-margin-bottom", "scroll-margin-left", "scroll-margin-right",
+    "scroll-margin-top", "scroll-padding", "scroll-padding-bottom", "scroll-padding-left",
+    "scroll-padding-right", "scroll-padding-top", "text-indent", "text-underline-offset",
+    "top", "width", "word-spacing",
 ];
 
+/// Which spelling style object keys should use
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PropertyCase {
+    /// `"font-size"`, which is what Solid's `style` expects
+    #[default]
+    Kebab,
+    /// `fontSize`
+    Camel,
+}
+
+/// Whether the style prop may be a string
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StyleStringOption {
+    /// Allow `style="color: red"`
+    Allow,
+    /// Require an object
+    #[default]
+    Never,
+}
+
 /// style-prop rule
 #[derive(Debug, Clone)]
 pub struct StyleProp {
     /// Prop names to treat as CSS style object
     pub style_props: Vec<String>,
-    /// If true, allow string style values
-    pub allow_string: bool,
+    /// Whether string style values are reported
+    pub style_string: StyleStringOption,
+    /// Preferred spelling of style object keys
+    pub property_case: PropertyCase,
 }
 
 impl Default for StyleProp {
     fn default() -> Self {
         Self {
             style_props: vec!["style".to_string()],
-            allow_string: false,
+            style_string: StyleStringOption::Never,
+            property_case: PropertyCase::Kebab,
         }
     }
 }
@@ -54,6 +181,45 @@ impl Default for StyleProp {
 impl RuleMeta for StyleProp {
     const NAME: &'static str = "style-prop";
     const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "\
+        Require CSS properties in the `style` prop to be valid and kebab-cased.";
+    const FIXABLE: bool = true;
+}
+
+/// Options for style-prop, as spelled by eslint-plugin-solid
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct StylePropOptions {
+    pub style_props: Vec<String>,
+    pub allow_string: bool,
+    pub property_case: PropertyCase,
+}
+
+impl Default for StylePropOptions {
+    fn default() -> Self {
+        let rule = StyleProp::default();
+        Self {
+            style_props: rule.style_props,
+            allow_string: false,
+            property_case: rule.property_case,
+        }
+    }
+}
+
+impl ConfigurableRule for StyleProp {
+    type Options = StylePropOptions;
+
+    fn from_options(options: StylePropOptions) -> Self {
+        let style_string = if options.allow_string {
+            StyleStringOption::Allow
+        } else {
+            StyleStringOption::Never
+        };
+        Self::new()
+            .with_style_props(options.style_props)
+            .with_style_string(style_string)
+            .with_property_case(options.property_case)
+    }
 }
 
 impl StyleProp {
@@ -66,14 +232,20 @@ impl StyleProp {
         self
     }
 
-    pub fn with_allow_string(mut self, allow: bool) -> Self {
-        self.allow_string = allow;
+    pub fn with_style_string(mut self, style_string: StyleStringOption) -> Self {
+        self.style_string = style_string;
+        self
+    }
+
+    pub fn with_property_case(mut self, property_case: PropertyCase) -> Self {
+        self.property_case = property_case;
         self
     }
 
     /// Check a JSX opening element for style prop issues
     pub fn check<'a>(&self, opening: &JSXOpeningElement<'a>) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
+        let forbid_string = self.style_string == StyleStringOption::Never;
 
         for attr in &opening.attributes {
             if let oxc_ast::ast::JSXAttributeItem::Attribute(jsx_attr) = attr {
@@ -89,9 +261,9 @@ impl StyleProp {
                 // Get the style value
                 let style_expr = match &jsx_attr.value {
                     Some(JSXAttributeValue::ExpressionContainer(container)) => {
-                        container.expression.as_expression()
+                        jsx_container_expression(container)
                     }
-                    Some(JSXAttributeValue::StringLiteral(lit)) if !self.allow_string => {
+                    Some(JSXAttributeValue::StringLiteral(lit)) if forbid_string => {
                         // String style prop - warn
                         diagnostics.push(
                             Diagnostic::warning(
@@ -100,14 +272,8 @@ impl StyleProp {
                                 "Use an object for the style prop instead of a string.",
                             )
                             .with_fix(
-                                Fix::new(
-                                    jsx_attr.value.as_ref().map(|v| match v {
-                                        JSXAttributeValue::StringLiteral(l) => l.span,
-                                        _ => Span::default(),
-                                    }).unwrap_or_default(),
-                                    format!("{{{}}}", self.parse_style_string(&lit.value)),
-                                )
-                                .with_message("Convert to style object"),
+                                Fix::new(lit.span, format!("{{{}}}", self.parse_style_string(&lit.value)))
+                                    .with_message("Convert to style object"),
                             ),
                         );
                         continue;
@@ -119,7 +285,7 @@ impl StyleProp {
 
                 // Check for template literal (string)
                 if let Expression::TemplateLiteral(_) = expr {
-                    if !self.allow_string {
+                    if forbid_string {
                         diagnostics.push(
                             Diagnostic::warning(
                                 Self::NAME,
@@ -133,7 +299,7 @@ impl StyleProp {
 
                 // Check for string literal in expression
                 if let Expression::StringLiteral(lit) = expr {
-                    if !self.allow_string {
+                    if forbid_string {
                         diagnostics.push(
                             Diagnostic::warning(
                                 Self::NAME,
@@ -156,6 +322,9 @@ impl StyleProp {
                 if let Expression::ObjectExpression(obj) = expr {
                     for prop in &obj.properties {
                         if let ObjectPropertyKind::ObjectProperty(prop) = prop {
+                            if prop.computed {
+                                continue;
+                            }
                             let (prop_name, key_span) = match &prop.key {
                                 PropertyKey::StaticIdentifier(ident) => {
                                     (ident.name.to_string(), ident.span)
@@ -171,29 +340,8 @@ impl StyleProp {
                                 continue;
                             }
 
-                            // Check for camelCase that should be kebab-case
                             let kebab_name = to_kebab_case(&prop_name);
-                            if prop_name != kebab_name && is_valid_css_property(&kebab_name) {
-                                diagnostics.push(
-                                    Diagnostic::warning(
-                                        Self::NAME,
-                                        key_span,
-                                        format!(
-                                            "Use {} instead of {}.",
-                                            kebab_name, prop_name
-                                        ),
-                                    )
-                                    .with_fix(
-                                        Fix::new(key_span, format!("\"{}\"", kebab_name))
-                                            .with_message(format!(
-                                                "Replace {} with {}",
-                                                prop_name, kebab_name
-                                            )),
-                                    ),
-                                );
-                            } else if !is_valid_css_property(&prop_name)
-                                && !is_valid_css_property(&kebab_name)
-                            {
+                            if !is_valid_css_property(&kebab_name) {
                                 diagnostics.push(
                                     Diagnostic::warning(
                                         Self::NAME,
@@ -201,12 +349,16 @@ impl StyleProp {
                                         format!("{} is not a valid CSS property.", prop_name),
                                     ),
                                 );
+                                continue;
                             }
 
-                            // Check for numeric values on length/percentage properties
-                            if is_length_percentage_property(&prop_name)
-                                || is_length_percentage_property(&kebab_name)
-                            {
+                            if let Some(diagnostic) = self.check_key_case(&prop_name, &kebab_name, key_span) {
+                                diagnostics.push(diagnostic);
+                            }
+
+                            // Numbers are not given a unit at runtime. Adding one is
+                            // only suggested: it changes how the element renders
+                            if LENGTH_PROPERTIES.contains(&kebab_name.as_str()) {
                                 if let Some(value) = get_numeric_value(&prop.value) {
                                     if value != 0.0 {
                                         diagnostics.push(
@@ -214,6 +366,11 @@ impl StyleProp {
                                                 Self::NAME,
                                                 prop.value.span(),
                                                 "This CSS property value should be a string with a unit; Solid does not automatically append a \"px\" unit.",
+                                            )
+                                            .with_suggestion(
+                                                Suggestion::new("Add a \"px\" unit").with_fix(
+                                                    Fix::new(prop.value.span(), format!("\"{}px\"", value)),
+                                                ),
                                             ),
                                         );
                                     }
@@ -228,6 +385,31 @@ impl StyleProp {
         diagnostics
     }
 
+    /// Report a key spelled differently from the configured case
+    fn check_key_case(&self, prop_name: &str, kebab_name: &str, key_span: Span) -> Option<Diagnostic> {
+        let (expected, replacement) = match self.property_case {
+            PropertyCase::Kebab if prop_name != kebab_name => {
+                (kebab_name.to_string(), format!("\"{}\"", kebab_name))
+            }
+            PropertyCase::Camel if prop_name.contains('-') => {
+                let camel_name = to_camel_case(prop_name);
+                (camel_name.clone(), camel_name)
+            }
+            _ => return None,
+        };
+        Some(
+            Diagnostic::warning(
+                Self::NAME,
+                key_span,
+                format!("Use {} instead of {}.", expected, prop_name),
+            )
+            .with_fix(
+                Fix::new(key_span, replacement)
+                    .with_message(format!("Replace {} with {}", prop_name, expected)),
+            ),
+        )
+    }
+
     /// Parse a CSS style string into a JSON object string
     fn parse_style_string(&self, style: &str) -> String {
         let mut result = String::from("{");
@@ -250,7 +432,7 @@ impl StyleProp {
 }
 
 /// Convert camelCase to kebab-case
-fn to_kebab_case(s: &str) -> String {
+pub(crate) fn to_kebab_case(s: &str) -> String {
     let mut result = String::new();
     for (i, c) in s.chars().enumerate() {
         if c.is_uppercase() {
@@ -265,32 +447,30 @@ fn to_kebab_case(s: &str) -> String {
     result
 }
 
-/// Check if property is a valid CSS property (simplified check)
-fn is_valid_css_property(name: &str) -> bool {
-    // Common CSS properties - in a real implementation, use a comprehensive list
-    const COMMON_CSS: &[&str] = &[
-        "display", "position", "top", "right", "bottom", "left", "width", "height",
-        "min-width", "max-width", "min-height", "max-height", "margin", "margin-top",
-        "margin-right", "margin-bottom", "margin-left", "padding", "padding-top",
-        "padding-right", "padding-bottom", "padding-left", "border", "border-width",
-        "border-style", "border-color", "border-radius", "background", "background-color",
-        "color", "font", "font-size", "font-weight", "font-family", "font-style",
-        "line-height", "text-align", "text-decoration", "text-transform", "white-space",
-        "overflow", "overflow-x", "overflow-y", "visibility", "opacity", "z-index",
-        "flex", "flex-direction", "flex-wrap", "flex-grow", "flex-shrink", "flex-basis",
-        "justify-content", "align-items", "align-content", "align-self", "gap", "grid",
-        "grid-template-columns", "grid-template-rows", "grid-column", "grid-row",
-        "transform", "transition", "animation", "cursor", "pointer-events", "user-select",
-        "box-shadow", "box-sizing", "outline", "resize", "object-fit", "object-position",
-    ];
-    COMMON_CSS.contains(&name)
+/// Convert kebab-case to camelCase (`-webkit-box` becomes `WebkitBox`)
+pub(crate) fn to_camel_case(s: &str) -> String {
+    let mut result = String::new();
+    let mut upper = false;
+    for c in s.chars() {
+        if c == '-' {
+            upper = true;
+        } else if upper {
+            result.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
 }
 
-/// Check if property is a length/percentage property
-fn is_length_percentage_property(name: &str) -> bool {
-    LENGTH_PERCENTAGE_PROPS
+/// Check if a kebab-case name is a known CSS property. Vendor-prefixed names
+/// are accepted as-is.
+fn is_valid_css_property(name: &str) -> bool {
+    ["-webkit-", "-moz-", "-ms-", "-o-"]
         .iter()
-        .any(|p| name.contains(p))
+        .any(|prefix| name.starts_with(prefix))
+        || CSS_PROPERTIES.contains(&name)
 }
 
 /// Get numeric value from expression
@@ -311,6 +491,10 @@ fn get_numeric_value(expr: &Expression<'_>) -> Option<f64> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Statement;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
 
     #[test]
     fn test_rule_name() {
@@ -331,5 +515,61 @@ mod tests {
         assert!(is_valid_css_property("color"));
         assert!(is_valid_css_property("display"));
         assert!(!is_valid_css_property("invalidProp"));
+        assert!(is_valid_css_property("border-top-width"));
+        assert!(is_valid_css_property("-webkit-line-clamp"));
+    }
+
+    fn check(source: &str, rule: &StyleProp) -> Vec<Diagnostic> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected JSX element");
+        };
+        rule.check(&element.opening_element)
+    }
+
+    #[test]
+    fn test_camel_case_preference() {
+        let rule = StyleProp::new().with_property_case(PropertyCase::Camel);
+        let diagnostics = check(r#"<div style={{ "font-size": "1em", color: "red" }} />"#, &rule);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "Use fontSize instead of font-size.");
+        assert_eq!(diagnostics[0].fixes[0].replacement, "fontSize");
+        assert!(check(r#"<div style={{ fontSize: "1em" }} />"#, &rule).is_empty());
+    }
+
+    #[test]
+    fn test_missing_units() {
+        let rule = StyleProp::new();
+        let diagnostics = check(
+            r#"<div style={{ "border-top-width": 2, "line-height": 1.5, "z-index": 3, top: 0 }} />"#,
+            &rule,
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].fixes.is_empty());
+        assert_eq!(diagnostics[0].suggestions[0].message, r#"Add a "px" unit"#);
+        assert_eq!(diagnostics[0].suggestions[0].fixes[0].replacement, r#""2px""#);
+    }
+
+    #[test]
+    fn test_numbers_without_length() {
+        let rule = StyleProp::new();
+        assert!(check(
+            r#"<div style={{ "aspect-ratio": 1.5, scale: 2, "animation-delay": 1, "flex-grow": 1 }} />"#,
+            &rule,
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_style_string_option() {
+        let rule = StyleProp::new().with_style_string(StyleStringOption::Allow);
+        assert!(check(r#"<div style="color: red" />"#, &rule).is_empty());
+        assert_eq!(check(r#"<div style={`color: red`} />"#, &StyleProp::new()).len(), 1);
     }
 }
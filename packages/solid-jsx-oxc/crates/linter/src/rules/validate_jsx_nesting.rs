@@ -5,7 +5,9 @@
 //! Note: The original eslint-plugin-solid rule was a stub (`export {}`).
 //! This implementation provides basic validation of common nesting issues.
 
-use oxc_ast::ast::{JSXChild, JSXElement, JSXElementName};
+use common::{walk_element, JsxVisitor, VisitFlow};
+use oxc_ast::ast::{JSXElement, JSXElementName};
+use oxc_span::Span;
 
 use crate::diagnostic::Diagnostic;
 use crate::{RuleCategory, RuleMeta};
@@ -17,6 +19,8 @@ pub struct ValidateJsxNesting;
 impl RuleMeta for ValidateJsxNesting {
     const NAME: &'static str = "validate-jsx-nesting";
     const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const DESCRIPTION: &'static str = "Validate proper HTML element nesting in JSX.";
+    const DEFAULT_ENABLED: bool = false;
 }
 
 /// Elements that can only contain specific children
@@ -63,63 +67,103 @@ impl ValidateJsxNesting {
         element: &JSXElement<'a>,
         parent_name: Option<&str>,
     ) -> Vec<Diagnostic> {
-        let mut diagnostics = Vec::new();
-
-        let element_name = match &element.opening_element.name {
-            JSXElementName::Identifier(ident) => ident.name.as_str(),
-            JSXElementName::IdentifierReference(ident) => ident.name.as_str(),
-            _ => return diagnostics,
+        let mut checker = NestingChecker {
+            parents: parent_name.map(|p| p.to_string()).into_iter().collect(),
+            diagnostics: Vec::new(),
         };
+        walk_element(&mut checker, element);
+        checker.diagnostics
+    }
 
-        // Skip components (capitalized)
-        if element_name.chars().next().is_some_and(|c| c.is_uppercase()) {
-            return diagnostics;
-        }
-
-        // Check if parent restricts children
-        if let Some(parent) = parent_name {
-            // Check restrictive parents
-            if let Some((_, allowed)) = RESTRICTIVE_PARENTS.iter().find(|(p, _)| *p == parent) {
-                if !allowed.contains(&element_name) && !element_name.chars().next().is_some_and(|c| c.is_uppercase()) {
-                    diagnostics.push(Diagnostic::warning(
-                        Self::NAME,
-                        element.opening_element.span,
-                        format!("<{}> cannot be a child of <{}>.", element_name, parent),
-                    ));
-                }
-            }
-
-            // Check no self-nesting
-            if NO_SELF_NESTING.contains(&parent) && parent == element_name {
+    fn check_pair(element_name: &str, parent: &str, span: Span, diagnostics: &mut Vec<Diagnostic>) {
+        // Check restrictive parents
+        if let Some((_, allowed)) = RESTRICTIVE_PARENTS.iter().find(|(p, _)| *p == parent) {
+            if !allowed.contains(&element_name) {
                 diagnostics.push(Diagnostic::warning(
                     Self::NAME,
-                    element.opening_element.span,
-                    format!("<{}> cannot be nested inside another <{}>.", element_name, parent),
+                    span,
+                    format!("<{}> cannot be a child of <{}>.", element_name, parent),
                 ));
             }
+        }
 
-            // Check block inside inline
-            if INLINE_ELEMENTS.contains(&parent) && BLOCK_ELEMENTS.contains(&element_name) {
-                diagnostics.push(Diagnostic::warning(
-                    Self::NAME,
-                    element.opening_element.span,
-                    format!(
-                        "Block element <{}> cannot be a child of inline element <{}>.",
-                        element_name, parent
-                    ),
-                ));
-            }
+        // Check no self-nesting
+        if NO_SELF_NESTING.contains(&parent) && parent == element_name {
+            diagnostics.push(Diagnostic::warning(
+                Self::NAME,
+                span,
+                format!(
+                    "<{}> cannot be nested inside another <{}>.",
+                    element_name, parent
+                ),
+            ));
         }
 
-        // Recursively check children
-        for child in &element.children {
-            if let JSXChild::Element(child_elem) = child {
-                diagnostics.extend(self.check(child_elem, Some(element_name)));
-            }
+        // Check block inside inline
+        if INLINE_ELEMENTS.contains(&parent) && BLOCK_ELEMENTS.contains(&element_name) {
+            diagnostics.push(Diagnostic::warning(
+                Self::NAME,
+                span,
+                format!(
+                    "Block element <{}> cannot be a child of inline element <{}>.",
+                    element_name, parent
+                ),
+            ));
         }
+    }
+}
+
+/// Walks a JSX tree keeping a stack of enclosing native element names
+struct NestingChecker {
+    parents: Vec<String>,
+    diagnostics: Vec<Diagnostic>,
+}
 
-        diagnostics
+impl<'a> JsxVisitor<'a> for NestingChecker {
+    fn enter_element(&mut self, element: &JSXElement<'a>) -> VisitFlow {
+        let Some(element_name) = native_element_name(element) else {
+            return VisitFlow::SkipChildren;
+        };
+
+        if let Some(parent) = self.parents.last() {
+            ValidateJsxNesting::check_pair(
+                element_name,
+                parent,
+                element.opening_element.span,
+                &mut self.diagnostics,
+            );
+        }
+
+        self.parents.push(element_name.to_string());
+        VisitFlow::Continue
+    }
+
+    fn leave_element(&mut self, element: &JSXElement<'a>) {
+        // Only native elements we descended into were pushed
+        if native_element_name(element).is_some() {
+            self.parents.pop();
+        }
+    }
+}
+
+/// Name of a native element, or `None` for components and member/namespaced tags
+pub(crate) fn native_element_name<'a>(element: &JSXElement<'a>) -> Option<&'a str> {
+    let element_name = match &element.opening_element.name {
+        JSXElementName::Identifier(ident) => ident.name.as_str(),
+        JSXElementName::IdentifierReference(ident) => ident.name.as_str(),
+        _ => return None,
+    };
+
+    // Skip components (capitalized)
+    if element_name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_uppercase())
+    {
+        return None;
     }
+
+    Some(element_name)
 }
 
 #[cfg(test)]
@@ -130,4 +174,30 @@ mod tests {
     fn test_rule_name() {
         assert_eq!(ValidateJsxNesting::NAME, "validate-jsx-nesting");
     }
+
+    #[test]
+    fn test_nesting_through_fragment() {
+        use oxc_allocator::Allocator;
+        use oxc_ast::ast::{Expression, Statement};
+        use oxc_parser::Parser;
+        use oxc_span::SourceType;
+
+        let allocator = Allocator::default();
+        let source = "<ul><><div /></><li><p /></li></ul>";
+        let program = Parser::new(&allocator, source, SourceType::jsx())
+            .parse()
+            .program;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+            panic!("expected expression statement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("expected JSX element");
+        };
+
+        let diagnostics = ValidateJsxNesting::new().check(element, None);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0]
+            .message
+            .contains("<div> cannot be a child of <ul>"));
+    }
 }
@@ -3,20 +3,32 @@
 //! This module provides a `SemanticLintRunner` that integrates with oxc_semantic
 //! for proper scope resolution and symbol tracking.
 
+use std::cell::Cell;
+use std::sync::Arc;
+
 use oxc_ast::ast::{
-    Argument, ArrowFunctionExpression, CallExpression, Expression, Function,
+    Argument, ArrowFunctionExpression, BindingPattern, CallExpression, Expression, Function,
     ImportDeclaration, ImportDeclarationSpecifier, JSXElementName, JSXMemberExpressionObject,
-    JSXOpeningElement, Program, Statement,
+    JSXOpeningElement, Program, Statement, VariableDeclarator,
 };
 use oxc_ast_visit::{walk, Visit};
 use oxc_semantic::{ScopeId, Semantic, SymbolId};
 use oxc_span::{GetSpan, SourceType, Span};
+use oxc_syntax::scope::ScopeFlags;
 use rustc_hash::FxHashSet;
 
+use crate::config::default_rules;
+use crate::context::LintContext;
 use crate::diagnostic::Diagnostic;
-use crate::rules::jsx_no_undef::JsxNoUndef;
-use crate::rules::{ComponentsReturnOnce, NoDestructure, Reactivity};
-use crate::utils::is_dom_element;
+use crate::plugin::Rule;
+use crate::rules::jsx_no_undef::{JsxNoUndef, JsxNoUndefOptions, UndefinedIdent};
+use crate::rules::reactivity::{may_be_function, REACTIVE_PRIMITIVES};
+use crate::rules::{
+    ComponentNameCasing, ComponentsReturnOnce, NoDestructure, NoDirectStoreMutation, NoForwardRef,
+    NoPropsMutation, NoProxyApis, NoReactiveCallsAtModuleScope, NoRenderInComponent, NoThisInComponents,
+    NoUnusedSignals, NoUnusedVars, PreferMergeProps, PreferOptionalShow, Reactivity,
+};
+use crate::utils::{is_component_name, is_dom_element};
 use crate::RuleMeta;
 
 /// Solid.js module sources
@@ -26,26 +38,44 @@ const SOLID_SOURCES: &[&str] = &["solid-js", "solid-js/store", "solid-js/web"];
 #[derive(Debug, Clone, Default)]
 pub struct SemanticRulesConfig {
     pub jsx_no_undef: bool,
+    pub jsx_no_undef_options: JsxNoUndefOptions,
     pub jsx_uses_vars: bool,
+    pub component_name_casing: bool,
     pub components_return_once: bool,
     pub reactivity: bool,
     pub no_destructure: bool,
+    pub no_direct_store_mutation: bool,
+    pub no_forward_ref: bool,
+    pub no_props_mutation: bool,
+    /// Only for targets without `Proxy`, so off by default
+    pub no_proxy_apis: bool,
+    pub no_reactive_calls_at_module_scope: bool,
+    pub no_render_in_component: bool,
+    pub no_this_in_components: bool,
+    pub prefer_merge_props: bool,
+    pub prefer_optional_show: bool,
+    pub no_unused_signals: bool,
+    /// Unused-variable check that understands JSX-only uses. Off by default,
+    /// since most setups already run a general `no-unused-vars`.
+    pub no_unused_vars: bool,
+    /// Rules from outside this crate, run in the same walk
+    pub plugins: Vec<Arc<dyn Rule>>,
 }
 
 impl SemanticRulesConfig {
+    /// Each rule on or off as its `RuleMeta::DEFAULT_ENABLED` says
     pub fn all() -> Self {
-        Self {
-            jsx_no_undef: true,
-            jsx_uses_vars: true,
-            components_return_once: true,
-            reactivity: true,
-            no_destructure: true,
-        }
+        default_rules().1
     }
 
     pub fn none() -> Self {
         Self::default()
     }
+
+    pub fn with_plugin(mut self, rule: Arc<dyn Rule>) -> Self {
+        self.plugins.push(rule);
+        self
+    }
 }
 
 /// Result of semantic linting
@@ -76,6 +106,12 @@ pub struct SemanticLintRunner<'a> {
     functions_with_jsx: FxHashSet<Span>,
     /// Track if we're inside a JSX expression
     jsx_depth: usize,
+    /// Variable the next visited function is assigned to (`const App = () => ...`)
+    declarator_binding: Option<SymbolId>,
+    /// Undefined JSX identifiers, reported together once the whole program is seen
+    undefined_idents: Vec<UndefinedIdent>,
+    /// Context shared by plugin rules
+    plugin_ctx: LintContext<'a>,
 }
 
 impl<'a> SemanticLintRunner<'a> {
@@ -97,6 +133,9 @@ impl<'a> SemanticLintRunner<'a> {
             scope_stack: vec![semantic.scoping().root_scope_id()],
             functions_with_jsx: FxHashSet::default(),
             jsx_depth: 0,
+            declarator_binding: None,
+            undefined_idents: Vec::new(),
+            plugin_ctx: LintContext::new(source_text, source_type).with_semantic(semantic),
         }
     }
 
@@ -105,9 +144,99 @@ impl<'a> SemanticLintRunner<'a> {
         // Collect imports from solid-js
         self.collect_solid_imports(program);
 
+        // Collect everything used as a JSX tag, so components are known before
+        // their declarations are visited
+        let mut collector = ComponentCollector {
+            semantic: self.semantic,
+            symbols: &mut self.component_symbols,
+        };
+        collector.visit_program(program);
+
+        if !self.config.plugins.is_empty() {
+            for name in &self.solid_imports {
+                self.plugin_ctx.register_solid_import(name.clone());
+            }
+            for &symbol in &self.component_symbols {
+                self.plugin_ctx.mark_component(symbol);
+            }
+        }
+
         // Visit AST and run rules
         self.visit_program(program);
 
+        for rule in &self.config.plugins {
+            rule.run_on_program(program, &mut self.plugin_ctx);
+        }
+
+        if self.config.jsx_no_undef {
+            let rule = JsxNoUndef::with_options(self.config.jsx_no_undef_options.clone());
+            let undefined = std::mem::take(&mut self.undefined_idents);
+            self.diagnostics
+                .extend(rule.generate_diagnostics(undefined, Some(program)));
+        }
+
+        if self.config.no_proxy_apis {
+            let rule = NoProxyApis::new();
+            self.diagnostics
+                .extend(rule.check_program(program, Some(self.semantic)));
+        }
+
+        if self.config.component_name_casing {
+            let rule = ComponentNameCasing::new();
+            self.diagnostics.extend(rule.check(program, self.semantic));
+        }
+
+        if self.config.no_direct_store_mutation {
+            let rule = NoDirectStoreMutation::new();
+            self.diagnostics
+                .extend(rule.check(program, self.semantic, self.source_text));
+        }
+
+        if self.config.no_forward_ref {
+            let rule = NoForwardRef::new();
+            self.diagnostics.extend(rule.check_program(program));
+        }
+
+        if self.config.no_reactive_calls_at_module_scope {
+            let rule = NoReactiveCallsAtModuleScope::new();
+            self.diagnostics.extend(rule.check_program(program));
+        }
+
+        if self.config.no_render_in_component {
+            let rule = NoRenderInComponent::new();
+            self.diagnostics.extend(rule.check(program, self.semantic));
+        }
+
+        if self.config.no_this_in_components {
+            let rule = NoThisInComponents::new();
+            self.diagnostics.extend(rule.check_program(program));
+        }
+
+        if self.config.prefer_optional_show {
+            let rule = PreferOptionalShow::new();
+            self.diagnostics.extend(rule.check(program, self.semantic));
+        }
+
+        if self.config.no_unused_signals {
+            let rule = NoUnusedSignals::new();
+            self.diagnostics.extend(rule.check(self.semantic));
+        }
+
+        if self.config.no_unused_vars {
+            let rule = NoUnusedVars::new();
+            self.diagnostics
+                .extend(rule.check(self.semantic, &self.used_symbols));
+        }
+
+        // Tracking-scope analysis needs the whole program, so it runs as its own pass
+        if self.config.reactivity {
+            let rule = Reactivity::new();
+            self.diagnostics
+                .extend(rule.check_program(self.semantic, self.source_text, program));
+        }
+
+        self.diagnostics.extend(self.plugin_ctx.into_diagnostics());
+
         SemanticLintResult {
             diagnostics: self.diagnostics,
             used_symbols: self.used_symbols,
@@ -128,6 +257,11 @@ impl<'a> SemanticLintRunner<'a> {
             .find_binding(self.current_scope(), name.into())
     }
 
+    /// Whether a function's binding is used as a JSX tag somewhere
+    fn is_component_binding(&self, binding: Option<SymbolId>) -> bool {
+        binding.is_some_and(|symbol| self.component_symbols.contains(&symbol))
+    }
+
     /// Check if we're inside a JSX expression context
     fn is_inside_jsx(&self) -> bool {
         self.jsx_depth > 0
@@ -146,13 +280,16 @@ impl<'a> SemanticLintRunner<'a> {
 
     fn process_import(&mut self, import: &ImportDeclaration<'a>) {
         let source = import.source.value.as_str();
-        if !SOLID_SOURCES.iter().any(|s| source.starts_with(s)) {
+        // `import type` brings in no runtime names
+        if !SOLID_SOURCES.iter().any(|s| source.starts_with(s)) || import.import_kind.is_type() {
             return;
         }
 
         if let Some(specifiers) = &import.specifiers {
             for spec in specifiers {
                 match spec {
+                    ImportDeclarationSpecifier::ImportSpecifier(named)
+                        if named.import_kind.is_type() => {}
                     ImportDeclarationSpecifier::ImportSpecifier(named) => {
                         let local_name = named.local.name.as_str();
                         self.solid_imports.insert(local_name.to_string());
@@ -174,25 +311,31 @@ impl<'a> SemanticLintRunner<'a> {
     fn check_jsx_opening_element(&mut self, opening: &JSXOpeningElement<'a>) {
         let scope_id = self.current_scope();
 
+        if self.config.jsx_no_undef {
+            let rule = JsxNoUndef::with_options(self.config.jsx_no_undef_options.clone());
+            self.undefined_idents
+                .extend(rule.check(opening, self.semantic.scoping(), scope_id));
+        }
+
         // Extract the identifier name and check if it's a component
         match &opening.name {
             JSXElementName::Identifier(ident) => {
                 let name = &ident.name;
                 if !is_dom_element(name) && name.as_str() != "this" {
-                    self.check_jsx_identifier(name, ident.span, scope_id, true);
+                    self.mark_jsx_identifier(name, scope_id, true);
                 }
             }
             JSXElementName::IdentifierReference(ident) => {
                 let name = &ident.name;
                 if !is_dom_element(name) && name.as_str() != "this" {
-                    self.check_jsx_identifier(name, ident.span, scope_id, true);
+                    self.mark_jsx_identifier(name, scope_id, true);
                 }
             }
             JSXElementName::MemberExpression(member) => {
                 // For <Foo.Bar>, check the root (Foo)
-                if let Some((name, span)) = self.get_member_root(member) {
+                if let Some((name, _)) = self.get_member_root(member) {
                     if name != "this" {
-                        self.check_jsx_identifier(&name, span, scope_id, false);
+                        self.mark_jsx_identifier(&name, scope_id, false);
                     }
                 }
             }
@@ -205,57 +348,28 @@ impl<'a> SemanticLintRunner<'a> {
                 if let oxc_ast::ast::JSXAttributeName::NamespacedName(ns) = &jsx_attr.name {
                     if ns.namespace.name == "use" {
                         let directive_name = ns.name.name.as_str();
-                        self.check_jsx_identifier(
-                            directive_name,
-                            ns.name.span,
-                            scope_id,
-                            false,
-                        );
+                        self.mark_jsx_identifier(directive_name, scope_id, false);
                     }
                 }
             }
         }
     }
 
-    fn check_jsx_identifier(
-        &mut self,
-        name: &str,
-        span: Span,
-        scope_id: ScopeId,
-        is_component: bool,
-    ) {
+    /// Mark a JSX tag or directive as used, and tags as components
+    fn mark_jsx_identifier(&mut self, name: &str, scope_id: ScopeId, is_component: bool) {
         let scoping = self.semantic.scoping();
-        let symbol_id = scoping.find_binding(scope_id, name.into());
+        let Some(symbol_id) = scoping.find_binding(scope_id, name.into()) else {
+            return;
+        };
 
-        if let Some(symbol_id) = symbol_id {
-            // jsx-uses-vars: mark as used
-            if self.config.jsx_uses_vars {
-                self.used_symbols.insert(symbol_id);
-            }
+        // jsx-uses-vars: mark as used
+        if self.config.jsx_uses_vars || self.config.no_unused_vars {
+            self.used_symbols.insert(symbol_id);
+        }
 
-            // If it's used as a component tag, mark it as a component
-            if is_component {
-                self.component_symbols.insert(symbol_id);
-            }
-        } else if self.config.jsx_no_undef {
-            // Check if it's a Solid auto-import component
-            let auto_components = ["Show", "For", "Index", "Switch", "Match"];
-            if auto_components.contains(&name) {
-                self.diagnostics.push(
-                    Diagnostic::error(
-                        JsxNoUndef::NAME,
-                        span,
-                        format!("'{}' should be imported from 'solid-js'.", name),
-                    )
-                    .with_help(format!("Add: import {{ {} }} from \"solid-js\";", name)),
-                );
-            } else {
-                self.diagnostics.push(Diagnostic::error(
-                    JsxNoUndef::NAME,
-                    span,
-                    format!("'{}' is not defined.", name),
-                ));
-            }
+        // If it's used as a component tag, mark it as a component
+        if is_component {
+            self.component_symbols.insert(symbol_id);
         }
     }
 
@@ -293,9 +407,10 @@ impl<'a> SemanticLintRunner<'a> {
         }
 
         // Heuristic 1: PascalCase name
-        let is_pascal_case = func.id.as_ref().is_some_and(|id| {
-            id.name.chars().next().is_some_and(|c| c.is_uppercase())
-        });
+        let is_pascal_case = func
+            .id
+            .as_ref()
+            .is_some_and(|id| is_component_name(&id.name));
 
         // Heuristic 2: Returns JSX
         let returns_jsx = func.body.as_ref().is_some_and(|body| {
@@ -316,14 +431,27 @@ impl<'a> SemanticLintRunner<'a> {
             let rule = ComponentsReturnOnce::new();
             if func.body.is_some() {
                 self.diagnostics.extend(
-                    rule.check_function(func, true, self.is_inside_jsx())
+                    rule.check_function(
+                        func,
+                        true,
+                        self.is_inside_jsx(),
+                        self.semantic.nodes().program(),
+                        self.source_text,
+                    )
                 );
             }
         }
     }
 
-    fn check_arrow_component(&mut self, arrow: &ArrowFunctionExpression<'a>) {
-        if !self.config.components_return_once && !self.config.no_destructure {
+    fn check_arrow_component(
+        &mut self,
+        arrow: &ArrowFunctionExpression<'a>,
+        binding: Option<SymbolId>,
+    ) {
+        if !self.config.components_return_once
+            && !self.config.no_destructure
+            && !self.config.no_props_mutation
+        {
             return;
         }
 
@@ -334,22 +462,52 @@ impl<'a> SemanticLintRunner<'a> {
 
         // Check if returns JSX
         let returns_jsx = NoDestructure::body_has_jsx(&arrow.body);
-        if !returns_jsx {
+        if !returns_jsx && !self.is_component_binding(binding) {
             return;
         }
 
-        if self.config.components_return_once {
+        if self.config.components_return_once && returns_jsx {
             let rule = ComponentsReturnOnce::new();
             self.diagnostics.extend(
-                rule.check_arrow(arrow, true, self.is_inside_jsx())
+                rule.check_arrow(
+                    arrow,
+                    true,
+                    self.is_inside_jsx(),
+                    self.semantic.nodes().program(),
+                    self.source_text,
+                )
             );
         }
 
         if self.config.no_destructure {
             let rule = NoDestructure::new();
-            self.diagnostics.extend(
-                rule.check_arrow(arrow, returns_jsx, self.is_inside_jsx())
-            );
+            self.diagnostics.extend(rule.check_component(
+                &arrow.params,
+                Some(&arrow.body),
+                arrow.scope_id(),
+                self.semantic,
+                self.source_text,
+            ));
+        }
+
+        if self.config.no_props_mutation {
+            let rule = NoPropsMutation::new();
+            self.diagnostics.extend(rule.check_component(
+                &arrow.params,
+                Some(&arrow.body),
+                self.semantic,
+                self.source_text,
+            ));
+        }
+
+        if self.config.prefer_merge_props {
+            let rule = PreferMergeProps::new();
+            self.diagnostics.extend(rule.check_component(
+                &arrow.params,
+                Some(&arrow.body),
+                self.semantic,
+                self.source_text,
+            ));
         }
     }
 
@@ -373,27 +531,13 @@ impl<'a> SemanticLintRunner<'a> {
             return;
         };
 
-        let reactive_primitives = [
-            "createEffect",
-            "createMemo",
-            "createComputed",
-            "createRenderEffect",
-            "createReaction",
-            "on",
-        ];
-
-        if reactive_primitives.contains(&callee_name) {
+        if REACTIVE_PRIMITIVES.contains(&callee_name) {
             if let Some(first_arg) = call.arguments.first() {
                 match first_arg {
                     Argument::SpreadElement(_) => {}
                     arg => {
                         if let Some(expr) = arg.as_expression() {
-                            if !matches!(
-                                expr,
-                                Expression::ArrowFunctionExpression(_)
-                                    | Expression::FunctionExpression(_)
-                                    | Expression::Identifier(_)
-                            ) {
+                            if !may_be_function(callee_name, expr) {
                                 self.diagnostics.push(Diagnostic::warning(
                                     Reactivity::NAME,
                                     expr.span(),
@@ -412,22 +556,59 @@ impl<'a> SemanticLintRunner<'a> {
 }
 
 impl<'a> Visit<'a> for SemanticLintRunner<'a> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        let scope_id = scope_id.get().unwrap_or_else(|| self.current_scope());
+        self.scope_stack.push(scope_id);
+    }
+
+    fn leave_scope(&mut self) {
+        self.scope_stack.pop();
+    }
+
     fn visit_program(&mut self, program: &Program<'a>) {
         walk::walk_program(self, program);
     }
 
-    fn visit_function(&mut self, func: &Function<'a>, _flags: oxc_syntax::scope::ScopeFlags) {
+    fn visit_function(&mut self, func: &Function<'a>, _flags: ScopeFlags) {
         // Check function as component
         self.check_function_component(func);
 
         // Check for destructured props
-        if self.config.no_destructure && !self.is_inside_jsx() {
+        let binding = self
+            .declarator_binding
+            .take()
+            .or_else(|| func.id.as_ref().and_then(|id| id.symbol_id.get()));
+        if (self.config.no_destructure || self.config.no_props_mutation) && !self.is_inside_jsx() {
             let returns_jsx = func.body.as_ref().is_some_and(|b| NoDestructure::body_has_jsx(b));
-            if returns_jsx {
-                let rule = NoDestructure::new();
-                self.diagnostics.extend(
-                    rule.check_function(func, returns_jsx, self.is_inside_jsx())
-                );
+            if returns_jsx || self.is_component_binding(binding) {
+                if self.config.no_destructure {
+                    let rule = NoDestructure::new();
+                    self.diagnostics.extend(rule.check_component(
+                        &func.params,
+                        func.body.as_deref(),
+                        func.scope_id(),
+                        self.semantic,
+                        self.source_text,
+                    ));
+                }
+                if self.config.no_props_mutation {
+                    let rule = NoPropsMutation::new();
+                    self.diagnostics.extend(rule.check_component(
+                        &func.params,
+                        func.body.as_deref(),
+                        self.semantic,
+                        self.source_text,
+                    ));
+                }
+                if self.config.prefer_merge_props {
+                    let rule = PreferMergeProps::new();
+                    self.diagnostics.extend(rule.check_component(
+                        &func.params,
+                        func.body.as_deref(),
+                        self.semantic,
+                        self.source_text,
+                    ));
+                }
             }
         }
 
@@ -435,8 +616,23 @@ impl<'a> Visit<'a> for SemanticLintRunner<'a> {
         walk::walk_function(self, func, _flags);
     }
 
+    fn visit_variable_declarator(&mut self, declarator: &VariableDeclarator<'a>) {
+        if let (BindingPattern::BindingIdentifier(ident), Some(init)) =
+            (&declarator.id, &declarator.init)
+        {
+            if matches!(
+                init.get_inner_expression(),
+                Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_)
+            ) {
+                self.declarator_binding = ident.symbol_id.get();
+            }
+        }
+        walk::walk_variable_declarator(self, declarator);
+    }
+
     fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
-        self.check_arrow_component(arrow);
+        let binding = self.declarator_binding.take();
+        self.check_arrow_component(arrow, binding);
         walk::walk_arrow_function_expression(self, arrow);
     }
 
@@ -446,6 +642,9 @@ impl<'a> Visit<'a> for SemanticLintRunner<'a> {
     }
 
     fn visit_jsx_element(&mut self, element: &oxc_ast::ast::JSXElement<'a>) {
+        for rule in &self.config.plugins {
+            rule.run_on_jsx_element(element, &mut self.plugin_ctx);
+        }
         self.jsx_depth += 1;
         walk::walk_jsx_element(self, element);
         self.jsx_depth -= 1;
@@ -459,10 +658,34 @@ impl<'a> Visit<'a> for SemanticLintRunner<'a> {
 
     fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
         self.check_call_expression(call);
+        for rule in &self.config.plugins {
+            rule.run_on_call_expression(call, &mut self.plugin_ctx);
+        }
         walk::walk_call_expression(self, call);
     }
 }
 
+/// Collects the symbols of every identifier used as a JSX tag
+struct ComponentCollector<'s, 'a> {
+    semantic: &'a Semantic<'a>,
+    symbols: &'s mut FxHashSet<SymbolId>,
+}
+
+impl<'a> Visit<'a> for ComponentCollector<'_, 'a> {
+    fn visit_jsx_opening_element(&mut self, opening: &JSXOpeningElement<'a>) {
+        if let JSXElementName::IdentifierReference(ident) = &opening.name {
+            let symbol = ident
+                .reference_id
+                .get()
+                .and_then(|id| self.semantic.scoping().get_reference(id).symbol_id());
+            if let Some(symbol) = symbol {
+                self.symbols.insert(symbol);
+            }
+        }
+        walk::walk_jsx_opening_element(self, opening);
+    }
+}
+
 /// Convenience function to run semantic linting
 pub fn lint_with_semantic<'a>(
     semantic: &'a Semantic<'a>,
@@ -494,7 +717,7 @@ mod tests {
 
     fn parse_and_lint(source: &str) -> SemanticLintResult {
         let allocator = Allocator::default();
-        let source_type = SourceType::jsx();
+        let source_type = SourceType::tsx();
         let ret = Parser::new(&allocator, source, source_type).parse();
 
         let semantic_ret = SemanticBuilder::new()
@@ -661,6 +884,121 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_reactivity_untracked_prop_read() {
+        let result = parse_and_lint(
+            r#"
+            function Title(props) {
+                const text = props.text;
+                return <h1>{text}</h1>;
+            }
+            "#,
+        );
+        assert!(result.diagnostics.iter().any(|d|
+            d.rule == "reactivity" && d.message.contains("props.text")
+        ));
+    }
+
+    #[test]
+    fn test_no_destructure_component_used_in_jsx() {
+        let result = parse_and_lint(
+            r#"
+            const Wrapper = ({ children }) => children;
+            function App() {
+                return <Wrapper>hi</Wrapper>;
+            }
+            "#,
+        );
+        assert!(result.diagnostics.iter().any(|d| d.rule == "no-destructure"));
+    }
+
+    #[test]
+    fn test_jsx_no_undef_resolves_local_bindings() {
+        let result = parse_and_lint(
+            r#"
+            function App() {
+                const Local = () => <span />;
+                function tooltip(el) {}
+                return <div use:tooltip><Local /></div>;
+            }
+            "#,
+        );
+        assert!(!result.diagnostics.iter().any(|d| d.rule == "jsx-no-undef"));
+    }
+
+    #[test]
+    fn test_jsx_no_undef_type_only_imports() {
+        let result = parse_and_lint(
+            r#"
+            import type { Card } from "./card";
+            import { type Badge, Avatar } from "./avatar";
+            import type { Component } from "solid-js";
+            const App: Component = () => <div><Card /><Badge /><Avatar /></div>;
+            "#,
+        );
+        let messages: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule == "jsx-no-undef")
+            .map(|d| d.message.as_str())
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "'Card' is imported as a type only.",
+                "'Badge' is imported as a type only."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_jsx_no_undef_extends_existing_import() {
+        let result = parse_and_lint(
+            r#"import { createSignal } from "solid-js";
+            function App() {
+                return <Show when={true}><Portal /></Show>;
+            }
+            "#,
+        );
+        let fixes: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule == "jsx-no-undef")
+            .flat_map(|d| &d.fixes)
+            .map(|f| f.replacement.as_str())
+            .collect();
+        assert_eq!(
+            fixes,
+            [", Show", "\nimport { Portal } from \"solid-js/web\";"]
+        );
+    }
+
+    #[test]
+    fn test_no_unused_vars_counts_directives() {
+        let allocator = Allocator::default();
+        let source = r#"
+            import { tooltip, unusedDirective } from "./directives";
+            export function App() {
+                return <div use:tooltip />;
+            }
+        "#;
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).parse();
+        let semantic_ret = SemanticBuilder::new().build(&ret.program);
+        let config = SemanticRulesConfig {
+            no_unused_vars: true,
+            ..SemanticRulesConfig::none()
+        };
+        let result = lint_with_semantic_config(
+            &semantic_ret.semantic,
+            source,
+            SourceType::jsx(),
+            &ret.program,
+            config,
+        );
+        let messages: Vec<_> = result.diagnostics.iter().map(|d| d.message.as_str()).collect();
+        assert_eq!(messages, ["'unusedDirective' is imported but never used."]);
+    }
+
     #[test]
     fn test_solid_imports_tracked() {
         let result = parse_and_lint(
@@ -0,0 +1,67 @@
+//! Linting the text of a module: parsing it, then running both lint runners
+//!
+//! `solid-lint`, the language server and the WebAssembly bindings all lint
+//! source text the same way; syntax errors stop a module from being linted
+//! and come back as diagnostics of [`PARSE_ERROR_RULE`].
+
+use std::sync::Arc;
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{SourceType, Span};
+
+use crate::{Diagnostic, LintConfig, LintRunner, Rule, SemanticLintRunner, VisitorLintContext};
+
+/// Rule name used for syntax errors, which stop a file from being linted
+pub const PARSE_ERROR_RULE: &str = "parse-error";
+
+/// Lint `source_text` with `config`, already resolved for its file, and the
+/// `plugins` it does not turn off. Diagnostics are sorted by position.
+pub fn lint_source(
+    source_text: &str,
+    source_type: SourceType,
+    config: &LintConfig,
+    plugins: &[Arc<dyn Rule>],
+) -> Vec<Diagnostic> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if !ret.errors.is_empty() {
+        return ret
+            .errors
+            .iter()
+            .map(|error| {
+                let span = error
+                    .labels
+                    .as_ref()
+                    .and_then(|labels| labels.first())
+                    .map(|label| {
+                        Span::new(label.offset() as u32, (label.offset() + label.len()) as u32)
+                    })
+                    .unwrap_or_default();
+                Diagnostic::error(PARSE_ERROR_RULE, span, error.message.to_string())
+            })
+            .collect();
+    }
+
+    let mut semantic_rules = config.semantic_rules_config();
+    for rule in plugins {
+        if config.plugin_enabled(rule.name()) {
+            semantic_rules = semantic_rules.with_plugin(Arc::clone(rule));
+        }
+    }
+
+    let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+    let ctx = VisitorLintContext::new(source_text, source_type);
+    let mut diagnostics = LintRunner::new(ctx, config.rules_config())
+        .run(&ret.program)
+        .diagnostics;
+    diagnostics.extend(
+        SemanticLintRunner::new(&semantic, source_text, source_type, semantic_rules)
+            .run(&ret.program)
+            .diagnostics,
+    );
+    config.apply_severity(&mut diagnostics);
+    diagnostics.sort_by_key(|d| (d.start, d.end));
+    diagnostics
+}
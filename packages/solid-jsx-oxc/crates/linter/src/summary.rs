@@ -0,0 +1,107 @@
+//! Counting diagnostics by severity and turning the counts into an exit status
+//!
+//! CI usually fails on errors and tolerates warnings up to a budget
+//! (`--max-warnings`), so the two outcomes get different exit codes.
+
+use crate::diagnostic::{Diagnostic, DiagnosticSeverity};
+
+/// How a lint run ended, as a process exit code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitStatus {
+    /// No errors, and no more warnings than allowed
+    Success,
+    /// At least one error-severity diagnostic
+    LintErrors,
+    /// No errors, but more warnings than `--max-warnings` allows
+    TooManyWarnings,
+    /// The linter could not run, e.g. because of an invalid config file
+    Fatal,
+}
+
+impl ExitStatus {
+    pub fn code(self) -> i32 {
+        match self {
+            Self::Success => 0,
+            Self::LintErrors => 1,
+            Self::TooManyWarnings => 2,
+            Self::Fatal => 3,
+        }
+    }
+}
+
+/// Diagnostic counts across one or more files
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LintSummary {
+    pub errors: usize,
+    pub warnings: usize,
+}
+
+impl LintSummary {
+    pub fn from_diagnostics(diagnostics: &[Diagnostic]) -> Self {
+        let mut summary = Self::default();
+        summary.add(diagnostics);
+        summary
+    }
+
+    /// Count more diagnostics; info and hints count as neither
+    pub fn add(&mut self, diagnostics: &[Diagnostic]) {
+        for diagnostic in diagnostics {
+            match diagnostic.severity {
+                DiagnosticSeverity::Error => self.errors += 1,
+                DiagnosticSeverity::Warning => self.warnings += 1,
+                DiagnosticSeverity::Info | DiagnosticSeverity::Hint => {}
+            }
+        }
+    }
+
+    /// Errors always fail; warnings only fail past `max_warnings`, when set
+    pub fn exit_status(&self, max_warnings: Option<usize>) -> ExitStatus {
+        if self.errors > 0 {
+            ExitStatus::LintErrors
+        } else if max_warnings.is_some_and(|max| self.warnings > max) {
+            ExitStatus::TooManyWarnings
+        } else {
+            ExitStatus::Success
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_span::Span;
+
+    fn summary(errors: usize, warnings: usize) -> LintSummary {
+        let mut diagnostics = Vec::new();
+        diagnostics.extend((0..errors).map(|_| Diagnostic::error("a", Span::new(0, 1), "")));
+        diagnostics.extend((0..warnings).map(|_| Diagnostic::warning("b", Span::new(0, 1), "")));
+        diagnostics.push(
+            Diagnostic::warning("c", Span::new(0, 1), "").with_severity(DiagnosticSeverity::Hint),
+        );
+        LintSummary::from_diagnostics(&diagnostics)
+    }
+
+    #[test]
+    fn test_counts() {
+        assert_eq!(
+            summary(2, 3),
+            LintSummary {
+                errors: 2,
+                warnings: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_exit_status() {
+        assert_eq!(summary(0, 5).exit_status(None), ExitStatus::Success);
+        assert_eq!(summary(0, 5).exit_status(Some(5)), ExitStatus::Success);
+        assert_eq!(
+            summary(0, 6).exit_status(Some(5)),
+            ExitStatus::TooManyWarnings
+        );
+        assert_eq!(summary(1, 0).exit_status(Some(5)), ExitStatus::LintErrors);
+        assert_eq!(summary(1, 9).exit_status(Some(5)).code(), 1);
+        assert_eq!(ExitStatus::TooManyWarnings.code(), 2);
+    }
+}
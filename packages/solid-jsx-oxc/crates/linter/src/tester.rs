@@ -0,0 +1,544 @@
+//! Testing rules with lists of valid and invalid code, like ESLint's RuleTester
+//!
+//! A rule's tests list code the rule accepts and code it reports, with the
+//! diagnostics expected for each: their message, the source text or span they
+//! point at, and the code after applying their fixes. Every case is parsed as
+//! TSX (or as its `filename` says) and linted with only the tested rule turned
+//! on, by whichever runner runs it. All failing cases of a tester are reported
+//! together, with their code.
+//!
+//! [`rule_test!`](crate::rule_test) declares such a test:
+//!
+//! ```ignore
+//! solid_linter::rule_test!(test_no_innerhtml, "no-innerhtml", {
+//!     valid: [
+//!         r#"<div innerHTML="<b>bold</b>" />"#,
+//!         { code: "<div>{html}</div>", filename: "view.jsx" },
+//!     ],
+//!     invalid: [
+//!         {
+//!             code: "<div innerHTML={html} />",
+//!             errors: [{ message: "The innerHTML attribute is dangerous; ...", text: "innerHTML={html}" }],
+//!             options: serde_json::json!({ "allowStatic": false }),
+//!         },
+//!         {
+//!             code: r#"<div innerHTML="hello" />"#,
+//!             errors: ["The string passed to innerHTML does not appear to be valid HTML."],
+//!             output: "<div>hello</div>",
+//!         },
+//!     ],
+//! });
+//! ```
+//!
+//! As with ESLint, an invalid case whose diagnostics carry fixes must give the
+//! `output` of applying them once, and one without `output` must not fix
+//! anything. Downstream crates enable the `tester` feature to test their
+//! plugin rules the same way, with `plugin` before the rule:
+//! `rule_test!(test_no_legacy_button, plugin NoLegacyButton, { .. })`.
+
+use std::fmt::Write as _;
+use std::ops::Range;
+use std::sync::Arc;
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+use crate::config::{set_rule, RuleError};
+use crate::diagnostic::Diagnostic;
+use crate::fix::apply_fixes;
+use crate::plugin::Rule;
+use crate::registry::find_rule;
+use crate::semantic_visitor::{SemanticLintRunner, SemanticRulesConfig};
+use crate::visitor::{LintRunner, RulesConfig, VisitorLintContext};
+
+/// File name of cases that do not set one
+const DEFAULT_FILENAME: &str = "test.tsx";
+
+/// Runs the valid and invalid cases of one rule
+#[derive(Debug)]
+pub struct RuleTester {
+    rule: TestedRule,
+    valid: Vec<ValidCase>,
+    invalid: Vec<InvalidCase>,
+}
+
+#[derive(Debug)]
+enum TestedRule {
+    BuiltIn(String),
+    Plugin(Arc<dyn Rule>),
+}
+
+impl RuleTester {
+    /// Test the built-in rule called `name`
+    pub fn new(name: &str) -> Self {
+        let name = name.strip_prefix("solid/").unwrap_or(name);
+        Self::with_rule(TestedRule::BuiltIn(name.to_string()))
+    }
+
+    /// Test a plugin rule
+    pub fn plugin(rule: impl Rule + 'static) -> Self {
+        Self::with_rule(TestedRule::Plugin(Arc::new(rule)))
+    }
+
+    fn with_rule(rule: TestedRule) -> Self {
+        Self {
+            rule,
+            valid: Vec::new(),
+            invalid: Vec::new(),
+        }
+    }
+
+    pub fn valid(mut self, case: impl Into<ValidCase>) -> Self {
+        self.valid.push(case.into());
+        self
+    }
+
+    pub fn invalid(mut self, case: InvalidCase) -> Self {
+        self.invalid.push(case);
+        self
+    }
+
+    /// Run every case, panicking with a report of those that fail
+    pub fn run(self) {
+        let mut report = String::new();
+        let mut failed = 0;
+        for (i, case) in self.valid.iter().enumerate() {
+            let problems = self.check_valid(case);
+            if !problems.is_empty() {
+                failed += 1;
+                write_failure(&mut report, "valid", i, &case.code, &problems);
+            }
+        }
+        for (i, case) in self.invalid.iter().enumerate() {
+            let problems = self.check_invalid(case);
+            if !problems.is_empty() {
+                failed += 1;
+                write_failure(&mut report, "invalid", i, &case.code, &problems);
+            }
+        }
+        if failed > 0 {
+            panic!(
+                "`{}`: {} of {} cases failed\n{}",
+                self.rule_name(),
+                failed,
+                self.valid.len() + self.invalid.len(),
+                report
+            );
+        }
+    }
+
+    fn rule_name(&self) -> &str {
+        match &self.rule {
+            TestedRule::BuiltIn(name) => name,
+            TestedRule::Plugin(rule) => rule.name(),
+        }
+    }
+
+    fn check_valid(&self, case: &ValidCase) -> Vec<String> {
+        match self.lint(&case.code, case.options.as_ref(), &case.filename) {
+            Ok(diagnostics) => diagnostics
+                .iter()
+                .map(|d| format!("unexpected {}", describe(&case.code, d)))
+                .collect(),
+            Err(problem) => vec![problem],
+        }
+    }
+
+    fn check_invalid(&self, case: &InvalidCase) -> Vec<String> {
+        if case.errors.is_empty() {
+            return vec!["an invalid case has to expect at least one diagnostic".to_string()];
+        }
+        let diagnostics = match self.lint(&case.code, case.options.as_ref(), &case.filename) {
+            Ok(diagnostics) => diagnostics,
+            Err(problem) => return vec![problem],
+        };
+
+        let mut problems = Vec::new();
+        if diagnostics.len() != case.errors.len() {
+            let mut problem = format!(
+                "expected {} diagnostics, found {}",
+                case.errors.len(),
+                diagnostics.len()
+            );
+            for diagnostic in &diagnostics {
+                let _ = write!(problem, "\n  {}", describe(&case.code, diagnostic));
+            }
+            problems.push(problem);
+        }
+        for (i, (expected, diagnostic)) in case.errors.iter().zip(&diagnostics).enumerate() {
+            problems.extend(
+                expected
+                    .mismatches(&case.code, diagnostic)
+                    .into_iter()
+                    .map(|mismatch| format!("diagnostic {}: {}", i, mismatch)),
+            );
+        }
+
+        let fixable = match &self.rule {
+            TestedRule::BuiltIn(name) => find_rule(name).is_none_or(|info| info.fixable),
+            TestedRule::Plugin(_) => true,
+        };
+        if !fixable && diagnostics.iter().any(|d| !d.fixes.is_empty()) {
+            problems.push("the rule has fixes, but `RuleMeta::FIXABLE` is false".to_string());
+        }
+
+        let fixed = apply_fixes(&case.code, diagnostics).output;
+        match &case.output {
+            Some(output) if *output == case.code => problems.push(
+                "`output` is the code itself; leave it out for cases that fix nothing".to_string(),
+            ),
+            Some(output) if *output != fixed => problems.push(format!(
+                "fixed code differs\n  expected: {:?}\n  found:    {:?}",
+                output, fixed
+            )),
+            None if fixed != case.code => problems.push(format!(
+                "the code is fixed, but the case expects no `output`\n  found: {:?}",
+                fixed
+            )),
+            _ => {}
+        }
+        problems
+    }
+
+    /// Diagnostics of the tested rule for `code`, sorted by position
+    fn lint(
+        &self,
+        code: &str,
+        options: Option<&serde_json::Value>,
+        filename: &str,
+    ) -> Result<Vec<Diagnostic>, String> {
+        let mut rules = RulesConfig::none();
+        let mut semantic = SemanticRulesConfig::none();
+        match &self.rule {
+            TestedRule::BuiltIn(name) => {
+                match set_rule(&mut rules, &mut semantic, name, true, options) {
+                    Ok(()) => {}
+                    Err(RuleError::Unknown) => panic!("there is no rule called `{}`", name),
+                    Err(RuleError::InvalidOptions(message)) => {
+                        return Err(format!("invalid options: {}", message))
+                    }
+                }
+            }
+            TestedRule::Plugin(rule) => {
+                if options.is_some() {
+                    return Err("plugin rules take no options".to_string());
+                }
+                semantic = semantic.with_plugin(Arc::clone(rule));
+            }
+        }
+
+        let source_type = SourceType::from_path(filename)
+            .map_err(|error| format!("cannot lint `{}`: {}", filename, error))?
+            .with_jsx(true);
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, code, source_type).parse();
+        if let Some(error) = ret.errors.first() {
+            return Err(format!("the code does not parse: {}", error.message));
+        }
+
+        let semantic_model = SemanticBuilder::new().build(&ret.program).semantic;
+        let ctx = VisitorLintContext::new(code, source_type);
+        let mut diagnostics = LintRunner::new(ctx, rules).run(&ret.program).diagnostics;
+        diagnostics.extend(
+            SemanticLintRunner::new(&semantic_model, code, source_type, semantic)
+                .run(&ret.program)
+                .diagnostics,
+        );
+        diagnostics.sort_by_key(|d| (d.start, d.end));
+        Ok(diagnostics)
+    }
+}
+
+/// Code the rule has to accept
+#[derive(Debug, Clone)]
+pub struct ValidCase {
+    code: String,
+    options: Option<serde_json::Value>,
+    filename: String,
+}
+
+impl ValidCase {
+    pub fn new(code: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            options: None,
+            filename: DEFAULT_FILENAME.to_string(),
+        }
+    }
+
+    /// Rule options, as written in a config file
+    pub fn options(mut self, options: serde_json::Value) -> Self {
+        self.options = Some(options);
+        self
+    }
+
+    /// File name the code is parsed as, `test.tsx` by default
+    pub fn filename(mut self, filename: impl Into<String>) -> Self {
+        self.filename = filename.into();
+        self
+    }
+}
+
+impl From<&str> for ValidCase {
+    fn from(code: &str) -> Self {
+        Self::new(code)
+    }
+}
+
+/// Code the rule has to report, with the expected diagnostics
+#[derive(Debug, Clone)]
+pub struct InvalidCase {
+    code: String,
+    options: Option<serde_json::Value>,
+    filename: String,
+    errors: Vec<ExpectedError>,
+    output: Option<String>,
+}
+
+impl InvalidCase {
+    pub fn new(code: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            options: None,
+            filename: DEFAULT_FILENAME.to_string(),
+            errors: Vec::new(),
+            output: None,
+        }
+    }
+
+    /// Rule options, as written in a config file
+    pub fn options(mut self, options: serde_json::Value) -> Self {
+        self.options = Some(options);
+        self
+    }
+
+    /// File name the code is parsed as, `test.tsx` by default
+    pub fn filename(mut self, filename: impl Into<String>) -> Self {
+        self.filename = filename.into();
+        self
+    }
+
+    /// The next diagnostic, in source order
+    pub fn error(mut self, error: impl Into<ExpectedError>) -> Self {
+        self.errors.push(error.into());
+        self
+    }
+
+    /// The code after applying the fixes of all diagnostics once
+    pub fn output(mut self, output: impl Into<String>) -> Self {
+        self.output = Some(output.into());
+        self
+    }
+}
+
+/// What is checked of one diagnostic; anything left unset is not
+#[derive(Debug, Clone, Default)]
+pub struct ExpectedError {
+    message: Option<String>,
+    text: Option<String>,
+    span: Option<Range<u32>>,
+}
+
+impl ExpectedError {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The whole message
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// The source text the diagnostic points at
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Byte offsets the diagnostic points at
+    pub fn span(mut self, span: Range<u32>) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    fn mismatches(&self, code: &str, diagnostic: &Diagnostic) -> Vec<String> {
+        let mut mismatches = Vec::new();
+        if let Some(message) = &self.message {
+            if *message != diagnostic.message {
+                mismatches.push(format!(
+                    "message differs\n  expected: {:?}\n  found:    {:?}",
+                    message, diagnostic.message
+                ));
+            }
+        }
+        if let Some(text) = &self.text {
+            let found = span_text(code, diagnostic);
+            if text != found {
+                mismatches.push(format!("points at {:?} instead of {:?}", found, text));
+            }
+        }
+        if let Some(span) = &self.span {
+            if *span != (diagnostic.start..diagnostic.end) {
+                mismatches.push(format!(
+                    "span is {}..{} instead of {}..{}",
+                    diagnostic.start, diagnostic.end, span.start, span.end
+                ));
+            }
+        }
+        mismatches
+    }
+}
+
+impl From<&str> for ExpectedError {
+    fn from(message: &str) -> Self {
+        Self::new().message(message)
+    }
+}
+
+fn span_text<'a>(code: &'a str, diagnostic: &Diagnostic) -> &'a str {
+    code.get(diagnostic.start as usize..diagnostic.end as usize)
+        .unwrap_or_default()
+}
+
+fn describe(code: &str, diagnostic: &Diagnostic) -> String {
+    format!(
+        "{:?} at {}..{} {:?}",
+        diagnostic.message,
+        diagnostic.start,
+        diagnostic.end,
+        span_text(code, diagnostic)
+    )
+}
+
+fn write_failure(report: &mut String, kind: &str, index: usize, code: &str, problems: &[String]) {
+    let _ = writeln!(report, "\n{} case {}:\n{}", kind, index, code);
+    for problem in problems {
+        let _ = writeln!(report, "- {}", problem);
+    }
+}
+
+/// Declare a `#[test]` running a [`RuleTester`] over valid and invalid cases
+///
+/// A valid case is its code, or `{ code: .., options: .., filename: .. }`. An
+/// invalid case is `{ code: .., errors: [..], .. }` followed by any of
+/// `options`, `filename` and `output`. An expected error is its message, or
+/// `{ message: .., text: .., span: .. }` with any of those. The other keys
+/// call the builder method of the same name, so they take what it takes.
+#[macro_export]
+macro_rules! rule_test {
+    (@cases $tester:expr, valid: [$($valid:tt),* $(,)?], invalid: [$($invalid:tt),* $(,)?] $(,)?) => {
+        $tester
+            $(.valid($crate::rule_test!(@valid $valid)))*
+            $(.invalid($crate::rule_test!(@invalid $invalid)))*
+            .run()
+    };
+    (@valid { code: $code:expr $(, $key:ident: $value:expr)* $(,)? }) => {
+        $crate::tester::ValidCase::new($code) $(.$key($value))*
+    };
+    (@valid $code:expr) => {
+        $crate::tester::ValidCase::new($code)
+    };
+    (@invalid {
+        code: $code:expr,
+        errors: [$($error:tt),* $(,)?]
+        $(, $key:ident: $value:expr)* $(,)?
+    }) => {
+        $crate::tester::InvalidCase::new($code)
+            $(.error($crate::rule_test!(@error $error)))*
+            $(.$key($value))*
+    };
+    (@error { $($key:ident: $value:expr),* $(,)? }) => {
+        $crate::tester::ExpectedError::new() $(.$key($value))*
+    };
+    (@error $message:expr) => {
+        $crate::tester::ExpectedError::from($message)
+    };
+    ($test:ident, plugin $rule:expr, { $($cases:tt)* }) => {
+        #[test]
+        fn $test() {
+            $crate::rule_test!(@cases $crate::tester::RuleTester::plugin($rule), $($cases)*);
+        }
+    };
+    ($test:ident, $rule:expr, { $($cases:tt)* }) => {
+        #[test]
+        fn $test() {
+            $crate::rule_test!(@cases $crate::tester::RuleTester::new($rule), $($cases)*);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::NoInnerhtml;
+    use crate::RuleMeta;
+
+    fn failure(tester: RuleTester) -> String {
+        let panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| tester.run()))
+            .expect_err("the tester should fail");
+        panic.downcast_ref::<String>().cloned().unwrap_or_default()
+    }
+
+    crate::rule_test!(test_rule_test_macro, NoInnerhtml::NAME, {
+        valid: [
+            r#"<div innerHTML="<b>bold</b>" />"#,
+            { code: "<div>{html}</div>", filename: "view.jsx" },
+        ],
+        invalid: [
+            {
+                code: "<div innerHTML={html} />",
+                errors: [{ text: "innerHTML={html}", span: 5..21 }],
+            },
+            {
+                code: r#"<div innerHTML="hello" />"#,
+                errors: ["The string passed to innerHTML does not appear to be valid HTML."],
+                output: "<div>hello</div>",
+            },
+            {
+                code: r#"<div innerHTML="<b>bold</b>" />"#,
+                errors: [{ message: "The innerHTML attribute is dangerous; passing unsanitized input can lead to security vulnerabilities." }],
+                options: serde_json::json!({ "allowStatic": false }),
+                output: "<div><b>bold</b></div>",
+            },
+        ],
+    });
+
+    #[test]
+    fn test_reports_failures() {
+        let report = failure(
+            RuleTester::new("no-innerhtml")
+                .valid("<div innerHTML={html} />")
+                .invalid(InvalidCase::new(r#"<div innerHTML="hello" />"#).error("Wrong message"))
+                .invalid(InvalidCase::new("<div />").error(ExpectedError::new())),
+        );
+        assert!(
+            report.starts_with("`no-innerhtml`: 3 of 3 cases failed"),
+            "{}",
+            report
+        );
+        assert!(report.contains("valid case 0:\n<div innerHTML={html} />\n- unexpected"));
+        assert!(report.contains("diagnostic 0: message differs"));
+        assert!(report.contains("the code is fixed, but the case expects no `output`"));
+        assert!(report.contains("expected 1 diagnostics, found 0"));
+    }
+
+    #[test]
+    fn test_checks_options_and_parsing() {
+        let report = failure(
+            RuleTester::new("no-innerhtml")
+                .valid(ValidCase::new("<div />").options(serde_json::json!({ "strict": true })))
+                .valid("<div"),
+        );
+        assert!(report.contains("- invalid options: unknown field `strict`"));
+        assert!(report.contains("- the code does not parse"));
+    }
+
+    #[test]
+    #[should_panic(expected = "there is no rule called `no-such-rule`")]
+    fn test_unknown_rule() {
+        RuleTester::new("solid/no-such-rule").valid("<div />").run();
+    }
+}
@@ -1,16 +1,35 @@
 //! Utility functions for Solid linting rules
 
 use oxc_ast::ast::{
-    JSXAttribute, JSXAttributeItem, JSXAttributeName, JSXChild,
-    JSXElementName, JSXMemberExpressionObject, JSXOpeningElement,
+    Declaration, ImportDeclaration, ImportDeclarationSpecifier, JSXAttribute, JSXAttributeItem,
+    JSXAttributeName, JSXChild, JSXElementName, JSXMemberExpressionObject, JSXOpeningElement,
+    Program, Statement,
 };
 use oxc_span::Span;
 
+use crate::diagnostic::Fix;
+
 /// Check if an element name is a DOM element (lowercase)
 pub fn is_dom_element(name: &str) -> bool {
     name.chars().next().is_some_and(|c| c.is_lowercase())
 }
 
+/// Whether a function or variable name looks like a component's: `Counter`,
+/// not `counter`
+pub fn is_component_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+/// Globals that look like setters but schedule work instead
+const TIMER_FUNCTIONS: &[&str] = &["setTimeout", "setInterval", "setImmediate"];
+
+/// `setCount`, but not `setup` or timers
+pub fn is_setter_name(name: &str) -> bool {
+    name.strip_prefix("set")
+        .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase()))
+        && !TIMER_FUNCTIONS.contains(&name)
+}
+
 /// Check if a JSX element name represents a component (capitalized or member expression)
 pub fn is_component(opening: &JSXOpeningElement) -> bool {
     match &opening.name {
@@ -22,34 +41,14 @@ pub fn is_component(opening: &JSXOpeningElement) -> bool {
     }
 }
 
-/// Void HTML elements that don't have closing tags
-pub const VOID_ELEMENTS: &[&str] = &[
-    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
-    "track", "wbr",
-];
-
 /// Check if a DOM element is a void element
 pub fn is_void_element(name: &str) -> bool {
-    VOID_ELEMENTS.contains(&name)
-}
-
-/// Solid built-in components
-pub const SOLID_BUILTINS: &[&str] = &[
-    "For",
-    "Show",
-    "Switch",
-    "Match",
-    "Index",
-    "ErrorBoundary",
-    "Suspense",
-    "SuspenseList",
-    "Dynamic",
-    "Portal",
-];
+    common::constants::VOID_ELEMENTS.contains(name)
+}
 
 /// Check if a component is a Solid built-in
 pub fn is_solid_builtin(name: &str) -> bool {
-    SOLID_BUILTINS.contains(&name)
+    common::constants::BUILT_INS.contains(name)
 }
 
 /// Get the name of a JSX element as a string
@@ -167,17 +166,8 @@ pub fn normalize_event_name(name: &str) -> String {
         .replace("on:", "on")
 }
 
-/// Solid namespace prefixes
-pub const SOLID_NAMESPACES: &[&str] = &[
-    "on",
-    "oncapture",
-    "use",
-    "prop",
-    "attr",
-    "bool",
-    "class",
-    "style",
-];
+/// Solid namespace prefixes, as understood by the transform
+pub const SOLID_NAMESPACES: &[&str] = common::constants::ATTRIBUTE_NAMESPACES;
 
 /// Check if a namespace is valid for Solid
 pub fn is_valid_namespace(ns: &str) -> bool {
@@ -197,3 +187,120 @@ pub fn get_solid_prop_replacement(react_prop: &str) -> Option<&'static str> {
         .find(|(from, _)| *from == react_prop)
         .map(|(_, to)| *to)
 }
+
+/// The source text of a span, or an empty string when the span runs past the
+/// end of the source or does not fall on character boundaries
+pub fn span_text(source_text: &str, span: Span) -> &str {
+    source_text
+        .get(span.start as usize..span.end as usize)
+        .unwrap_or_default()
+}
+
+/// Extend a statement's span to its whole line when nothing else is on it,
+/// so removing it doesn't leave a blank line behind
+pub fn line_span(source_text: &str, span: Span) -> Span {
+    let bytes = source_text.as_bytes();
+    let mut start = span.start as usize;
+    while start > 0 && matches!(bytes[start - 1], b' ' | b'\t') {
+        start -= 1;
+    }
+    let mut end = span.end as usize;
+    while end < bytes.len() && matches!(bytes[end], b' ' | b'\t') {
+        end += 1;
+    }
+    let owns_line = (start == 0 || bytes[start - 1] == b'\n')
+        && (end == bytes.len() || bytes[end] == b'\n' || bytes[end] == b'\r');
+    if !owns_line {
+        return span;
+    }
+    if bytes.get(end) == Some(&b'\r') {
+        end += 1;
+    }
+    if bytes.get(end) == Some(&b'\n') {
+        end += 1;
+    }
+    Span::new(start as u32, end as u32)
+}
+
+/// The edit importing `names` from `source`: they are added to an existing
+/// named import of the module, or imported after the last import
+pub fn add_import_fix(program: &Program, source: &str, names: &[&str]) -> Fix {
+    if let Some(last_specifier) = find_named_import(program, source) {
+        return Fix::new(
+            Span::new(last_specifier.end, last_specifier.end),
+            format!(", {}", names.join(", ")),
+        );
+    }
+    let import_statement = format!("import {{ {} }} from \"{}\";", names.join(", "), source);
+    match imports(program).last() {
+        Some(import) => Fix::new(
+            Span::new(import.span.end, import.span.end),
+            format!("\n{}", import_statement),
+        ),
+        None => Fix::new(Span::new(0, 0), format!("{}\n", import_statement)),
+    }
+}
+
+/// The edit a fix introducing `name` needs to import it from `source`, or
+/// `None` when the name is already bound at the top level
+pub fn import_fix(program: &Program, source: &str, name: &str) -> Option<Fix> {
+    (!has_top_level_binding(program, name)).then(|| add_import_fix(program, source, &[name]))
+}
+
+/// Whether `name` is imported or declared at the top level of `program`
+fn has_top_level_binding(program: &Program, name: &str) -> bool {
+    let declares = |declaration: &Declaration| match declaration {
+        Declaration::VariableDeclaration(declaration) => {
+            declaration.declarations.iter().any(|declarator| {
+                declarator
+                    .id
+                    .get_binding_identifiers()
+                    .iter()
+                    .any(|ident| ident.name == name)
+            })
+        }
+        Declaration::FunctionDeclaration(func) => {
+            func.id.as_ref().is_some_and(|id| id.name == name)
+        }
+        Declaration::ClassDeclaration(class) => class.id.as_ref().is_some_and(|id| id.name == name),
+        _ => false,
+    };
+    program.body.iter().any(|stmt| match stmt {
+        Statement::ImportDeclaration(import) => import
+            .specifiers
+            .iter()
+            .flatten()
+            .any(|specifier| specifier.local().name == name),
+        Statement::ExportNamedDeclaration(export) => {
+            export.declaration.as_ref().is_some_and(declares)
+        }
+        stmt => stmt.as_declaration().is_some_and(declares),
+    })
+}
+
+/// The span of the last named specifier of an `import { ... } from source`
+fn find_named_import(program: &Program, source: &str) -> Option<Span> {
+    imports(program)
+        .filter(|import| import.source.value == source && import.import_kind.is_value())
+        .find_map(|import| {
+            let specifiers = import.specifiers.as_ref()?;
+            if !specifiers
+                .iter()
+                .any(|spec| matches!(spec, ImportDeclarationSpecifier::ImportSpecifier(_)))
+            {
+                return None;
+            }
+            specifiers.last().map(|spec| match spec {
+                ImportDeclarationSpecifier::ImportSpecifier(named) => named.span,
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(default) => default.span,
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(ns) => ns.span,
+            })
+        })
+}
+
+fn imports<'p, 'a>(program: &'p Program<'a>) -> impl Iterator<Item = &'p ImportDeclaration<'a>> {
+    program.body.iter().filter_map(|stmt| match stmt {
+        Statement::ImportDeclaration(import) => Some(&**import),
+        _ => None,
+    })
+}
@@ -3,48 +3,76 @@
 //! This module provides a `LintRunner` that traverses the AST once and runs
 //! all enabled rules during the traversal, collecting diagnostics efficiently.
 
-use oxc_ast::ast::{JSXElement, JSXFragment, JSXOpeningElement, Program};
+use oxc_ast::ast::{
+    CallExpression, JSXElement, JSXExpressionContainer, JSXFragment, JSXOpeningElement, Program,
+};
 use oxc_ast_visit::{walk, Visit};
 use oxc_semantic::Semantic;
 use oxc_span::SourceType;
 
+use crate::config::default_rules;
 use crate::diagnostic::Diagnostic;
+use crate::rules::validate_jsx_nesting::native_element_name;
 use crate::rules::{
-    JsxNoDuplicateProps, JsxNoScriptUrl, JsxUsesVars, NoInnerhtml, NoReactSpecificProps,
-    NoUnknownNamespaces, PreferClasslist, PreferFor, PreferShow, SelfClosingComp, StyleProp,
+    AltText, AnchorIsValid, AriaProps, AriaRole, ClickEventsHaveKeyEvents, EventHandlers,
+    EventNameCasing, Imports, JsxBooleanValue, JsxNoDuplicateProps, JsxNoScriptUrl, JsxUsesVars,
+    NoArrayHandlers, NoAsyncEffect, NoAutofocus, NoCreateSignalInJsx, NoDupeStyleProperties,
+    NoInnerhtml, NoMemoSideEffects, NoReactDeps, NoReactSpecificProps, NoUnknownNamespaces,
+    NoUnstableJsxInSignal, PreferClasslist, PreferFor, PreferIndex, PreferShow, RequireCleanup,
+    SelfClosingComp, StyleProp, ValidateJsxNesting,
 };
 
 /// Configuration for which rules are enabled
 #[derive(Debug, Clone)]
 pub struct RulesConfig {
+    // Accessibility rules, off by default as in eslint-plugin-jsx-a11y
+    pub alt_text: bool,
+    pub anchor_is_valid: bool,
+    pub aria_props: bool,
+    pub aria_role: bool,
+    pub click_events_have_key_events: bool,
+    pub no_autofocus: Option<NoAutofocus>,
+
+    pub event_handlers: Option<EventHandlers>,
+    pub event_name_casing: Option<EventNameCasing>,
+    pub imports: bool,
+    /// Off by default: a team style preference rather than a correctness check
+    pub jsx_boolean_value: Option<JsxBooleanValue>,
     pub jsx_no_duplicate_props: Option<JsxNoDuplicateProps>,
     pub jsx_no_script_url: Option<JsxNoScriptUrl>,
     pub jsx_uses_vars: bool,
+    /// Off by default: the array handler form is valid Solid, some teams just forbid it
+    pub no_array_handlers: bool,
+    pub no_async_effect: bool,
+    pub no_create_signal_in_jsx: bool,
+    pub no_dupe_style_properties: bool,
     pub no_innerhtml: Option<NoInnerhtml>,
+    pub no_memo_side_effects: bool,
+    pub no_react_deps: bool,
     pub no_react_specific_props: bool,
     pub no_unknown_namespaces: Option<NoUnknownNamespaces>,
-    pub prefer_classlist: bool,
+    pub no_unstable_jsx_in_signal: bool,
+    /// Off by default: deprecated upstream, kept so eslint-plugin-solid presets
+    /// that still list it keep working
+    pub prefer_classlist: Option<PreferClasslist>,
     pub prefer_for: bool,
-    pub prefer_show: bool,
+    /// Off by default: whether an item is primitive is inferred from how it is used
+    pub prefer_index: bool,
+    pub prefer_show: Option<PreferShow>,
+    /// Off by default: whether a resource is released is inferred from the
+    /// `onCleanup` calls next to it
+    pub require_cleanup: bool,
     pub self_closing_comp: Option<SelfClosingComp>,
     pub style_prop: Option<StyleProp>,
+    /// Off by default: browsers repair most invalid nesting, and the rule only
+    /// knows the HTML content models, not custom elements
+    pub validate_jsx_nesting: bool,
 }
 
 impl Default for RulesConfig {
+    /// Each rule on or off as its `RuleMeta::DEFAULT_ENABLED` says
     fn default() -> Self {
-        Self {
-            jsx_no_duplicate_props: Some(JsxNoDuplicateProps::new()),
-            jsx_no_script_url: Some(JsxNoScriptUrl::new()),
-            jsx_uses_vars: true,
-            no_innerhtml: Some(NoInnerhtml::new()),
-            no_react_specific_props: true,
-            no_unknown_namespaces: Some(NoUnknownNamespaces::new()),
-            prefer_classlist: true,
-            prefer_for: true,
-            prefer_show: true,
-            self_closing_comp: Some(SelfClosingComp::new()),
-            style_prop: Some(StyleProp::new()),
-        }
+        default_rules().0
     }
 }
 
@@ -55,20 +83,101 @@ impl RulesConfig {
 
     pub fn none() -> Self {
         Self {
+            alt_text: false,
+            anchor_is_valid: false,
+            aria_props: false,
+            aria_role: false,
+            click_events_have_key_events: false,
+            no_autofocus: None,
+            event_handlers: None,
+            event_name_casing: None,
+            imports: false,
+            jsx_boolean_value: None,
             jsx_no_duplicate_props: None,
             jsx_no_script_url: None,
             jsx_uses_vars: false,
+            no_array_handlers: false,
+            no_async_effect: false,
+            no_create_signal_in_jsx: false,
+            no_dupe_style_properties: false,
             no_innerhtml: None,
+            no_memo_side_effects: false,
+            no_react_deps: false,
             no_react_specific_props: false,
             no_unknown_namespaces: None,
-            prefer_classlist: false,
+            no_unstable_jsx_in_signal: false,
+            prefer_classlist: None,
             prefer_for: false,
-            prefer_show: false,
+            prefer_index: false,
+            prefer_show: None,
+            require_cleanup: false,
             self_closing_comp: None,
             style_prop: None,
+            validate_jsx_nesting: false,
         }
     }
 
+    /// Enable or disable every accessibility rule with its default options
+    pub fn with_a11y(mut self, enabled: bool) -> Self {
+        self.alt_text = enabled;
+        self.anchor_is_valid = enabled;
+        self.aria_props = enabled;
+        self.aria_role = enabled;
+        self.click_events_have_key_events = enabled;
+        self.no_autofocus = enabled.then(NoAutofocus::new);
+        self
+    }
+
+    pub fn with_alt_text(mut self, enabled: bool) -> Self {
+        self.alt_text = enabled;
+        self
+    }
+
+    pub fn with_anchor_is_valid(mut self, enabled: bool) -> Self {
+        self.anchor_is_valid = enabled;
+        self
+    }
+
+    pub fn with_aria_props(mut self, enabled: bool) -> Self {
+        self.aria_props = enabled;
+        self
+    }
+
+    pub fn with_aria_role(mut self, enabled: bool) -> Self {
+        self.aria_role = enabled;
+        self
+    }
+
+    pub fn with_click_events_have_key_events(mut self, enabled: bool) -> Self {
+        self.click_events_have_key_events = enabled;
+        self
+    }
+
+    pub fn with_no_autofocus(mut self, rule: NoAutofocus) -> Self {
+        self.no_autofocus = Some(rule);
+        self
+    }
+
+    pub fn with_event_handlers(mut self, rule: EventHandlers) -> Self {
+        self.event_handlers = Some(rule);
+        self
+    }
+
+    pub fn with_event_name_casing(mut self, rule: EventNameCasing) -> Self {
+        self.event_name_casing = Some(rule);
+        self
+    }
+
+    pub fn with_imports(mut self, enabled: bool) -> Self {
+        self.imports = enabled;
+        self
+    }
+
+    pub fn with_jsx_boolean_value(mut self, rule: JsxBooleanValue) -> Self {
+        self.jsx_boolean_value = Some(rule);
+        self
+    }
+
     pub fn with_jsx_no_duplicate_props(mut self, rule: JsxNoDuplicateProps) -> Self {
         self.jsx_no_duplicate_props = Some(rule);
         self
@@ -84,11 +193,41 @@ impl RulesConfig {
         self
     }
 
+    pub fn with_no_array_handlers(mut self, enabled: bool) -> Self {
+        self.no_array_handlers = enabled;
+        self
+    }
+
+    pub fn with_no_async_effect(mut self, enabled: bool) -> Self {
+        self.no_async_effect = enabled;
+        self
+    }
+
+    pub fn with_no_create_signal_in_jsx(mut self, enabled: bool) -> Self {
+        self.no_create_signal_in_jsx = enabled;
+        self
+    }
+
+    pub fn with_no_dupe_style_properties(mut self, enabled: bool) -> Self {
+        self.no_dupe_style_properties = enabled;
+        self
+    }
+
     pub fn with_no_innerhtml(mut self, rule: NoInnerhtml) -> Self {
         self.no_innerhtml = Some(rule);
         self
     }
 
+    pub fn with_no_memo_side_effects(mut self, enabled: bool) -> Self {
+        self.no_memo_side_effects = enabled;
+        self
+    }
+
+    pub fn with_no_react_deps(mut self, enabled: bool) -> Self {
+        self.no_react_deps = enabled;
+        self
+    }
+
     pub fn with_no_react_specific_props(mut self, enabled: bool) -> Self {
         self.no_react_specific_props = enabled;
         self
@@ -99,8 +238,13 @@ impl RulesConfig {
         self
     }
 
-    pub fn with_prefer_classlist(mut self, enabled: bool) -> Self {
-        self.prefer_classlist = enabled;
+    pub fn with_no_unstable_jsx_in_signal(mut self, enabled: bool) -> Self {
+        self.no_unstable_jsx_in_signal = enabled;
+        self
+    }
+
+    pub fn with_prefer_classlist(mut self, rule: PreferClasslist) -> Self {
+        self.prefer_classlist = Some(rule);
         self
     }
 
@@ -109,8 +253,18 @@ impl RulesConfig {
         self
     }
 
-    pub fn with_prefer_show(mut self, enabled: bool) -> Self {
-        self.prefer_show = enabled;
+    pub fn with_prefer_index(mut self, enabled: bool) -> Self {
+        self.prefer_index = enabled;
+        self
+    }
+
+    pub fn with_prefer_show(mut self, rule: PreferShow) -> Self {
+        self.prefer_show = Some(rule);
+        self
+    }
+
+    pub fn with_require_cleanup(mut self, enabled: bool) -> Self {
+        self.require_cleanup = enabled;
         self
     }
 
@@ -123,6 +277,11 @@ impl RulesConfig {
         self.style_prop = Some(rule);
         self
     }
+
+    pub fn with_validate_jsx_nesting(mut self, enabled: bool) -> Self {
+        self.validate_jsx_nesting = enabled;
+        self
+    }
 }
 
 /// Context for lint execution
@@ -165,6 +324,9 @@ pub struct LintRunner<'a> {
     config: RulesConfig,
     diagnostics: Vec<Diagnostic>,
     used_vars: Vec<String>,
+    /// Native elements around the current one in the same JSX tree; the
+    /// nesting check walks each tree from its outermost element
+    nesting_depth: usize,
 }
 
 impl<'a> LintRunner<'a> {
@@ -174,11 +336,26 @@ impl<'a> LintRunner<'a> {
             config,
             diagnostics: Vec::new(),
             used_vars: Vec::new(),
+            nesting_depth: 0,
         }
     }
 
     /// Run all enabled rules on the given program
     pub fn run(mut self, program: &Program<'a>) -> LintResult {
+        // imports (rewrites all Solid imports at once, so it sees them together)
+        if self.config.imports {
+            let rule = Imports::new();
+            self.diagnostics
+                .extend(rule.check_program(program, self.ctx.source_text()));
+        }
+
+        // no-react-deps (its `on` fix imports from the program)
+        if self.config.no_react_deps {
+            let rule = NoReactDeps::new();
+            self.diagnostics
+                .extend(rule.check_program(program, self.ctx.source_text()));
+        }
+
         self.visit_program(program);
         LintResult {
             diagnostics: self.diagnostics,
@@ -197,6 +374,12 @@ impl<'a> LintRunner<'a> {
             self.diagnostics.extend(rule.check(opening, children));
         }
 
+        // alt-text (needs children for the <object> fallback)
+        if self.config.alt_text {
+            let rule = AltText::new();
+            self.diagnostics.extend(rule.check(element));
+        }
+
         // no-innerhtml (needs full element for children check)
         if let Some(rule) = &self.config.no_innerhtml {
             self.diagnostics.extend(rule.check(element));
@@ -214,16 +397,71 @@ impl<'a> LintRunner<'a> {
             self.diagnostics.extend(rule.check_element_children(element));
         }
 
+        // prefer-index: <For> and map() over primitive values
+        if self.config.prefer_index {
+            let rule = PreferIndex::new();
+            self.diagnostics.extend(rule.check_element(element));
+        }
+
         // prefer-show: check children for conditionals
-        if self.config.prefer_show {
-            let rule = PreferShow::new();
+        if let Some(rule) = &self.config.prefer_show {
             self.diagnostics
                 .extend(rule.check_element_children(element, self.ctx.source_text()));
         }
+
+        // validate-jsx-nesting (walks nested elements itself, so only from the outermost)
+        if self.config.validate_jsx_nesting && self.nesting_depth == 0 {
+            let rule = ValidateJsxNesting::new();
+            self.diagnostics.extend(rule.check(element, None));
+        }
     }
 
     /// Check a JSX opening element with all applicable rules
     fn check_jsx_opening_element(&mut self, opening: &JSXOpeningElement<'a>) {
+        // jsx-boolean-value
+        if let Some(rule) = &self.config.jsx_boolean_value {
+            self.diagnostics.extend(rule.check(opening));
+        }
+
+        // anchor-is-valid
+        if self.config.anchor_is_valid {
+            let rule = AnchorIsValid::new();
+            self.diagnostics.extend(rule.check(opening));
+        }
+
+        // aria-props
+        if self.config.aria_props {
+            let rule = AriaProps::new();
+            self.diagnostics.extend(rule.check(opening));
+        }
+
+        // aria-role
+        if self.config.aria_role {
+            let rule = AriaRole::new();
+            self.diagnostics.extend(rule.check(opening));
+        }
+
+        // click-events-have-key-events
+        if self.config.click_events_have_key_events {
+            let rule = ClickEventsHaveKeyEvents::new();
+            self.diagnostics.extend(rule.check(opening));
+        }
+
+        // no-autofocus
+        if let Some(rule) = &self.config.no_autofocus {
+            self.diagnostics.extend(rule.check(opening));
+        }
+
+        // event-handlers
+        if let Some(rule) = &self.config.event_handlers {
+            self.diagnostics.extend(rule.check(opening));
+        }
+
+        // event-name-casing
+        if let Some(rule) = &self.config.event_name_casing {
+            self.diagnostics.extend(rule.check(opening));
+        }
+
         // jsx-no-script-url
         if let Some(rule) = &self.config.jsx_no_script_url {
             self.diagnostics.extend(rule.check(opening));
@@ -235,6 +473,13 @@ impl<'a> LintRunner<'a> {
             self.diagnostics.extend(rule.check(opening));
         }
 
+        // no-array-handlers
+        if self.config.no_array_handlers {
+            let rule = NoArrayHandlers::new();
+            self.diagnostics
+                .extend(rule.check(opening, self.ctx.source_text()));
+        }
+
         // no-unknown-namespaces
         if let Some(rule) = &self.config.no_unknown_namespaces {
             self.diagnostics.extend(rule.check(opening));
@@ -245,9 +490,14 @@ impl<'a> LintRunner<'a> {
             self.diagnostics.extend(rule.check(opening));
         }
 
+        // no-dupe-style-properties
+        if self.config.no_dupe_style_properties {
+            let rule = NoDupeStyleProperties::new();
+            self.diagnostics.extend(rule.check(opening));
+        }
+
         // prefer-classlist
-        if self.config.prefer_classlist {
-            let rule = PreferClasslist::new();
+        if let Some(rule) = &self.config.prefer_classlist {
             self.diagnostics.extend(rule.check(opening));
         }
 
@@ -267,19 +517,81 @@ impl<'a> LintRunner<'a> {
                 .extend(rule.check_fragment_children(fragment));
         }
 
+        // prefer-index: map() over primitive values
+        if self.config.prefer_index {
+            let rule = PreferIndex::new();
+            self.diagnostics.extend(rule.check_fragment_children(fragment));
+        }
+
         // prefer-show: check children for conditionals
-        if self.config.prefer_show {
-            let rule = PreferShow::new();
+        if let Some(rule) = &self.config.prefer_show {
             self.diagnostics
                 .extend(rule.check_fragment_children(fragment, self.ctx.source_text()));
         }
     }
+
+    /// Check a JSX expression container with all applicable rules
+    fn check_jsx_expression_container(&mut self, container: &JSXExpressionContainer<'a>) {
+        // no-createSignal-in-jsx
+        if self.config.no_create_signal_in_jsx {
+            let rule = NoCreateSignalInJsx::new();
+            self.diagnostics
+                .extend(rule.check_expression_container(container));
+        }
+    }
+
+    /// Check a call expression with all applicable rules
+    fn check_call_expression(&mut self, call: &CallExpression<'a>) {
+        // no-async-effect
+        if self.config.no_async_effect {
+            let rule = NoAsyncEffect::new();
+            self.diagnostics.extend(rule.check(call));
+        }
+
+        // no-memo-side-effects
+        if self.config.no_memo_side_effects {
+            let rule = NoMemoSideEffects::new();
+            self.diagnostics.extend(rule.check(call));
+        }
+
+        // no-unstable-jsx-in-signal
+        if self.config.no_unstable_jsx_in_signal {
+            let rule = NoUnstableJsxInSignal::new();
+            self.diagnostics.extend(rule.check(call));
+        }
+
+        // require-cleanup
+        if self.config.require_cleanup {
+            let rule = RequireCleanup::new();
+            self.diagnostics.extend(rule.check(call));
+        }
+    }
 }
 
 impl<'a> Visit<'a> for LintRunner<'a> {
+    fn visit_jsx_expression_container(&mut self, container: &JSXExpressionContainer<'a>) {
+        self.check_jsx_expression_container(container);
+        // JSX in an expression starts a new tree
+        let depth = std::mem::take(&mut self.nesting_depth);
+        walk::walk_jsx_expression_container(self, container);
+        self.nesting_depth = depth;
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        self.check_call_expression(call);
+        walk::walk_call_expression(self, call);
+    }
+
     fn visit_jsx_element(&mut self, element: &JSXElement<'a>) {
         self.check_jsx_element(element);
+        // Children of a component are not walked by the nesting check
+        let depth = self.nesting_depth;
+        self.nesting_depth = match native_element_name(element) {
+            Some(_) => depth + 1,
+            None => 0,
+        };
         walk::walk_jsx_element(self, element);
+        self.nesting_depth = depth;
     }
 
     fn visit_jsx_opening_element(&mut self, opening: &JSXOpeningElement<'a>) {
@@ -308,7 +620,7 @@ impl LintResult {
     }
 
     pub fn has_warnings(&self) -> bool {
-        !self.diagnostics.is_empty()
+        self.warning_count() > 0
     }
 
     pub fn error_count(&self) -> usize {
@@ -413,6 +725,40 @@ mod tests {
         assert!(result.diagnostics[0].message.contains("className"));
     }
 
+    #[test]
+    fn test_prefer_classlist_is_opt_in() {
+        let source = r#"<div class={clsx({ active: on() })} />"#;
+        assert!(parse_and_lint(source).diagnostics.is_empty());
+
+        let config = RulesConfig::none().with_prefer_classlist(PreferClasslist::new());
+        let result = parse_and_lint_with_config(source, config);
+        assert_eq!(result.diagnostics.len(), 1);
+        assert!(result.diagnostics[0].message.contains("clsx"));
+    }
+
+    #[test]
+    fn test_validate_jsx_nesting_is_opt_in() {
+        let source = r#"<ul><div /><Show>{() => <span><div /></span>}</Show></ul>"#;
+        assert!(parse_and_lint(source)
+            .diagnostics
+            .iter()
+            .all(|d| d.rule != "validate-jsx-nesting"));
+
+        // Each tree is checked once, including JSX inside expressions
+        let config = RulesConfig::none().with_validate_jsx_nesting(true);
+        let result = parse_and_lint_with_config(source, config);
+        assert_eq!(result.diagnostics.len(), 2);
+        assert!(result.diagnostics[0].message.contains("<div> cannot be a child of <ul>"));
+        assert!(result.diagnostics[1].message.contains("inline element <span>"));
+    }
+
+    #[test]
+    fn test_lint_react_deps() {
+        let result = parse_and_lint(r#"createEffect(() => console.log(a()), [a]);"#);
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(result.diagnostics[0].rule, "no-react-deps");
+    }
+
     #[test]
     fn test_lint_nested_elements() {
         let result = parse_and_lint(
@@ -0,0 +1,168 @@
+//! Mutated real-world TSX through every rule
+//!
+//! The example apps, benchmark fixtures and test inputs of the repository are
+//! mutated by a seeded generator: multi-byte characters, brackets, quotes and
+//! JSX fragments are inserted, and bytes around them deleted or replaced.
+//! Every rule must lint the result without panicking and with spans on
+//! character boundaries, see `support::check`. `fuzz/` feeds the same checks
+//! from cargo-fuzz for longer runs.
+//!
+//! `SOLID_FUZZ_CASES` sets how many mutations to try (the default keeps
+//! `cargo test` fast), and `SOLID_FUZZ_SEED` replays the mutation of one seed
+//! reported by a failure.
+
+mod support;
+
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+const DEFAULT_CASES: u64 = 500;
+
+/// Inserted at random positions, weighted towards what splits characters
+/// and what changes how JSX nests
+const INSERTS: &[&str] = &[
+    "é", "日本", "😀", "\u{2028}", "\u{feff}", "…", "{", "}", "<", ">", "/", "\"", "'", "`", "${",
+    "(", ")", "=>", " ", "\n", "<div>", "</div>", "<>", "</>", "props.", "on:click",
+];
+
+/// xorshift64*, the same on every platform
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n.max(1) as u64) as usize
+    }
+
+    /// A char boundary of `text` at or before a random byte
+    fn boundary(&mut self, text: &str) -> usize {
+        floor_boundary(text, self.below(text.len() + 1))
+    }
+}
+
+fn floor_boundary(text: &str, mut index: usize) -> usize {
+    index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+fn collect_inputs(path: &Path, inputs: &mut Vec<PathBuf>) {
+    if path.is_file() {
+        if path
+            .extension()
+            .is_some_and(|extension| matches!(extension.to_str(), Some("tsx" | "jsx" | "ts")))
+        {
+            inputs.push(path.to_path_buf());
+        }
+        return;
+    }
+    let Ok(entries) = fs::read_dir(path) else {
+        return;
+    };
+    for entry in entries {
+        let path = entry.unwrap().path();
+        if path.file_name().is_some_and(|name| name != "node_modules") {
+            collect_inputs(&path, inputs);
+        }
+    }
+}
+
+fn inputs() -> Vec<String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    let mut paths = Vec::new();
+    for dir in [
+        "../../examples",
+        "benches/fixtures",
+        "tests/golden",
+        "tests/runtime/cases",
+    ] {
+        collect_inputs(&root.join(dir), &mut paths);
+    }
+    paths.sort();
+    assert!(paths.len() > 10, "only found {} inputs", paths.len());
+    paths
+        .iter()
+        .map(|path| fs::read_to_string(path).unwrap())
+        .collect()
+}
+
+fn mutate(inputs: &[String], seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut text = inputs[rng.below(inputs.len())].clone();
+    for _ in 0..1 + rng.below(4) {
+        let at = rng.boundary(&text);
+        let insert = INSERTS[rng.below(INSERTS.len())];
+        match rng.below(3) {
+            0 => text.insert_str(at, insert),
+            1 => {
+                let end = floor_boundary(&text, at + rng.below(8));
+                text.replace_range(at..end, "");
+            }
+            _ => {
+                let end = floor_boundary(&text, at + rng.below(3));
+                text.replace_range(at..end, insert);
+            }
+        }
+    }
+    text
+}
+
+#[test]
+fn test_mutated_sources_lint() {
+    let seeds: Vec<u64> = match std::env::var("SOLID_FUZZ_SEED") {
+        Ok(seed) => vec![seed.parse().expect("SOLID_FUZZ_SEED is a number")],
+        Err(_) => {
+            let cases = std::env::var("SOLID_FUZZ_CASES")
+                .ok()
+                .and_then(|cases| cases.parse().ok())
+                .unwrap_or(DEFAULT_CASES);
+            (0..cases).collect()
+        }
+    };
+    let inputs = inputs();
+
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let mut failures = Vec::new();
+    for &seed in &seeds {
+        let source = mutate(&inputs, seed);
+        let error = match panic::catch_unwind(AssertUnwindSafe(|| support::check(&source))) {
+            Ok(result) => result.err(),
+            Err(payload) => Some(
+                payload
+                    .downcast_ref::<String>()
+                    .cloned()
+                    .or_else(|| payload.downcast_ref::<&str>().map(ToString::to_string))
+                    .map_or_else(
+                        || "panicked".to_string(),
+                        |message| format!("panicked: {message}"),
+                    ),
+            ),
+        };
+        if let Some(error) = error {
+            failures.push((seed, error, source));
+        }
+    }
+    panic::set_hook(hook);
+
+    if let Some((seed, error, source)) = failures.first() {
+        panic!(
+            "{} of {} mutated sources failed\nthe first, SOLID_FUZZ_SEED={seed}: {error}\n{source}",
+            failures.len(),
+            seeds.len()
+        );
+    }
+}
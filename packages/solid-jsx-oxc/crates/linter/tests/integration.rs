@@ -5,6 +5,7 @@ use oxc_ast::ast::JSXElement;
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
+use solid_linter::apply_fixes;
 use solid_linter::rules::{
     JsxNoDuplicateProps, NoInnerhtml, NoReactDeps, NoReactSpecificProps, NoUnknownNamespaces,
     PreferClasslist, SelfClosingComp, StyleProp,
@@ -90,7 +91,7 @@ fn test_no_react_specific_props_class_name() {
     
     assert_eq!(diagnostics.len(), 1, "should have one diagnostic");
     assert!(diagnostics[0].message.contains("class"));
-    assert!(diagnostics[0].fixes.len() > 0, "should have a fix");
+    assert!(!diagnostics[0].fixes.is_empty(), "should have a fix");
 }
 
 #[test]
@@ -200,6 +201,40 @@ fn test_no_innerhtml_dangerously_set() {
     assert!(diagnostics[0].message.contains("dangerouslySetInnerHTML"));
 }
 
+#[test]
+fn test_no_innerhtml_static_text_moved_to_children() {
+    let allocator = Allocator::default();
+    let source = r#"<div id="a" innerHTML="hello" />"#;
+
+    let program = parse_jsx_element(&allocator, source).expect("should parse");
+    let element = find_jsx_element(&program).expect("should find element");
+
+    let diagnostics = NoInnerhtml::new().check(element);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        apply_fixes(source, diagnostics).output,
+        r#"<div id="a">hello</div>"#
+    );
+}
+
+#[test]
+fn test_no_innerhtml_disallow_static_fix() {
+    let allocator = Allocator::default();
+    let source = r#"<p innerHTML="<b>bold</b>"></p>"#;
+
+    let program = parse_jsx_element(&allocator, source).expect("should parse");
+    let element = find_jsx_element(&program).expect("should find element");
+
+    let diagnostics = NoInnerhtml::new().with_allow_static(false).check(element);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        apply_fixes(source, diagnostics).output,
+        "<p><b>bold</b></p>"
+    );
+}
+
 // ============ no-unknown-namespaces tests ============
 
 #[test]
@@ -271,7 +306,7 @@ fn test_style_prop_string() {
     let program = parse_jsx_element(&allocator, source).expect("should parse");
     let element = find_jsx_element(&program).expect("should find element");
     
-    let rule = StyleProp::new(); // allow_string defaults to false
+    let rule = StyleProp::new(); // style_string defaults to never
     let diagnostics = rule.check(&element.opening_element);
     
     assert_eq!(diagnostics.len(), 1, "should warn about string style");
@@ -375,7 +410,7 @@ fn test_no_react_deps_valid_single_arg() {
     let call = find_call_expression(&program).expect("should find call");
     
     let rule = NoReactDeps::new();
-    let diagnostics = rule.check(call);
+    let diagnostics = rule.check(call, source);
     
     assert!(diagnostics.is_empty(), "single argument should be valid");
 }
@@ -389,7 +424,7 @@ fn test_no_react_deps_valid_with_initial_value() {
     let call = find_call_expression(&program).expect("should find call");
     
     let rule = NoReactDeps::new();
-    let diagnostics = rule.check(call);
+    let diagnostics = rule.check(call, source);
     
     assert!(diagnostics.is_empty(), "function with params and initial value should be valid");
 }
@@ -403,7 +438,7 @@ fn test_no_react_deps_valid_memo_single_arg() {
     let call = find_call_expression(&program).expect("should find call");
     
     let rule = NoReactDeps::new();
-    let diagnostics = rule.check(call);
+    let diagnostics = rule.check(call, source);
     
     assert!(diagnostics.is_empty(), "single argument memo should be valid");
 }
@@ -417,7 +452,7 @@ fn test_no_react_deps_invalid_effect_with_deps() {
     let call = find_call_expression(&program).expect("should find call");
     
     let rule = NoReactDeps::new();
-    let diagnostics = rule.check(call);
+    let diagnostics = rule.check(call, source);
     
     assert_eq!(diagnostics.len(), 1, "should have one diagnostic");
     assert!(diagnostics[0].message.contains("createEffect"));
@@ -434,7 +469,7 @@ fn test_no_react_deps_invalid_memo_with_deps() {
     let call = find_call_expression(&program).expect("should find call");
     
     let rule = NoReactDeps::new();
-    let diagnostics = rule.check(call);
+    let diagnostics = rule.check(call, source);
     
     assert_eq!(diagnostics.len(), 1, "should have one diagnostic");
     assert!(diagnostics[0].message.contains("createMemo"));
@@ -450,7 +485,21 @@ fn test_no_react_deps_valid_other_function() {
     let call = find_call_expression(&program).expect("should find call");
     
     let rule = NoReactDeps::new();
-    let diagnostics = rule.check(call);
+    let diagnostics = rule.check(call, source);
     
     assert!(diagnostics.is_empty(), "should not warn about other functions");
 }
+
+#[test]
+fn test_span_text_outside_char_boundaries() {
+    let source = r#"<p title="héllo">日本</p>"#;
+    let ctx = solid_linter::LintContext::new(source, SourceType::jsx());
+
+    assert_eq!(ctx.span_text(oxc_span::Span::new(10, 16)), "héllo");
+    assert_eq!(ctx.span_text(oxc_span::Span::new(18, 24)), "日本");
+    // Inside `é`, inside `日`, past the end and reversed
+    assert_eq!(ctx.span_text(oxc_span::Span::new(10, 12)), "");
+    assert_eq!(ctx.span_text(oxc_span::Span::new(19, 24)), "");
+    assert_eq!(ctx.span_text(oxc_span::Span::new(18, 100)), "");
+    assert_eq!(ctx.span_text(oxc_span::Span::new(16, 10)), "");
+}
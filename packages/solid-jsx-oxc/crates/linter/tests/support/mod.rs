@@ -0,0 +1,127 @@
+//! Every rule on arbitrary source, shared by `tests/fuzz.rs` and the
+//! cargo-fuzz target in `fuzz/`
+//!
+//! Source that does not parse is skipped, as the CLI reports it instead of
+//! linting it. For everything else all rules run, and every diagnostic, label and fix must point into the source on
+//! character boundaries. Fixes are then applied until stable.
+
+use std::sync::{Arc, OnceLock};
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{CallExpression, JSXElement};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{SourceType, Span};
+use solid_linter::{
+    fix_until_stable, Diagnostic, LintConfig, LintContext, LintRunner, Rule, SemanticLintRunner,
+    VisitorLintContext, RULES,
+};
+
+/// Slices the source around every element and call, one byte off to either
+/// side, as a plugin computing its own spans might
+struct SpanProbe;
+
+impl SpanProbe {
+    fn probe(span: Span, ctx: &LintContext) {
+        for start in [span.start.saturating_sub(1), span.start, span.start + 1] {
+            for end in [span.end.saturating_sub(1), span.end, span.end + 1] {
+                ctx.span_text(Span::new(start, end));
+            }
+        }
+    }
+}
+
+impl Rule for SpanProbe {
+    fn name(&self) -> &str {
+        "fuzz/span-probe"
+    }
+
+    fn run_on_jsx_element<'a>(&self, element: &JSXElement<'a>, ctx: &mut LintContext<'a>) {
+        Self::probe(element.span, ctx);
+    }
+
+    fn run_on_call_expression<'a>(&self, call: &CallExpression<'a>, ctx: &mut LintContext<'a>) {
+        Self::probe(call.span, ctx);
+    }
+}
+
+/// Every built-in rule, at `warn`
+fn config() -> &'static LintConfig {
+    static CONFIG: OnceLock<LintConfig> = OnceLock::new();
+    CONFIG.get_or_init(|| {
+        let rules: Vec<String> = RULES
+            .iter()
+            .map(|rule| format!("\"solid/{}\": \"warn\"", rule.name))
+            .collect();
+        LintConfig::from_json_str(&format!("{{\"rules\": {{{}}}}}", rules.join(", ")))
+            .expect("every rule accepts `warn`")
+    })
+}
+
+/// Lint `source_text` as TSX with every rule, or `None` if it does not parse
+pub fn lint_all(source_text: &str) -> Option<Vec<Diagnostic>> {
+    let allocator = Allocator::default();
+    let source_type = SourceType::tsx();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if !ret.errors.is_empty() || ret.panicked {
+        return None;
+    }
+
+    let config = config();
+    let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+    let semantic_rules = config
+        .semantic_rules_config()
+        .with_plugin(Arc::new(SpanProbe));
+    let ctx = VisitorLintContext::new(source_text, source_type);
+    let mut diagnostics = LintRunner::new(ctx, config.rules_config())
+        .run(&ret.program)
+        .diagnostics;
+    diagnostics.extend(
+        SemanticLintRunner::new(&semantic, source_text, source_type, semantic_rules)
+            .run(&ret.program)
+            .diagnostics,
+    );
+    Some(diagnostics)
+}
+
+fn check_span(source_text: &str, start: u32, end: u32) -> bool {
+    start <= end
+        && source_text.is_char_boundary(start as usize)
+        && source_text.is_char_boundary(end as usize)
+}
+
+/// Lint and fix `source_text`, describing the first span that does not
+/// point into it
+pub fn check(source_text: &str) -> Result<(), String> {
+    let Some(diagnostics) = lint_all(source_text) else {
+        return Ok(());
+    };
+    for diagnostic in &diagnostics {
+        let rule = &diagnostic.rule;
+        if !check_span(source_text, diagnostic.start, diagnostic.end) {
+            return Err(format!(
+                "{rule} reported {}..{}",
+                diagnostic.start, diagnostic.end
+            ));
+        }
+        for (start, end, _) in &diagnostic.labels {
+            if !check_span(source_text, *start, *end) {
+                return Err(format!("{rule} labelled {start}..{end}"));
+            }
+        }
+        let fixes = diagnostic
+            .fixes
+            .iter()
+            .chain(diagnostic.suggestions.iter().flat_map(|s| &s.fixes));
+        for fix in fixes {
+            if !check_span(source_text, fix.start, fix.end) {
+                return Err(format!("{rule} fixed {}..{}", fix.start, fix.end));
+            }
+        }
+    }
+
+    fix_until_stable(source_text, |source_text| {
+        lint_all(source_text).unwrap_or_default()
+    });
+    Ok(())
+}
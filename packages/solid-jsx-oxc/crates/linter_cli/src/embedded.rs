@@ -0,0 +1,402 @@
+//! Linting JSX embedded in Markdown
+//!
+//! An `.mdx` file is linted as one module made of its `import`/`export`
+//! statements and its block-level JSX, so components resolve to their imports
+//! as they do when the page is compiled. JSX inside a paragraph is not linted.
+//! Fenced `jsx`/`tsx` code blocks in `.md` and `.mdx` files are modules of
+//! their own, linted only when enabled with `--code-blocks`.
+//!
+//! Each module is parsed from a virtual source text whose segments are copied
+//! from the file; `EmbeddedModule::map_diagnostic` moves spans back to file
+//! offsets. Fixes that would span more than one segment are dropped.
+
+use std::path::Path;
+
+use oxc_span::SourceType;
+use solid_linter::{Diagnostic, Fix};
+
+/// Markdown flavors with embedded code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Markdown {
+    /// Fenced code blocks only
+    Md,
+    /// ESM and JSX blocks, plus fenced code blocks
+    Mdx,
+}
+
+impl Markdown {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "md" | "markdown" => Some(Self::Md),
+            "mdx" => Some(Self::Mdx),
+            _ => None,
+        }
+    }
+}
+
+/// A range of the virtual source text copied from the file
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    virtual_start: u32,
+    original_start: u32,
+    len: u32,
+}
+
+impl Segment {
+    fn contains(&self, start: u32, end: u32) -> bool {
+        self.virtual_start <= start && end <= self.virtual_start + self.len
+    }
+}
+
+/// One module extracted from a Markdown file
+#[derive(Debug)]
+pub struct EmbeddedModule {
+    pub source_text: String,
+    pub source_type: SourceType,
+    /// In order of `virtual_start`
+    segments: Vec<Segment>,
+}
+
+impl EmbeddedModule {
+    fn new(source_type: SourceType) -> Self {
+        Self {
+            source_text: String::new(),
+            source_type,
+            segments: Vec::new(),
+        }
+    }
+
+    /// Copy `file[start..end]`, then `separator`, which is not mapped
+    fn push(&mut self, file: &str, start: usize, end: usize, separator: &str) {
+        self.segments.push(Segment {
+            virtual_start: self.source_text.len() as u32,
+            original_start: start as u32,
+            len: (end - start) as u32,
+        });
+        self.source_text.push_str(&file[start..end]);
+        self.source_text.push_str(separator);
+    }
+
+    fn segment(&self, offset: u32) -> Option<&Segment> {
+        let index = self
+            .segments
+            .partition_point(|segment| segment.virtual_start <= offset);
+        self.segments.get(index.checked_sub(1)?)
+    }
+
+    /// File offset of a virtual offset; offsets in a separator map to the end
+    /// of the segment before it
+    pub fn to_original(&self, offset: u32) -> u32 {
+        match self.segment(offset) {
+            Some(segment) => {
+                segment.original_start + (offset - segment.virtual_start).min(segment.len)
+            }
+            None => self.segments.first().map_or(0, |s| s.original_start),
+        }
+    }
+
+    /// Map fixes that stay within one segment; `None` if any does not
+    fn map_fixes(&self, fixes: Vec<Fix>) -> Option<Vec<Fix>> {
+        fixes
+            .into_iter()
+            .map(|fix| {
+                self.segment(fix.start)
+                    .filter(|segment| segment.contains(fix.start, fix.end))?;
+                Some(Fix {
+                    start: self.to_original(fix.start),
+                    end: self.to_original(fix.end),
+                    ..fix
+                })
+            })
+            .collect()
+    }
+
+    /// Move a diagnostic of the virtual source text to the file
+    pub fn map_diagnostic(&self, mut diagnostic: Diagnostic) -> Diagnostic {
+        diagnostic.start = self.to_original(diagnostic.start);
+        diagnostic.end = self.to_original(diagnostic.end);
+        for (start, end, _) in &mut diagnostic.labels {
+            *start = self.to_original(*start);
+            *end = self.to_original(*end);
+        }
+        // A diagnostic's fixes are applied together, so they are kept together
+        diagnostic.fixes = self
+            .map_fixes(std::mem::take(&mut diagnostic.fixes))
+            .unwrap_or_default();
+        diagnostic.suggestions = std::mem::take(&mut diagnostic.suggestions)
+            .into_iter()
+            .filter_map(|mut suggestion| {
+                suggestion.fixes = self.map_fixes(suggestion.fixes)?;
+                Some(suggestion)
+            })
+            .collect();
+        diagnostic
+    }
+}
+
+/// A line of the file, without its line break
+struct Line<'s> {
+    start: usize,
+    text: &'s str,
+}
+
+impl Line<'_> {
+    fn end(&self) -> usize {
+        self.start + self.text.len()
+    }
+
+    fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+}
+
+fn lines(text: &str) -> Vec<Line<'_>> {
+    let mut start = 0;
+    text.split_inclusive('\n')
+        .map(|line| {
+            let line_start = start;
+            start += line.len();
+            let line = line.strip_suffix('\n').unwrap_or(line);
+            Line {
+                start: line_start,
+                text: line.strip_suffix('\r').unwrap_or(line),
+            }
+        })
+        .collect()
+}
+
+/// The modules of a Markdown file, with fenced code blocks if `code_blocks`
+pub fn extract(source_text: &str, markdown: Markdown, code_blocks: bool) -> Vec<EmbeddedModule> {
+    let lines = lines(source_text);
+    let mut mdx = EmbeddedModule::new(SourceType::jsx());
+    let mut blocks = Vec::new();
+
+    let mut i = frontmatter_end(&lines);
+    while i < lines.len() {
+        let line = &lines[i];
+        if let Some((fence, info)) = fence_open(line.text) {
+            let close = (i + 1..lines.len())
+                .find(|&j| is_fence_close(lines[j].text, fence))
+                .unwrap_or(lines.len());
+            let source_type = code_block_source_type(info);
+            if let (true, Some(source_type), Some(first), Some(last)) = (
+                code_blocks,
+                source_type,
+                lines.get(i + 1),
+                lines.get(close - 1).filter(|_| close > i + 1),
+            ) {
+                let mut module = EmbeddedModule::new(source_type);
+                module.push(source_text, first.start, last.end(), "\n");
+                blocks.push(module);
+            }
+            i = close + 1;
+            continue;
+        }
+        if markdown == Markdown::Md || indent(line.text) > 3 {
+            i += 1;
+            continue;
+        }
+
+        // An ESM block ends at a blank line, a JSX block once its tags are closed
+        let end = if is_esm_start(line.text) {
+            (i..lines.len())
+                .find(|&j| lines[j].is_blank())
+                .unwrap_or(lines.len())
+        } else if is_jsx_start(line.text) {
+            let mut depth = 0;
+            let mut j = i;
+            while j < lines.len() {
+                depth += tag_depth_change(lines[j].text);
+                j += 1;
+                if depth <= 0 {
+                    break;
+                }
+            }
+            j
+        } else {
+            i += 1;
+            continue;
+        };
+        let separator = if is_esm_start(line.text) { "\n" } else { ";\n" };
+        mdx.push(source_text, line.start, lines[end - 1].end(), separator);
+        i = end;
+    }
+
+    let mut modules = Vec::new();
+    if !mdx.segments.is_empty() {
+        modules.push(mdx);
+    }
+    modules.extend(blocks);
+    modules
+}
+
+/// The line after a leading `---` front matter block
+fn frontmatter_end(lines: &[Line]) -> usize {
+    if lines
+        .first()
+        .is_none_or(|line| line.text.trim_end() != "---")
+    {
+        return 0;
+    }
+    (1..lines.len())
+        .find(|&i| lines[i].text.trim_end() == "---")
+        .map_or(0, |i| i + 1)
+}
+
+fn indent(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+/// The fence (such as "```") and info string of an opening code fence
+fn fence_open(line: &str) -> Option<(&str, &str)> {
+    if indent(line) > 3 {
+        return None;
+    }
+    let trimmed = line.trim_start_matches(' ');
+    let marker = trimmed.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+    let fence_len = trimmed.len() - trimmed.trim_start_matches(marker).len();
+    if fence_len < 3 {
+        return None;
+    }
+    let (fence, info) = trimmed.split_at(fence_len);
+    Some((fence, info.trim()))
+}
+
+fn is_fence_close(line: &str, fence: &str) -> bool {
+    let trimmed = line.trim();
+    indent(line) <= 3
+        && trimmed.len() >= fence.len()
+        && trimmed.chars().all(|c| fence.starts_with(c))
+}
+
+/// Source type of a fenced code block, by the first word of its info string
+fn code_block_source_type(info: &str) -> Option<SourceType> {
+    match info
+        .split_whitespace()
+        .next()?
+        .to_ascii_lowercase()
+        .as_str()
+    {
+        "jsx" | "js" | "javascript" => Some(SourceType::jsx()),
+        "tsx" | "ts" | "typescript" => Some(SourceType::tsx()),
+        _ => None,
+    }
+}
+
+fn is_esm_start(line: &str) -> bool {
+    line.starts_with("import ") || line.starts_with("export ")
+}
+
+/// `<Tag`, `<tag` or `<>`, but not `<!-- comments -->`
+fn is_jsx_start(line: &str) -> bool {
+    let mut chars = line.trim_start_matches(' ').chars();
+    chars.next() == Some('<')
+        && chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '>')
+}
+
+/// Tags opened minus tags closed, counting `/>` and `</...>` as closing
+fn tag_depth_change(line: &str) -> i32 {
+    let bytes = line.as_bytes();
+    let mut depth = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match (bytes[i], bytes.get(i + 1)) {
+            (b'<', Some(b'/')) | (b'/', Some(b'>')) => {
+                depth -= 1;
+                i += 1;
+            }
+            (b'<', Some(c)) if c.is_ascii_alphabetic() || *c == b'>' => depth += 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    depth
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_span::Span;
+
+    const PAGE: &str = "---
+title: Counter
+---
+
+import { Counter } from \"../components/Counter\";
+
+# Counter
+
+Some text with an inline <Inline /> component.
+
+<Tabs>
+  <Counter className=\"big\" />
+</Tabs>
+
+```tsx
+const a: number = 1;
+```
+
+<Note />
+";
+
+    #[test]
+    fn test_extract_mdx() {
+        let modules = extract(PAGE, Markdown::Mdx, false);
+        assert_eq!(modules.len(), 1);
+        assert_eq!(
+            modules[0].source_text,
+            "import { Counter } from \"../components/Counter\";\n\
+             <Tabs>\n  <Counter className=\"big\" />\n</Tabs>;\n\
+             <Note />;\n"
+        );
+
+        // `className` maps back to the page
+        let module = &modules[0];
+        let virtual_start = module.source_text.find("className").unwrap() as u32;
+        let start = module.to_original(virtual_start) as usize;
+        assert_eq!(&PAGE[start..start + 9], "className");
+    }
+
+    #[test]
+    fn test_extract_code_blocks() {
+        let modules = extract(PAGE, Markdown::Mdx, true);
+        assert_eq!(modules.len(), 2);
+        assert_eq!(modules[1].source_text, "const a: number = 1;\n");
+        assert!(modules[1].source_type.is_typescript());
+
+        let markdown = "# Title\n\n<div>not JSX in .md</div>\n\n~~~jsx title=\"App\"\n<App />\n~~~\n\n```css\na {}\n```\n";
+        assert!(extract(markdown, Markdown::Md, false).is_empty());
+        let modules = extract(markdown, Markdown::Md, true);
+        assert_eq!(modules.len(), 1);
+        assert_eq!(modules[0].source_text, "<App />\n");
+    }
+
+    #[test]
+    fn test_map_diagnostic() {
+        let file = "text\n<A />\n\ntext\n<B />\n";
+        let mut module = EmbeddedModule::new(SourceType::jsx());
+        module.push(file, 5, 10, ";\n");
+        module.push(file, 17, 22, ";\n");
+        assert_eq!(module.source_text, "<A />;\n<B />;\n");
+
+        let diagnostic = module.map_diagnostic(
+            Diagnostic::warning("rule", Span::new(8, 9), "B")
+                .with_fix(Fix::new(Span::new(8, 9), "C")),
+        );
+        assert_eq!((diagnostic.start, diagnostic.end), (18, 19));
+        assert_eq!(
+            (diagnostic.fixes[0].start, diagnostic.fixes[0].end),
+            (18, 19)
+        );
+
+        // A fix across both blocks has no place in the file
+        let diagnostic = module.map_diagnostic(
+            Diagnostic::warning("rule", Span::new(0, 12), "both")
+                .with_fix(Fix::new(Span::new(0, 12), "")),
+        );
+        assert_eq!((diagnostic.start, diagnostic.end), (5, 22));
+        assert!(diagnostic.fixes.is_empty());
+    }
+}
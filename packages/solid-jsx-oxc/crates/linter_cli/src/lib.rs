@@ -0,0 +1,9 @@
+//! File discovery, linting and reporting behind the `solid-lint` binary,
+//! shared with the language server
+
+pub mod embedded;
+pub mod lint;
+pub mod report;
+pub mod stylish;
+pub mod walk;
+pub mod watch;
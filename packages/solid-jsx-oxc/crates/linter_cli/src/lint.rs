@@ -0,0 +1,303 @@
+//! Linting source files with both lint runners
+//!
+//! Files are linted in parallel, each with its own allocator and `Semantic`;
+//! results come back in the order of the input paths, so output does not
+//! depend on scheduling.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use oxc_span::SourceType;
+use rayon::prelude::*;
+pub use solid_linter::PARSE_ERROR_RULE;
+use solid_linter::{fix_until_stable, Diagnostic, FixResult, LintConfig, Rule};
+
+use crate::embedded::{self, Markdown};
+
+/// What to do with the fixes of linted files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FixMode {
+    /// Report diagnostics only
+    #[default]
+    Off,
+    /// Apply fixes and write the files back (`--fix`)
+    Write,
+    /// Apply fixes in memory only (`--fix-dry-run`)
+    DryRun,
+}
+
+/// Diagnostics for one file
+pub struct FileReport {
+    pub path: PathBuf,
+    /// The source the diagnostics point into, after fixes when fixing
+    pub source_text: String,
+    /// The source as read, when fixes changed it
+    pub original_text: Option<String>,
+    pub diagnostics: Vec<Diagnostic>,
+    /// Reading or writing the file failed
+    pub error: Option<String>,
+}
+
+impl FileReport {
+    /// The fixed source text, if fixing changed the file
+    pub fn fixed_text(&self) -> Option<&str> {
+        self.original_text
+            .as_ref()
+            .map(|_| self.source_text.as_str())
+    }
+}
+
+/// Lints files with the config of their directory, plus the overrides
+/// matching each file
+pub struct Linter {
+    /// Used for directories without their own config
+    config: LintConfig,
+    /// Resolved configs of nested config files, by directory
+    dir_configs: HashMap<PathBuf, LintConfig>,
+    plugins: Vec<Arc<dyn Rule>>,
+    /// Lint fenced code blocks in Markdown files too
+    code_blocks: bool,
+}
+
+impl Linter {
+    pub fn new(config: LintConfig) -> Self {
+        Self {
+            config,
+            dir_configs: HashMap::new(),
+            plugins: Vec::new(),
+            code_blocks: false,
+        }
+    }
+
+    /// Use `config` for the files directly in `dir`
+    pub fn with_dir_config(mut self, dir: PathBuf, config: LintConfig) -> Self {
+        self.dir_configs.insert(dir, config);
+        self
+    }
+
+    /// Run a plugin rule too, in files whose config does not turn it off
+    pub fn with_plugin(mut self, rule: Arc<dyn Rule>) -> Self {
+        self.plugins.push(rule);
+        self
+    }
+
+    /// Lint fenced `jsx`/`tsx` code blocks in `.md` and `.mdx` files
+    pub fn with_code_blocks(mut self, enabled: bool) -> Self {
+        self.code_blocks = enabled;
+        self
+    }
+
+    /// The config for `path`, with its overrides applied
+    pub fn config_for(&self, path: &Path) -> LintConfig {
+        path.parent()
+            .and_then(|dir| self.dir_configs.get(dir))
+            .unwrap_or(&self.config)
+            .for_file(path)
+    }
+
+    /// Lint `source_text`, sorted by position; JSX is enabled for every file.
+    /// For Markdown files, the embedded modules are linted and diagnostics
+    /// point into the Markdown source.
+    pub fn lint_source(&self, path: &Path, source_text: &str) -> Vec<Diagnostic> {
+        if let Some(markdown) = Markdown::from_path(path) {
+            let mut diagnostics: Vec<_> =
+                embedded::extract(source_text, markdown, self.code_blocks)
+                    .into_iter()
+                    .flat_map(|module| {
+                        self.lint_module(path, &module.source_text, module.source_type)
+                            .into_iter()
+                            .map(move |diagnostic| module.map_diagnostic(diagnostic))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect();
+            diagnostics.sort_by_key(|d| (d.start, d.end));
+            return diagnostics;
+        }
+
+        let source_type = SourceType::from_path(path)
+            .unwrap_or_default()
+            .with_jsx(true);
+        self.lint_module(path, source_text, source_type)
+    }
+
+    fn lint_module(
+        &self,
+        path: &Path,
+        source_text: &str,
+        source_type: SourceType,
+    ) -> Vec<Diagnostic> {
+        solid_linter::lint_source(
+            source_text,
+            source_type,
+            &self.config_for(path),
+            &self.plugins,
+        )
+    }
+
+    /// Lint and fix `source_text` until no more fixes apply
+    pub fn fix_source(&self, path: &Path, source_text: &str) -> FixResult {
+        fix_until_stable(source_text, |source| self.lint_source(path, source))
+    }
+
+    /// Lint files in parallel, fixing them as `fix` says
+    pub fn lint_files(&self, paths: &[PathBuf], fix: FixMode) -> Vec<FileReport> {
+        paths
+            .par_iter()
+            .map(|path| self.lint_file(path, fix))
+            .collect()
+    }
+
+    fn lint_file(&self, path: &Path, fix: FixMode) -> FileReport {
+        let mut report = FileReport {
+            path: path.to_path_buf(),
+            source_text: String::new(),
+            original_text: None,
+            diagnostics: Vec::new(),
+            error: None,
+        };
+        let source_text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) => {
+                report.error = Some(format!("failed to read {}: {}", path.display(), err));
+                return report;
+            }
+        };
+
+        if fix != FixMode::Off {
+            let result = self.fix_source(path, &source_text);
+            if result.is_changed() {
+                if fix == FixMode::Write {
+                    if let Err(err) = std::fs::write(path, &result.output) {
+                        report.error = Some(format!("failed to write {}: {}", path.display(), err));
+                    }
+                }
+                report.original_text = Some(source_text);
+            }
+            report.source_text = result.output;
+            report.diagnostics = result.remaining;
+        } else {
+            report.diagnostics = self.lint_source(path, &source_text);
+            report.source_text = source_text;
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lint_files_in_order() {
+        let root = std::env::temp_dir().join(format!("solid-lint-files-{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        let paths: Vec<_> = (0..32)
+            .map(|i| {
+                let path = root.join(format!("File{:02}.tsx", i));
+                let source = if i % 2 == 0 {
+                    "export const A = () => <div className=\"a\" />;\n"
+                } else {
+                    "export const B = (props: { a: number }) => <div>{props.a}</div>;\n"
+                };
+                std::fs::write(&path, source).unwrap();
+                path
+            })
+            .collect();
+
+        let reports = Linter::new(LintConfig::default()).lint_files(&paths, FixMode::Off);
+        assert_eq!(
+            reports.iter().map(|r| &r.path).collect::<Vec<_>>(),
+            paths.iter().collect::<Vec<_>>()
+        );
+        for (i, report) in reports.iter().enumerate() {
+            let rules: Vec<_> = report.diagnostics.iter().map(|d| d.rule.as_str()).collect();
+            let expected: &[&str] = if i % 2 == 0 {
+                &["no-react-specific-props"]
+            } else {
+                &[]
+            };
+            assert_eq!(rules, expected, "{}", report.path.display());
+        }
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_config_per_file() {
+        let config = LintConfig::from_json_str(
+            r#"{
+                "rules": { "no-react-specific-props": "error" },
+                "overrides": [{ "files": "*.stories.tsx", "rules": { "no-react-specific-props": "off" } }]
+            }"#,
+        )
+        .unwrap();
+        let quiet =
+            LintConfig::from_json_str(r#"{ "rules": { "no-react-specific-props": "off" } }"#)
+                .unwrap();
+        let linter = Linter::new(config).with_dir_config("legacy".into(), quiet);
+        let source = "export const A = () => <div className=\"a\" />;\n";
+        let rules = |path: &str| -> Vec<String> {
+            linter
+                .lint_source(Path::new(path), source)
+                .into_iter()
+                .map(|d| format!("{:?} {}", d.severity, d.rule))
+                .collect()
+        };
+        assert_eq!(rules("src/A.tsx"), ["Error no-react-specific-props"]);
+        assert!(rules("src/A.stories.tsx").is_empty());
+        assert!(rules("legacy/A.tsx").is_empty());
+    }
+
+    #[test]
+    fn test_lint_markdown() {
+        let page = "import { Tabs } from \"./Tabs\";\n\n# Page\n\n<Tabs>\n  <Missing className=\"a\" />\n</Tabs>\n\n```jsx\n<div className=\"b\" />\n```\n";
+        let rules = |linter: &Linter, path: &str| -> Vec<(String, String)> {
+            linter
+                .lint_source(Path::new(path), page)
+                .into_iter()
+                .map(|d| (d.rule, page[d.start as usize..d.end as usize].to_string()))
+                .collect()
+        };
+        let linter = Linter::new(LintConfig::default());
+        assert_eq!(
+            rules(&linter, "docs/page.mdx"),
+            [
+                ("jsx-no-undef".to_string(), "Missing".to_string()),
+                (
+                    "no-react-specific-props".to_string(),
+                    "className=\"a\"".to_string()
+                ),
+            ]
+        );
+        assert!(rules(&linter, "docs/page.md").is_empty());
+
+        let linter = linter.with_code_blocks(true);
+        assert_eq!(rules(&linter, "docs/page.md").len(), 1);
+        let fixed = linter.fix_source(Path::new("page.mdx"), page);
+        assert_eq!(fixed.output, page.replace("className", "class"));
+    }
+
+    #[test]
+    fn test_fix_dry_run() {
+        let root = std::env::temp_dir().join(format!("solid-lint-dry-run-{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        let source = "export const A = () => <div className=\"a\" />;\n";
+        let fixed = root.join("Fixed.tsx");
+        let clean = root.join("Clean.tsx");
+        std::fs::write(&fixed, source).unwrap();
+        std::fs::write(&clean, "export const B = () => <div />;\n").unwrap();
+
+        let reports =
+            Linter::new(LintConfig::default()).lint_files(&[fixed.clone(), clean], FixMode::DryRun);
+        assert_eq!(std::fs::read_to_string(&fixed).unwrap(), source);
+        assert_eq!(reports[0].original_text.as_deref(), Some(source));
+        assert_eq!(
+            reports[0].fixed_text(),
+            Some("export const A = () => <div class=\"a\" />;\n")
+        );
+        assert!(reports[0].diagnostics.is_empty());
+        assert_eq!(reports[1].fixed_text(), None);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,252 @@
+//! solid-lint: run solid-linter over files and directories
+//!
+//! ```text
+//! solid-lint src/ --max-warnings 0
+//! solid-lint --fix src/components
+//! solid-lint --fix-dry-run src/ > fixes.diff
+//! solid-lint --format stylish src/
+//! solid-lint --format json src/ > diagnostics.json
+//! solid-lint --explain solid/reactivity
+//! solid-lint --code-blocks docs/
+//! solid-lint --watch src/
+//! ```
+
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Instant;
+
+use clap::Parser;
+use solid_lint::lint::{FixMode, Linter};
+use solid_lint::report::{self, Format};
+use solid_lint::stylish::Stylish;
+use solid_lint::walk;
+use solid_lint::watch::{self, Change, Watch};
+use solid_linter::{find_rule, ConfigResolver, ExitStatus, LintConfig, LintSummary};
+
+#[derive(Debug, Parser)]
+#[command(name = "solid-lint", version, about = "Lint Solid JSX and TSX files")]
+struct Args {
+    /// Files and directories to lint
+    #[arg(default_value = ".")]
+    paths: Vec<PathBuf>,
+
+    /// Config file for every file; by default each file uses the
+    /// .solidlint.json or solid-lint.toml files in its directory and ancestors
+    #[arg(short, long)]
+    config: Option<PathBuf>,
+
+    /// File extensions to lint in directories
+    #[arg(
+        long,
+        value_delimiter = ',',
+        default_value = "js,jsx,ts,tsx,mjs,cjs,mts,cts,mdx"
+    )]
+    ext: Vec<String>,
+
+    /// Fail when there are more warnings than this
+    #[arg(long)]
+    max_warnings: Option<usize>,
+
+    /// Apply fixes and write the files back
+    #[arg(long)]
+    fix: bool,
+
+    /// Print the fixes as unified diffs instead of writing them; with
+    /// --format json, the fixed text is each file's `output`
+    #[arg(long, conflicts_with = "fix")]
+    fix_dry_run: bool,
+
+    /// Also lint fenced jsx/tsx code blocks in Markdown, including .md files
+    #[arg(long)]
+    code_blocks: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Default)]
+    format: Format,
+
+    /// Print the documentation of a rule, such as `solid/reactivity`, and exit
+    #[arg(long, value_name = "RULE")]
+    explain: Option<String>,
+
+    /// Keep running and lint changed files again; a changed config or
+    /// ignore file lints every file again
+    #[arg(short, long)]
+    watch: bool,
+
+    /// Number of files to lint in parallel; defaults to the number of CPUs
+    #[arg(short = 'j', long)]
+    threads: Option<usize>,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let status = run(&args).unwrap_or_else(|message| {
+        eprintln!("solid-lint: {}", message);
+        ExitStatus::Fatal
+    });
+    ExitCode::from(status.code() as u8)
+}
+
+fn run(args: &Args) -> Result<ExitStatus, String> {
+    if let Some(name) = &args.explain {
+        let rule = find_rule(name).ok_or_else(|| format!("unknown rule `{}`", name))?;
+        print!("{}", report::explain(rule));
+        return Ok(ExitStatus::Success);
+    }
+
+    if let Some(threads) = args.threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .map_err(|err| err.to_string())?;
+    }
+
+    let mut extensions = args.ext.clone();
+    if args.code_blocks && !extensions.iter().any(|ext| ext == "md") {
+        extensions.push("md".to_string());
+    }
+    let fix = if args.fix {
+        FixMode::Write
+    } else if args.fix_dry_run {
+        FixMode::DryRun
+    } else {
+        FixMode::Off
+    };
+    let files = walk::collect_files(&args.paths, &extensions)?;
+    let linter = build_linter(args, &files)?.with_code_blocks(args.code_blocks);
+    if !args.watch {
+        return Ok(lint_and_report(args, &linter, &files, fix));
+    }
+
+    let start = Instant::now();
+    lint_and_report(args, &linter, &files, fix);
+    print_timing(files.len(), start);
+    watch(args, &extensions, fix, linter, files)
+}
+
+/// Lint again whenever the linted paths change, until interrupted
+fn watch(
+    args: &Args,
+    extensions: &[String],
+    fix: FixMode,
+    mut linter: Linter,
+    mut files: Vec<PathBuf>,
+) -> Result<ExitStatus, String> {
+    let watcher = Watch::new(&args.paths).map_err(|err| err.to_string())?;
+    loop {
+        let changed = watcher.next_changes()?;
+        let current = match walk::collect_files(&args.paths, extensions) {
+            Ok(current) => current,
+            Err(message) => {
+                eprintln!("solid-lint: {}", message);
+                continue;
+            }
+        };
+        let (relint, config_changed) =
+            match watch::classify(&changed, &current, args.config.as_deref()) {
+                None => continue,
+                Some(Change::All) => (current.clone(), true),
+                Some(Change::Files(changed)) => (changed, false),
+            };
+        // New files may be in directories the linter has no config for yet
+        if config_changed || relint.iter().any(|file| !files.contains(file)) {
+            match build_linter(args, &current) {
+                Ok(rebuilt) => linter = rebuilt.with_code_blocks(args.code_blocks),
+                Err(message) => {
+                    eprintln!("solid-lint: {}", message);
+                    continue;
+                }
+            }
+        }
+        files = current;
+
+        let start = Instant::now();
+        lint_and_report(args, &linter, &relint, fix);
+        print_timing(relint.len(), start);
+    }
+}
+
+fn print_timing(files: usize, start: Instant) {
+    eprintln!(
+        "solid-lint: linted {} file{} in {:.1?}, watching for changes",
+        files,
+        if files == 1 { "" } else { "s" },
+        start.elapsed()
+    );
+}
+
+/// Lint `files` and print the results in the chosen format
+fn lint_and_report(args: &Args, linter: &Linter, files: &[PathBuf], fix: FixMode) -> ExitStatus {
+    let mut summary = LintSummary::default();
+    let mut failed = false;
+    let reports = linter.lint_files(files, fix);
+    for file in &reports {
+        if let Some(error) = &file.error {
+            eprintln!("solid-lint: {}", error);
+            failed = true;
+        }
+        summary.add(&file.diagnostics);
+    }
+
+    if fix == FixMode::DryRun && args.format != Format::Json {
+        for file in &reports {
+            if let (Some(original), Some(fixed)) = (&file.original_text, file.fixed_text()) {
+                print!("{}", report::unified_diff(&file.path, original, fixed));
+            }
+        }
+    }
+
+    match args.format {
+        Format::Default => {
+            for file in &reports {
+                report::print_default(&file.path, &file.source_text, &file.diagnostics);
+            }
+            if summary.errors + summary.warnings > 0 {
+                println!("\n{} errors, {} warnings", summary.errors, summary.warnings);
+            }
+        }
+        Format::Stylish => {
+            let stylish = Stylish::for_stdout();
+            for file in &reports {
+                print!("{}", stylish.render_file(file));
+            }
+            print!("{}", stylish.render_summary(&reports));
+        }
+        Format::Json => println!("{}", report::to_json(&reports)),
+    }
+    if failed {
+        return ExitStatus::Fatal;
+    }
+    summary.exit_status(args.max_warnings)
+}
+
+/// With `--config`, one config for every file; otherwise each directory
+/// uses the config files found in it and its ancestors
+fn build_linter(args: &Args, files: &[PathBuf]) -> Result<Linter, String> {
+    if let Some(path) = &args.config {
+        let config = LintConfig::load(path).map_err(|err| err.to_string())?;
+        for diagnostic in &config.diagnostics {
+            eprintln!("solid-lint: config: {}", diagnostic.message);
+        }
+        return Ok(Linter::new(config));
+    }
+
+    let mut resolver = ConfigResolver::new();
+    let mut linter = Linter::new(LintConfig::default());
+    let dirs: BTreeSet<_> = files.iter().filter_map(|file| file.parent()).collect();
+    for dir in dirs {
+        let config = resolver
+            .resolve(dir)
+            .map_err(|err| format!("config for {}: {}", dir.display(), err))?;
+        linter = linter.with_dir_config(dir.to_path_buf(), config);
+    }
+    for (path, diagnostic) in resolver.diagnostics() {
+        eprintln!(
+            "solid-lint: config: {}: {}",
+            path.display(),
+            diagnostic.message
+        );
+    }
+    Ok(linter)
+}
@@ -0,0 +1,313 @@
+//! Printing diagnostics
+
+use std::path::Path;
+
+use serde::Serialize;
+use similar::TextDiff;
+use solid_linter::{Diagnostic, DiagnosticSeverity, LintSummary, RuleInfo};
+
+use crate::lint::FileReport;
+
+/// Output format, selected with `--format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// One line per diagnostic
+    Default,
+    /// Code frames and a per-rule summary
+    Stylish,
+    /// A JSON array with one entry per file, for editors and CI
+    Json,
+}
+
+/// 1-based line and column (in characters) of a byte offset
+pub fn line_col(source_text: &str, offset: u32) -> (usize, usize) {
+    let offset = (offset as usize).min(source_text.len());
+    let before = source_text.get(..offset).unwrap_or(source_text);
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let line = before.matches('\n').count() + 1;
+    (line, before[line_start..].chars().count() + 1)
+}
+
+pub fn severity_label(severity: DiagnosticSeverity) -> &'static str {
+    match severity {
+        DiagnosticSeverity::Error => "error",
+        DiagnosticSeverity::Warning => "warning",
+        DiagnosticSeverity::Info => "info",
+        DiagnosticSeverity::Hint => "hint",
+    }
+}
+
+/// One line per diagnostic, `path:line:col: severity [rule] message`
+pub fn print_default(path: &Path, source_text: &str, diagnostics: &[Diagnostic]) {
+    for diagnostic in diagnostics {
+        let (line, column) = line_col(source_text, diagnostic.start);
+        println!(
+            "{}:{}:{}: {} [{}] {}",
+            path.display(),
+            line,
+            column,
+            severity_label(diagnostic.severity),
+            diagnostic.rule,
+            diagnostic.message
+        );
+        if let Some(help) = &diagnostic.help {
+            println!("  help: {}", help);
+        }
+    }
+}
+
+/// `--fix-dry-run`: a unified diff of the fixes, for `patch -p0` or review
+/// tools; empty when nothing changed
+pub fn unified_diff(path: &Path, original: &str, fixed: &str) -> String {
+    let path = path.display().to_string();
+    TextDiff::from_lines(original, fixed)
+        .unified_diff()
+        .header(&path, &path)
+        .to_string()
+}
+
+/// `--explain`: the rule's description and metadata
+pub fn explain(rule: &RuleInfo) -> String {
+    let mut out = format!("solid/{}\n\n", rule.name);
+    let mut line = String::new();
+    for word in rule.description.split_whitespace() {
+        if !line.is_empty() && line.len() + 1 + word.len() > 80 {
+            out.push_str(&line);
+            out.push('\n');
+            line.clear();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    out.push_str(&line);
+    out.push_str("\n\n");
+    let severity = if rule.default_enabled {
+        format!("{} by default", severity_label(rule.default_severity))
+    } else {
+        format!(
+            "off by default, {} when enabled",
+            severity_label(rule.default_severity)
+        )
+    };
+    out.push_str(&format!(
+        "Category: {}\nSeverity: {}\nFixable:  {}\nDocs:     {}\n",
+        format!("{:?}", rule.category).to_lowercase(),
+        severity,
+        if rule.fixable { "yes" } else { "no" },
+        rule.docs_url()
+    ));
+    out
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonFile<'a> {
+    file: String,
+    error_count: usize,
+    warning_count: usize,
+    /// Fixed source text, when `--fix` or `--fix-dry-run` changed the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output: Option<&'a str>,
+    diagnostics: Vec<JsonDiagnostic<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonDiagnostic<'a> {
+    rule: &'a str,
+    severity: &'static str,
+    message: &'a str,
+    help: Option<&'a str>,
+    /// Documentation of the rule
+    url: Option<&'a str>,
+    start: JsonPosition,
+    end: JsonPosition,
+    /// Byte offsets into the file
+    range: [u32; 2],
+    /// Whether `--fix` would fix it
+    fixable: bool,
+    suggestions: Vec<JsonSuggestion<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonPosition {
+    line: usize,
+    column: usize,
+}
+
+#[derive(Serialize)]
+struct JsonSuggestion<'a> {
+    message: &'a str,
+    edits: Vec<JsonEdit<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonEdit<'a> {
+    range: [u32; 2],
+    replacement: &'a str,
+}
+
+fn position(source_text: &str, offset: u32) -> JsonPosition {
+    let (line, column) = line_col(source_text, offset);
+    JsonPosition { line, column }
+}
+
+/// All files as a JSON array, including files without diagnostics
+pub fn to_json(files: &[FileReport]) -> String {
+    let files: Vec<_> = files
+        .iter()
+        .map(|file| {
+            let summary = LintSummary::from_diagnostics(&file.diagnostics);
+            JsonFile {
+                file: file.path.display().to_string(),
+                error_count: summary.errors,
+                warning_count: summary.warnings,
+                output: file.fixed_text(),
+                diagnostics: file
+                    .diagnostics
+                    .iter()
+                    .map(|d| JsonDiagnostic {
+                        rule: &d.rule,
+                        severity: severity_label(d.severity),
+                        message: &d.message,
+                        help: d.help.as_deref(),
+                        url: d.url.as_deref(),
+                        start: position(&file.source_text, d.start),
+                        end: position(&file.source_text, d.end),
+                        range: [d.start, d.end],
+                        fixable: !d.fixes.is_empty(),
+                        suggestions: d
+                            .suggestions
+                            .iter()
+                            .map(|suggestion| JsonSuggestion {
+                                message: &suggestion.message,
+                                edits: suggestion
+                                    .fixes
+                                    .iter()
+                                    .map(|fix| JsonEdit {
+                                        range: [fix.start, fix.end],
+                                        replacement: &fix.replacement,
+                                    })
+                                    .collect(),
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            }
+        })
+        .collect();
+    serde_json::to_string_pretty(&files).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_span::Span;
+    use solid_linter::{Fix, Suggestion};
+
+    #[test]
+    fn test_line_col() {
+        let source = "const a = 1;\nconst é = <div />;\n";
+        assert_eq!(line_col(source, 0), (1, 1));
+        assert_eq!(line_col(source, 13), (2, 1));
+        // `<` follows the two-byte `é`
+        assert_eq!(line_col(source, 24), (2, 11));
+        assert_eq!(line_col(source, 999), (3, 1));
+    }
+
+    #[test]
+    fn test_unified_diff() {
+        let original = "const a = <div className=\"a\" />;\nconst b = 1;\n";
+        let fixed = "const a = <div class=\"a\" />;\nconst b = 1;\n";
+        assert_eq!(
+            unified_diff(Path::new("src/App.tsx"), original, fixed),
+            "--- src/App.tsx\n+++ src/App.tsx\n@@ -1,2 +1,2 @@\n\
+             -const a = <div className=\"a\" />;\n\
+             +const a = <div class=\"a\" />;\n \
+             const b = 1;\n"
+        );
+        assert_eq!(unified_diff(Path::new("a.tsx"), fixed, fixed), "");
+    }
+
+    #[test]
+    fn test_json() {
+        let source_text = "<div className=\"a\">{open() && <Modal />}</div>";
+        let file = FileReport {
+            path: "src/App.tsx".into(),
+            source_text: source_text.to_string(),
+            original_text: None,
+            diagnostics: vec![
+                Diagnostic::warning("no-react-specific-props", Span::new(5, 18), "className")
+                    .with_fix(Fix::new(Span::new(5, 14), "class")),
+                Diagnostic::error("prefer-show", Span::new(20, 39), "Use Show")
+                    .with_help("Show renders nothing for falsy values")
+                    .with_suggestion(
+                        Suggestion::new("Convert")
+                            .with_fix(Fix::new(Span::new(19, 40), "<Show />")),
+                    ),
+            ],
+            error: None,
+        };
+        let json: serde_json::Value = serde_json::from_str(&to_json(&[file])).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([{
+                "file": "src/App.tsx",
+                "errorCount": 1,
+                "warningCount": 1,
+                "diagnostics": [
+                    {
+                        "rule": "no-react-specific-props",
+                        "severity": "warning",
+                        "message": "className",
+                        "help": null,
+                        "url": "https://github.com/solidjs-community/eslint-plugin-solid/blob/main/docs/no-react-specific-props.md",
+                        "start": { "line": 1, "column": 6 },
+                        "end": { "line": 1, "column": 19 },
+                        "range": [5, 18],
+                        "fixable": true,
+                        "suggestions": []
+                    },
+                    {
+                        "rule": "prefer-show",
+                        "severity": "error",
+                        "message": "Use Show",
+                        "help": "Show renders nothing for falsy values",
+                        "url": "https://github.com/solidjs-community/eslint-plugin-solid/blob/main/docs/prefer-show.md",
+                        "start": { "line": 1, "column": 21 },
+                        "end": { "line": 1, "column": 40 },
+                        "range": [20, 39],
+                        "fixable": false,
+                        "suggestions": [{
+                            "message": "Convert",
+                            "edits": [{ "range": [19, 40], "replacement": "<Show />" }]
+                        }]
+                    }
+                ]
+            }])
+        );
+    }
+
+    #[test]
+    fn test_explain() {
+        let rule = solid_linter::find_rule("solid/no-react-deps").unwrap();
+        assert_eq!(
+            explain(rule),
+            "solid/no-react-deps\n\n\
+             Disallow usage of dependency arrays in createEffect and createMemo.\n\n\
+             Category: correctness\n\
+             Severity: warning by default\n\
+             Fixable:  yes\n\
+             Docs:     https://github.com/solidjs-community/eslint-plugin-solid/blob/main/docs/no-react-deps.md\n"
+        );
+
+        let rule = solid_linter::find_rule("require-cleanup").unwrap();
+        assert!(explain(rule).contains(
+            "Severity: off by default, warning when enabled\n\
+             Fixable:  no\n\
+             Docs:     https://github.com/ryansolid/solid-jsx-oxc/blob/main/packages/solid-jsx-oxc/crates/linter/docs/rules/require-cleanup.md\n"
+        ));
+    }
+}
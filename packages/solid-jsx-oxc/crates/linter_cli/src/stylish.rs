@@ -0,0 +1,310 @@
+//! The `stylish` reporter: code frames and a per-rule summary
+//!
+//! Each diagnostic shows the source line it starts on, with the span
+//! underlined up to the end of that line. Colors are plain ANSI escapes and
+//! are only used when stdout is a terminal and `NO_COLOR` is unset.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io::IsTerminal;
+
+use solid_linter::{Diagnostic, DiagnosticSeverity, LintSummary};
+
+use crate::lint::FileReport;
+use crate::report::{line_col, severity_label};
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+const RED: &str = "\x1b[31m";
+const YELLOW: &str = "\x1b[33m";
+const BLUE: &str = "\x1b[34m";
+const CYAN: &str = "\x1b[36m";
+
+/// Tabs are expanded so the underline lines up with the source
+const TAB: &str = "    ";
+
+pub struct Stylish {
+    color: bool,
+}
+
+impl Stylish {
+    pub fn new(color: bool) -> Self {
+        Self { color }
+    }
+
+    /// Color when writing to a terminal, unless `NO_COLOR` is set
+    pub fn for_stdout() -> Self {
+        Self::new(std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none())
+    }
+
+    fn paint(&self, style: &str, text: &str) -> String {
+        if self.color {
+            format!("{}{}{}", style, text, RESET)
+        } else {
+            text.to_string()
+        }
+    }
+
+    fn severity_style(severity: DiagnosticSeverity) -> &'static str {
+        match severity {
+            DiagnosticSeverity::Error => RED,
+            DiagnosticSeverity::Warning => YELLOW,
+            DiagnosticSeverity::Info => BLUE,
+            DiagnosticSeverity::Hint => CYAN,
+        }
+    }
+
+    /// All diagnostics of one file; empty when there are none
+    pub fn render_file(&self, file: &FileReport) -> String {
+        let mut out = String::new();
+        for diagnostic in &file.diagnostics {
+            self.render_diagnostic(&mut out, file, diagnostic);
+        }
+        out
+    }
+
+    fn render_diagnostic(&self, out: &mut String, file: &FileReport, diagnostic: &Diagnostic) {
+        let source_text = file.source_text.as_str();
+        let style = Self::severity_style(diagnostic.severity);
+        let (line, column) = line_col(source_text, diagnostic.start);
+        let gutter = " ".repeat(line.to_string().len());
+        let bar = self.paint(BLUE, "|");
+
+        let _ = writeln!(
+            out,
+            "{}{}",
+            self.paint(
+                &format!("{}{}", BOLD, style),
+                &format!(
+                    "{}[{}]",
+                    severity_label(diagnostic.severity),
+                    diagnostic.rule
+                )
+            ),
+            self.paint(BOLD, &format!(": {}", diagnostic.message))
+        );
+        let _ = writeln!(
+            out,
+            "{}{} {}:{}:{}",
+            gutter,
+            self.paint(BLUE, "-->"),
+            file.path.display(),
+            line,
+            column
+        );
+        let _ = writeln!(out, "{} {}", gutter, bar);
+
+        let mut start = (diagnostic.start as usize).min(source_text.len());
+        while !source_text.is_char_boundary(start) {
+            start -= 1;
+        }
+        let line_start = source_text[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source_text[start..]
+            .find('\n')
+            .map_or(source_text.len(), |i| start + i);
+        let mut end = (diagnostic.end as usize).clamp(start, line_end);
+        while !source_text.is_char_boundary(end) {
+            end -= 1;
+        }
+        let source_line = &source_text[line_start..line_end];
+        let prefix = source_text.get(line_start..start).unwrap_or("");
+        let label = source_text.get(start..end).unwrap_or("");
+
+        let _ = writeln!(
+            out,
+            "{} {} {}",
+            self.paint(BLUE, &line.to_string()),
+            bar,
+            source_line.replace('\t', TAB).trim_end()
+        );
+        let _ = writeln!(
+            out,
+            "{} {} {}{}",
+            gutter,
+            bar,
+            " ".repeat(display_width(prefix)),
+            self.paint(style, &"^".repeat(display_width(label).max(1)))
+        );
+        if let Some(help) = &diagnostic.help {
+            let _ = writeln!(out, "{} {} help: {}", gutter, self.paint(BLUE, "="), help);
+        }
+        out.push('\n');
+    }
+
+    /// Counts per rule, sorted by rule name, then the totals
+    pub fn render_summary(&self, files: &[FileReport]) -> String {
+        let mut rules: BTreeMap<&str, LintSummary> = BTreeMap::new();
+        let mut total = LintSummary::default();
+        for file in files {
+            for diagnostic in &file.diagnostics {
+                let single = std::slice::from_ref(diagnostic);
+                rules.entry(&diagnostic.rule).or_default().add(single);
+                total.add(single);
+            }
+        }
+        if total.errors + total.warnings == 0 {
+            return String::new();
+        }
+
+        let width = rules
+            .keys()
+            .map(|rule| rule.len())
+            .max()
+            .unwrap_or(0)
+            .max(4);
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{}",
+            self.paint(
+                DIM,
+                &format!("{:<width$}  {:>6}  {:>8}", "rule", "errors", "warnings")
+            )
+        );
+        for (rule, counts) in &rules {
+            if counts.errors + counts.warnings == 0 {
+                continue;
+            }
+            let _ = writeln!(
+                out,
+                "{:<width$}  {}  {}",
+                rule,
+                self.count(RED, counts.errors, 6),
+                self.count(YELLOW, counts.warnings, 8)
+            );
+        }
+        let _ = writeln!(
+            out,
+            "\n{}, {}",
+            self.paint(
+                &format!("{}{}", BOLD, if total.errors > 0 { RED } else { "" }),
+                &plural(total.errors, "error")
+            ),
+            self.paint(
+                &format!("{}{}", BOLD, if total.warnings > 0 { YELLOW } else { "" }),
+                &plural(total.warnings, "warning")
+            )
+        );
+        out
+    }
+
+    /// Right-aligned, and only colored when non-zero
+    fn count(&self, style: &str, count: usize, width: usize) -> String {
+        let text = format!("{:>width$}", count);
+        if count > 0 {
+            self.paint(style, &text)
+        } else {
+            text
+        }
+    }
+}
+
+fn display_width(text: &str) -> usize {
+    text.chars()
+        .map(|c| if c == '\t' { TAB.len() } else { 1 })
+        .sum()
+}
+
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("{} {}", count, noun)
+    } else {
+        format!("{} {}s", count, noun)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_span::Span;
+
+    fn report(source_text: &str, diagnostics: Vec<Diagnostic>) -> FileReport {
+        FileReport {
+            path: "src/App.tsx".into(),
+            source_text: source_text.to_string(),
+            original_text: None,
+            diagnostics,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_code_frame() {
+        let source_text = "const a = 1;\n\tconst b = <div className=\"a\" />;\n";
+        let file = report(
+            source_text,
+            vec![
+                Diagnostic::warning("no-react-specific-props", Span::new(29, 38), "Use class")
+                    .with_help("Solid uses `class`"),
+            ],
+        );
+        assert_eq!(
+            Stylish::new(false).render_file(&file),
+            [
+                "warning[no-react-specific-props]: Use class",
+                " --> src/App.tsx:2:17",
+                "  |",
+                "2 |     const b = <div className=\"a\" />;",
+                "  |                    ^^^^^^^^^",
+                "  = help: Solid uses `class`",
+                "",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_multiline_and_empty_spans() {
+        let source_text = "<Show\n  when={a}>\n";
+        let file = report(
+            source_text,
+            vec![
+                Diagnostic::error("prefer-show", Span::new(1, 16), "Multi-line"),
+                Diagnostic::error("parse-error", Span::new(17, 17), "Unexpected end"),
+            ],
+        );
+        let output = Stylish::new(false).render_file(&file);
+        let lines: Vec<_> = output.lines().collect();
+        // Underlined up to the end of the first line
+        assert_eq!(lines[3], "1 | <Show");
+        assert_eq!(lines[4], "  |  ^^^^");
+        // Empty spans get one caret
+        assert_eq!(lines[9], "2 |   when={a}>");
+        assert_eq!(lines[10], "  |            ^");
+    }
+
+    #[test]
+    fn test_summary() {
+        let files = [
+            report(
+                "",
+                vec![
+                    Diagnostic::warning("prefer-for", Span::new(0, 0), ""),
+                    Diagnostic::error("jsx-no-undef", Span::new(0, 0), ""),
+                ],
+            ),
+            report(
+                "",
+                vec![Diagnostic::warning("prefer-for", Span::new(0, 0), "")],
+            ),
+        ];
+        assert_eq!(
+            Stylish::new(false).render_summary(&files),
+            [
+                "rule          errors  warnings",
+                "jsx-no-undef       1         0",
+                "prefer-for         0         2",
+                "",
+                "1 error, 2 warnings",
+                "",
+            ]
+            .join("\n")
+        );
+        assert_eq!(Stylish::new(false).render_summary(&[]), "");
+
+        let colored = Stylish::new(true).render_summary(&files);
+        assert!(colored.contains("\x1b[31m     1\x1b[0m"));
+    }
+}
@@ -0,0 +1,93 @@
+//! Finding the files to lint
+//!
+//! Directories are walked with the `ignore` crate, so `.gitignore`, `.ignore`
+//! and `.solidlintignore` files are respected (even outside a git checkout)
+//! and hidden files are skipped. Files named on the command line are always
+//! linted, and a path that does not exist is an error.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ignore::WalkBuilder;
+
+/// Ignore file with `.gitignore` syntax for paths only the linter should skip
+pub const IGNORE_FILE_NAME: &str = ".solidlintignore";
+
+/// Files under `paths` with one of `extensions`, sorted and deduplicated
+pub fn collect_files(paths: &[PathBuf], extensions: &[String]) -> Result<Vec<PathBuf>, String> {
+    let mut files = Vec::new();
+    for path in paths {
+        let metadata = fs::metadata(path).map_err(|err| format!("{}: {}", path.display(), err))?;
+        if metadata.is_file() {
+            files.push(path.clone());
+            continue;
+        }
+        let walker = WalkBuilder::new(path)
+            .add_custom_ignore_filename(IGNORE_FILE_NAME)
+            .require_git(false)
+            .build();
+        for entry in walker.flatten() {
+            if entry.file_type().is_some_and(|t| t.is_file())
+                && has_extension(entry.path(), extensions)
+            {
+                files.push(entry.into_path());
+            }
+        }
+    }
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+fn has_extension(path: &Path, extensions: &[String]) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| extensions.iter().any(|e| e == ext))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect_files() {
+        let root = std::env::temp_dir().join(format!("solid-lint-walk-{}", std::process::id()));
+        for dir in ["src/components", "dist", "generated"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        for file in [
+            "src/App.tsx",
+            "src/components/Button.jsx",
+            "src/styles.css",
+            "dist/App.js",
+            "generated/routes.tsx",
+            "vite.config.ts",
+        ] {
+            fs::write(root.join(file), "").unwrap();
+        }
+        fs::write(root.join(".gitignore"), "dist/\n").unwrap();
+        fs::write(root.join(IGNORE_FILE_NAME), "generated/\n").unwrap();
+
+        let extensions = ["js", "jsx", "tsx"].map(String::from);
+        let files = collect_files(std::slice::from_ref(&root), &extensions).unwrap();
+        let relative: Vec<_> = files
+            .iter()
+            .map(|f| f.strip_prefix(&root).unwrap().to_str().unwrap().to_string())
+            .collect();
+        assert_eq!(relative, ["src/App.tsx", "src/components/Button.jsx"]);
+
+        // Named files are linted even when ignored
+        let named = root.join("dist/App.js");
+        assert_eq!(
+            collect_files(std::slice::from_ref(&named), &extensions).unwrap(),
+            [named]
+        );
+
+        // A missing path is an error rather than nothing to lint
+        let missing = root.join("missing");
+        let error = collect_files(&[root.clone(), missing.clone()], &extensions).unwrap_err();
+        assert!(error.starts_with(&missing.display().to_string()), "{error}");
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,184 @@
+//! Watching the linted paths for changes (`--watch`)
+//!
+//! File system events are collected until the paths have been quiet for
+//! [`DEBOUNCE`], so an editor saving several files at once, or writing one
+//! file in several steps, causes a single re-lint. Only the changed files are
+//! linted again, unless a config or ignore file changed, which can change the
+//! result for any file.
+//!
+//! The `solid-jsx` compiler watches its sources with the same [`Watch`], and
+//! answers a batch of changes with a [`Change`] too.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use solid_linter::CONFIG_FILE_NAMES;
+
+use crate::walk::IGNORE_FILE_NAME;
+
+/// How long the paths have to be quiet before a batch of changes is handled
+pub const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Ignore files that directory walks respect, which change which files there
+/// are
+pub const IGNORE_FILE_NAMES: &[&str] = &[".gitignore", ".ignore"];
+
+/// What a batch of changed paths means for the next run
+#[derive(Debug, PartialEq, Eq)]
+pub enum Change {
+    /// A file that any file may depend on changed, such as a config or
+    /// ignore file: run on every file again
+    All,
+    /// Run on these files again
+    Files(Vec<PathBuf>),
+}
+
+/// The file name of `path`, empty when it has none
+pub fn file_name(path: &Path) -> &str {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default()
+}
+
+/// Whether `path` is a config or ignore file, or the `--config` file
+pub fn is_config_file(path: &Path, config: Option<&Path>) -> bool {
+    let name = file_name(path);
+    CONFIG_FILE_NAMES.contains(&name)
+        || name == IGNORE_FILE_NAME
+        || IGNORE_FILE_NAMES.contains(&name)
+        || config.is_some_and(|config| same_file(path, config))
+}
+
+fn same_file(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+/// The run that `changed` calls for, given the files there are to lint now;
+/// `None` when none of them is linted
+pub fn classify(changed: &[PathBuf], files: &[PathBuf], config: Option<&Path>) -> Option<Change> {
+    if changed.iter().any(|path| is_config_file(path, config)) {
+        return Some(Change::All);
+    }
+
+    // Events carry absolute paths, while `files` are as found from the
+    // command line
+    let by_canonical: HashMap<PathBuf, &PathBuf> = files
+        .iter()
+        .filter_map(|file| Some((file.canonicalize().ok()?, file)))
+        .collect();
+    let mut relint: Vec<PathBuf> = changed
+        .iter()
+        .filter_map(|path| by_canonical.get(&path.canonicalize().ok()?))
+        .map(|file| file.to_path_buf())
+        .collect();
+    relint.sort();
+    relint.dedup();
+    (!relint.is_empty()).then_some(Change::Files(relint))
+}
+
+/// Watches the files and directories given on the command line
+pub struct Watch {
+    // Dropping the watcher stops the events
+    _watcher: RecommendedWatcher,
+    events: Receiver<notify::Result<Event>>,
+}
+
+impl Watch {
+    /// Watch directories recursively; files are watched through their
+    /// directory, so editors that save by replacing the file are noticed
+    pub fn new(paths: &[PathBuf]) -> notify::Result<Self> {
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+        for path in paths {
+            if path.is_dir() {
+                watcher.watch(path, RecursiveMode::Recursive)?;
+            } else {
+                let dir = path
+                    .parent()
+                    .filter(|dir| !dir.as_os_str().is_empty())
+                    .unwrap_or(Path::new("."));
+                watcher.watch(dir, RecursiveMode::NonRecursive)?;
+            }
+        }
+        Ok(Self {
+            _watcher: watcher,
+            events,
+        })
+    }
+
+    /// Wait for changes and return the changed paths, once the paths have
+    /// been quiet for [`DEBOUNCE`]
+    pub fn next_changes(&self) -> Result<Vec<PathBuf>, String> {
+        let mut changed = Vec::new();
+        let mut received = self.events.recv().map_err(|err| err.to_string())?;
+        loop {
+            let event = received.map_err(|err| err.to_string())?;
+            if !matches!(event.kind, EventKind::Access(_)) {
+                changed.extend(event.paths);
+            }
+            received = match self.events.recv_timeout(DEBOUNCE) {
+                Ok(event) => event,
+                Err(RecvTimeoutError::Timeout) if changed.is_empty() => {
+                    self.events.recv().map_err(|err| err.to_string())?
+                }
+                Err(RecvTimeoutError::Timeout) => break,
+                Err(err) => return Err(err.to_string()),
+            };
+        }
+        changed.sort();
+        changed.dedup();
+        Ok(changed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_classify() {
+        let root = std::env::temp_dir().join(format!("solid-lint-watch-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        let files: Vec<_> = ["src/A.tsx", "src/B.tsx"]
+            .iter()
+            .map(|file| {
+                let path = root.join(file);
+                fs::write(&path, "").unwrap();
+                path
+            })
+            .collect();
+        fs::write(root.join("notes.txt"), "").unwrap();
+        let absolute = root.canonicalize().unwrap();
+
+        assert_eq!(
+            classify(
+                &[absolute.join("src/B.tsx"), absolute.join("notes.txt")],
+                &files,
+                None
+            ),
+            Some(Change::Files(vec![files[1].clone()]))
+        );
+        assert_eq!(classify(&[absolute.join("notes.txt")], &files, None), None);
+        assert_eq!(
+            classify(&[absolute.join("src/.solidlint.json")], &files, None),
+            Some(Change::All)
+        );
+        assert_eq!(
+            classify(
+                &[absolute.join("notes.txt")],
+                &files,
+                Some(&root.join("notes.txt"))
+            ),
+            Some(Change::All)
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,294 @@
+//! The language server
+//!
+//! Open JavaScript and TypeScript documents are linted on open and on every
+//! change. Each document uses the config files of its directory and its
+//! ancestors, resolved as the CLI resolves them. Saving a config file reloads
+//! every config and relints the open documents.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use solid_lint::lint::Linter;
+use solid_linter::{ConfigResolver, Diagnostic, LintConfig, CONFIG_FILE_NAMES};
+use tower_lsp::jsonrpc::Result;
+use tower_lsp::lsp_types::*;
+use tower_lsp::{Client, LanguageServer};
+
+use crate::convert;
+
+/// `languageId`s of the documents to lint
+const LANGUAGE_IDS: [&str; 5] = [
+    "javascript",
+    "javascriptreact",
+    "typescript",
+    "typescriptreact",
+    "mdx",
+];
+
+struct Document {
+    text: String,
+    /// Diagnostics for `text`, kept for code actions and hover
+    diagnostics: Vec<Diagnostic>,
+}
+
+/// The config files loaded so far, and a linter for every directory with
+/// open documents
+#[derive(Default)]
+struct Configs {
+    resolver: ConfigResolver,
+    linters: HashMap<PathBuf, Arc<Linter>>,
+    /// Config files whose problems were shown already
+    reported: HashSet<PathBuf>,
+}
+
+impl Configs {
+    /// The linter for documents in `dir`, and the config problems to show
+    /// that were not shown yet
+    fn linter_for(&mut self, dir: &Path) -> (Arc<Linter>, Vec<(MessageType, String)>) {
+        if let Some(linter) = self.linters.get(dir) {
+            return (linter.clone(), Vec::new());
+        }
+        let mut messages = Vec::new();
+        // A broken config is reported once, and the directory linted with the
+        // defaults until it is saved again
+        let config = self.resolver.resolve(dir).unwrap_or_else(|err| {
+            messages.push((MessageType::ERROR, format!("solid-lint: {}", err)));
+            LintConfig::default()
+        });
+        let problems: Vec<_> = self
+            .resolver
+            .diagnostics()
+            .filter(|(path, _)| !self.reported.contains(*path))
+            .map(|(path, diagnostic)| (path.to_path_buf(), diagnostic.message.clone()))
+            .collect();
+        for (path, message) in problems {
+            messages.push((
+                MessageType::WARNING,
+                format!("solid-lint: config: {}: {}", path.display(), message),
+            ));
+            self.reported.insert(path);
+        }
+
+        let linter = Arc::new(Linter::new(config));
+        self.linters.insert(dir.to_path_buf(), linter.clone());
+        (linter, messages)
+    }
+}
+
+pub struct Backend {
+    client: Client,
+    configs: Mutex<Configs>,
+    documents: Mutex<HashMap<Url, Document>>,
+}
+
+impl Backend {
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            configs: Mutex::new(Configs::default()),
+            documents: Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn lint(&self, uri: Url, text: String, version: Option<i32>) {
+        let path = uri
+            .to_file_path()
+            .unwrap_or_else(|_| PathBuf::from(uri.path()));
+        let dir = path.parent().unwrap_or(Path::new("."));
+        let (linter, messages) = self.configs.lock().unwrap().linter_for(dir);
+        for (kind, message) in messages {
+            self.client.show_message(kind, message).await;
+        }
+        let diagnostics = linter.lint_source(&path, &text);
+        let lsp_diagnostics = diagnostics
+            .iter()
+            .map(|diagnostic| convert::diagnostic(&uri, &text, diagnostic))
+            .collect();
+        self.documents
+            .lock()
+            .unwrap()
+            .insert(uri.clone(), Document { text, diagnostics });
+        self.client
+            .publish_diagnostics(uri, lsp_diagnostics, version)
+            .await;
+    }
+
+    async fn relint_all(&self) {
+        let documents: Vec<_> = self
+            .documents
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(uri, document)| (uri.clone(), document.text.clone()))
+            .collect();
+        for (uri, text) in documents {
+            self.lint(uri, text, None).await;
+        }
+    }
+}
+
+fn is_config_file(uri: &Url) -> bool {
+    uri.to_file_path().is_ok_and(|path| {
+        path.file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| CONFIG_FILE_NAMES.contains(&name))
+    })
+}
+
+#[tower_lsp::async_trait]
+impl LanguageServer for Backend {
+    async fn initialize(&self, _: InitializeParams) -> Result<InitializeResult> {
+        Ok(InitializeResult {
+            capabilities: ServerCapabilities {
+                text_document_sync: Some(TextDocumentSyncCapability::Options(
+                    TextDocumentSyncOptions {
+                        open_close: Some(true),
+                        change: Some(TextDocumentSyncKind::FULL),
+                        save: Some(TextDocumentSyncSaveOptions::Supported(true)),
+                        ..Default::default()
+                    },
+                )),
+                code_action_provider: Some(CodeActionProviderCapability::Options(
+                    CodeActionOptions {
+                        code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+                        ..Default::default()
+                    },
+                )),
+                hover_provider: Some(HoverProviderCapability::Simple(true)),
+                ..Default::default()
+            },
+            server_info: Some(ServerInfo {
+                name: env!("CARGO_PKG_NAME").to_string(),
+                version: Some(env!("CARGO_PKG_VERSION").to_string()),
+            }),
+        })
+    }
+
+    async fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        let document = params.text_document;
+        if LANGUAGE_IDS.contains(&document.language_id.as_str()) {
+            self.lint(document.uri, document.text, Some(document.version))
+                .await;
+        }
+    }
+
+    async fn did_change(&self, params: DidChangeTextDocumentParams) {
+        let uri = params.text_document.uri;
+        if !self.documents.lock().unwrap().contains_key(&uri) {
+            return;
+        }
+        // Full sync: the last change holds the whole text
+        if let Some(change) = params.content_changes.into_iter().last() {
+            self.lint(uri, change.text, Some(params.text_document.version))
+                .await;
+        }
+    }
+
+    async fn did_save(&self, params: DidSaveTextDocumentParams) {
+        if is_config_file(&params.text_document.uri) {
+            *self.configs.lock().unwrap() = Configs::default();
+            self.relint_all().await;
+        }
+    }
+
+    async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        let uri = params.text_document.uri;
+        if self.documents.lock().unwrap().remove(&uri).is_some() {
+            self.client.publish_diagnostics(uri, Vec::new(), None).await;
+        }
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        let uri = params.text_document.uri;
+        let documents = self.documents.lock().unwrap();
+        let Some(document) = documents.get(&uri) else {
+            return Ok(None);
+        };
+        let start = convert::offset(&document.text, params.range.start);
+        let end = convert::offset(&document.text, params.range.end);
+        let actions: Vec<_> = document
+            .diagnostics
+            .iter()
+            .filter(|d| d.start <= end && start <= d.end)
+            .flat_map(|d| convert::code_actions(&uri, &document.text, d))
+            .map(CodeActionOrCommand::CodeAction)
+            .collect();
+        Ok((!actions.is_empty()).then_some(actions))
+    }
+
+    async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        let position = params.text_document_position_params;
+        let documents = self.documents.lock().unwrap();
+        let Some(document) = documents.get(&position.text_document.uri) else {
+            return Ok(None);
+        };
+        let offset = convert::offset(&document.text, position.position);
+        let diagnostics: Vec<_> = document
+            .diagnostics
+            .iter()
+            .filter(|d| d.start <= offset && offset <= d.end)
+            .collect();
+        let Some(first) = diagnostics.first() else {
+            return Ok(None);
+        };
+        Ok(Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: convert::hover(&diagnostics),
+            }),
+            range: Some(convert::range(&document.text, first.start, first.end)),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_config_per_directory() {
+        let root = std::env::temp_dir().join(format!("solid-lsp-configs-{}", std::process::id()));
+        let legacy = root.join("legacy");
+        let broken = root.join("broken");
+        fs::create_dir_all(&legacy).unwrap();
+        fs::create_dir_all(&broken).unwrap();
+        fs::write(root.join(".solidlint.json"), r#"{ "root": true }"#).unwrap();
+        fs::write(
+            legacy.join(".solidlint.json"),
+            r#"{ "rules": { "solid/no-react-specific-props": "off" } }"#,
+        )
+        .unwrap();
+        fs::write(
+            broken.join(".solidlint.json"),
+            r#"{ "rules": { "solid/no-such-rule": "warn" } }"#,
+        )
+        .unwrap();
+
+        let source = r#"const a = <div className="a" />;"#;
+        let lint = |configs: &mut Configs, dir: &Path| {
+            let (linter, messages) = configs.linter_for(dir);
+            (linter.lint_source(&dir.join("App.tsx"), source), messages)
+        };
+        let mut configs = Configs::default();
+        let (at_root, root_messages) = lint(&mut configs, &root);
+        let (in_legacy, _) = lint(&mut configs, &legacy);
+        let (in_broken, broken_messages) = lint(&mut configs, &broken);
+        let (_, repeated) = lint(&mut configs, &broken);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(at_root.len(), 1);
+        assert!(root_messages.is_empty());
+        assert!(in_legacy.is_empty());
+        // The broken config is reported once, and the defaults used
+        assert_eq!(in_broken.len(), 1);
+        assert_eq!(broken_messages.len(), 1);
+        assert!(broken_messages[0].1.contains("no-such-rule"));
+        assert!(repeated.is_empty());
+    }
+}
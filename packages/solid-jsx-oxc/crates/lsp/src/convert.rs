@@ -0,0 +1,231 @@
+//! Converting solid-linter diagnostics to LSP types
+//!
+//! Diagnostics carry byte offsets; LSP positions are a line and a column in
+//! UTF-16 code units.
+
+use std::collections::HashMap;
+
+use solid_linter::{Diagnostic, DiagnosticSeverity, Fix};
+use tower_lsp::lsp_types::{
+    self, CodeAction, CodeActionKind, CodeDescription, DiagnosticRelatedInformation, Location,
+    NumberOrString, Position, Range, TextEdit, Url, WorkspaceEdit,
+};
+
+/// Shown as the diagnostic source in editors
+pub const SOURCE: &str = "solid";
+
+/// Byte offset to an LSP position, clamped to the text
+pub fn position(text: &str, offset: u32) -> Position {
+    let mut offset = (offset as usize).min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    Position::new(
+        before.matches('\n').count() as u32,
+        before[line_start..].encode_utf16().count() as u32,
+    )
+}
+
+/// LSP position to a byte offset; columns past the end of a line stop there
+pub fn offset(text: &str, position: Position) -> u32 {
+    let mut line_start = 0;
+    for _ in 0..position.line {
+        match text[line_start..].find('\n') {
+            Some(i) => line_start += i + 1,
+            None => return text.len() as u32,
+        }
+    }
+    let mut units = 0;
+    let mut offset = line_start;
+    for c in text[line_start..].chars() {
+        if c == '\n' || units >= position.character as usize {
+            break;
+        }
+        units += c.len_utf16();
+        offset += c.len_utf8();
+    }
+    offset as u32
+}
+
+pub fn range(text: &str, start: u32, end: u32) -> Range {
+    Range::new(position(text, start), position(text, end))
+}
+
+pub fn diagnostic(uri: &Url, text: &str, diagnostic: &Diagnostic) -> lsp_types::Diagnostic {
+    let code_description = diagnostic
+        .url
+        .as_deref()
+        .and_then(|url| Url::parse(url).ok())
+        .map(|href| CodeDescription { href });
+    let related_information = (!diagnostic.labels.is_empty()).then(|| {
+        diagnostic
+            .labels
+            .iter()
+            .map(|(start, end, message)| DiagnosticRelatedInformation {
+                location: Location::new(uri.clone(), range(text, *start, *end)),
+                message: message.clone(),
+            })
+            .collect()
+    });
+    lsp_types::Diagnostic {
+        range: range(text, diagnostic.start, diagnostic.end),
+        severity: Some(match diagnostic.severity {
+            DiagnosticSeverity::Error => lsp_types::DiagnosticSeverity::ERROR,
+            DiagnosticSeverity::Warning => lsp_types::DiagnosticSeverity::WARNING,
+            DiagnosticSeverity::Info => lsp_types::DiagnosticSeverity::INFORMATION,
+            DiagnosticSeverity::Hint => lsp_types::DiagnosticSeverity::HINT,
+        }),
+        code: Some(NumberOrString::String(diagnostic.rule.clone())),
+        code_description,
+        source: Some(SOURCE.to_string()),
+        message: diagnostic.message.clone(),
+        related_information,
+        ..Default::default()
+    }
+}
+
+/// Quick fixes for a diagnostic: its fix first, then each suggestion
+pub fn code_actions(uri: &Url, text: &str, diagnostic: &Diagnostic) -> Vec<CodeAction> {
+    let lsp_diagnostic = self::diagnostic(uri, text, diagnostic);
+    let action = |title: String, fixes: &[Fix], preferred: bool| CodeAction {
+        title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![lsp_diagnostic.clone()]),
+        edit: Some(workspace_edit(uri, text, fixes)),
+        is_preferred: Some(preferred),
+        ..Default::default()
+    };
+
+    let mut actions = Vec::new();
+    if !diagnostic.fixes.is_empty() {
+        let title = diagnostic
+            .fixes
+            .iter()
+            .find_map(|fix| fix.message.clone().filter(|m| !m.is_empty()))
+            .unwrap_or_else(|| format!("Fix this `{}` problem", diagnostic.rule));
+        actions.push(action(title, &diagnostic.fixes, true));
+    }
+    for suggestion in &diagnostic.suggestions {
+        actions.push(action(suggestion.message.clone(), &suggestion.fixes, false));
+    }
+    actions
+}
+
+fn workspace_edit(uri: &Url, text: &str, fixes: &[Fix]) -> WorkspaceEdit {
+    let edits = fixes
+        .iter()
+        .map(|fix| TextEdit::new(range(text, fix.start, fix.end), fix.replacement.clone()))
+        .collect();
+    WorkspaceEdit {
+        changes: Some(HashMap::from([(uri.clone(), edits)])),
+        ..Default::default()
+    }
+}
+
+/// Markdown for the diagnostics under the cursor, with links to rule docs
+pub fn hover(diagnostics: &[&Diagnostic]) -> String {
+    diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let mut value = format!("**{}/{}**: {}", SOURCE, diagnostic.rule, diagnostic.message);
+            if let Some(help) = &diagnostic.help {
+                value.push_str("\n\n");
+                value.push_str(help);
+            }
+            if let Some(url) = &diagnostic.url {
+                value.push_str(&format!("\n\n[Documentation]({})", url));
+            }
+            value
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n---\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_span::Span;
+    use solid_lint::lint::PARSE_ERROR_RULE;
+    use solid_linter::{docs_url, Suggestion};
+
+    #[test]
+    fn test_positions() {
+        // `😀` is two UTF-16 code units and four bytes
+        let text = "a\n😀 <div />\nb";
+        assert_eq!(position(text, 0), Position::new(0, 0));
+        assert_eq!(position(text, 2), Position::new(1, 0));
+        assert_eq!(position(text, 7), Position::new(1, 3));
+        assert_eq!(position(text, 99), Position::new(2, 1));
+        // Inside `😀` rounds down to its start
+        assert_eq!(position(text, 4), Position::new(1, 0));
+
+        assert_eq!(offset(text, Position::new(1, 3)), 7);
+        assert_eq!(offset(text, Position::new(1, 99)), 14);
+        assert_eq!(offset(text, Position::new(9, 0)), text.len() as u32);
+        for i in [0, 2, 6, 7, 14, 15] {
+            assert_eq!(offset(text, position(text, i)), i);
+        }
+    }
+
+    #[test]
+    fn test_code_actions() {
+        let uri = Url::parse("file:///src/App.tsx").unwrap();
+        let text = "<div className=\"a\" />";
+        let diagnostic = Diagnostic::warning("no-react-specific-props", Span::new(5, 18), "m")
+            .with_fix(Fix::new(Span::new(5, 14), "class"))
+            .with_suggestion(
+                Suggestion::new("Remove the prop").with_fix(Fix::new(Span::new(4, 18), "")),
+            );
+
+        let actions = code_actions(&uri, text, &diagnostic);
+        let titles: Vec<_> = actions.iter().map(|a| a.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            [
+                "Fix this `no-react-specific-props` problem",
+                "Remove the prop"
+            ]
+        );
+        assert_eq!(actions[0].is_preferred, Some(true));
+        let changes = actions[0].edit.as_ref().unwrap().changes.as_ref().unwrap();
+        assert_eq!(
+            changes[&uri],
+            [TextEdit::new(
+                Range::new(Position::new(0, 5), Position::new(0, 14)),
+                "class".to_string()
+            )]
+        );
+
+        let lsp_diagnostic = &actions[1].diagnostics.as_ref().unwrap()[0];
+        assert_eq!(
+            lsp_diagnostic.code,
+            Some(NumberOrString::String(
+                "no-react-specific-props".to_string()
+            ))
+        );
+        assert_eq!(
+            lsp_diagnostic.severity,
+            Some(lsp_types::DiagnosticSeverity::WARNING)
+        );
+    }
+
+    #[test]
+    fn test_hover() {
+        let diagnostic = Diagnostic::error("prefer-for", Span::new(0, 1), "Use <For />")
+            .with_help("For keys items by reference");
+        assert_eq!(
+            hover(&[&diagnostic]),
+            format!(
+                "**solid/prefer-for**: Use <For />\n\nFor keys items by reference\n\n[Documentation]({})",
+                docs_url("prefer-for")
+            )
+        );
+        let parse_error = Diagnostic::error(PARSE_ERROR_RULE, Span::new(0, 0), "Unexpected token");
+        assert_eq!(
+            hover(&[&parse_error]),
+            "**solid/parse-error**: Unexpected token"
+        );
+    }
+}
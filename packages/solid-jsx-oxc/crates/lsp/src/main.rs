@@ -0,0 +1,17 @@
+//! solid-lint-lsp: solid-linter diagnostics, quick fixes and rule docs for
+//! editors, over the Language Server Protocol on stdin/stdout
+
+mod backend;
+mod convert;
+
+use tower_lsp::{LspService, Server};
+
+use crate::backend::Backend;
+
+#[tokio::main]
+async fn main() {
+    let (service, socket) = LspService::new(Backend::new);
+    Server::new(tokio::io::stdin(), tokio::io::stdout(), socket)
+        .serve(service)
+        .await;
+}
@@ -65,16 +65,15 @@ fn getter_return_expr<'a>(
     span: oxc_span::Span,
     expr: Expression<'a>,
 ) -> Expression<'a> {
-    let _ = span;
     let params =
-        ast.alloc_formal_parameters(SPAN, FormalParameterKind::FormalParameter, ast.vec(), NONE);
+        ast.alloc_formal_parameters(span, FormalParameterKind::FormalParameter, ast.vec(), NONE);
     let mut statements = ast.vec_with_capacity(1);
     statements.push(Statement::ReturnStatement(
-        ast.alloc_return_statement(SPAN, Some(expr)),
+        ast.alloc_return_statement(span, Some(expr)),
     ));
-    let body = ast.alloc_function_body(SPAN, ast.vec(), statements);
+    let body = ast.alloc_function_body(span, ast.vec(), statements);
     ast.expression_function(
-        SPAN,
+        span,
         FunctionType::FunctionExpression,
         None,
         false,
@@ -98,12 +97,11 @@ fn is_valid_prop_identifier(key: &str) -> bool {
 }
 
 fn make_prop_key<'a>(ast: AstBuilder<'a>, span: oxc_span::Span, raw_key: &str) -> PropertyKey<'a> {
-    let _ = span;
     let key = ast.allocator.alloc_str(raw_key);
     if is_valid_prop_identifier(raw_key) {
-        PropertyKey::StaticIdentifier(ast.alloc_identifier_name(SPAN, key))
+        PropertyKey::StaticIdentifier(ast.alloc_identifier_name(span, key))
     } else {
-        PropertyKey::StringLiteral(ast.alloc_string_literal(SPAN, key, None))
+        PropertyKey::StringLiteral(ast.alloc_string_literal(span, key, None))
     }
 }
 
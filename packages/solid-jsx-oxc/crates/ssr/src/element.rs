@@ -10,9 +10,11 @@ use oxc_ast::ast::{
 use oxc_span::SPAN;
 
 use common::{
-    constants::{ALIASES, CHILD_PROPERTIES, PROPERTIES, VOID_ELEMENTS},
+    attr::{plan_attribute, AttrKind, AttrPlan, PlannedAttr},
+    children_attr_child,
+    constants::{ALIASES, BOOLEANS, VOID_ELEMENTS},
     expression::escape_html,
-    get_attr_name, is_svg_element, TransformOptions,
+    is_svg_element, TransformOptions,
 };
 
 use crate::ir::{SSRContext, SSRResult};
@@ -58,7 +60,8 @@ pub fn transform_element<'a>(
             ast.vec(),
             false,
         );
-        result.push_dynamic(expr, false, true);
+        // Inside the tag, so never between hydration markers
+        result.push_dynamic_with_marker(expr, false, true, false);
     }
 
     // Transform attributes
@@ -96,8 +99,8 @@ fn transform_element_with_spread<'a>(
     let is_svg = is_svg_element(tag_name);
     let mut props = ast.vec();
 
-    for attr in &element.opening_element.attributes {
-        match attr {
+    for attr_item in &element.opening_element.attributes {
+        match attr_item {
             JSXAttributeItem::SpreadAttribute(spread) => {
                 props.push(ast.object_property_kind_spread_property(
                     span,
@@ -105,31 +108,19 @@ fn transform_element_with_spread<'a>(
                 ));
             }
             JSXAttributeItem::Attribute(attr) => {
-                let key = get_attr_name(&attr.name);
+                let planned = plan_attribute(attr_item, is_svg, options);
                 // Skip client-only attributes
-                if key == "ref"
-                    || key.starts_with("on")
-                    || key.starts_with("use:")
-                    || key.starts_with("prop:")
-                {
+                if planned.kind.is_client_only() {
                     continue;
                 }
 
-                let attr_name = if is_svg {
-                    key.clone()
-                } else {
-                    ALIASES
-                        .get(key.as_str())
-                        .copied()
-                        .unwrap_or(&key)
-                        .to_string()
-                };
+                let attr_name = ssr_attr_name(&planned, is_svg);
 
                 match &attr.value {
                     Some(JSXAttributeValue::StringLiteral(lit)) => {
                         let key = PropertyKey::StringLiteral(ast.alloc_string_literal(
                             span,
-                            ast.allocator.alloc_str(&attr_name),
+                            ast.allocator.alloc_str(attr_name),
                             None,
                         ));
                         let value = ast.expression_string_literal(
@@ -151,7 +142,7 @@ fn transform_element_with_spread<'a>(
                         if let Some(expr) = container.expression.as_expression() {
                             let key = PropertyKey::StringLiteral(ast.alloc_string_literal(
                                 span,
-                                ast.allocator.alloc_str(&attr_name),
+                                ast.allocator.alloc_str(attr_name),
                                 None,
                             ));
                             props.push(ast.object_property_kind_object_property(
@@ -168,7 +159,7 @@ fn transform_element_with_spread<'a>(
                     None => {
                         let key = PropertyKey::StringLiteral(ast.alloc_string_literal(
                             span,
-                            ast.allocator.alloc_str(&attr_name),
+                            ast.allocator.alloc_str(attr_name),
                             None,
                         ));
                         let value = ast.expression_boolean_literal(span, true);
@@ -320,7 +311,8 @@ fn transform_element_with_spread<'a>(
         args,
         false,
     );
-    result.push_dynamic(call, false, true);
+    // The element itself, not an insertion
+    result.push_dynamic_with_marker(call, false, true, false);
 
     result
 }
@@ -334,48 +326,70 @@ fn transform_attributes<'a>(
 ) {
     let tag_name = result.tag_name.as_deref().unwrap_or("");
     let is_svg = is_svg_element(tag_name);
-
-    for attr in &element.opening_element.attributes {
-        if let JSXAttributeItem::Attribute(attr) = attr {
-            transform_attribute(attr, result, context, options, is_svg);
+    let plan = AttrPlan::new(element, is_svg, options);
+
+    for planned in plan.iter() {
+        // Client-only attributes don't render, and child properties
+        // (innerHTML, textContent, children) are handled in the children transform
+        if planned.kind.is_client_only()
+            || matches!(
+                planned.kind,
+                AttrKind::ChildProperty { .. } | AttrKind::Children
+            )
+        {
+            continue;
         }
+        if let JSXAttributeItem::Attribute(attr) = planned.item {
+            transform_attribute(attr, planned, result, context, is_svg);
+        }
+    }
+}
+
+/// Attribute name as rendered into the SSR template
+fn ssr_attr_name<'p>(planned: &'p PlannedAttr<'_, '_>, is_svg: bool) -> &'p str {
+    match &planned.kind {
+        AttrKind::Static { name }
+        | AttrKind::SetAttribute { name, forced: true }
+        | AttrKind::BoolAttribute { name } => name,
+        _ if is_svg => &planned.key,
+        _ => ALIASES
+            .get(planned.key.as_ref())
+            .copied()
+            .unwrap_or(&planned.key),
     }
 }
 
 /// Transform a single attribute for SSR
 fn transform_attribute<'a>(
     attr: &JSXAttribute<'a>,
+    planned: &PlannedAttr<'_, 'a>,
     result: &mut SSRResult<'a>,
     context: &SSRContext<'a>,
-    _options: &TransformOptions<'a>,
     is_svg: bool,
 ) {
     let ast = context.ast();
-    let key = get_attr_name(&attr.name);
-
-    // Skip client-only attributes
-    if key == "ref" || key.starts_with("on") || key.starts_with("use:") || key.starts_with("prop:")
-    {
-        return;
-    }
+    let key = planned.key.as_ref();
 
-    // Handle child properties (innerHTML, textContent)
-    if CHILD_PROPERTIES.contains(key.as_str()) {
-        // These are handled in children transform
+    // Get the attribute name (handle aliases like className -> class)
+    let attr_name = ssr_attr_name(planned, is_svg);
+
+    // bool: prefix - only the attribute's presence is rendered
+    if matches!(planned.kind, AttrKind::BoolAttribute { .. }) {
+        match planned.static_presence() {
+            Some(present) => {
+                if present {
+                    result.push_static(&format!(" {}", attr_name));
+                }
+            }
+            None => {
+                if let Some(expr) = planned.expression() {
+                    push_ssr_attribute(attr_name, context.clone_expr(expr), result, context);
+                }
+            }
+        }
         return;
     }
 
-    // Get the attribute name (handle aliases like className -> class)
-    let attr_name = if is_svg {
-        key.clone()
-    } else {
-        ALIASES
-            .get(key.as_str())
-            .copied()
-            .unwrap_or(&key)
-            .to_string()
-    };
-
     match &attr.value {
         // Static string value
         Some(JSXAttributeValue::StringLiteral(lit)) => {
@@ -389,7 +403,7 @@ fn transform_attribute<'a>(
                 let expr = context.clone_expr(expr);
 
                 // Handle special attributes
-                if key == "style" {
+                if planned.kind == AttrKind::Style {
                     context.register_helper("ssrStyle");
                     result.push_static(&format!(" {}=\"", attr_name));
                     let callee = ast.expression_identifier(SPAN, "ssrStyle");
@@ -430,29 +444,9 @@ fn transform_attribute<'a>(
                         true,
                     );
                     result.push_static("\"");
-                } else if PROPERTIES.contains(key.as_str()) {
+                } else if BOOLEANS.contains(key) {
                     // Boolean attributes
-                    context.register_helper("ssrAttribute");
-                    let callee = ast.expression_identifier(SPAN, "ssrAttribute");
-                    let mut args = ast.vec();
-                    args.push(Argument::from(ast.expression_string_literal(
-                        SPAN,
-                        ast.allocator.alloc_str(&attr_name),
-                        None,
-                    )));
-                    args.push(Argument::from(expr));
-                    args.push(Argument::from(ast.expression_boolean_literal(SPAN, true)));
-                    result.push_dynamic(
-                        ast.expression_call(
-                            SPAN,
-                            callee,
-                            None::<oxc_ast::ast::TSTypeParameterInstantiation<'a>>,
-                            args,
-                            false,
-                        ),
-                        false,
-                        true,
-                    );
+                    push_ssr_attribute(attr_name, expr, result, context);
                 } else {
                     // Regular attribute
                     context.register_helper("escape");
@@ -472,6 +466,38 @@ fn transform_attribute<'a>(
     }
 }
 
+/// `ssrAttribute(name, value, true)`, which renders ` name` while `value` is
+/// truthy
+fn push_ssr_attribute<'a>(
+    attr_name: &str,
+    expr: Expression<'a>,
+    result: &mut SSRResult<'a>,
+    context: &SSRContext<'a>,
+) {
+    let ast = context.ast();
+    context.register_helper("ssrAttribute");
+    let callee = ast.expression_identifier(SPAN, "ssrAttribute");
+    let mut args = ast.vec();
+    args.push(Argument::from(ast.expression_string_literal(
+        SPAN,
+        ast.allocator.alloc_str(attr_name),
+        None,
+    )));
+    args.push(Argument::from(expr));
+    args.push(Argument::from(ast.expression_boolean_literal(SPAN, true)));
+    result.push_dynamic(
+        ast.expression_call(
+            SPAN,
+            callee,
+            None::<oxc_ast::ast::TSTypeParameterInstantiation<'a>>,
+            args,
+            false,
+        ),
+        false,
+        true,
+    );
+}
+
 /// Transform element children for SSR
 fn transform_children<'a>(
     element: &JSXElement<'a>,
@@ -490,8 +516,14 @@ fn transform_children<'a>(
             if key == "innerHTML" {
                 if let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value {
                     if let Some(expr) = container.expression.as_expression() {
-                        // innerHTML - don't escape
-                        result.push_dynamic(context.clone_expr(expr), false, true);
+                        // innerHTML - don't escape, and the content is all
+                        // of the element's, so it needs no markers
+                        result.push_dynamic_with_marker(
+                            context.clone_expr(expr),
+                            false,
+                            true,
+                            false,
+                        );
                         return;
                     }
                 }
@@ -499,7 +531,12 @@ fn transform_children<'a>(
                 if let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value {
                     if let Some(expr) = container.expression.as_expression() {
                         context.register_helper("escape");
-                        result.push_dynamic(context.clone_expr(expr), false, false);
+                        result.push_dynamic_with_marker(
+                            context.clone_expr(expr),
+                            false,
+                            false,
+                            false,
+                        );
                         return;
                     }
                 }
@@ -509,13 +546,18 @@ fn transform_children<'a>(
 
     // Process children
     let skip_escape = result.skip_escape;
-    process_jsx_children(&element.children, result, skip_escape, context, options);
+    let children_attr = children_attr_child(element, context.ast());
+    let children = match &children_attr {
+        Some(child) => std::slice::from_ref(child),
+        None => &element.children[..],
+    };
+    process_jsx_children(children, result, skip_escape, context, options);
 }
 
 /// Process a list of JSX children, appending to the result.
 /// This is extracted as a helper to enable recursive processing of fragment children.
 fn process_jsx_children<'a>(
-    children: &oxc_allocator::Vec<'a, oxc_ast::ast::JSXChild<'a>>,
+    children: &[oxc_ast::ast::JSXChild<'a>],
     result: &mut SSRResult<'a>,
     skip_escape: bool,
     context: &SSRContext<'a>,
@@ -595,8 +637,9 @@ fn process_jsx_children<'a>(
                     let expr = context.clone_expr(expr);
 
                     if skip_escape {
-                        // Inside script/style - don't escape
-                        result.push_dynamic(expr, false, true);
+                        // Inside script/style - don't escape, and markers
+                        // would be part of the script
+                        result.push_dynamic_with_marker(expr, false, true, false);
                     } else {
                         // Normal content - escape
                         context.register_helper("escape");
@@ -11,7 +11,7 @@ use oxc_semantic::SemanticBuilder;
 use oxc_span::SPAN;
 use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
 
-use common::{get_tag_name, is_component, TransformOptions};
+use common::{get_tag_name, is_component, TransformMetadata, TransformOptions};
 
 use crate::component::transform_component;
 use crate::element::transform_element;
@@ -33,8 +33,9 @@ impl<'a> SSRTransform<'a> {
         }
     }
 
-    /// Run the transform on a program
-    pub fn transform(mut self, program: &mut Program<'a>) {
+    /// Run the transform on a program. SSR output has no hoisted templates
+    /// or delegated events, so the metadata only lists the helpers.
+    pub fn transform(mut self, program: &mut Program<'a>) -> TransformMetadata {
         // SAFETY: We convert the allocator reference to a raw pointer and back to a reference
         // to satisfy oxc_traverse's API which requires `&Allocator` while we hold `&mut self`.
         // This is safe because:
@@ -53,6 +54,10 @@ impl<'a> SSRTransform<'a> {
                 .into_scoping(),
             (),
         );
+        TransformMetadata {
+            helpers: self.context.helpers.take().into_iter().collect(),
+            ..TransformMetadata::default()
+        }
     }
 
     /// Transform a JSX node and return the SSR result
@@ -0,0 +1,3 @@
+//! Deciding what the `solid-jsx` binary compiles again in watch mode
+
+pub mod watch;
@@ -0,0 +1,219 @@
+//! solid-jsx: compile a directory of Solid JSX, as libraries precompile
+//! `src/` to `dist/`
+//!
+//! ```text
+//! solid-jsx src --out-dir dist
+//! solid-jsx src --out-dir dist --generate ssr --hydratable
+//! solid-jsx src --out-dir dist --cache-dir node_modules/.cache/solid-jsx
+//! solid-jsx src --out-dir dist --watch
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Instant;
+
+use clap::{Parser, ValueEnum};
+use solid_jsx::watch;
+use solid_jsx_oxc::project::{project_sources, transform_files, ProjectOptions, ProjectReport};
+use solid_jsx_oxc::{GenerateMode, TransformCache};
+use solid_lint::watch::{Change, Watch};
+
+/// How many outputs are kept in memory between runs
+const CACHE_CAPACITY: usize = 4096;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Generate {
+    Dom,
+    Ssr,
+    Universal,
+}
+
+#[derive(Debug, Parser)]
+#[command(name = "solid-jsx", version, about = "Compile Solid JSX and TSX files")]
+struct Args {
+    /// Directory of the sources
+    root: PathBuf,
+
+    /// Where the outputs go, at the same relative paths
+    #[arg(short, long)]
+    out_dir: PathBuf,
+
+    /// Output for the browser, the server or a custom renderer
+    #[arg(long, value_enum, default_value_t = Generate::Dom)]
+    generate: Generate,
+
+    /// Add hydration support
+    #[arg(long)]
+    hydratable: bool,
+
+    /// The module to import runtime helpers from
+    #[arg(long, default_value = "solid-js/web")]
+    module_name: String,
+
+    /// Write a source map next to each output
+    #[arg(long)]
+    source_map: bool,
+
+    /// File extensions to compile
+    #[arg(long, value_delimiter = ',', default_value = "jsx,tsx")]
+    ext: Vec<String>,
+
+    /// Keep the outputs in this directory, so the next run only compiles
+    /// changed files
+    #[arg(long)]
+    cache_dir: Option<PathBuf>,
+
+    /// Largest size of `--cache-dir` in megabytes; the least recently used
+    /// outputs are removed after each run
+    #[arg(long, value_name = "MB", default_value_t = 256)]
+    cache_size: u64,
+
+    /// Keep running and compile changed files again, and the files a changed
+    /// tsconfig.json applies to
+    #[arg(short, long)]
+    watch: bool,
+
+    /// Number of files to compile in parallel; defaults to the number of CPUs
+    #[arg(short = 'j', long)]
+    threads: Option<usize>,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    match run(&args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(message) => {
+            eprintln!("solid-jsx: {}", message);
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Compile once, or until interrupted with `--watch`; `Ok(false)` when a file
+/// failed
+fn run(args: &Args) -> Result<bool, String> {
+    if let Some(threads) = args.threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .map_err(|err| err.to_string())?;
+    }
+
+    let cache = match &args.cache_dir {
+        Some(dir) => TransformCache::persistent(dir, CACHE_CAPACITY)
+            .map_err(|err| format!("{}: {}", dir.display(), err))?,
+        None => TransformCache::in_memory(CACHE_CAPACITY),
+    };
+    let mut options = ProjectOptions::new(&args.out_dir);
+    options.transform.module_name = &args.module_name;
+    options.transform.generate = match args.generate {
+        Generate::Dom => GenerateMode::Dom,
+        Generate::Ssr => GenerateMode::Ssr,
+        Generate::Universal => GenerateMode::Universal,
+    };
+    options.transform.hydratable = args.hydratable;
+    options.transform.source_map = args.source_map;
+    options.extensions = args.ext.clone();
+    options.cache = Some(&cache);
+
+    let root = args
+        .root
+        .canonicalize()
+        .map_err(|err| format!("{}: {}", args.root.display(), err))?;
+    let sources = project_sources(&root, &options).map_err(|err| err.to_string())?;
+    let max_cache_bytes = args.cache_size * 1024 * 1024;
+    let succeeded = compile(&root, &sources, &options, &cache, args.watch)?;
+    prune(&cache, max_cache_bytes);
+    if !args.watch {
+        return Ok(succeeded);
+    }
+    watch(&root, &options, &cache, max_cache_bytes, sources)
+}
+
+/// Compile again whenever the sources change, until interrupted
+fn watch(
+    root: &Path,
+    options: &ProjectOptions,
+    cache: &TransformCache,
+    max_cache_bytes: u64,
+    mut sources: Vec<PathBuf>,
+) -> Result<bool, String> {
+    let mut paths = vec![root.to_path_buf()];
+    paths.extend(watch::nearest_tsconfig(root));
+    let watcher = Watch::new(&paths).map_err(|err| err.to_string())?;
+    loop {
+        let changed = watcher.next_changes()?;
+        let current = match project_sources(root, options) {
+            Ok(current) => current,
+            Err(err) => {
+                eprintln!("solid-jsx: {}", err);
+                continue;
+            }
+        };
+        let recompile = match watch::classify(&changed, &current, &sources) {
+            None => continue,
+            Some(Change::All) => current.clone(),
+            Some(Change::Files(files)) => files,
+        };
+        sources = current;
+        compile(root, &recompile, options, cache, true)?;
+        prune(cache, max_cache_bytes);
+    }
+}
+
+/// Keep the cache directory to its size; a cache that cannot be pruned only
+/// costs disk space
+fn prune(cache: &TransformCache, max_bytes: u64) {
+    if let Err(err) = cache.prune(max_bytes) {
+        eprintln!("solid-jsx: pruning the cache: {}", err);
+    }
+}
+
+/// Compile `sources` and print what happened; `Ok(false)` when a file failed
+fn compile(
+    root: &Path,
+    sources: &[PathBuf],
+    options: &ProjectOptions,
+    cache: &TransformCache,
+    watching: bool,
+) -> Result<bool, String> {
+    let start = Instant::now();
+    let before = cache.stats();
+    let report = transform_files(root, sources, options).map_err(|err| err.to_string())?;
+    let after = cache.stats();
+    print_report(&report);
+
+    let compiled = report.files.len();
+    eprintln!(
+        "solid-jsx: compiled {} file{} ({} from cache) in {:.1?}{}",
+        compiled,
+        if compiled == 1 { "" } else { "s" },
+        after.hits - before.hits,
+        start.elapsed(),
+        if watching {
+            ", watching for changes"
+        } else {
+            ""
+        }
+    );
+    Ok(report.errors.is_empty() && report.diagnostics.is_empty())
+}
+
+fn print_report(report: &ProjectReport) {
+    for (path, error) in &report.errors {
+        eprintln!("solid-jsx: {}: {}", path.display(), error);
+    }
+    for (path, error) in &report.diagnostics {
+        eprintln!("{}: {}", path.display(), error.message);
+    }
+    for path in &report.removed {
+        eprintln!("solid-jsx: removed the output of {}", path.display());
+    }
+    for path in &report.skipped {
+        eprintln!(
+            "solid-jsx: skipped {}, its JSX import source is not Solid",
+            path.display()
+        );
+    }
+}
@@ -0,0 +1,106 @@
+//! What a batch of changed paths means for `--watch`
+//!
+//! File system events come from solid-lint's debounced [`Watch`], as for
+//! `solid-lint --watch`, and are answered with the same [`Change`]. A changed
+//! source is compiled again. So are the sources whose JSX import source a
+//! changed tsconfig.json decides: every source in its directory. A changed
+//! ignore file, or a tsconfig file other than tsconfig.json (one that others
+//! may extend), can change any file, so everything is compiled again.
+//!
+//! [`Watch`]: solid_lint::watch::Watch
+
+use std::path::{Path, PathBuf};
+
+use solid_lint::watch::{file_name, Change, IGNORE_FILE_NAMES};
+
+/// The nearest tsconfig.json in `root` or above it, which decides the JSX
+/// import source of the sources without a closer one
+pub fn nearest_tsconfig(root: &Path) -> Option<PathBuf> {
+    root.ancestors()
+        .map(|dir| dir.join("tsconfig.json"))
+        .find(|tsconfig| tsconfig.is_file())
+}
+
+/// The run that `changed` calls for, given the sources there are now and the
+/// sources of the last run (whose outputs go when they are deleted); `None`
+/// when it affects none of them
+pub fn classify(changed: &[PathBuf], sources: &[PathBuf], previous: &[PathBuf]) -> Option<Change> {
+    let mut recompile = Vec::new();
+    for path in changed {
+        let name = file_name(path);
+        if IGNORE_FILE_NAMES.contains(&name)
+            || (name.starts_with("tsconfig") && name.ends_with(".json") && name != "tsconfig.json")
+        {
+            return Some(Change::All);
+        }
+        if name == "tsconfig.json" {
+            let dir = path.parent().unwrap_or(Path::new(""));
+            recompile.extend(
+                sources
+                    .iter()
+                    .filter(|source| source.starts_with(dir))
+                    .cloned(),
+            );
+        } else if sources.contains(path) || previous.contains(path) {
+            recompile.push(path.clone());
+        }
+    }
+    recompile.sort();
+    recompile.dedup();
+    (!recompile.is_empty()).then_some(Change::Files(recompile))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify() {
+        let root = Path::new("/p/src");
+        let sources = vec![root.join("App.tsx"), root.join("ui/Button.tsx")];
+
+        assert_eq!(
+            classify(
+                &[root.join("ui/Button.tsx"), root.join("notes.txt")],
+                &sources,
+                &sources
+            ),
+            Some(Change::Files(vec![root.join("ui/Button.tsx")]))
+        );
+        assert_eq!(
+            classify(&[root.join("notes.txt")], &sources, &sources),
+            None
+        );
+
+        // A deleted source is compiled again, which removes its output
+        assert_eq!(
+            classify(&[root.join("Old.tsx")], &sources, &[root.join("Old.tsx")]),
+            Some(Change::Files(vec![root.join("Old.tsx")]))
+        );
+
+        assert_eq!(
+            classify(&[root.join("ui/tsconfig.json")], &sources, &sources),
+            Some(Change::Files(vec![root.join("ui/Button.tsx")]))
+        );
+        assert_eq!(
+            classify(
+                &[Path::new("/p/tsconfig.json").to_path_buf()],
+                &sources,
+                &sources
+            ),
+            Some(Change::Files(sources.clone()))
+        );
+        assert_eq!(
+            classify(
+                &[Path::new("/p/tsconfig.base.json").to_path_buf()],
+                &sources,
+                &sources
+            ),
+            Some(Change::All)
+        );
+        assert_eq!(
+            classify(&[root.join(".gitignore")], &sources, &sources),
+            Some(Change::All)
+        );
+    }
+}
@@ -0,0 +1,32 @@
+// Compiles files with babel-preset-solid for `cargo xtask compare`.
+//
+// Reads `{ options, files: [{ path, source }] }` as JSON from stdin and writes
+// one `{ code }` or `{ error }` per file as a JSON array to stdout. The Babel
+// packages are resolved as if imported from a file in the directory
+// SOLID_COMPARE_RESOLVE_FROM, or else in the working directory.
+
+import { createRequire } from 'node:module';
+import { readFileSync } from 'node:fs';
+import { join, resolve } from 'node:path';
+
+const base = resolve(process.env.SOLID_COMPARE_RESOLVE_FROM ?? '.');
+const require = createRequire(join(base, 'compare.js'));
+const babel = require('@babel/core');
+const solid = require('babel-preset-solid');
+
+const { options, files } = JSON.parse(readFileSync(0, 'utf8'));
+const outputs = files.map(({ path, source }) => {
+  try {
+    const { code } = babel.transformSync(source, {
+      filename: path,
+      babelrc: false,
+      configFile: false,
+      presets: [[solid, options]],
+      parserOpts: { plugins: ['jsx', 'typescript'] },
+    });
+    return { code };
+  } catch (error) {
+    return { error: String(error?.message ?? error) };
+  }
+});
+process.stdout.write(JSON.stringify(outputs));
@@ -0,0 +1,310 @@
+//! `cargo xtask compare`: differential testing against babel-preset-solid
+//!
+//! Every input is compiled by this crate and, in one `node` process, by
+//! babel-preset-solid with the same options. Both outputs are then
+//! normalized before they are diffed:
+//!
+//! - both are printed again by oxc's code generator, so formatting and quotes
+//!   do not count;
+//! - Babel's helper imports lose their `_$` prefix (`_$insert` is `insert`);
+//! - generated names like `_el$3` or `_tmpl$2` are numbered again in order of
+//!   appearance, per prefix, so uids that are only counted differently match.
+//!
+//! What remains is a difference in the code itself, printed as a unified
+//! diff from Babel's output to ours.
+
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use clap::{Args, ValueEnum};
+use ignore::WalkBuilder;
+use oxc_allocator::Allocator;
+use oxc_codegen::Codegen;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use serde_json::{json, Value};
+use similar::TextDiff;
+use solid_jsx_oxc::{GenerateMode, TransformOptions, Transformer};
+
+use crate::support::renumber_names;
+
+/// Compiles the files given on stdin with Babel
+const BABEL_SCRIPT: &str = include_str!("babel.mjs");
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Generate {
+    Dom,
+    Ssr,
+}
+
+#[derive(Debug, Args)]
+pub struct CompareArgs {
+    /// Files and directories to compile; files given by name are compiled
+    /// whatever their extension
+    #[arg(required = true)]
+    paths: Vec<PathBuf>,
+
+    /// File extensions to compile in directories
+    #[arg(long, value_delimiter = ',', default_value = "jsx,tsx")]
+    ext: Vec<String>,
+
+    /// Output to generate
+    #[arg(long, value_enum, default_value_t = Generate::Dom)]
+    generate: Generate,
+
+    /// Generate hydratable output
+    #[arg(long)]
+    hydratable: bool,
+
+    /// The module to import runtime helpers from
+    #[arg(long, default_value = "solid-js/web")]
+    module_name: String,
+
+    /// Directory to resolve @babel/core and babel-preset-solid from, as
+    /// node would for a file in it; defaults to the working directory
+    #[arg(long, value_name = "DIR")]
+    resolve_from: Option<PathBuf>,
+
+    /// Only list which files differ, without the diffs
+    #[arg(long)]
+    summary: bool,
+}
+
+/// A compiled input and what became of it
+struct Comparison {
+    path: PathBuf,
+    outcome: Outcome,
+}
+
+enum Outcome {
+    Same,
+    /// A unified diff from Babel's normalized output to ours
+    Differs(String),
+    /// Babel could not compile the file
+    BabelError(String),
+}
+
+pub fn run(args: &CompareArgs) -> Result<(), String> {
+    let files = collect_files(args);
+    if files.is_empty() {
+        return Err("no files to compare".to_string());
+    }
+    let sources = files
+        .iter()
+        .map(|path| {
+            std::fs::read_to_string(path)
+                .map_err(|error| format!("cannot read {}: {}", path.display(), error))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let babel = compile_with_babel(args, &files, &sources)?;
+    let mut transformer = Transformer::new(transform_options(args));
+    let comparisons: Vec<Comparison> = files
+        .iter()
+        .zip(&sources)
+        .zip(babel)
+        .map(|((path, source), babel)| {
+            let outcome = match babel {
+                Ok(babel) => {
+                    let ours = transformer.transform_source(path, source).code;
+                    let source_type = source_type_of(path);
+                    let babel = normalize(&babel, source_type);
+                    let ours = normalize(&ours, source_type);
+                    if babel == ours {
+                        Outcome::Same
+                    } else {
+                        Outcome::Differs(
+                            TextDiff::from_lines(&babel, &ours)
+                                .unified_diff()
+                                .context_radius(2)
+                                .header("babel-preset-solid", "solid-jsx-oxc")
+                                .to_string(),
+                        )
+                    }
+                }
+                Err(error) => Outcome::BabelError(error),
+            };
+            Comparison {
+                path: path.clone(),
+                outcome,
+            }
+        })
+        .collect();
+
+    print_report(&comparisons, args.summary);
+    Ok(())
+}
+
+fn collect_files(args: &CompareArgs) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for path in &args.paths {
+        if path.is_file() {
+            files.push(path.clone());
+            continue;
+        }
+        let mut found: Vec<PathBuf> = WalkBuilder::new(path)
+            .build()
+            .flatten()
+            .filter(|entry| entry.file_type().is_some_and(|t| t.is_file()))
+            .map(|entry| entry.into_path())
+            .filter(|path| {
+                path.extension()
+                    .and_then(|extension| extension.to_str())
+                    .is_some_and(|extension| args.ext.iter().any(|e| e == extension))
+            })
+            .collect();
+        found.sort();
+        files.extend(found);
+    }
+    files
+}
+
+/// The options of this crate matching the preset options of [`babel_options`]
+fn transform_options(args: &CompareArgs) -> TransformOptions<'_> {
+    TransformOptions {
+        module_name: &args.module_name,
+        generate: match args.generate {
+            Generate::Dom => GenerateMode::Dom,
+            Generate::Ssr => GenerateMode::Ssr,
+        },
+        hydratable: args.hydratable,
+        ..TransformOptions::solid_defaults()
+    }
+}
+
+fn babel_options(args: &CompareArgs) -> Value {
+    json!({
+        "moduleName": args.module_name,
+        "generate": match args.generate {
+            Generate::Dom => "dom",
+            Generate::Ssr => "ssr",
+        },
+        "hydratable": args.hydratable,
+    })
+}
+
+/// Babel's output for each file, or the error it failed with
+fn compile_with_babel(
+    args: &CompareArgs,
+    files: &[PathBuf],
+    sources: &[String],
+) -> Result<Vec<Result<String, String>>, String> {
+    let input = json!({
+        "options": babel_options(args),
+        "files": files
+            .iter()
+            .zip(sources)
+            .map(|(path, source)| json!({ "path": path, "source": source }))
+            .collect::<Vec<_>>(),
+    });
+
+    let mut command = Command::new("node");
+    command
+        .args(["--input-type=module", "-e", BABEL_SCRIPT])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if let Some(dir) = &args.resolve_from {
+        command.env("SOLID_COMPARE_RESOLVE_FROM", dir);
+    }
+    let mut child = command
+        .spawn()
+        .map_err(|error| format!("cannot run node: {}", error))?;
+    // Written from another thread, so that node never waits on a full stdout
+    // while we wait on a full stdin
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let writer = std::thread::spawn(move || stdin.write_all(input.to_string().as_bytes()));
+    let output = child
+        .wait_with_output()
+        .map_err(|error| format!("cannot run node: {}", error))?;
+    let _ = writer.join();
+    if !output.status.success() {
+        return Err(format!(
+            "babel-preset-solid failed to run:\n{}",
+            String::from_utf8_lossy(&output.stderr).trim_end()
+        ));
+    }
+
+    let outputs: Vec<Value> = serde_json::from_slice(&output.stdout)
+        .map_err(|error| format!("unexpected output from node: {}", error))?;
+    Ok(outputs
+        .into_iter()
+        .map(|output| match output["code"].as_str() {
+            Some(code) => Ok(code.to_string()),
+            None => Err(output["error"].as_str().unwrap_or("no output").to_string()),
+        })
+        .collect())
+}
+
+fn source_type_of(path: &Path) -> SourceType {
+    SourceType::from_path(path).unwrap_or_else(|_| SourceType::tsx())
+}
+
+/// `code` printed by oxc's code generator, with Babel's helper prefixes
+/// removed and generated names numbered in order of appearance
+fn normalize(code: &str, source_type: SourceType) -> String {
+    let allocator = Allocator::default();
+    let parsed = Parser::new(&allocator, code, source_type).parse();
+    if parsed.panicked {
+        return code.to_string();
+    }
+    let printed = Codegen::new().build(&parsed.program).code;
+    renumber_names(&printed)
+}
+
+fn print_report(comparisons: &[Comparison], summary: bool) {
+    let mut same = 0;
+    for comparison in comparisons {
+        let path = comparison.path.display();
+        match &comparison.outcome {
+            Outcome::Same => {
+                same += 1;
+                println!("same     {}", path);
+            }
+            Outcome::Differs(diff) => {
+                println!("differs  {}", path);
+                if !summary {
+                    println!("{}", diff);
+                }
+            }
+            Outcome::BabelError(error) => {
+                println!("error    {}: babel-preset-solid: {}", path, error);
+            }
+        }
+    }
+    println!(
+        "\n{} of {} files compile to the same code",
+        same,
+        comparisons.len()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_renumber_names() {
+        let babel = r#"import { insert as _$insert } from "r-dom";
+const _tmpl$ = _$template(`<div>`);
+const _el$4 = _tmpl$(), _el$5 = _el$4.firstChild;
+_$insert(_el$5, a);"#;
+        let ours = r#"import { insert } from "r-dom";
+const _tmpl$1 = template(`<div>`);
+const _el$1 = _tmpl$1(), _el$2 = _el$1.firstChild;
+insert(_el$2, a);"#;
+        assert_eq!(renumber_names(babel), renumber_names(ours));
+        // Other names with a `$` are left alone
+        assert_eq!(renumber_names("$el + a$1 + _x$y"), "$el + a$1 + _x$y");
+    }
+
+    #[test]
+    fn test_normalize_formatting() {
+        let source_type = SourceType::jsx();
+        assert_eq!(
+            normalize("const a = 'x';\n\n\nf( a )", source_type),
+            normalize("const a = \"x\"; f(a);", source_type)
+        );
+    }
+}
@@ -0,0 +1,441 @@
+//! `cargo xtask conformance`: how much of dom-expressions' fixture suite passes
+//!
+//! Every fixture of `babel-plugin-jsx-dom-expressions` for DOM, SSR and their
+//! hydratable variants is compiled with the options of its Babel spec. The
+//! top-level statements of the fixture's `output.js` are then looked for in
+//! our output, both printed by oxc's code generator and with generated names
+//! renumbered per statement. A fixture passes when all of them are found,
+//! partially passes when some are, and fails when none are or the transform
+//! panics.
+//!
+//! Fixtures are grouped into the feature they exercise (attributes, events,
+//! components, ...), with every SSR fixture under SSR, and the report is
+//! printed as markdown or JSON.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+use clap::{Args, ValueEnum};
+use serde_json::{json, Value};
+use solid_jsx_oxc::{transform, GenerateMode, TransformOptions};
+
+use crate::support::{spec_options, statements};
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Format {
+    Markdown,
+    Json,
+}
+
+#[derive(Debug, Args)]
+pub struct ConformanceArgs {
+    /// Report format
+    #[arg(long, value_enum, default_value_t = Format::Markdown)]
+    format: Format,
+
+    /// Write the report to this file instead of stdout
+    #[arg(long, value_name = "FILE")]
+    output: Option<PathBuf>,
+
+    /// Fail if the file given by `--output` is not the current report,
+    /// instead of writing it
+    #[arg(long, requires = "output")]
+    check: bool,
+
+    /// The `test` directory of babel-plugin-jsx-dom-expressions
+    #[arg(long, value_name = "DIR")]
+    fixtures: Option<PathBuf>,
+}
+
+/// A fixture directory of dom-expressions and the Babel spec compiling it
+struct Variant {
+    dir: &'static str,
+    label: &'static str,
+    generate: GenerateMode,
+    hydratable: bool,
+}
+
+const VARIANTS: [Variant; 4] = [
+    Variant {
+        dir: "__dom_fixtures__",
+        label: "DOM",
+        generate: GenerateMode::Dom,
+        hydratable: false,
+    },
+    Variant {
+        dir: "__dom_hydratable_fixtures__",
+        label: "DOM, hydratable",
+        generate: GenerateMode::Dom,
+        hydratable: true,
+    },
+    Variant {
+        dir: "__ssr_fixtures__",
+        label: "SSR",
+        generate: GenerateMode::Ssr,
+        hydratable: false,
+    },
+    Variant {
+        dir: "__ssr_hydratable_fixtures__",
+        label: "SSR, hydratable",
+        generate: GenerateMode::Ssr,
+        hydratable: true,
+    },
+];
+
+/// Categories in report order
+const CATEGORIES: [&str; 9] = [
+    "Elements",
+    "Attributes",
+    "Events",
+    "Components",
+    "Children",
+    "Control flow",
+    "Hydration",
+    "SSR",
+    "Other",
+];
+
+impl Variant {
+    /// The options of the variant's spec (`dom.spec.js`,
+    /// `ssr-hydratable.spec.js`, ...)
+    fn options(&self) -> TransformOptions<'static> {
+        spec_options(self.generate, self.hydratable)
+    }
+
+    /// The feature a fixture of this variant exercises
+    fn category(&self, fixture: &str) -> &'static str {
+        if self.generate == GenerateMode::Ssr {
+            return "SSR";
+        }
+        match fixture {
+            "simpleElements" | "fragments" | "namespaceElements" | "customElements" | "SVG"
+            | "document" => "Elements",
+            "attributeExpressions" => "Attributes",
+            "eventExpressions" => "Events",
+            "components" => "Components",
+            "insertChildren" | "textInterpolation" => "Children",
+            "conditionalExpressions" => "Control flow",
+            "flags" => "Hydration",
+            _ => "Other",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Pass,
+    Partial,
+    Fail,
+}
+
+impl Status {
+    fn name(self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Partial => "partial",
+            Status::Fail => "fail",
+        }
+    }
+
+    fn badge(self) -> &'static str {
+        match self {
+            Status::Pass => "✅ pass",
+            Status::Partial => "🟡 partial",
+            Status::Fail => "❌ fail",
+        }
+    }
+}
+
+/// What became of one fixture in one variant
+struct FixtureResult {
+    name: String,
+    variant: &'static Variant,
+    category: &'static str,
+    /// Statements of Babel's output found in ours; `None` if the transform
+    /// panicked
+    found: Option<usize>,
+    total: usize,
+}
+
+impl FixtureResult {
+    fn status(&self) -> Status {
+        match self.found {
+            Some(found) if found == self.total => Status::Pass,
+            Some(found) if found > 0 => Status::Partial,
+            _ => Status::Fail,
+        }
+    }
+
+    fn statements(&self) -> String {
+        match self.found {
+            Some(found) => format!("{}/{}", found, self.total),
+            None => format!("panics, of {}", self.total),
+        }
+    }
+}
+
+pub fn run(args: &ConformanceArgs) -> Result<(), String> {
+    let root = args.fixtures.clone().unwrap_or_else(|| {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../../../babel-plugin-jsx-dom-expressions/test")
+    });
+    let results = run_fixtures(&root)?;
+    let report = match args.format {
+        Format::Markdown => markdown(&results),
+        Format::Json => format!("{:#}\n", json_report(&results)),
+    };
+
+    match &args.output {
+        None => print!("{}", report),
+        Some(path) if args.check => {
+            let current = std::fs::read_to_string(path).unwrap_or_default();
+            if current != report {
+                return Err(format!(
+                    "{} is out of date; write it again without --check",
+                    path.display()
+                ));
+            }
+        }
+        Some(path) => std::fs::write(path, report)
+            .map_err(|error| format!("cannot write {}: {}", path.display(), error))?,
+    }
+    Ok(())
+}
+
+fn run_fixtures(root: &Path) -> Result<Vec<FixtureResult>, String> {
+    let mut results = Vec::new();
+    for variant in &VARIANTS {
+        let dir = root.join(variant.dir);
+        let entries = std::fs::read_dir(&dir)
+            .map_err(|error| format!("cannot read fixtures in {}: {}", dir.display(), error))?;
+        let mut fixtures: Vec<PathBuf> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.join("code.js").is_file())
+            .collect();
+        fixtures.sort();
+
+        for fixture in fixtures {
+            let name = fixture.file_name().unwrap().to_string_lossy().into_owned();
+            let source = std::fs::read_to_string(fixture.join("code.js")).unwrap_or_default();
+            let expected = std::fs::read_to_string(fixture.join("output.js")).unwrap_or_default();
+            let expected = statements(&expected);
+            let found = compile(&source, variant.options()).map(|code| {
+                let mut actual = statements(&code);
+                expected
+                    .iter()
+                    .filter(|statement| {
+                        let index = actual.iter().position(|a| a == *statement);
+                        index.map(|index| actual.swap_remove(index)).is_some()
+                    })
+                    .count()
+            });
+            results.push(FixtureResult {
+                category: variant.category(&name),
+                name,
+                variant,
+                found,
+                total: expected.len(),
+            });
+        }
+    }
+    Ok(results)
+}
+
+/// Our output for `source`, or `None` if the transform panics
+fn compile(source: &str, options: TransformOptions) -> Option<String> {
+    // A panic counts as a failure; its message would only be noise
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let code = panic::catch_unwind(AssertUnwindSafe(|| transform(source, Some(options)).code));
+    panic::set_hook(hook);
+    code.ok()
+}
+
+/// Fixtures per status, and statements found of all
+#[derive(Default)]
+struct Tally {
+    pass: usize,
+    partial: usize,
+    fail: usize,
+    found: usize,
+    total: usize,
+}
+
+impl Tally {
+    fn of<'a>(results: impl IntoIterator<Item = &'a FixtureResult>) -> Self {
+        let mut tally = Tally::default();
+        for result in results {
+            match result.status() {
+                Status::Pass => tally.pass += 1,
+                Status::Partial => tally.partial += 1,
+                Status::Fail => tally.fail += 1,
+            }
+            tally.found += result.found.unwrap_or(0);
+            tally.total += result.total;
+        }
+        tally
+    }
+
+    fn percent(&self) -> usize {
+        (self.found * 100).checked_div(self.total).unwrap_or(0)
+    }
+
+    fn json(&self) -> Value {
+        json!({
+            "pass": self.pass,
+            "partial": self.partial,
+            "fail": self.fail,
+            "statements": { "found": self.found, "total": self.total },
+        })
+    }
+}
+
+fn in_category<'a>(
+    results: &'a [FixtureResult],
+    category: &'a str,
+) -> impl Iterator<Item = &'a FixtureResult> {
+    results.iter().filter(move |r| r.category == category)
+}
+
+fn markdown(results: &[FixtureResult]) -> String {
+    let mut out = String::from(
+        "# Conformance with dom-expressions\n\
+         \n\
+         Generated by `cargo xtask conformance`; do not edit.\n\
+         \n\
+         Each fixture of `babel-plugin-jsx-dom-expressions` is compiled with the\n\
+         options of its Babel spec. A fixture passes when our output has every\n\
+         top-level statement of Babel's `output.js`, after both are printed by the\n\
+         same code generator and generated names are renumbered. It partially\n\
+         passes when some statements match, and fails when none do or the\n\
+         transform panics.\n\
+         \n\
+         | Category | Pass | Partial | Fail | Statements |\n\
+         | --- | ---: | ---: | ---: | ---: |\n",
+    );
+    for category in CATEGORIES {
+        let tally = Tally::of(in_category(results, category));
+        if tally.pass + tally.partial + tally.fail == 0 {
+            continue;
+        }
+        out.push_str(&format!(
+            "| [{}](#{}) | {} | {} | {} | {}/{} ({}%) |\n",
+            category,
+            category.to_lowercase().replace(' ', "-"),
+            tally.pass,
+            tally.partial,
+            tally.fail,
+            tally.found,
+            tally.total,
+            tally.percent()
+        ));
+    }
+    let tally = Tally::of(results);
+    out.push_str(&format!(
+        "| **Total** | **{}** | **{}** | **{}** | **{}/{} ({}%)** |\n",
+        tally.pass,
+        tally.partial,
+        tally.fail,
+        tally.found,
+        tally.total,
+        tally.percent()
+    ));
+
+    for category in CATEGORIES {
+        let mut rows = in_category(results, category).peekable();
+        if rows.peek().is_none() {
+            continue;
+        }
+        out.push_str(&format!(
+            "\n## {}\n\n| Fixture | Output | Status | Statements |\n| --- | --- | --- | ---: |\n",
+            category
+        ));
+        for result in rows {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} |\n",
+                result.name,
+                result.variant.label,
+                result.status().badge(),
+                result.statements()
+            ));
+        }
+    }
+    out
+}
+
+fn json_report(results: &[FixtureResult]) -> Value {
+    let categories: Vec<Value> = CATEGORIES
+        .iter()
+        .filter(|category| in_category(results, category).next().is_some())
+        .map(|category| {
+            let mut value = Tally::of(in_category(results, category)).json();
+            value["name"] = json!(category);
+            value["fixtures"] = in_category(results, category)
+                .map(|result| {
+                    json!({
+                        "name": result.name,
+                        "output": result.variant.label,
+                        "directory": result.variant.dir,
+                        "status": result.status().name(),
+                        "panics": result.found.is_none(),
+                        "statements": { "found": result.found.unwrap_or(0), "total": result.total },
+                    })
+                })
+                .collect();
+            value
+        })
+        .collect();
+    json!({
+        "summary": Tally::of(results).json(),
+        "categories": categories,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(name: &str, variant: usize, found: Option<usize>, total: usize) -> FixtureResult {
+        let variant = &VARIANTS[variant];
+        FixtureResult {
+            name: name.to_string(),
+            variant,
+            category: variant.category(name),
+            found,
+            total,
+        }
+    }
+
+    #[test]
+    fn test_status_and_category() {
+        let results = [
+            result("eventExpressions", 0, Some(10), 10),
+            result("eventExpressions", 1, Some(5), 10),
+            result("components", 2, Some(0), 4),
+            result("SVG", 0, None, 16),
+        ];
+        let statuses: Vec<_> = results.iter().map(FixtureResult::status).collect();
+        assert_eq!(
+            statuses,
+            [Status::Pass, Status::Partial, Status::Fail, Status::Fail]
+        );
+        let categories: Vec<_> = results.iter().map(|r| r.category).collect();
+        assert_eq!(categories, ["Events", "Events", "SSR", "Elements"]);
+
+        let report = json_report(&results);
+        assert_eq!(report["summary"]["pass"], 1);
+        assert_eq!(report["summary"]["statements"]["found"], 15);
+        assert_eq!(report["categories"][0]["name"], "Elements");
+        assert_eq!(report["categories"][0]["fixtures"][0]["panics"], true);
+        assert!(markdown(&results).contains("| [Events](#events) | 1 | 1 | 0 | 15/20 (75%) |"));
+    }
+
+    #[test]
+    fn test_statements() {
+        let babel =
+            "import { insert as _$insert } from \"r-dom\";\nconst _el$3 = 1; _$insert(_el$3, a)";
+        let ours = "import { insert } from 'r-dom';\nconst _el$1 = 1;\ninsert(_el$1, a);";
+        assert_eq!(statements(babel), statements(ours));
+        assert_eq!(statements(ours).len(), 3);
+    }
+}
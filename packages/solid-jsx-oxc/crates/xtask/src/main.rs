@@ -0,0 +1,49 @@
+//! Development tasks, run with `cargo xtask <task>`
+//!
+//! ```text
+//! cargo xtask compare ../babel-plugin-jsx-dom-expressions/test/__dom_fixtures__
+//! cargo xtask compare --generate ssr --hydratable src/App.tsx
+//! cargo xtask compare --summary --resolve-from ../.. examples/
+//! cargo xtask conformance --output CONFORMANCE.md
+//! cargo xtask conformance --format json
+//! ```
+
+mod compare;
+mod conformance;
+#[path = "../../../tests/support/mod.rs"]
+mod support;
+
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+
+#[derive(Debug, Parser)]
+#[command(name = "xtask", about = "Development tasks for solid-jsx-oxc")]
+struct Args {
+    #[command(subcommand)]
+    task: Task,
+}
+
+#[derive(Debug, Subcommand)]
+enum Task {
+    /// Compile files with this crate and with babel-preset-solid, and print
+    /// where the outputs differ
+    Compare(compare::CompareArgs),
+    /// Report which dom-expressions fixtures pass, partially pass or fail,
+    /// per category
+    Conformance(conformance::ConformanceArgs),
+}
+
+fn main() -> ExitCode {
+    let result = match Args::parse().task {
+        Task::Compare(args) => compare::run(&args),
+        Task::Conformance(args) => conformance::run(&args),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -33,6 +33,12 @@ export interface TransformOptions {
    */
   wrapConditionals?: boolean;
 
+  /**
+   * Whether to leave off the closing tags that end a DOM template
+   * @default false
+   */
+  omitLastClosingTag?: boolean;
+
   /**
    * Whether to pass context to custom elements
    * @default true
@@ -51,6 +57,26 @@ export interface TransformOptions {
    */
   sourceMap?: boolean;
 
+  /**
+   * Register exported components with solid-refresh for hot reload
+   * (ignored for SSR output)
+   * @default false
+   */
+  hmr?: boolean;
+
+  /**
+   * HMR API the solid-refresh glue talks to
+   * @default "vite"
+   */
+  hmrBundler?: 'vite' | 'esm' | 'standard' | 'webpack5';
+
+  /**
+   * Extensions parsed like a known one, e.g. `{ solid: 'tsx' }`; the source
+   * type otherwise follows the extension of `filename`, and unknown
+   * extensions are parsed as TSX
+   */
+  customExtensions?: Record<string, 'tsx' | 'jsx' | 'ts' | 'mts' | 'cts' | 'js' | 'mjs' | 'cjs'>;
+
   /**
    * Built-in components that receive special handling
    */
@@ -62,6 +88,12 @@ export interface TransformResult {
   code: string;
   /** Source map (if enabled) */
   map?: string;
+  /** Events the output passes to `delegateEvents` */
+  delegatedEvents: string[];
+  /** HTML of each hoisted template */
+  templates: string[];
+  /** Runtime helpers the output imports */
+  helpers: string[];
 }
 
 /**
@@ -81,11 +113,38 @@ export function transformJsx(source: string, options?: {
   hydratable?: boolean;
   delegateEvents?: boolean;
   wrapConditionals?: boolean;
+  omitLastClosingTag?: boolean;
   contextToCustomElements?: boolean;
   filename?: string;
   sourceMap?: boolean;
+  hmr?: boolean;
+  hmrBundler?: string;
+  customExtensions?: Record<string, string>;
 } | null): TransformResult;
 
+/**
+ * Events that are delegated to the document by default.
+ */
+export function getDelegatedEvents(): string[];
+
+/**
+ * Attributes treated as booleans (present = true).
+ */
+export function getBooleanAttributes(): string[];
+
+/**
+ * JSX attribute aliases, mapping the JSX name to the DOM attribute name.
+ */
+export function getAliases(): Record<string, string>;
+
+/**
+ * The JSX import source of a file: from a `@jsxImportSource` comment in
+ * `source`, then from `jsxImportSource` (or a React `jsx` mode, as 'react')
+ * in the nearest tsconfig.json and the configs it extends. Null when neither
+ * says.
+ */
+export function jsxImportSource(filename: string, source?: string | null): string | null;
+
 export interface PresetResult {
   options: TransformOptions;
   transform: (source: string) => TransformResult;
@@ -109,6 +168,10 @@ declare const _default: {
   preset: typeof preset;
   defaultOptions: typeof defaultOptions;
   transformJsx: typeof transformJsx;
+  getDelegatedEvents: typeof getDelegatedEvents;
+  getBooleanAttributes: typeof getBooleanAttributes;
+  getAliases: typeof getAliases;
+  jsxImportSource: typeof jsxImportSource;
 };
 
 export default _default;
@@ -61,6 +61,7 @@ export const defaultOptions = {
   ],
   contextToCustomElements: true,
   wrapConditionals: true,
+  omitLastClosingTag: false,
   generate: 'dom', // 'dom' | 'ssr' | 'universal'
   hydratable: false,
   delegateEvents: true,
@@ -108,10 +109,26 @@ export function preset(context, options = {}) {
  */
 export const transformJsx = nativeBinding ? nativeBinding.transformJsx : null;
 
+/**
+ * Compiler metadata, so tooling doesn't need to hardcode copies
+ */
+export const getDelegatedEvents = nativeBinding ? nativeBinding.getDelegatedEvents : null;
+export const getBooleanAttributes = nativeBinding ? nativeBinding.getBooleanAttributes : null;
+export const getAliases = nativeBinding ? nativeBinding.getAliases : null;
+
+/**
+ * JSX import source of a file, for leaving other frameworks' JSX alone
+ */
+export const jsxImportSource = nativeBinding ? nativeBinding.jsxImportSource : null;
+
 // Default export for convenience
 export default {
   transform,
   preset,
   defaultOptions,
   transformJsx,
+  getDelegatedEvents,
+  getBooleanAttributes,
+  getAliases,
+  jsxImportSource,
 };
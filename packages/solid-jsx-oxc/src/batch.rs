@@ -0,0 +1,86 @@
+//! Compiling many files at once, on a thread pool
+//!
+//! Build tools that already hold the sources of a module graph hand them to
+//! [`transform_batch`] together. Each file is parsed and compiled on rayon's
+//! thread pool by a [`Transformer`] of the thread, and the outputs come back
+//! in the order of the input whichever thread compiled them.
+
+use std::path::{Path, PathBuf};
+
+use rayon::prelude::*;
+
+use crate::{SyntaxError, TransformOptions, TransformOutput, Transformer};
+
+/// What [`transform_batch`] did
+#[derive(Debug, Default)]
+pub struct BatchReport {
+    /// One output per file, in input order
+    pub outputs: Vec<TransformOutput>,
+    /// The syntax errors of every file, in input order
+    pub diagnostics: Vec<(PathBuf, SyntaxError)>,
+}
+
+/// Compile `files`, pairs of a path and its contents, in parallel. The path
+/// picks the source type of each file and names it in its source map and
+/// diagnostics.
+pub fn transform_batch<P, S>(files: &[(P, S)], options: TransformOptions) -> BatchReport
+where
+    P: AsRef<Path> + Sync,
+    S: AsRef<str> + Sync,
+{
+    let outputs: Vec<TransformOutput> = files
+        .par_iter()
+        .map_with(Transformer::new(options), |transformer, (path, source)| {
+            transformer.transform_source(path, source.as_ref())
+        })
+        .collect();
+
+    let diagnostics = files
+        .iter()
+        .zip(&outputs)
+        .flat_map(|((path, _), output)| {
+            output
+                .errors
+                .iter()
+                .map(|error| (path.as_ref().to_path_buf(), error.clone()))
+        })
+        .collect();
+    BatchReport {
+        outputs,
+        diagnostics,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform_batch() {
+        let files: Vec<(String, String)> = (0..64)
+            .map(|i| {
+                let source = if i == 40 {
+                    "const broken = <div>;".to_string()
+                } else {
+                    format!("export const C{i} = () => <p onClick={{go}}>{i}</p>;")
+                };
+                (format!("src/C{i}.jsx"), source)
+            })
+            .collect();
+        let report = transform_batch(&files, TransformOptions::solid_defaults());
+
+        assert_eq!(report.outputs.len(), 64);
+        for (i, output) in report.outputs.iter().enumerate() {
+            if i != 40 {
+                assert!(output.code.contains(&format!("template(`<p>{i}</p>`)")));
+                assert_eq!(output.metadata.delegated_events, ["click"]);
+            }
+        }
+        assert!(!report.diagnostics.is_empty());
+        assert!(report
+            .diagnostics
+            .iter()
+            .all(|(path, _)| path == Path::new("src/C40.jsx")));
+        assert!(report.diagnostics[0].1.offset.is_some());
+    }
+}
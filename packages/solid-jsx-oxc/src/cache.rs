@@ -0,0 +1,357 @@
+//! Cache of compiled files, for watch mode and CI re-runs
+//!
+//! A [`TransformCache`] maps the contents of a file, its path and the options
+//! it is compiled with to its [`TransformOutput`], so unchanged files are not
+//! compiled again. Recent outputs are kept in memory, least recently used
+//! first out. A persistent cache also writes every output to a directory,
+//! where the next process (a restarted dev server, the next CI run) finds it;
+//! [`TransformCache::prune`] keeps that directory to a size.
+
+use std::hash::Hash;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use indexmap::IndexMap;
+use serde_json::{json, Value};
+use xxhash_rust::xxh3::Xxh3;
+
+use crate::{
+    GenerateMode, SyntaxError, TransformMetadata, TransformOptions, TransformOutput, Transformer,
+};
+
+/// How often the cache had an output
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// Outputs of compiled files by their content and options
+pub struct TransformCache {
+    dir: Option<PathBuf>,
+    capacity: usize,
+    /// Least recently used first
+    memory: Mutex<IndexMap<u128, TransformOutput>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl TransformCache {
+    /// A cache of the `capacity` most recently used outputs
+    pub fn in_memory(capacity: usize) -> Self {
+        Self {
+            dir: None,
+            capacity,
+            memory: Mutex::new(IndexMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// A cache that also keeps every output in `dir`, created if missing
+    pub fn persistent(dir: impl Into<PathBuf>, capacity: usize) -> io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir: Some(dir),
+            ..Self::in_memory(capacity)
+        })
+    }
+
+    /// The output of `transformer` for `source`, the contents of the file at
+    /// `path`: from the cache, or compiled and added to it
+    pub fn transform(
+        &self,
+        transformer: &mut Transformer,
+        path: impl AsRef<Path>,
+        source: &str,
+    ) -> TransformOutput {
+        let path = path.as_ref();
+        let key = cache_key(transformer.options(), path, source);
+        if let Some(output) = self.get(key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return output;
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let output = transformer.transform_source(path, source);
+        self.insert(key, output.clone());
+        output
+    }
+
+    /// Remove the outputs in the directory of a persistent cache that were
+    /// least recently written or read, until the rest take at most
+    /// `max_bytes`. Returns how many were removed.
+    pub fn prune(&self, max_bytes: u64) -> io::Result<usize> {
+        let Some(dir) = &self.dir else {
+            return Ok(0);
+        };
+        let mut files = Vec::new();
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|extension| extension != "json") {
+                continue;
+            }
+            // Another process may be pruning too
+            let Ok(metadata) = path.metadata() else {
+                continue;
+            };
+            files.push((metadata.modified()?, metadata.len(), path));
+        }
+        files.sort();
+
+        let mut size: u64 = files.iter().map(|(_, len, _)| len).sum();
+        let mut removed = 0;
+        for (_, len, path) in files {
+            if size <= max_bytes {
+                break;
+            }
+            if std::fs::remove_file(&path).is_ok() {
+                removed += 1;
+            }
+            size -= len;
+        }
+        Ok(removed)
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    fn get(&self, key: u128) -> Option<TransformOutput> {
+        {
+            let mut memory = self.memory.lock().unwrap();
+            if let Some(index) = memory.get_index_of(&key) {
+                let last = memory.len() - 1;
+                memory.move_index(index, last);
+                return Some(memory[last].clone());
+            }
+        }
+        let file = self.file_of(key)?;
+        let text = std::fs::read_to_string(&file).ok()?;
+        let output = output_from_json(&serde_json::from_str(&text).ok()?)?;
+        // Read outputs are pruned last
+        let _ = std::fs::File::options()
+            .write(true)
+            .open(&file)
+            .and_then(|file| file.set_modified(SystemTime::now()));
+        self.remember(key, output.clone());
+        Some(output)
+    }
+
+    fn insert(&self, key: u128, output: TransformOutput) {
+        if let Some(file) = self.file_of(key) {
+            // Written aside and renamed, so that other processes never read
+            // half a file. A cache that cannot be written only costs time.
+            let temporary = file.with_extension(format!("{}.tmp", std::process::id()));
+            let written = std::fs::write(&temporary, output_to_json(&output).to_string())
+                .and_then(|()| std::fs::rename(&temporary, &file));
+            if written.is_err() {
+                let _ = std::fs::remove_file(&temporary);
+            }
+        }
+        self.remember(key, output);
+    }
+
+    fn remember(&self, key: u128, output: TransformOutput) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut memory = self.memory.lock().unwrap();
+        if memory.len() >= self.capacity && !memory.contains_key(&key) {
+            memory.shift_remove_index(0);
+        }
+        memory.insert(key, output);
+    }
+
+    fn file_of(&self, key: u128) -> Option<PathBuf> {
+        let dir = self.dir.as_ref()?;
+        Some(dir.join(format!("{:032x}.json", key)))
+    }
+}
+
+/// A hash of everything the output depends on: the compiler version, the
+/// file and the options that change the generated code
+fn cache_key(options: &TransformOptions, path: &Path, source: &str) -> u128 {
+    let mut hasher = Xxh3::new();
+    env!("CARGO_PKG_VERSION").hash(&mut hasher);
+    path.hash(&mut hasher);
+    source.hash(&mut hasher);
+
+    options
+        .source_type_of(&path.to_string_lossy())
+        .hash(&mut hasher);
+    options.module_name.hash(&mut hasher);
+    match options.generate {
+        GenerateMode::Dom => 0u8,
+        GenerateMode::Ssr => 1,
+        GenerateMode::Universal => 2,
+    }
+    .hash(&mut hasher);
+    options.hydratable.hash(&mut hasher);
+    options.delegate_events.hash(&mut hasher);
+    options.delegated_events.hash(&mut hasher);
+    options.wrap_conditionals.hash(&mut hasher);
+    options.omit_last_closing_tag.hash(&mut hasher);
+    options.context_to_custom_elements.hash(&mut hasher);
+    options.built_ins.hash(&mut hasher);
+    options.custom_elements.hash(&mut hasher);
+    options.effect_wrapper.hash(&mut hasher);
+    options.memo_wrapper.hash(&mut hasher);
+    options.source_map.hash(&mut hasher);
+    options.static_marker.hash(&mut hasher);
+    options.hmr.hash(&mut hasher);
+    options.hmr_bundler.name().hash(&mut hasher);
+    hasher.digest128()
+}
+
+fn output_to_json(output: &TransformOutput) -> Value {
+    json!({
+        "code": output.code,
+        "map": output.map,
+        "delegatedEvents": output.metadata.delegated_events,
+        "templates": output.metadata.templates,
+        "helpers": output.metadata.helpers,
+        "errors": output
+            .errors
+            .iter()
+            .map(|error| json!({ "message": error.message, "offset": error.offset }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn output_from_json(value: &Value) -> Option<TransformOutput> {
+    let strings = |key: &str| -> Option<Vec<String>> {
+        value[key]
+            .as_array()?
+            .iter()
+            .map(|item| item.as_str().map(String::from))
+            .collect()
+    };
+    let errors = value["errors"]
+        .as_array()?
+        .iter()
+        .map(|error| {
+            Some(SyntaxError {
+                message: error["message"].as_str()?.to_string(),
+                offset: error["offset"].as_u64().map(|offset| offset as usize),
+            })
+        })
+        .collect::<Option<_>>()?;
+    Some(TransformOutput {
+        code: value["code"].as_str()?.to_string(),
+        map: value["map"].as_str().map(String::from),
+        metadata: TransformMetadata {
+            delegated_events: strings("delegatedEvents")?,
+            templates: strings("templates")?,
+            helpers: strings("helpers")?,
+        },
+        errors,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_memory_cache() {
+        let cache = TransformCache::in_memory(2);
+        let mut transformer = Transformer::new(TransformOptions::solid_defaults());
+        let a = "<div onClick={go}>a</div>";
+
+        let first = cache.transform(&mut transformer, "a.jsx", a);
+        let second = cache.transform(&mut transformer, "a.jsx", a);
+        assert_eq!(first.code, second.code);
+        assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 1 });
+
+        // Other contents, paths and options are other entries
+        cache.transform(&mut transformer, "a.jsx", "<div>b</div>");
+        cache.transform(&mut transformer, "b.jsx", a);
+        let mut ssr = Transformer::new(TransformOptions {
+            generate: GenerateMode::Ssr,
+            ..TransformOptions::solid_defaults()
+        });
+        assert!(!cache
+            .transform(&mut ssr, "a.jsx", a)
+            .code
+            .contains("template("));
+        assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 4 });
+
+        // Only the two most recent outputs are kept
+        cache.transform(&mut transformer, "b.jsx", a);
+        cache.transform(&mut transformer, "a.jsx", a);
+        assert_eq!(cache.stats(), CacheStats { hits: 2, misses: 5 });
+    }
+
+    #[test]
+    fn test_persistent_cache() {
+        let dir = std::env::temp_dir().join(format!("solid-cache-{}", std::process::id()));
+        let options = TransformOptions {
+            source_map: true,
+            ..TransformOptions::solid_defaults()
+        };
+        let source = "const App = () => <button onClick={go}>{count()}</button>;";
+
+        let cache = TransformCache::persistent(&dir, 16).unwrap();
+        let compiled = cache.transform(&mut Transformer::new(options.clone()), "App.jsx", source);
+        // A new process, with nothing in memory
+        let cache = TransformCache::persistent(&dir, 16).unwrap();
+        let cached = cache.transform(&mut Transformer::new(options), "App.jsx", source);
+        let files = std::fs::read_dir(&dir).unwrap().count();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 0 });
+        assert_eq!(files, 1);
+        assert_eq!(cached.code, compiled.code);
+        assert_eq!(cached.map, compiled.map);
+        assert_eq!(cached.metadata, compiled.metadata);
+        assert_eq!(cached.errors, compiled.errors);
+    }
+
+    #[test]
+    fn test_prune() {
+        let dir = std::env::temp_dir().join(format!("solid-cache-prune-{}", std::process::id()));
+        let cache = TransformCache::persistent(&dir, 0).unwrap();
+        let mut transformer = Transformer::new(TransformOptions::solid_defaults());
+        for name in ["a", "b", "c"] {
+            cache.transform(&mut transformer, format!("{}.jsx", name), "<div />");
+        }
+        let old = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1);
+        for entry in std::fs::read_dir(&dir).unwrap() {
+            let file = std::fs::File::options()
+                .write(true)
+                .open(entry.unwrap().path())
+                .unwrap();
+            file.set_modified(old).unwrap();
+        }
+        // Read from disk, so `a.jsx` is the most recently used
+        cache.transform(&mut transformer, "a.jsx", "<div />");
+        let size = |dir: &Path| -> u64 {
+            std::fs::read_dir(dir)
+                .unwrap()
+                .map(|entry| entry.unwrap().metadata().unwrap().len())
+                .sum()
+        };
+        let one = size(&dir) / 3;
+
+        let kept = cache.prune(u64::MAX).unwrap();
+        let removed = cache.prune(one).unwrap();
+        let hit = cache.transform(&mut transformer, "a.jsx", "<div />");
+        let stats = cache.stats();
+        let files = std::fs::read_dir(&dir).unwrap().count();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(kept, 0);
+        assert_eq!(removed, 2);
+        assert_eq!(files, 1);
+        assert!(hit.code.contains("template("));
+        assert_eq!(stats, CacheStats { hits: 2, misses: 3 });
+        assert_eq!(TransformCache::in_memory(4).prune(0).unwrap(), 0);
+    }
+}
@@ -0,0 +1,169 @@
+//! C ABI, behind the `ffi` feature
+//!
+//! The shared library (`libsolid_jsx_oxc.so`, `.dylib` or `solid_jsx_oxc.dll`)
+//! exports two functions for hosts that cannot load a Node addon, such as Bun
+//! FFI, Python's ctypes or Zig. Strings are NUL-terminated UTF-8; options and
+//! results are JSON, as in the WebAssembly build.
+//!
+//! ```c
+//! char *solid_jsx_transform(const char *source, const char *options_json);
+//! void solid_jsx_free(char *result);
+//! ```
+//!
+//! Built for `wasm32-wasip1`, the same functions serve WebAssembly hosts such
+//! as Deno and Cloudflare Workers. Those write the arguments into the
+//! module's memory, in buffers from `solid_jsx_alloc`.
+
+use std::any::Any;
+use std::ffi::{c_char, CStr, CString};
+use std::panic;
+
+use crate::json::{error, transform_json};
+
+/// Read a string argument; `None` for a null pointer
+///
+/// # Safety
+///
+/// `ptr` is null or points to a NUL-terminated string
+unsafe fn read_str<'s>(ptr: *const c_char, name: &str) -> Result<Option<&'s str>, String> {
+    if ptr.is_null() {
+        return Ok(None);
+    }
+    CStr::from_ptr(ptr)
+        .to_str()
+        .map(Some)
+        .map_err(|err| error(format!("{} is not valid UTF-8: {}", name, err)))
+}
+
+/// Transform `source` with `options_json`, a JSON object of transform
+/// options (null or empty for the defaults). Returns
+/// `{ code, map, delegatedEvents, templates, helpers }`, or `{ error }` when
+/// an argument is invalid or the transform panics, so a bug in the compiler
+/// never unwinds into the host. Pass the result to [`solid_jsx_free`].
+///
+/// # Safety
+///
+/// `source` points to a NUL-terminated string; `options_json` is null or
+/// points to one.
+#[no_mangle]
+pub unsafe extern "C" fn solid_jsx_transform(
+    source: *const c_char,
+    options_json: *const c_char,
+) -> *mut c_char {
+    let result = match (
+        read_str(source, "source"),
+        read_str(options_json, "options"),
+    ) {
+        (Ok(None), _) => error("source is null"),
+        (Ok(Some(source)), Ok(options)) => {
+            panic::catch_unwind(|| transform_json(source, options.unwrap_or("")))
+                .unwrap_or_else(|payload| error(panic_message(payload.as_ref())))
+        }
+        (Err(err), _) | (_, Err(err)) => err,
+    };
+    // JSON escapes NUL, so the result never contains one
+    CString::new(result).unwrap_or_default().into_raw()
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    let message = payload
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| payload.downcast_ref::<&str>().copied())
+        .unwrap_or("unknown error");
+    format!("transform panicked: {}", message)
+}
+
+/// Free a result of [`solid_jsx_transform`]; null is ignored
+///
+/// # Safety
+///
+/// `result` is null or was returned by `solid_jsx_transform` and is not
+/// freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn solid_jsx_free(result: *mut c_char) {
+    if !result.is_null() {
+        drop(CString::from_raw(result));
+    }
+}
+
+/// Allocate `len` bytes, for a host that writes arguments into the memory of
+/// the library (as WebAssembly hosts do); free them with [`solid_jsx_dealloc`]
+#[no_mangle]
+pub extern "C" fn solid_jsx_alloc(len: usize) -> *mut u8 {
+    let mut buffer = std::mem::ManuallyDrop::new(Vec::<u8>::with_capacity(len));
+    buffer.as_mut_ptr()
+}
+
+/// Free `len` bytes from [`solid_jsx_alloc`]
+///
+/// # Safety
+///
+/// `ptr` was returned by `solid_jsx_alloc(len)` and is not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn solid_jsx_dealloc(ptr: *mut u8, len: usize) {
+    drop(Vec::from_raw_parts(ptr, 0, len));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn call(source: *const c_char, options: *const c_char) -> Value {
+        unsafe {
+            let result = solid_jsx_transform(source, options);
+            let json = CStr::from_ptr(result).to_str().unwrap().to_string();
+            solid_jsx_free(result);
+            serde_json::from_str(&json).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_transform() {
+        let source = CString::new("<div onClick={a}>hi</div>").unwrap();
+        let output = call(source.as_ptr(), std::ptr::null());
+        assert_eq!(output["templates"][0], "<div>hi</div>");
+        assert_eq!(output["delegatedEvents"][0], "click");
+
+        let options = CString::new(r#"{ "generate": "ssr", "sourceMap": true }"#).unwrap();
+        let output = call(source.as_ptr(), options.as_ptr());
+        assert!(output["templates"].as_array().unwrap().is_empty());
+        assert!(output["map"].is_string());
+
+        let options = CString::new(r#"{ "generate": "dmo" }"#).unwrap();
+        let output = call(source.as_ptr(), options.as_ptr());
+        assert!(output["error"]
+            .as_str()
+            .unwrap()
+            .contains("unknown generate \"dmo\""));
+
+        let options = CString::new("{ generate }").unwrap();
+        let output = call(source.as_ptr(), options.as_ptr());
+        assert!(output["error"]
+            .as_str()
+            .unwrap()
+            .starts_with("invalid options"));
+
+        let output = call(std::ptr::null(), std::ptr::null());
+        assert_eq!(output["error"], "source is null");
+
+        let invalid = CString::new(vec![0xff, 0xfe]).unwrap();
+        let output = call(invalid.as_ptr(), std::ptr::null());
+        assert!(output["error"].as_str().unwrap().contains("UTF-8"));
+
+        unsafe { solid_jsx_free(std::ptr::null_mut()) };
+    }
+
+    #[test]
+    fn test_alloc() {
+        let source = b"<div>hi</div>\0";
+        let ptr = solid_jsx_alloc(source.len());
+        unsafe {
+            std::ptr::copy_nonoverlapping(source.as_ptr(), ptr, source.len());
+            let output = call(ptr.cast(), std::ptr::null());
+            assert_eq!(output["templates"][0], "<div>hi</div>");
+            solid_jsx_dealloc(ptr, source.len());
+        }
+    }
+}
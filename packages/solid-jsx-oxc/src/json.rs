@@ -0,0 +1,73 @@
+//! Transform with options and results as JSON, for the bindings that only
+//! pass strings: WebAssembly and the C ABI
+
+use serde_json::json;
+
+use crate::{transform_internal, transform_options, JsTransformOptions};
+
+pub(crate) fn error(message: impl std::fmt::Display) -> String {
+    json!({ "error": message.to_string() }).to_string()
+}
+
+/// Transform `source` with `options`, a JSON object of transform options (or
+/// an empty string for the defaults). Returns
+/// `{ code, map, delegatedEvents, templates, helpers }`, or `{ error }` when
+/// the options are invalid.
+pub(crate) fn transform_json(source: &str, options: &str) -> String {
+    let json_options: JsTransformOptions = if options.trim().is_empty() {
+        JsTransformOptions::default()
+    } else {
+        match serde_json::from_str(options) {
+            Ok(options) => options,
+            Err(err) => return error(format!("invalid options: {}", err)),
+        }
+    };
+
+    let options = match transform_options(&json_options) {
+        Ok(options) => options,
+        Err(message) => return error(message),
+    };
+
+    let (result, metadata) = transform_internal(source, &options);
+    json!({
+        "code": result.code,
+        "map": result.map.map(|map| map.to_json_string()),
+        "delegatedEvents": metadata.delegated_events,
+        "templates": metadata.templates,
+        "helpers": metadata.helpers,
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn test_custom_extensions() {
+        let source = "const App = (props: Props) => <div>{props.name}</div>;";
+        let options = r#"{ "filename": "App.solid", "customExtensions": { "solid": "tsx" } }"#;
+        let output: Value = serde_json::from_str(&transform_json(source, options)).unwrap();
+        assert!(output["code"].as_str().unwrap().contains("template("));
+
+        let options = r#"{ "customExtensions": { "solid": "vue" } }"#;
+        let output: Value = serde_json::from_str(&transform_json(source, options)).unwrap();
+        assert!(output["error"].as_str().unwrap().contains("\"vue\""));
+    }
+
+    #[test]
+    fn test_hmr_bundler() {
+        let source = "export const App = () => <div />;";
+        let options = r#"{ "hmr": true, "hmrBundler": "webpack5" }"#;
+        let output: Value = serde_json::from_str(&transform_json(source, options)).unwrap();
+        assert!(output["code"]
+            .as_str()
+            .unwrap()
+            .contains("import.meta.webpackHot"));
+
+        let options = r#"{ "hmr": true, "hmrBundler": "webpack" }"#;
+        let output: Value = serde_json::from_str(&transform_json(source, options)).unwrap();
+        assert!(output["error"].as_str().unwrap().contains("\"webpack\""));
+    }
+}
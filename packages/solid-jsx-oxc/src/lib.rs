@@ -12,22 +12,62 @@
 //! let result = transform(source, None);
 //! println!("{}", result.code);
 //! ```
-
-pub use common::TransformOptions;
+//!
+//! With the `napi` feature this crate is the Node addon published as
+//! `@solid-jsx-oxc/core`, exposing `transformSync`, `transform`,
+//! `TransformSession`, which reuses its memory across files, and
+//! `TransformCache`. With the `wasm` feature it builds for
+//! `wasm32-unknown-unknown`; see [`wasm`]. With the `ffi` feature the shared
+//! library exports a C ABI; see [`ffi`].
+//! Bundler plugins written in Rust compile modules through [`plugin`]; other
+//! Rust tools embed the compiler through [`Transformer`], or, with the default
+//! `project` feature, compile many files in parallel with `transform_batch`
+//! and `transform_project`, and skip unchanged ones with `TransformCache`
+//! (the `cache` feature on its own).
+
+pub use common::{
+    aliases, boolean_attributes, delegated_events, ChunkMetadata, GenerateMode, HmrBundler,
+    SharedTemplate, TransformMetadata, TransformOptions,
+};
+pub use oxc_span::SourceType;
 
 #[cfg(feature = "napi")]
 use napi_derive::napi;
 
+#[cfg(feature = "project")]
+pub mod batch;
+#[cfg(feature = "cache")]
+pub mod cache;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(any(feature = "wasm", feature = "ffi"))]
+mod json;
+pub mod plugin;
+#[cfg(feature = "project")]
+pub mod project;
+mod refresh;
+pub mod transformer;
+pub mod tsconfig;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 use oxc_allocator::Allocator;
 use oxc_codegen::{Codegen, CodegenOptions, CodegenReturn, IndentChar};
 use oxc_parser::Parser;
-use oxc_span::SourceType;
 
 use std::path::PathBuf;
 
 use dom::SolidTransform;
 use ssr::SSRTransform;
 
+#[cfg(feature = "project")]
+pub use batch::transform_batch;
+#[cfg(feature = "cache")]
+pub use cache::TransformCache;
+#[cfg(feature = "project")]
+pub use project::transform_project;
+pub use transformer::{SyntaxError, TransformOutput, Transformer};
+
 /// Result of a transform operation
 #[cfg(feature = "napi")]
 #[napi(object)]
@@ -36,12 +76,24 @@ pub struct TransformResult {
     pub code: String,
     /// Source map (if enabled)
     pub map: Option<String>,
+    /// Events the output passes to `delegateEvents`
+    pub delegated_events: Vec<String>,
+    /// HTML of each hoisted template
+    pub templates: Vec<String>,
+    /// Runtime helpers the output imports
+    pub helpers: Vec<String>,
 }
 
-/// Transform options exposed to JavaScript
-#[cfg(feature = "napi")]
-#[napi(object)]
-#[derive(Default)]
+/// Transform options as JavaScript passes them: an object to the Node addon,
+/// JSON to the WebAssembly and C bindings
+#[cfg(any(feature = "napi", feature = "wasm", feature = "ffi"))]
+#[cfg_attr(feature = "napi", napi(object))]
+#[cfg_attr(
+    any(feature = "wasm", feature = "ffi"),
+    derive(serde::Deserialize),
+    serde(rename_all = "camelCase", default, deny_unknown_fields)
+)]
+#[derive(Debug, Default)]
 pub struct JsTransformOptions {
     /// The module to import runtime helpers from
     /// @default "solid-js/web"
@@ -64,6 +116,10 @@ pub struct JsTransformOptions {
     /// @default true
     pub wrap_conditionals: Option<bool>,
 
+    /// Whether to leave off the closing tags that end a DOM template
+    /// @default false
+    pub omit_last_closing_tag: Option<bool>,
+
     /// Whether to pass context to custom elements
     /// @default true
     pub context_to_custom_elements: Option<bool>,
@@ -75,52 +131,396 @@ pub struct JsTransformOptions {
     /// Whether to generate source maps
     /// @default false
     pub source_map: Option<bool>,
+
+    /// Whether to register exported components with solid-refresh
+    /// @default false
+    pub hmr: Option<bool>,
+
+    /// HMR API for solid-refresh: "vite", "esm", "standard" or "webpack5"
+    /// @default "vite"
+    pub hmr_bundler: Option<String>,
+
+    /// Extensions parsed like a known one, e.g. `{ solid: "tsx" }`; files with
+    /// other unknown extensions are parsed as TSX
+    pub custom_extensions: Option<std::collections::HashMap<String, String>>,
 }
 
 /// Transform JSX source code
 #[cfg(feature = "napi")]
 #[napi]
-pub fn transform_jsx(source: String, options: Option<JsTransformOptions>) -> TransformResult {
-    let js_options = options.unwrap_or_default();
+pub fn transform_jsx(
+    source: String,
+    options: Option<JsTransformOptions>,
+) -> napi::Result<TransformResult> {
+    transform_js(&source, options.unwrap_or_default())
+}
+
+/// Transform JSX source code on the calling thread
+#[cfg(feature = "napi")]
+#[napi]
+pub fn transform_sync(
+    source: String,
+    options: Option<JsTransformOptions>,
+) -> napi::Result<TransformResult> {
+    transform_js(&source, options.unwrap_or_default())
+}
+
+/// Transform JSX source code on the libuv thread pool, resolving to the
+/// same result as `transformSync`
+#[cfg(feature = "napi")]
+#[napi(js_name = "transform")]
+pub fn transform_async(
+    source: String,
+    options: Option<JsTransformOptions>,
+) -> napi::bindgen_prelude::AsyncTask<TransformTask> {
+    napi::bindgen_prelude::AsyncTask::new(TransformTask {
+        source,
+        options: options.unwrap_or_default(),
+    })
+}
+
+/// Background work behind `transform`
+#[cfg(feature = "napi")]
+pub struct TransformTask {
+    source: String,
+    options: JsTransformOptions,
+}
+
+#[cfg(feature = "napi")]
+impl napi::Task for TransformTask {
+    type Output = TransformResult;
+    type JsValue = TransformResult;
+
+    fn compute(&mut self) -> napi::Result<TransformResult> {
+        let options = std::mem::take(&mut self.options);
+        transform_js(&self.source, options)
+    }
+
+    fn resolve(
+        &mut self,
+        _env: napi::Env,
+        output: TransformResult,
+    ) -> napi::Result<TransformResult> {
+        Ok(output)
+    }
+}
+
+/// Compiles many files with the same options, as a dev server does. The
+/// options are converted from JavaScript and validated once, when the session
+/// is created. The compiler's options borrow their strings, so they are built
+/// again for each file, without copying any. The memory that holds the syntax
+/// tree of a file is reset and reused for the next.
+#[cfg(feature = "napi")]
+#[napi]
+pub struct TransformSession {
+    options: JsTransformOptions,
+    allocator: Allocator,
+}
 
-    // Convert JS options to internal options
+#[cfg(feature = "napi")]
+#[napi]
+impl TransformSession {
+    /// Throws when the options are invalid
+    #[napi(constructor)]
+    pub fn new(options: Option<JsTransformOptions>) -> napi::Result<Self> {
+        let options = options.unwrap_or_default();
+        transform_options(&options).map_err(napi::Error::from_reason)?;
+        Ok(Self {
+            options,
+            allocator: Allocator::default(),
+        })
+    }
+
+    /// Transform `source`, the contents of `filename`; without a filename,
+    /// the one of the session's options
+    #[napi]
+    pub fn transform(
+        &mut self,
+        source: String,
+        filename: Option<String>,
+    ) -> napi::Result<TransformResult> {
+        // Validated by the constructor, so this does not fail
+        let mut options = transform_options(&self.options).map_err(napi::Error::from_reason)?;
+        if let Some(filename) = &filename {
+            options.filename = filename;
+        }
+        self.allocator.reset();
+        let (result, metadata, _) = transform_in(&self.allocator, &source, &options);
+        Ok(js_result(result, metadata))
+    }
+}
+
+/// How many outputs a `TransformCache` keeps in memory by default
+#[cfg(feature = "napi")]
+const CACHE_CAPACITY: u32 = 4096;
+
+/// Compiled modules by their contents, path and options, so a dev server does
+/// not compile an unchanged module again. With a directory, outputs are also
+/// kept there for the next process; `prune` keeps it to a size.
+#[cfg(feature = "napi")]
+#[napi(js_name = "TransformCache")]
+pub struct JsTransformCache {
+    cache: std::sync::Arc<TransformCache>,
+}
+
+#[cfg(feature = "napi")]
+#[napi]
+impl JsTransformCache {
+    /// Keep the `capacity` (4096 by default) most recently used outputs in
+    /// memory, and every output in `dir` when given
+    #[napi(constructor)]
+    pub fn new(dir: Option<String>, capacity: Option<u32>) -> napi::Result<Self> {
+        let capacity = capacity.unwrap_or(CACHE_CAPACITY) as usize;
+        let cache = match dir {
+            Some(dir) => TransformCache::persistent(&dir, capacity)
+                .map_err(|err| napi::Error::from_reason(format!("{}: {}", dir, err)))?,
+            None => TransformCache::in_memory(capacity),
+        };
+        Ok(Self {
+            cache: std::sync::Arc::new(cache),
+        })
+    }
+
+    /// `transform`, with the output from the cache when the module, its
+    /// `filename` and options are unchanged
+    #[napi]
+    pub fn transform(
+        &self,
+        source: String,
+        options: Option<JsTransformOptions>,
+    ) -> napi::bindgen_prelude::AsyncTask<CachedTransformTask> {
+        napi::bindgen_prelude::AsyncTask::new(CachedTransformTask {
+            cache: std::sync::Arc::clone(&self.cache),
+            source,
+            options: options.unwrap_or_default(),
+        })
+    }
+
+    /// Remove the least recently used outputs from the directory until the
+    /// rest take at most `maxBytes`; returns how many were removed
+    #[napi]
+    pub fn prune(&self, max_bytes: f64) -> napi::Result<u32> {
+        self.cache
+            .prune(max_bytes as u64)
+            .map(|removed| removed as u32)
+            .map_err(|err| napi::Error::from_reason(err.to_string()))
+    }
+}
+
+/// Background work behind `TransformCache.transform`
+#[cfg(feature = "napi")]
+pub struct CachedTransformTask {
+    cache: std::sync::Arc<TransformCache>,
+    source: String,
+    options: JsTransformOptions,
+}
+
+#[cfg(feature = "napi")]
+impl napi::Task for CachedTransformTask {
+    type Output = TransformResult;
+    type JsValue = TransformResult;
+
+    fn compute(&mut self) -> napi::Result<TransformResult> {
+        let options = transform_options(&self.options).map_err(napi::Error::from_reason)?;
+        let filename = options.filename;
+        let output = self
+            .cache
+            .transform(&mut Transformer::new(options), filename, &self.source);
+        Ok(TransformResult {
+            code: output.code,
+            map: output.map,
+            delegated_events: output.metadata.delegated_events,
+            templates: output.metadata.templates,
+            helpers: output.metadata.helpers,
+        })
+    }
+
+    fn resolve(
+        &mut self,
+        _env: napi::Env,
+        output: TransformResult,
+    ) -> napi::Result<TransformResult> {
+        Ok(output)
+    }
+}
+
+#[cfg(feature = "napi")]
+fn transform_js(source: &str, js_options: JsTransformOptions) -> napi::Result<TransformResult> {
+    let options = transform_options(&js_options).map_err(napi::Error::from_reason)?;
+    let (result, metadata) = transform_internal(source, &options);
+    Ok(js_result(result, metadata))
+}
+
+/// Convert JS options to internal options, or describe the first invalid one
+#[cfg(any(feature = "napi", feature = "wasm", feature = "ffi"))]
+fn transform_options(js_options: &JsTransformOptions) -> Result<TransformOptions<'_>, String> {
     let generate = match js_options.generate.as_deref() {
-        Some("ssr") => common::GenerateMode::Ssr,
-        Some("universal") => common::GenerateMode::Universal,
-        _ => common::GenerateMode::Dom,
+        None => common::GenerateMode::Dom,
+        Some(name) => common::GenerateMode::from_name(name).ok_or_else(|| {
+            format!(
+                "invalid options: unknown generate \"{}\", expected \"dom\", \"ssr\" or \"universal\"",
+                name
+            )
+        })?,
+    };
+    let mut custom_extensions = Vec::new();
+    for (extension, like) in js_options.custom_extensions.iter().flatten() {
+        let source_type = SourceType::from_extension(like).map_err(|_| {
+            format!(
+                "invalid options: customExtensions maps \"{}\" to unknown extension \"{}\"",
+                extension, like
+            )
+        })?;
+        custom_extensions.push((extension.as_str(), source_type));
+    }
+    let hmr_bundler = match js_options.hmr_bundler.as_deref() {
+        None => HmrBundler::default(),
+        Some(name) => HmrBundler::from_name(name).ok_or_else(|| {
+            format!(
+                "invalid options: unknown hmrBundler \"{}\", expected \"vite\", \"esm\", \"standard\" or \"webpack5\"",
+                name
+            )
+        })?,
     };
 
-    let options = TransformOptions {
+    Ok(TransformOptions {
+        module_name: js_options.module_name.as_deref().unwrap_or("solid-js/web"),
         generate,
         hydratable: js_options.hydratable.unwrap_or(false),
         delegate_events: js_options.delegate_events.unwrap_or(true),
         wrap_conditionals: js_options.wrap_conditionals.unwrap_or(true),
+        omit_last_closing_tag: js_options.omit_last_closing_tag.unwrap_or(false),
         context_to_custom_elements: js_options.context_to_custom_elements.unwrap_or(true),
         filename: js_options.filename.as_deref().unwrap_or("input.jsx"),
         source_map: js_options.source_map.unwrap_or(false),
+        hmr: js_options.hmr.unwrap_or(false),
+        hmr_bundler,
+        custom_extensions,
         ..TransformOptions::solid_defaults()
-    };
-
-    let result = transform_internal(&source, &options);
+    })
+}
 
+#[cfg(feature = "napi")]
+fn js_result(result: CodegenReturn, metadata: TransformMetadata) -> TransformResult {
     TransformResult {
         code: result.code,
         map: result.map.map(|m| m.to_json_string()),
+        delegated_events: metadata.delegated_events,
+        templates: metadata.templates,
+        helpers: metadata.helpers,
+    }
+}
+
+/// Events that are delegated to the document by default
+#[cfg(feature = "napi")]
+#[napi]
+pub fn get_delegated_events() -> Vec<String> {
+    delegated_events().into_iter().map(String::from).collect()
+}
+
+/// Attributes treated as booleans (present = true)
+#[cfg(feature = "napi")]
+#[napi]
+pub fn get_boolean_attributes() -> Vec<String> {
+    boolean_attributes().into_iter().map(String::from).collect()
+}
+
+/// JSX attribute aliases, mapping the JSX name to the DOM attribute name
+#[cfg(feature = "napi")]
+#[napi]
+pub fn get_aliases() -> std::collections::HashMap<String, String> {
+    aliases()
+        .into_iter()
+        .map(|(jsx, dom)| (jsx.to_string(), dom.to_string()))
+        .collect()
+}
+
+/// The JSX import source of a file, from a `@jsxImportSource` comment in
+/// `source` or the nearest tsconfig.json; null when neither says
+#[cfg(feature = "napi")]
+#[napi]
+pub fn jsx_import_source(filename: String, source: Option<String>) -> Option<String> {
+    tsconfig::jsx_import_source(std::path::Path::new(&filename), source.as_deref())
+}
+
+/// Whether `importSource` is Solid's: solid-js, or the package of
+/// `moduleName` (solid-js/web by default) for custom renderers
+#[cfg(feature = "napi")]
+#[napi]
+pub fn is_solid_import_source(import_source: String, module_name: Option<String>) -> bool {
+    tsconfig::is_solid_import_source(
+        &import_source,
+        module_name.as_deref().unwrap_or("solid-js/web"),
+    )
+}
+
+/// `jsxImportSource` for a dev server, which reads the configs of each
+/// directory once; call `invalidate` with the files its watcher reports
+#[cfg(feature = "napi")]
+#[napi]
+#[derive(Default)]
+pub struct JsxImportSources {
+    cache: tsconfig::ImportSourceCache,
+}
+
+#[cfg(feature = "napi")]
+#[napi]
+impl JsxImportSources {
+    #[napi(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The JSX import source of `filename`, as `jsxImportSource` finds it
+    #[napi]
+    pub fn get(&self, filename: String, source: Option<String>) -> Option<String> {
+        self.cache
+            .jsx_import_source(std::path::Path::new(&filename), source.as_deref())
+    }
+
+    /// Forget what was read when `path`, a changed, added or removed file, is
+    /// a tsconfig.json or a config one extends; `true` when it was
+    #[napi]
+    pub fn invalidate(&self, path: String) -> bool {
+        self.cache.invalidate(std::path::Path::new(&path))
     }
 }
 
 /// Internal transform function
 pub fn transform(source: &str, options: Option<TransformOptions>) -> CodegenReturn {
+    transform_with_metadata(source, options).0
+}
+
+/// Transform, also returning the delegated events and templates of the output
+pub fn transform_with_metadata(
+    source: &str,
+    options: Option<TransformOptions>,
+) -> (CodegenReturn, TransformMetadata) {
     let options = options.unwrap_or_else(TransformOptions::solid_defaults);
     transform_internal(source, &options)
 }
 
-fn transform_internal(source: &str, options: &TransformOptions) -> CodegenReturn {
-    let allocator = Allocator::default();
-    let source_type = SourceType::from_path(options.filename).unwrap_or(SourceType::tsx());
+fn transform_internal(
+    source: &str,
+    options: &TransformOptions,
+) -> (CodegenReturn, TransformMetadata) {
+    let (result, metadata, _) = transform_in(&Allocator::default(), source, options);
+    (result, metadata)
+}
+
+/// Transform with the AST in `allocator`, also returning the syntax errors
+/// the parser recovered from
+fn transform_in(
+    allocator: &Allocator,
+    source: &str,
+    options: &TransformOptions,
+) -> (CodegenReturn, TransformMetadata, Vec<SyntaxError>) {
+    let source_type = options.source_type_of(options.filename);
 
     // Parse the source
-    let mut program = Parser::new(&allocator, source, source_type).parse().program;
+    let parsed = Parser::new(allocator, source, source_type).parse();
+    let mut program = parsed.program;
+    let errors = parsed.errors.iter().map(SyntaxError::from).collect();
 
     // Run the appropriate transform based on generate mode
     // SAFETY: We create a raw pointer to `options` and dereference it to get a reference
@@ -132,24 +532,28 @@ fn transform_internal(source: &str, options: &TransformOptions) -> CodegenReturn
     // multiple mutable borrows needed during AST traversal.
     let options_ref = unsafe { &*(options as *const TransformOptions) };
 
-    match options.generate {
+    let metadata = match options.generate {
         common::GenerateMode::Dom => {
-            let transformer = SolidTransform::new(&allocator, options_ref);
-            transformer.transform(&mut program);
+            let transformer = SolidTransform::new(allocator, options_ref);
+            transformer.transform(&mut program)
         }
         common::GenerateMode::Ssr => {
-            let transformer = SSRTransform::new(&allocator, options_ref);
-            transformer.transform(&mut program);
+            let transformer = SSRTransform::new(allocator, options_ref);
+            transformer.transform(&mut program)
         }
         common::GenerateMode::Universal => {
             // Universal mode is not implemented yet; treat as DOM for now.
-            let transformer = SolidTransform::new(&allocator, options_ref);
-            transformer.transform(&mut program);
+            let transformer = SolidTransform::new(allocator, options_ref);
+            transformer.transform(&mut program)
         }
+    };
+
+    if options.hmr && options.generate != common::GenerateMode::Ssr {
+        refresh::add_refresh(allocator, &mut program, options.hmr_bundler);
     }
 
     // Generate code
-    Codegen::new()
+    let result = Codegen::new()
         .with_options(CodegenOptions {
             source_map_path: if options.source_map {
                 Some(PathBuf::from(options.filename))
@@ -160,7 +564,8 @@ fn transform_internal(source: &str, options: &TransformOptions) -> CodegenReturn
             indent_char: IndentChar::Space,
             ..CodegenOptions::default()
         })
-        .build(&program)
+        .build(&program);
+    (result, metadata, errors)
 }
 
 #[cfg(test)]
@@ -196,6 +601,58 @@ mod tests {
         assert!(!result.code.is_empty());
     }
 
+    #[test]
+    fn test_metadata_exports() {
+        assert!(delegated_events().contains(&"click"));
+        assert!(!delegated_events().contains(&"scroll"));
+        assert!(boolean_attributes().contains(&"disabled"));
+        assert!(!boolean_attributes().contains(&"value"));
+        assert!(aliases().contains(&("className", "class")));
+    }
+
+    #[test]
+    fn test_transform_metadata() {
+        let source = r#"<div onClick={a}><svg><rect /></svg><input onScroll={b} /></div>"#;
+        let (result, metadata) = transform_with_metadata(source, None);
+        assert_eq!(metadata.delegated_events, ["click"]);
+        assert_eq!(metadata.templates.len(), 1);
+        assert!(result.code.contains(&metadata.templates[0]));
+        assert!(metadata.helpers.iter().any(|h| h == "delegateEvents"));
+
+        let options = TransformOptions {
+            generate: common::GenerateMode::Ssr,
+            ..TransformOptions::solid_defaults()
+        };
+        let (_, metadata) = transform_with_metadata(r#"<div>{a()}</div>"#, Some(options));
+        assert!(metadata.delegated_events.is_empty());
+        assert!(metadata.templates.is_empty());
+        assert!(metadata.helpers.iter().any(|h| h == "escape"));
+    }
+
+    #[test]
+    fn test_source_type_of() {
+        let options = TransformOptions {
+            custom_extensions: vec![("sjs", SourceType::jsx()), (".solid", SourceType::tsx())],
+            ..TransformOptions::solid_defaults()
+        };
+        assert!(!options.source_type_of("src/App.sjs").is_typescript());
+        assert!(options.source_type_of("src/App.solid").is_typescript());
+        assert!(options.source_type_of("src/App.vue").is_jsx());
+        assert!(!options.source_type_of("src/util.mts").is_jsx());
+        assert!(!options.source_type_of("src/util.cjs").is_typescript());
+
+        // Type annotations only parse as TypeScript
+        let source = "const App = (props: Props) => <div>{props.name}</div>;";
+        let result = transform(
+            source,
+            Some(TransformOptions {
+                filename: "App.solid",
+                ..options
+            }),
+        );
+        assert!(result.code.contains("template("));
+    }
+
     #[test]
     fn test_ssr_basic_element() {
         let source = r#"<div class="hello">world</div>"#;
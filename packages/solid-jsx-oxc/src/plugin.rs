@@ -0,0 +1,228 @@
+//! Module-level transform for bundler plugins
+//!
+//! Bundlers written in Rust, such as Rolldown, call the compiler from their
+//! `transform` hook without crossing into JavaScript. [`SolidPlugin`] holds the
+//! options from the bundler config and decides per module whether to compile
+//! it and with which options: the module id picks the file and its source type,
+//! and the environment the module is built for picks DOM or SSR output.
+//!
+//! This crate does not implement Rolldown's `rolldown_plugin::Plugin` trait.
+//! Rolldown's Rust crates are not published with a stable API, so the
+//! `Plugin` impl belongs to the Rolldown build that vendors them: its
+//! `transform` hook forwards the module id, code and environment to
+//! [`SolidPlugin::transform_module`] and returns the code and source map.
+
+use std::path::Path;
+
+use oxc_codegen::CodegenReturn;
+
+use crate::{
+    transform_internal, tsconfig, GenerateMode, HmrBundler, TransformMetadata, TransformOptions,
+};
+
+/// Plugin options, as set in the bundler config
+#[derive(Clone)]
+pub struct PluginOptions {
+    /// Extensions of the modules to compile, without the dot
+    pub extensions: Vec<String>,
+    /// Modules whose path contains any of these are left alone
+    pub exclude: Vec<String>,
+    /// The module to import runtime helpers from
+    pub module_name: String,
+    /// Output for modules built for the client
+    pub generate: GenerateMode,
+    pub hydratable: bool,
+    pub delegate_events: bool,
+    pub wrap_conditionals: bool,
+    pub omit_last_closing_tag: bool,
+    pub context_to_custom_elements: bool,
+    pub built_ins: Vec<String>,
+    pub source_map: bool,
+    /// Register exported components with solid-refresh, in client modules
+    pub hmr: bool,
+    pub hmr_bundler: HmrBundler,
+    /// Leave modules alone whose JSX import source, from a
+    /// `@jsxImportSource` comment or the nearest tsconfig.json, is not Solid's
+    pub tsconfig: bool,
+}
+
+impl Default for PluginOptions {
+    fn default() -> Self {
+        let defaults = TransformOptions::solid_defaults();
+        Self {
+            extensions: vec!["jsx".to_string(), "tsx".to_string()],
+            exclude: vec!["/node_modules/".to_string()],
+            module_name: defaults.module_name.to_string(),
+            generate: defaults.generate,
+            hydratable: defaults.hydratable,
+            delegate_events: defaults.delegate_events,
+            wrap_conditionals: defaults.wrap_conditionals,
+            omit_last_closing_tag: defaults.omit_last_closing_tag,
+            context_to_custom_elements: defaults.context_to_custom_elements,
+            built_ins: defaults.built_ins.iter().map(|s| s.to_string()).collect(),
+            source_map: true,
+            hmr: false,
+            hmr_bundler: HmrBundler::default(),
+            tsconfig: false,
+        }
+    }
+}
+
+/// Compiles the modules a bundler hands over, with options resolved per module
+#[derive(Clone, Default)]
+pub struct SolidPlugin {
+    options: PluginOptions,
+}
+
+impl SolidPlugin {
+    pub fn new(options: PluginOptions) -> Self {
+        Self { options }
+    }
+
+    pub fn options(&self) -> &PluginOptions {
+        &self.options
+    }
+
+    /// The file path of a module id, without the query or hash dev servers
+    /// append; `None` for virtual modules
+    pub fn module_path(id: &str) -> Option<&str> {
+        if id.starts_with('\0') {
+            return None;
+        }
+        id.split(['?', '#']).next()
+    }
+
+    /// Whether the module `id` is compiled at all
+    pub fn filter(&self, id: &str) -> bool {
+        let Some(path) = Self::module_path(id) else {
+            return false;
+        };
+        let normalized = path.replace('\\', "/");
+        let extension = normalized
+            .rsplit_once('.')
+            .map(|(_, extension)| extension)
+            .unwrap_or("");
+        self.options.extensions.iter().any(|e| e == extension)
+            && !self
+                .options
+                .exclude
+                .iter()
+                .any(|pattern| normalized.contains(pattern.as_str()))
+    }
+
+    /// Transform options for the module `id`, or `None` when it is filtered
+    /// out. Modules built for the server (`ssr`) always get SSR output.
+    pub fn resolve<'a>(&'a self, id: &'a str, ssr: bool) -> Option<TransformOptions<'a>> {
+        if !self.filter(id) {
+            return None;
+        }
+        let options = &self.options;
+        Some(TransformOptions {
+            module_name: &options.module_name,
+            generate: if ssr {
+                GenerateMode::Ssr
+            } else {
+                options.generate
+            },
+            hydratable: options.hydratable,
+            delegate_events: options.delegate_events,
+            wrap_conditionals: options.wrap_conditionals,
+            omit_last_closing_tag: options.omit_last_closing_tag,
+            context_to_custom_elements: options.context_to_custom_elements,
+            built_ins: options.built_ins.iter().map(String::as_str).collect(),
+            filename: Self::module_path(id)?,
+            source_map: options.source_map,
+            hmr: options.hmr && !ssr,
+            hmr_bundler: options.hmr_bundler,
+            ..TransformOptions::solid_defaults()
+        })
+    }
+
+    /// Whether the JSX of `source`, the module `id`, is another framework's;
+    /// always `false` unless the `tsconfig` option is set
+    pub fn is_foreign_jsx(&self, id: &str, source: &str) -> bool {
+        if !self.options.tsconfig {
+            return false;
+        }
+        let Some(path) = Self::module_path(id) else {
+            return false;
+        };
+        tsconfig::jsx_import_source(Path::new(path), Some(source)).is_some_and(|import_source| {
+            !tsconfig::is_solid_import_source(&import_source, &self.options.module_name)
+        })
+    }
+
+    /// Compile the module `id`, or `None` when it is filtered out or its JSX
+    /// is another framework's
+    pub fn transform_module(
+        &self,
+        id: &str,
+        source: &str,
+        ssr: bool,
+    ) -> Option<(CodegenReturn, TransformMetadata)> {
+        let options = self.resolve(id, ssr)?;
+        if self.is_foreign_jsx(id, source) {
+            return None;
+        }
+        Some(transform_internal(source, &options))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_per_module() {
+        let plugin = SolidPlugin::new(PluginOptions {
+            hydratable: true,
+            ..PluginOptions::default()
+        });
+        assert!(plugin
+            .resolve("/app/node_modules/lib/A.jsx", false)
+            .is_none());
+        assert!(plugin.resolve("/app/src/util.ts", false).is_none());
+        assert!(plugin.resolve("\0virtual:A.tsx", false).is_none());
+
+        let options = plugin.resolve("/app/src/App.tsx?v=1a2b", false).unwrap();
+        assert_eq!(options.filename, "/app/src/App.tsx");
+        assert!(options.generate == GenerateMode::Dom);
+        assert!(options.hydratable);
+
+        let options = plugin.resolve("C:\\app\\src\\App.jsx", true).unwrap();
+        assert!(options.generate == GenerateMode::Ssr);
+    }
+
+    #[test]
+    fn test_transform_module() {
+        let plugin = SolidPlugin::default();
+        let source = "export const A = () => <div onClick={a}>hi</div>;";
+        let (result, metadata) = plugin
+            .transform_module("/src/A.tsx", source, false)
+            .unwrap();
+        assert!(result.code.contains("template(`<div>hi</div>`)"));
+        assert!(result.map.is_some());
+        assert_eq!(metadata.delegated_events, ["click"]);
+
+        let (result, _) = plugin.transform_module("/src/A.tsx", source, true).unwrap();
+        assert!(!result.code.contains("template("));
+        assert!(plugin
+            .transform_module("/src/a.ts", source, false)
+            .is_none());
+
+        let preact = format!("/** @jsxImportSource preact */\n{}", source);
+        assert!(plugin
+            .transform_module("/src/A.tsx", &preact, false)
+            .is_some());
+        let plugin = SolidPlugin::new(PluginOptions {
+            tsconfig: true,
+            ..PluginOptions::default()
+        });
+        assert!(plugin
+            .transform_module("/src/A.tsx", &preact, false)
+            .is_none());
+        assert!(plugin
+            .transform_module("/src/A.tsx", source, false)
+            .is_some());
+    }
+}
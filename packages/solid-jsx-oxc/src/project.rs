@@ -0,0 +1,351 @@
+//! Compiling a whole source directory, as libraries precompile `src/` to
+//! `dist/`
+//!
+//! [`transform_project`] walks the directory with the `ignore` crate (so
+//! `.gitignore` is respected), compiles the JSX files in parallel, and writes
+//! each output at the same relative path under the output directory. Other
+//! files are left for the library's own build to copy. The metadata of every
+//! file is merged into one [`ChunkMetadata`], in path order, for tools that
+//! hoist shared templates or emit one `delegateEvents` call for the package.
+//!
+//! Files whose JSX belongs to another framework, by their `@jsxImportSource`
+//! pragma or the nearest tsconfig.json (see [`crate::tsconfig`]), are skipped.
+//! [`transform_files`] compiles only some of the files again, as watch mode
+//! does after a change.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use ignore::WalkBuilder;
+use rayon::prelude::*;
+
+use crate::tsconfig::{is_solid_import_source, jsx_import_source};
+use crate::{
+    ChunkMetadata, SyntaxError, TransformCache, TransformMetadata, TransformOptions, Transformer,
+};
+
+/// Options of [`transform_project`]
+pub struct ProjectOptions<'o> {
+    /// Options for every file; `filename` is set per file
+    pub transform: TransformOptions<'o>,
+    /// Where the outputs go
+    pub out_dir: PathBuf,
+    /// Extensions of the files to compile, without the dot
+    pub extensions: Vec<String>,
+    /// Where to find the outputs of files that did not change since the last
+    /// run
+    pub cache: Option<&'o TransformCache>,
+}
+
+impl<'o> ProjectOptions<'o> {
+    /// Compile `.jsx` and `.tsx` files with Solid's defaults into `out_dir`
+    pub fn new(out_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            transform: TransformOptions::solid_defaults(),
+            out_dir: out_dir.into(),
+            extensions: vec!["jsx".to_string(), "tsx".to_string()],
+            cache: None,
+        }
+    }
+}
+
+/// A compiled file
+#[derive(Debug)]
+pub struct ProjectFile {
+    pub source: PathBuf,
+    pub output: PathBuf,
+    pub metadata: TransformMetadata,
+    pub errors: Vec<SyntaxError>,
+}
+
+/// What [`transform_project`] did
+#[derive(Debug, Default)]
+pub struct ProjectReport {
+    /// Compiled files, in path order
+    pub files: Vec<ProjectFile>,
+    /// The metadata of every compiled file, keyed by its path relative to the
+    /// root
+    pub metadata: ChunkMetadata,
+    /// Files left alone because their JSX is another framework's
+    pub skipped: Vec<PathBuf>,
+    /// Sources that no longer exist, whose outputs were removed
+    pub removed: Vec<PathBuf>,
+    /// Files that could not be read or written
+    pub errors: Vec<(PathBuf, io::Error)>,
+    /// Syntax errors of the compiled files, in path order
+    pub diagnostics: Vec<(PathBuf, SyntaxError)>,
+}
+
+/// Compile the JSX files under `root` into `options.out_dir`, keeping their
+/// paths relative to `root`. Outputs of TypeScript files get a `.ts`
+/// extension and keep their types; the others get `.js`. With `source_map`
+/// set, each output has a `.map` file next to it.
+///
+/// Fails only when `root` or the output directory cannot be used; errors of
+/// single files are in the report.
+pub fn transform_project(
+    root: impl AsRef<Path>,
+    options: ProjectOptions,
+) -> io::Result<ProjectReport> {
+    let sources = project_sources(&root, &options)?;
+    transform_files(root, &sources, &options)
+}
+
+/// The files under `root` that [`transform_project`] compiles, as absolute
+/// paths in path order
+pub fn project_sources(
+    root: impl AsRef<Path>,
+    options: &ProjectOptions,
+) -> io::Result<Vec<PathBuf>> {
+    let root = root.as_ref().canonicalize()?;
+    std::fs::create_dir_all(&options.out_dir)?;
+    let out_dir = options.out_dir.canonicalize()?;
+
+    let mut sources: Vec<PathBuf> = WalkBuilder::new(&root)
+        .require_git(false)
+        .build()
+        .flatten()
+        .filter(|entry| entry.file_type().is_some_and(|t| t.is_file()))
+        .map(|entry| entry.into_path())
+        // The output directory may be inside the root
+        .filter(|path| !path.starts_with(&out_dir))
+        .filter(|path| {
+            path.extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| options.extensions.iter().any(|e| e == extension))
+        })
+        .collect();
+    sources.sort();
+    Ok(sources)
+}
+
+/// Compile `sources`, files under `root`, as [`transform_project`] would.
+/// Sources that no longer exist have their outputs removed.
+pub fn transform_files(
+    root: impl AsRef<Path>,
+    sources: &[PathBuf],
+    options: &ProjectOptions,
+) -> io::Result<ProjectReport> {
+    let root = root.as_ref().canonicalize()?;
+    std::fs::create_dir_all(&options.out_dir)?;
+    let out_dir = options.out_dir.canonicalize()?;
+
+    // The transform options are not `Sync`; every thread gets a copy
+    let cache = options.cache;
+    let results: Vec<_> = sources
+        .par_iter()
+        .map_with(
+            Transformer::new(options.transform.clone()),
+            |transformer, source| {
+                compile_file(transformer, cache, &root, &out_dir, source)
+                    .map_err(|error| (source.clone(), error))
+            },
+        )
+        .collect();
+
+    let mut report = ProjectReport::default();
+    for result in results {
+        match result {
+            Ok(Compiled::Skipped(source)) => report.skipped.push(source),
+            Ok(Compiled::Removed(source)) => report.removed.push(source),
+            Ok(Compiled::File(file)) => {
+                let id = file.source.strip_prefix(&root).unwrap_or(&file.source);
+                report.metadata.add(&id.to_string_lossy(), &file.metadata);
+                for error in &file.errors {
+                    report
+                        .diagnostics
+                        .push((file.source.clone(), error.clone()));
+                }
+                report.files.push(file);
+            }
+            Err(error) => report.errors.push(error),
+        }
+    }
+    Ok(report)
+}
+
+enum Compiled {
+    File(ProjectFile),
+    Skipped(PathBuf),
+    Removed(PathBuf),
+}
+
+fn compile_file(
+    transformer: &mut Transformer,
+    cache: Option<&TransformCache>,
+    root: &Path,
+    out_dir: &Path,
+    source: &Path,
+) -> io::Result<Compiled> {
+    let relative = source.strip_prefix(root).unwrap_or(source);
+    let extension = if transformer
+        .options()
+        .source_type_of(&source.to_string_lossy())
+        .is_typescript()
+    {
+        "ts"
+    } else {
+        "js"
+    };
+    let output = out_dir.join(relative).with_extension(extension);
+    let text = match std::fs::read_to_string(source) {
+        Ok(text) => text,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {
+            remove_output(&output)?;
+            return Ok(Compiled::Removed(source.to_path_buf()));
+        }
+        Err(error) => return Err(error),
+    };
+    if jsx_import_source(source, Some(&text)).is_some_and(|import_source| {
+        !is_solid_import_source(&import_source, transformer.options().module_name)
+    }) {
+        return Ok(Compiled::Skipped(source.to_path_buf()));
+    }
+    let output_dir = output.parent().unwrap_or(out_dir);
+    std::fs::create_dir_all(output_dir)?;
+
+    // Source maps point at the source from where the map is written
+    let map_source = relative_to(source, output_dir);
+    let result = match cache {
+        Some(cache) => cache.transform(transformer, map_source, &text),
+        None => transformer.transform_source(map_source, &text),
+    };
+    let mut code = result.code;
+    if let Some(map) = result.map {
+        let mut map_path = output.clone().into_os_string();
+        map_path.push(".map");
+        let map_path = PathBuf::from(map_path);
+        std::fs::write(&map_path, map)?;
+        let name = map_path.file_name().unwrap_or_default().to_string_lossy();
+        code.push_str(&format!("//# sourceMappingURL={}\n", name));
+    }
+    std::fs::write(&output, code)?;
+
+    Ok(Compiled::File(ProjectFile {
+        source: source.to_path_buf(),
+        output,
+        metadata: result.metadata,
+        errors: result.errors,
+    }))
+}
+
+/// Remove an output and its source map, if they exist
+fn remove_output(output: &Path) -> io::Result<()> {
+    let mut map = output.as_os_str().to_owned();
+    map.push(".map");
+    for path in [output, Path::new(&map)] {
+        match std::fs::remove_file(path) {
+            Err(error) if error.kind() != io::ErrorKind::NotFound => return Err(error),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// The path of `to` from the directory `from`; both absolute
+fn relative_to(to: &Path, from: &Path) -> PathBuf {
+    let to: Vec<_> = to.components().collect();
+    let from: Vec<_> = from.components().collect();
+    let common = to.iter().zip(&from).take_while(|(a, b)| a == b).count();
+    let mut path = PathBuf::new();
+    for _ in common..from.len() {
+        path.push("..");
+    }
+    path.extend(&to[common..]);
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_transform_project() {
+        let root = std::env::temp_dir().join(format!("solid-project-{}", std::process::id()));
+        let src = root.join("src");
+        fs::create_dir_all(src.join("components")).unwrap();
+        fs::write(
+            src.join("App.tsx"),
+            "export const App = (props: { a: () => void }) => <button onClick={props.a}>go</button>;",
+        )
+        .unwrap();
+        fs::write(
+            src.join("components/Card.jsx"),
+            "export const Card = () => <button onClick={go}>go</button>;",
+        )
+        .unwrap();
+        fs::write(src.join("util.ts"), "export const x = 1;").unwrap();
+
+        let mut options = ProjectOptions::new(root.join("dist"));
+        options.transform.source_map = true;
+        let report = transform_project(&src, options).unwrap();
+        let app = fs::read_to_string(root.join("dist/App.ts")).unwrap();
+        let card = fs::read_to_string(root.join("dist/components/Card.js")).unwrap();
+        let card_map = fs::read_to_string(root.join("dist/components/Card.js.map")).unwrap();
+        let util_exists = root.join("dist/util.js").exists() || root.join("dist/util.ts").exists();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert!(report.errors.is_empty());
+        assert!(report.diagnostics.is_empty());
+        assert_eq!(report.files.len(), 2);
+        assert!(app.contains("props: {"));
+        assert!(card.ends_with("//# sourceMappingURL=Card.js.map\n"));
+        assert!(card_map.contains("\"../../src/components/Card.jsx\""));
+        assert!(!util_exists);
+
+        assert_eq!(
+            report.metadata.delegated_events().collect::<Vec<_>>(),
+            ["click"]
+        );
+        let shared: Vec<_> = report.metadata.shared_templates().collect();
+        assert_eq!(shared.len(), 1);
+        assert_eq!(shared[0].html, "<button>go</button>");
+        assert_eq!(shared[0].modules.len(), 2);
+    }
+
+    #[test]
+    fn test_transform_files() {
+        let root = std::env::temp_dir().join(format!("solid-project-files-{}", std::process::id()));
+        let src = root.join("src");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(
+            src.join("Card.jsx"),
+            "export const Card = () => <p>card</p>;",
+        )
+        .unwrap();
+        fs::write(
+            src.join("Preact.jsx"),
+            "/** @jsxImportSource preact */\nexport const P = () => <p>preact</p>;",
+        )
+        .unwrap();
+
+        let options = ProjectOptions::new(root.join("dist"));
+        let sources = project_sources(&src, &options).unwrap();
+        let report = transform_files(&src, &sources, &options).unwrap();
+        assert_eq!(report.files.len(), 1);
+        assert_eq!(report.skipped, [sources[1].clone()]);
+        assert!(root.join("dist/Card.js").exists());
+        assert!(!root.join("dist/Preact.js").exists());
+
+        fs::remove_file(src.join("Card.jsx")).unwrap();
+        let report = transform_files(&src, &sources[..1], &options).unwrap();
+        let card_exists = root.join("dist/Card.js").exists();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(report.removed, [sources[0].clone()]);
+        assert!(!card_exists);
+    }
+
+    #[test]
+    fn test_relative_to() {
+        assert_eq!(
+            relative_to(Path::new("/p/src/a/App.tsx"), Path::new("/p/dist/a")),
+            Path::new("../../src/a/App.tsx")
+        );
+        assert_eq!(
+            relative_to(Path::new("/p/src/App.tsx"), Path::new("/p/src")),
+            Path::new("App.tsx")
+        );
+    }
+}
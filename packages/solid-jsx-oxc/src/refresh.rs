@@ -0,0 +1,336 @@
+//! solid-refresh glue for the `hmr` option
+//!
+//! Exported components are re-exported through solid-refresh's
+//! `$$component`, so an edit swaps them in place instead of reloading the
+//! page. Components are exported functions with a PascalCase name:
+//! `export function Name`, `export default function Name`, and
+//! `export const Name =` with a function or arrow function. Their `export` is
+//! dropped, and the registrations are appended to the module (imports are
+//! hoisted). The appended nodes are built with empty spans, as they have no
+//! source.
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    Argument, BindingPattern, Declaration, ExportDefaultDeclarationKind, Expression,
+    ImportDeclarationSpecifier, ImportOrExportKind, ModuleExportName, Program, Statement,
+    VariableDeclarationKind,
+};
+use oxc_ast::{AstBuilder, NONE};
+use oxc_span::SPAN;
+
+use common::HmrBundler;
+
+struct Component {
+    name: String,
+    is_default: bool,
+}
+
+fn is_component_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+/// The name of the component `declaration` declares, if it is one
+fn declared_component(declaration: &Declaration) -> Option<String> {
+    match declaration {
+        Declaration::FunctionDeclaration(function) => function
+            .id
+            .as_ref()
+            .map(|id| id.name.to_string())
+            .filter(|name| is_component_name(name)),
+        Declaration::VariableDeclaration(variable)
+            if variable.kind != VariableDeclarationKind::Var
+                && variable.declarations.len() == 1 =>
+        {
+            let declarator = &variable.declarations[0];
+            let BindingPattern::BindingIdentifier(id) = &declarator.id else {
+                return None;
+            };
+            let is_function = matches!(
+                declarator.init,
+                Some(Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_))
+            );
+            (is_function && is_component_name(&id.name)).then(|| id.name.to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Take the exported component out of `statement`, leaving its declaration
+fn unexport_component<'a>(statement: &mut Statement<'a>, ast: AstBuilder<'a>) -> Option<Component> {
+    let component = match statement {
+        Statement::ExportNamedDeclaration(export) if export.source.is_none() => {
+            export.declaration.as_ref().and_then(declared_component)
+        }
+        Statement::ExportDefaultDeclaration(export) => match &export.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(function) => function
+                .id
+                .as_ref()
+                .map(|id| id.name.to_string())
+                .filter(|name| is_component_name(name)),
+            _ => None,
+        },
+        _ => None,
+    }?;
+
+    let exported = std::mem::replace(statement, ast.statement_empty(SPAN));
+    let is_default = matches!(exported, Statement::ExportDefaultDeclaration(_));
+    *statement = match exported {
+        Statement::ExportNamedDeclaration(export) => match export.unbox().declaration? {
+            Declaration::FunctionDeclaration(function) => Statement::FunctionDeclaration(function),
+            Declaration::VariableDeclaration(variable) => Statement::VariableDeclaration(variable),
+            _ => unreachable!("only functions and variables are components"),
+        },
+        Statement::ExportDefaultDeclaration(export) => match export.unbox().declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(function) => {
+                Statement::FunctionDeclaration(function)
+            }
+            _ => unreachable!("only functions are default exported components"),
+        },
+        _ => unreachable!(),
+    };
+    Some(Component {
+        name: component,
+        is_default,
+    })
+}
+
+fn identifier<'a>(ast: AstBuilder<'a>, name: &str) -> Expression<'a> {
+    ast.expression_identifier(SPAN, ast.atom(name))
+}
+
+fn call<'a>(
+    ast: AstBuilder<'a>,
+    callee: &str,
+    arguments: impl IntoIterator<Item = Expression<'a>>,
+) -> Expression<'a> {
+    ast.expression_call(
+        SPAN,
+        identifier(ast, callee),
+        NONE,
+        ast.vec_from_iter(arguments.into_iter().map(Argument::from)),
+        false,
+    )
+}
+
+fn const_statement<'a>(ast: AstBuilder<'a>, name: &str, init: Expression<'a>) -> Statement<'a> {
+    let declarator = ast.variable_declarator(
+        SPAN,
+        VariableDeclarationKind::Const,
+        ast.binding_pattern_binding_identifier(SPAN, ast.atom(name)),
+        NONE,
+        Some(init),
+        false,
+    );
+    Statement::VariableDeclaration(ast.alloc_variable_declaration(
+        SPAN,
+        VariableDeclarationKind::Const,
+        ast.vec1(declarator),
+        false,
+    ))
+}
+
+/// `import.meta.hot`, `module.hot` or `import.meta.webpackHot`
+fn hot_api<'a>(ast: AstBuilder<'a>, bundler: HmrBundler) -> Expression<'a> {
+    let mut parts = bundler.hot_api().split('.');
+    let mut object = match parts.next() {
+        Some("import") => {
+            parts.next();
+            ast.expression_meta_property(
+                SPAN,
+                ast.identifier_name(SPAN, "import"),
+                ast.identifier_name(SPAN, "meta"),
+            )
+        }
+        name => identifier(ast, name.unwrap_or_default()),
+    };
+    for property in parts {
+        object = Expression::StaticMemberExpression(ast.alloc_static_member_expression(
+            SPAN,
+            object,
+            ast.identifier_name(SPAN, ast.atom(property)),
+            false,
+        ));
+    }
+    object
+}
+
+/// The statements registering `components`, appended to the module
+fn registrations<'a>(
+    ast: AstBuilder<'a>,
+    components: &[Component],
+    bundler: HmrBundler,
+) -> oxc_allocator::Vec<'a, Statement<'a>> {
+    let mut body = ast.vec();
+    let specifiers = ["$$component", "$$registry", "$$refresh"].map(|helper| {
+        ImportDeclarationSpecifier::ImportSpecifier(ast.alloc_import_specifier(
+            SPAN,
+            ModuleExportName::IdentifierName(ast.identifier_name(SPAN, helper)),
+            ast.binding_identifier(SPAN, ast.atom(&format!("_{}", helper))),
+            ImportOrExportKind::Value,
+        ))
+    });
+    body.push(Statement::ImportDeclaration(ast.alloc_import_declaration(
+        SPAN,
+        Some(ast.vec_from_array(specifiers)),
+        ast.string_literal(SPAN, "solid-refresh", None),
+        None,
+        NONE,
+        ImportOrExportKind::Value,
+    )));
+    body.push(const_statement(
+        ast,
+        "_REGISTRY",
+        call(ast, "_$$registry", []),
+    ));
+
+    for Component { name, is_default } in components {
+        let local = format!("_$${}", name);
+        let register = call(
+            ast,
+            "_$$component",
+            [
+                identifier(ast, "_REGISTRY"),
+                ast.expression_string_literal(SPAN, ast.atom(name), None),
+                identifier(ast, name),
+            ],
+        );
+        body.push(const_statement(ast, &local, register));
+        body.push(if *is_default {
+            Statement::ExportDefaultDeclaration(ast.alloc_export_default_declaration(
+                SPAN,
+                ExportDefaultDeclarationKind::Identifier(
+                    ast.alloc_identifier_reference(SPAN, ast.atom(&local)),
+                ),
+            ))
+        } else {
+            let specifier = ast.export_specifier(
+                SPAN,
+                ModuleExportName::IdentifierReference(
+                    ast.identifier_reference(SPAN, ast.atom(&local)),
+                ),
+                ModuleExportName::IdentifierName(ast.identifier_name(SPAN, ast.atom(name))),
+                ImportOrExportKind::Value,
+            );
+            Statement::ExportNamedDeclaration(ast.alloc_export_named_declaration(
+                SPAN,
+                None,
+                ast.vec1(specifier),
+                None,
+                ImportOrExportKind::Value,
+                NONE,
+            ))
+        });
+    }
+
+    let refresh = call(
+        ast,
+        "_$$refresh",
+        [
+            ast.expression_string_literal(SPAN, bundler.name(), None),
+            hot_api(ast, bundler),
+            identifier(ast, "_REGISTRY"),
+        ],
+    );
+    let mut consequent = ast.vec1(ast.statement_expression(SPAN, refresh));
+    // Vite only treats a module as self-accepting when the call is in its
+    // source; the other runtimes accept from inside `$$refresh`
+    if matches!(bundler, HmrBundler::Vite | HmrBundler::Esm) {
+        let accept = Expression::StaticMemberExpression(ast.alloc_static_member_expression(
+            SPAN,
+            hot_api(ast, bundler),
+            ast.identifier_name(SPAN, "accept"),
+            false,
+        ));
+        let accept = ast.expression_call(SPAN, accept, NONE, ast.vec(), false);
+        consequent.push(ast.statement_expression(SPAN, accept));
+    }
+    body.push(ast.statement_if(
+        SPAN,
+        hot_api(ast, bundler),
+        ast.statement_block(SPAN, consequent),
+        None,
+    ));
+    body
+}
+
+/// Register the exported components of `program` with solid-refresh and make
+/// the module accept its own updates. Modules without exported components are
+/// left alone, so updates propagate to their importers.
+pub(crate) fn add_refresh<'a>(
+    allocator: &'a Allocator,
+    program: &mut Program<'a>,
+    bundler: HmrBundler,
+) {
+    let ast = AstBuilder::new(allocator);
+    let components: Vec<_> = program
+        .body
+        .iter_mut()
+        .filter_map(|statement| unexport_component(statement, ast))
+        .collect();
+    if components.is_empty() {
+        return;
+    }
+
+    program
+        .body
+        .extend(registrations(ast, &components, bundler));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{transform, HmrBundler, TransformOptions};
+
+    fn transform_hmr(source: &str, bundler: HmrBundler) -> String {
+        let options = TransformOptions {
+            hmr: true,
+            hmr_bundler: bundler,
+            ..TransformOptions::solid_defaults()
+        };
+        transform(source, Some(options)).code
+    }
+
+    #[test]
+    fn test_refresh_vite() {
+        let code = transform_hmr(
+            "export function App() { return <div />; }\n\
+             export const Button = () => <button />;\n\
+             export const theme = () => 'dark';\n\
+             export const Context = createContext();\n\
+             export default function Page() { return <App />; }",
+            HmrBundler::Vite,
+        );
+        assert!(code.contains("\nfunction App()"));
+        assert!(code.contains("\nconst Button = "));
+        assert!(code.contains("export const theme"));
+        assert!(code.contains("export const Context"));
+        assert!(code.contains("const _$$App = _$$component(_REGISTRY, \"App\", App);"));
+        assert!(code.contains("export { _$$Button as Button };"));
+        assert!(code.contains("export default _$$Page;"));
+        assert!(code.contains("_$$refresh(\"vite\", import.meta.hot, _REGISTRY);"));
+        assert!(code.contains("import.meta.hot.accept();"));
+    }
+
+    #[test]
+    fn test_refresh_bundlers() {
+        let source = "export const App = () => <div />;";
+        let code = transform_hmr(source, HmrBundler::Webpack5);
+        assert!(code.contains("_$$refresh(\"webpack5\", import.meta.webpackHot, _REGISTRY);"));
+        assert!(!code.contains("accept()"));
+        let code = transform_hmr(source, HmrBundler::Standard);
+        assert!(code.contains("_$$refresh(\"standard\", module.hot, _REGISTRY);"));
+
+        // No components, no glue
+        let code = transform_hmr("export const value = <div />;", HmrBundler::Vite);
+        assert!(!code.contains("solid-refresh"));
+
+        // Not for the server
+        let options = TransformOptions {
+            hmr: true,
+            generate: common::GenerateMode::Ssr,
+            ..TransformOptions::solid_defaults()
+        };
+        assert!(!transform(source, Some(options))
+            .code
+            .contains("solid-refresh"));
+    }
+}
@@ -0,0 +1,151 @@
+//! Compiler for Rust programs that embed it
+//!
+//! SSR servers and static site generators compile many files with the same
+//! options. A [`Transformer`] holds those options and one arena allocator,
+//! which is reset between files instead of being allocated for each.
+
+use std::io;
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_diagnostics::OxcDiagnostic;
+
+use crate::{transform_in, TransformMetadata, TransformOptions};
+
+/// A compiled file
+#[derive(Debug, Clone)]
+pub struct TransformOutput {
+    /// The generated code
+    pub code: String,
+    /// Source map as JSON, if `source_map` is set
+    pub map: Option<String>,
+    pub metadata: TransformMetadata,
+    /// Syntax errors in the source; the code is compiled from what the parser
+    /// recovered
+    pub errors: Vec<SyntaxError>,
+}
+
+/// A syntax error in a compiled file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxError {
+    pub message: String,
+    /// Byte offset of the error in the source, when the parser gave one
+    pub offset: Option<usize>,
+}
+
+impl From<&OxcDiagnostic> for SyntaxError {
+    fn from(diagnostic: &OxcDiagnostic) -> Self {
+        Self {
+            message: diagnostic.message.to_string(),
+            offset: diagnostic
+                .labels
+                .as_ref()
+                .and_then(|labels| labels.first())
+                .map(|label| label.offset()),
+        }
+    }
+}
+
+/// Compiles files with the same options, reusing its memory between them
+pub struct Transformer<'o> {
+    options: TransformOptions<'o>,
+    allocator: Allocator,
+}
+
+/// A transformer with the same options and its own memory, as each thread
+/// compiling files needs
+impl Clone for Transformer<'_> {
+    fn clone(&self) -> Self {
+        Self::new(self.options.clone())
+    }
+}
+
+impl<'o> Transformer<'o> {
+    /// A transformer with `options`; their `filename` is replaced by the path
+    /// of each file
+    pub fn new(options: TransformOptions<'o>) -> Self {
+        Self {
+            options,
+            allocator: Allocator::default(),
+        }
+    }
+
+    pub fn options(&self) -> &TransformOptions<'o> {
+        &self.options
+    }
+
+    /// Compile `source`, the contents of the file at `path`. The extension of
+    /// `path` picks the source type, and the source map refers to it.
+    pub fn transform_source(&mut self, path: impl AsRef<Path>, source: &str) -> TransformOutput {
+        let filename = path.as_ref().to_string_lossy();
+        let options = TransformOptions {
+            filename: &filename,
+            ..self.options.clone()
+        };
+        self.allocator.reset();
+        let (result, metadata, errors) = transform_in(&self.allocator, source, &options);
+        TransformOutput {
+            code: result.code,
+            map: result.map.map(|map| map.to_json_string()),
+            metadata,
+            errors,
+        }
+    }
+
+    /// Read and compile the file at `path`
+    pub fn transform_file(&mut self, path: impl AsRef<Path>) -> io::Result<TransformOutput> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path)?;
+        Ok(self.transform_source(path, &source))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GenerateMode;
+
+    #[test]
+    fn test_transform_source() {
+        let mut transformer = Transformer::new(TransformOptions {
+            source_map: true,
+            ..TransformOptions::solid_defaults()
+        });
+        let output = transformer.transform_source(
+            "src/App.tsx",
+            "const App = (props: Props) => <div onClick={props.onClick}>hi</div>;",
+        );
+        assert!(output.code.contains("template(`<div>hi</div>`)"));
+        assert!(output.map.unwrap().contains("src/App.tsx"));
+        assert_eq!(output.metadata.delegated_events, ["click"]);
+        assert!(output.errors.is_empty());
+
+        // Nothing carries over to the next file
+        let output = transformer.transform_source("src/Page.jsx", "<span>page</span>");
+        assert!(output.code.contains("template(`<span>page</span>`)"));
+        assert!(!output.code.contains("<div>hi</div>"));
+        assert!(output.metadata.delegated_events.is_empty());
+    }
+
+    #[test]
+    fn test_transform_file() {
+        let path =
+            std::env::temp_dir().join(format!("solid-transformer-{}.jsx", std::process::id()));
+        std::fs::write(&path, "export const App = () => <div>{count()}</div>;").unwrap();
+
+        let mut transformer = Transformer::new(TransformOptions {
+            generate: GenerateMode::Ssr,
+            ..TransformOptions::solid_defaults()
+        });
+        let output = transformer.transform_file(&path);
+        std::fs::remove_file(&path).unwrap();
+        let output = output.unwrap();
+        assert!(output.code.contains("escape("));
+        assert!(output.map.is_none());
+
+        let error = transformer
+            .transform_file(path.with_extension("missing.jsx"))
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,377 @@
+//! JSX import source of a file, from its pragma or the nearest tsconfig.json
+//!
+//! Monorepos mixing Solid with Preact or React compile each package's JSX
+//! with a different compiler. TypeScript already knows which one a file
+//! belongs to: a `@jsxImportSource` comment at the top of the file, or
+//! `compilerOptions.jsxImportSource` (or a React `jsx` mode) in the nearest
+//! tsconfig.json. Plugins use [`jsx_import_source`] to leave other
+//! frameworks' files alone.
+//!
+//! Only the nearest tsconfig.json is read, with the files it `extends`;
+//! solution-style configs that only list `references` leave the import
+//! source unknown. Dev servers keep an [`ImportSourceCache`], so that each
+//! directory's configs are read once until their watcher sees one change.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde_json::Value;
+
+/// How many `extends` are followed before giving up on a cycle
+const MAX_EXTENDS: usize = 16;
+
+/// `jsx` modes whose runtime is React's, when `jsxImportSource` is not set
+const REACT_JSX_MODES: [&str; 4] = ["react", "react-jsx", "react-jsxdev", "react-native"];
+
+/// The JSX import source of `source`, the contents of the file at `path`:
+/// from a `@jsxImportSource` pragma in its leading comments, then from the
+/// nearest tsconfig.json. `None` when neither says.
+pub fn jsx_import_source(path: &Path, source: Option<&str>) -> Option<String> {
+    if let Some(pragma) = source.and_then(pragma_import_source) {
+        return Some(pragma.to_string());
+    }
+    dir_import_source(path.parent()?, &mut Vec::new())
+}
+
+/// [`jsx_import_source`], remembering the answer of the nearest tsconfig.json
+/// per directory
+#[derive(Debug, Default)]
+pub struct ImportSourceCache {
+    state: Mutex<CacheState>,
+}
+
+#[derive(Debug, Default)]
+struct CacheState {
+    dirs: HashMap<PathBuf, Option<String>>,
+    /// Every config read for `dirs`, including the ones they extend
+    configs: HashSet<PathBuf>,
+}
+
+impl ImportSourceCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The JSX import source of `source`, the contents of the file at `path`,
+    /// as [`jsx_import_source`] finds it
+    pub fn jsx_import_source(&self, path: &Path, source: Option<&str>) -> Option<String> {
+        if let Some(pragma) = source.and_then(pragma_import_source) {
+            return Some(pragma.to_string());
+        }
+        let dir = path.parent()?;
+        let mut state = self.state.lock().unwrap();
+        if let Some(import_source) = state.dirs.get(dir) {
+            return import_source.clone();
+        }
+        let mut read = Vec::new();
+        let import_source = dir_import_source(dir, &mut read);
+        state
+            .configs
+            .extend(read.iter().filter_map(|config| config.canonicalize().ok()));
+        state.dirs.insert(dir.to_path_buf(), import_source.clone());
+        import_source
+    }
+
+    /// Forget every answer when `path` is a tsconfig.json, which may now be
+    /// the nearest one of a directory, or a config read before; `true` when
+    /// it was
+    pub fn invalidate(&self, path: &Path) -> bool {
+        let mut state = self.state.lock().unwrap();
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        if path.file_name().is_some_and(|name| name == "tsconfig.json")
+            || state.configs.contains(&path)
+        {
+            state.dirs.clear();
+            state.configs.clear();
+            return true;
+        }
+        false
+    }
+}
+
+/// The import source the nearest tsconfig.json of `dir` implies, adding the
+/// configs read to `read`
+fn dir_import_source(dir: &Path, read: &mut Vec<PathBuf>) -> Option<String> {
+    let tsconfig = dir
+        .ancestors()
+        .map(|dir| dir.join("tsconfig.json"))
+        .find(|tsconfig| tsconfig.is_file())?;
+    tsconfig_import_source(&tsconfig, read)
+}
+
+/// Whether `import_source` is Solid's: `solid-js`, or the package of
+/// `module_name` for custom renderers
+pub fn is_solid_import_source(import_source: &str, module_name: &str) -> bool {
+    let is_package_of = |module: &str| {
+        module == import_source
+            || module
+                .strip_prefix(import_source)
+                .is_some_and(|rest| rest.starts_with('/'))
+    };
+    is_package_of("solid-js") || is_package_of(module_name)
+}
+
+/// The `@jsxImportSource` pragma in the comments before the first token
+pub fn pragma_import_source(source: &str) -> Option<&str> {
+    let mut rest = source.trim_start();
+    loop {
+        let comment = if let Some(line) = rest.strip_prefix("//") {
+            let end = line.find('\n').unwrap_or(line.len());
+            rest = &line[end..];
+            &line[..end]
+        } else if let Some(block) = rest.strip_prefix("/*") {
+            let end = block.find("*/")?;
+            rest = &block[end + 2..];
+            &block[..end]
+        } else {
+            return None;
+        };
+        if let Some((_, after)) = comment.split_once("@jsxImportSource") {
+            return after
+                .split(|c: char| c.is_whitespace() || c == '*')
+                .find(|word| !word.is_empty());
+        }
+        rest = rest.trim_start();
+    }
+}
+
+/// The import source a tsconfig.json implies, following `extends`; the
+/// configs read are added to `read`
+fn tsconfig_import_source(tsconfig: &Path, read: &mut Vec<PathBuf>) -> Option<String> {
+    let mut jsx = None;
+    let mut import_source = None;
+    let mut pending = vec![tsconfig.to_path_buf()];
+    let mut count = 0;
+    // Depth first, nearest first: a setting is taken from the first config
+    // that has it
+    while let Some(path) = pending.pop() {
+        count += 1;
+        if count > MAX_EXTENDS {
+            break;
+        }
+        read.push(path.clone());
+        let Some(config) = read_jsonc(&path) else {
+            continue;
+        };
+        let options = &config["compilerOptions"];
+        if import_source.is_none() {
+            import_source = options["jsxImportSource"].as_str().map(String::from);
+        }
+        if jsx.is_none() {
+            jsx = options["jsx"].as_str().map(String::from);
+        }
+        if import_source.is_some() {
+            break;
+        }
+
+        let dir = path.parent().unwrap_or(Path::new("."));
+        let extends = match &config["extends"] {
+            Value::String(extends) => vec![extends.as_str()],
+            Value::Array(extends) => extends.iter().filter_map(Value::as_str).collect(),
+            _ => vec![],
+        };
+        // Later entries of an `extends` array win, so they are read first
+        pending.extend(
+            extends
+                .into_iter()
+                .filter_map(|extends| resolve_extends(dir, extends)),
+        );
+    }
+
+    import_source.or_else(|| {
+        jsx.filter(|jsx| REACT_JSX_MODES.contains(&jsx.to_ascii_lowercase().as_str()))
+            .map(|_| "react".to_string())
+    })
+}
+
+/// The file an `extends` entry of a config in `dir` points to
+fn resolve_extends(dir: &Path, extends: &str) -> Option<PathBuf> {
+    let with_extension = |path: PathBuf| {
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            path
+        } else {
+            let mut path = path.into_os_string();
+            path.push(".json");
+            PathBuf::from(path)
+        }
+    };
+    if extends.starts_with('.') || Path::new(extends).is_absolute() {
+        return Some(with_extension(dir.join(extends)));
+    }
+    // A package, such as `@tsconfig/strictest`: its tsconfig.json, or a file
+    // in it
+    dir.ancestors()
+        .map(|ancestor| ancestor.join("node_modules").join(extends))
+        .find_map(|path| {
+            if path.is_dir() {
+                Some(path.join("tsconfig.json"))
+            } else {
+                Some(with_extension(path)).filter(|path| path.is_file())
+            }
+        })
+}
+
+fn read_jsonc(path: &Path) -> Option<Value> {
+    let text = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&strip_jsonc(&text)).ok()
+}
+
+/// JSON with the comments and trailing commas TypeScript allows removed
+fn strip_jsonc(text: &str) -> String {
+    let mut json = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                json.push(c);
+                while let Some(c) = chars.next() {
+                    json.push(c);
+                    match c {
+                        '\\' => json.extend(chars.next()),
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'/') => while chars.next_if(|&c| c != '\n').is_some() {},
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = '\0';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+            }
+            '}' | ']' => {
+                let trimmed = json.trim_end().len();
+                if json[..trimmed].ends_with(',') {
+                    json.truncate(trimmed - 1);
+                }
+                json.push(c);
+            }
+            _ => json.push(c),
+        }
+    }
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pragma_import_source() {
+        assert_eq!(
+            pragma_import_source("/** @jsxImportSource preact */\nimport { h } from 'preact';"),
+            Some("preact")
+        );
+        assert_eq!(
+            pragma_import_source("// @ts-check\n/**\n * @jsxImportSource solid-js\n */\n"),
+            Some("solid-js")
+        );
+        assert_eq!(
+            pragma_import_source("const a = 1; // @jsxImportSource preact"),
+            None
+        );
+        assert!(is_solid_import_source("solid-js", "solid-js/web"));
+        assert!(is_solid_import_source("my-renderer", "my-renderer/dom"));
+        assert!(!is_solid_import_source("preact", "solid-js/web"));
+        assert!(!is_solid_import_source("solid", "solid-js/web"));
+    }
+
+    #[test]
+    fn test_tsconfig_import_source() {
+        let root = std::env::temp_dir().join(format!("solid-tsconfig-{}", std::process::id()));
+        let write = |path: &str, text: &str| {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, text).unwrap();
+        };
+        write(
+            "tsconfig.base.json",
+            r#"{
+                // Shared settings
+                "compilerOptions": { "jsx": "preserve", "jsxImportSource": "solid-js", },
+            }"#,
+        );
+        write(
+            "packages/app/tsconfig.json",
+            r#"{ "extends": "../../tsconfig.base", "compilerOptions": { "strict": true } }"#,
+        );
+        write(
+            "packages/widgets/tsconfig.json",
+            r#"{ "extends": "../../tsconfig.base.json", "compilerOptions": { "jsxImportSource": "preact" } }"#,
+        );
+        write(
+            "packages/legacy/tsconfig.json",
+            r#"{ /* "jsxImportSource": "x" */ "compilerOptions": { "jsx": "react-jsx" } }"#,
+        );
+        write(
+            "packages/plain/tsconfig.json",
+            r#"{ "files": [], "references": [] }"#,
+        );
+
+        let import_source = |file: &str, source: Option<&str>| {
+            jsx_import_source(&root.join("packages").join(file), source)
+        };
+        let solid = import_source("app/src/App.tsx", None);
+        let preact = import_source("widgets/src/Card.tsx", None);
+        let pragma = import_source(
+            "widgets/src/Solid.tsx",
+            Some("/* @jsxImportSource solid-js */"),
+        );
+        let react = import_source("legacy/Old.tsx", None);
+        let unknown = import_source("plain/A.tsx", None);
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(solid.as_deref(), Some("solid-js"));
+        assert_eq!(preact.as_deref(), Some("preact"));
+        assert_eq!(pragma.as_deref(), Some("solid-js"));
+        assert_eq!(react.as_deref(), Some("react"));
+        assert_eq!(unknown, None);
+    }
+
+    #[test]
+    fn test_import_source_cache() {
+        let root =
+            std::env::temp_dir().join(format!("solid-tsconfig-cache-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("app/src")).unwrap();
+        let base = root.join("tsconfig.base.json");
+        std::fs::write(
+            &base,
+            r#"{ "compilerOptions": { "jsxImportSource": "solid-js" } }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("app/tsconfig.json"),
+            r#"{ "extends": "../tsconfig.base.json" }"#,
+        )
+        .unwrap();
+
+        let cache = ImportSourceCache::new();
+        let file = root.join("app/src/App.tsx");
+        let before = cache.jsx_import_source(&file, None);
+        std::fs::write(
+            &base,
+            r#"{ "compilerOptions": { "jsxImportSource": "preact" } }"#,
+        )
+        .unwrap();
+        let cached = cache.jsx_import_source(&file, None);
+        let unrelated = cache.invalidate(&root.join("app/src/App.tsx"));
+        let invalidated = cache.invalidate(&base);
+        let after = cache.jsx_import_source(&file, None);
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(before.as_deref(), Some("solid-js"));
+        assert_eq!(cached.as_deref(), Some("solid-js"));
+        assert!(!unrelated);
+        assert!(invalidated);
+        assert_eq!(after.as_deref(), Some("preact"));
+    }
+}
@@ -0,0 +1,135 @@
+//! Browser bindings, behind the `wasm` feature
+//!
+//! Built for `wasm32-unknown-unknown` with wasm-bindgen, for playgrounds that
+//! show compiled output and lint results as the user types. Every function
+//! takes strings and returns a JSON string. Offsets in the output count UTF-16
+//! code units, the way JavaScript indexes strings.
+
+use oxc_span::SourceType;
+use serde_json::{json, Value};
+use solid_linter::{lint_source, Diagnostic, DiagnosticSeverity, LintConfig};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::json::{error, transform_json};
+
+/// Transform `source` with `options`, a JSON object of transform options (or
+/// an empty string for the defaults). Returns
+/// `{ code, map, delegatedEvents, templates, helpers }`, or `{ error }` when
+/// the options are invalid.
+#[wasm_bindgen]
+pub fn transform(source: &str, options: &str) -> String {
+    transform_json(source, options)
+}
+
+/// Lint `source` as `filename`, whose extension picks the parser, with
+/// `config`, the text of a `.solidlint.json` file (or an empty string for the
+/// defaults). Returns `{ diagnostics }`, or `{ error }` when the config is
+/// invalid.
+#[wasm_bindgen]
+pub fn lint(source: &str, filename: &str, config: &str) -> String {
+    let config = if config.trim().is_empty() {
+        LintConfig::default()
+    } else {
+        match LintConfig::from_json_str(config) {
+            Ok(config) => config,
+            Err(err) => return error(err),
+        }
+    };
+
+    let source_type = SourceType::from_path(filename)
+        .unwrap_or_default()
+        .with_jsx(true);
+    let config = config.for_file(std::path::Path::new(filename));
+    let diagnostics: Vec<_> = lint_source(source, source_type, &config, &[])
+        .iter()
+        .map(|diagnostic| diagnostic_json(source, diagnostic))
+        .collect();
+    json!({ "diagnostics": diagnostics }).to_string()
+}
+
+/// UTF-16 offset of a byte offset into `source`
+fn utf16_offset(source: &str, offset: u32) -> usize {
+    let offset = (offset as usize).min(source.len());
+    source
+        .get(..offset)
+        .unwrap_or(source)
+        .encode_utf16()
+        .count()
+}
+
+fn diagnostic_json(source: &str, diagnostic: &Diagnostic) -> Value {
+    let edits = |fixes: &[solid_linter::Fix]| -> Vec<Value> {
+        fixes
+            .iter()
+            .map(|fix| {
+                json!({
+                    "start": utf16_offset(source, fix.start),
+                    "end": utf16_offset(source, fix.end),
+                    "replacement": fix.replacement,
+                })
+            })
+            .collect()
+    };
+    let severity = match diagnostic.severity {
+        DiagnosticSeverity::Error => "error",
+        DiagnosticSeverity::Warning => "warning",
+        DiagnosticSeverity::Info => "info",
+        DiagnosticSeverity::Hint => "hint",
+    };
+    json!({
+        "rule": diagnostic.rule,
+        "severity": severity,
+        "message": diagnostic.message,
+        "help": diagnostic.help,
+        "url": diagnostic.url,
+        "start": utf16_offset(source, diagnostic.start),
+        "end": utf16_offset(source, diagnostic.end),
+        "fix": edits(&diagnostic.fixes),
+        "suggestions": diagnostic
+            .suggestions
+            .iter()
+            .map(|suggestion| {
+                json!({ "message": suggestion.message, "edits": edits(&suggestion.fixes) })
+            })
+            .collect::<Vec<_>>(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform() {
+        let output: Value =
+            serde_json::from_str(&transform("<div onClick={a}>hi</div>", "")).unwrap();
+        assert_eq!(output["delegatedEvents"], json!(["click"]));
+        assert_eq!(output["templates"], json!(["<div>hi</div>"]));
+        assert_eq!(output["map"], Value::Null);
+
+        let output: Value =
+            serde_json::from_str(&transform("<div />", r#"{ "genrate": "ssr" }"#)).unwrap();
+        assert!(output["error"].as_str().unwrap().contains("genrate"));
+    }
+
+    #[test]
+    fn test_lint() {
+        // `é` is two bytes but one UTF-16 code unit
+        let source = "const é = <div className=\"a\" />;";
+        let output: Value = serde_json::from_str(&lint(source, "a.jsx", "")).unwrap();
+        let diagnostic = &output["diagnostics"][0];
+        assert_eq!(diagnostic["rule"], "no-react-specific-props");
+        assert_eq!(diagnostic["start"], 15);
+        assert_eq!(diagnostic["fix"][0]["replacement"], "class");
+
+        let config = r#"{ "rules": { "no-react-specific-props": "off" } }"#;
+        let output: Value = serde_json::from_str(&lint(source, "a.jsx", config)).unwrap();
+        assert_eq!(output["diagnostics"], json!([]));
+
+        let output: Value = serde_json::from_str(&lint("<div", "a.jsx", "")).unwrap();
+        assert_eq!(
+            output["diagnostics"][0]["rule"],
+            solid_linter::PARSE_ERROR_RULE
+        );
+    }
+}
@@ -0,0 +1,140 @@
+//! Snapshots of the upstream dom-expressions fixtures
+//!
+//! Every `code.js` under the fixture directories of
+//! `babel-plugin-jsx-dom-expressions` is compiled with the options of the
+//! matching Babel spec, and the output is kept as an insta snapshot named
+//! after the variant and the fixture, e.g. `dom__simpleElements`. A change in
+//! the output of one fixture shows up as a diff of that snapshot only.
+//!
+//! Each variant also has a `parity` snapshot counting, per fixture, the
+//! top-level statements of the upstream `output.js` that this crate's output
+//! has too. Both are printed by the same code generator first, and generated
+//! names (`_el$3`, `_tmpl$2`, Babel's `_$insert` imports) are renumbered, so
+//! that only differences in what the code does remain. Closing a gap with
+//! Babel changes that snapshot, so the progress is part of the diff that makes
+//! it.
+//!
+//! Review changed snapshots with `cargo insta review`, or accept them all with
+//! `INSTA_UPDATE=always cargo test --test fixtures`.
+
+mod support;
+
+use std::fmt::Write;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+use solid_jsx_oxc::{transform, GenerateMode, TransformOptions};
+use support::{spec_options, statements};
+
+/// What became of one fixture
+enum Outcome {
+    /// How many statements of `output.js` the output has, of how many
+    Statements(usize, usize),
+    /// The transform panicked
+    Panics,
+}
+
+fn fixtures_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../babel-plugin-jsx-dom-expressions/test")
+}
+
+fn run_variant(variant: &str, dir: &str, options: TransformOptions) {
+    let dir = fixtures_root().join(dir);
+    if !dir.is_dir() {
+        eprintln!("skipping {variant}: no fixtures at {}", dir.display());
+        return;
+    }
+    let mut fixtures: Vec<PathBuf> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.join("code.js").is_file())
+        .collect();
+    fixtures.sort();
+
+    let mut settings = insta::Settings::clone_current();
+    settings.set_snapshot_path("snapshots/fixtures");
+    settings.set_prepend_module_to_snapshot(false);
+    settings.set_omit_expression(true);
+
+    let mut outcomes = Vec::with_capacity(fixtures.len());
+    for fixture in &fixtures {
+        let name = fixture.file_name().unwrap().to_string_lossy().into_owned();
+        let source = fs::read_to_string(fixture.join("code.js")).unwrap();
+        let compiled = panic::catch_unwind(AssertUnwindSafe(|| {
+            transform(&source, Some(options.clone())).code
+        }));
+        let (snapshot, outcome) = match compiled {
+            Ok(code) => {
+                let expected = fs::read_to_string(fixture.join("output.js")).unwrap_or_default();
+                let expected = statements(&expected);
+                let mut actual = statements(&code);
+                let found = expected
+                    .iter()
+                    .filter(|statement| {
+                        let index = actual.iter().position(|a| a == *statement);
+                        index.map(|index| actual.swap_remove(index)).is_some()
+                    })
+                    .count();
+                (code, Outcome::Statements(found, expected.len()))
+            }
+            Err(_) => ("// the transform panicked\n".to_string(), Outcome::Panics),
+        };
+        settings.set_input_file(fixture.join("code.js"));
+        settings.bind(|| insta::assert_snapshot!(format!("{variant}__{name}"), snapshot));
+        outcomes.push((name, outcome));
+    }
+
+    let matching = outcomes
+        .iter()
+        .filter(|(_, outcome)| matches!(outcome, Outcome::Statements(found, all) if found == all))
+        .count();
+    let mut parity = format!("{matching}/{} fixtures match Babel\n\n", outcomes.len());
+    for (name, outcome) in &outcomes {
+        match outcome {
+            Outcome::Statements(found, all) => {
+                let _ = writeln!(parity, "{name}: {found}/{all} statements");
+            }
+            Outcome::Panics => {
+                let _ = writeln!(parity, "{name}: panics");
+            }
+        }
+    }
+    settings.bind(|| insta::assert_snapshot!(format!("{variant}__parity"), parity));
+}
+
+#[test]
+fn test_dom_fixtures() {
+    run_variant(
+        "dom",
+        "__dom_fixtures__",
+        spec_options(GenerateMode::Dom, false),
+    );
+}
+
+#[test]
+fn test_dom_hydratable_fixtures() {
+    run_variant(
+        "dom_hydratable",
+        "__dom_hydratable_fixtures__",
+        spec_options(GenerateMode::Dom, true),
+    );
+}
+
+#[test]
+fn test_ssr_fixtures() {
+    run_variant(
+        "ssr",
+        "__ssr_fixtures__",
+        spec_options(GenerateMode::Ssr, false),
+    );
+}
+
+#[test]
+fn test_ssr_hydratable_fixtures() {
+    run_variant(
+        "ssr_hydratable",
+        "__ssr_hydratable_fixtures__",
+        spec_options(GenerateMode::Ssr, true),
+    );
+}
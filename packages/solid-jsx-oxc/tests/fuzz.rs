@@ -0,0 +1,468 @@
+//! Random JSX through the transform
+//!
+//! A seeded generator builds random modules of JSX: nested elements,
+//! components, fragments, spreads, namespaced attributes, odd text and
+//! expressions in every position. Each module is compiled in every output
+//! mode, and the transform must neither panic nor emit code that does not
+//! parse.
+//!
+//! `SOLID_FUZZ_CASES` sets how many modules to try (the default keeps
+//! `cargo test` fast), and `SOLID_FUZZ_SEED` replays the module of one seed
+//! reported by a failure.
+
+use std::fmt::Write;
+use std::panic::{self, AssertUnwindSafe};
+
+use common::GenerateMode;
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use solid_jsx_oxc::{transform, TransformOptions};
+
+const DEFAULT_CASES: u64 = 300;
+const MAX_DEPTH: u32 = 4;
+
+/// xorshift64*, enough randomness for building trees and the same on every
+/// platform
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        // Any seed but 0 works; spread small seeds over the state
+        Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn chance(&mut self, percent: usize) -> bool {
+        self.below(100) < percent
+    }
+
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.below(items.len())]
+    }
+}
+
+const HTML_TAGS: &[&str] = &[
+    "div", "span", "p", "a", "ul", "li", "button", "table", "tbody", "tr", "td", "label", "select",
+    "option", "template", "slot", "textarea", "style", "noscript",
+];
+const VOID_TAGS: &[&str] = &["input", "br", "img", "hr"];
+const SVG_TAGS: &[&str] = &["svg", "g", "path", "rect", "text", "foreignObject"];
+const OTHER_TAGS: &[&str] = &["my-element", "svg:rect", "math", "x-list"];
+const COMPONENTS: &[&str] = &[
+    "Comp",
+    "Foo.Bar",
+    "For",
+    "Show",
+    "Index",
+    "Switch",
+    "Match",
+    "Portal",
+    "Dynamic",
+    "ErrorBoundary",
+    "Suspense",
+];
+const ATTR_NAMES: &[&str] = &[
+    "id",
+    "class",
+    "className",
+    "title",
+    "value",
+    "checked",
+    "disabled",
+    "href",
+    "for",
+    "htmlFor",
+    "data-id",
+    "aria-label",
+    "tabIndex",
+    "readonly",
+    "innerHTML",
+    "textContent",
+    "innerText",
+    "style",
+    "classList",
+    "ref",
+    "onClick",
+    "onInput",
+    "onMouseMove",
+    "onClickCapture",
+    "on:custom",
+    "oncapture:focus",
+    "use:tooltip",
+    "prop:value",
+    "attr:foo",
+    "bool:hidden",
+    "class:active",
+    "style:color",
+    "xlink:href",
+    "each",
+    "when",
+    "fallback",
+    "children",
+    "component",
+];
+const TEXTS: &[&str] = &[
+    "hello",
+    " spaced out ",
+    "\n    multi\n    line\n  ",
+    "a &amp; b",
+    "&lt;tag&gt;",
+    "&nbsp;",
+    "émoji 🎉",
+    "quote \" and ' marks",
+    "back`tick $not",
+    "\\ slash",
+    "  ",
+    "日本語",
+    "&#123;",
+    "a\tb",
+];
+/// Attribute values, quotes included
+const STRINGS: &[&str] = &[
+    "\"plain\"",
+    "\"\"",
+    "'say \"hi\"'",
+    "\"it's\"",
+    "\"a & b < c\"",
+    "\"${x}\"",
+    "\"`\"",
+    "\"back\\slash\\n\"",
+    "\"🎉\"",
+    "\"&amp; &#x27;\"",
+    "\"color: red; width: 10px\"",
+    "\"multi\nline\"",
+];
+const EXPRESSIONS: &[&str] = &[
+    "a",
+    "a()",
+    "props.value",
+    "props.items[0]",
+    "state.count + 1",
+    "count() > 1 ? \"many\" : \"one\"",
+    "ok() && value()",
+    "(() => x)()",
+    "null",
+    "undefined",
+    "42",
+    "`t${a}`",
+    "\"string\"",
+    "signal",
+    "/*@once*/ props.once",
+    "obj?.deep?.value",
+    "x = 1",
+    "[a, b]",
+    "{ a: 1 }",
+    "a as string",
+    "b!",
+];
+
+/// Builds one random module
+struct Generator {
+    rng: Rng,
+    out: String,
+}
+
+impl Generator {
+    fn module(seed: u64) -> String {
+        let mut generator = Generator {
+            rng: Rng::new(seed),
+            out: String::new(),
+        };
+        let statements = 1 + generator.rng.below(3);
+        for i in 0..statements {
+            match generator.rng.below(4) {
+                0 => {
+                    let _ = write!(generator.out, "export const App{i} = (props) => ");
+                    generator.node(0);
+                    generator.out.push_str(";\n");
+                }
+                1 => {
+                    let _ = write!(
+                        generator.out,
+                        "function View{i}(props) {{\n  const local = props.x;\n  return "
+                    );
+                    generator.node(0);
+                    generator.out.push_str(";\n}\n");
+                }
+                _ => {
+                    let _ = write!(generator.out, "const view{i} = ");
+                    generator.node(0);
+                    generator.out.push_str(";\n");
+                }
+            }
+        }
+        generator.out
+    }
+
+    /// An element, a component or a fragment
+    fn node(&mut self, depth: u32) {
+        match self.rng.below(10) {
+            0 => self.fragment(depth),
+            1 | 2 => {
+                let name = self.rng.pick(COMPONENTS);
+                self.element(name, false, depth);
+            }
+            3 => {
+                let name = self.rng.pick(VOID_TAGS);
+                self.element(name, true, depth);
+            }
+            4 => {
+                let name = self.rng.pick(SVG_TAGS);
+                self.element(name, false, depth);
+            }
+            5 => {
+                let name = self.rng.pick(OTHER_TAGS);
+                self.element(name, false, depth);
+            }
+            _ => {
+                let name = self.rng.pick(HTML_TAGS);
+                self.element(name, false, depth);
+            }
+        }
+    }
+
+    fn fragment(&mut self, depth: u32) {
+        self.out.push_str("<>");
+        self.children(depth);
+        self.out.push_str("</>");
+    }
+
+    fn element(&mut self, name: &str, void: bool, depth: u32) {
+        self.out.push('<');
+        self.out.push_str(name);
+        for _ in 0..self.rng.below(5) {
+            self.out.push(' ');
+            self.attribute(depth);
+        }
+        if void || depth >= MAX_DEPTH || self.rng.chance(20) {
+            self.out.push_str(" />");
+            return;
+        }
+        self.out.push('>');
+        self.children(depth);
+        self.out.push_str("</");
+        self.out.push_str(name);
+        self.out.push('>');
+    }
+
+    fn attribute(&mut self, depth: u32) {
+        if self.rng.chance(8) {
+            let spread = self.rng.pick(&["props", "others", "{ a: 1 }", "rest()"]);
+            let _ = write!(self.out, "{{...{spread}}}");
+            return;
+        }
+        let name = self.rng.pick(ATTR_NAMES);
+        self.out.push_str(name);
+        match self.rng.below(8) {
+            0 => {}
+            1 | 2 => {
+                self.out.push('=');
+                self.out.push_str(self.rng.pick(STRINGS));
+            }
+            3 => {
+                let object = self.rng.pick(&[
+                    "{ color: \"red\", fontSize: 12 }",
+                    "{ active: isActive(), \"two words\": true }",
+                    "{ [key]: value }",
+                    "{ ...rest }",
+                    "{}",
+                ]);
+                let _ = write!(self.out, "={{{object}}}");
+            }
+            4 if depth < MAX_DEPTH => {
+                self.out.push_str("={");
+                self.node(depth + 1);
+                self.out.push('}');
+            }
+            5 => {
+                let handler = self.rng.pick(&[
+                    "(e) => go(e)",
+                    "[handler, data]",
+                    "function () {}",
+                    "(el) => (ref = el)",
+                    "ref",
+                    "props.ref",
+                ]);
+                let _ = write!(self.out, "={{{handler}}}");
+            }
+            _ => {
+                let expression = self.expression(depth);
+                let _ = write!(self.out, "={{{expression}}}");
+            }
+        }
+    }
+
+    fn children(&mut self, depth: u32) {
+        for _ in 0..self.rng.below(5) {
+            match self.rng.below(8) {
+                0 | 1 => self.out.push_str(self.rng.pick(TEXTS)),
+                2 | 3 => {
+                    let expression = self.expression(depth);
+                    let _ = write!(self.out, "{{{expression}}}");
+                }
+                4 => self.out.push_str(self.rng.pick(&[
+                    "{/* comment */}",
+                    "{}",
+                    "{\"literal\"}",
+                    "{`template ${x}`}",
+                    "{...children}",
+                ])),
+                _ if depth < MAX_DEPTH => self.node(depth + 1),
+                _ => self.out.push_str("text"),
+            }
+        }
+    }
+
+    /// An expression, sometimes holding more JSX
+    fn expression(&mut self, depth: u32) -> String {
+        if depth >= MAX_DEPTH || self.rng.chance(60) {
+            return self.rng.pick(EXPRESSIONS).to_string();
+        }
+        // JSX inside the expression goes to a buffer of its own
+        let outer = std::mem::take(&mut self.out);
+        match self.rng.below(4) {
+            0 => {
+                self.out.push_str("cond() ? ");
+                self.node(depth + 1);
+                self.out.push_str(" : ");
+                self.node(depth + 1);
+            }
+            1 => {
+                self.out.push_str("show && ");
+                self.node(depth + 1);
+            }
+            2 => {
+                self.out.push_str("list().map((item, i) => ");
+                self.node(depth + 1);
+                self.out.push(')');
+            }
+            _ => {
+                self.out.push_str("(item) => ");
+                self.node(depth + 1);
+            }
+        }
+        std::mem::replace(&mut self.out, outer)
+    }
+}
+
+fn modes() -> [(&'static str, TransformOptions<'static>); 4] {
+    [
+        ("dom", TransformOptions::solid_defaults()),
+        (
+            "dom hydratable",
+            TransformOptions {
+                hydratable: true,
+                ..TransformOptions::solid_defaults()
+            },
+        ),
+        (
+            "ssr",
+            TransformOptions {
+                generate: GenerateMode::Ssr,
+                ..TransformOptions::solid_defaults()
+            },
+        ),
+        (
+            "ssr hydratable",
+            TransformOptions {
+                generate: GenerateMode::Ssr,
+                hydratable: true,
+                ..TransformOptions::solid_defaults()
+            },
+        ),
+    ]
+}
+
+/// Why the module of a seed failed, if it did
+fn check(seed: u64) -> Result<(), String> {
+    let source = Generator::module(seed);
+    let allocator = Allocator::default();
+    let parsed = Parser::new(&allocator, &source, SourceType::tsx()).parse();
+    if !parsed.errors.is_empty() {
+        return Err(format!(
+            "the generator wrote invalid JSX: {:?}\n{source}",
+            parsed.errors[0].message
+        ));
+    }
+
+    for (mode, options) in modes() {
+        let options = TransformOptions {
+            filename: "fuzz.tsx",
+            ..options
+        };
+        let code = panic::catch_unwind(AssertUnwindSafe(|| transform(&source, Some(options))))
+            .map_err(|payload| {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_default();
+                format!("the {mode} transform panicked: {message}\n{source}")
+            })?
+            .code;
+        let output = Parser::new(&allocator, &code, SourceType::tsx()).parse();
+        if let Some(error) = output.errors.first() {
+            return Err(format!(
+                "the {mode} output does not parse: {:?}\n--- input\n{source}\n--- output\n{code}",
+                error.message
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_random_jsx_compiles() {
+    let seeds: Vec<u64> = match std::env::var("SOLID_FUZZ_SEED") {
+        Ok(seed) => vec![seed.parse().expect("SOLID_FUZZ_SEED is a number")],
+        Err(_) => {
+            let cases = std::env::var("SOLID_FUZZ_CASES")
+                .ok()
+                .and_then(|cases| cases.parse().ok())
+                .unwrap_or(DEFAULT_CASES);
+            (0..cases).collect()
+        }
+    };
+
+    // Panics are caught and reported with their seed; the default hook would
+    // print each one again
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let failures: Vec<(u64, String)> = seeds
+        .into_iter()
+        .filter_map(|seed| check(seed).err().map(|error| (seed, error)))
+        .collect();
+    panic::set_hook(hook);
+
+    let Some((seed, error)) = failures.first() else {
+        return;
+    };
+    // Each kind of failure once, with the first seed that shows it
+    let mut kinds: Vec<(&str, u64, usize)> = Vec::new();
+    for (seed, error) in &failures {
+        let kind = error.lines().next().unwrap_or_default();
+        match kinds.iter_mut().find(|(k, _, _)| *k == kind) {
+            Some((_, _, count)) => *count += 1,
+            None => kinds.push((kind, *seed, 1)),
+        }
+    }
+    let mut summary = String::new();
+    for (kind, seed, count) in kinds {
+        let _ = writeln!(summary, "  {count} x {kind} (SOLID_FUZZ_SEED={seed})");
+    }
+    panic!(
+        "{} of the random modules failed:\n{summary}\nthe first, SOLID_FUZZ_SEED={seed}: {error}",
+        failures.len()
+    );
+}
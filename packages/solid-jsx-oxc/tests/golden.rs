@@ -0,0 +1,62 @@
+//! Golden output of the SSR and hydratable modes
+//!
+//! Each file in `tests/golden` gathers the cases of one concern of server
+//! rendering and hydration: escaping, hydration keys, markers, spreads and
+//! streaming boundaries. Every file is compiled for SSR, hydratable SSR and
+//! hydratable DOM, and each output is kept as an insta snapshot in
+//! `tests/snapshots/golden`, named after the mode and the file, e.g.
+//! `ssr_hydratable__markers`. They are kept apart from the snapshots of the
+//! upstream fixtures, which mostly cover the DOM output.
+//!
+//! Review changed snapshots with `cargo insta review`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use common::GenerateMode;
+use solid_jsx_oxc::{transform, TransformOptions};
+
+fn run_mode(mode: &str, generate: GenerateMode, hydratable: bool) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+    let mut files: Vec<PathBuf> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "jsx"))
+        .collect();
+    files.sort();
+    assert!(!files.is_empty(), "no golden inputs in {}", dir.display());
+
+    let mut settings = insta::Settings::clone_current();
+    settings.set_snapshot_path("snapshots/golden");
+    settings.set_prepend_module_to_snapshot(false);
+    settings.set_omit_expression(true);
+
+    for file in &files {
+        let name = file.file_stem().unwrap().to_str().unwrap();
+        let source = fs::read_to_string(file).unwrap();
+        let options = TransformOptions {
+            generate,
+            hydratable,
+            filename: file.file_name().unwrap().to_str().unwrap(),
+            ..TransformOptions::solid_defaults()
+        };
+        let code = transform(&source, Some(options)).code;
+        settings.set_input_file(file);
+        settings.bind(|| insta::assert_snapshot!(format!("{mode}__{name}"), code));
+    }
+}
+
+#[test]
+fn test_ssr_golden() {
+    run_mode("ssr", GenerateMode::Ssr, false);
+}
+
+#[test]
+fn test_ssr_hydratable_golden() {
+    run_mode("ssr_hydratable", GenerateMode::Ssr, true);
+}
+
+#[test]
+fn test_dom_hydratable_golden() {
+    run_mode("dom_hydratable", GenerateMode::Dom, true);
+}
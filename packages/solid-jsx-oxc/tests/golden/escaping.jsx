@@ -0,0 +1,21 @@
+const text = <p>Fish & chips <b>cost</b> "quoted" 'single'</p>;
+
+const entities = <p>&lt;b&gt; stays text &amp; so does this &copy; &#169;</p>;
+
+const attributes = (
+  <a href="/search?q=a&b=c" title={`"${title}" & <more>`} data-quote='say "hi"' data-value={value} />
+);
+
+const interpolated = (
+  <div>
+    {user.name} & {"<script>alert(1)</script>"} {`<${tag}>`} {count}
+  </div>
+);
+
+const styles = <div style={{ content: '"quoted"', "background-image": `url(${src})` }} />;
+
+const raw = <div innerHTML={"<b>raw</b>" + html} />;
+
+const textContent = <div textContent={"<b>" + label} />;
+
+const script = <script>{"window.__DATA__ = " + JSON.stringify(data)}</script>;
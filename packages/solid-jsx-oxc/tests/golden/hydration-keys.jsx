@@ -0,0 +1,44 @@
+const element = <div id="root">static</div>;
+
+const nested = (
+  <main>
+    <header>{title()}</header>
+    <section>
+      <h1>{heading}</h1>
+      <p>{body()}</p>
+    </section>
+  </main>
+);
+
+const fragment = (
+  <>
+    <div>{first()}</div>
+    <div>{second()}</div>
+  </>
+);
+
+const component = (
+  <Layout title={title()}>
+    <div>{content()}</div>
+    <Footer />
+  </Layout>
+);
+
+const list = (
+  <ul>
+    <For each={items()}>{item => <li>{item.name}</li>}</For>
+  </ul>
+);
+
+function App() {
+  return (
+    <html>
+      <head>
+        <title>{title()}</title>
+      </head>
+      <body>
+        <div id="app">{children()}</div>
+      </body>
+    </html>
+  );
+}
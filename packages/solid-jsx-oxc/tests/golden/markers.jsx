@@ -0,0 +1,26 @@
+const single = <div>{value()}</div>;
+
+const between = <div>before {value()} after</div>;
+
+const siblings = (
+  <div>
+    {first()}
+    {second()}
+    <span />
+    {third()}
+  </div>
+);
+
+const conditional = <div>{visible() ? <span>shown</span> : "hidden"}</div>;
+
+const logical = <div>{visible() && <span>shown</span>}</div>;
+
+const componentChild = (
+  <div>
+    <Child />
+    text
+    <Child />
+  </div>
+);
+
+const onceMarked = <div>{/*@once*/ value()}</div>;
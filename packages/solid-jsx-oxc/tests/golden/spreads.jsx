@@ -0,0 +1,19 @@
+const element = <div {...props} />;
+
+const withAttributes = <div id="a" {...props} class={className()} />;
+
+const withChildren = (
+  <div {...props}>
+    <span>{child()}</span>
+  </div>
+);
+
+const multiple = <input {...first} type="text" {...second} disabled={disabled()} />;
+
+const component = <Child {...props} name={name()} />;
+
+const componentWithChildren = (
+  <Child {...props} {...other}>
+    <p>content</p>
+  </Child>
+);
@@ -0,0 +1,40 @@
+const suspense = (
+  <Suspense fallback={<div class="loading">Loading...</div>}>
+    <Profile user={user()} />
+    <p>{details()}</p>
+  </Suspense>
+);
+
+const nested = (
+  <Suspense fallback={<Spinner />}>
+    <Header />
+    <Suspense fallback={<p>Loading posts...</p>}>
+      <Posts />
+    </Suspense>
+  </Suspense>
+);
+
+const errorBoundary = (
+  <ErrorBoundary fallback={error => <p>Error: {error.message}</p>}>
+    <Suspense>
+      <Data />
+    </Suspense>
+  </ErrorBoundary>
+);
+
+const noHydration = (
+  <NoHydration>
+    <footer>{year()}</footer>
+  </NoHydration>
+);
+
+const list = (
+  <SuspenseList revealOrder="forwards">
+    <Suspense fallback={<p>1</p>}>
+      <One />
+    </Suspense>
+    <Suspense fallback={<p>2</p>}>
+      <Two />
+    </Suspense>
+  </SuspenseList>
+);
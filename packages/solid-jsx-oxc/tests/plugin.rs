@@ -0,0 +1,71 @@
+//! `SolidPlugin` driven the way a Rust bundler's `transform` hook drives it
+//!
+//! The hook gets a module id, its code and whether it is built for the
+//! server, and hands back code and a source map, or nothing to leave the
+//! module to other plugins. Modules are picked by extension and by their JSX
+//! import source, from a `@jsxImportSource` comment or the nearest
+//! tsconfig.json.
+
+use std::fs;
+use std::path::Path;
+
+use solid_jsx_oxc::plugin::{PluginOptions, SolidPlugin};
+
+/// A bundler's `transform` hook forwarding to `plugin`
+fn transform_hook(
+    plugin: &SolidPlugin,
+    id: &str,
+    code: &str,
+    ssr: bool,
+) -> Option<(String, Option<String>)> {
+    let (output, _) = plugin.transform_module(id, code, ssr)?;
+    Some((output.code, output.map.map(|map| map.to_json_string())))
+}
+
+fn write(root: &Path, path: &str, text: &str) -> String {
+    let path = root.join(path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, text).unwrap();
+    path.to_str().unwrap().to_string()
+}
+
+#[test]
+fn test_transform_hook() {
+    let root = std::env::temp_dir().join(format!("solid-plugin-hook-{}", std::process::id()));
+    let code = "export const A = () => <div onClick={a}>hi</div>;";
+    write(
+        &root,
+        "app/tsconfig.json",
+        r#"{ "compilerOptions": { "jsx": "preserve", "jsxImportSource": "solid-js" } }"#,
+    );
+    write(
+        &root,
+        "widgets/tsconfig.json",
+        r#"{ "compilerOptions": { "jsxImportSource": "preact" } }"#,
+    );
+    let app = write(&root, "app/src/App.tsx", code);
+    let widget = write(&root, "widgets/src/Widget.tsx", code);
+    let util = write(&root, "app/src/util.ts", code);
+
+    let plugin = SolidPlugin::new(PluginOptions {
+        tsconfig: true,
+        ..PluginOptions::default()
+    });
+
+    let (client, map) = transform_hook(&plugin, &format!("{app}?v=1a2b"), code, false).unwrap();
+    assert!(client.contains("template(`<div>hi</div>`)"));
+    assert!(map.is_some_and(|map| map.contains("App.tsx")));
+    let (server, _) = transform_hook(&plugin, &app, code, true).unwrap();
+    assert!(!server.contains("template("));
+
+    // Other extensions, virtual modules and other frameworks' JSX are left alone
+    assert!(transform_hook(&plugin, &util, code, false).is_none());
+    assert!(transform_hook(&plugin, "\0virtual:App.tsx", code, false).is_none());
+    assert!(transform_hook(&plugin, &widget, code, false).is_none());
+    let pragma = format!("/** @jsxImportSource solid-js */\n{code}");
+    assert!(transform_hook(&plugin, &widget, &pragma, false).is_some());
+    let pragma = format!("/** @jsxImportSource preact */\n{code}");
+    assert!(transform_hook(&plugin, &app, &pragma, false).is_none());
+
+    fs::remove_dir_all(&root).unwrap();
+}
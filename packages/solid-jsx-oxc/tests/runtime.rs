@@ -0,0 +1,86 @@
+//! Generated DOM code, run against solid-js/web
+//!
+//! Every case in `tests/runtime/cases` is compiled in DOM mode and then run
+//! by `tests/runtime/harness.mjs` under node, in a jsdom document. A case
+//! renders its default export and asserts in its `check` export what was
+//! rendered, and how clicks, input and signal updates change it, so that the
+//! output does not only compile but also works with the real runtime.
+//!
+//! The compiled cases are written to cargo's temporary directory for
+//! integration tests and import `solid-js` from there, so `solid-js` and
+//! `jsdom` have to be installed in a `node_modules` above it, as `bun install`
+//! in the repository does.
+//!
+//! The cases only run with `SOLID_RUNTIME_TESTS` set, as in CI; then a
+//! missing node or package fails the test instead of skipping it.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use solid_jsx_oxc::{transform, TransformOptions};
+
+fn runtime_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/runtime")
+}
+
+/// Compile every case into `out_dir`, returning how many there are
+fn compile_cases(out_dir: &Path) -> usize {
+    let mut cases: Vec<PathBuf> = fs::read_dir(runtime_dir().join("cases"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "jsx"))
+        .collect();
+    cases.sort();
+
+    for case in &cases {
+        let source = fs::read_to_string(case).unwrap();
+        let filename = case.file_name().unwrap().to_str().unwrap();
+        let options = TransformOptions {
+            filename,
+            ..TransformOptions::solid_defaults()
+        };
+        let code = transform(&source, Some(options)).code;
+        fs::write(out_dir.join(filename).with_extension("mjs"), code).unwrap();
+    }
+    cases.len()
+}
+
+#[test]
+fn test_runtime_cases() {
+    if std::env::var_os("SOLID_RUNTIME_TESTS").is_none() {
+        eprintln!("skipping runtime cases: SOLID_RUNTIME_TESTS is not set");
+        return;
+    }
+
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("runtime");
+    let _ = fs::remove_dir_all(&out_dir);
+    fs::create_dir_all(&out_dir).unwrap();
+    let cases = compile_cases(&out_dir);
+
+    // The browser build of solid-js/web, not the one for servers
+    let output = Command::new("node")
+        .arg("--conditions=browser")
+        .arg(runtime_dir().join("harness.mjs"))
+        .arg(&out_dir)
+        .output()
+        .unwrap_or_else(|error| panic!("cannot run node: {error}"));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if let Some(reason) = stdout.strip_prefix("skip: ") {
+        panic!(
+            "cannot run the runtime cases, run `bun install` first: {}",
+            reason.trim_end()
+        );
+    }
+
+    let passed = stdout
+        .lines()
+        .filter(|line| line.starts_with("ok "))
+        .count();
+    assert!(
+        output.status.success() && passed == cases,
+        "{passed} of {cases} runtime cases passed\n{stdout}{stderr}\ncompiled cases are in {}",
+        out_dir.display()
+    );
+}
@@ -0,0 +1,34 @@
+import assert from "node:assert/strict";
+import { createSignal } from "solid-js";
+
+const [active, setActive] = createSignal(false);
+
+export default function App() {
+  return (
+    <div>
+      <a id="link" href={active() ? "/on" : "/off"} title="static" />
+      <span id="class" class={active() ? "on" : "off"} />
+      <span id="list" class="base" classList={{ selected: active(), idle: !active() }} />
+      <span id="style" style={{ color: active() ? "red" : "blue", "margin-top": "2px" }} />
+      <input id="checkbox" type="checkbox" checked={active()} />
+    </div>
+  );
+}
+
+export function check({ find }) {
+  assert.equal(find("#link").getAttribute("href"), "/off");
+  assert.equal(find("#link").getAttribute("title"), "static");
+  assert.equal(find("#class").className, "off");
+  assert.equal(find("#list").className, "base idle");
+  assert.equal(find("#style").style.color, "blue");
+  assert.equal(find("#style").style.marginTop, "2px");
+  assert.equal(find("#checkbox").checked, false);
+
+  setActive(true);
+  assert.equal(find("#link").getAttribute("href"), "/on");
+  assert.equal(find("#class").className, "on");
+  assert.equal(find("#list").className, "base selected");
+  assert.equal(find("#style").style.color, "red");
+  assert.equal(find("#checkbox").checked, true);
+  setActive(false);
+}
@@ -0,0 +1,41 @@
+import assert from "node:assert/strict";
+import { createSignal } from "solid-js";
+
+const [name, setName] = createSignal("World");
+
+function Greeting(props) {
+  return (
+    <p class={props.class}>
+      Hello, {props.name}! {props.children}
+    </p>
+  );
+}
+
+function Card(props) {
+  return (
+    <section>
+      <h2>{props.title}</h2>
+      {props.children}
+    </section>
+  );
+}
+
+export default function App() {
+  return (
+    <Card title="Greetings">
+      <Greeting name={name()} class="greeting">
+        <em>Welcome</em>
+      </Greeting>
+    </Card>
+  );
+}
+
+export function check({ find, html }) {
+  assert.equal(
+    html(),
+    '<section><h2>Greetings</h2><p class="greeting">Hello, World! <em>Welcome</em></p></section>'
+  );
+  setName("Solid");
+  assert.equal(find("p").textContent, "Hello, Solid! Welcome");
+  setName("World");
+}
@@ -0,0 +1,31 @@
+import assert from "node:assert/strict";
+import { createSignal, For, Show } from "solid-js";
+
+const [items, setItems] = createSignal(["a", "b"]);
+const [visible, setVisible] = createSignal(false);
+
+export default function App() {
+  return (
+    <>
+      <ul>
+        <For each={items()}>{(item, index) => <li>{index()}: {item}</li>}</For>
+      </ul>
+      <Show when={visible()} fallback={<p>Hidden</p>}>
+        <p>Shown</p>
+      </Show>
+      {visible() && <span>also shown</span>}
+    </>
+  );
+}
+
+export function check({ html }) {
+  assert.equal(html(), "<ul><li>0: a</li><li>1: b</li></ul><p>Hidden</p>");
+  setItems(["c", "a", "b"]);
+  setVisible(true);
+  assert.equal(
+    html(),
+    "<ul><li>0: c</li><li>1: a</li><li>2: b</li></ul><p>Shown</p><span>also shown</span>"
+  );
+  setItems(["a", "b"]);
+  setVisible(false);
+}
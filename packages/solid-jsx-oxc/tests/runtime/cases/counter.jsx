@@ -0,0 +1,19 @@
+import assert from "node:assert/strict";
+import { createSignal } from "solid-js";
+
+export default function App() {
+  const [count, setCount] = createSignal(0);
+  return (
+    <div>
+      <button onClick={() => setCount(count() + 1)}>Add</button>
+      <p>Clicked {count()} times</p>
+    </div>
+  );
+}
+
+export function check({ text, click }) {
+  assert.equal(text(), "AddClicked 0 times");
+  click("button");
+  click("button");
+  assert.equal(text(), "AddClicked 2 times");
+}
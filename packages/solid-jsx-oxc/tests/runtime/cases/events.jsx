@@ -0,0 +1,27 @@
+import assert from "node:assert/strict";
+import { createSignal } from "solid-js";
+
+export default function App() {
+  const [log, setLog] = createSignal([]);
+  const push = entry => setLog([...log(), entry]);
+  let input;
+  return (
+    <div>
+      <div onClick={[push, "outer"]} oncapture:click={() => push("capture")}>
+        <button id="bound" onClick={[push, "inner"]} />
+      </div>
+      <button id="custom" on:ping={event => push(`ping ${event.detail}`)} />
+      <button id="native" onFocus={() => push("focus")} />
+      <input ref={input} onInput={() => push(`input ${input.value}`)} />
+      <p>{log().join(", ")}</p>
+    </div>
+  );
+}
+
+export function check({ find, click, input, dispatch }) {
+  click("#bound");
+  dispatch("#custom", "ping", { detail: 1 });
+  find("#native").dispatchEvent(new window.FocusEvent("focus"));
+  input("input", "x");
+  assert.equal(find("p").textContent, "capture, inner, outer, ping 1, focus, input x");
+}
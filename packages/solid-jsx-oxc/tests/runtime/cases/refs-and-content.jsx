@@ -0,0 +1,30 @@
+import assert from "node:assert/strict";
+import { createSignal } from "solid-js";
+
+const [markup, setMarkup] = createSignal("<b>bold</b>");
+const [label, setLabel] = createSignal("<plain>");
+let root;
+let seen;
+
+export default function App() {
+  return (
+    <div ref={root}>
+      <span ref={element => (seen = element)} id="callback" />
+      <p id="inner" innerHTML={markup()} />
+      <p id="text" textContent={label()} />
+    </div>
+  );
+}
+
+export function check({ container, find }) {
+  assert.equal(root, container.firstChild);
+  assert.equal(seen, find("#callback"));
+  assert.equal(find("#inner").innerHTML, "<b>bold</b>");
+  assert.equal(find("#text").textContent, "<plain>");
+  setMarkup("<i>italic</i>");
+  setLabel("<other>");
+  assert.equal(find("#inner").innerHTML, "<i>italic</i>");
+  assert.equal(find("#text").textContent, "<other>");
+  setMarkup("<b>bold</b>");
+  setLabel("<plain>");
+}
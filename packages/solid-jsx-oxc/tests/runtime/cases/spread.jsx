@@ -0,0 +1,36 @@
+import assert from "node:assert/strict";
+import { createSignal } from "solid-js";
+
+const [title, setTitle] = createSignal("first");
+let clicks = 0;
+
+function Button(props) {
+  return <button type="button" {...props} />;
+}
+
+export default function App() {
+  const props = {
+    get title() {
+      return title();
+    },
+    onClick: () => clicks++,
+  };
+  return (
+    <Button {...props} class="button">
+      Press
+    </Button>
+  );
+}
+
+export function check({ find, click }) {
+  const button = find("button");
+  assert.equal(button.getAttribute("type"), "button");
+  assert.equal(button.className, "button");
+  assert.equal(button.textContent, "Press");
+  assert.equal(button.title, "first");
+  setTitle("second");
+  assert.equal(button.title, "second");
+  click("button");
+  assert.equal(clicks, 1);
+  setTitle("first");
+}
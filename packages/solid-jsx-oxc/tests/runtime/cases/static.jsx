@@ -0,0 +1,21 @@
+import assert from "node:assert/strict";
+
+export default function App() {
+  return (
+    <main id="app">
+      <h1 class="title">Hello</h1>
+      <input type="text" disabled />
+      <ul>
+        <li>One</li>
+        <li>Two</li>
+      </ul>
+    </main>
+  );
+}
+
+export function check({ html }) {
+  assert.equal(
+    html(),
+    '<main id="app"><h1 class="title">Hello</h1><input type="text" disabled=""><ul><li>One</li><li>Two</li></ul></main>'
+  );
+}
@@ -0,0 +1,30 @@
+import assert from "node:assert/strict";
+import { createSignal } from "solid-js";
+
+const [radius, setRadius] = createSignal(4);
+
+function Dot(props) {
+  return <circle cx="5" cy="5" r={props.r} />;
+}
+
+export default function App() {
+  return (
+    <svg viewBox="0 0 10 10">
+      <rect width="10" height="10" />
+      <Dot r={radius()} />
+    </svg>
+  );
+}
+
+export function check({ find }) {
+  const svg = find("svg");
+  assert.equal(svg.namespaceURI, "http://www.w3.org/2000/svg");
+  assert.equal(svg.getAttribute("viewBox"), "0 0 10 10");
+  assert.equal(find("rect").namespaceURI, "http://www.w3.org/2000/svg");
+  const circle = find("circle");
+  assert.equal(circle.namespaceURI, "http://www.w3.org/2000/svg");
+  assert.equal(circle.getAttribute("r"), "4");
+  setRadius(2);
+  assert.equal(circle.getAttribute("r"), "2");
+  setRadius(4);
+}
@@ -0,0 +1,88 @@
+// Runs compiled DOM-mode modules against solid-js/web in jsdom, for
+// `tests/runtime.rs`.
+//
+// Usage: node --conditions=browser harness.mjs <dir>
+//
+// Every `.mjs` file in <dir> is a case compiled from `tests/runtime/cases`.
+// Its default export is the component to render, and its `check` export is
+// called with helpers to inspect and drive what was rendered. One line is
+// printed per case, `ok <name>` or `fail <name>: <error>`, and the exit code
+// is 1 if any case failed. If jsdom or solid-js cannot be found, a single
+// `skip: <reason>` line is printed instead.
+
+import { readdirSync } from 'node:fs';
+import { join } from 'node:path';
+import { pathToFileURL } from 'node:url';
+
+const dir = process.argv[2];
+
+let JSDOM;
+try {
+  ({ JSDOM } = await import('jsdom'));
+  await import('solid-js');
+} catch (error) {
+  if (error?.code !== 'ERR_MODULE_NOT_FOUND') throw error;
+  console.log(`skip: ${error.message.split('\n')[0]}`);
+  process.exit(0);
+}
+
+// solid-js/web looks up `window`, `document` and the DOM classes as globals,
+// so they have to be in place before it is first imported
+const { window } = new JSDOM('<!doctype html><html><head></head><body></body></html>', {
+  url: 'http://localhost/',
+});
+for (const key of Object.getOwnPropertyNames(window)) {
+  if (key in globalThis) continue;
+  try {
+    globalThis[key] = window[key];
+  } catch {
+    // Some getters throw, and none of them matter here
+  }
+}
+globalThis.window = window;
+globalThis.document = window.document;
+
+const { render } = await import('solid-js/web');
+
+function helpers(container) {
+  const find = selector => {
+    const element = container.querySelector(selector);
+    if (!element) throw new Error(`nothing matches ${selector}`);
+    return element;
+  };
+  return {
+    container,
+    find,
+    // Without the comments Solid leaves as insertion markers
+    html: () => container.innerHTML.replace(/<!--.*?-->/g, ''),
+    text: () => container.textContent,
+    click: selector => find(selector).dispatchEvent(new window.MouseEvent('click', { bubbles: true })),
+    input: (selector, value) => {
+      const element = find(selector);
+      element.value = value;
+      element.dispatchEvent(new window.Event('input', { bubbles: true }));
+    },
+    dispatch: (selector, type, init = {}) => find(selector).dispatchEvent(new window.CustomEvent(type, init)),
+  };
+}
+
+let failed = 0;
+for (const file of readdirSync(dir).filter(file => file.endsWith('.mjs')).sort()) {
+  const name = file.slice(0, -'.mjs'.length);
+  const container = document.createElement('div');
+  document.body.appendChild(container);
+  let dispose;
+  try {
+    const { default: App, check } = await import(pathToFileURL(join(dir, file)).href);
+    dispose = render(App, container);
+    await check(helpers(container));
+    console.log(`ok ${name}`);
+  } catch (error) {
+    failed++;
+    console.log(`fail ${name}: ${String(error?.stack ?? error).replace(/\n/g, '\n    ')}`);
+  } finally {
+    dispose?.();
+    container.remove();
+  }
+}
+process.exit(failed ? 1 : 0);
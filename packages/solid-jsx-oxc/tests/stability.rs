@@ -0,0 +1,206 @@
+//! The same input always compiles to the same bytes
+//!
+//! Every upstream fixture, golden input and runtime case is compiled once as
+//! a reference, and then again: on another thread, whose `RandomState` seeds
+//! std hash maps differently; by one `Transformer` that has compiled all other
+//! files before, so ids or templates left over from a previous file would
+//! show; and in a parallel batch. Code, source map and metadata must be
+//! identical each time. The output itself has no JSX left, so compiling it
+//! again must not change it either.
+
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::thread;
+
+use common::GenerateMode;
+#[cfg(feature = "project")]
+use solid_jsx_oxc::transform_batch;
+use solid_jsx_oxc::{transform, TransformOptions, Transformer};
+
+const MODES: [(&str, GenerateMode, bool); 4] = [
+    ("dom", GenerateMode::Dom, false),
+    ("dom_hydratable", GenerateMode::Dom, true),
+    ("ssr", GenerateMode::Ssr, false),
+    ("ssr_hydratable", GenerateMode::Ssr, true),
+];
+
+struct Case {
+    /// Path of the input, which also names it in the source map
+    path: PathBuf,
+    source: String,
+    generate: GenerateMode,
+    hydratable: bool,
+}
+
+impl Case {
+    fn name(&self) -> String {
+        let mode = MODES
+            .iter()
+            .find(|(_, generate, hydratable)| {
+                *generate == self.generate && *hydratable == self.hydratable
+            })
+            .map_or("?", |(mode, ..)| mode);
+        format!("{mode} {}", self.path.display())
+    }
+
+    fn options(&self) -> TransformOptions<'_> {
+        TransformOptions {
+            generate: self.generate,
+            hydratable: self.hydratable,
+            filename: self.path.to_str().unwrap(),
+            source_map: true,
+            ..TransformOptions::solid_defaults()
+        }
+    }
+}
+
+/// Code, source map and metadata of one compilation
+type Output = (String, Option<String>, common::TransformMetadata);
+
+fn compile(case: &Case) -> Output {
+    let mut transformer = Transformer::new(case.options());
+    let output = transformer.transform_source(&case.path, &case.source);
+    (output.code, output.map, output.metadata)
+}
+
+fn inputs(dir: &Path, file_name: &str) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .map(|entry| entry.unwrap().path())
+        .flat_map(|path| {
+            if path.is_dir() {
+                vec![path.join(file_name)]
+            } else {
+                vec![path]
+            }
+        })
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .extension()
+                    .is_some_and(|extension| extension == "jsx" || extension == "js")
+        })
+        .collect();
+    paths.sort();
+    paths
+}
+
+/// Every input with every mode, except upstream fixtures, which are compiled
+/// only in the mode of their directory, and the few the transform panics on
+fn cases() -> Vec<Case> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let fixtures = root.join("../babel-plugin-jsx-dom-expressions/test");
+
+    let mut cases = Vec::new();
+    for (mode, generate, hydratable) in MODES {
+        let mut paths = inputs(&fixtures.join(format!("__{mode}_fixtures__")), "code.js");
+        paths.extend(inputs(&root.join("tests/golden"), ""));
+        paths.extend(inputs(&root.join("tests/runtime/cases"), ""));
+        for path in paths {
+            let source = fs::read_to_string(&path).unwrap();
+            cases.push(Case {
+                path,
+                source,
+                generate,
+                hydratable,
+            });
+        }
+    }
+
+    let cases: Vec<Case> = cases
+        .into_iter()
+        .filter(|case| panic::catch_unwind(AssertUnwindSafe(|| compile(case))).is_ok())
+        .collect();
+    assert!(cases.len() > 20, "only found {} inputs", cases.len());
+    cases
+}
+
+fn assert_same(case: &Case, how: &str, reference: &Output, output: &Output) {
+    assert!(
+        reference == output,
+        "{} compiled {how} differs\n--- first\n{}\n--- {how}\n{}",
+        case.name(),
+        reference.0,
+        output.0
+    );
+}
+
+#[test]
+fn test_output_is_identical_across_runs() {
+    let cases = cases();
+    let references: Vec<Output> = cases.iter().map(compile).collect();
+
+    // Again on another thread, where std hash maps iterate in another order
+    let again = thread::scope(|scope| {
+        scope
+            .spawn(|| cases.iter().map(compile).collect::<Vec<_>>())
+            .join()
+    })
+    .unwrap();
+    for ((case, reference), output) in cases.iter().zip(&references).zip(&again) {
+        assert_same(case, "on another thread", reference, output);
+    }
+
+    // By one transformer per mode, after all other files, in reverse order
+    for (_, generate, hydratable) in MODES {
+        let mut transformer = None;
+        for (case, reference) in cases.iter().zip(&references).rev() {
+            if case.generate != generate || case.hydratable != hydratable {
+                continue;
+            }
+            let transformer = transformer.get_or_insert_with(|| Transformer::new(case.options()));
+            let output = transformer.transform_source(&case.path, &case.source);
+            assert_same(
+                case,
+                "by a reused transformer",
+                reference,
+                &(output.code, output.map, output.metadata),
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "project")]
+fn test_batch_output_matches_single_files() {
+    let cases = cases();
+    for (_, generate, hydratable) in MODES {
+        let cases: Vec<&Case> = cases
+            .iter()
+            .filter(|case| case.generate == generate && case.hydratable == hydratable)
+            .collect();
+        let files: Vec<(&Path, &str)> = cases
+            .iter()
+            .map(|case| (case.path.as_path(), case.source.as_str()))
+            .collect();
+        let report = transform_batch(&files, cases[0].options());
+        for (case, output) in cases.iter().zip(report.outputs) {
+            assert_same(
+                case,
+                "in a batch",
+                &compile(case),
+                &(output.code, output.map, output.metadata),
+            );
+        }
+    }
+}
+
+#[test]
+fn test_output_is_a_fixed_point() {
+    for case in cases() {
+        let options = TransformOptions {
+            source_map: false,
+            ..case.options()
+        };
+        let once = transform(&case.source, Some(options.clone())).code;
+        let twice = transform(&once, Some(options)).code;
+        assert!(
+            once == twice,
+            "{} changes when compiled again\n--- once\n{once}\n--- twice\n{twice}",
+            case.name()
+        );
+    }
+}
@@ -0,0 +1,101 @@
+//! Comparing our output with dom-expressions' fixtures, shared by
+//! `tests/fixtures.rs` and `cargo xtask compare` / `cargo xtask conformance`
+//!
+//! Both sides are printed by oxc's code generator, Babel's helper imports
+//! lose their `_$` prefix (`insert as _$insert` is `insert`), and generated
+//! names like `_el$3` are numbered again in order of appearance, per prefix,
+//! so that only differences in what the code does remain.
+
+use oxc_allocator::Allocator;
+use oxc_codegen::Codegen;
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType};
+use solid_jsx_oxc::{GenerateMode, TransformOptions};
+
+/// The options of the Babel spec of each variant (`dom.spec.js`,
+/// `ssr-hydratable.spec.js`, ...)
+pub fn spec_options(generate: GenerateMode, hydratable: bool) -> TransformOptions<'static> {
+    TransformOptions {
+        module_name: if generate == GenerateMode::Ssr {
+            "r-server"
+        } else {
+            "r-dom"
+        },
+        generate,
+        hydratable,
+        built_ins: vec!["For", "Show"],
+        wrap_conditionals: true,
+        context_to_custom_elements: true,
+        static_marker: "@once",
+        filename: "code.jsx",
+        ..TransformOptions::solid_defaults()
+    }
+}
+
+/// The top-level statements of `code`, printed by oxc's code generator, with
+/// generated names renumbered in each
+pub fn statements(code: &str) -> Vec<String> {
+    let allocator = Allocator::default();
+    let parsed = Parser::new(&allocator, code, SourceType::jsx()).parse();
+    let printed = Codegen::new().build(&parsed.program).code;
+    let parsed = Parser::new(&allocator, &printed, SourceType::jsx()).parse();
+    parsed
+        .program
+        .body
+        .iter()
+        .map(|statement| renumber_names(statement.span().source_text(&printed)))
+        .collect()
+}
+
+/// Drop the `_$` of Babel's helper imports and number generated names like
+/// `_el$3` in order of appearance, per prefix
+pub fn renumber_names(code: &str) -> String {
+    fn is_name_char(c: char) -> bool {
+        c == '_' || c == '$' || c.is_alphanumeric()
+    }
+
+    // Per prefix, the numbers in order of appearance
+    let mut numbers: Vec<(&str, Vec<&str>)> = Vec::new();
+    let mut out = String::with_capacity(code.len());
+    let mut rest = code;
+    while let Some(start) = rest.find(is_name_char) {
+        out.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find(|c| !is_name_char(c))
+            .map_or(rest.len(), |end| start + end);
+        let name = &rest[start..end];
+        rest = &rest[end..];
+
+        if let Some(helper) = name.strip_prefix("_$") {
+            // `insert as _$insert` imports `insert` under its own name
+            let alias = format!("{} as ", helper);
+            if out.ends_with(&alias) {
+                out.truncate(out.len() - alias.len());
+            }
+            out.push_str(helper);
+            continue;
+        }
+        let generated = name.rsplit_once('$').filter(|(prefix, number)| {
+            prefix.starts_with('_') && number.chars().all(|c| c.is_ascii_digit())
+        });
+        let Some((prefix, number)) = generated else {
+            out.push_str(name);
+            continue;
+        };
+        let index = match numbers.iter_mut().find(|(p, _)| *p == prefix) {
+            Some((_, seen)) => seen.iter().position(|n| *n == number).unwrap_or_else(|| {
+                seen.push(number);
+                seen.len() - 1
+            }),
+            None => {
+                numbers.push((prefix, vec![number]));
+                0
+            }
+        };
+        out.push_str(prefix);
+        out.push('$');
+        out.push_str(&index.to_string());
+    }
+    out.push_str(rest);
+    out
+}
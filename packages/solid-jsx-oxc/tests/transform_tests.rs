@@ -46,6 +46,47 @@ fn test_dom_nested_elements() {
     assert!(code.contains("template(`<div><span>hello</span><p>world</p></div>`)"));
 }
 
+#[test]
+fn test_dom_duplicate_templates_are_shared() {
+    let code = transform_dom(
+        r#"const a = <li>item</li>; const b = <li>item</li>; const c = <li>other</li>;"#,
+    );
+    assert_eq!(code.matches("template(`<li>item</li>`)").count(), 1);
+    assert_eq!(code.matches("_tmpl$1.cloneNode(true)").count(), 2);
+    assert!(code.contains("_tmpl$2 = template(`<li>other</li>`)"));
+}
+
+#[test]
+fn test_dom_static_subtree_before_dynamic_sibling() {
+    let code = transform_dom(
+        r#"<nav><ul class="menu"><li><a href="/a?x=1&y=2">A</a></li><li><hr /> Two</li></ul><button disabled style="color: red">Go</button><p>{count()}</p></nav>"#,
+    );
+    assert!(code.contains(
+        r#"template(`<nav><ul class="menu"><li><a href="/a?x=1&amp;y=2">A</a></li><li><hr> Two</li></ul><button disabled style="color: red">Go</button><p></p></nav>`)"#
+    ));
+    // Only the dynamic paragraph is walked to
+    assert!(code.contains(".firstChild.nextSibling.nextSibling"));
+    assert!(code.contains("insert("));
+}
+
+#[test]
+fn test_dom_nested_inner_content_attribute() {
+    // `textContent` is planned as inner content, so the nested element is
+    // given an id even though its value is a string
+    let code = transform_dom(r#"<p><span>{a}<b textContent="x" /></span></p>"#);
+    assert!(code.contains("template(`<p><span><!><b></b></span></p>`)"));
+    assert!(code.contains("insert("));
+}
+
+#[test]
+fn test_dom_children_attribute_is_inserted() {
+    // `Element.children` is read-only, so the value is inserted as the
+    // element's children instead of being assigned
+    let code = transform_dom(r#"<div children={a()} />"#);
+    assert!(code.contains("insert(_el$1, () => a())"), "{code}");
+    assert!(!code.contains(".children ="));
+}
+
 #[test]
 fn test_dom_void_element() {
     let code = transform_dom(r#"<input type="text" />"#);
@@ -110,6 +151,21 @@ fn test_dom_style_namespace_binding() {
     assert!(code.contains("props.top"));
 }
 
+#[test]
+fn test_dom_bool_namespace_binding() {
+    let code = transform_dom(
+        r#"<div bool:quack={props.quack} bool:on="yes" bool:off="" bool:none={null} />"#,
+    );
+    assert!(code.contains("<div on>"), "got: {}", code);
+    assert!(
+        code.contains("setBoolAttribute(_el$1, \"quack\", props.quack)"),
+        "got: {}",
+        code
+    );
+    assert!(!code.contains("bool:"), "got: {}", code);
+    assert!(!code.contains("none"), "got: {}", code);
+}
+
 // ============================================================================
 // DOM: Event Handlers
 // ============================================================================
@@ -687,6 +743,29 @@ fn test_ssr_dynamic_attribute() {
     assert!(code.contains("style()"));
 }
 
+#[test]
+fn test_ssr_skips_client_only_attributes() {
+    let code = transform_ssr(
+        r#"<input ref={el} onInput={handler} use:model={value} prop:value={v()} attr:title={t()} />"#,
+    );
+    assert!(!code.contains("ref"), "got: {}", code);
+    assert!(!code.contains("onInput"), "got: {}", code);
+    assert!(!code.contains("use:model"), "got: {}", code);
+    assert!(!code.contains("prop:value"), "got: {}", code);
+    assert!(code.contains(" title=\""), "attr: prefix should be stripped, got: {}", code);
+}
+
+#[test]
+fn test_ssr_bool_namespace_renders_presence() {
+    let code = transform_ssr(r#"<div bool:quack={quack()} bool:on={true} bool:off={false} />"#);
+    assert!(
+        code.contains("<div${ssrAttribute(\"quack\", quack(), true)} on>"),
+        "got: {}",
+        code
+    );
+    assert!(!code.contains("off"), "got: {}", code);
+}
+
 #[test]
 fn test_ssr_dynamic_child() {
     let code = transform_ssr(r#"<div>{count()}</div>"#);
@@ -893,6 +972,67 @@ fn test_dom_source_map_generation() {
     assert!(result.map.is_some(), "expected source map to be generated");
 }
 
+#[test]
+fn test_dom_source_map_points_effect_at_attribute() {
+    let options = TransformOptions {
+        filename: "input.jsx",
+        source_map: true,
+        ..TransformOptions::solid_defaults()
+    };
+    let source = "const a = (\n  <div\n    class={x()}\n  />\n);\n";
+    let result = transform(source, Some(options));
+    let map = result.map.expect("expected source map to be generated");
+
+    let (effect_line, effect_col) = result
+        .code
+        .lines()
+        .enumerate()
+        .find_map(|(i, l)| l.find("effect(() =>").map(|col| (i as u32, col as u32)))
+        .expect("expected an effect");
+    assert!(
+        map.get_tokens().any(|t| t.get_dst_line() == effect_line
+            && t.get_dst_col() <= effect_col
+            && t.get_src_line() == 2),
+        "effect should map back to the class attribute. Output was:\n{}",
+        result.code
+    );
+}
+
+#[test]
+fn test_dom_source_map_points_insert_and_handler_at_source() {
+    let options = TransformOptions {
+        filename: "input.jsx",
+        source_map: true,
+        ..TransformOptions::solid_defaults()
+    };
+    let source = "const a = (\n  <div\n    onClick={handle}\n    on:custom={other}\n  >\n    {count()}\n  </div>\n);\n";
+    let result = transform(source, Some(options));
+    let map = result.map.expect("expected source map to be generated");
+
+    // The first token at or before `needle` on its output line must come from `src_line`
+    let maps_to = |needle: &str, src_line: u32| {
+        let (line, col) = result
+            .code
+            .lines()
+            .enumerate()
+            .find_map(|(i, l)| l.find(needle).map(|col| (i as u32, col as u32)))
+            .unwrap_or_else(|| panic!("expected `{}` in:\n{}", needle, result.code));
+        map.get_tokens()
+            .filter(|t| t.get_dst_line() == line && t.get_dst_col() <= col)
+            .last()
+            .is_some_and(|t| t.get_src_line() == src_line)
+    };
+    for (needle, src_line) in [("insert(", 5), ("$$click", 2), ("addEventListener(", 3)] {
+        assert!(
+            maps_to(needle, src_line),
+            "`{}` should map back to line {}. Output was:\n{}",
+            needle,
+            src_line + 1,
+            result.code
+        );
+    }
+}
+
 #[test]
 fn test_ssr_source_map_generation() {
     let options = TransformOptions {
@@ -0,0 +1,170 @@
+/**
+ * webpack and Rspack loader for SolidJS using OXC-based compiler
+ *
+ * Compiles each module with the native addon on the libuv thread pool, so
+ * parallel loader calls do not block each other.
+ */
+
+import type { TransformOptions } from '@solid-jsx-oxc/core';
+
+export interface SolidOxcLoaderOptions {
+  /**
+   * The module to import runtime helpers from
+   * @default 'solid-js/web'
+   */
+  moduleName?: string;
+
+  /**
+   * Generate mode; use 'ssr' in the server compilation
+   * @default 'dom'
+   */
+  generate?: 'dom' | 'ssr' | 'universal';
+
+  /**
+   * Enable hydration support
+   * @default false
+   */
+  hydratable?: boolean;
+
+  /**
+   * Delegate events for better performance
+   * @default true
+   */
+  delegateEvents?: boolean;
+
+  /**
+   * Wrap conditionals in memos
+   * @default true
+   */
+  wrapConditionals?: boolean;
+
+  /**
+   * Pass context to custom elements
+   * @default true
+   */
+  contextToCustomElements?: boolean;
+
+  /**
+   * Register exported components with solid-refresh, through
+   * `import.meta.webpackHot`; needs solid-refresh installed and hot module
+   * replacement turned on
+   * @default false
+   */
+  hmr?: boolean;
+
+  /**
+   * Source maps: `true` hands the map to the bundler, 'inline' appends it to
+   * the code as a data URL, `false` skips it
+   * @default true when the bundler's `devtool` asks for source maps
+   */
+  sourceMap?: boolean | 'inline';
+
+  /**
+   * Let the bundler cache the output until the file changes
+   * @default true
+   */
+  cacheable?: boolean;
+}
+
+/**
+ * The part of the loader context this loader uses; webpack and Rspack agree
+ * on it
+ */
+export interface LoaderContext<Options> {
+  resourcePath: string;
+  sourceMap: boolean;
+  getOptions(): Options;
+  cacheable(flag?: boolean): void;
+  async(): (error: Error | null, content?: string, sourceMap?: object) => void;
+}
+
+const GENERATE_MODES = ['dom', 'ssr', 'universal'];
+
+/**
+ * Compiler options for the file at `filename`
+ */
+export function toTransformOptions(
+  options: SolidOxcLoaderOptions,
+  filename: string,
+  sourceMap: boolean
+): TransformOptions {
+  if (options.generate !== undefined && !GENERATE_MODES.includes(options.generate)) {
+    throw new Error(
+      `solid-oxc-loader: invalid \`generate\` option "${options.generate}", expected one of ${GENERATE_MODES.join(', ')}`
+    );
+  }
+
+  return {
+    filename,
+    moduleName: options.moduleName,
+    generate: options.generate,
+    hydratable: options.hydratable,
+    delegateEvents: options.delegateEvents,
+    wrapConditionals: options.wrapConditionals,
+    contextToCustomElements: options.contextToCustomElements,
+    sourceMap,
+    hmr: options.hmr && options.generate !== 'ssr',
+    hmrBundler: 'webpack5',
+  };
+}
+
+function inlineSourceMap(code: string, map: string): string {
+  const encoded = Buffer.from(map).toString('base64');
+  return `${code}\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,${encoded}\n`;
+}
+
+// Lazy load the native module
+let core: Promise<typeof import('@solid-jsx-oxc/core')> | null = null;
+
+function loadCore(): Promise<typeof import('@solid-jsx-oxc/core')> {
+  core ??= import('@solid-jsx-oxc/core').catch((e: unknown) => {
+    core = null;
+    const message = e instanceof Error ? e.message : String(e);
+    throw new Error(
+      `solid-oxc-loader: failed to load @solid-jsx-oxc/core. Make sure it is built for your platform.\n${message}`
+    );
+  });
+  return core;
+}
+
+/**
+ * webpack and Rspack loader for SolidJS using OXC-based compiler
+ */
+export default function solidOxcLoader(
+  this: LoaderContext<SolidOxcLoaderOptions>,
+  source: string
+): void {
+  const options = this.getOptions() ?? {};
+  const callback = this.async();
+  this.cacheable(options.cacheable !== false);
+
+  const sourceMap = options.sourceMap ?? this.sourceMap;
+  let transformOptions: TransformOptions;
+  try {
+    transformOptions = toTransformOptions(options, this.resourcePath, sourceMap !== false);
+  } catch (e: unknown) {
+    callback(e instanceof Error ? e : new Error(String(e)));
+    return;
+  }
+
+  loadCore()
+    .then(({ transform }) => transform(source, transformOptions))
+    .then(
+      (result) => {
+        if (!result.map) {
+          callback(null, result.code);
+        } else if (sourceMap === 'inline') {
+          callback(null, inlineSourceMap(result.code, result.map));
+        } else {
+          callback(null, result.code, JSON.parse(result.map));
+        }
+      },
+      (e: unknown) => {
+        const message = e instanceof Error ? e.message : String(e);
+        callback(new Error(`Failed to transform ${this.resourcePath}: ${message}`));
+      }
+    );
+}
+
+// Named export for compatibility
+export { solidOxcLoader };
@@ -0,0 +1,137 @@
+/**
+ * SolidJS JSX compilation with the OXC-based compiler for every bundler
+ * unplugin supports
+ *
+ * Import the plugin for your bundler from its entry, e.g.
+ * `unplugin-solid-oxc/vite` or `unplugin-solid-oxc/webpack`.
+ */
+
+import { createUnplugin } from 'unplugin';
+import { createFilter, type FilterPattern } from '@rollup/pluginutils';
+import type { TransformOptions } from '@solid-jsx-oxc/core';
+
+export interface SolidOxcOptions {
+  /**
+   * Files to transform, as globs or regular expressions
+   * @default [/\.[mc]?[jt]sx$/]
+   */
+  include?: FilterPattern;
+
+  /**
+   * Files to leave alone, as globs or regular expressions
+   * @default [/node_modules/]
+   */
+  exclude?: FilterPattern;
+
+  /**
+   * When the transform runs relative to the bundler's other plugins. The
+   * output keeps TypeScript syntax, so the default runs it before TypeScript
+   * is stripped; `undefined` leaves the plugin where it is listed.
+   * @default 'pre'
+   */
+  enforce?: 'pre' | 'post' | undefined;
+
+  /**
+   * The module to import runtime helpers from
+   * @default 'solid-js/web'
+   */
+  moduleName?: string;
+
+  /**
+   * Generate mode
+   * @default 'dom'
+   */
+  generate?: 'dom' | 'ssr' | 'universal';
+
+  /**
+   * Enable hydration support
+   * @default false
+   */
+  hydratable?: boolean;
+
+  /**
+   * Delegate events for better performance
+   * @default true
+   */
+  delegateEvents?: boolean;
+
+  /**
+   * Wrap conditionals in memos
+   * @default true
+   */
+  wrapConditionals?: boolean;
+
+  /**
+   * Pass context to custom elements
+   * @default true
+   */
+  contextToCustomElements?: boolean;
+
+  /**
+   * Generate source maps
+   * @default true
+   */
+  sourceMap?: boolean;
+}
+
+const defaultInclude = [/\.[mc]?[jt]sx$/];
+const defaultExclude = [/node_modules/];
+
+// Lazy load the native module
+let core: Promise<typeof import('@solid-jsx-oxc/core')> | null = null;
+
+function loadCore(): Promise<typeof import('@solid-jsx-oxc/core')> {
+  core ??= import('@solid-jsx-oxc/core').catch((e: unknown) => {
+    core = null;
+    const message = e instanceof Error ? e.message : String(e);
+    throw new Error(
+      `Failed to load @solid-jsx-oxc/core. Make sure it is built for your platform.\n${message}`
+    );
+  });
+  return core;
+}
+
+export const unpluginSolidOxc = createUnplugin((options: SolidOxcOptions | undefined) => {
+  const opts = options ?? {};
+  const filter = createFilter(opts.include ?? defaultInclude, opts.exclude ?? defaultExclude);
+  const sourceMap = opts.sourceMap !== false;
+
+  return {
+    name: 'unplugin-solid-oxc',
+    enforce: 'enforce' in opts ? opts.enforce : 'pre',
+
+    transformInclude(id: string) {
+      // Strip query parameters (e.g., ?v=123 from dev servers)
+      return filter(id.split('?', 1)[0]);
+    },
+
+    async transform(code: string, id: string) {
+      const fileId = id.split('?', 1)[0];
+      const transformOptions: TransformOptions = {
+        filename: fileId,
+        moduleName: opts.moduleName,
+        generate: opts.generate,
+        hydratable: opts.hydratable,
+        delegateEvents: opts.delegateEvents,
+        wrapConditionals: opts.wrapConditionals,
+        contextToCustomElements: opts.contextToCustomElements,
+        sourceMap,
+      };
+
+      try {
+        const { transform } = await loadCore();
+        const result = await transform(code, transformOptions);
+        return {
+          code: result.code,
+          map: result.map ? JSON.parse(result.map) : null,
+        };
+      } catch (e: unknown) {
+        const message = e instanceof Error ? e.message : String(e);
+        this.error(`Failed to transform ${id}: ${message}`);
+        return null;
+      }
+    },
+  };
+});
+
+export default unpluginSolidOxc;
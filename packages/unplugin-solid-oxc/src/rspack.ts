@@ -0,0 +1,3 @@
+import { unpluginSolidOxc } from './index.js';
+
+export default unpluginSolidOxc.rspack;
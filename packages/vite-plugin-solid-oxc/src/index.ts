@@ -3,14 +3,58 @@ import { createFilter } from 'vite';
 import { createRequire } from 'node:module';
 import { dirname, isAbsolute, resolve as resolvePath } from 'node:path';
 import { readFileSync } from 'node:fs';
+import type { TransformOptions } from '@solid-jsx-oxc/core';
 
-// Will be imported from the NAPI bindings
-// import { transform } from 'solid-jsx-oxc';
+/**
+ * Compiler options, as under `solid` in vite-plugin-solid
+ */
+export interface SolidCompilerOptions {
+  /**
+   * The module to import runtime helpers from
+   * @default 'solid-js/web'
+   */
+  moduleName?: string;
+
+  /**
+   * Generate mode for client modules; server modules always use 'ssr'
+   * @default 'dom'
+   */
+  generate?: 'dom' | 'ssr' | 'universal';
+
+  /**
+   * Enable hydration support
+   * @default false
+   */
+  hydratable?: boolean;
+
+  /**
+   * Delegate events for better performance
+   * @default true
+   */
+  delegateEvents?: boolean;
+
+  /**
+   * Wrap conditionals in memos
+   * @default true
+   */
+  wrapConditionals?: boolean;
+
+  /**
+   * Pass context to custom elements
+   * @default true
+   */
+  contextToCustomElements?: boolean;
+
+  /**
+   * Built-in components that should be passed through
+   */
+  builtIns?: string[];
+}
 
 export interface SolidOxcOptions {
   /**
    * Filter which files to transform
-   * @default /\.[jt]sx$/
+   * @default /\.[mc]?[jt]sx$/
    */
   include?: FilterPattern;
 
@@ -21,61 +65,72 @@ export interface SolidOxcOptions {
   exclude?: FilterPattern;
 
   /**
-   * The module to import runtime helpers from
+   * More file extensions to compile, such as '.mdx' (as in vite-plugin-solid,
+   * a `[extension, options]` pair is accepted too)
+   */
+  extensions?: (string | [string, { typescript?: boolean }])[];
+
+  /**
+   * Compiler options; take precedence over the flat options below
+   */
+  solid?: SolidCompilerOptions;
+
+  /**
+   * @deprecated Use `solid.moduleName`
    * @default 'solid-js/web'
    */
   module_name?: string;
 
   /**
-   * Generate mode
+   * @deprecated Use `solid.generate`
    * @default 'dom'
    */
   generate?: 'dom' | 'ssr' | 'universal';
 
   /**
-   * Enable hydration support
+   * @deprecated Use `solid.hydratable`
    * @default false
    */
   hydratable?: boolean;
 
   /**
-   * Delegate events for better performance
+   * @deprecated Use `solid.delegateEvents`
    * @default true
    */
   delegate_events?: boolean;
 
   /**
-   * Wrap conditionals in memos
+   * @deprecated Use `solid.wrapConditionals`
    * @default true
    */
   wrap_conditionals?: boolean;
 
   /**
-   * Pass context to custom elements
+   * @deprecated Use `solid.contextToCustomElements`
    * @default true
    */
   context_to_custom_elements?: boolean;
 
   /**
-   * Built-in components that should be passed through
+   * @deprecated Use `solid.builtIns`
    */
   builtIns?: string[];
 
   /**
-   * Enable SSR mode (shorthand for generate: 'ssr')
-   * @default false
+   * Compile every module for the server. By default server environments
+   * (and `vite build --ssr`) get SSR output and client environments DOM output.
    */
   ssr?: boolean;
 
   /**
-   * Dev mode - enables additional debugging
-   * @default based on vite mode
+   * Resolve Solid to its development build (the `development` export condition)
+   * @default true in `vite serve`
    */
   dev?: boolean;
 
   /**
-   * Hot module replacement
-   * @default true in dev mode
+   * Hot component reload with solid-refresh, when it is installed
+   * @default true in `vite serve`
    */
   hot?: boolean;
 
@@ -85,10 +140,31 @@ export interface SolidOxcOptions {
    * @default false
    */
   solid_condition?: boolean;
+
+  /**
+   * Leave files alone whose JSX import source, from a `@jsxImportSource`
+   * comment or the nearest tsconfig.json, is not Solid's, for monorepos that
+   * mix Solid with Preact or React. esbuild then compiles the other JSX with
+   * the settings of each tsconfig.json.
+   * @default false
+   */
+  tsconfig?: boolean;
+
+  /**
+   * Keep compiled modules, so unchanged modules are not compiled again:
+   * `true` in memory, or a directory (relative to the root) where a
+   * restarted dev server finds them too. The directory is pruned to 256 MB
+   * at startup.
+   * @default false
+   */
+  cache?: boolean | string;
 }
 
+/** Largest size of a `cache` directory */
+const MAX_CACHE_BYTES = 256 * 1024 * 1024;
+
 const defaultOptions: SolidOxcOptions = {
-  include: /\.[jt]sx$/,
+  include: /\.[mc]?[jt]sx$/,
   exclude: /node_modules/,
   module_name: 'solid-js/web',
   generate: 'dom',
@@ -110,6 +186,38 @@ const defaultOptions: SolidOxcOptions = {
   ],
 };
 
+/**
+ * Compiler options from `solid`, falling back to the deprecated flat options
+ */
+function resolveCompilerOptions(opts: SolidOxcOptions): SolidCompilerOptions {
+  return {
+    moduleName: opts.solid?.moduleName ?? opts.module_name,
+    generate: opts.solid?.generate ?? opts.generate,
+    hydratable: opts.solid?.hydratable ?? opts.hydratable,
+    delegateEvents: opts.solid?.delegateEvents ?? opts.delegate_events,
+    wrapConditionals: opts.solid?.wrapConditionals ?? opts.wrap_conditionals,
+    contextToCustomElements: opts.solid?.contextToCustomElements ?? opts.context_to_custom_elements,
+    builtIns: opts.solid?.builtIns ?? opts.builtIns,
+  };
+}
+
+function toArray(pattern: FilterPattern | undefined): (string | RegExp)[] {
+  if (pattern == null) {
+    return [];
+  }
+  return Array.isArray(pattern) ? [...pattern] : [pattern as string | RegExp];
+}
+
+function escapeRegExp(text: string): string {
+  return text.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+}
+
+// Vite's default export conditions, which setting `resolve.conditions` replaces
+const DEFAULT_CONDITIONS = {
+  client: ['module', 'browser', 'development|production'],
+  server: ['module', 'node', 'development|production'],
+};
+
 const BARE_IMPORT_RE = /^[^./]|^\.[^./]|^\.\.[^/]/;
 const EXPORT_CONDITION_PREFERENCE = ['solid', 'default', 'development', 'production'] as const;
 
@@ -165,14 +273,25 @@ function pickExportTarget(entry: unknown): string | null {
  */
 export default function solidOxc(options: SolidOxcOptions = {}): Plugin {
   const opts = { ...defaultOptions, ...options };
-  const filter = createFilter(opts.include, opts.exclude);
+  const compilerOptions = resolveCompilerOptions(opts);
+  const extensionPatterns = (opts.extensions ?? []).map((extension) => {
+    const ext = typeof extension === 'string' ? extension : extension[0];
+    return new RegExp(`${escapeRegExp(ext)}$`);
+  });
+  const filter = createFilter([...toArray(opts.include), ...extensionPatterns], opts.exclude);
   const packageJsonCache = new Map<string, unknown>();
 
+  let root = process.cwd();
   let isDev = false;
   let buildSSR = false;
+  let refresh = false;
 
   // Lazy load the native module
-  let solidJsxOxc: typeof import('solid-jsx-oxc') | null = null;
+  let core: typeof import('@solid-jsx-oxc/core') | null = null;
+  // With `tsconfig`, the import source of each directory, until the dev
+  // server's watcher sees a config change
+  let importSources: import('@solid-jsx-oxc/core').JsxImportSources | null = null;
+  let transformCache: import('@solid-jsx-oxc/core').TransformCache | null = null;
 
   return {
     name: 'vite-plugin-solid-oxc',
@@ -180,38 +299,81 @@ export default function solidOxc(options: SolidOxcOptions = {}): Plugin {
 
     enforce: 'pre',
 
-    configEnvironment(_name, config) {
-      if (!opts.solid_condition) {
+    configEnvironment(name, config) {
+      if (!opts.solid_condition && opts.dev === undefined) {
         return;
       }
 
-      const conditions = config.resolve?.conditions ?? [];
-      if (conditions.includes('solid')) {
-        return;
+      const consumer = config.consumer ?? (name === 'client' ? 'client' : 'server');
+      let conditions = config.resolve?.conditions ?? DEFAULT_CONDITIONS[consumer];
+      if (opts.dev !== undefined) {
+        const mode = opts.dev ? 'development' : 'production';
+        conditions = conditions.map((condition) =>
+          condition === 'development|production' ? mode : condition
+        );
+      }
+      if (opts.solid_condition && !conditions.includes('solid')) {
+        conditions = ['solid', ...conditions];
       }
 
       return {
         resolve: {
-          conditions: ['solid', ...conditions],
+          conditions,
         },
       };
     },
 
     configResolved(config) {
+      root = config.root;
       isDev = config.command === 'serve';
       buildSSR = typeof config.build?.ssr === 'boolean' ? config.build.ssr : !!config.build?.ssr;
+
+      refresh = false;
+      if (isDev && opts.hot !== false) {
+        try {
+          createRequire(resolvePath(config.root, 'package.json')).resolve('solid-refresh');
+          refresh = true;
+        } catch {
+          if (opts.hot === true) {
+            config.logger.warn(
+              '[vite-plugin-solid-oxc] `hot` needs solid-refresh; install it for hot component reload.'
+            );
+          }
+        }
+      }
     },
 
     async buildStart() {
       // Load the native module
       try {
-        solidJsxOxc = await import('solid-jsx-oxc');
+        core = await import('@solid-jsx-oxc/core');
+        importSources = opts.tsconfig ? new core.JsxImportSources() : null;
       } catch (e) {
         this.error(
-          'Failed to load solid-jsx-oxc. Make sure it is built for your platform.\n' +
-          'Run: cd packages/solid-jsx-oxc && npm run build'
+          'Failed to load @solid-jsx-oxc/core. Make sure it is built for your platform.\n' +
+          'Run: cd packages/core && npm run build'
         );
       }
+
+      transformCache = null;
+      if (core && typeof opts.cache === 'string') {
+        transformCache = new core.TransformCache(resolvePath(root, opts.cache));
+        transformCache.prune(MAX_CACHE_BYTES);
+      } else if (core && opts.cache) {
+        transformCache = new core.TransformCache();
+      }
+    },
+
+    configureServer(server) {
+      if (!opts.tsconfig) {
+        return;
+      }
+      // Configs are read again when one changes, or a tsconfig.json appears
+      // closer to a file
+      const invalidate = (file: string) => importSources?.invalidate(file);
+      server.watcher.on('add', invalidate);
+      server.watcher.on('change', invalidate);
+      server.watcher.on('unlink', invalidate);
     },
 
     async resolveId(source, importer, options) {
@@ -307,11 +469,18 @@ export default function solidOxc(options: SolidOxcOptions = {}): Plugin {
         return null;
       }
 
-      if (!solidJsxOxc) {
-        this.error('solid-jsx-oxc module not loaded');
+      if (!core) {
+        this.error('@solid-jsx-oxc/core module not loaded');
         return null;
       }
 
+      if (importSources) {
+        const importSource = importSources.get(fileId, code);
+        if (importSource && !core.isSolidImportSource(importSource, compilerOptions.moduleName)) {
+          return null;
+        }
+      }
+
       const envName = (this as unknown as { environment?: { name?: string; config?: { consumer?: string } } })
         .environment?.name;
       const envConsumer = (this as unknown as { environment?: { name?: string; config?: { consumer?: string } } })
@@ -325,29 +494,25 @@ export default function solidOxc(options: SolidOxcOptions = {}): Plugin {
       const transformSSR =
         opts.ssr ??
         (typeof transformOptions?.ssr === 'boolean' ? transformOptions.ssr : inferredSSR || buildSSR);
-      const generate = transformSSR ? 'ssr' : opts.generate;
+      const generate = transformSSR ? 'ssr' : compilerOptions.generate;
+
+      const compileOptions: TransformOptions = {
+        filename: fileId,
+        moduleName: compilerOptions.moduleName,
+        generate,
+        hydratable: compilerOptions.hydratable,
+        delegateEvents: compilerOptions.delegateEvents,
+        wrapConditionals: compilerOptions.wrapConditionals,
+        contextToCustomElements: compilerOptions.contextToCustomElements,
+        sourceMap: true,
+        hmr: refresh && !transformSSR,
+        hmrBundler: 'vite',
+      };
 
       try {
-        const result = solidJsxOxc.transformJsx(code, {
-          filename: fileId,
-          moduleName: opts.module_name,
-          generate,
-          hydratable: opts.hydratable,
-          delegateEvents: opts.delegate_events,
-          wrapConditionals: opts.wrap_conditionals,
-          contextToCustomElements: opts.context_to_custom_elements,
-          sourceMap: true,
-        });
-
-        // Add HMR support in dev mode
-        if (isDev && opts.hot !== false) {
-          const hotCode = `
-if (import.meta.hot) {
-  import.meta.hot.accept();
-}
-`;
-          result.code = result.code + hotCode;
-        }
+        const result = transformCache
+          ? await transformCache.transform(code, compileOptions)
+          : await core.transform(code, compileOptions);
 
         return {
           code: result.code,
@@ -366,15 +531,21 @@ if (import.meta.hot) {
         ? ['solid']
         : undefined;
       return {
-        esbuild: {
-          // Let our plugin handle JSX, not esbuild
-          jsx: 'preserve',
-          jsxImportSource: 'solid-js',
-        },
+        // Let our plugin handle JSX, not esbuild; with `tsconfig`, esbuild
+        // only sees other frameworks' JSX
+        esbuild: opts.tsconfig
+          ? undefined
+          : {
+              jsx: 'preserve',
+              jsxImportSource: 'solid-js',
+            },
         resolve: {
           ...(resolveConditions ? { conditions: resolveConditions } : {}),
           dedupe: ['solid-js', 'solid-js/web'],
         },
+        optimizeDeps: {
+          include: ['solid-js', 'solid-js/web'],
+        },
       };
     },
   };
@@ -0,0 +1,119 @@
+/**
+ * @solid-jsx-oxc/wasi - WASI build of the OXC-based SolidJS JSX compiler
+ */
+
+export interface TransformOptions {
+  /**
+   * The module to import runtime helpers from
+   * @default "solid-js/web"
+   */
+  moduleName?: string;
+
+  /**
+   * Generate mode: "dom", "ssr", or "universal" (currently treated as "dom")
+   * @default "dom"
+   */
+  generate?: 'dom' | 'ssr' | 'universal';
+
+  /**
+   * Whether to enable hydration support
+   * @default false
+   */
+  hydratable?: boolean;
+
+  /**
+   * Whether to delegate events
+   * @default true
+   */
+  delegateEvents?: boolean;
+
+  /**
+   * Whether to wrap conditionals
+   * @default true
+   */
+  wrapConditionals?: boolean;
+
+  /**
+   * Whether to pass context to custom elements
+   * @default true
+   */
+  contextToCustomElements?: boolean;
+
+  /**
+   * Source filename, also used to pick the parser (jsx, tsx, ...)
+   * @default "input.jsx"
+   */
+  filename?: string;
+
+  /**
+   * Whether to generate a source map
+   * @default false
+   */
+  sourceMap?: boolean;
+
+  /**
+   * Register exported components with solid-refresh for hot reload
+   * (ignored for SSR output)
+   * @default false
+   */
+  hmr?: boolean;
+
+  /**
+   * HMR API the solid-refresh glue talks to: `import.meta.hot` for 'vite'
+   * and 'esm', `module.hot` for 'standard', `import.meta.webpackHot` for
+   * 'webpack5'
+   * @default 'vite'
+   */
+  hmrBundler?: 'vite' | 'esm' | 'standard' | 'webpack5';
+
+  /**
+   * Extensions parsed like a known one, e.g. `{ solid: 'tsx' }`; the source
+   * type otherwise follows the extension of `filename`, and unknown
+   * extensions are parsed as TSX
+   */
+  customExtensions?: Record<string, 'tsx' | 'jsx' | 'ts' | 'mts' | 'cts' | 'js' | 'mjs' | 'cjs'>;
+}
+
+export interface TransformResult {
+  /** The transformed code */
+  code: string;
+  /** Source map as a JSON string, when `sourceMap` is enabled */
+  map?: string;
+  /** Events the output passes to `delegateEvents` (empty for SSR) */
+  delegatedEvents: string[];
+  /** HTML of each hoisted template, in declaration order (empty for SSR) */
+  templates: string[];
+  /** Runtime helpers the output imports from `moduleName`, in order of first use */
+  helpers: string[];
+}
+
+/**
+ * Where to load the module from: a URL or path to fetch, a fetch response, its
+ * bytes, or a compiled module (as Cloudflare Workers import `.wasm` files)
+ * @default the solid_jsx_oxc.wasm next to this file
+ */
+export type InitInput =
+  | string
+  | URL
+  | Response
+  | Promise<Response>
+  | BufferSource
+  | WebAssembly.Module;
+
+/**
+ * Instantiate the compiler; call (and await) once before `transform`
+ */
+export function init(input?: InitInput): Promise<void>;
+
+/**
+ * Transform JSX source code
+ * @throws when `init` has not finished, or the options are invalid
+ */
+export function transform(source: string, options?: TransformOptions): TransformResult;
+
+declare const _default: {
+  init: typeof init;
+  transform: typeof transform;
+};
+
+export default _default;
@@ -0,0 +1,167 @@
+/**
+ * WASI build of the OXC-based SolidJS JSX compiler
+ *
+ * The module is the compiler's C ABI built for `wasm32-wasip1`. It only needs
+ * the few WASI calls Rust's standard library makes (random numbers, the
+ * clock, writing a panic message), which are provided here, so it runs
+ * wherever WebAssembly does: Deno, Cloudflare Workers, Bun, browsers.
+ */
+
+const ERRNO_SUCCESS = 0;
+const ERRNO_BADF = 8;
+const ERRNO_NOSYS = 52;
+
+const encoder = new TextEncoder();
+const decoder = new TextDecoder();
+
+let exports = null;
+
+/**
+ * The WASI functions the module imports, on top of `memory()`
+ */
+function wasiImports(memory) {
+  const view = () => new DataView(memory().buffer);
+  const output = { 1: '', 2: '' };
+
+  const functions = {
+    args_sizes_get(countPtr, sizePtr) {
+      view().setUint32(countPtr, 0, true);
+      view().setUint32(sizePtr, 0, true);
+      return ERRNO_SUCCESS;
+    },
+    args_get() {
+      return ERRNO_SUCCESS;
+    },
+    environ_sizes_get(countPtr, sizePtr) {
+      view().setUint32(countPtr, 0, true);
+      view().setUint32(sizePtr, 0, true);
+      return ERRNO_SUCCESS;
+    },
+    environ_get() {
+      return ERRNO_SUCCESS;
+    },
+    random_get(ptr, len) {
+      // getRandomValues fills at most 64 KiB per call
+      for (let offset = 0; offset < len; offset += 65536) {
+        const size = Math.min(65536, len - offset);
+        crypto.getRandomValues(new Uint8Array(memory().buffer, ptr + offset, size));
+      }
+      return ERRNO_SUCCESS;
+    },
+    clock_time_get(_id, _precision, timePtr) {
+      view().setBigUint64(timePtr, BigInt(Date.now()) * 1000000n, true);
+      return ERRNO_SUCCESS;
+    },
+    fd_write(fd, iovs, iovsLen, writtenPtr) {
+      if (fd !== 1 && fd !== 2) {
+        return ERRNO_BADF;
+      }
+      let written = 0;
+      for (let i = 0; i < iovsLen; i++) {
+        const ptr = view().getUint32(iovs + i * 8, true);
+        const len = view().getUint32(iovs + i * 8 + 4, true);
+        output[fd] += decoder.decode(new Uint8Array(memory().buffer, ptr, len));
+        written += len;
+      }
+      // Log whole lines, as the standard library writes them in pieces
+      const lines = output[fd].split('\n');
+      output[fd] = lines.pop();
+      for (const line of lines) {
+        (fd === 1 ? console.log : console.error)(line);
+      }
+      view().setUint32(writtenPtr, written, true);
+      return ERRNO_SUCCESS;
+    },
+    proc_exit(code) {
+      throw new Error(`@solid-jsx-oxc/wasi: the compiler exited with code ${code}`);
+    },
+  };
+
+  // Everything else, such as file access, is not available
+  return new Proxy(functions, {
+    get: (target, name) => target[name] ?? (() => ERRNO_NOSYS),
+  });
+}
+
+async function compile(input) {
+  if (input instanceof WebAssembly.Module) {
+    return input;
+  }
+  if (typeof input === 'string' || input instanceof URL) {
+    input = fetch(input);
+  }
+  if (input instanceof Response || typeof input?.then === 'function') {
+    const response = await input;
+    if (!response.ok) {
+      throw new Error(`@solid-jsx-oxc/wasi: failed to fetch the module (${response.status})`);
+    }
+    input = await response.arrayBuffer();
+  }
+  return WebAssembly.compile(input);
+}
+
+/**
+ * Instantiate the compiler; call (and await) once before `transform`
+ */
+export async function init(input = new URL('./solid_jsx_oxc.wasm', import.meta.url)) {
+  if (exports) {
+    return;
+  }
+  const module = await compile(input);
+  let memory = null;
+  const instance = await WebAssembly.instantiate(module, {
+    wasi_snapshot_preview1: wasiImports(() => memory),
+  });
+  memory = instance.exports.memory;
+  // Reactor modules set up the standard library here
+  instance.exports._initialize?.();
+  exports = instance.exports;
+}
+
+/**
+ * Copy `text` into the module's memory, NUL-terminated
+ */
+function writeString(text) {
+  const bytes = encoder.encode(text);
+  const len = bytes.length + 1;
+  const ptr = exports.solid_jsx_alloc(len) >>> 0;
+  const buffer = new Uint8Array(exports.memory.buffer, ptr, len);
+  buffer.set(bytes);
+  buffer[bytes.length] = 0;
+  return { ptr, len };
+}
+
+function readString(ptr) {
+  const memory = new Uint8Array(exports.memory.buffer);
+  const end = memory.indexOf(0, ptr);
+  return decoder.decode(memory.subarray(ptr, end));
+}
+
+/**
+ * Transform JSX source code
+ */
+export function transform(source, options = {}) {
+  if (!exports) {
+    throw new Error('@solid-jsx-oxc/wasi: call `await init()` before `transform`');
+  }
+
+  const sourceArg = writeString(source);
+  const optionsArg = writeString(JSON.stringify(options));
+  let output;
+  try {
+    const result = exports.solid_jsx_transform(sourceArg.ptr, optionsArg.ptr) >>> 0;
+    output = readString(result);
+    exports.solid_jsx_free(result);
+  } finally {
+    exports.solid_jsx_dealloc(sourceArg.ptr, sourceArg.len);
+    exports.solid_jsx_dealloc(optionsArg.ptr, optionsArg.len);
+  }
+
+  const { error, ...result } = JSON.parse(output);
+  if (error) {
+    throw new Error(`@solid-jsx-oxc/wasi: ${error}`);
+  }
+  return { ...result, map: result.map ?? undefined };
+}
+
+export default { init, transform };